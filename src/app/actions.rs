@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use super::{error::Error, store};
+use crate::revaultd::RevaultD;
+
+pub const DEFAULT_FILE_NAME: &str = "revault_gui_actions.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionKind {
+    Secured,
+    Delegated,
+    Revaulted,
+    SpendAnnounced,
+    Emergency,
+    /// The user acknowledged the critical alert of an unvault
+    /// which was not announced from this GUI.
+    UnvaultAcknowledged,
+    /// The user acknowledged the critical alert of a watchtower outage,
+    /// recorded under the host of the watchtower.
+    WatchtowerDownAcknowledged,
+}
+
+impl std::fmt::Display for ActionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Secured => write!(f, "Revocation transactions signed and shared"),
+            Self::Delegated => write!(f, "Delegated to the managers"),
+            Self::Revaulted => write!(f, "Revaulted"),
+            Self::SpendAnnounced => write!(f, "Spend announced"),
+            Self::Emergency => write!(f, "Emergency triggered"),
+            Self::UnvaultAcknowledged => write!(f, "Unexpected unvault acknowledged"),
+            Self::WatchtowerDownAcknowledged => write!(f, "Watchtower outage acknowledged"),
+        }
+    }
+}
+
+/// Action is an operation on a vault performed from this GUI.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Action {
+    pub kind: ActionKind,
+    /// Unix timestamp of the action.
+    pub date: i64,
+}
+
+/// Actions is the local audit trail of the operations performed from this
+/// GUI, kept by vault outpoint in a json file of the revaultd network datadir.
+#[derive(Debug, Clone, Default)]
+pub struct Actions {
+    path: PathBuf,
+    actions: BTreeMap<String, Vec<Action>>,
+}
+
+impl Actions {
+    pub fn load(path: PathBuf) -> Result<Self, Error> {
+        let actions = store::load(&path)?;
+        Ok(Self { path, actions })
+    }
+
+    /// Loads the actions from the file of the revaultd network datadir.
+    pub fn load_datadir(revaultd: &RevaultD) -> Result<Self, Error> {
+        let mut path = revaultd.config.network_datadir()?;
+        path.push(DEFAULT_FILE_NAME);
+        Self::load(path)
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        store::save(&self.path, &self.actions)
+    }
+
+    /// Returns the actions on the vault, the oldest first.
+    pub fn get(&self, outpoint: &str) -> &[Action] {
+        self.actions
+            .get(outpoint)
+            .map(|actions| actions.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Returns the last of the actions of the given kinds, on any vault.
+    pub fn last(&self, kinds: &[ActionKind]) -> Option<&Action> {
+        self.actions
+            .values()
+            .flatten()
+            .filter(|action| kinds.contains(&action.kind))
+            .max_by_key(|action| action.date)
+    }
+
+    pub fn add<'a>(
+        &mut self,
+        outpoints: impl IntoIterator<Item = &'a String>,
+        kind: ActionKind,
+        date: i64,
+    ) {
+        for outpoint in outpoints {
+            self.actions
+                .entry(outpoint.clone())
+                .or_default()
+                .push(Action { kind, date });
+        }
+    }
+}
+
+/// Records the action on the vaults now. The trail is informative only,
+/// a failure to write it is logged and does not fail the action.
+pub fn record<'a>(
+    revaultd: &RevaultD,
+    outpoints: impl IntoIterator<Item = &'a String>,
+    kind: ActionKind,
+) {
+    let res = Actions::load_datadir(revaultd).and_then(|mut actions| {
+        actions.add(outpoints, kind, Utc::now().timestamp());
+        actions.save()
+    });
+    if let Err(e) = res {
+        tracing::warn!("Failed to record action {:?}: {}", kind, e);
+    }
+}
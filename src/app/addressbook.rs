@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use bitcoin::Network;
+
+use super::{error::Error, store};
+use crate::revaultd::RevaultD;
+
+pub const DEFAULT_FILE_NAME: &str = "revault_gui_addressbook.json";
+
+/// Maximum number of suggestions displayed under a recipient field.
+const MAX_SUGGESTIONS: usize = 5;
+
+/// AddressBook is the local store of the spend destinations used by the
+/// managers, kept as labels by address in a json file of the revaultd
+/// network datadir.
+#[derive(Debug, Clone)]
+pub struct AddressBook {
+    path: PathBuf,
+    /// network of revaultd, the addresses of the other networks are refused.
+    network: Network,
+    entries: BTreeMap<String, String>,
+}
+
+impl Default for AddressBook {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::new(),
+            network: Network::Bitcoin,
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl AddressBook {
+    pub fn load(path: PathBuf, network: Network) -> Result<Self, Error> {
+        let entries = store::load(&path)?;
+        Ok(Self {
+            path,
+            network,
+            entries,
+        })
+    }
+
+    /// Loads the address book from the file of the revaultd network datadir.
+    pub fn load_datadir(revaultd: &RevaultD) -> Result<Self, Error> {
+        let mut path = revaultd.config.network_datadir()?;
+        path.push(DEFAULT_FILE_NAME);
+        Self::load(path, revaultd.network())
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        store::save(&self.path, &self.entries)
+    }
+
+    /// Returns the label and the address of every entry, sorted by label.
+    pub fn entries(&self) -> Vec<(&String, &String)> {
+        let mut entries: Vec<(&String, &String)> = self
+            .entries
+            .iter()
+            .map(|(address, label)| (label, address))
+            .collect();
+        entries.sort();
+        entries
+    }
+
+    /// Returns the labels by address.
+    pub fn all(&self) -> &BTreeMap<String, String> {
+        &self.entries
+    }
+
+    pub fn label(&self, address: &str) -> Option<&String> {
+        self.entries.get(address.trim())
+    }
+
+    /// Adds the address with its label, replacing the label of a known address.
+    pub fn add(&mut self, label: &str, address: &str) -> Result<(), Error> {
+        let (label, address) = (label.trim(), address.trim());
+        if label.is_empty() {
+            return Err(Error::UnexpectedError(
+                "The entry must have a label".to_string(),
+            ));
+        }
+        let parsed = bitcoin::Address::from_str(address).map_err(|e| {
+            Error::UnexpectedError(format!("{} is not a valid address: {}", address, e))
+        })?;
+        if parsed.network != self.network {
+            return Err(Error::UnexpectedError(format!(
+                "{} is not an address of the {} network",
+                address, self.network
+            )));
+        }
+        self.entries.insert(address.to_string(), label.to_string());
+        Ok(())
+    }
+
+    pub fn remove(&mut self, address: &str) {
+        self.entries.remove(address);
+    }
+
+    /// Returns the entries whose label or address contains the query,
+    /// ignoring the case. A known address has no suggestion.
+    pub fn suggestions(&self, query: &str) -> Vec<(&String, &String)> {
+        let query = query.trim();
+        if query.is_empty() || self.entries.contains_key(query) {
+            return Vec::new();
+        }
+        let query = query.to_lowercase();
+        self.entries()
+            .into_iter()
+            .filter(|(label, address)| {
+                label.to_lowercase().contains(&query) || address.to_lowercase().contains(&query)
+            })
+            .take(MAX_SUGGESTIONS)
+            .collect()
+    }
+}
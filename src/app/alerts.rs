@@ -0,0 +1,240 @@
+//! Critical alerts pinned at the top of every view until the user
+//! acknowledges them, the acknowledgment is recorded in the activity log
+//! of the GUI.
+
+use std::time::Duration;
+
+use crate::app::actions::{self, ActionKind, Actions};
+use crate::net::PingError;
+use crate::revaultd::{model::VaultStatus, RevaultD, RevaultDError};
+
+/// Number of seconds between two checks, the watchtowers are pinged.
+pub const CHECK_INTERVAL_SECS: i64 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertKind {
+    /// A vault is being unvaulted but no spend of it was announced
+    /// from this GUI.
+    UnexpectedUnvault,
+    WatchtowerDown,
+}
+
+impl AlertKind {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::UnexpectedUnvault => "Unexpected unvault",
+            Self::WatchtowerDown => "Watchtower down",
+        }
+    }
+
+    fn acknowledgment(&self) -> ActionKind {
+        match self {
+            Self::UnexpectedUnvault => ActionKind::UnvaultAcknowledged,
+            Self::WatchtowerDown => ActionKind::WatchtowerDownAcknowledged,
+        }
+    }
+}
+
+/// Finding is a critical event found by a check.
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub kind: AlertKind,
+    /// Outpoint of the vault or host of the watchtower.
+    pub reference: String,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Alert {
+    pub finding: Finding,
+    /// Unix timestamp of the first check which found the event.
+    pub since: i64,
+    pub acknowledged: bool,
+    /// true while the cancel transaction of the unvault is broadcast.
+    pub canceling: bool,
+    /// Error of the last broadcast of the cancel transaction.
+    pub cancel_error: Option<String>,
+}
+
+/// Returns the hosts of the watchtowers, pinged by the checks.
+pub fn watchtowers(revaultd: &RevaultD) -> Vec<String> {
+    revaultd
+        .config
+        .stakeholder_config
+        .iter()
+        .flat_map(|stakeholder| stakeholder.watchtowers.iter())
+        .map(|watchtower| watchtower.host.clone())
+        .collect()
+}
+
+/// Returns the critical events: the unvaults which were neither announced
+/// nor acknowledged from this GUI and whose spend transaction is unknown,
+/// and the unreachable watchtowers, given with the result of their ping.
+pub fn check(
+    revaultd: &RevaultD,
+    pings: Vec<(String, Result<Duration, PingError>)>,
+) -> Result<Vec<Finding>, RevaultDError> {
+    let vaults = revaultd
+        .list_vaults(
+            Some(&[VaultStatus::Unvaulting, VaultStatus::Unvaulted]),
+            None,
+        )?
+        .vaults;
+    let actions = Actions::load_datadir(revaultd).unwrap_or_else(|e| {
+        tracing::warn!("Failed to load the actions of the GUI: {}", e);
+        Actions::default()
+    });
+    // The spend transactions are only known by the managers.
+    let spent: Vec<String> = if revaultd.config.manager_config.is_some() {
+        revaultd
+            .list_spend_txs(None)
+            .map(|res| {
+                res.spend_txs
+                    .into_iter()
+                    .flat_map(|tx| tx.deposit_outpoints)
+                    .collect()
+            })
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    let mut findings: Vec<Finding> = vaults
+        .iter()
+        .filter(|vault| {
+            !actions.get(&vault.outpoint()).iter().any(|action| {
+                action.kind == ActionKind::SpendAnnounced
+                    || action.kind == ActionKind::UnvaultAcknowledged
+            })
+        })
+        .filter(|vault| !spent.contains(&vault.outpoint()))
+        .map(|vault| Finding {
+            kind: AlertKind::UnexpectedUnvault,
+            reference: vault.outpoint(),
+            detail: format!("{} is {}", vault.outpoint(), vault.status),
+        })
+        .collect();
+
+    for (host, ping) in pings {
+        if let Err(e) = ping {
+            findings.push(Finding {
+                kind: AlertKind::WatchtowerDown,
+                reference: host,
+                detail: e.to_string(),
+            });
+        }
+    }
+    Ok(findings)
+}
+
+/// Alerts are the critical events of the current run of the GUI. The
+/// acknowledgment of an outage holds until the watchtower is back.
+#[derive(Debug, Clone, Default)]
+pub struct Alerts {
+    list: Vec<Alert>,
+    last_check: Option<i64>,
+}
+
+impl Alerts {
+    /// Returns true if a check is due and marks it as started,
+    /// so that a slow check is not run twice.
+    pub fn start_check(&mut self, now: i64) -> bool {
+        let due = self
+            .last_check
+            .map(|last| now - last >= CHECK_INTERVAL_SECS)
+            .unwrap_or(true);
+        if due {
+            self.last_check = Some(now);
+        }
+        due
+    }
+
+    /// Runs the check on the next tick, e.g. once a vault started unvaulting.
+    pub fn check_now(&mut self) {
+        self.last_check = None;
+    }
+
+    /// Replaces the alerts by the findings of the check, keeping
+    /// the state of the events which are still going on.
+    pub fn update(&mut self, findings: Vec<Finding>, now: i64) {
+        self.list = findings
+            .into_iter()
+            .map(|finding| {
+                match self.list.iter().find(|alert| {
+                    alert.finding.kind == finding.kind
+                        && alert.finding.reference == finding.reference
+                }) {
+                    Some(alert) => Alert {
+                        finding,
+                        since: alert.since,
+                        acknowledged: alert.acknowledged,
+                        canceling: alert.canceling,
+                        cancel_error: alert.cancel_error.clone(),
+                    },
+                    None => Alert {
+                        finding,
+                        since: now,
+                        acknowledged: false,
+                        canceling: false,
+                        cancel_error: None,
+                    },
+                }
+            })
+            .collect();
+    }
+
+    /// Returns the alerts waiting for an acknowledgment, the oldest first.
+    pub fn pending(&self) -> Vec<&Alert> {
+        let mut pending: Vec<&Alert> = self.list.iter().filter(|a| !a.acknowledged).collect();
+        pending.sort_by_key(|alert| alert.since);
+        pending
+    }
+
+    /// Marks the unexpected unvault of the vault as being canceled, returns
+    /// false if the vault has no such alert or is already being canceled.
+    pub fn start_cancel(&mut self, outpoint: &str) -> bool {
+        match self.list.iter_mut().find(|a| {
+            a.finding.kind == AlertKind::UnexpectedUnvault && a.finding.reference == outpoint
+        }) {
+            Some(alert) if !alert.canceling => {
+                alert.canceling = true;
+                alert.cancel_error = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Records the result of the broadcast of the cancel transaction,
+    /// the alert of a canceled unvault is acknowledged.
+    pub fn canceled(
+        &mut self,
+        revaultd: &RevaultD,
+        outpoint: &str,
+        res: Result<(), RevaultDError>,
+    ) {
+        if let Some(alert) = self.list.iter_mut().find(|a| {
+            a.finding.kind == AlertKind::UnexpectedUnvault && a.finding.reference == outpoint
+        }) {
+            alert.canceling = false;
+            match res {
+                Ok(()) => {
+                    alert.acknowledged = true;
+                    actions::record(revaultd, [&alert.finding.reference], ActionKind::Revaulted);
+                }
+                Err(e) => alert.cancel_error = Some(e.to_string()),
+            }
+        }
+    }
+
+    /// Marks the alert as acknowledged and records it in the activity log.
+    pub fn acknowledge(&mut self, revaultd: &RevaultD, kind: AlertKind, reference: &str) {
+        if let Some(alert) = self
+            .list
+            .iter_mut()
+            .find(|a| a.finding.kind == kind && a.finding.reference == reference)
+        {
+            alert.acknowledged = true;
+            actions::record(revaultd, [&alert.finding.reference], kind.acknowledgment());
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::PathBuf;
+
+use super::{error::Error, store};
+use crate::revaultd::{
+    model::{Vault, VaultStatus},
+    RevaultD,
+};
+
+pub const DEFAULT_FILE_NAME: &str = "revault_gui_archive.json";
+
+/// Default number of days after which a vault in a final state is archived.
+pub const DEFAULT_DAYS: u32 = 30;
+
+/// Returns true if the vault reached a final state: spent, canceled
+/// or emergency vaulted.
+pub fn is_final(vault: &Vault) -> bool {
+    VaultStatus::MOVED.contains(&vault.status)
+}
+
+/// Returns true if the query is found in the outpoint, the deposit
+/// address or the label of the vault, ignoring the case.
+pub fn matches(vault: &Vault, label: Option<&String>, query: &str) -> bool {
+    let query = query.trim().to_lowercase();
+    query.is_empty()
+        || vault.outpoint().to_lowercase().contains(&query)
+        || vault.address.to_lowercase().contains(&query)
+        || label
+            .map(|l| l.to_lowercase().contains(&query))
+            .unwrap_or(false)
+}
+
+/// Archive is the local store of the choices of the user overriding the
+/// automatic archiving of the vaults in a final state: a vault can be
+/// archived before the delay or kept in the list after it.
+#[derive(Debug, Clone, Default)]
+pub struct Archive {
+    path: PathBuf,
+    /// true if the vault was archived by the user, false if it was restored.
+    vaults: BTreeMap<String, bool>,
+}
+
+impl Archive {
+    /// Loads the archive from the file, if the file does not exist
+    /// the store is empty and the file is created on the first save.
+    pub fn load(path: PathBuf) -> Result<Self, Error> {
+        let vaults = store::load(&path)?;
+        Ok(Self { path, vaults })
+    }
+
+    /// Loads the archive from the file of the revaultd network datadir.
+    pub fn load_datadir(revaultd: &RevaultD) -> Result<Self, Error> {
+        let mut path = revaultd.config.network_datadir()?;
+        path.push(DEFAULT_FILE_NAME);
+        Self::load(path)
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        store::save(&self.path, &self.vaults)
+    }
+
+    /// Returns true if the vault is in a final state and was either archived
+    /// by the user or last updated more than the given days ago.
+    pub fn is_archived(&self, vault: &Vault, now: i64, days: u32) -> bool {
+        if !is_final(vault) {
+            return false;
+        }
+        match self.vaults.get(&vault.outpoint()) {
+            Some(archived) => *archived,
+            None => now - vault.updated_at >= i64::from(days) * 24 * 3600,
+        }
+    }
+
+    /// Archives the given vaults, the vaults still moving are ignored.
+    pub fn archive(&mut self, vaults: &[Vault], outpoints: &HashSet<String>) {
+        for vault in vaults {
+            let outpoint = vault.outpoint();
+            if is_final(vault) && outpoints.contains(&outpoint) {
+                self.vaults.insert(outpoint, true);
+            }
+        }
+    }
+
+    /// Moves the given vaults back to the list of the moved vaults.
+    pub fn restore(&mut self, outpoints: &HashSet<String>) {
+        for outpoint in outpoints {
+            self.vaults.insert(outpoint.clone(), false);
+        }
+    }
+}
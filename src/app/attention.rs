@@ -0,0 +1,155 @@
+//! Actions expected from the user right now, listed by urgency at the top
+//! of the home views with a message jumping into the corresponding flow.
+
+use crate::app::{
+    menu::Menu,
+    message::{Message, SpendTxMessage, VaultMessage},
+    notes::Note,
+};
+use crate::revaultd::model::{SpendTx, Vault, VaultStatus};
+
+/// AttentionKind is ordered by urgency, the most urgent first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AttentionKind {
+    /// The vault is being unvaulted, a stakeholder can still cancel it
+    /// until the end of the timelock.
+    Unvault,
+    /// The spend transaction waits for the managers signatures.
+    SignSpend,
+    /// The deposit waits for the revocation transactions signatures.
+    SignRevocation,
+    /// The unvault of the vault was canceled by a stakeholder.
+    Canceled,
+    /// The vault reached the status awaited by a note of the user.
+    Note,
+}
+
+impl AttentionKind {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Unvault => "Vault being unvaulted, check the spend",
+            Self::SignSpend => "Spend transaction waiting for signatures",
+            Self::SignRevocation => "Deposit to secure, sign the revocation transactions",
+            Self::Canceled => "Unvault canceled by a stakeholder",
+            Self::Note => "Note on the vault",
+        }
+    }
+
+    pub fn action(&self) -> &'static str {
+        match self {
+            Self::Unvault | Self::Canceled => "Check",
+            Self::SignSpend | Self::SignRevocation => "Sign",
+            Self::Note => "Dismiss",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Attention {
+    pub kind: AttentionKind,
+    /// Outpoint of the vault or txid of the spend transaction.
+    pub reference: String,
+    pub amount: u64,
+    /// Message jumping into the flow of the action.
+    pub action: Message,
+    /// Text written by the user, replacing the description of the kind.
+    pub text: Option<String>,
+}
+
+impl Attention {
+    fn vault(kind: AttentionKind, vault: &Vault, action: Message) -> Self {
+        Self {
+            kind,
+            reference: vault.outpoint(),
+            amount: vault.amount,
+            action,
+            text: None,
+        }
+    }
+
+    pub fn description(&self) -> &str {
+        self.text
+            .as_deref()
+            .unwrap_or_else(|| self.kind.description())
+    }
+}
+
+/// Returns the actions expected from a stakeholder.
+pub fn stakeholder<'a>(vaults: impl Iterator<Item = &'a Vault>) -> Vec<Attention> {
+    let mut list: Vec<Attention> = vaults
+        .filter_map(|vault| match vault.status {
+            VaultStatus::Unvaulting | VaultStatus::Unvaulted => Some(Attention::vault(
+                AttentionKind::Unvault,
+                vault,
+                Message::Vault(vault.outpoint(), VaultMessage::Select),
+            )),
+            VaultStatus::Funded => Some(Attention::vault(
+                AttentionKind::SignRevocation,
+                vault,
+                Message::Menu(Menu::CreateVaults),
+            )),
+            _ => None,
+        })
+        .collect();
+    sort(&mut list);
+    list
+}
+
+/// Returns the actions expected from a manager.
+pub fn manager<'a>(
+    vaults: impl Iterator<Item = &'a Vault>,
+    spend_txs: &[SpendTx],
+) -> Vec<Attention> {
+    let mut list: Vec<Attention> = vaults
+        .filter(|vault| vault.status == VaultStatus::Canceling)
+        .map(|vault| {
+            Attention::vault(
+                AttentionKind::Canceled,
+                vault,
+                Message::Vault(vault.outpoint(), VaultMessage::Select),
+            )
+        })
+        .collect();
+    list.extend(spend_txs.iter().map(|tx| {
+        Attention {
+            kind: AttentionKind::SignSpend,
+            reference: tx.psbt.global.unsigned_tx.txid().to_string(),
+            amount: tx
+                .psbt
+                .global
+                .unsigned_tx
+                .output
+                .iter()
+                .map(|output| output.value)
+                .sum(),
+            action: Message::SpendTx(SpendTxMessage::Select(tx.psbt.clone())),
+            text: None,
+        }
+    }));
+    sort(&mut list);
+    list
+}
+
+/// Returns the triggered notes, the amount is the one of the vault
+/// if it is listed.
+pub fn notes(notes: &[&Note], vaults: &[Vault]) -> Vec<Attention> {
+    notes
+        .iter()
+        .map(|note| Attention {
+            kind: AttentionKind::Note,
+            reference: format!("{} is {}", note.outpoint, note.status),
+            amount: vaults
+                .iter()
+                .find(|v| v.outpoint() == note.outpoint)
+                .map(|v| v.amount)
+                .unwrap_or(0),
+            action: Message::DismissNote(note.id),
+            text: Some(note.text.clone()),
+        })
+        .collect()
+}
+
+/// Sorts by urgency, then by amount for the same kind of action.
+fn sort(list: &mut [Attention]) {
+    list.sort_by(|a, b| a.kind.cmp(&b.kind).then(b.amount.cmp(&a.amount)));
+}
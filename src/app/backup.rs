@@ -0,0 +1,404 @@
+use std::path::{Path, PathBuf};
+
+use super::{
+    actions, addressbook, archive, contacts, deposit_addresses, drafts, error::Error, journal,
+    key_checks, labels, notes, notifications, preferences, report,
+};
+use crate::disk;
+
+/// Default number of hours between two automatic backups.
+pub const DEFAULT_INTERVAL_HOURS: u32 = 24;
+
+/// Default number of backups kept in the backup directory.
+pub const DEFAULT_KEEP: usize = 7;
+
+/// Number of seconds before a failed automatic backup is tried again.
+const RETRY_DELAY: i64 = 15 * 60;
+
+const BACKUP_PREFIX: &str = "revault_gui_backup_";
+
+/// Local stores of the GUI saved by a backup, a store is a file or a
+/// directory of files.
+const STORES: &[&str] = &[
+    labels::DEFAULT_FILE_NAME,
+    key_checks::DEFAULT_FILE_NAME,
+    preferences::DEFAULT_FILE_NAME,
+    actions::DEFAULT_FILE_NAME,
+    notes::DEFAULT_FILE_NAME,
+    contacts::DEFAULT_FILE_NAME,
+    archive::DEFAULT_FILE_NAME,
+    addressbook::DEFAULT_FILE_NAME,
+    report::DEFAULT_FILE_NAME,
+    notifications::DEFAULT_FILE_NAME,
+    deposit_addresses::DEFAULT_FILE_NAME,
+    journal::DEFAULT_FILE_NAME,
+    drafts::DEFAULT_DIR_NAME,
+];
+
+/// Backup is a directory of the backup directory containing
+/// a copy of the GUI local stores.
+#[derive(Debug, Clone)]
+pub struct Backup {
+    pub path: PathBuf,
+    /// Unix timestamp of the backup.
+    pub date: i64,
+}
+
+/// Backups saves the GUI local stores of the revaultd network datadir
+/// to the backup directory chosen by the user, keeping the last backups only.
+/// The app and the settings panel hold their own copy, the date of the next
+/// automatic backup is read again from the backup directory when it is due.
+#[derive(Debug)]
+pub struct Backups {
+    datadir: PathBuf,
+    dir: PathBuf,
+    interval: i64,
+    keep: usize,
+    /// Unix timestamp of the next automatic backup.
+    next_due: i64,
+}
+
+impl Backups {
+    pub fn new(datadir: PathBuf, dir: PathBuf, interval_hours: u32, keep: usize) -> Self {
+        let mut backups = Self {
+            datadir,
+            dir,
+            interval: interval_hours as i64 * 60 * 60,
+            keep: keep.max(1),
+            next_due: 0,
+        };
+        backups.schedule();
+        backups
+    }
+
+    /// Sets the next automatic backup an interval after the last backup
+    /// of the backup directory.
+    fn schedule(&mut self) {
+        self.next_due = match self.list().map(|list| list.first().cloned()) {
+            Ok(Some(last)) => last.date + self.interval,
+            _ => 0,
+        };
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns true if no backup was made during the last interval, by this
+    /// copy or by another one. The backup is made by a copy of the backups,
+    /// it is not due again before the retry delay whether it fails or not.
+    pub fn is_due(&mut self, now: i64) -> bool {
+        if now < self.next_due {
+            return false;
+        }
+        self.schedule();
+        if now < self.next_due {
+            return false;
+        }
+        self.next_due = now + RETRY_DELAY;
+        true
+    }
+
+    /// Lists the backups of the backup directory, the most recent first.
+    pub fn list(&self) -> Result<Vec<Backup>, Error> {
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(io_error(&self.dir, e)),
+        };
+        let mut list: Vec<(Backup, u32)> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let name = entry.file_name();
+                let name = name.to_str()?.strip_prefix(BACKUP_PREFIX)?;
+                let (date, index) = match name.split_once('_') {
+                    Some((date, index)) => (date.parse().ok()?, index.parse().ok()?),
+                    None => (name.parse().ok()?, 0),
+                };
+                let backup = Backup {
+                    path: entry.path(),
+                    date,
+                };
+                Some((backup, index))
+            })
+            .collect();
+        list.sort_by_key(|(backup, index)| std::cmp::Reverse((backup.date, *index)));
+        Ok(list.into_iter().map(|(backup, _)| backup).collect())
+    }
+
+    /// Copies the local stores to a new backup and removes the oldest backups.
+    pub fn backup(&mut self, now: i64) -> Result<Backup, Error> {
+        self.save(now, None)
+    }
+
+    /// Saves the local stores to a new backup, the oldest backups are
+    /// removed except the kept one.
+    fn save(&mut self, now: i64, kept: Option<&Path>) -> Result<Backup, Error> {
+        disk::check_dir(&self.dir, stores_size(&self.datadir))?;
+        let path = self.create_backup_dir(now)?;
+        for store in STORES {
+            let source = self.datadir.join(store);
+            if source.exists() {
+                copy_store(&source, &path.join(store))?;
+            }
+        }
+        self.next_due = now + self.interval;
+
+        for old in self
+            .list()?
+            .iter()
+            .skip(self.keep)
+            .filter(|old| Some(old.path.as_path()) != kept)
+        {
+            if let Err(e) = std::fs::remove_dir_all(&old.path) {
+                tracing::warn!("Failed to remove old backup {}: {}", old.path.display(), e);
+            }
+        }
+
+        Ok(Backup { path, date: now })
+    }
+
+    /// Creates the directory of a new backup. The backups of a same second
+    /// are numbered so that a backup never overwrites another one.
+    fn create_backup_dir(&self, now: i64) -> Result<PathBuf, Error> {
+        std::fs::create_dir_all(&self.dir).map_err(|e| io_error(&self.dir, e))?;
+        let mut index = 0;
+        loop {
+            let name = if index == 0 {
+                format!("{}{}", BACKUP_PREFIX, now)
+            } else {
+                format!("{}{}_{}", BACKUP_PREFIX, now, index)
+            };
+            let path = self.dir.join(name);
+            match std::fs::create_dir(&path) {
+                Ok(()) => return Ok(path),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => index += 1,
+                Err(e) => return Err(io_error(&path, e)),
+            }
+        }
+    }
+
+    /// Replaces the local stores by the ones of the backup.
+    /// The current stores are saved first to a new backup so that the
+    /// restore can be undone, the restored backup is not removed by the
+    /// rotation. A store missing from the backup is left untouched.
+    pub fn restore(&mut self, backup: &Backup, now: i64) -> Result<(), Error> {
+        if !backup.path.is_dir() {
+            return Err(Error::UnexpectedError(format!(
+                "Restore failed: backup {} not found",
+                backup.path.display()
+            )));
+        }
+        self.save(now, Some(&backup.path))?;
+        disk::check_dir(&self.datadir, stores_size(&backup.path))?;
+        for store in STORES {
+            let source = backup.path.join(store);
+            if source.exists() {
+                copy_store(&source, &self.datadir.join(store))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Clone for Backups {
+    /// The copy schedules its next backup from the backup directory instead
+    /// of the date of the original, which may be outdated.
+    fn clone(&self) -> Self {
+        let mut backups = Self {
+            datadir: self.datadir.clone(),
+            dir: self.dir.clone(),
+            interval: self.interval,
+            keep: self.keep,
+            next_due: 0,
+        };
+        backups.schedule();
+        backups
+    }
+}
+
+/// Returns the size of the stores of the directory.
+fn stores_size(dir: &Path) -> u64 {
+    STORES
+        .iter()
+        .map(|store| store_size(&dir.join(store)))
+        .sum()
+}
+
+/// Returns the size of the store file, or of the files of the store directory.
+fn store_size(path: &Path) -> u64 {
+    match std::fs::read_dir(path) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| entry.metadata().ok())
+            .map(|metadata| metadata.len())
+            .sum(),
+        Err(_) => std::fs::metadata(path)
+            .map(|metadata| metadata.len())
+            .unwrap_or(0),
+    }
+}
+
+/// Copies the store file, or replaces the destination directory by a copy
+/// of the files of the store directory.
+fn copy_store(source: &Path, destination: &Path) -> Result<(), Error> {
+    if !source.is_dir() {
+        std::fs::copy(source, destination).map_err(|e| io_error(source, e))?;
+        return Ok(());
+    }
+    if destination.exists() {
+        std::fs::remove_dir_all(destination).map_err(|e| io_error(destination, e))?;
+    }
+    std::fs::create_dir_all(destination).map_err(|e| io_error(destination, e))?;
+    for entry in std::fs::read_dir(source).map_err(|e| io_error(source, e))? {
+        let entry = entry.map_err(|e| io_error(source, e))?;
+        if entry.path().is_file() {
+            std::fs::copy(entry.path(), destination.join(entry.file_name()))
+                .map_err(|e| io_error(&entry.path(), e))?;
+        }
+    }
+    Ok(())
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> Error {
+    Error::UnexpectedError(format!("Backup failed for {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("revault_gui_test_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn failed_backup_is_retried_after_a_delay() {
+        let root = test_dir("failed_backup_is_retried_after_a_delay");
+        // The backup directory cannot be created under a file.
+        std::fs::write(root.join("file"), "").unwrap();
+        let mut backups = Backups::new(root.clone(), root.join("file").join("backups"), 24, 1);
+
+        assert!(backups.is_due(100));
+        assert!(backups.clone().backup(100).is_err());
+        assert!(!backups.is_due(101));
+        assert!(backups.is_due(100 + RETRY_DELAY));
+    }
+
+    #[test]
+    fn restore_oldest_backup() {
+        let root = test_dir("restore_oldest_backup");
+        let datadir = root.join("datadir");
+        std::fs::create_dir_all(&datadir).unwrap();
+        let store = datadir.join(labels::DEFAULT_FILE_NAME);
+
+        let keep = 3;
+        let mut backups = Backups::new(datadir, root.join("backups"), 24, keep);
+        for date in 1..=keep as i64 {
+            std::fs::write(&store, format!("labels {}", date)).unwrap();
+            backups.backup(date).unwrap();
+        }
+        let oldest = backups.list().unwrap().pop().unwrap();
+        assert_eq!(oldest.date, 1);
+
+        std::fs::write(&store, "current labels").unwrap();
+        backups.restore(&oldest, 10).unwrap();
+        assert_eq!(std::fs::read_to_string(&store).unwrap(), "labels 1");
+
+        // The current labels are saved and the restored backup is kept.
+        let dates: Vec<i64> = backups.list().unwrap().iter().map(|b| b.date).collect();
+        assert!(dates.contains(&10));
+        assert!(dates.contains(&1));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn backups_of_a_same_second() {
+        let root = test_dir("backups_of_a_same_second");
+        let datadir = root.join("datadir");
+        std::fs::create_dir_all(&datadir).unwrap();
+        let store = datadir.join(labels::DEFAULT_FILE_NAME);
+
+        let mut backups = Backups::new(datadir, root.join("backups"), 24, 3);
+        std::fs::write(&store, "labels 1").unwrap();
+        let first = backups.backup(1).unwrap();
+        std::fs::write(&store, "labels 2").unwrap();
+        let second = backups.backup(1).unwrap();
+        assert_ne!(first.path, second.path);
+
+        // The restore saves the current labels in a third backup of the
+        // same second instead of overwriting the restored one.
+        std::fs::write(&store, "labels 3").unwrap();
+        backups.restore(&first, 1).unwrap();
+        assert_eq!(std::fs::read_to_string(&store).unwrap(), "labels 1");
+        let list = backups.list().unwrap();
+        assert_eq!(list.len(), 3);
+        assert_eq!(
+            std::fs::read_to_string(list[0].path.join(labels::DEFAULT_FILE_NAME)).unwrap(),
+            "labels 3"
+        );
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn clone_schedules_from_the_backup_directory() {
+        let root = test_dir("clone_schedules_from_the_backup_directory");
+        let datadir = root.join("datadir");
+        std::fs::create_dir_all(&datadir).unwrap();
+
+        let mut backups = Backups::new(datadir, root.join("backups"), 1, 3);
+        let mut copy = backups.clone();
+        assert!(backups.is_due(10));
+        backups.backup(10).unwrap();
+        // The copy was made before the backup, it is not due anymore.
+        assert!(!copy.is_due(20));
+        assert!(!backups.clone().is_due(20));
+        assert!(copy.is_due(10 + 3600));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn restore_drafts() {
+        let root = test_dir("restore_drafts");
+        let datadir = root.join("datadir");
+        let drafts = datadir.join(drafts::DEFAULT_DIR_NAME);
+        std::fs::create_dir_all(&drafts).unwrap();
+        std::fs::write(drafts.join("1.json"), "draft 1").unwrap();
+
+        let mut backups = Backups::new(datadir, root.join("backups"), 24, 3);
+        let backup = backups.backup(1).unwrap();
+        std::fs::write(drafts.join("1.json"), "draft 1 edited").unwrap();
+        std::fs::write(drafts.join("2.json"), "draft 2").unwrap();
+
+        backups.restore(&backup, 2).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(drafts.join("1.json")).unwrap(),
+            "draft 1"
+        );
+        assert!(!drafts.join("2.json").exists());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn restore_missing_backup() {
+        let root = test_dir("restore_missing_backup");
+        let datadir = root.join("datadir");
+        std::fs::create_dir_all(&datadir).unwrap();
+        std::fs::write(datadir.join(labels::DEFAULT_FILE_NAME), "labels").unwrap();
+
+        let mut backups = Backups::new(datadir, root.join("backups"), 24, 3);
+        let backup = backups.backup(1).unwrap();
+        std::fs::remove_dir_all(backups.dir()).unwrap();
+        assert!(backups.restore(&backup, 2).is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
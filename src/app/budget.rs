@@ -0,0 +1,66 @@
+//! Delegation budget: the maximum amount the stakeholders agreed to
+//! delegate to the managers at once.
+
+/// DelegationBudget is the state of the budget for the delegated vaults,
+/// the active ones and the ones waiting for the other stakeholders.
+#[derive(Debug, Clone, Copy)]
+pub struct DelegationBudget {
+    /// Maximum delegated amount in satoshis.
+    pub max: u64,
+    /// Amount of the delegated vaults in satoshis.
+    pub delegated: u64,
+}
+
+impl DelegationBudget {
+    /// Returns the amount which can still be delegated.
+    pub fn remaining(&self) -> u64 {
+        self.max.saturating_sub(self.delegated)
+    }
+
+    /// Returns the amount above the budget if the vault is delegated,
+    /// None if it fits.
+    pub fn excess(&self, amount: u64) -> Option<u64> {
+        let total = self.delegated + amount;
+        if total > self.max {
+            Some(total - self.max)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remaining_budget() {
+        let budget = DelegationBudget {
+            max: 100,
+            delegated: 30,
+        };
+        assert_eq!(budget.remaining(), 70);
+        // The vaults delegated before the budget was lowered may exceed it.
+        let budget = DelegationBudget {
+            max: 100,
+            delegated: 130,
+        };
+        assert_eq!(budget.remaining(), 0);
+    }
+
+    #[test]
+    fn excess_of_a_delegation() {
+        let budget = DelegationBudget {
+            max: 100,
+            delegated: 30,
+        };
+        assert_eq!(budget.excess(0), None);
+        assert_eq!(budget.excess(70), None);
+        assert_eq!(budget.excess(71), Some(1));
+        let budget = DelegationBudget {
+            max: 100,
+            delegated: 130,
+        };
+        assert_eq!(budget.excess(10), Some(40));
+    }
+}
@@ -0,0 +1,67 @@
+//! Coin control of the spend creation: the vaults funding a spend are
+//! selected by the manager or automatically, every vault spent is one more
+//! unvault to broadcast and one more input of the spend transaction, whose
+//! weight is bounded by the standardness limit.
+
+use std::str::FromStr;
+
+use miniscript::DescriptorTrait;
+use revault_tx::{scripts::UnvaultDescriptor, transactions::MAX_STANDARD_TX_WEIGHT};
+
+use crate::revaultd::model::Vault;
+
+/// Weight of the version, the locktime and the input and output counts,
+/// plus the segwit marker and flag.
+const TX_BASE_WEIGHT: usize = (4 + 4 + 3 + 3) * 4 + 2;
+
+/// Weight of an input without its witness: outpoint, empty script and
+/// sequence.
+const TXIN_BASE_WEIGHT: usize = (36 + 1 + 4) * 4;
+
+/// Weight of the largest output, a P2WSH one.
+const TXOUT_WEIGHT: usize = (8 + 1 + 34) * 4;
+
+/// Returns the weight of an unvault output spent by the spend transaction,
+/// with the largest witness of the unvault descriptor.
+pub fn input_weight(unvault_descriptor: &str) -> Option<usize> {
+    let descriptor = UnvaultDescriptor::from_str(unvault_descriptor).ok()?;
+    let witness = descriptor.into_inner().max_satisfaction_weight().ok()?;
+    Some(TXIN_BASE_WEIGHT + witness)
+}
+
+/// Returns the maximum number of vaults a spend transaction of the given
+/// number of outputs can spend.
+pub fn max_inputs(input_weight: usize, outputs: usize) -> usize {
+    (MAX_STANDARD_TX_WEIGHT as usize)
+        .saturating_sub(TX_BASE_WEIGHT + outputs * TXOUT_WEIGHT)
+        .checked_div(input_weight)
+        .unwrap_or(0)
+}
+
+/// Returns the outpoints of the fewest vaults worth at least the required
+/// amount, the smallest excess among them, or None if all the vaults are
+/// not enough.
+pub fn auto_select(vaults: &[Vault], required: u64) -> Option<Vec<String>> {
+    let mut sorted: Vec<&Vault> = vaults.iter().collect();
+    sorted.sort_by_key(|vault| std::cmp::Reverse(vault.amount));
+
+    let mut total = 0;
+    let count = sorted.iter().position(|vault| {
+        total += vault.amount;
+        total >= required
+    })? + 1;
+
+    // The largest vaults but the last one, which is the smallest one
+    // covering the rest of the required amount.
+    let first: u64 = sorted[..count - 1].iter().map(|vault| vault.amount).sum();
+    let last = sorted[count - 1..]
+        .iter()
+        .rev()
+        .find(|vault| first + vault.amount >= required)?;
+    let mut selection: Vec<String> = sorted[..count - 1]
+        .iter()
+        .map(|vault| vault.outpoint())
+        .collect();
+    selection.push(last.outpoint());
+    Some(selection)
+}
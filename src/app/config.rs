@@ -1,44 +1,306 @@
 use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use crate::revaultd::config::default_datadir;
+use crate::{
+    i18n::Language,
+    price::FiatCurrency,
+    revaultd::{config::default_datadir, CallPolicy},
+    ui::theme::ThemePreference,
+};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Config {
+    /// Path of the file the configuration was read from.
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+    /// Version of the file, the files of a previous version are migrated
+    /// when they are read. None for the files written before the versions.
+    pub version: Option<u32>,
     /// Path to revaultd configuration file.
     pub revaultd_config_path: PathBuf,
-    /// Path to revaultd binary.
+    /// Path to revaultd binary, searched in the PATH and next to the GUI if None.
     pub revaultd_path: Option<PathBuf>,
     /// log level, can be "info", "debug", "trace".
+    /// The logs are written as json to the logs directory of the datadir.
     pub log_level: Option<String>,
     /// Use iced debug feature if true.
     pub debug: Option<bool>,
+    /// Language of the interface, can be "english" or "french".
+    /// Defaults to "english".
+    pub language: Option<Language>,
+    /// Theme of the interface, can be "system", "light", "dark".
+    /// Defaults to "system".
+    pub theme: Option<ThemePreference>,
+    /// Number of days between two checks of the emergency signing device.
+    /// Defaults to 90.
+    pub emergency_check_reminder_days: Option<u32>,
+    /// Record local-only usage metrics if true. Disabled by default.
+    pub local_metrics: Option<bool>,
+    /// Number of confirmations before a spend is displayed as final.
+    /// Defaults to 6.
+    pub spend_final_confirmations: Option<u32>,
+    /// Directory of the backups of the GUI local data,
+    /// automatic backups are disabled if not set.
+    pub backup_dir: Option<PathBuf>,
+    /// Number of hours between two automatic backups. Defaults to 24.
+    pub backup_interval_hours: Option<u32>,
+    /// Number of backups kept in the backup directory. Defaults to 7.
+    pub backup_keep: Option<usize>,
+    /// Display the balances in fiat on mainnet if true, with the rates of
+    /// the price feed url. Disabled by default.
+    pub price_feed: Option<bool>,
+    /// Url of the price feed provider.
+    pub price_feed_url: Option<String>,
+    /// Fiat currency of the balances, can be "USD" or "EUR". Defaults to "USD".
+    pub fiat_currency: Option<FiatCurrency>,
+    /// Timeout in seconds of the calls to revaultd. Defaults to 30, the calls
+    /// requiring revaultd to reach the coordinator have four times this timeout.
+    pub revaultd_timeout_secs: Option<u64>,
+    /// Number of retries of the calls to revaultd safe to retry, reading
+    /// data or idempotent, when they time out. Defaults to 2. The delay before
+    /// a retry starts at one second and doubles, the call is not retried once
+    /// the delays would sum to more than 15 seconds.
+    pub revaultd_retries: Option<u32>,
+    /// Run as a training profile if true: the GUI only connects to a revaultd
+    /// running on regtest and every screen is watermarked. Disabled by default.
+    pub training: Option<bool>,
+    /// Path to a TrueType font replacing the bundled regular font.
+    pub font: Option<PathBuf>,
+    /// Path to a TrueType font replacing the bundled bold font,
+    /// defaults to the configured regular font if any.
+    pub font_bold: Option<PathBuf>,
+    /// Size of the regular text, the other texts are scaled along.
+    /// Defaults to 20.
+    pub text_size: Option<u16>,
+    /// Number of seconds between two refreshes of the vaults, the block
+    /// height and the spend transactions. Defaults to 30.
+    pub refresh_interval_secs: Option<u64>,
+    /// Maximum amount in satoshis the stakeholders agreed to delegate to the
+    /// managers, the active vaults and the ones waiting for the other
+    /// stakeholders included. Delegating beyond it requires an explicit
+    /// override. Disabled by default.
+    pub max_delegated_amount: Option<u64>,
+    /// Target amount in satoshis of a vault, the deposit panel suggests
+    /// splitting a planned deposit in deposits of this amount.
+    /// Disabled by default.
+    pub vault_size: Option<u64>,
+    /// Addresses the spend transactions created by the manager may pay,
+    /// checked before signing. Any address if not set.
+    pub spend_whitelist: Option<Vec<String>>,
+    /// Number of days after which the spent, canceled and emergency vaulted
+    /// vaults move from the vaults list to the archive. Defaults to 30.
+    pub archive_after_days: Option<u32>,
+    /// Milliseconds without keystroke before a search is filtered or a typed
+    /// field is checked. Defaults to 300.
+    pub debounce_ms: Option<u64>,
+    /// Size of the window when the GUI was last resized, the window opens
+    /// with it. Written by the GUI.
+    pub window_size: Option<WindowSize>,
+    /// Display the developer console in the sidebar if true, the console
+    /// sends raw JSON-RPC calls to revaultd. Disabled by default.
+    pub developer_console: Option<bool>,
+    /// SOCKS5 proxy of the GUI health checks of the coordinator, the
+    /// cosigners and the watchtowers, like the one of a Tor daemon. It only
+    /// covers these checks, revaultd connects to the servers directly.
+    pub health_check_proxy: Option<SocketAddr>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WindowSize {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl WindowSize {
+    /// Smallest size of the window saved or restored. A minimized window
+    /// is reported with a size of zero or a few pixels on some platforms.
+    pub const MIN: WindowSize = WindowSize {
+        width: 400,
+        height: 300,
+    };
+
+    /// Returns false for the size of a minimized window.
+    pub fn is_usable(&self) -> bool {
+        self.width >= Self::MIN.width && self.height >= Self::MIN.height
+    }
+
+    /// Returns the size, enlarged to the minimum size if it is smaller.
+    pub fn clamped(self) -> Self {
+        WindowSize {
+            width: self.width.max(Self::MIN.width),
+            height: self.height.max(Self::MIN.height),
+        }
+    }
+}
+
+/// Version of the configuration file written by this GUI.
+pub const VERSION: u32 = 2;
+
+/// Migrations of the configuration file, the migration at index i upgrades
+/// a file of version i to version i + 1.
+const MIGRATIONS: [fn(&mut toml::value::Table); VERSION as usize] =
+    [enable_price_feed, rename_proxy];
+
+/// Version 1: the price feed is enabled by the price_feed entry, the files
+/// already configuring a feed url keep it enabled.
+fn enable_price_feed(table: &mut toml::value::Table) {
+    if table.contains_key("price_feed_url") && !table.contains_key("price_feed") {
+        table.insert("price_feed".to_string(), toml::Value::Boolean(true));
+    }
+}
+
+/// Version 2: the proxy entry is renamed health_check_proxy, it only
+/// covers the health checks of the GUI.
+fn rename_proxy(table: &mut toml::value::Table) {
+    if let Some(proxy) = table.remove("proxy") {
+        table.insert("health_check_proxy".to_string(), proxy);
+    }
+}
+
+/// Applies the migrations the file misses, returns true if it was migrated.
+fn migrate(table: &mut toml::value::Table) -> bool {
+    let version = table
+        .get("version")
+        .and_then(|v| v.as_integer())
+        .unwrap_or(0)
+        .max(0) as usize;
+    if version >= MIGRATIONS.len() {
+        return false;
+    }
+    for migration in &MIGRATIONS[version..] {
+        migration(table);
+    }
+    table.insert("version".to_string(), toml::Value::Integer(VERSION as i64));
+    true
 }
 
 pub const DEFAULT_FILE_NAME: &str = "revault_gui.toml";
 
+const LOG_DIR_NAME: &str = "logs";
+
+/// Default number of confirmations before a spend is displayed as final.
+pub const DEFAULT_SPEND_FINAL_CONFIRMATIONS: u32 = 6;
+
 impl Config {
     pub fn new(revaultd_config_path: PathBuf) -> Self {
         Self {
+            path: None,
+            version: Some(VERSION),
             revaultd_config_path,
             revaultd_path: None,
             log_level: None,
             debug: None,
+            language: None,
+            theme: None,
+            emergency_check_reminder_days: None,
+            local_metrics: None,
+            spend_final_confirmations: None,
+            backup_dir: None,
+            backup_interval_hours: None,
+            backup_keep: None,
+            price_feed: None,
+            price_feed_url: None,
+            fiat_currency: None,
+            revaultd_timeout_secs: None,
+            revaultd_retries: None,
+            training: None,
+            font: None,
+            font_bold: None,
+            text_size: None,
+            refresh_interval_secs: None,
+            max_delegated_amount: None,
+            vault_size: None,
+            spend_whitelist: None,
+            archive_after_days: None,
+            debounce_ms: None,
+            window_size: None,
+            developer_console: None,
+            health_check_proxy: None,
         }
     }
 
+    pub fn is_training(&self) -> bool {
+        self.training == Some(true)
+    }
+
+    /// Returns the timeouts of the calls to revaultd.
+    pub fn call_policy(&self) -> CallPolicy {
+        let mut policy = CallPolicy::default();
+        if let Some(secs) = self.revaultd_timeout_secs {
+            policy.timeout = Duration::from_secs(secs);
+            policy.coordinator_timeout = Duration::from_secs(secs * 4);
+        }
+        if let Some(retries) = self.revaultd_retries {
+            policy.retry.retries = retries;
+        }
+        policy
+    }
+
+    /// Directory of the log files, next to the revaultd configuration file.
+    pub fn log_dir(&self) -> Option<PathBuf> {
+        self.revaultd_config_path
+            .parent()
+            .map(|dir| dir.join(LOG_DIR_NAME))
+    }
+
+    /// Reads the configuration file, a file of a previous version is
+    /// migrated and written back.
     pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
-        let config = std::fs::read(path)
-            .map_err(|e| match e.kind() {
-                std::io::ErrorKind::NotFound => ConfigError::NotFound,
-                _ => ConfigError::ReadingFile(format!("Reading configuration file: {}", e)),
-            })
-            .and_then(|file_content| {
-                toml::from_slice::<Config>(&file_content).map_err(|e| {
-                    ConfigError::ReadingFile(format!("Parsing configuration file: {}", e))
-                })
-            })?;
-        Ok(config)
+        let mut content = read_file(path)?;
+        if let Some(table) = content.as_table_mut() {
+            if migrate(table) {
+                tracing::info!("Configuration file migrated to version {}", VERSION);
+                if let Err(e) = std::fs::write(path, content.to_string()) {
+                    tracing::error!("Failed to write the migrated configuration file: {}", e);
+                }
+            }
+        }
+        let config: Config = content
+            .try_into()
+            .map_err(|e| ConfigError::ReadingFile(format!("Parsing configuration file: {}", e)))?;
+        Ok(Config {
+            path: Some(path.to_path_buf()),
+            ..config
+        })
+    }
+
+    /// Changes the theme and writes it to the configuration file.
+    pub fn set_theme(&mut self, theme: ThemePreference) -> Result<(), ConfigError> {
+        self.theme = Some(theme);
+        self.write_entry("theme", theme)
+    }
+
+    /// Changes the window size and writes it to the configuration file.
+    pub fn set_window_size(&mut self, size: WindowSize) -> Result<(), ConfigError> {
+        self.window_size = Some(size);
+        self.write_entry("window_size", size)
+    }
+
+    /// Shows or hides the developer console and writes it to the
+    /// configuration file.
+    pub fn set_developer_console(&mut self, enabled: bool) -> Result<(), ConfigError> {
+        self.developer_console = Some(enabled);
+        self.write_entry("developer_console", enabled)
+    }
+
+    /// Writes the entry to the configuration file, the other entries of the
+    /// file are kept as they are.
+    fn write_entry<T: Serialize>(&self, key: &str, value: T) -> Result<(), ConfigError> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        let mut content = read_file(path)?;
+        if let Some(table) = content.as_table_mut() {
+            table.insert(
+                key.to_string(),
+                toml::Value::try_from(value).map_err(|e| ConfigError::Unexpected(e.to_string()))?,
+            );
+        }
+        std::fs::write(path, content.to_string())
+            .map_err(|e| ConfigError::Unexpected(format!("Writing configuration file: {}", e)))
     }
 
     pub fn default_path() -> Result<PathBuf, ConfigError> {
@@ -50,6 +312,18 @@ impl Config {
     }
 }
 
+fn read_file(path: &Path) -> Result<toml::Value, ConfigError> {
+    std::fs::read(path)
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => ConfigError::NotFound,
+            _ => ConfigError::ReadingFile(format!("Reading configuration file: {}", e)),
+        })
+        .and_then(|content| {
+            toml::from_slice(&content)
+                .map_err(|e| ConfigError::ReadingFile(format!("Parsing configuration file: {}", e)))
+        })
+}
+
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum ConfigError {
     NotFound,
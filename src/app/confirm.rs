@@ -0,0 +1,146 @@
+//! Confirmation asked to the user before an action: the message of the
+//! action is only dispatched once the user confirmed in the modal.
+
+use chrono::NaiveDateTime;
+
+use super::message::{
+    BackupMessage, ConfigEditMessage, ConfirmMessage, DaemonMessage, EmergencyConfirmMessage,
+    Message, VaultMessage,
+};
+
+/// Phrase typed by the user to confirm the emergency.
+pub const EMERGENCY_PHRASE: &str = "EMERGENCY";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmLevel {
+    /// The action can be undone.
+    Info,
+    /// The action cannot be undone.
+    Destructive,
+    /// The action cannot be undone and the user must type the phrase.
+    Critical(&'static str),
+}
+
+#[derive(Debug, Clone)]
+pub struct Confirmation {
+    pub level: ConfirmLevel,
+    pub title: String,
+    pub description: String,
+    /// Label of the button confirming the action.
+    pub label: String,
+    pub action: Box<Message>,
+    /// Text typed by the user for a critical action.
+    pub phrase: String,
+}
+
+impl Confirmation {
+    pub fn new(
+        level: ConfirmLevel,
+        title: &str,
+        description: &str,
+        label: &str,
+        action: Message,
+    ) -> Self {
+        Self {
+            level,
+            title: title.to_string(),
+            description: description.to_string(),
+            label: label.to_string(),
+            action: Box::new(action),
+            phrase: String::new(),
+        }
+    }
+
+    /// Replaces the action dispatched once the user confirmed, for the same
+    /// confirmation asked from another panel.
+    pub fn with_action(mut self, action: Message) -> Self {
+        self.action = Box::new(action);
+        self
+    }
+
+    /// Returns the message opening the modal.
+    pub fn ask(self) -> Message {
+        Message::Confirm(ConfirmMessage::Ask(self))
+    }
+
+    pub fn can_confirm(&self) -> bool {
+        match self.level {
+            ConfirmLevel::Critical(phrase) => self.phrase.trim() == phrase,
+            _ => true,
+        }
+    }
+}
+
+pub fn delegate(outpoint: String) -> Confirmation {
+    Confirmation::new(
+        ConfirmLevel::Destructive,
+        "Delegate the vault",
+        "Once the unvault transaction is signed, the managers can spend the funds of \
+         the vault. Only a cancel transaction can then stop a spend.",
+        "Delegate",
+        Message::Vault(outpoint, VaultMessage::Delegate),
+    )
+}
+
+pub fn revault(outpoint: String) -> Confirmation {
+    Confirmation::new(
+        ConfirmLevel::Destructive,
+        "Revault the funds",
+        "The cancel transaction will be broadcast, the funds go back to a new vault \
+         and the spend of the managers is aborted.",
+        "Yes, revault",
+        Message::Vault(outpoint, VaultMessage::Revault),
+    )
+}
+
+/// The amount is displayed with the unit of the user.
+pub fn emergency(vaults: usize, amount: &str) -> Confirmation {
+    Confirmation::new(
+        ConfirmLevel::Critical(EMERGENCY_PHRASE),
+        "Emergency",
+        &format!(
+            "This action will send {} from {} vaults to the Emergency Deep Vault. \
+             The funds can only be recovered with the Emergency Deep Vault keys, \
+             this cannot be undone.",
+            amount, vaults
+        ),
+        "Broadcast the emergency transactions",
+        Message::EmergencyConfirm(EmergencyConfirmMessage::Broadcast),
+    )
+}
+
+pub fn stop_daemon() -> Confirmation {
+    Confirmation::new(
+        ConfirmLevel::Info,
+        "Stop revaultd",
+        "The vaults are not watched while revaultd is stopped: an unvault is neither \
+         displayed nor canceled by this wallet until revaultd is started again.",
+        "Stop",
+        Message::Daemon(DaemonMessage::Stop),
+    )
+}
+
+pub fn restore(date: i64) -> Confirmation {
+    Confirmation::new(
+        ConfirmLevel::Info,
+        "Restore the backup",
+        &format!(
+            "The labels, preferences and history of the GUI are replaced by the backup of {}. \
+             The current data is saved in a new backup first.",
+            NaiveDateTime::from_timestamp(date, 0)
+        ),
+        "Restore",
+        Message::Backup(BackupMessage::Restore(date)),
+    )
+}
+
+pub fn config_overwrite() -> Confirmation {
+    Confirmation::new(
+        ConfirmLevel::Destructive,
+        "Write the configuration",
+        "The revaultd configuration file is overwritten, the previous one is kept with \
+         the .bak extension. revaultd is then restarted with the new settings.",
+        "Write and restart",
+        Message::ConfigEdit(ConfigEditMessage::Save),
+    )
+}
@@ -0,0 +1,146 @@
+//! Connection to revaultd during the session: each call opens a new socket,
+//! so the GUI reconnects by itself once the daemon is back. The connection
+//! tracks the running instance to tell the panel to load again its data
+//! after a restart, instead of keeping the errors of the calls which failed
+//! while the daemon was down.
+
+use crate::revaultd::{RevaultD, RevaultDError};
+
+/// Seconds between two checks of the daemon while it is unreachable,
+/// and between two checks of the expiry of the toast.
+pub const HEARTBEAT_SECS: u64 = 2;
+
+/// Seconds the toast is displayed.
+pub const TOAST_SECS: i64 = 5;
+
+/// Instance is the running revaultd, the pid is the one of its pid file,
+/// which only exists if the daemon was daemonized.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instance {
+    pub version: String,
+    pub pid: Option<u32>,
+    /// Unix timestamp of the pid file, written when the daemon started.
+    pub started_at: Option<i64>,
+    pub blockheight: u64,
+    /// Progress of the synchronisation with bitcoind, from 0 to 1.
+    pub sync: f64,
+}
+
+impl Instance {
+    pub fn get(revaultd: &RevaultD) -> Result<Self, RevaultDError> {
+        let info = revaultd.get_info()?;
+        let (pid, started_at) = pid_file(revaultd);
+        Ok(Self {
+            version: info.version,
+            pid,
+            started_at,
+            blockheight: info.blockheight,
+            sync: info.sync,
+        })
+    }
+}
+
+/// Returns the pid of the pid file of the revaultd network datadir
+/// and the date the file was written.
+fn pid_file(revaultd: &RevaultD) -> (Option<u32>, Option<i64>) {
+    let path = match revaultd.config.pid_file() {
+        Ok(path) => path,
+        Err(_) => return (None, None),
+    };
+    let pid = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|pid| pid.trim().parse().ok());
+    let started_at = std::fs::metadata(&path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs() as i64);
+    (pid, started_at)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transition {
+    Lost,
+    /// The same instance answers again.
+    Reconnected,
+    Restarted,
+    /// The daemon restarted with another version, its RPC API may differ.
+    Upgraded,
+}
+
+#[derive(Debug, Default)]
+pub struct Connection {
+    /// None until the first check.
+    instance: Option<Instance>,
+    /// Unix timestamp of the first failed check.
+    lost_since: Option<i64>,
+    /// true if the user stopped the daemon from the GUI.
+    stopped: bool,
+}
+
+impl Connection {
+    pub fn is_lost(&self) -> bool {
+        self.lost_since.is_some()
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+
+    /// Records the stop of the daemon by the user, it is still checked
+    /// in case it is started again outside of the GUI.
+    pub fn stop(&mut self, now: i64) {
+        self.lost_since = Some(now);
+        self.stopped = true;
+    }
+
+    pub fn lost_since(&self) -> Option<i64> {
+        self.lost_since
+    }
+
+    /// Records the result of the check and returns the transition of the
+    /// connection, if any. The errors other than a failure to reach the
+    /// socket, e.g. a timeout of a busy daemon, are not a loss.
+    pub fn update(&mut self, res: Result<Instance, RevaultDError>, now: i64) -> Option<Transition> {
+        match res {
+            Err(e) if is_connection_error(&e) => {
+                if self.lost_since.is_some() {
+                    return None;
+                }
+                self.lost_since = Some(now);
+                Some(Transition::Lost)
+            }
+            Err(_) => None,
+            Ok(instance) => {
+                let lost = self.lost_since.take().is_some();
+                self.stopped = false;
+                let transition = match &self.instance {
+                    Some(previous) if previous.version != instance.version => {
+                        Some(Transition::Upgraded)
+                    }
+                    Some(previous) if previous.pid != instance.pid => Some(Transition::Restarted),
+                    _ if lost => Some(Transition::Reconnected),
+                    _ => None,
+                };
+                self.instance = Some(instance);
+                transition
+            }
+        }
+    }
+}
+
+/// The socket is missing or closed while revaultd is stopped.
+pub fn is_connection_error(e: &RevaultDError) -> bool {
+    use std::io::ErrorKind;
+    matches!(
+        e,
+        RevaultDError::IOError(
+            ErrorKind::NotFound
+                | ErrorKind::ConnectionRefused
+                | ErrorKind::ConnectionReset
+                | ErrorKind::ConnectionAborted
+                | ErrorKind::BrokenPipe
+                | ErrorKind::UnexpectedEof
+        ) | RevaultDError::NoAnswerError
+    )
+}
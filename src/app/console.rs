@@ -0,0 +1,80 @@
+//! Developer console: raw JSON-RPC calls to revaultd typed by the user,
+//! their pretty-printed responses are kept in memory until the GUI is closed
+//! so that the history survives the changes of panel.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use serde_json::Value;
+
+use crate::revaultd::RevaultDError;
+
+/// Number of calls kept in the history, the oldest are dropped.
+const MAX_HISTORY: usize = 50;
+
+#[derive(Debug, Clone)]
+pub struct Call {
+    pub time: i64,
+    pub method: String,
+    /// Params as typed by the user, empty if the call had none.
+    pub params: String,
+    /// Pretty-printed result or the error of revaultd.
+    pub response: Result<String, String>,
+}
+
+static HISTORY: Mutex<VecDeque<Call>> = Mutex::new(VecDeque::new());
+
+/// Returns the calls of the history, the latest first.
+pub fn history() -> Vec<Call> {
+    HISTORY.lock().unwrap().iter().rev().cloned().collect()
+}
+
+pub fn clear_history() {
+    HISTORY.lock().unwrap().clear();
+}
+
+/// Adds the response of the call to the history.
+pub fn record(method: String, params: String, response: &Result<Value, RevaultDError>, time: i64) {
+    let call = Call {
+        time,
+        method,
+        params,
+        response: match response {
+            Ok(value) => serde_json::to_string_pretty(value).map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        },
+    };
+    let mut history = HISTORY.lock().unwrap();
+    if history.len() == MAX_HISTORY {
+        history.pop_front();
+    }
+    history.push_back(call);
+}
+
+/// Methods the console refuses to send: they move funds or stop revaultd,
+/// and their panels ask the user to confirm them first.
+const REFUSED_METHODS: [&str; 4] = ["emergency", "revault", "stop", "setspendtx"];
+
+/// Returns an error if the console refuses to send the method.
+pub fn check_method(method: &str) -> Result<(), String> {
+    if REFUSED_METHODS.contains(&method) {
+        return Err(format!(
+            "The console does not send {}, use its panel which asks for a confirmation",
+            method
+        ));
+    }
+    Ok(())
+}
+
+/// Parses the params typed by the user: nothing, a JSON array of positional
+/// params or a JSON object of named ones.
+pub fn parse_params(params: &str) -> Result<Option<Value>, String> {
+    if params.trim().is_empty() {
+        return Ok(None);
+    }
+    match serde_json::from_str(params) {
+        Ok(value @ Value::Array(_)) | Ok(value @ Value::Object(_)) => Ok(Some(value)),
+        Ok(_) => Err("The params must be a JSON array or a JSON object".to_string()),
+        Err(e) => Err(format!("The params are not valid JSON: {}", e)),
+    }
+}
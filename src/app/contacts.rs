@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::{error::Error, store};
+use crate::revaultd::RevaultD;
+
+pub const DEFAULT_FILE_NAME: &str = "revault_gui_contacts.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactField {
+    Name,
+    /// Email or matrix handle.
+    Handle,
+    Phone,
+}
+
+impl ContactField {
+    pub const ALL: [ContactField; 3] = [
+        ContactField::Name,
+        ContactField::Handle,
+        ContactField::Phone,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Name => "Name",
+            Self::Handle => "Email or matrix",
+            Self::Phone => "Phone",
+        }
+    }
+}
+
+/// Contact is the operator to call when a server of the deployment is down.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Contact {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub handle: String,
+    #[serde(default)]
+    pub phone: String,
+}
+
+impl Contact {
+    pub fn get(&self, field: ContactField) -> &str {
+        match field {
+            ContactField::Name => &self.name,
+            ContactField::Handle => &self.handle,
+            ContactField::Phone => &self.phone,
+        }
+    }
+
+    fn set(&mut self, field: ContactField, value: String) {
+        match field {
+            ContactField::Name => self.name = value,
+            ContactField::Handle => self.handle = value,
+            ContactField::Phone => self.phone = value,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        ContactField::ALL
+            .iter()
+            .all(|field| self.get(*field).trim().is_empty())
+    }
+}
+
+impl std::fmt::Display for Contact {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let fields: Vec<&str> = ContactField::ALL
+            .iter()
+            .map(|field| self.get(*field).trim())
+            .filter(|value| !value.is_empty())
+            .collect();
+        write!(f, "{}", fields.join(", "))
+    }
+}
+
+/// Contacts is the local store of the operators of the coordinator and the
+/// watchtowers, kept by server host in a json file of the revaultd network datadir.
+#[derive(Debug, Clone, Default)]
+pub struct Contacts {
+    path: PathBuf,
+    contacts: BTreeMap<String, Contact>,
+}
+
+impl Contacts {
+    pub fn load(path: PathBuf) -> Result<Self, Error> {
+        let contacts = store::load(&path)?;
+        Ok(Self { path, contacts })
+    }
+
+    /// Loads the contacts from the file of the revaultd network datadir.
+    pub fn load_datadir(revaultd: &RevaultD) -> Result<Self, Error> {
+        let mut path = revaultd.config.network_datadir()?;
+        path.push(DEFAULT_FILE_NAME);
+        Self::load(path)
+    }
+
+    /// Empty contacts are not saved.
+    pub fn save(&mut self) -> Result<(), Error> {
+        self.contacts.retain(|_, contact| !contact.is_empty());
+        store::save(&self.path, &self.contacts)
+    }
+
+    /// Returns the contact of the server, None if it has no information.
+    pub fn get(&self, host: &str) -> Option<&Contact> {
+        self.contacts
+            .get(host)
+            .filter(|contact| !contact.is_empty())
+    }
+
+    pub fn edit(&mut self, host: &str, field: ContactField, value: String) {
+        self.contacts
+            .entry(host.to_string())
+            .or_default()
+            .set(field, value);
+    }
+}
@@ -0,0 +1,59 @@
+//! State of the exchanges with the coordinator, which relays the signatures
+//! and the spend transactions between the participants.
+
+use std::time::Duration;
+
+use crate::app::actions::{Action, ActionKind, Actions};
+use crate::net::PingError;
+use crate::revaultd::{
+    model::{Vault, VaultStatus},
+    RevaultD, RevaultDError,
+};
+
+/// Actions of the GUI sharing signatures or transactions with the coordinator.
+const EXCHANGES: [ActionKind; 3] = [
+    ActionKind::Secured,
+    ActionKind::Delegated,
+    ActionKind::SpendAnnounced,
+];
+
+#[derive(Debug, Clone)]
+pub struct CoordinatorStatus {
+    pub host: String,
+    /// Time to open a connection to the coordinator from this machine.
+    pub probe: Result<Duration, PingError>,
+    /// false if the last call of revaultd requiring the coordinator failed.
+    pub reachable: bool,
+    /// Last signature or transaction shared from this GUI.
+    pub last_exchange: Option<Action>,
+    /// Vaults with our signatures, waiting for the ones of the other
+    /// participants.
+    pub pending: Vec<Vault>,
+}
+
+/// Returns the status of the coordinator with the result of its probe.
+pub fn coordinator_status(
+    revaultd: &RevaultD,
+    probe: Result<Duration, PingError>,
+) -> Result<CoordinatorStatus, RevaultDError> {
+    let pending = revaultd
+        .list_vaults(
+            Some(&[VaultStatus::Securing, VaultStatus::Activating]),
+            None,
+        )?
+        .vaults;
+    let last_exchange = match Actions::load_datadir(revaultd) {
+        Ok(actions) => actions.last(&EXCHANGES).cloned(),
+        Err(e) => {
+            tracing::warn!("Failed to load the actions of the GUI: {}", e);
+            None
+        }
+    };
+    Ok(CoordinatorStatus {
+        host: revaultd.config.coordinator_host.clone(),
+        probe,
+        reachable: revaultd.coordinator_reachable(),
+        last_exchange,
+        pending,
+    })
+}
@@ -0,0 +1,163 @@
+//! Edition of the connection settings of the revaultd configuration file,
+//! the daemon is restarted with the written file.
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::conversion;
+use crate::revaultd::config::Config;
+use crate::ui::component::form;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigField {
+    BitcoindAddr,
+    CookiePath,
+    BitcoindPoll,
+    CoordinatorHost,
+    CoordinatorNoiseKey,
+    CoordinatorPoll,
+}
+
+impl ConfigField {
+    pub const ALL: [ConfigField; 6] = [
+        ConfigField::BitcoindAddr,
+        ConfigField::CookiePath,
+        ConfigField::BitcoindPoll,
+        ConfigField::CoordinatorHost,
+        ConfigField::CoordinatorNoiseKey,
+        ConfigField::CoordinatorPoll,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::BitcoindAddr => "Bitcoind RPC address",
+            Self::CookiePath => "Bitcoind cookie file path",
+            Self::BitcoindPoll => "Bitcoind poll interval (seconds)",
+            Self::CoordinatorHost => "Coordinator host",
+            Self::CoordinatorNoiseKey => "Coordinator noise key",
+            Self::CoordinatorPoll => "Coordinator poll interval (seconds)",
+        }
+    }
+
+    pub fn warning(&self) -> &'static str {
+        match self {
+            Self::BitcoindAddr => "Please enter an ip:port address",
+            Self::CookiePath => "No file found at this path",
+            Self::BitcoindPoll | Self::CoordinatorPoll => {
+                "Please enter a number of seconds or leave it empty for the default"
+            }
+            Self::CoordinatorHost => "Please enter a host:port address",
+            Self::CoordinatorNoiseKey => "Please enter the 64 hexadecimal characters of the key",
+        }
+    }
+
+    fn value(&self, config: &Config) -> String {
+        let poll = |secs: Option<u64>| secs.map(|s| s.to_string()).unwrap_or_default();
+        match self {
+            Self::BitcoindAddr => config.bitcoind_config.addr.to_string(),
+            Self::CookiePath => config.bitcoind_config.cookie_path.display().to_string(),
+            Self::BitcoindPoll => poll(config.bitcoind_config.poll_interval_secs),
+            Self::CoordinatorHost => config.coordinator_host.clone(),
+            Self::CoordinatorNoiseKey => config.coordinator_noise_key.clone(),
+            Self::CoordinatorPoll => poll(config.coordinator_poll_seconds),
+        }
+    }
+
+    /// Sets the value to the configuration, returns false if it is not valid.
+    fn apply(&self, value: &str, config: &mut Config) -> bool {
+        let value = value.trim();
+        match self {
+            Self::BitcoindAddr => match SocketAddr::from_str(value) {
+                Ok(addr) => config.bitcoind_config.addr = addr,
+                Err(_) => return false,
+            },
+            Self::CookiePath => {
+                let path = PathBuf::from(value);
+                if !path.is_file() {
+                    return false;
+                }
+                config.bitcoind_config.cookie_path = path;
+            }
+            Self::BitcoindPoll => match parse_poll(value) {
+                Some(secs) => config.bitcoind_config.poll_interval_secs = secs,
+                None => return false,
+            },
+            Self::CoordinatorHost => match value.rsplit_once(':') {
+                Some((host, port)) if !host.is_empty() && port.parse::<u16>().is_ok() => {
+                    config.coordinator_host = value.to_string()
+                }
+                _ => return false,
+            },
+            Self::CoordinatorNoiseKey => {
+                if value.len() != 64 || !value.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return false;
+                }
+                config.coordinator_noise_key = value.to_lowercase();
+            }
+            Self::CoordinatorPoll => match parse_poll(value) {
+                Some(secs) => config.coordinator_poll_seconds = secs,
+                None => return false,
+            },
+        }
+        true
+    }
+}
+
+/// An empty poll interval is the default of revaultd.
+fn parse_poll(value: &str) -> Option<Option<u64>> {
+    if value.is_empty() {
+        return Some(None);
+    }
+    conversion::parse_integer::<u64>(value)
+        .filter(|secs| *secs > 0)
+        .map(Some)
+}
+
+/// ConfigEdit is the form of the connection settings.
+#[derive(Debug, Clone)]
+pub struct ConfigEdit {
+    pub fields: Vec<(ConfigField, form::Value<String>)>,
+}
+
+impl ConfigEdit {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            fields: ConfigField::ALL
+                .iter()
+                .map(|field| {
+                    (
+                        *field,
+                        form::Value {
+                            value: field.value(config),
+                            valid: true,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn edit(&mut self, field: ConfigField, value: String) {
+        if let Some((_, v)) = self.fields.iter_mut().find(|(f, _)| *f == field) {
+            v.value = value;
+            v.valid = true;
+        }
+    }
+
+    /// Returns the configuration with the new values,
+    /// None if one of them is not valid.
+    pub fn apply(&mut self, config: &Config) -> Option<Config> {
+        let mut config = config.clone();
+        let mut valid = true;
+        for (field, value) in &mut self.fields {
+            value.valid = field.apply(&value.value, &mut config);
+            valid &= value.valid;
+        }
+        if valid {
+            Some(config)
+        } else {
+            None
+        }
+    }
+}
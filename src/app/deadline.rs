@@ -0,0 +1,107 @@
+//! Deadlines of the cancel transactions of the vaults being unvaulted: the
+//! cancel must confirm before the CSV of the unvault expires, else the
+//! spend can be broadcast.
+
+use std::collections::BTreeMap;
+
+use revault_tx::transactions::REVAULTING_TX_FEERATE;
+
+use crate::bitcoind;
+use crate::revaultd::{
+    model::{BroadcastedTransaction, Vault, VaultStatus},
+    RevaultD, RevaultDError,
+};
+
+/// Feerate in sats/vbyte of the pre-signed cancel transactions.
+pub const CANCEL_FEERATE: u32 = REVAULTING_TX_FEERATE as u32 * 4;
+
+/// A vault is at risk once fewer blocks remain to cancel it.
+pub const AT_RISK_BLOCKS: u32 = 6;
+
+#[derive(Debug, Clone)]
+pub struct CancelDeadline {
+    pub outpoint: String,
+    pub amount: u64,
+    /// Blocks left for the cancel transaction to confirm, the CSV is
+    /// counted from the confirmation of the unvault transaction.
+    pub remaining: u32,
+    pub csv: u32,
+    /// Feerate in sats/vbyte needed to confirm within the remaining blocks,
+    /// None if bitcoind cannot estimate it.
+    pub feerate: Option<u32>,
+}
+
+impl CancelDeadline {
+    /// Returns true if the cancel transaction may not confirm in time.
+    pub fn at_risk(&self) -> bool {
+        self.remaining <= AT_RISK_BLOCKS || matches!(self.feerate, Some(f) if f > CANCEL_FEERATE)
+    }
+}
+
+/// Returns the blocks left before the CSV of the unvault expires, the whole
+/// CSV if the unvault transaction is not confirmed.
+pub fn remaining_blocks(
+    unvault: Option<&BroadcastedTransaction>,
+    blockheight: u64,
+    csv: u32,
+) -> u32 {
+    let confirmations = unvault
+        .map(|unvault| unvault.confirmations(blockheight))
+        .unwrap_or(0);
+    (csv as u64).saturating_sub(confirmations) as u32
+}
+
+/// Returns the deadlines of the unvaulting vaults, the closest first.
+pub fn cancel_deadlines(
+    revaultd: &RevaultD,
+    vaults: Vec<Vault>,
+    csv: u32,
+) -> Result<Vec<CancelDeadline>, RevaultDError> {
+    let vaults: Vec<Vault> = vaults
+        .into_iter()
+        .filter(|v| v.status == VaultStatus::Unvaulting || v.status == VaultStatus::Unvaulted)
+        .collect();
+    if vaults.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let blockheight = revaultd.get_info()?.blockheight;
+    let txs = revaultd
+        .list_onchain_transactions(Some(vaults.iter().map(|v| v.outpoint()).collect()))?
+        .onchain_transactions;
+
+    let config = &revaultd.config.bitcoind_config;
+    // The vaults with the same remaining blocks share the estimate, bitcoind
+    // is asked once per confirmation target of the check.
+    let mut estimates: BTreeMap<u16, Option<u32>> = BTreeMap::new();
+    let mut deadlines: Vec<CancelDeadline> = vaults
+        .into_iter()
+        .map(|vault| {
+            let outpoint = vault.outpoint();
+            let unvault = txs
+                .iter()
+                .find(|tx| tx.vault_outpoint == outpoint)
+                .and_then(|tx| tx.unvault.as_ref());
+            let remaining = remaining_blocks(unvault, blockheight, csv);
+            let target = remaining.min(bitcoind::MAX_FEE_TARGET as u32) as u16;
+            let feerate = *estimates.entry(target).or_insert_with(|| {
+                match bitcoind::estimate_feerate(&config.addr, &config.cookie_path, target) {
+                    Ok(estimate) => estimate.map(|(feerate, _)| feerate),
+                    Err(e) => {
+                        tracing::warn!("Failed to estimate the cancel feerate: {}", e);
+                        None
+                    }
+                }
+            });
+            CancelDeadline {
+                outpoint,
+                amount: vault.amount,
+                remaining,
+                csv,
+                feerate,
+            }
+        })
+        .collect();
+    deadlines.sort_by_key(|d| d.remaining);
+    Ok(deadlines)
+}
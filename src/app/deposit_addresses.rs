@@ -0,0 +1,85 @@
+//! History of the deposit addresses derived by revaultd: the daemon returns
+//! the same address until a deposit is received on it, the GUI then asks for
+//! the next one. The addresses displayed are kept with the date they were
+//! first displayed, their usage is found in the vaults of the daemon.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::{error::Error, store};
+use crate::revaultd::{model::Vault, RevaultD};
+
+pub const DEFAULT_FILE_NAME: &str = "revault_gui_deposit_addresses.json";
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DerivedAddress {
+    pub address: String,
+    /// Unix timestamp of the first display of the address.
+    pub derived_at: i64,
+}
+
+/// DepositAddresses is the local store of the deposit addresses displayed,
+/// the oldest first, kept in a json file of the revaultd network datadir.
+#[derive(Debug, Clone, Default)]
+pub struct DepositAddresses {
+    path: PathBuf,
+    addresses: Vec<DerivedAddress>,
+}
+
+impl DepositAddresses {
+    pub fn load(path: PathBuf) -> Result<Self, Error> {
+        let addresses = store::load(&path)?;
+        Ok(Self { path, addresses })
+    }
+
+    /// Loads the addresses from the file of the revaultd network datadir.
+    pub fn load_datadir(revaultd: &RevaultD) -> Result<Self, Error> {
+        let mut path = revaultd.config.network_datadir()?;
+        path.push(DEFAULT_FILE_NAME);
+        Self::load(path)
+    }
+
+    pub fn all(&self) -> &[DerivedAddress] {
+        &self.addresses
+    }
+
+    /// Records the address if it was never displayed.
+    pub fn record(&mut self, address: &str, now: i64) -> Result<(), Error> {
+        if self.addresses.iter().any(|a| a.address == address) {
+            return Ok(());
+        }
+        self.addresses.push(DerivedAddress {
+            address: address.to_string(),
+            derived_at: now,
+        });
+        store::save(&self.path, &self.addresses)
+    }
+}
+
+/// Deposits received on an address.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub deposits: usize,
+    /// Sum of the deposits in satoshis.
+    pub amount: u64,
+}
+
+impl Usage {
+    /// An address receiving several deposits links them onchain.
+    pub fn is_reused(&self) -> bool {
+        self.deposits > 1
+    }
+}
+
+/// Returns the usage of the deposit addresses of the vaults.
+pub fn usage(vaults: &[Vault]) -> HashMap<String, Usage> {
+    let mut usage: HashMap<String, Usage> = HashMap::new();
+    for vault in vaults {
+        let u = usage.entry(vault.address.clone()).or_default();
+        u.deposits += 1;
+        u.amount += vault.amount;
+    }
+    usage
+}
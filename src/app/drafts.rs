@@ -0,0 +1,144 @@
+use std::path::PathBuf;
+
+use bitcoin::{base64, consensus::encode, util::psbt::PartiallySignedTransaction as Psbt};
+use serde::{Deserialize, Serialize};
+
+use super::{error::Error, store};
+
+/// Directory of the drafts in the revaultd network datadir.
+pub const DEFAULT_DIR_NAME: &str = "revault_gui_drafts";
+
+/// Draft is a spend transaction in creation, saved after every change
+/// so that it can be resumed after a restart of the GUI.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Draft {
+    /// Unix timestamp of the draft creation, identifies the draft.
+    pub id: i64,
+    /// Unix timestamp of the last change.
+    pub updated_at: i64,
+    /// Destination addresses with their amounts as entered by the user.
+    pub outputs: Vec<(String, String)>,
+    /// Outpoints of the selected vaults.
+    pub inputs: Vec<String>,
+    pub feerate: Option<u32>,
+    /// Base64 of the generated psbt, with the feerate it was generated with.
+    pub psbt: Option<(String, u32)>,
+}
+
+impl Draft {
+    pub fn new(id: i64) -> Self {
+        Self {
+            id,
+            updated_at: id,
+            ..Self::default()
+        }
+    }
+
+    /// A draft without destination nor selected vault is not worth saving.
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty()
+            && self
+                .outputs
+                .iter()
+                .all(|(address, amount)| address.is_empty() && amount.is_empty())
+    }
+
+    pub fn set_psbt(&mut self, psbt: Option<&(Psbt, u32)>) {
+        self.psbt =
+            psbt.map(|(psbt, feerate)| (base64::encode(&encode::serialize(psbt)), *feerate));
+    }
+
+    pub fn psbt(&self) -> Option<(Psbt, u32)> {
+        let (psbt, feerate) = self.psbt.as_ref()?;
+        let psbt = base64::decode(psbt)
+            .ok()
+            .and_then(|bytes| encode::deserialize(&bytes).ok())?;
+        Some((psbt, *feerate))
+    }
+}
+
+/// Drafts is the local store of the spend transaction drafts,
+/// one json file per draft.
+#[derive(Debug, Clone, Default)]
+pub struct Drafts {
+    dir: PathBuf,
+}
+
+impl Drafts {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn path(&self, id: i64) -> PathBuf {
+        self.dir.join(format!("{}.json", id))
+    }
+
+    /// Returns the drafts, the most recently changed first. A draft which
+    /// cannot be read is skipped so that the others can still be resumed.
+    pub fn list(&self) -> Result<Vec<Draft>, Error> {
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => {
+                return Err(Error::UnexpectedError(format!(
+                    "Failed to read {}: {}",
+                    self.dir.display(),
+                    e
+                )))
+            }
+        };
+        let mut drafts = Vec::new();
+        for entry in entries.filter_map(|entry| entry.ok()) {
+            let path = entry.path();
+            if matches!(path.extension(), Some(ext) if ext == "json") {
+                match store::load::<Draft>(&path) {
+                    Ok(draft) => drafts.push(draft),
+                    Err(e) => tracing::warn!("Skipping draft {}: {}", path.display(), e),
+                }
+            }
+        }
+        drafts.sort_by_key(|draft| std::cmp::Reverse(draft.updated_at));
+        Ok(drafts)
+    }
+
+    pub fn save(&self, draft: &Draft) -> Result<(), Error> {
+        std::fs::create_dir_all(&self.dir).map_err(|e| {
+            Error::UnexpectedError(format!("Failed to create {}: {}", self.dir.display(), e))
+        })?;
+        store::save(&self.path(draft.id), draft)
+    }
+
+    pub fn delete(&self, id: i64) -> Result<(), Error> {
+        match std::fs::remove_file(self.path(id)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(Error::UnexpectedError(
+                format!("Failed to delete draft {}: {}", id, e),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_skips_corrupt_drafts() {
+        let dir = std::env::temp_dir().join(format!(
+            "revault_gui_test_list_skips_corrupt_drafts_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        let drafts = Drafts::new(dir.clone());
+        let mut draft = Draft::new(1);
+        draft.inputs.push("outpoint".to_string());
+        drafts.save(&draft).unwrap();
+        std::fs::write(dir.join("2.json"), "{ not a draft").unwrap();
+
+        let list = drafts.list().unwrap();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].id, 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
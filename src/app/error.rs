@@ -1,3 +1,5 @@
+use crate::bitcoind::BitcoindError;
+use crate::disk::DiskError;
 use crate::revaultd::{config::ConfigError, RevaultDError};
 use std::convert::From;
 
@@ -5,6 +7,8 @@ use std::convert::From;
 pub enum Error {
     ConfigError(ConfigError),
     RevaultDError(RevaultDError),
+    BitcoindError(BitcoindError),
+    DiskError(DiskError),
     UnexpectedError(String),
 }
 
@@ -13,6 +17,8 @@ impl std::fmt::Display for Error {
         match self {
             Self::ConfigError(e) => write!(f, "Config error: {}", e),
             Self::RevaultDError(e) => write!(f, "RevaultD error: {}", e),
+            Self::BitcoindError(e) => write!(f, "{}", e),
+            Self::DiskError(e) => write!(f, "Disk error: {}", e),
             Self::UnexpectedError(e) => write!(f, "Unexpected error: {}", e),
         }
     }
@@ -29,3 +35,15 @@ impl From<RevaultDError> for Error {
         Error::RevaultDError(error)
     }
 }
+
+impl From<BitcoindError> for Error {
+    fn from(error: BitcoindError) -> Self {
+        Error::BitcoindError(error)
+    }
+}
+
+impl From<DiskError> for Error {
+    fn from(error: DiskError) -> Self {
+        Error::DiskError(error)
+    }
+}
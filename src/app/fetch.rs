@@ -0,0 +1,312 @@
+//! Fetching of the revocation transactions of a batch of deposits by chunks
+//! of outpoints, revaultd answering getrevocationtxs for a single vault.
+//! A few chunks are fetched at the same time, each by its own command
+//! calling revaultd with its own connection, the calls of a chunk one after
+//! the other. The results of a chunk are recorded as soon as it is received
+//! so that the state can display the progress. Each fetch has an id, the
+//! chunks received for a previous fetch are dropped.
+//!
+//! The vault lists of the dashboards are fetched with one listvaults call
+//! per status, the calls made at the same time. The vaults of a status are
+//! displayed as soon as they are received, the vaults of the other statuses
+//! being the ones of the previous fetch until their own call answers.
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::revaultd::model::{Vault, VaultStatus};
+
+/// Number of outpoints of a chunk.
+pub const CHUNK_SIZE: usize = 20;
+
+/// Number of chunks fetched at the same time.
+pub const MAX_CONCURRENT_CHUNKS: usize = 4;
+
+/// Chunk of outpoints to fetch, with its index.
+pub type Chunk = (usize, Vec<String>);
+
+/// ChunkedFetch tracks the chunks of outpoints to fetch, in flight and
+/// received.
+#[derive(Debug, Clone)]
+pub struct ChunkedFetch<T> {
+    id: usize,
+    pending: VecDeque<Chunk>,
+    /// results of the received chunks, by chunk index.
+    received: Vec<Option<Vec<T>>>,
+    in_flight: usize,
+    total: usize,
+    fetched: usize,
+}
+
+impl<T> ChunkedFetch<T> {
+    pub fn new(id: usize, outpoints: Vec<String>) -> Self {
+        let total = outpoints.len();
+        let pending: VecDeque<Chunk> = outpoints
+            .chunks(CHUNK_SIZE)
+            .map(|chunk| chunk.to_vec())
+            .enumerate()
+            .collect();
+        Self {
+            id,
+            received: pending.iter().map(|_| None).collect(),
+            pending,
+            in_flight: 0,
+            total,
+            fetched: 0,
+        }
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns the chunks to fetch now, so that no more than
+    /// MAX_CONCURRENT_CHUNKS are in flight.
+    pub fn next_chunks(&mut self) -> Vec<Chunk> {
+        let mut chunks = Vec::new();
+        while self.in_flight < MAX_CONCURRENT_CHUNKS {
+            match self.pending.pop_front() {
+                Some(chunk) => {
+                    self.in_flight += 1;
+                    chunks.push(chunk);
+                }
+                None => break,
+            }
+        }
+        chunks
+    }
+
+    /// Records the results of the chunk, returns the chunks to fetch next.
+    /// The chunk of another fetch is dropped.
+    pub fn receive(&mut self, id: usize, index: usize, results: Vec<T>) -> Vec<Chunk> {
+        if id != self.id {
+            return Vec::new();
+        }
+        if let Some(slot @ None) = self.received.get_mut(index) {
+            self.fetched += results.len();
+            *slot = Some(results);
+            self.in_flight -= 1;
+        }
+        self.next_chunks()
+    }
+
+    /// Returns the number of fetched outpoints and the total.
+    pub fn progress(&self) -> (usize, usize) {
+        (self.fetched, self.total)
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.received.iter().all(|chunk| chunk.is_some())
+    }
+
+    /// Returns the results in the order of the outpoints, None if a chunk
+    /// is not received yet.
+    pub fn results(self) -> Option<Vec<T>> {
+        let mut results = Vec::with_capacity(self.total);
+        for chunk in self.received {
+            results.extend(chunk?);
+        }
+        Some(results)
+    }
+}
+
+/// VaultsFetch merges the vaults of the listvaults calls of a list,
+/// one call per status.
+#[derive(Debug, Clone)]
+pub struct VaultsFetch {
+    statuses: Vec<VaultStatus>,
+    /// Statuses whose vaults were received at least once.
+    received: HashSet<VaultStatus>,
+    vaults: Vec<Vault>,
+}
+
+impl VaultsFetch {
+    pub fn new(statuses: &[VaultStatus]) -> Self {
+        Self {
+            statuses: statuses.to_vec(),
+            received: HashSet::new(),
+            vaults: Vec::new(),
+        }
+    }
+
+    pub fn statuses(&self) -> &[VaultStatus] {
+        &self.statuses
+    }
+
+    /// Changes the fetched statuses, the vaults of the other statuses are
+    /// removed.
+    pub fn set_statuses(&mut self, statuses: &[VaultStatus]) {
+        self.statuses = statuses.to_vec();
+        self.received.retain(|status| statuses.contains(status));
+        self.vaults.retain(|vault| statuses.contains(&vault.status));
+    }
+
+    /// Replaces the vaults of the status by the received ones, returns false
+    /// if the status is no longer fetched. A vault listed with another status
+    /// changed its status since, the received one is kept.
+    pub fn receive(&mut self, status: VaultStatus, vaults: Vec<Vault>) -> bool {
+        if !self.statuses.contains(&status) {
+            return false;
+        }
+        let outpoints: HashSet<String> = vaults.iter().map(|v| v.outpoint()).collect();
+        self.vaults
+            .retain(|v| v.status != status && !outpoints.contains(&v.outpoint()));
+        self.vaults.extend(vaults);
+        self.received.insert(status);
+        true
+    }
+
+    /// Returns true once the vaults of a status were received.
+    pub fn has_received(&self) -> bool {
+        !self.received.is_empty()
+    }
+
+    /// Returns true once the vaults of every status were received.
+    pub fn is_loaded(&self) -> bool {
+        self.statuses
+            .iter()
+            .all(|status| self.received.contains(status))
+    }
+
+    pub fn vaults(&self) -> &[Vault] {
+        &self.vaults
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outpoints(n: usize) -> Vec<String> {
+        (0..n).map(|i| format!("{:064x}:0", i)).collect()
+    }
+
+    /// Returns the results of the chunk: the position of each outpoint.
+    fn fetched(chunk: &Chunk) -> Vec<usize> {
+        let first = chunk.0 * CHUNK_SIZE;
+        (first..first + chunk.1.len()).collect()
+    }
+
+    #[test]
+    fn chunks_in_flight() {
+        let total = CHUNK_SIZE * 5 + 3;
+        let mut fetch = ChunkedFetch::new(1, outpoints(total));
+        let chunks = fetch.next_chunks();
+        assert_eq!(chunks.len(), MAX_CONCURRENT_CHUNKS);
+        assert!(chunks.iter().all(|(_, c)| c.len() == CHUNK_SIZE));
+        // No chunk is started until one is received.
+        assert!(fetch.next_chunks().is_empty());
+
+        let next = fetch.receive(1, chunks[1].0, fetched(&chunks[1]));
+        assert_eq!(next.len(), 1);
+        assert_eq!(next[0].0, MAX_CONCURRENT_CHUNKS);
+        assert_eq!(fetch.progress(), (CHUNK_SIZE, total));
+        assert!(!fetch.is_done());
+    }
+
+    #[test]
+    fn results_in_order() {
+        let total = CHUNK_SIZE * 5 + 3;
+        let mut fetch = ChunkedFetch::new(1, outpoints(total));
+        let mut chunks = fetch.next_chunks();
+        // The chunks are received in the reverse order.
+        while let Some(chunk) = chunks.pop() {
+            let next = fetch.receive(1, chunk.0, fetched(&chunk));
+            chunks.splice(0..0, next);
+        }
+        assert!(fetch.is_done());
+        assert_eq!(fetch.progress(), (total, total));
+        assert_eq!(fetch.results(), Some((0..total).collect()));
+    }
+
+    #[test]
+    fn duplicate_and_stale_chunks() {
+        let mut fetch = ChunkedFetch::new(2, outpoints(CHUNK_SIZE * 2));
+        let chunks = fetch.next_chunks();
+        assert_eq!(chunks.len(), 2);
+
+        // A chunk of a previous fetch with the same index is dropped.
+        fetch.receive(1, 0, vec![usize::MAX; CHUNK_SIZE]);
+        assert_eq!(fetch.progress(), (0, CHUNK_SIZE * 2));
+
+        fetch.receive(2, 0, fetched(&chunks[0]));
+        // A chunk received twice is recorded once.
+        fetch.receive(2, 0, vec![usize::MAX; CHUNK_SIZE]);
+        assert_eq!(fetch.progress(), (CHUNK_SIZE, CHUNK_SIZE * 2));
+        assert!(!fetch.is_done());
+        assert!(fetch.clone().results().is_none());
+
+        fetch.receive(2, 1, fetched(&chunks[1]));
+        assert_eq!(fetch.results(), Some((0..CHUNK_SIZE * 2).collect()));
+    }
+
+    #[test]
+    fn empty_fetch() {
+        let mut fetch: ChunkedFetch<usize> = ChunkedFetch::new(1, Vec::new());
+        assert!(fetch.next_chunks().is_empty());
+        assert!(fetch.is_done());
+        assert_eq!(fetch.results(), Some(Vec::new()));
+    }
+
+    fn vault(vout: u32, status: VaultStatus) -> Vault {
+        Vault {
+            address: String::new(),
+            amount: 0,
+            derivation_index: 0,
+            received_at: 0,
+            status,
+            txid: "txid".to_string(),
+            updated_at: 0,
+            vout,
+        }
+    }
+
+    fn listed(fetch: &VaultsFetch) -> Vec<(u32, VaultStatus)> {
+        let mut vaults: Vec<(u32, VaultStatus)> = fetch
+            .vaults()
+            .iter()
+            .map(|v| (v.vout, v.status.clone()))
+            .collect();
+        vaults.sort_by_key(|(vout, _)| *vout);
+        vaults
+    }
+
+    #[test]
+    fn vaults_merged_by_status() {
+        let mut fetch = VaultsFetch::new(&[VaultStatus::Secured, VaultStatus::Active]);
+        assert!(fetch.receive(VaultStatus::Active, vec![vault(0, VaultStatus::Active)]));
+        assert!(!fetch.is_loaded());
+        assert!(fetch.receive(
+            VaultStatus::Secured,
+            vec![
+                vault(1, VaultStatus::Secured),
+                vault(2, VaultStatus::Secured)
+            ]
+        ));
+        assert!(fetch.is_loaded());
+
+        // The vault 1 got active, the active vaults are received first.
+        fetch.receive(
+            VaultStatus::Active,
+            vec![vault(0, VaultStatus::Active), vault(1, VaultStatus::Active)],
+        );
+        assert_eq!(
+            listed(&fetch),
+            vec![
+                (0, VaultStatus::Active),
+                (1, VaultStatus::Active),
+                (2, VaultStatus::Secured)
+            ]
+        );
+        fetch.receive(VaultStatus::Secured, vec![vault(2, VaultStatus::Secured)]);
+        assert_eq!(fetch.vaults().len(), 3);
+
+        // The vaults of a status that is no longer fetched are dropped.
+        fetch.set_statuses(&[VaultStatus::Active]);
+        assert!(!fetch.receive(VaultStatus::Secured, vec![vault(3, VaultStatus::Secured)]));
+        assert_eq!(
+            listed(&fetch),
+            vec![(0, VaultStatus::Active), (1, VaultStatus::Active)]
+        );
+        assert!(fetch.is_loaded());
+    }
+}
@@ -0,0 +1,209 @@
+//! Flow of the funds between the vaults and the spend transactions: the
+//! vaults spent by each spend transaction and the vaults created by its
+//! change, so that an auditor can follow the funds without the txids.
+
+use bitcoin::{Address, Network, Transaction};
+
+use super::spend_review::OutputKind;
+use crate::revaultd::model::{SpendTx, Vault, VaultStatus, VaultTransactions};
+
+/// Zoom levels of the flow graph, in percent.
+const ZOOM_MIN: u16 = 50;
+const ZOOM_MAX: u16 = 200;
+const ZOOM_STEP: u16 = 25;
+/// Distance in units the graph is moved by a pan.
+pub const PAN_STEP: i16 = 100;
+
+#[derive(Debug, Clone)]
+pub struct FlowVault {
+    pub outpoint: String,
+    /// None if the vault is not known by revaultd.
+    pub amount: Option<u64>,
+    pub status: Option<VaultStatus>,
+}
+
+impl FlowVault {
+    fn new(outpoint: &str, vaults: &[Vault]) -> Self {
+        let vault = vaults.iter().find(|v| v.outpoint() == outpoint);
+        Self {
+            outpoint: outpoint.to_string(),
+            amount: vault.map(|v| v.amount),
+            status: vault.map(|v| v.status.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FlowOutput {
+    /// None if the script has no address form.
+    pub address: Option<String>,
+    pub value: u64,
+    pub kind: OutputKind,
+    /// Vault created by the change output, once revaultd saw its deposit.
+    pub vault: Option<FlowVault>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FlowSpend {
+    pub txid: String,
+    pub inputs: Vec<FlowVault>,
+    pub outputs: Vec<FlowOutput>,
+}
+
+/// Returns the spend transactions with the vaults they spend and the
+/// vaults created by their change, the spends funded by the most recent
+/// vaults first.
+pub fn flow(vaults: &[Vault], spend_txs: &[SpendTx], network: Network) -> Vec<FlowSpend> {
+    let spends = spend_txs
+        .iter()
+        .map(|spend| {
+            let tx = &spend.psbt.global.unsigned_tx;
+            FlowSpend {
+                txid: tx.txid().to_string(),
+                inputs: spend
+                    .deposit_outpoints
+                    .iter()
+                    .map(|outpoint| FlowVault::new(outpoint, vaults))
+                    .collect(),
+                outputs: outputs(tx, vaults, network, |i| {
+                    if Some(i) == spend.change_index {
+                        OutputKind::Change
+                    } else if i == spend.cpfp_index {
+                        OutputKind::Cpfp
+                    } else {
+                        OutputKind::Payment
+                    }
+                }),
+            }
+        })
+        .collect();
+    sorted(spends, vaults)
+}
+
+/// Returns the broadcasted spend transactions of the vaults, for the
+/// stakeholders which do not know the spend transactions of the managers.
+/// An output is the change if revaultd knows its vault, the CPFP output is
+/// not told apart from the payments.
+pub fn onchain_flow(
+    vaults: &[Vault],
+    txs: &[VaultTransactions],
+    network: Network,
+) -> Vec<FlowSpend> {
+    let mut spends: Vec<FlowSpend> = Vec::new();
+    for vault_txs in txs {
+        let tx = match &vault_txs.spend {
+            Some(spend) => &spend.tx,
+            None => continue,
+        };
+        let txid = tx.txid().to_string();
+        let input = FlowVault::new(&vault_txs.vault_outpoint, vaults);
+        match spends.iter_mut().find(|spend| spend.txid == txid) {
+            Some(spend) => spend.inputs.push(input),
+            None => spends.push(FlowSpend {
+                txid,
+                inputs: vec![input],
+                outputs: outputs(tx, vaults, network, |_| OutputKind::Payment),
+            }),
+        }
+    }
+    sorted(spends, vaults)
+}
+
+/// Returns the outputs of the spend transaction, an output creating a vault
+/// known by revaultd is the change.
+fn outputs(
+    tx: &Transaction,
+    vaults: &[Vault],
+    network: Network,
+    kind: impl Fn(usize) -> OutputKind,
+) -> Vec<FlowOutput> {
+    let txid = tx.txid();
+    tx.output
+        .iter()
+        .enumerate()
+        .map(|(i, output)| {
+            let outpoint = format!("{}:{}", txid, i);
+            let vault = vaults
+                .iter()
+                .any(|v| v.outpoint() == outpoint)
+                .then(|| FlowVault::new(&outpoint, vaults));
+            FlowOutput {
+                address: Address::from_script(&output.script_pubkey, network)
+                    .map(|a| a.to_string()),
+                value: output.value,
+                kind: if vault.is_some() {
+                    OutputKind::Change
+                } else {
+                    kind(i)
+                },
+                vault,
+            }
+        })
+        .collect()
+}
+
+/// Sorts the spends, the spends funded by the most recent vaults first.
+fn sorted(mut spends: Vec<FlowSpend>, vaults: &[Vault]) -> Vec<FlowSpend> {
+    let received_at = |spend: &FlowSpend| {
+        spend
+            .inputs
+            .iter()
+            .filter_map(|input| vaults.iter().find(|v| v.outpoint() == input.outpoint))
+            .map(|v| v.received_at)
+            .max()
+    };
+    spends.sort_by_key(|spend| std::cmp::Reverse(received_at(spend)));
+    spends
+}
+
+/// Flow is the graph displayed in place of the vaults list,
+/// None spends until revaultd answered.
+#[derive(Debug, Clone)]
+pub struct Flow {
+    pub spends: Option<Vec<FlowSpend>>,
+    /// Vaults of every status, a vault of the graph can be opened
+    /// while it is not in the list.
+    pub vaults: Vec<Vault>,
+    /// Zoom level in percent.
+    pub zoom: u16,
+    /// Offset of the graph from the top left corner.
+    pub pan: (u16, u16),
+}
+
+impl Default for Flow {
+    fn default() -> Self {
+        Self {
+            spends: None,
+            vaults: Vec::new(),
+            zoom: 100,
+            pan: (0, 0),
+        }
+    }
+}
+
+impl Flow {
+    pub fn zoom(&mut self, zoom_in: bool) {
+        self.zoom = if zoom_in {
+            (self.zoom + ZOOM_STEP).min(ZOOM_MAX)
+        } else {
+            self.zoom.saturating_sub(ZOOM_STEP).max(ZOOM_MIN)
+        };
+    }
+
+    pub fn pan(&mut self, x: i16, y: i16) {
+        self.pan = (
+            self.pan.0.saturating_add_signed(x),
+            self.pan.1.saturating_add_signed(y),
+        );
+    }
+
+    pub fn reset(&mut self) {
+        self.zoom = 100;
+        self.pan = (0, 0);
+    }
+
+    /// Returns the size scaled by the zoom level.
+    pub fn scaled(&self, size: u16) -> u16 {
+        (size as u32 * self.zoom as u32 / 100) as u16
+    }
+}
@@ -0,0 +1,84 @@
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDateTime;
+
+use super::{error::Error, labels::Labels};
+use crate::disk;
+use crate::revaultd::model::{BroadcastedTransaction, Vault, VaultTransactions};
+
+/// Prefix of the history files written to the revaultd network datadir.
+const FILE_PREFIX: &str = "revault_gui_history_";
+
+/// Returns the events of the vaults as csv, one line per onchain transaction
+/// sorted by date. The amount and the label of an event are the ones of its vault.
+pub fn history_csv(vaults: &[Vault], txs: &[VaultTransactions], labels: &Labels) -> String {
+    let mut events: Vec<(&str, &VaultTransactions, &BroadcastedTransaction)> = Vec::new();
+    for vault_txs in txs {
+        events.push(("deposit", vault_txs, &vault_txs.deposit));
+        for (event, tx) in [
+            ("unvault", &vault_txs.unvault),
+            ("spend", &vault_txs.spend),
+            ("cancel", &vault_txs.cancel),
+            ("emergency", &vault_txs.emergency),
+            ("unvault_emergency", &vault_txs.unvault_emergency),
+        ] {
+            if let Some(tx) = tx {
+                events.push((event, vault_txs, tx));
+            }
+        }
+    }
+    events.sort_by_key(|(_, _, tx)| tx.received_at);
+
+    let mut csv = "date,event,vault,amount,txid,blockheight,vault_status,label\n".to_string();
+    for (event, vault_txs, tx) in events {
+        let vault = vaults
+            .iter()
+            .find(|v| v.outpoint() == vault_txs.vault_outpoint);
+        let fields = [
+            NaiveDateTime::from_timestamp(tx.received_at, 0).to_string(),
+            event.to_string(),
+            vault_txs.vault_outpoint.clone(),
+            vault.map(|v| v.amount.to_string()).unwrap_or_default(),
+            tx.tx.txid().to_string(),
+            tx.blockheight.map(|h| h.to_string()).unwrap_or_default(),
+            vault.map(|v| v.status.to_string()).unwrap_or_default(),
+            labels
+                .get(&vault_txs.vault_outpoint)
+                .cloned()
+                .unwrap_or_default(),
+        ];
+        let fields: Vec<String> = fields.iter().map(|f| quote(f)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Quotes the field as in RFC 4180, so that a label keeps its commas,
+/// quotes and line breaks.
+fn quote(field: &str) -> String {
+    format!("\"{}\"", field.replace('"', "\"\""))
+}
+
+/// Writes the history to a new file of the directory and returns its path.
+pub fn export(dir: &Path, csv: &str, now: i64) -> Result<PathBuf, Error> {
+    disk::check_dir(dir, csv.len() as u64)?;
+    let path = dir.join(format!("{}{}.csv", FILE_PREFIX, now));
+    std::fs::write(&path, csv).map_err(|e| {
+        Error::UnexpectedError(format!("Failed to write {}: {}", path.display(), e))
+    })?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::quote;
+
+    #[test]
+    fn quote_field() {
+        assert_eq!(quote(""), "\"\"");
+        assert_eq!(quote("cold, storage"), "\"cold, storage\"");
+        assert_eq!(quote("the \"big\" one"), "\"the \"\"big\"\" one\"");
+        assert_eq!(quote("two\nlines"), "\"two\nlines\"");
+    }
+}
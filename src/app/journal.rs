@@ -0,0 +1,143 @@
+//! Journal of the status transitions of the vaults: each list of vaults
+//! given by revaultd is compared to the last recorded status of every vault,
+//! a vault found with another status gets a new entry. The entries and the
+//! onchain transactions of a vault make its timeline.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use super::{error::Error, store};
+use crate::revaultd::{
+    model::{BroadcastedTransaction, Vault, VaultStatus, VaultTransactions},
+    RevaultD,
+};
+
+pub const DEFAULT_FILE_NAME: &str = "revault_gui_journal.json";
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Entry {
+    pub status: VaultStatus,
+    /// Unix timestamp of the last update of the vault by revaultd.
+    pub changed_at: i64,
+    /// Unix timestamp of the list which found the vault with the status.
+    pub observed_at: i64,
+}
+
+/// Journal is the local store of the entries by vault outpoint, kept in a
+/// json file of the revaultd network datadir.
+#[derive(Debug, Clone, Default)]
+pub struct Journal {
+    path: PathBuf,
+    entries: BTreeMap<String, Vec<Entry>>,
+}
+
+impl Journal {
+    pub fn load(path: PathBuf) -> Result<Self, Error> {
+        let entries = store::load(&path)?;
+        Ok(Self { path, entries })
+    }
+
+    /// Loads the journal from the file of the revaultd network datadir.
+    pub fn load_datadir(revaultd: &RevaultD) -> Result<Self, Error> {
+        let mut path = revaultd.config.network_datadir()?;
+        path.push(DEFAULT_FILE_NAME);
+        Self::load(path)
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        store::save(&self.path, &self.entries)
+    }
+
+    pub fn get(&self, outpoint: &str) -> &[Entry] {
+        self.entries
+            .get(outpoint)
+            .map(|entries| entries.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Records the vaults whose status changed since their last entry,
+    /// returns true if an entry was added.
+    pub fn record(&mut self, vaults: &[Vault], now: i64) -> bool {
+        let mut recorded = false;
+        for vault in vaults {
+            let entries = self.entries.entry(vault.outpoint()).or_default();
+            if entries.last().map(|e| &e.status) == Some(&vault.status) {
+                continue;
+            }
+            entries.push(Entry {
+                status: vault.status.clone(),
+                // revaultd may not give the time of the update.
+                changed_at: if vault.updated_at > 0 {
+                    vault.updated_at
+                } else {
+                    now
+                },
+                observed_at: now,
+            });
+            recorded = true;
+        }
+        recorded
+    }
+}
+
+/// Records the statuses of the vaults to the journal of the revaultd
+/// network datadir, the file is written only if a status changed.
+pub fn observe(revaultd: &RevaultD, vaults: &[Vault], now: i64) -> Result<(), Error> {
+    let mut journal = Journal::load_datadir(revaultd)?;
+    if journal.record(vaults, now) {
+        journal.save()?;
+    }
+    Ok(())
+}
+
+/// Event of the timeline of a vault.
+#[derive(Debug, Clone)]
+pub struct Event {
+    pub time: i64,
+    pub title: String,
+    pub detail: String,
+}
+
+/// Returns the events of the vault, the oldest first: its entries in the
+/// journal and its onchain transactions.
+pub fn timeline(entries: &[Entry], txs: &VaultTransactions) -> Vec<Event> {
+    let mut events: Vec<Event> = entries
+        .iter()
+        .map(|entry| Event {
+            time: entry.changed_at,
+            title: entry.status.to_string(),
+            detail: format!(
+                "Observed by the GUI at {}",
+                NaiveDateTime::from_timestamp(entry.observed_at, 0)
+            ),
+        })
+        .collect();
+    events.push(tx_event("Deposit", &txs.deposit));
+    for (name, tx) in [
+        ("Unvault", &txs.unvault),
+        ("Spend", &txs.spend),
+        ("Cancel", &txs.cancel),
+        ("Emergency", &txs.emergency),
+        ("Unvault emergency", &txs.unvault_emergency),
+    ] {
+        if let Some(tx) = tx {
+            events.push(tx_event(name, tx));
+        }
+    }
+    events.sort_by_key(|event| event.time);
+    events
+}
+
+fn tx_event(name: &str, tx: &BroadcastedTransaction) -> Event {
+    Event {
+        time: tx.received_at,
+        title: format!("{} transaction", name),
+        detail: match tx.blockheight {
+            Some(height) => format!("{}, confirmed at block {}", tx.tx.txid(), height),
+            None => format!("{}, unconfirmed", tx.tx.txid()),
+        },
+    }
+}
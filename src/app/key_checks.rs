@@ -0,0 +1,64 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::{error::Error, store};
+
+pub const DEFAULT_FILE_NAME: &str = "revault_gui_key_checks.json";
+
+/// Default number of days between two checks of the emergency signing device.
+pub const DEFAULT_REMINDER_DAYS: u32 = 90;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// KeyCheck records that the stakeholder verified the emergency
+/// signing device with the given fingerprint.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KeyCheck {
+    /// Unix timestamp of the check.
+    pub date: i64,
+    /// Fingerprint of the emergency signing device.
+    pub fingerprint: String,
+}
+
+/// KeyChecks is the local history of the emergency signing device checks.
+#[derive(Debug, Clone, Default)]
+pub struct KeyChecks {
+    path: PathBuf,
+    checks: Vec<KeyCheck>,
+}
+
+impl KeyChecks {
+    pub fn load(path: PathBuf) -> Result<Self, Error> {
+        let checks = store::load(&path)?;
+        Ok(Self { path, checks })
+    }
+
+    pub fn last(&self) -> Option<&KeyCheck> {
+        self.checks.iter().max_by_key(|check| check.date)
+    }
+
+    /// Records a new check and saves the history.
+    pub fn record(&mut self, date: i64, fingerprint: String) -> Result<(), Error> {
+        self.checks.push(KeyCheck { date, fingerprint });
+        store::save(&self.path, &self.checks)
+    }
+
+    /// Returns the timestamp of the next expected check,
+    /// None if the device was never checked.
+    pub fn next_due(&self, reminder_days: u32) -> Option<i64> {
+        self.last()
+            .map(|check| check.date + reminder_days as i64 * SECONDS_PER_DAY)
+    }
+
+    pub fn is_due(&self, reminder_days: u32, now: i64) -> bool {
+        self.next_due(reminder_days)
+            .map(|due| due <= now)
+            .unwrap_or(true)
+    }
+}
+
+/// A device fingerprint is the 4 bytes hex encoded BIP32 fingerprint.
+pub fn is_valid_fingerprint(fingerprint: &str) -> bool {
+    fingerprint.len() == 8 && fingerprint.chars().all(|c| c.is_ascii_hexdigit())
+}
@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use super::{error::Error, store};
+use crate::revaultd::RevaultD;
+
+pub const DEFAULT_FILE_NAME: &str = "revault_gui_labels.json";
+
+/// Labels is the local store of the labels attached to the vaults by the user.
+/// Labels are kept by vault outpoint in a json file of the revaultd network
+/// datadir, they are never shared with the other participants.
+#[derive(Debug, Clone, Default)]
+pub struct Labels {
+    path: PathBuf,
+    labels: BTreeMap<String, String>,
+}
+
+impl Labels {
+    /// Loads the labels from the file, if the file does not exist
+    /// the store is empty and the file is created on the first save.
+    pub fn load(path: PathBuf) -> Result<Self, Error> {
+        let labels = store::load(&path)?;
+        Ok(Self { path, labels })
+    }
+
+    /// Loads the labels from the file of the revaultd network datadir.
+    pub fn load_datadir(revaultd: &RevaultD) -> Result<Self, Error> {
+        let mut path = revaultd.config.network_datadir()?;
+        path.push(DEFAULT_FILE_NAME);
+        Self::load(path)
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        store::save(&self.path, &self.labels)
+    }
+
+    pub fn get(&self, outpoint: &str) -> Option<&String> {
+        self.labels.get(outpoint)
+    }
+
+    pub fn all(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    /// Sets the label of the vault, an empty label removes it.
+    pub fn set(&mut self, outpoint: &str, label: &str) {
+        let label = label.trim();
+        if label.is_empty() {
+            self.labels.remove(outpoint);
+        } else {
+            self.labels.insert(outpoint.to_string(), label.to_string());
+        }
+    }
+
+    /// Assigns the label to every given vault, replacing their previous label.
+    pub fn assign<'a>(&mut self, outpoints: impl IntoIterator<Item = &'a String>, label: &str) {
+        let label = label.trim();
+        if label.is_empty() {
+            return;
+        }
+        for outpoint in outpoints {
+            self.labels.insert(outpoint.clone(), label.to_string());
+        }
+    }
+
+    /// Returns every label with the number of vaults using it.
+    pub fn usage(&self) -> BTreeMap<String, usize> {
+        let mut usage = BTreeMap::new();
+        for label in self.labels.values() {
+            *usage.entry(label.clone()).or_insert(0) += 1;
+        }
+        usage
+    }
+
+    /// Renames the label on every vault using it. If the new name is
+    /// already an existing label, both labels are merged.
+    pub fn rename(&mut self, from: &str, to: &str) {
+        let to = to.trim();
+        if to.is_empty() {
+            return;
+        }
+        for label in self.labels.values_mut() {
+            if label == from {
+                *label = to.to_string();
+            }
+        }
+    }
+
+    /// Merges the label into the other one, see rename.
+    pub fn merge(&mut self, from: &str, into: &str) {
+        self.rename(from, into)
+    }
+
+    /// Removes the label from every vault using it.
+    pub fn delete(&mut self, label: &str) {
+        self.labels.retain(|_, l| l != label);
+    }
+}
@@ -0,0 +1,120 @@
+//! Lines of the logs panel, from the events of the GUI captured in memory
+//! and from the tail of the revaultd log file, and the redacted bundle of
+//! both exported for the bug reports.
+
+use std::path::{Path, PathBuf};
+
+use tracing::Level;
+
+use super::error::Error;
+use crate::{disk, logs};
+
+/// Prefix of the log bundles written to the revaultd network datadir.
+const FILE_PREFIX: &str = "revault_gui_logs_";
+
+/// Bytes of the end of the revaultd log file read for the panel.
+pub const DAEMON_TAIL_BYTES: u64 = 256 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogSource {
+    Gui,
+    Daemon,
+}
+
+impl LogSource {
+    pub const ALL: [LogSource; 2] = [LogSource::Gui, LogSource::Daemon];
+}
+
+impl std::fmt::Display for LogSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Gui => write!(f, "GUI"),
+            Self::Daemon => write!(f, "revaultd"),
+        }
+    }
+}
+
+/// Levels of the filter of the panel, the most severe first.
+pub const LEVELS: [Level; 5] = [
+    Level::ERROR,
+    Level::WARN,
+    Level::INFO,
+    Level::DEBUG,
+    Level::TRACE,
+];
+
+#[derive(Debug, Clone)]
+pub struct Line {
+    /// None for a line of the revaultd logs without level,
+    /// e.g. the continuation of a multiline message.
+    pub level: Option<Level>,
+    pub text: String,
+}
+
+impl Line {
+    /// The lines without level are kept, the search is case insensitive.
+    pub fn matches(&self, level: Level, search: &str) -> bool {
+        self.level.map(|l| l <= level).unwrap_or(true)
+            && (search.is_empty() || self.text.to_lowercase().contains(&search.to_lowercase()))
+    }
+}
+
+pub fn gui_lines() -> Vec<Line> {
+    logs::captured()
+        .into_iter()
+        .map(|entry| Line {
+            level: Some(entry.level),
+            text: entry.to_string(),
+        })
+        .collect()
+}
+
+pub fn daemon_lines(lines: &[String]) -> Vec<Line> {
+    lines
+        .iter()
+        .map(|line| Line {
+            level: logs::daemon_line_level(line),
+            text: line.clone(),
+        })
+        .collect()
+}
+
+/// Returns the bundle of the logs of the GUI and of revaultd with the
+/// keys, addresses and transaction ids redacted.
+pub fn render(gui: &[Line], daemon: Result<&[Line], &str>, now: i64) -> String {
+    let mut bundle = format!(
+        "Revault GUI {} logs, exported at {}\n\
+        Keys, addresses and transaction ids are redacted.\n",
+        env!("CARGO_PKG_VERSION"),
+        chrono::NaiveDateTime::from_timestamp(now, 0).format("%Y-%m-%d %H:%M:%S"),
+    );
+    bundle.push_str("\n== GUI ==\n");
+    for line in gui {
+        bundle.push_str(&logs::redact(&line.text));
+        bundle.push('\n');
+    }
+    bundle.push_str("\n== revaultd ==\n");
+    match daemon {
+        Ok(lines) => {
+            for line in lines {
+                bundle.push_str(&logs::redact(&line.text));
+                bundle.push('\n');
+            }
+        }
+        Err(e) => {
+            bundle.push_str(&logs::redact(e));
+            bundle.push('\n');
+        }
+    }
+    bundle
+}
+
+/// Writes the bundle to a new file of the directory and returns its path.
+pub fn export(dir: &Path, bundle: &str, now: i64) -> Result<PathBuf, Error> {
+    disk::check_dir(dir, bundle.len() as u64)?;
+    let path = dir.join(format!("{}{}.txt", FILE_PREFIX, now));
+    std::fs::write(&path, bundle).map_err(|e| {
+        Error::UnexpectedError(format!("Failed to write {}: {}", path.display(), e))
+    })?;
+    Ok(path)
+}
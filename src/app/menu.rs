@@ -1,8 +1,10 @@
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Menu {
+    Console,
     Deposit,
     Emergency,
     Home,
+    Logs,
     Network,
     Send,
     CreateVaults,
@@ -1,7 +1,30 @@
 use bitcoin::util::psbt::PartiallySignedTransaction as Psbt;
 use std::sync::Arc;
 
-use super::{error::Error, menu::Menu};
+use super::{
+    alerts::{AlertKind, Finding},
+    backup::Backup,
+    confirm::Confirmation,
+    connection::Instance,
+    contacts::ContactField,
+    coordinator::CoordinatorStatus,
+    daemon_config::ConfigField,
+    deadline::CancelDeadline,
+    error::Error,
+    flow::FlowSpend,
+    log_bundle::LogSource,
+    menu::Menu,
+    metadata::Resolution,
+    notifications::NotificationEvent,
+    preferences::{VaultLayout, VaultSort},
+    report::ReportField,
+    sync::SyncProgress,
+};
+use crate::bitcoind::{BitcoindError, FeeEstimate, NodeReport, PruningCheck};
+use crate::i18n::Language;
+use crate::logs::LogLevel;
+use crate::net::PingError;
+use crate::price::{PriceError, Rates};
 use crate::revault::Role;
 use crate::revaultd::{
     model::{
@@ -10,31 +33,242 @@ use crate::revaultd::{
     },
     RevaultD, RevaultDError,
 };
+use crate::ui::theme::ThemePreference;
 
 #[derive(Debug, Clone)]
 pub enum Message {
     Clipboard(String),
     ChangeRole(Role),
-    Syncing(Result<f64, RevaultDError>),
+    Theme(ThemePreference),
+    ScreenshotMode(bool),
+    /// Shows or hides the developer console in the sidebar.
+    DeveloperConsole(bool),
+    Console(ConsoleMessage),
+    /// The window was resized to the width and the height.
+    WindowResized(u32, u32),
+    /// The window was not resized since the wait with the given id,
+    /// see debounce::Debouncer.
+    WindowSizeSettled(u64),
+    Syncing(Result<SyncProgress, RevaultDError>),
     Synced(Arc<RevaultD>),
     DaemonStarted(Result<Arc<RevaultD>, Error>),
     Vaults(Result<Vec<Vault>, RevaultDError>),
+    /// Vaults of a status, the dashboards listing their vaults with one
+    /// call per status.
+    VaultsOfStatus(VaultStatus, Result<Vec<Vault>, RevaultDError>),
+    /// Vaults of the periodic check of the desktop notifications.
+    VaultsWatched(Result<Vec<Vault>, RevaultDError>),
     Vault(String, VaultMessage),
+    /// Removes the triggered note with the given id.
+    DismissNote(u64),
     FilterVaults(VaultFilterMessage),
+    Flow(FlowMessage),
+    /// Displays the next page of the vaults list.
+    ShowMoreVaults,
+    VaultLayout(VaultLayout),
+    ExportHistory,
+    HistoryExported(Result<std::path::PathBuf, Error>),
     BlockHeight(Result<u64, RevaultDError>),
+    CancelDeadlines(Result<Vec<CancelDeadline>, RevaultDError>),
+    Rates(Result<Rates, PriceError>),
+    BitcoindPruning(Result<PruningCheck, BitcoindError>),
+    FeeEstimates(Result<Vec<FeeEstimate>, BitcoindError>),
+    PingServers,
+    /// Time to reach the server with the given host.
+    ServerPinged(String, Result<std::time::Duration, PingError>),
+    CoordinatorStatus(Result<CoordinatorStatus, RevaultDError>),
+    /// Critical events found by the periodic check.
+    Alerts(Result<Vec<Finding>, RevaultDError>),
+    AcknowledgeAlert(AlertKind, String),
+    /// Broadcasts the cancel transaction of the unexpected unvault of the vault.
+    CancelUnvault(String),
+    UnvaultCanceled(String, Result<(), RevaultDError>),
     Connected(Result<Arc<RevaultD>, Error>),
     Menu(Menu),
     Next,
     Previous,
     DepositAddress(Result<bitcoin::Address, RevaultDError>),
+    Deposit(DepositMessage),
     Recipient(usize, RecipientMessage),
     Input(usize, InputMessage),
+    /// Selects the fewest vaults funding the recipients.
+    AutoSelectInputs,
     AddRecipient,
+    /// Merges the recipients of the same address, summing their amounts.
+    MergeRecipients,
+    /// Shuffles the outputs of the generated spend transaction if true.
+    ShuffleOutputs(bool),
     SpendTransaction(Result<SpendTransaction, RevaultDError>),
     SpendTransactions(Result<Vec<SpendTx>, RevaultDError>),
     SpendTx(SpendTxMessage),
-    Emergency,
-    EmergencyBroadcasted(Result<(), RevaultDError>),
+    EmergencyConfirm(EmergencyConfirmMessage),
+    SecureBatch(SecureBatchMessage),
+    Draft(DraftMessage),
+    /// Result of the broadcast of the emergency transaction of the vault.
+    EmergencyBroadcasted(String, Result<bitcoin::Txid, Error>),
+    Label(LabelMessage),
+    Archive(ArchiveMessage),
+    KeyboardModifiers(iced::keyboard::Modifiers),
+    EmergencyKeyCheck(KeyCheckMessage),
+    Backup(BackupMessage),
+    /// Result of the automatic backup of the GUI local data.
+    AutoBackup(Result<Backup, Error>),
+    Metadata(MetadataMessage),
+    Contact(ContactMessage),
+    Report(ReportMessage),
+    /// Enables or disables the desktop notifications of the event.
+    Notification(NotificationEvent, bool),
+    AddressBook(AddressBookMessage),
+    /// Leaves the configuration for the launcher.
+    OpenLauncher,
+    /// A descriptor pasted to check its checksum against the configured ones.
+    DescriptorCheckEdited(String),
+    NoiseKey(NoiseKeyMessage),
+    Logs(LogsMessage),
+    LogPanel(LogPanelMessage),
+    Language(Language),
+    ConfigEdit(ConfigEditMessage),
+    DaemonStopped(Result<(), RevaultDError>),
+    DaemonChecked(Result<Instance, RevaultDError>),
+    Daemon(DaemonMessage),
+    Tour(TourMessage),
+    Confirm(ConfirmMessage),
+    Poll(PollMessage),
+    Shortcut(ShortcutMessage),
+}
+
+#[derive(Debug, Clone)]
+pub enum ShortcutMessage {
+    KeyPressed(iced::keyboard::KeyCode, iced::keyboard::Modifiers),
+    PaletteQueryEdited(String),
+    /// Closes the palette and dispatches the message of the action.
+    Run(Box<Message>),
+    /// Runs the first action matching the query of the palette.
+    RunFirst,
+}
+
+#[derive(Debug, Clone)]
+pub enum PollMessage {
+    /// The panel fetches again the data it displays.
+    Tick,
+    /// The app checks the connection to revaultd and the expiry of the toast.
+    Heartbeat,
+    TogglePause,
+}
+
+#[derive(Debug, Clone)]
+pub enum TourMessage {
+    Next,
+    Previous,
+    Dismiss,
+}
+
+/// ConfigEditMessage drives the edition of the revaultd configuration file.
+#[derive(Debug, Clone)]
+pub enum ConfigEditMessage {
+    Edit,
+    FieldEdited(ConfigField, String),
+    Cancel,
+    /// Writes the configuration file and restarts revaultd.
+    Save,
+    /// Tests the connection to bitcoind with the edited settings, or the
+    /// current ones if they are not edited.
+    TestBitcoind,
+    BitcoindTested(Result<NodeReport, BitcoindError>),
+}
+
+#[derive(Debug, Clone)]
+pub enum LogsMessage {
+    LevelSelected(LogLevel),
+    OpenDir,
+}
+
+/// DaemonMessage drives the lifecycle of revaultd from the GUI.
+#[derive(Debug, Clone)]
+pub enum DaemonMessage {
+    Start,
+    Stop,
+    /// Stops revaultd, the app starts it again once stopped.
+    Restart,
+    Stopped(Result<(), RevaultDError>),
+    Status(Result<Instance, RevaultDError>),
+}
+
+/// ConsoleMessage drives the developer console.
+#[derive(Debug, Clone)]
+pub enum ConsoleMessage {
+    MethodEdited(String),
+    ParamsEdited(String),
+    Send,
+    /// Response of revaultd to the method with the params as typed.
+    Response(String, String, Result<serde_json::Value, RevaultDError>),
+    /// Fills the inputs with the call of the history at the index.
+    Recall(usize),
+    ClearHistory,
+}
+
+/// LogPanelMessage drives the logs panel.
+#[derive(Debug, Clone)]
+pub enum LogPanelMessage {
+    SourceSelected(LogSource),
+    LevelSelected(tracing::Level),
+    SearchEdited(String),
+    /// Last lines of the revaultd log file.
+    DaemonLogs(Result<Vec<String>, Error>),
+    /// Writes the redacted logs to a file of the datadir.
+    Export,
+    Exported(Result<std::path::PathBuf, Error>),
+}
+
+#[derive(Debug, Clone)]
+pub enum NoiseKeyMessage {
+    /// Restrict the key file permissions to its owner.
+    FixPermissions,
+    /// Create a new key file, only if it is missing.
+    Create,
+}
+
+#[derive(Debug, Clone)]
+pub enum BackupMessage {
+    BackupNow,
+    /// Restore the backup with the given date.
+    Restore(i64),
+}
+
+#[derive(Debug, Clone)]
+pub enum ContactMessage {
+    /// Edit the field of the contact of the server with the given host.
+    Edited(String, ContactField, String),
+    Save,
+}
+
+#[derive(Debug, Clone)]
+pub enum ReportMessage {
+    Edited(ReportField, String),
+    Enable(bool),
+    Save,
+    /// Saves the settings and sends a report without waiting for the next one.
+    SendNow,
+    /// Date of the report sent.
+    Sent(Result<i64, Error>),
+}
+
+#[derive(Debug, Clone)]
+pub enum AddressBookMessage {
+    LabelEdited(String),
+    AddressEdited(String),
+    Add,
+    /// Removes the entry with the given address.
+    Remove(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum MetadataMessage {
+    /// Writes the labels and the notes to a file of the datadir.
+    Export,
+    PathEdited(String),
+    ResolutionSelected(Resolution),
+    Import,
 }
 
 #[derive(Debug, Clone)]
@@ -71,16 +305,139 @@ pub enum VaultMessage {
     Signed(Result<(), RevaultDError>),
     Select,
     Delegate,
+    /// Active and activating vaults, counted in the delegation budget.
+    DelegatedVaults(Result<Vec<Vault>, RevaultDError>),
+    /// Delegates the vault beyond the delegation budget.
+    OverrideBudget,
     Secure,
     Retry,
-    SelectRevault,
     Revault,
     Revaulted(Result<(), RevaultDError>),
+    /// Asks revaultd for the current state of the vault waiting for signatures.
+    CheckSignatures,
+    SignaturesChecked(Result<Vec<Vault>, RevaultDError>),
+    Note(NoteMessage),
+    Label(VaultLabelMessage),
+}
+
+#[derive(Debug, Clone)]
+pub enum NoteMessage {
+    TextEdited(String),
+    StatusSelected(VaultStatus),
+    Add,
+    Delete(u64),
+}
+
+/// Edition of the label of a single vault from its panel.
+#[derive(Debug, Clone)]
+pub enum VaultLabelMessage {
+    Edited(String),
+    Save,
+}
+
+#[derive(Debug, Clone)]
+pub enum DepositMessage {
+    /// Displays the address as a BIP21 payment request if true.
+    Bip21(bool),
+    AmountEdited(String),
+    /// Amount of the planned deposit to split in vaults, in BTC.
+    PlannedAmountEdited(String),
+    /// Derives the addresses of the deposits of the split.
+    SplitDeposit,
+}
+
+#[derive(Debug, Clone)]
+pub enum LabelMessage {
+    /// Select or unselect the vault with the given outpoint.
+    SelectVault(String, bool),
+    LabelEdited(String),
+    /// Assign the edited label to the selected vaults.
+    Assign,
+    ToggleManager,
+    RenameEdited(String, String),
+    Rename(String),
+    /// Merge the first label into the second one.
+    Merge(String, String),
+    Delete(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum KeyCheckMessage {
+    FingerprintEdited(String),
+    /// The stakeholder confirms the emergency signing device was verified.
+    Confirm,
 }
 
 #[derive(Debug, Clone)]
 pub enum VaultFilterMessage {
     Status(&'static [VaultStatus]),
+    /// Lists the archived vaults.
+    Archive,
+    Sort(VaultSort),
+    Search(String),
+    /// The user stopped typing the search, see debounce::Debouncer.
+    SearchDebounced(u64),
+}
+
+#[derive(Debug, Clone)]
+pub enum FlowMessage {
+    /// Opens or closes the graph of the funds flow.
+    Toggle,
+    Loaded(Result<(Vec<Vault>, Vec<FlowSpend>), RevaultDError>),
+    /// Zooms in if true, out otherwise.
+    Zoom(bool),
+    Pan(i16, i16),
+    Reset,
+}
+
+#[derive(Debug, Clone)]
+pub enum ArchiveMessage {
+    /// Archives the selected vaults in a final state.
+    Selection,
+    /// Moves the selected vaults back to the list of the moved vaults.
+    Restore,
+}
+
+/// EmergencyConfirmMessage drives the broadcast of the emergency
+/// transactions, once the user confirmed the emergency.
+#[derive(Debug, Clone)]
+pub enum EmergencyConfirmMessage {
+    /// Selects or unselects the vault swept to the deep vault.
+    SelectVault(String, bool),
+    Broadcast,
+    /// Broadcasts again the emergency transactions whose broadcast failed.
+    Retry,
+}
+
+/// DraftMessage acts on a saved spend transaction draft, given by its id.
+#[derive(Debug, Clone)]
+pub enum DraftMessage {
+    Resume(i64),
+    Delete(i64),
+    /// The user stopped typing, the draft is saved if the id is the one of
+    /// the last wait.
+    SaveDebounced(u64),
+}
+
+/// SecureBatchMessage drives the securing of several deposits
+/// in one signing session.
+#[derive(Debug, Clone)]
+pub enum SecureBatchMessage {
+    /// Switches the deposit list to the multi-select mode and back.
+    ToggleSelection,
+    SelectVault(String, bool),
+    Start,
+    /// Revocation transactions of the vaults of the chunk with the index,
+    /// for the fetch with the id.
+    RevocationTransactions(
+        usize,
+        usize,
+        Result<Vec<(String, RevocationTransactions)>, RevaultDError>,
+    ),
+    Sign(SignMessage),
+    Push,
+    Pushed(Vec<(String, Result<(), RevaultDError>)>),
+    Close,
 }
 
 #[derive(Debug, Clone)]
@@ -91,6 +448,12 @@ pub enum SignMessage {
     SharingStatus(SignatureSharingStatus),
     Clipboard(String),
     PsbtEdited(String),
+    /// Path of the PSBT file exchanged with the signing device.
+    PathEdited(String),
+    /// Writes the unsigned PSBT to the file.
+    Export,
+    /// Reads the signed PSBT from the file.
+    Import,
 }
 
 #[derive(Debug, Clone)]
@@ -108,6 +471,17 @@ pub enum InputMessage {
 #[derive(Debug, Clone)]
 pub enum RecipientMessage {
     Delete,
+    MoveUp,
+    MoveDown,
     AddressEdited(String),
     AmountEdited(String),
 }
+
+/// ConfirmMessage drives the confirmation modal.
+#[derive(Debug, Clone)]
+pub enum ConfirmMessage {
+    Ask(Confirmation),
+    PhraseEdited(String),
+    Confirm,
+    Cancel,
+}
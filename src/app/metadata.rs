@@ -0,0 +1,261 @@
+//! Portable file of the operational metadata kept by the GUI, shared
+//! between the members of a team or moved to a new workstation.
+//!
+//! The file is a json object:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "labels": { "<txid>:<vout>": "<label>" },
+//!   "notes": [
+//!     { "outpoint": "<txid>:<vout>", "status": "<vault status>", "text": "<note>" }
+//!   ],
+//!   "address_book": { "<address>": "<label>" }
+//! }
+//! ```
+//!
+//! `labels`, `notes` and `address_book` may be omitted. The status of a note
+//! is one of the vault statuses it can wait for, the date a note was
+//! triggered is local and not exported. The imported addresses of another
+//! network than the one of revaultd are skipped.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::{addressbook::AddressBook, error::Error, labels::Labels, notes::Notes};
+use crate::disk;
+use crate::revaultd::model::VaultStatus;
+
+/// Version of the format, a file of a newer version is refused.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Prefix of the metadata files written to the revaultd network datadir.
+const FILE_PREFIX: &str = "revault_gui_metadata_";
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Metadata {
+    pub version: u32,
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+    #[serde(default)]
+    pub notes: Vec<SharedNote>,
+    /// Labels of the spend destinations, by address.
+    #[serde(default)]
+    pub address_book: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SharedNote {
+    pub outpoint: String,
+    pub status: VaultStatus,
+    pub text: String,
+}
+
+/// Resolution of the conflicts between the local metadata of a vault or of
+/// an address and the imported one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    KeepLocal,
+    TakeImported,
+    Merge,
+}
+
+impl Resolution {
+    pub const ALL: [Resolution; 3] = [
+        Resolution::KeepLocal,
+        Resolution::TakeImported,
+        Resolution::Merge,
+    ];
+}
+
+impl std::fmt::Display for Resolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::KeepLocal => write!(f, "Keep local"),
+            Self::TakeImported => write!(f, "Take imported"),
+            Self::Merge => write!(f, "Merge"),
+        }
+    }
+}
+
+/// Result of an import, for the user.
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    pub labels: usize,
+    pub notes: usize,
+    pub addresses: usize,
+    /// Vaults and addresses with local metadata different from the
+    /// imported one.
+    pub conflicts: usize,
+}
+
+/// Writes the labels, the notes and the address book to a new file of the
+/// directory.
+pub fn export(
+    dir: &Path,
+    labels: &Labels,
+    notes: &Notes,
+    address_book: &AddressBook,
+    now: i64,
+) -> Result<PathBuf, Error> {
+    let metadata = Metadata {
+        version: FORMAT_VERSION,
+        labels: labels.all().clone(),
+        notes: notes
+            .all()
+            .iter()
+            .map(|note| SharedNote {
+                outpoint: note.outpoint.clone(),
+                status: note.status.clone(),
+                text: note.text.clone(),
+            })
+            .collect(),
+        address_book: address_book.all().clone(),
+    };
+    let content = serde_json::to_vec_pretty(&metadata)
+        .map_err(|e| Error::UnexpectedError(format!("Failed to serialize metadata: {}", e)))?;
+    disk::check_dir(dir, content.len() as u64)?;
+    let path = dir.join(format!("{}{}.json", FILE_PREFIX, now));
+    std::fs::write(&path, content).map_err(|e| {
+        Error::UnexpectedError(format!("Failed to write {}: {}", path.display(), e))
+    })?;
+    Ok(path)
+}
+
+pub fn read(path: &Path) -> Result<Metadata, Error> {
+    let content = std::fs::read(path)
+        .map_err(|e| Error::UnexpectedError(format!("Failed to read {}: {}", path.display(), e)))?;
+    let metadata: Metadata = serde_json::from_slice(&content).map_err(|e| {
+        Error::UnexpectedError(format!("Failed to parse {}: {}", path.display(), e))
+    })?;
+    if metadata.version > FORMAT_VERSION {
+        return Err(Error::UnexpectedError(format!(
+            "{} has the version {} of the format, this GUI only reads up to the version {}",
+            path.display(),
+            metadata.version,
+            FORMAT_VERSION
+        )));
+    }
+    Ok(metadata)
+}
+
+/// Adds the imported metadata to the stores, the caller saves them.
+pub fn import(
+    metadata: Metadata,
+    labels: &mut Labels,
+    notes: &mut Notes,
+    address_book: &mut AddressBook,
+    resolution: Resolution,
+) -> ImportReport {
+    let mut report = ImportReport::default();
+    for (outpoint, imported) in metadata.labels {
+        if let Some(label) = resolve(labels.get(&outpoint), &imported, resolution, &mut report) {
+            labels.assign(&[outpoint], &label);
+            report.labels += 1;
+        }
+    }
+
+    let mut imported_notes: BTreeMap<String, Vec<SharedNote>> = BTreeMap::new();
+    for note in metadata.notes {
+        imported_notes
+            .entry(note.outpoint.clone())
+            .or_default()
+            .push(note);
+    }
+    for (outpoint, imported) in imported_notes {
+        let local = notes.get(&outpoint);
+        let new: Vec<&SharedNote> = imported
+            .iter()
+            .filter(|note| {
+                !local
+                    .iter()
+                    .any(|l| l.status == note.status && l.text == note.text.trim())
+            })
+            .collect();
+        if new.is_empty() {
+            continue;
+        }
+        if !local.is_empty() {
+            report.conflicts += 1;
+            match resolution {
+                Resolution::KeepLocal => continue,
+                Resolution::TakeImported => notes.clear(&outpoint),
+                Resolution::Merge => {}
+            }
+        }
+        let added: Vec<&SharedNote> = if resolution == Resolution::TakeImported {
+            imported.iter().collect()
+        } else {
+            new
+        };
+        for note in added {
+            notes.add(outpoint.clone(), note.status.clone(), &note.text);
+            report.notes += 1;
+        }
+    }
+
+    for (address, imported) in metadata.address_book {
+        let local = address_book.label(&address);
+        if let Some(label) = resolve(local, &imported, resolution, &mut report) {
+            match address_book.add(&label, &address) {
+                Ok(()) => report.addresses += 1,
+                Err(e) => tracing::warn!("Skipping the imported address {}: {}", address, e),
+            }
+        }
+    }
+    report
+}
+
+/// Separator of the labels merged in one.
+const MERGE_SEPARATOR: &str = " / ";
+
+/// Returns the label to set from the local and the imported ones, None if
+/// the local label is kept. A label already merged is not merged again,
+/// so that importing the same file twice changes nothing.
+fn resolve(
+    local: Option<&String>,
+    imported: &str,
+    resolution: Resolution,
+    report: &mut ImportReport,
+) -> Option<String> {
+    let imported = imported.trim();
+    let local = match local {
+        None => return Some(imported.to_string()),
+        Some(local) => local,
+    };
+    if local == imported
+        || (resolution == Resolution::Merge
+            && local.split(MERGE_SEPARATOR).any(|part| part == imported))
+    {
+        return None;
+    }
+    report.conflicts += 1;
+    match resolution {
+        Resolution::KeepLocal => None,
+        Resolution::TakeImported => Some(imported.to_string()),
+        Resolution::Merge => Some(format!("{}{}{}", local, MERGE_SEPARATOR, imported)),
+    }
+}
+
+/// Transfer is the form of the settings exporting and importing the metadata.
+#[derive(Debug, Clone)]
+pub struct Transfer {
+    /// Path of the file to import.
+    pub path: String,
+    pub resolution: Resolution,
+    pub exported: Option<PathBuf>,
+    pub imported: Option<ImportReport>,
+}
+
+impl Default for Transfer {
+    fn default() -> Self {
+        Self {
+            path: String::new(),
+            resolution: Resolution::KeepLocal,
+            exported: None,
+            imported: None,
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    error::Error,
+    message::{EmergencyConfirmMessage, Message, SpendTxMessage, VaultMessage},
+    store,
+};
+
+pub const DEFAULT_FILE_NAME: &str = "revault_gui_metrics.json";
+
+/// Metrics are local-only counters of the features used and of the errors
+/// met by the user. They are disabled by default, written to a local file
+/// and never sent anywhere.
+#[derive(Debug, Clone)]
+pub struct Metrics {
+    path: PathBuf,
+    counters: Counters,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Counters {
+    pub usage: BTreeMap<String, u64>,
+    pub errors: BTreeMap<String, u64>,
+}
+
+impl Metrics {
+    pub fn load(path: PathBuf) -> Result<Self, Error> {
+        let counters = store::load(&path)?;
+        Ok(Self { path, counters })
+    }
+
+    pub fn counters(&self) -> &Counters {
+        &self.counters
+    }
+
+    /// Records the feature used or the error carried by the message, if any.
+    pub fn record(&mut self, message: &Message) -> Result<(), Error> {
+        let (name, counters) = if let Some(name) = usage(message) {
+            (name, &mut self.counters.usage)
+        } else if let Some(name) = error(message) {
+            (name, &mut self.counters.errors)
+        } else {
+            return Ok(());
+        };
+        *counters.entry(name.to_string()).or_insert(0) += 1;
+        store::save(&self.path, &self.counters)
+    }
+}
+
+fn usage(message: &Message) -> Option<&'static str> {
+    match message {
+        Message::Menu(_) => Some("navigation"),
+        Message::Clipboard(_) => Some("copy to clipboard"),
+        Message::ChangeRole(_) => Some("change role"),
+        Message::EmergencyConfirm(EmergencyConfirmMessage::Broadcast) => Some("emergency"),
+        Message::Label(_) => Some("labels"),
+        Message::SpendTx(SpendTxMessage::Import) => Some("spend: import"),
+        Message::SpendTx(SpendTxMessage::Generate) => Some("spend: create"),
+        Message::SpendTx(SpendTxMessage::Sign(_)) => Some("spend: sign"),
+        Message::SpendTx(SpendTxMessage::Delete) => Some("spend: delete"),
+        Message::SpendTx(SpendTxMessage::Broadcast) => Some("spend: broadcast"),
+        Message::Vault(_, VaultMessage::Secure) => Some("vault: secure"),
+        Message::Vault(_, VaultMessage::Delegate) => Some("vault: delegate"),
+        Message::Vault(_, VaultMessage::Revault) => Some("vault: revault"),
+        _ => None,
+    }
+}
+
+fn error(message: &Message) -> Option<&'static str> {
+    match message {
+        Message::Vaults(Err(_)) | Message::VaultsOfStatus(_, Err(_)) => Some("listing vaults"),
+        Message::BlockHeight(Err(_)) => Some("fetching block height"),
+        Message::DepositAddress(Err(_)) => Some("getting a deposit address"),
+        Message::SpendTransaction(Err(_)) | Message::SpendTransactions(Err(_)) => {
+            Some("loading spend transactions")
+        }
+        Message::EmergencyBroadcasted(_, Err(_)) => Some("emergency"),
+        Message::SpendTx(SpendTxMessage::Signed(Err(_))) => Some("spend: sign"),
+        Message::SpendTx(SpendTxMessage::Deleted(Err(_))) => Some("spend: delete"),
+        Message::SpendTx(SpendTxMessage::Broadcasted(Err(_))) => Some("spend: broadcast"),
+        Message::SpendTx(SpendTxMessage::Updated(Err(_))) => Some("spend: update"),
+        Message::Vault(_, VaultMessage::Signed(Err(_))) => Some("vault: sign"),
+        Message::Vault(_, VaultMessage::Revaulted(Err(_))) => Some("vault: revault"),
+        _ => None,
+    }
+}
@@ -1,70 +1,259 @@
+mod actions;
+mod addressbook;
+mod alerts;
+mod archive;
+mod attention;
+mod backup;
+mod budget;
+mod coin_control;
 pub mod config;
+mod confirm;
+mod connection;
+mod console;
+mod contacts;
+mod coordinator;
+mod daemon_config;
+mod deadline;
+mod deposit_addresses;
+mod drafts;
 mod error;
+mod fetch;
+mod flow;
+mod history;
+mod journal;
+mod key_checks;
+mod labels;
+mod log_bundle;
 mod menu;
 mod message;
+mod metadata;
+mod metrics;
+mod notes;
+mod notifications;
+mod poll;
+mod preferences;
+mod psbt_file;
+mod report;
+mod selection;
+mod shortcuts;
+mod signatures;
+mod spend_checks;
+mod spend_hints;
+mod spend_outputs;
+mod spend_review;
 mod state;
+mod store;
+mod sync;
+mod tour;
+mod vault_sizing;
 mod view;
 
 use std::sync::Arc;
+use std::time::Duration;
 
-use iced::{Clipboard, Color, Command, Element, Subscription};
+use iced::{Clipboard, Color, Column, Command, Element, Subscription};
 
 pub use config::Config;
+use config::{WindowSize, DEFAULT_SPEND_FINAL_CONFIRMATIONS};
 pub use message::Message;
 
+use alerts::Alerts;
+use backup::Backups;
+use chrono::Utc;
+use confirm::Confirmation;
+use connection::{Connection, Transition};
 use menu::Menu;
-use message::{SignMessage, SpendTxMessage, VaultMessage};
+use message::{
+    ConfirmMessage, DaemonMessage, PollMessage, ReportMessage, SecureBatchMessage, ShortcutMessage,
+    SignMessage, SpendTxMessage, TourMessage, VaultMessage,
+};
+use metrics::Metrics;
+use notifications::{NotificationEvent, NotificationSettings, Watcher};
+use report::Report;
+use shortcuts::Shortcuts;
 use state::{
-    ChargingState, DepositState, EmergencyState, ManagerHomeState, ManagerNetworkState,
-    ManagerSendState, SettingsState, StakeholderCreateVaultsState, StakeholderDelegateFundsState,
-    StakeholderHomeState, StakeholderNetworkState, State, VaultsState,
+    auto_backup, check_alerts, check_daemon, fetch_rates, list_vaults, revault, send_report,
+    stop_daemon, ChargingState, ConsoleState, DepositState, EmergencyState, LogsState,
+    ManagerHomeState, ManagerNetworkState, ManagerSendState, SettingsState,
+    StakeholderCreateVaultsState, StakeholderDelegateFundsState, StakeholderHomeState,
+    StakeholderNetworkState, State, VaultsState,
 };
+use tour::{Tour, TourTarget};
 
-use crate::{app::view::Context, conversion::Converter, revault::Role, revaultd::RevaultD};
+use crate::{
+    app::{
+        error::Error,
+        view::{
+            daemon_lost, degraded_mode, screenshot_banner, toast, training_watermark, AlertsView,
+            ConfirmationView, Context, PaletteView, TourView,
+        },
+    },
+    conversion::Converter,
+    debounce::{self, Debouncer},
+    notify,
+    revault::Role,
+    revaultd::{model::Vault, RevaultD},
+    screenshot,
+    ui::theme,
+};
 
 pub struct App {
     config: Config,
     revaultd: Option<Arc<RevaultD>>,
     state: Box<dyn State>,
     context: Context,
+    /// Some if the user opted in the local metrics.
+    metrics: Option<Metrics>,
+    /// Some if the user set a backup directory.
+    backups: Option<Backups>,
+    /// statuses of the vaults notified on the desktop when they change.
+    watcher: Watcher,
+    /// Unix timestamp of the next check of the report schedule.
+    report_check: i64,
+    connection: Connection,
+    daemon_button: iced::button::State,
+    /// notice displayed above the panel until its expiry timestamp.
+    toast: Option<(String, i64)>,
+    /// guided tour displayed on the first launch for each role.
+    tour: Option<Tour>,
+    tour_view: TourView,
+    /// confirmation asked to the user before dispatching an action.
+    confirmation: Option<Confirmation>,
+    confirmation_view: ConfirmationView,
+    /// critical alerts pinned above the panel until acknowledged.
+    alerts: Alerts,
+    alerts_view: AlertsView,
+    shortcuts: Shortcuts,
+    /// query of the command palette, Some if the palette is open.
+    palette: Option<String>,
+    palette_view: PaletteView,
+    /// size of the window written to the configuration once the user
+    /// stopped resizing it.
+    window_size: Option<WindowSize>,
+    window_debouncer: Debouncer,
 }
 
 impl App {
     #[allow(unreachable_patterns)]
     pub fn load_state(&mut self, role: Role, menu: Menu) -> Command<Message> {
+        // The console is only reachable once enabled in the settings.
+        let menu = if menu == Menu::Console && !self.context.developer_console {
+            Menu::Home
+        } else {
+            menu
+        };
         self.context.role = role;
         self.context.menu = menu;
         let revaultd = self.revaultd.clone().unwrap();
+        let reminder_days = self
+            .config
+            .emergency_check_reminder_days
+            .unwrap_or(key_checks::DEFAULT_REMINDER_DAYS);
+        let archive_days = self
+            .config
+            .archive_after_days
+            .unwrap_or(archive::DEFAULT_DAYS);
+        let debounce_interval = Duration::from_millis(
+            self.config
+                .debounce_ms
+                .unwrap_or(debounce::DEFAULT_INTERVAL_MS),
+        );
         self.state = match self.context.role {
             Role::Manager => match self.context.menu {
-                Menu::Deposit => DepositState::new(revaultd).into(),
+                Menu::Deposit => DepositState::new(revaultd, self.config.vault_size).into(),
                 Menu::Home => ManagerHomeState::new(revaultd).into(),
-                Menu::Vaults => VaultsState::new(revaultd).into(),
-                Menu::Network => ManagerNetworkState::new(revaultd).into(),
-                Menu::Send => ManagerSendState::new(revaultd).into(),
+                Menu::Vaults => VaultsState::new(
+                    revaultd,
+                    archive_days,
+                    debounce_interval,
+                    self.config.max_delegated_amount,
+                )
+                .into(),
+                Menu::Network => {
+                    ManagerNetworkState::new(revaultd, self.config.health_check_proxy).into()
+                }
+                Menu::Logs => LogsState::new(revaultd).into(),
+                Menu::Console => ConsoleState::new(revaultd).into(),
+                Menu::Send => {
+                    ManagerSendState::new(revaultd, self.config.spend_whitelist.clone()).into()
+                }
                 // Manager cannot delegate funds, the user is redirected to the home.
                 Menu::DelegateFunds => ManagerHomeState::new(revaultd).into(),
-                Menu::Settings => SettingsState::new(revaultd.config.clone()).into(),
+                Menu::Settings => SettingsState::new(
+                    revaultd,
+                    self.config.revaultd_config_path.clone(),
+                    self.metrics.clone(),
+                    self.backups.clone(),
+                )
+                .into(),
                 _ => unreachable!(),
             },
             Role::Stakeholder => match self.context.menu {
-                Menu::Deposit => StakeholderHomeState::new(revaultd).into(),
-                Menu::Home => StakeholderHomeState::new(revaultd).into(),
-                Menu::Vaults => VaultsState::new(revaultd).into(),
-                Menu::Network => StakeholderNetworkState::new(revaultd).into(),
+                Menu::Deposit => StakeholderHomeState::new(
+                    revaultd,
+                    reminder_days,
+                    self.config.max_delegated_amount,
+                )
+                .into(),
+                Menu::Home => StakeholderHomeState::new(
+                    revaultd,
+                    reminder_days,
+                    self.config.max_delegated_amount,
+                )
+                .into(),
+                Menu::Vaults => VaultsState::new(
+                    revaultd,
+                    archive_days,
+                    debounce_interval,
+                    self.config.max_delegated_amount,
+                )
+                .into(),
+                Menu::Network => {
+                    StakeholderNetworkState::new(revaultd, self.config.health_check_proxy).into()
+                }
+                Menu::Logs => LogsState::new(revaultd).into(),
+                Menu::Console => ConsoleState::new(revaultd).into(),
                 Menu::CreateVaults => StakeholderCreateVaultsState::new(revaultd).into(),
-                Menu::DelegateFunds => StakeholderDelegateFundsState::new(revaultd).into(),
-                Menu::Settings => SettingsState::new(revaultd.config.clone()).into(),
+                Menu::DelegateFunds => {
+                    StakeholderDelegateFundsState::new(revaultd, self.config.max_delegated_amount)
+                        .into()
+                }
+                Menu::Settings => SettingsState::new(
+                    revaultd,
+                    self.config.revaultd_config_path.clone(),
+                    self.metrics.clone(),
+                    self.backups.clone(),
+                )
+                .into(),
                 Menu::Emergency => EmergencyState::new(revaultd).into(),
                 _ => unreachable!(),
             },
         };
-        self.state.load()
+        Command::batch(vec![self.state.load(), self.refresh_rates()])
+    }
+
+    /// Fetches the rates of the price feed if they are stale,
+    /// the feed is only used on mainnet.
+    fn refresh_rates(&self) -> Command<Message> {
+        match &self.config.price_feed_url {
+            Some(url)
+                if self.config.price_feed == Some(true)
+                    && self.context.network == bitcoin::Network::Bitcoin =>
+            {
+                let now = Utc::now().timestamp();
+                match &self.context.converter.rates {
+                    Some(rates) if !rates.is_stale(now) => Command::none(),
+                    _ => Command::perform(fetch_rates(url.clone()), Message::Rates),
+                }
+            }
+            _ => Command::none(),
+        }
     }
 
     /// After the synchronisation process, the UI displays the home panel to the user
     /// according to the role specified in the revaultd configuration.
     fn on_synced(&mut self, revaultd: Arc<RevaultD>) -> Command<Message> {
+        let poll_paused = self.context.poll_paused;
         let role = if revaultd.config.stakeholder_config.is_some() {
             Role::Stakeholder
         } else {
@@ -75,56 +264,703 @@ impl App {
         let edit_role = revaultd.config.stakeholder_config.is_some()
             && revaultd.config.manager_config.is_some();
 
+        // The language picked in the settings is kept until the GUI is closed.
+        let language = self.context.converter.language;
         self.context = Context::new(
             Converter::new(revaultd.network()),
             revaultd.network(),
             edit_role,
             role,
             Menu::Home,
+            self.context.theme,
         );
+        self.context.theme_preference = self.config.theme.unwrap_or_default();
         self.context.network_up = true;
-        self.revaultd = Some(revaultd);
-        self.load_state(role, Menu::Home)
+        self.context.poll_paused = poll_paused;
+        self.context.developer_console = self.config.developer_console == Some(true);
+        self.context.converter.currency = self.config.fiat_currency.unwrap_or_default();
+        self.context.converter.language = language;
+        self.context.spend_final_confirmations = self
+            .config
+            .spend_final_confirmations
+            .unwrap_or(DEFAULT_SPEND_FINAL_CONFIRMATIONS);
+
+        if let Some(true) = self.config.local_metrics {
+            let metrics = revaultd
+                .config
+                .network_datadir()
+                .map_err(Error::from)
+                .and_then(|mut path| {
+                    path.push(metrics::DEFAULT_FILE_NAME);
+                    Metrics::load(path)
+                });
+            match metrics {
+                Ok(metrics) => self.metrics = Some(metrics),
+                Err(e) => tracing::error!("Failed to load local metrics: {}", e),
+            }
+        }
+
+        if let Some(dir) = &self.config.backup_dir {
+            match revaultd.config.network_datadir() {
+                Ok(datadir) => {
+                    self.backups = Some(Backups::new(
+                        datadir,
+                        dir.clone(),
+                        self.config
+                            .backup_interval_hours
+                            .unwrap_or(backup::DEFAULT_INTERVAL_HOURS),
+                        self.config.backup_keep.unwrap_or(backup::DEFAULT_KEEP),
+                    ))
+                }
+                Err(e) => tracing::error!("Failed to locate the data to backup: {}", e),
+            }
+        }
+
+        let tour = revaultd
+            .config
+            .network_datadir()
+            .map_err(Error::from)
+            .and_then(|mut path| {
+                path.push(tour::DEFAULT_FILE_NAME);
+                Tour::load(path)
+            });
+        match tour {
+            Ok(mut tour) => {
+                tour.start(role);
+                self.tour = Some(tour);
+            }
+            Err(e) => tracing::error!("Failed to load the tour: {}", e),
+        }
+
+        self.connection = Connection::default();
+        self.alerts = Alerts::default();
+        self.alerts.start_check(Utc::now().timestamp());
+        self.revaultd = Some(revaultd.clone());
+        Command::batch(vec![
+            self.load_state(role, Menu::Home),
+            Command::perform(
+                check_alerts(revaultd, self.config.health_check_proxy),
+                Message::Alerts,
+            ),
+        ])
     }
 
-    pub fn new(config: Config) -> (App, Command<Message>) {
-        let state = ChargingState::new(
+    fn charging_state(config: &Config) -> ChargingState {
+        ChargingState::new(
             config.revaultd_config_path.to_owned(),
             config.revaultd_path.to_owned(),
-        );
+            config.call_policy(),
+            config.is_training(),
+        )
+    }
+
+    pub fn new(config: Config) -> (App, Command<Message>) {
+        let state = App::charging_state(&config);
         let cmd = state.load();
+        let theme_preference = config.theme.unwrap_or_default();
+        let mut context = Context {
+            theme_preference,
+            theme: theme_preference.resolve(),
+            ..Context::default()
+        };
+        context.converter.language = config.language.unwrap_or_default();
+        theme::set(context.theme);
         (
             App {
                 config,
                 state: std::boxed::Box::new(state),
                 revaultd: None,
-                context: Context::default(),
+                context,
+                metrics: None,
+                backups: None,
+                watcher: Watcher::default(),
+                report_check: 0,
+                connection: Connection::default(),
+                daemon_button: iced::button::State::new(),
+                toast: None,
+                tour: None,
+                tour_view: TourView::new(),
+                confirmation: None,
+                confirmation_view: ConfirmationView::new(),
+                alerts: Alerts::default(),
+                alerts_view: AlertsView::new(),
+                shortcuts: Shortcuts::default(),
+                palette: None,
+                palette_view: PaletteView::new(),
+                window_size: None,
+                window_debouncer: Debouncer::new(Duration::from_secs(1)),
             },
             cmd,
         )
     }
 
+    pub fn is_training(&self) -> bool {
+        self.config.is_training()
+    }
+
     pub fn subscription(&self) -> Subscription<Message> {
-        self.state.subscription()
+        let mut subscriptions = vec![self.state.subscription()];
+        // The panel is not refreshed while revaultd is unreachable,
+        // its calls would fail until the heartbeat reconnects.
+        if self.revaultd.is_some() && !self.context.poll_paused && !self.connection.is_lost() {
+            subscriptions.push(poll::every(Duration::from_secs(
+                self.config
+                    .refresh_interval_secs
+                    .unwrap_or(poll::DEFAULT_INTERVAL_SECS)
+                    .max(1),
+            )));
+        }
+        if self.revaultd.is_some() && (self.connection.is_lost() || self.toast.is_some()) {
+            subscriptions.push(poll::heartbeat(Duration::from_secs(
+                connection::HEARTBEAT_SECS,
+            )));
+        }
+        if self.confirmation.is_some() {
+            subscriptions.push(iced_native::subscription::events_with(
+                |event, _status| match event {
+                    iced_native::Event::Keyboard(iced::keyboard::Event::KeyPressed {
+                        key_code: iced::keyboard::KeyCode::Escape,
+                        ..
+                    }) => Some(Message::Confirm(ConfirmMessage::Cancel)),
+                    _ => None,
+                },
+            ));
+        } else if self.revaultd.is_some() {
+            // The letters typed in a text input are not shortcuts.
+            subscriptions.push(iced_native::subscription::events_with(
+                |event, status| match event {
+                    iced_native::Event::Keyboard(iced::keyboard::Event::KeyPressed {
+                        key_code,
+                        modifiers,
+                    }) if status == iced_native::event::Status::Ignored
+                        || key_code == iced::keyboard::KeyCode::Escape
+                        || shortcuts::is_palette_toggle(key_code, modifiers) =>
+                    {
+                        Some(Message::Shortcut(ShortcutMessage::KeyPressed(
+                            key_code, modifiers,
+                        )))
+                    }
+                    _ => None,
+                },
+            ));
+        }
+        subscriptions.push(iced_native::subscription::events_with(
+            |event, _status| match event {
+                iced_native::Event::Window(iced_native::window::Event::Resized {
+                    width,
+                    height,
+                }) => Some(Message::WindowResized(width, height)),
+                _ => None,
+            },
+        ));
+        Subscription::batch(subscriptions)
     }
 
     pub fn update(&mut self, message: Message, clipboard: &mut Clipboard) -> Command<Message> {
+        if let Some(metrics) = &mut self.metrics {
+            if let Err(e) = metrics.record(&message) {
+                tracing::error!("Failed to record local metrics: {}", e);
+            }
+        }
+        let command = self.dispatch(message, clipboard);
+        if let Some(backups) = &mut self.backups {
+            let now = Utc::now().timestamp();
+            if backups.is_due(now) {
+                return Command::batch(vec![
+                    Command::perform(auto_backup(backups.clone(), now), Message::AutoBackup),
+                    command,
+                ]);
+            }
+        }
+        command
+    }
+
+    /// Updates the app with the message, the automatic backup aside.
+    fn dispatch(&mut self, message: Message, clipboard: &mut Clipboard) -> Command<Message> {
         match message {
             Message::Synced(revaultd) => self.on_synced(revaultd),
-            Message::ChangeRole(role) => self.load_state(role, self.context.menu.to_owned()),
+            Message::ChangeRole(role) => {
+                if let Some(tour) = &mut self.tour {
+                    tour.start(role);
+                }
+                self.load_state(role, self.context.menu.to_owned())
+            }
+            Message::Theme(preference) => {
+                self.context.theme_preference = preference;
+                self.context.theme = preference.resolve();
+                theme::set(self.context.theme);
+                if let Err(e) = self.config.set_theme(preference) {
+                    tracing::error!("Failed to save the theme: {}", e);
+                }
+                Command::none()
+            }
+            Message::Language(language) => {
+                self.context.converter.language = language;
+                Command::none()
+            }
+            Message::ScreenshotMode(enabled) => {
+                screenshot::set(enabled);
+                Command::none()
+            }
+            Message::DeveloperConsole(enabled) => {
+                self.context.developer_console = enabled;
+                if let Err(e) = self.config.set_developer_console(enabled) {
+                    tracing::error!("Failed to save the developer console setting: {}", e);
+                }
+                Command::none()
+            }
+            Message::WindowResized(width, height) => {
+                let size = WindowSize { width, height };
+                if !size.is_usable() {
+                    // The window is minimized, its size is not saved.
+                    return Command::none();
+                }
+                self.window_size = Some(size);
+                self.window_debouncer.trigger(Message::WindowSizeSettled)
+            }
+            Message::WindowSizeSettled(id) => {
+                if let Some(size) = self.window_size {
+                    if self.window_debouncer.is_due(id) && self.config.window_size != Some(size) {
+                        if let Err(e) = self.config.set_window_size(size) {
+                            tracing::error!("Failed to save the window size: {}", e);
+                        }
+                    }
+                }
+                Command::none()
+            }
+            Message::Tour(msg) => self.on_tour_message(msg),
+            Message::Shortcut(msg) => self.on_shortcut_message(msg, clipboard),
+            Message::Confirm(msg) => match msg {
+                ConfirmMessage::Ask(confirmation) => {
+                    self.confirmation = Some(confirmation);
+                    self.confirmation_view = ConfirmationView::new();
+                    Command::none()
+                }
+                ConfirmMessage::PhraseEdited(phrase) => {
+                    if let Some(confirmation) = &mut self.confirmation {
+                        confirmation.phrase = phrase;
+                    }
+                    Command::none()
+                }
+                ConfirmMessage::Cancel => {
+                    self.confirmation = None;
+                    Command::none()
+                }
+                // The modal is closed before the action is dispatched,
+                // so that a double click dispatches it only once.
+                ConfirmMessage::Confirm => match self.confirmation.take() {
+                    Some(confirmation) if confirmation.can_confirm() => {
+                        self.update(*confirmation.action, clipboard)
+                    }
+                    confirmation => {
+                        self.confirmation = confirmation;
+                        Command::none()
+                    }
+                },
+            },
             Message::Menu(menu) => self.load_state(self.context.role, menu),
+            Message::Poll(PollMessage::TogglePause) => {
+                self.context.poll_paused = !self.context.poll_paused;
+                Command::none()
+            }
+            // revaultd was stopped after its configuration was written,
+            // the charging state starts it again with the new one.
+            Message::Poll(PollMessage::Heartbeat) => {
+                if matches!(self.toast, Some((_, expiry)) if expiry <= Utc::now().timestamp()) {
+                    self.toast = None;
+                }
+                match &self.revaultd {
+                    Some(revaultd) if self.connection.is_lost() => {
+                        Command::perform(check_daemon(revaultd.clone()), Message::DaemonChecked)
+                    }
+                    _ => Command::none(),
+                }
+            }
+            Message::DaemonChecked(res) => self.on_daemon_checked(res),
+            Message::Daemon(DaemonMessage::Start) => {
+                self.revaultd = None;
+                let state = App::charging_state(&self.config);
+                let cmd = state.load();
+                self.state = Box::new(state);
+                cmd
+            }
+            Message::Daemon(DaemonMessage::Stop) => match &self.revaultd {
+                Some(revaultd) => Command::perform(stop_daemon(revaultd.clone()), |res| {
+                    Message::Daemon(DaemonMessage::Stopped(res))
+                }),
+                None => Command::none(),
+            },
+            // The panel displays the restart until revaultd is stopped.
+            Message::Daemon(DaemonMessage::Restart) => match self.revaultd.clone() {
+                Some(revaultd) => Command::batch(vec![
+                    self.state.update(Message::Daemon(DaemonMessage::Restart)),
+                    Command::perform(stop_daemon(revaultd), Message::DaemonStopped),
+                ]),
+                None => Command::none(),
+            },
+            Message::Daemon(DaemonMessage::Stopped(Ok(()))) => {
+                tracing::info!("revaultd was stopped by the user");
+                self.connection.stop(Utc::now().timestamp());
+                self.context.network_up = false;
+                Command::none()
+            }
+            Message::DaemonStopped(Ok(())) => {
+                self.revaultd = None;
+                let state = App::charging_state(&self.config);
+                let cmd = state.load();
+                self.state = Box::new(state);
+                cmd
+            }
             Message::Clipboard(text)
             | Message::SpendTx(SpendTxMessage::Sign(SignMessage::Clipboard(text)))
-            | Message::Vault(_, VaultMessage::Sign(SignMessage::Clipboard(text))) => {
+            | Message::Vault(_, VaultMessage::Sign(SignMessage::Clipboard(text)))
+            | Message::SecureBatch(SecureBatchMessage::Sign(SignMessage::Clipboard(text))) => {
                 clipboard.write(text);
                 Command::none()
             }
-            _ => self.state.update(message),
+            Message::AutoBackup(res) => {
+                if let Err(e) = res {
+                    tracing::error!("Failed to backup the local data: {}", e);
+                }
+                Command::none()
+            }
+            Message::Alerts(res) => {
+                match res {
+                    Ok(findings) => self.alerts.update(findings, Utc::now().timestamp()),
+                    Err(e) => tracing::error!("Failed to check the critical alerts: {}", e),
+                }
+                Command::none()
+            }
+            Message::AcknowledgeAlert(kind, reference) => {
+                if let Some(revaultd) = &self.revaultd {
+                    self.alerts.acknowledge(revaultd, kind, &reference);
+                }
+                Command::none()
+            }
+            Message::CancelUnvault(outpoint) => match &self.revaultd {
+                Some(revaultd) if self.alerts.start_cancel(&outpoint) => {
+                    Command::perform(revault(revaultd.clone(), outpoint.clone()), move |res| {
+                        Message::UnvaultCanceled(outpoint.clone(), res)
+                    })
+                }
+                _ => Command::none(),
+            },
+            Message::UnvaultCanceled(outpoint, res) => {
+                if let Err(e) = &res {
+                    tracing::error!("Failed to cancel the unvault of {}: {}", outpoint, e);
+                }
+                if let Some(revaultd) = &self.revaultd {
+                    self.alerts.canceled(revaultd, &outpoint, res);
+                }
+                Command::none()
+            }
+            Message::VaultsWatched(res) => {
+                match res {
+                    Ok(vaults) => {
+                        self.journal(&vaults);
+                        self.notify(&vaults)
+                    }
+                    Err(e) => tracing::error!("Failed to check the vaults to notify: {}", e),
+                }
+                Command::none()
+            }
+            Message::Rates(res) => {
+                match res {
+                    Ok(rates) => self.context.converter.rates = Some(rates),
+                    // The last rates are kept until the next refresh.
+                    Err(e) => tracing::error!("Failed to fetch the price feed rates: {}", e),
+                }
+                Command::none()
+            }
+            _ => {
+                let mut cmds = Vec::new();
+                match message {
+                    Message::Poll(PollMessage::Tick) => {
+                        if let Some(revaultd) = &self.revaultd {
+                            if self.alerts.start_check(Utc::now().timestamp()) {
+                                cmds.push(Command::perform(
+                                    check_alerts(revaultd.clone(), self.config.health_check_proxy),
+                                    Message::Alerts,
+                                ));
+                            }
+                            cmds.push(Command::perform(
+                                check_daemon(revaultd.clone()),
+                                Message::DaemonChecked,
+                            ));
+                            let now = Utc::now().timestamp();
+                            if self.watcher.start_check(now) {
+                                cmds.push(Command::perform(
+                                    list_vaults(revaultd.clone(), None, None),
+                                    Message::VaultsWatched,
+                                ));
+                            }
+                            if now >= self.report_check {
+                                self.report_check = now + report::CHECK_INTERVAL_SECS;
+                                match Report::load_datadir(revaultd) {
+                                    Ok(report) if report.settings.is_due(now) => {
+                                        cmds.push(Command::perform(
+                                            send_report(revaultd.clone()),
+                                            |res| Message::Report(ReportMessage::Sent(res)),
+                                        ));
+                                    }
+                                    Ok(_) => {}
+                                    Err(e) => {
+                                        tracing::error!("Failed to load the report settings: {}", e)
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Message::BlockHeight(Ok(height)) => self.context.blockheight = height,
+                    // A call of the panel failed to reach the socket, the
+                    // daemon is checked without waiting for the next tick.
+                    Message::BlockHeight(Err(ref e))
+                    | Message::Vaults(Err(ref e))
+                    | Message::VaultsOfStatus(_, Err(ref e))
+                        if connection::is_connection_error(e) =>
+                    {
+                        if let Some(revaultd) = &self.revaultd {
+                            cmds.push(Command::perform(
+                                check_daemon(revaultd.clone()),
+                                Message::DaemonChecked,
+                            ));
+                        }
+                    }
+                    Message::Report(ReportMessage::Sent(Err(ref e))) => {
+                        tracing::error!("Failed to send the wallet report: {}", e)
+                    }
+                    Message::Vaults(Ok(ref vaults))
+                    | Message::VaultsOfStatus(_, Ok(ref vaults)) => {
+                        self.context.last_update = Some(Utc::now().timestamp());
+                        self.journal(vaults);
+                    }
+                    _ => {}
+                }
+                cmds.push(self.state.update(message));
+                if let Some(revaultd) = &self.revaultd {
+                    self.context.coordinator_up = revaultd.coordinator_reachable();
+                }
+                Command::batch(cmds)
+            }
+        }
+    }
+
+    /// Records the status transitions of the vaults to the journal.
+    fn journal(&self, vaults: &[Vault]) {
+        if let Some(revaultd) = &self.revaultd {
+            if let Err(e) = journal::observe(revaultd, vaults, Utc::now().timestamp()) {
+                tracing::error!("Failed to record the vault statuses: {}", e);
+            }
+        }
+    }
+
+    /// Sends a desktop notification for each vault which reached
+    /// a status of an event enabled in the settings.
+    fn notify(&mut self, vaults: &[Vault]) {
+        let events = self.watcher.update(vaults);
+        if events.is_empty() {
+            return;
+        }
+        // An unexpected unvault is surfaced without waiting for the next check.
+        if events
+            .iter()
+            .any(|(event, _)| *event == NotificationEvent::Unvaulting)
+        {
+            self.alerts.check_now();
+        }
+        let settings = match &self.revaultd {
+            Some(revaultd) => NotificationSettings::load_datadir(revaultd).unwrap_or_else(|e| {
+                tracing::error!("Failed to load the notification settings: {}", e);
+                NotificationSettings::default()
+            }),
+            None => return,
+        };
+        for (event, vault) in events {
+            if !settings.is_enabled(event) {
+                continue;
+            }
+            let body = format!(
+                "{} {}, vault {}",
+                self.context.converter.amount_string(vault.amount),
+                self.context.converter.unit,
+                vault.outpoint()
+            );
+            if let Err(e) = notify::desktop(event.description(), &body) {
+                tracing::error!("{}", e);
+            }
+        }
+    }
+
+    /// Reloads the panel once revaultd is back, an upgraded daemon
+    /// is connected again from the charging state to check its API.
+    fn on_daemon_checked(
+        &mut self,
+        res: Result<connection::Instance, crate::revaultd::RevaultDError>,
+    ) -> Command<Message> {
+        let now = Utc::now().timestamp();
+        match self.connection.update(res, now) {
+            Some(Transition::Lost) => {
+                tracing::warn!("The connection to revaultd was lost");
+                self.context.network_up = false;
+                Command::none()
+            }
+            Some(Transition::Upgraded) => {
+                tracing::info!("revaultd was upgraded, connecting again");
+                self.revaultd = None;
+                let state = App::charging_state(&self.config);
+                let cmd = state.load();
+                self.state = Box::new(state);
+                cmd
+            }
+            Some(transition) => {
+                tracing::info!("Reconnected to revaultd ({:?})", transition);
+                self.context.network_up = true;
+                let message = if transition == Transition::Restarted {
+                    "revaultd restarted, reconnected to the daemon"
+                } else {
+                    "Reconnected to revaultd"
+                };
+                self.toast = Some((message.to_string(), now + connection::TOAST_SECS));
+                self.alerts.check_now();
+                self.state.load()
+            }
+            None => Command::none(),
+        }
+    }
+
+    fn on_tour_message(&mut self, msg: TourMessage) -> Command<Message> {
+        let tour = match &mut self.tour {
+            Some(tour) => tour,
+            None => return Command::none(),
+        };
+        let target: Option<TourTarget> = match msg {
+            TourMessage::Next => tour.next().unwrap_or_else(|e| {
+                tracing::error!("Failed to save the tour progress: {}", e);
+                None
+            }),
+            TourMessage::Previous => tour.previous(),
+            TourMessage::Dismiss => {
+                if let Err(e) = tour.dismiss() {
+                    tracing::error!("Failed to save the tour progress: {}", e);
+                }
+                None
+            }
+        };
+        // The target panel is displayed, highlighting its sidebar entry.
+        match target.and_then(|t| t.menu(self.context.role)) {
+            Some(menu) if menu != self.context.menu => self.load_state(self.context.role, menu),
+            _ => Command::none(),
+        }
+    }
+
+    fn on_shortcut_message(
+        &mut self,
+        msg: ShortcutMessage,
+        clipboard: &mut Clipboard,
+    ) -> Command<Message> {
+        match msg {
+            ShortcutMessage::KeyPressed(key, modifiers) => {
+                if shortcuts::is_palette_toggle(key, modifiers) {
+                    if self.palette.take().is_none() {
+                        self.palette = Some(String::new());
+                        self.palette_view = PaletteView::new();
+                    }
+                } else if self.palette.is_some() {
+                    if key == iced::keyboard::KeyCode::Escape {
+                        self.palette = None;
+                    }
+                } else if let Some(menu) =
+                    self.shortcuts
+                        .key_pressed(self.context.role, key, modifiers)
+                {
+                    if menu != self.context.menu {
+                        return self.load_state(self.context.role, menu);
+                    }
+                }
+                Command::none()
+            }
+            ShortcutMessage::PaletteQueryEdited(query) => {
+                if self.palette.is_some() {
+                    self.palette = Some(query);
+                }
+                Command::none()
+            }
+            ShortcutMessage::Run(action) => {
+                self.palette = None;
+                self.update(*action, clipboard)
+            }
+            ShortcutMessage::RunFirst => {
+                let first = self.palette.take().and_then(|query| {
+                    shortcuts::palette_entries(&self.context, &query)
+                        .into_iter()
+                        .next()
+                });
+                match first {
+                    Some(entry) => self.update(entry.message, clipboard),
+                    None => Command::none(),
+                }
+            }
         }
     }
 
     pub fn view(&mut self) -> Element<Message> {
-        let content = self.state.view(&self.context);
+        let mut content = match (&self.confirmation, &self.palette) {
+            (Some(confirmation), _) => self.confirmation_view.view(&self.context, confirmation),
+            (None, Some(query)) => {
+                let entries = shortcuts::palette_entries(&self.context, query);
+                self.palette_view.view(&self.context, query, &entries)
+            }
+            (None, None) => self.state.view(&self.context),
+        };
+        if let Some((index, step)) = self.tour.as_ref().and_then(|tour| tour.step()) {
+            let total = self.tour.as_ref().map(|tour| tour.total()).unwrap_or(0);
+            content = Column::new()
+                .push(self.tour_view.view(&self.context, step, index, total))
+                .push(content)
+                .into();
+        }
+        if let Some(since) = self.connection.lost_since() {
+            content = Column::new()
+                .push(daemon_lost(
+                    &self.context,
+                    &mut self.daemon_button,
+                    since,
+                    self.connection.is_stopped(),
+                ))
+                .push(content)
+                .into();
+        } else if !self.context.coordinator_up {
+            content = Column::new()
+                .push(degraded_mode(&self.context))
+                .push(content)
+                .into();
+        }
+        if let Some((message, _)) = &self.toast {
+            content = Column::new()
+                .push(toast(&self.context, message))
+                .push(content)
+                .into();
+        }
+        let alerts = self.alerts.pending();
+        if !alerts.is_empty() {
+            content = Column::new()
+                .push(self.alerts_view.view(&self.context, &alerts))
+                .push(content)
+                .spacing(10)
+                .into();
+        }
+        if screenshot::enabled() {
+            content = Column::new()
+                .push(screenshot_banner(&self.context))
+                .push(content)
+                .into();
+        }
+        if self.config.is_training() {
+            content = Column::new()
+                .push(training_watermark(&self.context))
+                .push(content)
+                .into();
+        }
         if let Some(true) = self.config.debug {
             return content.explain(Color::BLACK);
         }
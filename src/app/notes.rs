@@ -0,0 +1,283 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::{error::Error, store};
+use crate::revaultd::{
+    model::{Vault, VaultStatus},
+    RevaultD,
+};
+
+pub const DEFAULT_FILE_NAME: &str = "revault_gui_notes.json";
+
+/// Statuses a note can wait for, they are the statuses of the vaults
+/// listed by the home panels.
+pub const TRIGGER_STATUSES: [VaultStatus; 5] = [
+    VaultStatus::Secured,
+    VaultStatus::Active,
+    VaultStatus::Unvaulting,
+    VaultStatus::Unvaulted,
+    VaultStatus::Spending,
+];
+
+/// Returns the step of the status in the life of a vault, the statuses of
+/// the same step are on diverging paths. None for the emergency statuses
+/// which may follow any of the steps.
+fn step(status: &VaultStatus) -> Option<u8> {
+    match status {
+        VaultStatus::Unconfirmed => Some(0),
+        VaultStatus::Funded => Some(1),
+        VaultStatus::Securing => Some(2),
+        VaultStatus::Secured => Some(3),
+        VaultStatus::Activating => Some(4),
+        VaultStatus::Active => Some(5),
+        VaultStatus::Unvaulting => Some(6),
+        VaultStatus::Unvaulted => Some(7),
+        // The cancel and the unvault emergency follow the unvault,
+        // confirmed or not.
+        VaultStatus::Canceling
+        | VaultStatus::Canceled
+        | VaultStatus::UnvaultEmergencyVaulting
+        | VaultStatus::UnvaultEmergencyVaulted => Some(7),
+        VaultStatus::Spending => Some(8),
+        VaultStatus::Spent => Some(9),
+        VaultStatus::EmergencyVaulting | VaultStatus::EmergencyVaulted => None,
+    }
+}
+
+/// Returns true if the vault with the status reached the awaited status,
+/// or went past it.
+fn reached(status: &VaultStatus, awaited: &VaultStatus) -> bool {
+    status == awaited || matches!((step(status), step(awaited)), (Some(s), Some(a)) if s > a)
+}
+
+/// Note is an operational note the user attached to a vault, it is
+/// surfaced once the vault reaches the status.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Note {
+    pub id: u64,
+    pub outpoint: String,
+    pub status: VaultStatus,
+    pub text: String,
+    /// Unix timestamp of the check which found the vault with the status,
+    /// the note is surfaced from then until it is dismissed.
+    pub triggered_at: Option<i64>,
+}
+
+/// Content of the notes file.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+struct NotesFile {
+    /// Id of the next note, kept so that the id of a deleted note is
+    /// never given to another one.
+    next_id: u64,
+    notes: Vec<Note>,
+}
+
+/// The files written before the next id was kept only hold the notes.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum StoredNotes {
+    File(NotesFile),
+    List(Vec<Note>),
+}
+
+impl Default for StoredNotes {
+    fn default() -> Self {
+        Self::File(NotesFile::default())
+    }
+}
+
+/// Notes is the local store of the notes, kept in a json file of the
+/// revaultd network datadir.
+#[derive(Debug, Clone, Default)]
+pub struct Notes {
+    path: PathBuf,
+    next_id: u64,
+    notes: Vec<Note>,
+}
+
+impl Notes {
+    pub fn load(path: PathBuf) -> Result<Self, Error> {
+        let (next_id, notes) = match store::load(&path)? {
+            StoredNotes::File(file) => (file.next_id, file.notes),
+            StoredNotes::List(notes) => (0, notes),
+        };
+        // The next id of a file edited by hand may be behind its notes.
+        let next_id = notes.iter().map(|note| note.id + 1).fold(next_id, u64::max);
+        Ok(Self {
+            path,
+            next_id,
+            notes,
+        })
+    }
+
+    /// Loads the notes from the file of the revaultd network datadir.
+    pub fn load_datadir(revaultd: &RevaultD) -> Result<Self, Error> {
+        let mut path = revaultd.config.network_datadir()?;
+        path.push(DEFAULT_FILE_NAME);
+        Self::load(path)
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        store::save(
+            &self.path,
+            &NotesFile {
+                next_id: self.next_id,
+                notes: self.notes.clone(),
+            },
+        )
+    }
+
+    pub fn all(&self) -> &[Note] {
+        &self.notes
+    }
+
+    pub fn get(&self, outpoint: &str) -> Vec<Note> {
+        self.notes
+            .iter()
+            .filter(|note| note.outpoint == outpoint)
+            .cloned()
+            .collect()
+    }
+
+    /// Adds the note, the ids are never reused even once the note is deleted.
+    pub fn add(&mut self, outpoint: String, status: VaultStatus, text: &str) {
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        let id = self.next_id;
+        self.next_id += 1;
+        self.notes.push(Note {
+            id,
+            outpoint,
+            status,
+            text: text.to_string(),
+            triggered_at: None,
+        });
+    }
+
+    /// Removes the note, once its event happened it is dismissed.
+    pub fn delete(&mut self, id: u64) {
+        self.notes.retain(|note| note.id != id);
+    }
+
+    /// Removes every note of the vault.
+    pub fn clear(&mut self, outpoint: &str) {
+        self.notes.retain(|note| note.outpoint != outpoint);
+    }
+
+    /// Marks the notes of the vaults which reached the awaited status as
+    /// triggered, returns true if one of them was. A vault may go past the
+    /// awaited status between two checks.
+    pub fn check(&mut self, vaults: &[Vault], now: i64) -> bool {
+        let mut changed = false;
+        for note in self.notes.iter_mut().filter(|n| n.triggered_at.is_none()) {
+            if vaults
+                .iter()
+                .any(|v| reached(&v.status, &note.status) && v.outpoint() == note.outpoint)
+            {
+                note.triggered_at = Some(now);
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Returns the triggered notes, the oldest first.
+    pub fn triggered(&self) -> Vec<&Note> {
+        let mut notes: Vec<&Note> = self
+            .notes
+            .iter()
+            .filter(|note| note.triggered_at.is_some())
+            .collect();
+        notes.sort_by_key(|note| note.triggered_at);
+        notes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vault(vout: u32, status: VaultStatus) -> Vault {
+        Vault {
+            address: String::new(),
+            amount: 0,
+            derivation_index: 0,
+            received_at: 0,
+            status,
+            txid: "txid".to_string(),
+            updated_at: 0,
+            vout,
+        }
+    }
+
+    #[test]
+    fn notes_trigger_on_reached_status() {
+        let mut notes = Notes::default();
+        notes.add("txid:0".to_string(), VaultStatus::Active, "active");
+        notes.add("txid:1".to_string(), VaultStatus::Unvaulted, "unvaulted");
+        notes.add("txid:2".to_string(), VaultStatus::Unvaulting, "unvaulting");
+        notes.add("txid:3".to_string(), VaultStatus::Secured, "secured");
+
+        let vaults = [
+            // Went from secured to unvaulting between two checks.
+            vault(0, VaultStatus::Unvaulting),
+            // Was canceled, it will never be unvaulted.
+            vault(1, VaultStatus::Canceled),
+            vault(2, VaultStatus::Canceling),
+            vault(3, VaultStatus::Securing),
+        ];
+        assert!(notes.check(&vaults, 10));
+        let triggered: Vec<&str> = notes.triggered().iter().map(|n| n.text.as_str()).collect();
+        assert_eq!(triggered, vec!["active", "unvaulting"]);
+
+        // The triggered notes keep their time.
+        assert!(!notes.check(&vaults, 20));
+        assert!(notes.triggered().iter().all(|n| n.triggered_at == Some(10)));
+    }
+
+    #[test]
+    fn ids_are_not_reused() {
+        let mut notes = Notes::default();
+        notes.add("txid:0".to_string(), VaultStatus::Active, "first");
+        notes.add("txid:0".to_string(), VaultStatus::Active, "second");
+        notes.delete(1);
+        notes.add("txid:0".to_string(), VaultStatus::Active, "third");
+        let ids: Vec<u64> = notes.all().iter().map(|n| n.id).collect();
+        assert_eq!(ids, vec![0, 2]);
+    }
+
+    #[test]
+    fn notes_files_without_next_id() {
+        let path = std::env::temp_dir().join(format!(
+            "revault_gui_notes_test_{}.json",
+            std::process::id()
+        ));
+        std::fs::write(
+            &path,
+            r#"[{"id": 3, "outpoint": "txid:0", "status": "active", "text": "note", "triggered_at": null}]"#,
+        )
+        .unwrap();
+        let mut notes = Notes::load(path.clone()).unwrap();
+        notes.delete(3);
+        notes.add("txid:0".to_string(), VaultStatus::Active, "next");
+        notes.save().unwrap();
+
+        let notes = Notes::load(path.clone()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(notes.all()[0].id, 4);
+        assert_eq!(notes.next_id, 5);
+    }
+
+    #[test]
+    fn emergency_does_not_trigger() {
+        assert!(!reached(
+            &VaultStatus::EmergencyVaulted,
+            &VaultStatus::Active
+        ));
+        assert!(reached(&VaultStatus::Spent, &VaultStatus::Spending));
+        assert!(!reached(&VaultStatus::Canceled, &VaultStatus::Spending));
+    }
+}
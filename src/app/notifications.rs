@@ -0,0 +1,159 @@
+//! Desktop notifications of the vault state transitions: the vaults listed
+//! by successive checks are compared and a notification is sent for each
+//! vault which reached a watched status since the previous check.
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::{error::Error, store};
+use crate::revaultd::{
+    model::{Vault, VaultStatus},
+    RevaultD,
+};
+
+pub const DEFAULT_FILE_NAME: &str = "revault_gui_notifications.json";
+
+/// Seconds between two checks of the vaults.
+pub const CHECK_INTERVAL_SECS: i64 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationEvent {
+    Unvaulting,
+    Canceling,
+    EmergencyVaulting,
+    Spent,
+}
+
+impl NotificationEvent {
+    pub const ALL: [NotificationEvent; 4] = [
+        NotificationEvent::Unvaulting,
+        NotificationEvent::Canceling,
+        NotificationEvent::EmergencyVaulting,
+        NotificationEvent::Spent,
+    ];
+
+    /// Returns the event of the vault reaching the status, if watched.
+    pub fn of(status: &VaultStatus) -> Option<Self> {
+        match status {
+            VaultStatus::Unvaulting => Some(Self::Unvaulting),
+            VaultStatus::Canceling => Some(Self::Canceling),
+            VaultStatus::EmergencyVaulting | VaultStatus::UnvaultEmergencyVaulting => {
+                Some(Self::EmergencyVaulting)
+            }
+            VaultStatus::Spent => Some(Self::Spent),
+            _ => None,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Unvaulting => "A vault is being unvaulted",
+            Self::Canceling => "A vault unvault is being canceled",
+            Self::EmergencyVaulting => "A vault is being sent to the emergency deep vault",
+            Self::Spent => "A vault was spent",
+        }
+    }
+}
+
+impl std::fmt::Display for NotificationEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Unvaulting => write!(f, "Unvaulting"),
+            Self::Canceling => write!(f, "Canceling"),
+            Self::EmergencyVaulting => write!(f, "Emergency vaulting"),
+            Self::Spent => write!(f, "Spent"),
+        }
+    }
+}
+
+/// The events are notified by default, the store keeps the disabled ones.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+struct Settings {
+    #[serde(default)]
+    disabled: BTreeSet<NotificationEvent>,
+}
+
+/// NotificationSettings is the local store of the events the user
+/// disabled, kept in a json file of the revaultd network datadir.
+#[derive(Debug, Clone, Default)]
+pub struct NotificationSettings {
+    path: PathBuf,
+    settings: Settings,
+}
+
+impl NotificationSettings {
+    pub fn load(path: PathBuf) -> Result<Self, Error> {
+        let settings = store::load(&path)?;
+        Ok(Self { path, settings })
+    }
+
+    /// Loads the settings from the file of the revaultd network datadir.
+    pub fn load_datadir(revaultd: &RevaultD) -> Result<Self, Error> {
+        let mut path = revaultd.config.network_datadir()?;
+        path.push(DEFAULT_FILE_NAME);
+        Self::load(path)
+    }
+
+    pub fn is_enabled(&self, event: NotificationEvent) -> bool {
+        !self.settings.disabled.contains(&event)
+    }
+
+    pub fn set(&mut self, event: NotificationEvent, enabled: bool) -> Result<(), Error> {
+        if enabled {
+            self.settings.disabled.remove(&event);
+        } else {
+            self.settings.disabled.insert(event);
+        }
+        store::save(&self.path, &self.settings)
+    }
+}
+
+/// Watcher keeps the statuses of the vaults of the last check.
+#[derive(Debug, Clone, Default)]
+pub struct Watcher {
+    /// None until the first check, its vaults are not notified.
+    statuses: Option<HashMap<String, VaultStatus>>,
+    last_check: Option<i64>,
+}
+
+impl Watcher {
+    /// Returns true if a check is due and marks it as started.
+    pub fn start_check(&mut self, now: i64) -> bool {
+        let due = self
+            .last_check
+            .map(|last| now - last >= CHECK_INTERVAL_SECS)
+            .unwrap_or(true);
+        if due {
+            self.last_check = Some(now);
+        }
+        due
+    }
+
+    /// Records the vaults of the check and returns the events of the vaults
+    /// which reached a watched status since the previous check. A vault
+    /// unknown by the previous check is notified as well: it may have been
+    /// deposited and unvaulted in between.
+    pub fn update<'a>(&mut self, vaults: &'a [Vault]) -> Vec<(NotificationEvent, &'a Vault)> {
+        let statuses: HashMap<String, VaultStatus> = vaults
+            .iter()
+            .map(|v| (v.outpoint(), v.status.clone()))
+            .collect();
+        let events = match &self.statuses {
+            None => Vec::new(),
+            Some(previous) => vaults
+                .iter()
+                .filter(|v| previous.get(&v.outpoint()) != Some(&v.status))
+                .filter_map(|v| NotificationEvent::of(&v.status).map(|event| (event, v)))
+                .filter(|(event, v)| {
+                    // The emergency of the unvault follows the one of the deposit.
+                    previous.get(&v.outpoint()).and_then(NotificationEvent::of) != Some(*event)
+                })
+                .collect(),
+        };
+        self.statuses = Some(statuses);
+        events
+    }
+}
@@ -0,0 +1,56 @@
+//! Background refresh of the vaults, the block height and the spend
+//! transactions: the ticks are dispatched to the panel, which fetches again
+//! the data it displays. The heartbeat is a faster tick handled by the app
+//! only, e.g. to reconnect to revaultd.
+
+use std::hash::Hash;
+use std::time::Duration;
+
+use iced::Subscription;
+use iced_native::futures::{channel::mpsc, stream::BoxStream, StreamExt};
+
+use crate::app::message::{Message, PollMessage};
+
+/// Default number of seconds between two refreshes.
+pub const DEFAULT_INTERVAL_SECS: u64 = 30;
+
+/// Returns the subscription ticking at every interval.
+pub fn every(interval: Duration) -> Subscription<Message> {
+    Subscription::from_recipe(Every(interval, "tick")).map(|_| Message::Poll(PollMessage::Tick))
+}
+
+/// Returns the subscription of the heartbeat at every interval.
+pub fn heartbeat(interval: Duration) -> Subscription<Message> {
+    Subscription::from_recipe(Every(interval, "heartbeat"))
+        .map(|_| Message::Poll(PollMessage::Heartbeat))
+}
+
+/// The name distinguishes the subscriptions of the same interval.
+struct Every(Duration, &'static str);
+
+/// iced is built without an async runtime providing timers, the ticks are
+/// sent by a thread which stops once the subscription is dropped.
+impl<H, E> iced_native::subscription::Recipe<H, E> for Every
+where
+    H: std::hash::Hasher,
+{
+    type Output = ();
+
+    fn hash(&self, state: &mut H) {
+        std::any::TypeId::of::<Self>().hash(state);
+        self.0.hash(state);
+        self.1.hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: BoxStream<'static, E>) -> BoxStream<'static, Self::Output> {
+        let (sender, receiver) = mpsc::unbounded();
+        let interval = self.0;
+        std::thread::spawn(move || loop {
+            std::thread::sleep(interval);
+            if sender.unbounded_send(()).is_err() {
+                break;
+            }
+        });
+        receiver.boxed()
+    }
+}
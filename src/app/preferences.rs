@@ -0,0 +1,100 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::{error::Error, store};
+use crate::revaultd::model::Vault;
+
+pub const DEFAULT_FILE_NAME: &str = "revault_gui_preferences.json";
+
+/// VaultLayout is the layout of the vaults panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VaultLayout {
+    #[default]
+    List,
+    /// Big cards, suited to a handful of large vaults.
+    Grid,
+}
+
+/// VaultSort is the order of the vaults list, by age of the deposit or by amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VaultSort {
+    #[default]
+    Newest,
+    Oldest,
+    LargestAmount,
+    SmallestAmount,
+}
+
+impl VaultSort {
+    pub const ALL: [VaultSort; 4] = [
+        VaultSort::Newest,
+        VaultSort::Oldest,
+        VaultSort::LargestAmount,
+        VaultSort::SmallestAmount,
+    ];
+
+    pub fn sort(&self, vaults: &mut [Vault]) {
+        match self {
+            Self::Newest => vaults.sort_by_key(|v| std::cmp::Reverse(v.received_at)),
+            Self::Oldest => vaults.sort_by_key(|v| v.received_at),
+            Self::LargestAmount => vaults.sort_by_key(|v| std::cmp::Reverse(v.amount)),
+            Self::SmallestAmount => vaults.sort_by_key(|v| v.amount),
+        }
+    }
+}
+
+impl std::fmt::Display for VaultSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Newest => write!(f, "Newest first"),
+            Self::Oldest => write!(f, "Oldest first"),
+            Self::LargestAmount => write!(f, "Largest first"),
+            Self::SmallestAmount => write!(f, "Smallest first"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+struct Values {
+    #[serde(default)]
+    vault_layout: VaultLayout,
+    #[serde(default)]
+    vault_sort: VaultSort,
+}
+
+/// Preferences is the local store of the interface preferences of the user.
+#[derive(Debug, Clone, Default)]
+pub struct Preferences {
+    path: PathBuf,
+    values: Values,
+}
+
+impl Preferences {
+    pub fn load(path: PathBuf) -> Result<Self, Error> {
+        let values = store::load(&path)?;
+        Ok(Self { path, values })
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        store::save(&self.path, &self.values)
+    }
+
+    pub fn vault_layout(&self) -> VaultLayout {
+        self.values.vault_layout
+    }
+
+    pub fn set_vault_layout(&mut self, layout: VaultLayout) {
+        self.values.vault_layout = layout;
+    }
+
+    pub fn vault_sort(&self) -> VaultSort {
+        self.values.vault_sort
+    }
+
+    pub fn set_vault_sort(&mut self, sort: VaultSort) {
+        self.values.vault_sort = sort;
+    }
+}
@@ -0,0 +1,69 @@
+//! PSBT files exchanged with an air gapped signing device.
+//! The unsigned PSBT is written in the binary format of BIP174, the signed
+//! one is read in the binary format or encoded in base64.
+
+use std::path::{Path, PathBuf};
+
+use bitcoin::{base64, consensus::encode, util::psbt::PartiallySignedTransaction as Psbt};
+
+use super::error::Error;
+use crate::{disk, revault::TransactionKind};
+
+/// Magic bytes starting a binary PSBT.
+const MAGIC: &[u8] = b"psbt\xff";
+
+/// Returns the default path of the file of the PSBT in the home directory.
+pub fn default_path(psbt: &Psbt, kind: &TransactionKind) -> PathBuf {
+    let kind = match kind {
+        TransactionKind::Emergency => "emergency",
+        TransactionKind::EmergencyUnvault => "emergency_unvault",
+        TransactionKind::Unvault => "unvault",
+        TransactionKind::Cancel => "cancel",
+        TransactionKind::Spend => "spend",
+    };
+    let txid = psbt.global.unsigned_tx.txid().to_string();
+    dirs::home_dir()
+        .unwrap_or_default()
+        .join(format!("{}_{}.psbt", kind, &txid[..8]))
+}
+
+/// Writes the PSBT to the file.
+pub fn export(path: &Path, psbt: &Psbt) -> Result<(), Error> {
+    let content = encode::serialize(psbt);
+    if let Some(dir) = path.parent() {
+        disk::check_dir(dir, content.len() as u64)?;
+    }
+    std::fs::write(path, content)
+        .map_err(|e| Error::UnexpectedError(format!("Failed to write {}: {}", path.display(), e)))
+}
+
+/// Reads the PSBT of the file, binary or base64.
+pub fn read(path: &Path) -> Result<Psbt, Error> {
+    let content = std::fs::read(path)
+        .map_err(|e| Error::UnexpectedError(format!("Failed to read {}: {}", path.display(), e)))?;
+    parse(&content).ok_or_else(|| {
+        Error::UnexpectedError(format!("{} is not a valid PSBT file", path.display()))
+    })
+}
+
+/// Parses a PSBT, binary or base64.
+pub fn parse(content: &[u8]) -> Option<Psbt> {
+    if content.starts_with(MAGIC) {
+        return encode::deserialize(content).ok();
+    }
+    let text = std::str::from_utf8(content).ok()?;
+    base64::decode(text.trim())
+        .ok()
+        .and_then(|bytes| encode::deserialize(&bytes).ok())
+}
+
+/// Checks that the signed PSBT is the expected unsigned template.
+pub fn check_template(template: &Psbt, signed: &Psbt) -> Result<(), String> {
+    if signed.global.unsigned_tx.txid() != template.global.unsigned_tx.txid() {
+        return Err("PSBT is not the targeted transaction to sign".to_string());
+    }
+    if signed.inputs.len() != template.inputs.len() {
+        return Err("PSBT does not have the inputs of the transaction to sign".to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,263 @@
+//! Periodic wallet report: the balances by status and the activity since
+//! the last report, mailed through the local SMTP relay configured by the
+//! user so that the team follows the treasury without opening the GUI.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::{error::Error, store};
+use crate::{
+    conversion::Converter,
+    revaultd::{
+        model::{Vault, VaultStatus},
+        RevaultD,
+    },
+    smtp::Mail,
+};
+
+pub const DEFAULT_FILE_NAME: &str = "revault_gui_report.json";
+
+/// Seconds between two checks of the schedule of the report.
+pub const CHECK_INTERVAL_SECS: i64 = 60 * 60;
+
+/// Default number of days between two reports.
+pub const DEFAULT_INTERVAL_DAYS: u32 = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportField {
+    Server,
+    From,
+    To,
+    IntervalDays,
+}
+
+impl ReportField {
+    pub const ALL: [ReportField; 4] = [
+        ReportField::Server,
+        ReportField::From,
+        ReportField::To,
+        ReportField::IntervalDays,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Server => "SMTP server (host:port)",
+            Self::From => "Sender address",
+            Self::To => "Recipients, separated by commas",
+            Self::IntervalDays => "Days between two reports",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ReportSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub server: String,
+    #[serde(default)]
+    pub from: String,
+    #[serde(default)]
+    pub to: String,
+    #[serde(default = "default_interval_days")]
+    pub interval_days: u32,
+    /// Unix timestamp of the last report sent.
+    #[serde(default)]
+    pub last_sent: Option<i64>,
+}
+
+fn default_interval_days() -> u32 {
+    DEFAULT_INTERVAL_DAYS
+}
+
+impl Default for ReportSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            server: String::new(),
+            from: String::new(),
+            to: String::new(),
+            interval_days: DEFAULT_INTERVAL_DAYS,
+            last_sent: None,
+        }
+    }
+}
+
+impl ReportSettings {
+    pub fn get(&self, field: ReportField) -> String {
+        match field {
+            ReportField::Server => self.server.clone(),
+            ReportField::From => self.from.clone(),
+            ReportField::To => self.to.clone(),
+            ReportField::IntervalDays => self.interval_days.to_string(),
+        }
+    }
+
+    /// An interval which is not a number of days is ignored.
+    pub fn set(&mut self, field: ReportField, value: String) {
+        match field {
+            ReportField::Server => self.server = value,
+            ReportField::From => self.from = value,
+            ReportField::To => self.to = value,
+            ReportField::IntervalDays => {
+                if value.is_empty() {
+                    self.interval_days = 0;
+                } else if let Ok(days) = value.trim().parse() {
+                    self.interval_days = days;
+                }
+            }
+        }
+    }
+
+    pub fn recipients(&self) -> Vec<String> {
+        self.to
+            .split(',')
+            .map(|to| to.trim().to_string())
+            .filter(|to| !to.is_empty())
+            .collect()
+    }
+
+    /// Returns the fields missing to send a report.
+    pub fn missing(&self) -> Vec<ReportField> {
+        let mut missing = Vec::new();
+        if self.server.trim().is_empty() {
+            missing.push(ReportField::Server);
+        }
+        if self.from.trim().is_empty() {
+            missing.push(ReportField::From);
+        }
+        if self.recipients().is_empty() {
+            missing.push(ReportField::To);
+        }
+        if self.interval_days == 0 {
+            missing.push(ReportField::IntervalDays);
+        }
+        missing
+    }
+
+    /// The first report is sent as soon as the reports are enabled.
+    pub fn is_due(&self, now: i64) -> bool {
+        self.enabled
+            && self.missing().is_empty()
+            && self
+                .last_sent
+                .map(|last| now - last >= self.interval_days as i64 * 24 * 60 * 60)
+                .unwrap_or(true)
+    }
+}
+
+/// Report is the local store of the report settings, kept in a json
+/// file of the revaultd network datadir.
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    path: PathBuf,
+    pub settings: ReportSettings,
+}
+
+impl Report {
+    pub fn load(path: PathBuf) -> Result<Self, Error> {
+        let settings = store::load(&path)?;
+        Ok(Self { path, settings })
+    }
+
+    /// Loads the report settings from the file of the revaultd network datadir.
+    pub fn load_datadir(revaultd: &RevaultD) -> Result<Self, Error> {
+        let mut path = revaultd.config.network_datadir()?;
+        path.push(DEFAULT_FILE_NAME);
+        Self::load(path)
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        store::save(&self.path, &self.settings)
+    }
+}
+
+/// Returns the mail of the report of the vaults, the activity
+/// is the one of the vaults updated since the given timestamp.
+pub fn render(
+    converter: &Converter,
+    vaults: &[Vault],
+    since: Option<i64>,
+    now: i64,
+    settings: &ReportSettings,
+) -> Mail {
+    let amount = |vaults: &[&Vault]| {
+        format!(
+            "{} {}",
+            converter.amount_string(vaults.iter().map(|v| v.amount).sum()),
+            converter.unit
+        )
+    };
+    let select = |statuses: &[VaultStatus], after: Option<i64>| -> Vec<&Vault> {
+        vaults
+            .iter()
+            .filter(|v| statuses.contains(&v.status))
+            .filter(|v| after.map(|t| v.updated_at > t).unwrap_or(true))
+            .collect()
+    };
+
+    let mut body = format!("Revault wallet report of {}\n", converter.date_string(now));
+    match since {
+        Some(since) => body.push_str(&format!(
+            "Activity since {}\n",
+            converter.date_string(since)
+        )),
+        None => body.push_str("First report, the activity covers every vault\n"),
+    }
+
+    body.push_str("\nBalances\n");
+    for (label, statuses) in [
+        ("Active", &VaultStatus::ACTIVE[..]),
+        ("Inactive", &VaultStatus::INACTIVE[..]),
+        ("Moving", &VaultStatus::MOVING[..]),
+    ] {
+        let selected = select(statuses, None);
+        body.push_str(&format!(
+            "  {:<10} {:>4} vaults  {}\n",
+            label,
+            selected.len(),
+            amount(&selected)
+        ));
+    }
+
+    body.push_str("\nActivity\n");
+    let deposits: Vec<&Vault> = vaults
+        .iter()
+        .filter(|v| since.map(|t| v.received_at > t).unwrap_or(true))
+        .collect();
+    for (label, selected) in [
+        ("Deposited", deposits),
+        ("Spent", select(&[VaultStatus::Spent], since)),
+        ("Canceled", select(&[VaultStatus::Canceled], since)),
+        (
+            "Emergency",
+            select(
+                &[
+                    VaultStatus::EmergencyVaulted,
+                    VaultStatus::UnvaultEmergencyVaulted,
+                ],
+                since,
+            ),
+        ),
+    ] {
+        body.push_str(&format!(
+            "  {:<10} {:>4} vaults  {}\n",
+            label,
+            selected.len(),
+            amount(&selected)
+        ));
+    }
+
+    body.push_str(
+        "\nThis report is read-only and sent by the Revault GUI, \
+        it can be disabled in its settings.\n",
+    );
+
+    Mail {
+        from: settings.from.trim().to_string(),
+        to: settings.recipients(),
+        subject: format!("Revault wallet report {}", converter.date_string(now)),
+        body,
+    }
+}
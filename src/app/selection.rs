@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+/// Selection is the multi-selection model of a list of items identified
+/// by a key. A toggle with the shift key pressed applies the new state
+/// to the whole range between the previous toggled item and this one.
+#[derive(Debug, Clone, Default)]
+pub struct Selection {
+    selected: HashSet<String>,
+    /// Key of the last toggled item, start of the next shift range.
+    anchor: Option<String>,
+}
+
+impl Selection {
+    pub fn contains(&self, key: &str) -> bool {
+        self.selected.contains(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.selected.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+
+    pub fn keys(&self) -> &HashSet<String> {
+        &self.selected
+    }
+
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+
+    /// Selects or unselects the item, `keys` are the keys of the items in
+    /// their displayed order.
+    pub fn toggle(&mut self, keys: &[String], key: String, selected: bool, range: bool) {
+        let range = if range {
+            self.anchor.as_ref().and_then(|anchor| {
+                let start = keys.iter().position(|k| k == anchor)?;
+                let end = keys.iter().position(|k| *k == key)?;
+                Some((start.min(end), start.max(end)))
+            })
+        } else {
+            None
+        };
+
+        let targets: Vec<String> = match range {
+            Some((start, end)) => keys[start..=end].to_vec(),
+            None => vec![key.clone()],
+        };
+        for target in targets {
+            if selected {
+                self.selected.insert(target);
+            } else {
+                self.selected.remove(&target);
+            }
+        }
+        self.anchor = Some(key);
+    }
+
+    /// Removes the keys that are no longer listed.
+    pub fn retain(&mut self, keys: &[String]) {
+        self.selected.retain(|k| keys.contains(k));
+        if let Some(anchor) = &self.anchor {
+            if !keys.contains(anchor) {
+                self.anchor = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Keyboard shortcuts navigating between the panels and command palette
+//! listing the actions available for the role of the user.
+
+use iced::keyboard::{KeyCode, Modifiers};
+
+use super::{
+    menu::Menu,
+    message::{Message, PollMessage},
+    view::Context,
+};
+use crate::{revault::Role, screenshot};
+
+const ALL: &[Role] = &[Role::Manager, Role::Stakeholder];
+
+struct Shortcut {
+    /// Keys typed in sequence, separated by a space.
+    keys: &'static str,
+    label: &'static str,
+    menu: Menu,
+    roles: &'static [Role],
+}
+
+/// The panels reachable from the sidebar of each role.
+const SHORTCUTS: [Shortcut; 10] = [
+    Shortcut {
+        keys: "g h",
+        label: "Go to home",
+        menu: Menu::Home,
+        roles: ALL,
+    },
+    Shortcut {
+        keys: "g v",
+        label: "Go to vaults",
+        menu: Menu::Vaults,
+        roles: ALL,
+    },
+    Shortcut {
+        keys: "g n",
+        label: "Go to network",
+        menu: Menu::Network,
+        roles: ALL,
+    },
+    Shortcut {
+        keys: "g s",
+        label: "Go to settings",
+        menu: Menu::Settings,
+        roles: ALL,
+    },
+    Shortcut {
+        keys: "g o",
+        label: "Go to logs",
+        menu: Menu::Logs,
+        roles: ALL,
+    },
+    Shortcut {
+        keys: "g d",
+        label: "Go to deposit",
+        menu: Menu::Deposit,
+        roles: &[Role::Manager],
+    },
+    Shortcut {
+        keys: "n",
+        label: "New spend",
+        menu: Menu::Send,
+        roles: &[Role::Manager],
+    },
+    Shortcut {
+        keys: "g c",
+        label: "Create vaults",
+        menu: Menu::CreateVaults,
+        roles: &[Role::Stakeholder],
+    },
+    Shortcut {
+        keys: "g l",
+        label: "Delegate funds",
+        menu: Menu::DelegateFunds,
+        roles: &[Role::Stakeholder],
+    },
+    Shortcut {
+        keys: "g e",
+        label: "Emergency",
+        menu: Menu::Emergency,
+        roles: &[Role::Stakeholder],
+    },
+];
+
+/// Returns the key of the shortcut sequences, only the letters
+/// of the shortcuts are mapped.
+fn letter(key: KeyCode) -> Option<char> {
+    match key {
+        KeyCode::C => Some('c'),
+        KeyCode::D => Some('d'),
+        KeyCode::E => Some('e'),
+        KeyCode::G => Some('g'),
+        KeyCode::H => Some('h'),
+        KeyCode::L => Some('l'),
+        KeyCode::N => Some('n'),
+        KeyCode::O => Some('o'),
+        KeyCode::S => Some('s'),
+        KeyCode::V => Some('v'),
+        _ => None,
+    }
+}
+
+/// Returns true if the keys open or close the command palette: Ctrl+K,
+/// or Cmd+K on macOS.
+pub fn is_palette_toggle(key: KeyCode, modifiers: Modifiers) -> bool {
+    key == KeyCode::K && modifiers.is_command_pressed()
+}
+
+/// Shortcuts tracks the prefix of the sequence typed by the user.
+#[derive(Debug, Default)]
+pub struct Shortcuts {
+    pending: Option<char>,
+}
+
+impl Shortcuts {
+    /// Returns the panel of the sequence completed by the key, if any.
+    /// A key which does not continue a sequence resets it.
+    pub fn key_pressed(&mut self, role: Role, key: KeyCode, modifiers: Modifiers) -> Option<Menu> {
+        let pending = self.pending.take();
+        if modifiers.control || modifiers.alt || modifiers.logo || modifiers.shift {
+            return None;
+        }
+        let letter = letter(key)?;
+        let keys = match pending {
+            Some(prefix) => format!("{} {}", prefix, letter),
+            None => letter.to_string(),
+        };
+        let mut prefix = false;
+        for shortcut in SHORTCUTS.iter().filter(|s| s.roles.contains(&role)) {
+            if shortcut.keys == keys {
+                return Some(shortcut.menu.clone());
+            }
+            prefix |= shortcut.keys.starts_with(&format!("{} ", keys));
+        }
+        if prefix {
+            self.pending = Some(letter);
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PaletteEntry {
+    pub label: String,
+    /// Keys of the shortcut of the action, if any.
+    pub keys: Option<&'static str>,
+    pub message: Message,
+}
+
+/// Returns the actions available in the context, matching the query
+/// in the language of the interface.
+pub fn palette_entries(ctx: &Context, query: &str) -> Vec<PaletteEntry> {
+    let mut entries: Vec<PaletteEntry> = SHORTCUTS
+        .iter()
+        .filter(|s| s.roles.contains(&ctx.role))
+        .map(|s| PaletteEntry {
+            label: ctx.tr(s.label).to_string(),
+            keys: Some(s.keys),
+            message: Message::Menu(s.menu.clone()),
+        })
+        .collect();
+    if ctx.role_edit {
+        let role = match ctx.role {
+            Role::Manager => Role::Stakeholder,
+            Role::Stakeholder => Role::Manager,
+        };
+        let name = role.to_string().to_lowercase();
+        entries.push(PaletteEntry {
+            label: ctx.trf("Switch to the {} role", &[&ctx.tr(&name)]),
+            keys: None,
+            message: Message::ChangeRole(role),
+        });
+    }
+    entries.push(PaletteEntry {
+        label: if ctx.poll_paused {
+            ctx.tr("Resume the refresh").to_string()
+        } else {
+            ctx.tr("Pause the refresh").to_string()
+        },
+        keys: None,
+        message: Message::Poll(PollMessage::TogglePause),
+    });
+    entries.push(PaletteEntry {
+        label: if screenshot::enabled() {
+            ctx.tr("Disable the screenshot mode").to_string()
+        } else {
+            ctx.tr("Enable the screenshot mode").to_string()
+        },
+        keys: None,
+        message: Message::ScreenshotMode(!screenshot::enabled()),
+    });
+
+    let query = query.trim().to_lowercase();
+    entries
+        .into_iter()
+        .filter(|entry| entry.label.to_lowercase().contains(&query))
+        .collect()
+}
@@ -0,0 +1,130 @@
+//! Checks of the signatures of the stakeholder in the signed PSBTs imported
+//! from the signing device, before they are shared with revaultd: every input
+//! must be signed by the key of the stakeholder xpub derived at the index of
+//! the vault, with the sighash type of the transaction.
+
+use bitcoin::{
+    secp256k1::{self, Secp256k1},
+    util::{
+        bip143::SigHashCache,
+        bip32::{ChildNumber, ExtendedPubKey},
+        psbt::PartiallySignedTransaction as Psbt,
+    },
+    PublicKey, SigHashType,
+};
+
+use crate::revault::TransactionKind;
+use crate::revaultd::{model::Vault, RevaultD};
+
+/// Signature of the user found in an input of the PSBT.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSignature {
+    Signed,
+    Missing,
+    /// The signature commits to another sighash type than the expected one.
+    WrongSighash {
+        found: u8,
+        expected: SigHashType,
+    },
+    /// The signature does not verify, with the reason.
+    Invalid(String),
+}
+
+impl std::fmt::Display for InputSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Signed => write!(f, "signed by your key"),
+            Self::Missing => write!(f, "not signed by your key"),
+            Self::WrongSighash { found, expected } => write!(
+                f,
+                "signed with the sighash type {:#04x} instead of {}",
+                found, expected
+            ),
+            Self::Invalid(reason) => write!(f, "signed with an invalid signature: {}", reason),
+        }
+    }
+}
+
+/// Returns the sighash type the user signs the transaction with, the
+/// revocation transactions are signed ALL|ANYONECANPAY so that inputs
+/// can be added to bump their feerate.
+pub fn sighash_type(kind: &TransactionKind) -> SigHashType {
+    match kind {
+        TransactionKind::Emergency
+        | TransactionKind::EmergencyUnvault
+        | TransactionKind::Cancel => SigHashType::AllPlusAnyoneCanPay,
+        TransactionKind::Unvault | TransactionKind::Spend => SigHashType::All,
+    }
+}
+
+/// Returns the key of the stakeholder in the descriptors of the vault,
+/// None if the user is not a stakeholder.
+pub fn stakeholder_key(revaultd: &RevaultD, vault: &Vault) -> Option<PublicKey> {
+    let xpub = &revaultd.config.stakeholder_config.as_ref()?.xpub;
+    derive_key(xpub, vault.derivation_index)
+}
+
+fn derive_key(xpub: &ExtendedPubKey, index: u32) -> Option<PublicKey> {
+    let child = ChildNumber::from_normal_idx(index).ok()?;
+    xpub.derive_pub(&Secp256k1::verification_only(), &[child])
+        .ok()
+        .map(|xpub| xpub.public_key)
+}
+
+/// Checks the signature of the key in each input of the PSBT.
+pub fn check(psbt: &Psbt, key: &PublicKey, sighash_type: SigHashType) -> Vec<InputSignature> {
+    let secp = Secp256k1::verification_only();
+    let mut cache = SigHashCache::new(&psbt.global.unsigned_tx);
+    psbt.inputs
+        .iter()
+        .enumerate()
+        .map(|(i, input)| {
+            let sig = match input.partial_sigs.get(key) {
+                Some(sig) => sig,
+                None => return InputSignature::Missing,
+            };
+            let (sighash_byte, der) = match sig.split_last() {
+                Some((byte, der)) => (*byte, der),
+                None => return InputSignature::Invalid("empty signature".to_string()),
+            };
+            if sighash_byte as u32 != sighash_type.as_u32() {
+                return InputSignature::WrongSighash {
+                    found: sighash_byte,
+                    expected: sighash_type,
+                };
+            }
+            let (script, value) = match (&input.witness_script, &input.witness_utxo) {
+                (Some(script), Some(utxo)) => (script, utxo.value),
+                _ => {
+                    return InputSignature::Invalid(
+                        "the input has no witness script or no previous output".to_string(),
+                    )
+                }
+            };
+            let sighash = cache.signature_hash(i, script, value, sighash_type);
+            let res = secp256k1::Message::from_slice(&sighash[..]).and_then(|msg| {
+                let sig = secp256k1::Signature::from_der(der)?;
+                secp.verify(&msg, &sig, &key.key)
+            });
+            match res {
+                Ok(()) => InputSignature::Signed,
+                Err(e) => InputSignature::Invalid(e.to_string()),
+            }
+        })
+        .collect()
+}
+
+/// Returns the error listing the inputs not correctly signed, if any.
+pub fn error(inputs: &[InputSignature]) -> Option<String> {
+    let failures: Vec<String> = inputs
+        .iter()
+        .enumerate()
+        .filter(|(_, input)| **input != InputSignature::Signed)
+        .map(|(i, input)| format!("input {} is {}", i, input))
+        .collect();
+    if failures.is_empty() {
+        None
+    } else {
+        Some(format!("PSBT rejected: {}", failures.join(", ")))
+    }
+}
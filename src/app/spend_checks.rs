@@ -0,0 +1,624 @@
+//! Validation of a spend transaction before the manager signs it.
+//! The checks are run by a pipeline: a policy is added by implementing
+//! `SpendCheck` and pushing it to the pipeline.
+
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use bitcoin::{util::psbt::PartiallySignedTransaction as Psbt, Address};
+use revault_tx::scripts::UnvaultDescriptor;
+
+use crate::{
+    app::{spend_hints::dust_limit, spend_review::spent_vaults},
+    bitcoind::{FeeEstimate, FeeLevel},
+    participant::{self, Participant},
+    revault::Role,
+    revaultd::model::Vault,
+};
+
+/// Maximum part of the spent amount the fees can take before a warning.
+const MAX_FEE_RATIO: f64 = 0.05;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    /// The transaction must not be signed.
+    Fail,
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+/// Spend is the transaction to validate with the choices of the user.
+pub struct Spend<'a> {
+    pub psbt: &'a Psbt,
+    pub feerate: u32,
+    /// Vaults selected by the user.
+    pub inputs: &'a [Vault],
+    /// Addresses entered by the user, the other outputs are the change.
+    pub recipients: &'a [String],
+    pub fee_estimates: &'a [FeeEstimate],
+    /// Keys of the unvault descriptor.
+    pub participants: &'a [Participant],
+}
+
+pub trait SpendCheck {
+    fn name(&self) -> &'static str;
+    fn run(&self, spend: &Spend) -> (CheckStatus, String);
+}
+
+pub struct Pipeline {
+    checks: Vec<Box<dyn SpendCheck>>,
+}
+
+impl Pipeline {
+    /// Returns the pipeline of the checks of the GUI, the whitelist
+    /// is checked if the user configured one.
+    pub fn new(whitelist: Option<Vec<String>>, unvault_descriptor: &str) -> Self {
+        let pipeline = Self { checks: Vec::new() }
+            .with(InputsCheck {
+                unvault: UnvaultDescriptor::from_str(unvault_descriptor).ok(),
+            })
+            .with(DustCheck)
+            .with(FeeCheck);
+        let pipeline = match whitelist {
+            Some(addresses) => pipeline.with(WhitelistCheck { addresses }),
+            None => pipeline,
+        };
+        pipeline.with(SignaturesCheck)
+    }
+
+    pub fn with(mut self, check: impl SpendCheck + 'static) -> Self {
+        self.checks.push(Box::new(check));
+        self
+    }
+
+    pub fn run(&self, spend: &Spend) -> Vec<CheckResult> {
+        self.checks
+            .iter()
+            .map(|check| {
+                let (status, detail) = check.run(spend);
+                CheckResult {
+                    name: check.name(),
+                    status,
+                    detail,
+                }
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.checks.iter().map(|check| check.name()))
+            .finish()
+    }
+}
+
+/// Returns true if one of the checks failed.
+pub fn has_failure(results: &[CheckResult]) -> bool {
+    results.iter().any(|r| r.status == CheckStatus::Fail)
+}
+
+/// The transaction spends the selected vaults and nothing else: the unvault
+/// outputs spent by the inputs are the ones of the selected vaults.
+struct InputsCheck {
+    /// None if the unvault descriptor cannot be parsed.
+    unvault: Option<UnvaultDescriptor>,
+}
+
+impl SpendCheck for InputsCheck {
+    fn name(&self) -> &'static str {
+        "Inputs"
+    }
+
+    fn run(&self, spend: &Spend) -> (CheckStatus, String) {
+        let inputs = spend.psbt.inputs.len();
+        if inputs != spend.inputs.len() {
+            return (
+                CheckStatus::Fail,
+                format!(
+                    "The transaction has {} inputs for {} selected vaults",
+                    inputs,
+                    spend.inputs.len()
+                ),
+            );
+        }
+        if spend.psbt.inputs.iter().any(|i| i.witness_utxo.is_none()) {
+            return (
+                CheckStatus::Warn,
+                "The value of some inputs is unknown and cannot be checked".to_string(),
+            );
+        }
+        let unvault = match &self.unvault {
+            Some(unvault) => unvault,
+            None => {
+                return (
+                    CheckStatus::Warn,
+                    "The unvault descriptor cannot be read, the spent vaults cannot be checked"
+                        .to_string(),
+                )
+            }
+        };
+        let spent = spent_vaults(spend.psbt, spend.inputs, unvault);
+        let unknown = spent.iter().filter(|vault| vault.is_none()).count();
+        if unknown > 0 {
+            return (
+                CheckStatus::Fail,
+                format!("{} inputs do not spend a selected vault", unknown),
+            );
+        }
+        let spent: BTreeSet<String> = spent.into_iter().flatten().collect();
+        let selected: BTreeSet<String> = spend.inputs.iter().map(|v| v.outpoint()).collect();
+        if spent != selected {
+            return (
+                CheckStatus::Fail,
+                format!(
+                    "The transaction spends {} of the {} selected vaults",
+                    spent.len(),
+                    selected.len()
+                ),
+            );
+        }
+        (
+            CheckStatus::Pass,
+            format!("{} vaults spent, all of them selected", inputs),
+        )
+    }
+}
+
+/// Every output is above the dust limit of its script.
+struct DustCheck;
+
+impl SpendCheck for DustCheck {
+    fn name(&self) -> &'static str {
+        "Outputs above dust"
+    }
+
+    fn run(&self, spend: &Spend) -> (CheckStatus, String) {
+        let dust = spend
+            .psbt
+            .global
+            .unsigned_tx
+            .output
+            .iter()
+            .filter(|o| o.value < dust_limit(&o.script_pubkey))
+            .count();
+        if dust > 0 {
+            (
+                CheckStatus::Fail,
+                format!(
+                    "{} outputs are below the dust limit and would not be relayed",
+                    dust
+                ),
+            )
+        } else {
+            (
+                CheckStatus::Pass,
+                "Every output is above the dust limit".to_string(),
+            )
+        }
+    }
+}
+
+/// The feerate is in the range of the estimations of bitcoind and the
+/// fees do not take a large part of the spent amount.
+struct FeeCheck;
+
+impl SpendCheck for FeeCheck {
+    fn name(&self) -> &'static str {
+        "Fee within policy"
+    }
+
+    fn run(&self, spend: &Spend) -> (CheckStatus, String) {
+        let tx = &spend.psbt.global.unsigned_tx;
+        let input_value: Option<u64> = spend
+            .psbt
+            .inputs
+            .iter()
+            .map(|i| i.witness_utxo.as_ref().map(|utxo| utxo.value))
+            .sum();
+        let output_value: u64 = tx.output.iter().map(|o| o.value).sum();
+        if let Some(input_value) = input_value {
+            let fee = input_value.saturating_sub(output_value);
+            if input_value > 0 && fee as f64 > input_value as f64 * MAX_FEE_RATIO {
+                return (
+                    CheckStatus::Warn,
+                    format!(
+                        "The fees of {} sats take more than {}% of the spent amount",
+                        fee,
+                        MAX_FEE_RATIO * 100.0
+                    ),
+                );
+            }
+        }
+        let estimate = |level: FeeLevel| {
+            spend
+                .fee_estimates
+                .iter()
+                .find(|e| e.level == level)
+                .map(|e| e.feerate)
+        };
+        match (estimate(FeeLevel::Low), estimate(FeeLevel::High)) {
+            (Some(low), _) if spend.feerate < low => (
+                CheckStatus::Warn,
+                format!(
+                    "{} sats/vbyte is below the low estimation of {} sats/vbyte, \
+                    the transaction may not confirm",
+                    spend.feerate, low
+                ),
+            ),
+            (_, Some(high)) if spend.feerate > high * 2 => (
+                CheckStatus::Warn,
+                format!(
+                    "{} sats/vbyte is more than twice the high estimation of {} sats/vbyte",
+                    spend.feerate, high
+                ),
+            ),
+            (None, None) => (
+                CheckStatus::Pass,
+                format!(
+                    "{} sats/vbyte, no estimation of bitcoind to compare with",
+                    spend.feerate
+                ),
+            ),
+            _ => (
+                CheckStatus::Pass,
+                format!(
+                    "{} sats/vbyte, in the range of the estimations",
+                    spend.feerate
+                ),
+            ),
+        }
+    }
+}
+
+/// Every recipient is an address of the whitelist.
+struct WhitelistCheck {
+    addresses: Vec<String>,
+}
+
+impl SpendCheck for WhitelistCheck {
+    fn name(&self) -> &'static str {
+        "Whitelist"
+    }
+
+    fn run(&self, spend: &Spend) -> (CheckStatus, String) {
+        let whitelist: Vec<Address> = self
+            .addresses
+            .iter()
+            .filter_map(|a| a.parse::<Address>().ok())
+            .collect();
+        let unlisted: Vec<&String> = spend
+            .recipients
+            .iter()
+            .filter(|recipient| {
+                !recipient
+                    .parse::<Address>()
+                    .map(|address| whitelist.contains(&address))
+                    .unwrap_or(false)
+            })
+            .collect();
+        if unlisted.is_empty() {
+            (
+                CheckStatus::Pass,
+                "Every recipient is whitelisted".to_string(),
+            )
+        } else {
+            (
+                CheckStatus::Fail,
+                format!(
+                    "Not whitelisted: {}",
+                    unlisted
+                        .iter()
+                        .map(|a| a.as_str())
+                        .collect::<Vec<&str>>()
+                        .join(", ")
+                ),
+            )
+        }
+    }
+}
+
+/// Lists the signatures the spend still needs.
+struct SignaturesCheck;
+
+impl SpendCheck for SignaturesCheck {
+    fn name(&self) -> &'static str {
+        "Signatures plan"
+    }
+
+    fn run(&self, spend: &Spend) -> (CheckStatus, String) {
+        let signatures = participant::signatures(spend.psbt, spend.participants);
+        let managers: Vec<bool> = signatures
+            .iter()
+            .filter(|(p, _)| p.role == Some(Role::Manager))
+            .map(|(_, signed)| *signed)
+            .collect();
+        if managers.is_empty() {
+            return (
+                CheckStatus::Warn,
+                "The managers could not be read from the unvault descriptor".to_string(),
+            );
+        }
+        let cosigners = signatures.iter().filter(|(p, _)| p.role.is_none()).count();
+        (
+            CheckStatus::Pass,
+            format!(
+                "{} of the {} managers signed, then {} cosigning servers sign \
+                once the spend is announced",
+                managers.iter().filter(|signed| **signed).count(),
+                managers.len(),
+                cosigners
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bitcoin::{
+        hashes::Hash, util::bip32::Fingerprint, Network, OutPoint, Script, Transaction, TxIn,
+        TxOut, WScriptHash,
+    };
+    use miniscript::{DescriptorPublicKey, DescriptorTrait};
+    use revault_tx::bitcoin::{
+        secp256k1::{PublicKey, Secp256k1, SecretKey},
+        util::bip32::{ChildNumber, ExtendedPrivKey, ExtendedPubKey},
+    };
+
+    use crate::revaultd::model::VaultStatus;
+
+    fn xpub_key(seed: u8) -> DescriptorPublicKey {
+        let secp = Secp256k1::new();
+        let xpriv = ExtendedPrivKey::new_master(revault_tx::bitcoin::Network::Testnet, &[seed; 32])
+            .unwrap();
+        let xpub = ExtendedPubKey::from_private(&secp, &xpriv);
+        DescriptorPublicKey::from_str(&format!("{}/*", xpub)).unwrap()
+    }
+
+    fn single_key(seed: u8) -> DescriptorPublicKey {
+        let secp = Secp256k1::new();
+        let key = PublicKey::from_secret_key(&secp, &SecretKey::from_slice(&[seed; 32]).unwrap());
+        DescriptorPublicKey::from_str(&key.to_string()).unwrap()
+    }
+
+    fn unvault_descriptor() -> UnvaultDescriptor {
+        UnvaultDescriptor::new(
+            vec![xpub_key(1), xpub_key(2)],
+            vec![xpub_key(3)],
+            1,
+            vec![single_key(4), single_key(5)],
+            144,
+        )
+        .unwrap()
+    }
+
+    fn vault(index: u32) -> Vault {
+        Vault {
+            address: String::new(),
+            amount: 100_000,
+            derivation_index: index,
+            received_at: 0,
+            status: VaultStatus::Active,
+            txid: format!("{:064x}", index),
+            updated_at: 0,
+            vout: 0,
+        }
+    }
+
+    /// Script of the unvault output of the vault.
+    fn unvault_script(unvault: &UnvaultDescriptor, vault: &Vault) -> Script {
+        let secp = Secp256k1::verification_only();
+        let child = ChildNumber::from_normal_idx(vault.derivation_index).unwrap();
+        let script = unvault.derive(child, &secp).into_inner().script_pubkey();
+        Script::from(script.into_bytes())
+    }
+
+    fn recipient(seed: u8) -> Script {
+        Script::new_v0_wsh(&WScriptHash::hash(&[seed]))
+    }
+
+    fn psbt(inputs: Vec<(Script, u64)>, outputs: Vec<(Script, u64)>) -> Psbt {
+        let tx = Transaction {
+            version: 2,
+            lock_time: 0,
+            input: (0..inputs.len())
+                .map(|i| TxIn {
+                    previous_output: OutPoint::new(Default::default(), i as u32),
+                    script_sig: Script::new(),
+                    sequence: 0,
+                    witness: Vec::new(),
+                })
+                .collect(),
+            output: outputs
+                .into_iter()
+                .map(|(script_pubkey, value)| TxOut {
+                    value,
+                    script_pubkey,
+                })
+                .collect(),
+        };
+        let mut psbt = Psbt::from_unsigned_tx(tx).unwrap();
+        for (input, (script_pubkey, value)) in psbt.inputs.iter_mut().zip(inputs) {
+            input.witness_utxo = Some(TxOut {
+                value,
+                script_pubkey,
+            });
+        }
+        psbt
+    }
+
+    fn selected_vaults_check() -> InputsCheck {
+        InputsCheck {
+            unvault: Some(unvault_descriptor()),
+        }
+    }
+
+    fn run(check: impl SpendCheck, spend: &Spend) -> CheckStatus {
+        check.run(spend).0
+    }
+
+    fn spend<'a>(psbt: &'a Psbt, inputs: &'a [Vault]) -> Spend<'a> {
+        Spend {
+            psbt,
+            feerate: 10,
+            inputs,
+            recipients: &[],
+            fee_estimates: &[],
+            participants: &[],
+        }
+    }
+
+    #[test]
+    fn inputs_check() {
+        let unvault = unvault_descriptor();
+        let vaults = vec![vault(1), vault(2)];
+        let output = vec![(recipient(1), 150_000)];
+
+        let selected = psbt(
+            vaults
+                .iter()
+                .map(|v| (unvault_script(&unvault, v), 90_000))
+                .collect(),
+            output.clone(),
+        );
+        assert_eq!(
+            run(selected_vaults_check(), &spend(&selected, &vaults)),
+            CheckStatus::Pass
+        );
+
+        // Same number of inputs, but one spends a vault not selected.
+        let other = psbt(
+            vec![
+                (unvault_script(&unvault, &vaults[0]), 90_000),
+                (unvault_script(&unvault, &vault(3)), 90_000),
+            ],
+            output.clone(),
+        );
+        assert_eq!(
+            run(selected_vaults_check(), &spend(&other, &vaults)),
+            CheckStatus::Fail
+        );
+
+        // The same vault spent twice.
+        let twice = psbt(
+            vec![
+                (unvault_script(&unvault, &vaults[0]), 90_000),
+                (unvault_script(&unvault, &vaults[0]), 90_000),
+            ],
+            output.clone(),
+        );
+        assert_eq!(
+            run(selected_vaults_check(), &spend(&twice, &vaults)),
+            CheckStatus::Fail
+        );
+
+        let missing = psbt(vec![(unvault_script(&unvault, &vaults[0]), 90_000)], output);
+        assert_eq!(
+            run(selected_vaults_check(), &spend(&missing, &vaults)),
+            CheckStatus::Fail
+        );
+    }
+
+    #[test]
+    fn dust_check() {
+        let unvault = unvault_descriptor();
+        let vaults = vec![vault(1)];
+        let input = vec![(unvault_script(&unvault, &vaults[0]), 90_000)];
+        let above = psbt(input.clone(), vec![(recipient(1), 330)]);
+        assert_eq!(run(DustCheck, &spend(&above, &vaults)), CheckStatus::Pass);
+        let below = psbt(input, vec![(recipient(1), 80_000), (recipient(2), 329)]);
+        assert_eq!(run(DustCheck, &spend(&below, &vaults)), CheckStatus::Fail);
+    }
+
+    #[test]
+    fn fee_check() {
+        let unvault = unvault_descriptor();
+        let vaults = vec![vault(1)];
+        let input = vec![(unvault_script(&unvault, &vaults[0]), 100_000)];
+        let estimates = [
+            FeeEstimate {
+                level: FeeLevel::Low,
+                feerate: 5,
+                blocks: 144,
+            },
+            FeeEstimate {
+                level: FeeLevel::High,
+                feerate: 20,
+                blocks: 2,
+            },
+        ];
+        let fees_of = |fee: u64, feerate: u32| {
+            let psbt = psbt(input.clone(), vec![(recipient(1), 100_000 - fee)]);
+            let mut spend = spend(&psbt, &vaults);
+            spend.feerate = feerate;
+            spend.fee_estimates = &estimates;
+            run(FeeCheck, &spend)
+        };
+        assert_eq!(fees_of(1_000, 10), CheckStatus::Pass);
+        // More than 5% of the spent amount.
+        assert_eq!(fees_of(6_000, 10), CheckStatus::Warn);
+        assert_eq!(fees_of(1_000, 4), CheckStatus::Warn);
+        assert_eq!(fees_of(1_000, 41), CheckStatus::Warn);
+    }
+
+    #[test]
+    fn whitelist_check() {
+        let unvault = unvault_descriptor();
+        let vaults = vec![vault(1)];
+        let psbt = psbt(
+            vec![(unvault_script(&unvault, &vaults[0]), 100_000)],
+            vec![(recipient(1), 90_000)],
+        );
+        let listed = Address::p2wsh(&Script::from(vec![1]), Network::Bitcoin).to_string();
+        let unlisted = Address::p2wsh(&Script::from(vec![2]), Network::Bitcoin).to_string();
+        let check = || WhitelistCheck {
+            addresses: vec![listed.clone()],
+        };
+
+        let recipients = [listed.clone()];
+        let mut spend = spend(&psbt, &vaults);
+        spend.recipients = &recipients;
+        assert_eq!(run(check(), &spend), CheckStatus::Pass);
+
+        let recipients = [listed.clone(), unlisted];
+        spend.recipients = &recipients;
+        assert_eq!(run(check(), &spend), CheckStatus::Fail);
+    }
+
+    #[test]
+    fn signatures_check() {
+        let unvault = unvault_descriptor();
+        let vaults = vec![vault(1)];
+        let psbt = psbt(
+            vec![(unvault_script(&unvault, &vaults[0]), 100_000)],
+            vec![(recipient(1), 90_000)],
+        );
+        let mut spend = spend(&psbt, &vaults);
+        assert_eq!(run(SignaturesCheck, &spend), CheckStatus::Warn);
+
+        let participants = [
+            Participant {
+                fingerprint: Fingerprint::default(),
+                role: Some(Role::Manager),
+            },
+            Participant {
+                fingerprint: Fingerprint::default(),
+                role: None,
+            },
+        ];
+        spend.participants = &participants;
+        let (status, detail) = SignaturesCheck.run(&spend);
+        assert_eq!(status, CheckStatus::Pass);
+        assert!(detail.contains("of the 1 managers signed, then 1 cosigning"));
+    }
+}
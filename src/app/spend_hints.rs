@@ -0,0 +1,103 @@
+use bitcoin::{
+    util::{address::AddressType, psbt::PartiallySignedTransaction as Psbt},
+    Address, Network, Script,
+};
+
+use crate::revaultd::model;
+
+/// Number of previous spend transactions paying an address
+/// before it is flagged as reused.
+pub const REUSE_THRESHOLD: usize = 2;
+
+/// SpendHint is an advisory hint about a destination of a spend transaction.
+/// Hints are computed from local heuristics only and never block the spend.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpendHint {
+    /// The address uses a legacy format, frequent for exchange deposit addresses.
+    LegacyFormat(String),
+    /// The address was already paid by previous spend transactions.
+    Reused { address: String, count: usize },
+    /// The output value is below the dust limit of its script.
+    Dust { address: String, value: u64 },
+}
+
+impl std::fmt::Display for SpendHint {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::LegacyFormat(address) => write!(
+                f,
+                "{} uses a legacy address format, common for exchange deposit addresses. \
+                Check that the recipient accepts this payment.",
+                address
+            ),
+            Self::Reused { address, count } => write!(
+                f,
+                "{} was already paid by {} previous spend transactions.",
+                address, count
+            ),
+            Self::Dust { address, value } => write!(
+                f,
+                "The output of {} sats to {} is below the dust limit and may not be relayed.",
+                value, address
+            ),
+        }
+    }
+}
+
+/// Returns the hints for the destinations of the spend transaction, the
+/// history is the list of the spend transactions known by revaultd.
+pub fn spend_hints(psbt: &Psbt, network: Network, history: &[model::SpendTx]) -> Vec<SpendHint> {
+    let txid = psbt.global.unsigned_tx.txid();
+    let mut hints = Vec::new();
+    for output in &psbt.global.unsigned_tx.output {
+        let address = match Address::from_script(&output.script_pubkey, network) {
+            Some(address) => address,
+            None => continue,
+        };
+
+        if matches!(
+            address.address_type(),
+            Some(AddressType::P2pkh) | Some(AddressType::P2sh)
+        ) {
+            hints.push(SpendHint::LegacyFormat(address.to_string()));
+        }
+
+        let count = history
+            .iter()
+            .filter(|tx| tx.psbt.global.unsigned_tx.txid() != txid)
+            .filter(|tx| {
+                tx.psbt
+                    .global
+                    .unsigned_tx
+                    .output
+                    .iter()
+                    .any(|o| o.script_pubkey == output.script_pubkey)
+            })
+            .count();
+        if count >= REUSE_THRESHOLD {
+            hints.push(SpendHint::Reused {
+                address: address.to_string(),
+                count,
+            });
+        }
+
+        if output.value < dust_limit(&output.script_pubkey) {
+            hints.push(SpendHint::Dust {
+                address: address.to_string(),
+                value: output.value,
+            });
+        }
+    }
+    hints
+}
+
+/// Dust limit of bitcoin core default relay policy for the script.
+pub fn dust_limit(script: &Script) -> u64 {
+    if script.is_v0_p2wpkh() {
+        294
+    } else if script.is_v0_p2wsh() {
+        330
+    } else {
+        546
+    }
+}
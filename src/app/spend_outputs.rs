@@ -0,0 +1,70 @@
+//! Order of the outputs of the spend transaction: revaultd receives the
+//! destinations as a map and orders the outputs itself, the outputs of the
+//! generated PSBT are then put back in the order of the recipients, or
+//! shuffled for privacy.
+
+use bitcoin::{util::psbt::PartiallySignedTransaction as Psbt, Script};
+
+/// Returns the addresses of several recipients, in their order.
+/// revaultd would only keep one of their amounts.
+pub fn duplicates<'a>(addresses: impl IntoIterator<Item = &'a str>) -> Vec<&'a str> {
+    let addresses: Vec<&str> = addresses.into_iter().collect();
+    let mut duplicates: Vec<&str> = Vec::new();
+    for (i, address) in addresses.iter().enumerate() {
+        if addresses[..i].contains(address) && !duplicates.contains(address) {
+            duplicates.push(address);
+        }
+    }
+    duplicates
+}
+
+/// Orders the outputs of the psbt: the destinations in the given order,
+/// then the change and the CPFP outputs, or all of them randomly.
+/// Returns false if the random order could not be drawn, the psbt is
+/// then left in the order of the destinations.
+pub fn order(psbt: &mut Psbt, destinations: &[Script], shuffle: bool) -> bool {
+    let outputs = &psbt.global.unsigned_tx.output;
+    let mut indexes: Vec<usize> = Vec::with_capacity(outputs.len());
+    for script in destinations {
+        if let Some(i) = outputs
+            .iter()
+            .enumerate()
+            .position(|(i, output)| output.script_pubkey == *script && !indexes.contains(&i))
+        {
+            indexes.push(i);
+        }
+    }
+    let others: Vec<usize> = (0..outputs.len())
+        .filter(|i| !indexes.contains(i))
+        .collect();
+    indexes.extend(others);
+    let shuffled = !shuffle || self::shuffle(&mut indexes);
+
+    let txouts = indexes
+        .iter()
+        .map(|i| psbt.global.unsigned_tx.output[*i].clone())
+        .collect();
+    let psbt_outputs = indexes.iter().map(|i| psbt.outputs[*i].clone()).collect();
+    psbt.global.unsigned_tx.output = txouts;
+    psbt.outputs = psbt_outputs;
+    shuffled
+}
+
+/// Fisher-Yates shuffle with the randomness of the operating system.
+fn shuffle(indexes: &mut [usize]) -> bool {
+    let mut random = vec![0u8; indexes.len() * 4];
+    if getrandom::getrandom(&mut random).is_err() {
+        return false;
+    }
+    for i in (1..indexes.len()).rev() {
+        let bytes = [
+            random[i * 4],
+            random[i * 4 + 1],
+            random[i * 4 + 2],
+            random[i * 4 + 3],
+        ];
+        let j = u32::from_le_bytes(bytes) as usize % (i + 1);
+        indexes.swap(i, j);
+    }
+    true
+}
@@ -0,0 +1,169 @@
+//! Breakdown of a spend transaction reviewed by the managers before it is
+//! broadcast: the vaults it spends, where the funds go and what comes back
+//! to the deposit descriptor.
+
+use std::str::FromStr;
+
+use bitcoin::{util::psbt::PartiallySignedTransaction as Psbt, Address, Network};
+use miniscript::DescriptorTrait;
+use revault_tx::{
+    bitcoin::{secp256k1::Secp256k1, util::bip32::ChildNumber},
+    scripts::UnvaultDescriptor,
+};
+
+use crate::revaultd::{config::ScriptsConfig, model::Vault};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputKind {
+    Payment,
+    /// Change back to the deposit descriptor, a new vault.
+    Change,
+    /// Output of the managers to bump the feerate of the transaction.
+    Cpfp,
+}
+
+impl std::fmt::Display for OutputKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Payment => write!(f, "Payment"),
+            Self::Change => write!(f, "Change"),
+            Self::Cpfp => write!(f, "CPFP"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReviewInput {
+    /// Outpoint of the deposit of the vault, None if no spent vault
+    /// matches the unvault output.
+    pub vault: Option<String>,
+    /// Value of the unvault output, the vault amount minus the unvault fees.
+    pub value: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReviewOutput {
+    /// None if the script has no address form.
+    pub address: Option<String>,
+    pub value: u64,
+    pub kind: OutputKind,
+}
+
+#[derive(Debug, Clone)]
+pub struct SpendReview {
+    pub inputs: Vec<ReviewInput>,
+    pub outputs: Vec<ReviewOutput>,
+    /// None if the value of an input is unknown.
+    pub fee: Option<u64>,
+    /// Estimated with the largest witness of the unvault descriptor,
+    /// the final feerate is at least this one.
+    pub feerate: Option<u64>,
+}
+
+impl SpendReview {
+    /// Decodes the psbt. The change and CPFP outputs are the ones
+    /// given by revaultd when it listed the spend transaction.
+    pub fn new(
+        psbt: &Psbt,
+        vaults: &[Vault],
+        scripts: &ScriptsConfig,
+        network: Network,
+        change_index: Option<usize>,
+        cpfp_index: Option<usize>,
+    ) -> Self {
+        let unvault = UnvaultDescriptor::from_str(&scripts.unvault_descriptor).ok();
+        let spent = unvault
+            .as_ref()
+            .map(|descriptor| spent_vaults(psbt, vaults, descriptor))
+            .unwrap_or_else(|| vec![None; psbt.inputs.len()]);
+        let inputs: Vec<ReviewInput> = psbt
+            .inputs
+            .iter()
+            .zip(spent)
+            .map(|(input, vault)| ReviewInput {
+                vault,
+                value: input.witness_utxo.as_ref().map(|utxo| utxo.value),
+            })
+            .collect();
+
+        let tx = &psbt.global.unsigned_tx;
+        let outputs = tx
+            .output
+            .iter()
+            .enumerate()
+            .map(|(i, output)| ReviewOutput {
+                address: Address::from_script(&output.script_pubkey, network)
+                    .map(|a| a.to_string()),
+                value: output.value,
+                kind: if Some(i) == change_index {
+                    OutputKind::Change
+                } else if Some(i) == cpfp_index {
+                    OutputKind::Cpfp
+                } else {
+                    OutputKind::Payment
+                },
+            })
+            .collect();
+
+        let input_value: Option<u64> = inputs.iter().map(|i| i.value).sum();
+        let output_value: u64 = tx.output.iter().map(|o| o.value).sum();
+        let fee = input_value.map(|value| value.saturating_sub(output_value));
+        let witness_weight =
+            unvault.and_then(|descriptor| descriptor.into_inner().max_satisfaction_weight().ok());
+        let feerate = fee.zip(witness_weight).map(|(fee, witness_weight)| {
+            // segwit marker and flag, then the witness of every input.
+            let weight = tx.get_weight() + 2 + witness_weight * tx.input.len();
+            fee / (weight as u64).div_ceil(4)
+        });
+
+        Self {
+            inputs,
+            outputs,
+            fee,
+            feerate,
+        }
+    }
+
+    /// Returns the sum of the outputs of the kind.
+    pub fn total(&self, kind: OutputKind) -> u64 {
+        self.outputs
+            .iter()
+            .filter(|o| o.kind == kind)
+            .map(|o| o.value)
+            .sum()
+    }
+}
+
+/// Returns the outpoint of the deposit of the vault spent by each input of
+/// the psbt, None if the input spends no unvault output of the vaults.
+pub fn spent_vaults(
+    psbt: &Psbt,
+    vaults: &[Vault],
+    unvault: &UnvaultDescriptor,
+) -> Vec<Option<String>> {
+    let secp = Secp256k1::verification_only();
+    // revault_tx depends on another version of the bitcoin crate,
+    // the scripts are compared as bytes.
+    let unvault_scripts: Vec<(String, Vec<u8>)> = vaults
+        .iter()
+        .filter_map(|vault| {
+            let child = ChildNumber::from_normal_idx(vault.derivation_index).ok()?;
+            let script = unvault
+                .derive(child, &secp)
+                .into_inner()
+                .script_pubkey()
+                .into_bytes();
+            Some((vault.outpoint(), script))
+        })
+        .collect();
+    psbt.inputs
+        .iter()
+        .map(|input| {
+            let utxo = input.witness_utxo.as_ref()?;
+            unvault_scripts
+                .iter()
+                .find(|(_, script)| script[..] == utxo.script_pubkey[..])
+                .map(|(outpoint, _)| outpoint.clone())
+        })
+        .collect()
+}
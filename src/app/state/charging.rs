@@ -1,24 +1,32 @@
 use std::io::ErrorKind;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use bitcoin::Network;
 use iced::{Command, Element};
 
 use super::State;
 use crate::app::{
     error::Error,
     message::Message,
+    sync::{SyncProgress, SyncRate},
     view::{charging::*, Context},
 };
+use crate::bitcoind;
+use crate::debounce;
 use crate::revaultd::{
     config::{Config, ConfigError},
-    start_daemon, RevaultD, RevaultDError,
+    daemon, start_daemon, CallPolicy, RevaultD, RevaultDError,
 };
 
 #[derive(Debug, Clone)]
 pub struct ChargingState {
     revaultd_config_path: PathBuf,
     revaultd_path: Option<PathBuf>,
+    policy: CallPolicy,
+    /// The training profile only connects to a revaultd running on regtest.
+    training: bool,
     revaultd: Option<Arc<RevaultD>>,
     step: ChargingStep,
 }
@@ -27,15 +35,55 @@ pub struct ChargingState {
 enum ChargingStep {
     Connecting,
     StartingDaemon,
-    Syncing { progress: f64 },
-    Error { error: String },
+    Syncing {
+        /// None until the first progress is fetched.
+        progress: Option<SyncProgress>,
+        rate: SyncRate,
+        eta: Option<Duration>,
+        /// Frame of the animation, advanced at each progress.
+        frame: usize,
+    },
+    /// revaultd is running but does not answer in time, the GUI keeps
+    /// trying to reach it.
+    Unresponsive {
+        error: String,
+    },
+    /// The revaultd binary is missing or its version is not supported,
+    /// the GUI refuses to start or to use it.
+    DaemonError {
+        error: String,
+    },
+    Error {
+        error: String,
+    },
 }
 
+impl ChargingStep {
+    fn syncing() -> Self {
+        ChargingStep::Syncing {
+            progress: None,
+            rate: SyncRate::default(),
+            eta: None,
+            frame: 0,
+        }
+    }
+}
+
+/// Delay before trying again to reach an unresponsive daemon.
+const UNRESPONSIVE_RETRY_DELAY: Duration = Duration::from_secs(5);
+
 impl ChargingState {
-    pub fn new(revaultd_config_path: PathBuf, revaultd_path: Option<PathBuf>) -> Self {
+    pub fn new(
+        revaultd_config_path: PathBuf,
+        revaultd_path: Option<PathBuf>,
+        policy: CallPolicy,
+        training: bool,
+    ) -> Self {
         ChargingState {
             revaultd_config_path,
             revaultd_path,
+            policy,
+            training,
             revaultd: None,
             step: ChargingStep::Connecting,
         }
@@ -44,7 +92,7 @@ impl ChargingState {
     fn on_connect(&mut self, res: Result<Arc<RevaultD>, Error>) -> Command<Message> {
         match res {
             Ok(revaultd) => {
-                self.step = ChargingStep::Syncing { progress: 0.0 };
+                self.step = ChargingStep::syncing();
                 self.revaultd = Some(revaultd.clone());
                 return Command::perform(sync(revaultd, false), Message::Syncing);
             }
@@ -61,10 +109,31 @@ impl ChargingState {
                         start_daemon_and_connect(
                             self.revaultd_config_path.to_owned(),
                             self.revaultd_path.to_owned(),
+                            self.policy,
+                            self.training,
                         ),
                         Message::DaemonStarted,
                     );
                 }
+                Error::RevaultDError(RevaultDError::UnsupportedVersion(_)) => {
+                    self.step = ChargingStep::DaemonError {
+                        error: e.to_string(),
+                    };
+                }
+                Error::RevaultDError(RevaultDError::Timeout(_)) => {
+                    self.step = ChargingStep::Unresponsive {
+                        error: e.to_string(),
+                    };
+                    return Command::perform(
+                        connect(
+                            self.revaultd_config_path.clone(),
+                            self.policy,
+                            self.training,
+                            true,
+                        ),
+                        Message::Connected,
+                    );
+                }
                 _ => return self.on_error(&e),
             },
         }
@@ -74,10 +143,17 @@ impl ChargingState {
     fn on_daemon_started(&mut self, res: Result<Arc<RevaultD>, Error>) -> Command<Message> {
         match res {
             Ok(revaultd) => {
-                self.step = ChargingStep::Syncing { progress: 0.0 };
+                self.step = ChargingStep::syncing();
                 self.revaultd = Some(revaultd.clone());
                 Command::perform(sync(revaultd, false), Message::Syncing)
             }
+            Err(Error::RevaultDError(e @ RevaultDError::StartError(_)))
+            | Err(Error::RevaultDError(e @ RevaultDError::UnsupportedVersion(_))) => {
+                self.step = ChargingStep::DaemonError {
+                    error: e.to_string(),
+                };
+                Command::none()
+            }
             Err(e) => self.on_error(&e),
         }
     }
@@ -89,27 +165,43 @@ impl ChargingState {
         Command::none()
     }
 
-    #[allow(unused_variables, unused_assignments)]
-    fn on_sync(&mut self, res: Result<f64, RevaultDError>) -> Command<Message> {
+    fn on_sync(&mut self, res: Result<SyncProgress, RevaultDError>) -> Command<Message> {
         match self.step {
-            ChargingStep::Syncing { mut progress } => {
+            ChargingStep::Syncing { .. } | ChargingStep::Unresponsive { .. } => {
+                let revaultd = match &self.revaultd {
+                    Some(revaultd) => revaultd.clone(),
+                    // A late message of a connection which was reset.
+                    None => return Command::none(),
+                };
                 match res {
+                    Err(RevaultDError::Timeout(method)) => {
+                        self.step = ChargingStep::Unresponsive {
+                            error: RevaultDError::Timeout(method).to_string(),
+                        };
+                        return Command::perform(sync(revaultd.clone(), true), Message::Syncing);
+                    }
                     Err(e) => return self.on_error(&e),
                     Ok(p) => {
-                        if (p - 1.0_f64).abs() < f64::EPSILON {
-                            return Command::perform(
-                                synced(self.revaultd.as_ref().unwrap().clone()),
-                                Message::Synced,
-                            );
-                        } else {
-                            progress = p
+                        if p.is_synced() {
+                            return Command::perform(synced(revaultd), Message::Synced);
+                        }
+                        if let ChargingStep::Unresponsive { .. } = self.step {
+                            self.step = ChargingStep::syncing();
+                        }
+                        if let ChargingStep::Syncing {
+                            progress,
+                            rate,
+                            eta,
+                            frame,
+                        } = &mut self.step
+                        {
+                            *eta = rate.estimate(&p, Instant::now());
+                            *progress = Some(p);
+                            *frame += 1;
                         }
                     }
                 };
-                Command::perform(
-                    sync(self.revaultd.as_ref().unwrap().clone(), true),
-                    Message::Syncing,
-                )
+                Command::perform(sync(revaultd, true), Message::Syncing)
             }
             _ => Command::none(),
         }
@@ -126,18 +218,30 @@ impl State for ChargingState {
         }
     }
 
-    fn view(&mut self, _ctx: &Context) -> Element<Message> {
+    fn view(&mut self, ctx: &Context) -> Element<Message> {
         match &mut self.step {
-            ChargingStep::StartingDaemon => charging_starting_daemon_view(),
-            ChargingStep::Connecting => charging_connect_view(),
-            ChargingStep::Syncing { progress, .. } => charging_syncing_view(&progress),
-            ChargingStep::Error { error } => charging_error_view(&error),
+            ChargingStep::StartingDaemon => charging_starting_daemon_view(ctx),
+            ChargingStep::Connecting => charging_connect_view(ctx),
+            ChargingStep::Syncing {
+                progress,
+                eta,
+                frame,
+                ..
+            } => charging_syncing_view(ctx, progress.as_ref(), eta.as_ref(), *frame),
+            ChargingStep::Unresponsive { error } => charging_unresponsive_view(ctx, error),
+            ChargingStep::DaemonError { error } => charging_daemon_error_view(ctx, error),
+            ChargingStep::Error { error } => charging_error_view(ctx, &error),
         }
     }
 
     fn load(&self) -> Command<Message> {
         Command::perform(
-            connect(self.revaultd_config_path.clone()),
+            connect(
+                self.revaultd_config_path.clone(),
+                self.policy,
+                self.training,
+                false,
+            ),
             Message::Connected,
         )
     }
@@ -153,43 +257,77 @@ async fn synced(revaultd: Arc<RevaultD>) -> Arc<RevaultD> {
     revaultd
 }
 
-async fn connect(revaultd_config_path: PathBuf) -> Result<Arc<RevaultD>, Error> {
+async fn connect(
+    revaultd_config_path: PathBuf,
+    policy: CallPolicy,
+    training: bool,
+    sleep: bool,
+) -> Result<Arc<RevaultD>, Error> {
+    if sleep {
+        debounce::delay(UNRESPONSIVE_RETRY_DELAY).await;
+    }
     let cfg = Config::from_file(&revaultd_config_path)?;
-    let revaultd = RevaultD::new(&cfg)?;
+    check_training_network(&cfg, training)?;
+    let revaultd = RevaultD::new(&cfg, policy)?;
+    // A daemon started outside of the GUI may not be supported.
+    daemon::check_version(&revaultd.get_info()?.version)?;
 
     Ok(Arc::new(revaultd))
 }
 
-async fn sync(revaultd: Arc<RevaultD>, sleep: bool) -> Result<f64, RevaultDError> {
+/// Refuses a revaultd configuration which is not on regtest
+/// if the GUI runs as a training profile.
+fn check_training_network(cfg: &Config, training: bool) -> Result<(), Error> {
+    let network = cfg.bitcoind_config.network;
+    if training && network != Network::Regtest {
+        return Err(Error::UnexpectedError(format!(
+            "the training profile only runs against a regtest daemon, revaultd is configured for {}",
+            network
+        )));
+    }
+    Ok(())
+}
+
+async fn sync(revaultd: Arc<RevaultD>, sleep: bool) -> Result<SyncProgress, RevaultDError> {
     if sleep {
-        std::thread::sleep(std::time::Duration::from_secs(1));
+        debounce::delay(Duration::from_secs(1)).await;
     }
     let resp = revaultd.get_info()?;
-    Ok(resp.sync)
+    let config = &revaultd.config.bitcoind_config;
+    Ok(SyncProgress {
+        revaultd: resp.sync,
+        blockheight: resp.blockheight,
+        bitcoind: bitcoind::get_blockchain_info(&config.addr, &config.cookie_path),
+    })
 }
 
 async fn start_daemon_and_connect(
     revaultd_config_path: PathBuf,
     revaultd_path: Option<PathBuf>,
+    policy: CallPolicy,
+    training: bool,
 ) -> Result<Arc<RevaultD>, Error> {
-    let revaultd_path = revaultd_path.unwrap_or_else(|| PathBuf::from("revaultd"));
+    // The daemon is not started if the GUI would refuse to connect to it.
+    let cfg = Config::from_file(&revaultd_config_path)?;
+    check_training_network(&cfg, training)?;
 
-    start_daemon(&revaultd_config_path, &revaultd_path).await?;
+    let revaultd_path = daemon::discover(revaultd_path.as_deref())?;
+    daemon::check_version(&daemon::version(&revaultd_path)?)?;
 
-    let cfg = Config::from_file(&revaultd_config_path)?;
+    start_daemon(&revaultd_config_path, &revaultd_path).await?;
 
-    fn try_connect_to_revault(cfg: &Config, i: i32) -> Result<Arc<RevaultD>, Error> {
-        std::thread::sleep(std::time::Duration::from_secs(3));
-        RevaultD::new(cfg).map(Arc::new).map_err(|e| {
-            tracing::warn!("Failed to connect to revaultd ({} more try): {}", i, e);
-            e.into()
-        })
+    let mut tries = 5;
+    loop {
+        debounce::delay(Duration::from_secs(3)).await;
+        match RevaultD::new(&cfg, policy) {
+            Ok(revaultd) => return Ok(Arc::new(revaultd)),
+            Err(e) => {
+                tracing::warn!("Failed to connect to revaultd ({} more try): {}", tries, e);
+                if tries == 0 {
+                    return Err(e.into());
+                }
+                tries -= 1;
+            }
+        }
     }
-
-    try_connect_to_revault(&cfg, 5)
-        .or_else(|_| try_connect_to_revault(&cfg, 4))
-        .or_else(|_| try_connect_to_revault(&cfg, 3))
-        .or_else(|_| try_connect_to_revault(&cfg, 2))
-        .or_else(|_| try_connect_to_revault(&cfg, 1))
-        .or_else(|_| try_connect_to_revault(&cfg, 0))
 }
@@ -1,14 +1,38 @@
-use bitcoin::util::psbt::PartiallySignedTransaction as Psbt;
+use bitcoin::{util::psbt::PartiallySignedTransaction as Psbt, Txid};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 
+use chrono::Utc;
+
+use crate::app::{
+    alerts::{self, Finding},
+    backup::{Backup, Backups},
+    connection::Instance,
+    coordinator::{self, CoordinatorStatus},
+    deadline::{self, CancelDeadline},
+    error::Error,
+    flow::{self, FlowSpend},
+    history,
+    labels::Labels,
+    log_bundle,
+    report::{self, Report},
+};
+use crate::bitcoind::{self, BitcoindError, FeeEstimate, NodeReport, PruningCheck};
+use crate::conversion::Converter;
+use crate::debounce;
+use crate::logs;
+use crate::net::{self, PingError};
+use crate::price::{self, PriceError, Rates};
 use crate::revaultd::{
+    config::BitcoindConfig,
     model::{
         RevocationTransactions, SpendTransaction, SpendTx, SpendTxStatus, UnvaultTransaction,
         Vault, VaultStatus, VaultTransactions,
     },
     RevaultD, RevaultDError,
 };
+use crate::smtp;
 
 /// retrieves a bitcoin address for deposit.
 pub async fn get_deposit_address(
@@ -21,6 +45,81 @@ pub async fn get_blockheight(revaultd: Arc<RevaultD>) -> Result<u64, RevaultDErr
     revaultd.get_info().map(|res| res.blockheight)
 }
 
+/// sends the raw call of the developer console.
+pub async fn raw_call(
+    revaultd: Arc<RevaultD>,
+    method: String,
+    params: Option<serde_json::Value>,
+) -> Result<serde_json::Value, RevaultDError> {
+    revaultd.raw_call(&method, params)
+}
+
+/// checks the pruning configuration of the bitcoind used by revaultd.
+pub async fn check_bitcoind_pruning(
+    revaultd: Arc<RevaultD>,
+) -> Result<PruningCheck, BitcoindError> {
+    let config = &revaultd.config.bitcoind_config;
+    bitcoind::get_blockchain_info(&config.addr, &config.cookie_path)
+        .map(|info| info.pruning_check(revaultd.config.unvault_csv()))
+}
+
+/// opens a connection to the server, through the proxy of the health checks if any.
+pub async fn ping_server(
+    host: String,
+    proxy: Option<std::net::SocketAddr>,
+) -> Result<std::time::Duration, PingError> {
+    net::probe(host, proxy).await
+}
+
+/// probes the coordinator, through the proxy of the health checks if any, and
+/// lists what still waits for the other participants.
+pub async fn coordinator_status(
+    revaultd: Arc<RevaultD>,
+    proxy: Option<std::net::SocketAddr>,
+) -> Result<CoordinatorStatus, RevaultDError> {
+    let host = revaultd.config.coordinator_host.clone();
+    let probe = net::probe(host, proxy).await;
+    coordinator::coordinator_status(&revaultd, probe)
+}
+
+/// checks the unvaults and the watchtowers for critical events, the
+/// watchtowers are probed through the proxy of the health checks if any.
+pub async fn check_alerts(
+    revaultd: Arc<RevaultD>,
+    proxy: Option<std::net::SocketAddr>,
+) -> Result<Vec<Finding>, RevaultDError> {
+    let hosts = alerts::watchtowers(&revaultd);
+    let pings = net::probe_all(hosts.clone(), proxy).await;
+    alerts::check(&revaultd, hosts.into_iter().zip(pings).collect())
+}
+
+/// tests the connection to the bitcoind of the configuration.
+pub async fn test_bitcoind(
+    config: BitcoindConfig,
+    unvault_csv: Option<u32>,
+) -> Result<NodeReport, BitcoindError> {
+    bitcoind::test_node(
+        &config.addr,
+        &config.cookie_path,
+        config.network,
+        unvault_csv,
+    )
+}
+
+/// estimates the feerates of the bitcoind used by revaultd.
+pub async fn estimate_fees(revaultd: Arc<RevaultD>) -> Result<Vec<FeeEstimate>, BitcoindError> {
+    let config = &revaultd.config.bitcoind_config;
+    bitcoind::estimate_fees(&config.addr, &config.cookie_path)
+}
+
+pub async fn cancel_deadlines(
+    revaultd: Arc<RevaultD>,
+    vaults: Vec<Vault>,
+    csv: u32,
+) -> Result<Vec<CancelDeadline>, RevaultDError> {
+    deadline::cancel_deadlines(&revaultd, vaults, csv)
+}
+
 pub async fn list_vaults(
     revaultd: Arc<RevaultD>,
     statuses: Option<&[VaultStatus]>,
@@ -31,6 +130,94 @@ pub async fn list_vaults(
         .map(|res| res.vaults)
 }
 
+/// lists the vaults of a single status, the dashboards making a call per status.
+pub async fn list_vaults_of_status(
+    revaultd: Arc<RevaultD>,
+    status: VaultStatus,
+) -> Result<Vec<Vault>, RevaultDError> {
+    revaultd
+        .list_vaults(Some(&[status]), None)
+        .map(|res| res.vaults)
+}
+
+/// fetches the bitcoin rates from the price feed provider.
+pub async fn fetch_rates(url: String) -> Result<Rates, PriceError> {
+    price::fetch_rates(&url, Utc::now().timestamp())
+}
+
+/// exports the history of every vault to a csv file of the revaultd network datadir.
+pub async fn export_history(revaultd: Arc<RevaultD>) -> Result<PathBuf, Error> {
+    let vaults = revaultd.list_vaults(None, None)?.vaults;
+    let txs = revaultd
+        .list_onchain_transactions(None)?
+        .onchain_transactions;
+    let labels = Labels::load_datadir(&revaultd)?;
+    let dir = revaultd.config.network_datadir()?;
+    history::export(
+        &dir,
+        &history::history_csv(&vaults, &txs, &labels),
+        Utc::now().timestamp(),
+    )
+}
+
+/// makes the automatic backup of the GUI local data.
+pub async fn auto_backup(mut backups: Backups, now: i64) -> Result<Backup, Error> {
+    backups.backup(now)
+}
+
+/// returns the running revaultd instance.
+pub async fn check_daemon(revaultd: Arc<RevaultD>) -> Result<Instance, RevaultDError> {
+    Instance::get(&revaultd)
+}
+
+/// reads the last lines of the revaultd log file.
+pub async fn read_daemon_logs(revaultd: Arc<RevaultD>) -> Result<Vec<String>, Error> {
+    let path = revaultd.config.log_file()?;
+    logs::tail(&path, log_bundle::DAEMON_TAIL_BYTES).map_err(|e| {
+        Error::UnexpectedError(format!(
+            "Failed to read the revaultd log file {}: {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+/// exports the redacted logs of the GUI and of revaultd to a file of the
+/// revaultd network datadir.
+pub async fn export_logs(revaultd: Arc<RevaultD>) -> Result<PathBuf, Error> {
+    let now = Utc::now().timestamp();
+    let daemon = read_daemon_logs(revaultd.clone())
+        .await
+        .map(|lines| log_bundle::daemon_lines(&lines))
+        .map_err(|e| e.to_string());
+    let bundle = log_bundle::render(
+        &log_bundle::gui_lines(),
+        daemon.as_deref().map_err(|e| e.as_str()),
+        now,
+    );
+    log_bundle::export(&revaultd.config.network_datadir()?, &bundle, now)
+}
+
+/// mails the wallet report with the settings of the local store,
+/// returning the date of the report once sent.
+pub async fn send_report(revaultd: Arc<RevaultD>) -> Result<i64, Error> {
+    let mut report = Report::load_datadir(&revaultd)?;
+    let vaults = revaultd.list_vaults(None, None)?.vaults;
+    let now = Utc::now().timestamp();
+    let mail = report::render(
+        &Converter::new(revaultd.network()),
+        &vaults,
+        report.settings.last_sent,
+        now,
+        &report.settings,
+    );
+    smtp::send(&report.settings.server, &mail)
+        .map_err(|e| Error::UnexpectedError(e.to_string()))?;
+    report.settings.last_sent = Some(now);
+    report.save()?;
+    Ok(now)
+}
+
 pub async fn get_onchain_txs(
     revaultd: Arc<RevaultD>,
     outpoint: String,
@@ -52,6 +239,44 @@ pub async fn get_revocation_txs(
     revaultd.get_revocation_txs(&outpoint)
 }
 
+/// Fetches the revocation transactions of every vault of the chunk, one
+/// vault after the other, fails if one of the vaults has none.
+pub async fn get_revocation_txs_batch(
+    revaultd: Arc<RevaultD>,
+    outpoints: Vec<String>,
+) -> Result<Vec<(String, RevocationTransactions)>, RevaultDError> {
+    outpoints
+        .into_iter()
+        .map(|outpoint| {
+            revaultd
+                .get_revocation_txs(&outpoint)
+                .map(|txs| (outpoint, txs))
+        })
+        .collect()
+}
+
+/// Shares the signed revocation transactions of every vault and returns
+/// the result of each vault, a failure does not stop the others.
+pub async fn set_revocation_txs_batch(
+    revaultd: Arc<RevaultD>,
+    vaults: Vec<(String, Psbt, Psbt, Psbt)>,
+) -> Vec<(String, Result<(), RevaultDError>)> {
+    vaults
+        .into_iter()
+        .map(
+            |(outpoint, emergency_tx, emergency_unvault_tx, cancel_tx)| {
+                let res = revaultd.set_revocation_txs(
+                    &outpoint,
+                    &emergency_tx,
+                    &emergency_unvault_tx,
+                    &cancel_tx,
+                );
+                (outpoint, res)
+            },
+        )
+        .collect()
+}
+
 pub async fn set_revocation_txs(
     revaultd: Arc<RevaultD>,
     outpoint: String,
@@ -97,6 +322,27 @@ pub async fn list_spend_txs(
     revaultd.list_spend_txs(statuses).map(|res| res.spend_txs)
 }
 
+/// fetches every vault and spend transaction known by revaultd,
+/// to draw the flow of the funds between them.
+/// Returns the vaults and the flow of their funds. The spend transactions
+/// are only known by the managers, the flow of the stakeholders is built
+/// from the broadcasted transactions of the vaults.
+pub async fn fund_flow(
+    revaultd: Arc<RevaultD>,
+) -> Result<(Vec<Vault>, Vec<FlowSpend>), RevaultDError> {
+    let vaults = revaultd.list_vaults(None, None)?.vaults;
+    let spends = if revaultd.config.manager_config.is_some() {
+        let spend_txs = revaultd.list_spend_txs(None)?.spend_txs;
+        flow::flow(&vaults, &spend_txs, revaultd.network())
+    } else {
+        let txs = revaultd
+            .list_onchain_transactions(None)?
+            .onchain_transactions;
+        flow::onchain_flow(&vaults, &txs, revaultd.network())
+    };
+    Ok((vaults, spends))
+}
+
 pub async fn delete_spend_tx(revaultd: Arc<RevaultD>, txid: String) -> Result<(), RevaultDError> {
     revaultd.delete_spend_tx(&txid)
 }
@@ -112,6 +358,50 @@ pub async fn revault(revaultd: Arc<RevaultD>, outpoint: String) -> Result<(), Re
     revaultd.revault(&outpoint)
 }
 
-pub async fn emergency(revaultd: Arc<RevaultD>) -> Result<(), RevaultDError> {
-    revaultd.emergency()
+/// Stops revaultd and waits for it to exit, so that it can be started again.
+pub async fn stop_daemon(revaultd: Arc<RevaultD>) -> Result<(), RevaultDError> {
+    revaultd.stop()?;
+    for _ in 0..20 {
+        debounce::delay(std::time::Duration::from_millis(500)).await;
+        if let Err(RevaultDError::IOError(_)) = revaultd.get_info() {
+            return Ok(());
+        }
+    }
+    Err(RevaultDError::UnexpectedError(
+        "revaultd is still running after the stop command".to_string(),
+    ))
+}
+
+/// broadcasts with bitcoind the transaction sweeping the vault to the deep
+/// vault: the emergency transaction of a vault whose deposit is unspent, the
+/// unvault emergency transaction of an unvaulting or unvaulted vault.
+pub async fn emergency(revaultd: Arc<RevaultD>, vault: Vault) -> Result<Txid, Error> {
+    let outpoint = vault.outpoint();
+    let presigned = revaultd
+        .list_presigned_transactions(&[outpoint.clone()])?
+        .presigned_transactions
+        .into_iter()
+        .find(|txs| txs.vault_outpoint == outpoint)
+        .ok_or_else(|| {
+            Error::UnexpectedError(format!("No presigned transactions for vault {}", outpoint))
+        })?;
+    let unvaulted = matches!(
+        vault.status,
+        VaultStatus::Unvaulting | VaultStatus::Unvaulted
+    );
+    let tx = if unvaulted {
+        presigned.unvault_emergency
+    } else {
+        presigned.emergency
+    }
+    .and_then(|presigned| presigned.tx)
+    .ok_or_else(|| {
+        Error::UnexpectedError(format!(
+            "The emergency transaction of vault {} is not fully signed",
+            outpoint
+        ))
+    })?;
+    let config = &revaultd.config.bitcoind_config;
+    let txid = bitcoind::send_raw_transaction(&config.addr, &config.cookie_path, &tx)?;
+    Ok(txid)
 }
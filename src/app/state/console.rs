@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use iced::{Command, Element};
+
+use super::{cmd::raw_call, State};
+
+use crate::app::{
+    console,
+    message::{ConsoleMessage, Message},
+    view::{ConsoleView, Context},
+};
+use crate::revaultd::RevaultD;
+
+/// ConsoleState sends the JSON-RPC calls typed by the user to revaultd
+/// and displays their responses, the latest first.
+#[derive(Debug)]
+pub struct ConsoleState {
+    revaultd: Arc<RevaultD>,
+    method: String,
+    params: String,
+    /// Error of the params typed by the user.
+    warning: Option<String>,
+    /// true while the call is sent.
+    processing: bool,
+    history: Vec<console::Call>,
+
+    view: ConsoleView,
+}
+
+impl ConsoleState {
+    pub fn new(revaultd: Arc<RevaultD>) -> Self {
+        ConsoleState {
+            revaultd,
+            method: String::new(),
+            params: String::new(),
+            warning: None,
+            processing: false,
+            history: console::history(),
+            view: ConsoleView::new(),
+        }
+    }
+}
+
+impl State for ConsoleState {
+    fn update(&mut self, message: Message) -> Command<Message> {
+        if let Message::Console(msg) = message {
+            match msg {
+                ConsoleMessage::MethodEdited(method) => {
+                    self.method = method;
+                    self.warning = None;
+                }
+                ConsoleMessage::ParamsEdited(params) => {
+                    self.params = params;
+                    self.warning = None;
+                }
+                ConsoleMessage::Send => {
+                    let method = self.method.trim().to_string();
+                    if method.is_empty() || self.processing {
+                        return Command::none();
+                    }
+                    if let Err(e) = console::check_method(&method) {
+                        self.warning = Some(e);
+                        return Command::none();
+                    }
+                    match console::parse_params(&self.params) {
+                        Ok(params) => {
+                            self.processing = true;
+                            let typed = self.params.clone();
+                            return Command::perform(
+                                raw_call(self.revaultd.clone(), method.clone(), params),
+                                move |res| {
+                                    Message::Console(ConsoleMessage::Response(
+                                        method.clone(),
+                                        typed.clone(),
+                                        res,
+                                    ))
+                                },
+                            );
+                        }
+                        Err(e) => self.warning = Some(e),
+                    }
+                }
+                ConsoleMessage::Response(method, params, res) => {
+                    self.processing = false;
+                    console::record(method, params, &res, Utc::now().timestamp());
+                    self.history = console::history();
+                }
+                ConsoleMessage::Recall(i) => {
+                    if let Some(call) = self.history.get(i) {
+                        self.method = call.method.clone();
+                        self.params = call.params.clone();
+                        self.warning = None;
+                    }
+                }
+                ConsoleMessage::ClearHistory => {
+                    console::clear_history();
+                    self.history = Vec::new();
+                }
+            }
+        }
+        Command::none()
+    }
+
+    fn view(&mut self, ctx: &Context) -> Element<'_, Message> {
+        self.view.view(
+            ctx,
+            &self.method,
+            &self.params,
+            self.warning.as_deref(),
+            self.processing,
+            &self.history,
+        )
+    }
+}
+
+impl From<ConsoleState> for Box<dyn State> {
+    fn from(s: ConsoleState) -> Box<dyn State> {
+        Box::new(s)
+    }
+}
@@ -1,67 +1,263 @@
+use std::collections::HashMap;
 use std::convert::From;
 use std::sync::Arc;
 
+use chrono::Utc;
 use iced::{Command, Element};
 
-use super::{cmd::get_deposit_address, State};
+use super::{
+    cmd::{get_deposit_address, list_vaults},
+    State,
+};
 
-use crate::revaultd::RevaultD;
+use crate::conversion;
+use crate::revaultd::{model::Vault, RevaultD};
 
 use crate::app::{
+    deposit_addresses::{self, DepositAddresses, Usage},
     error::Error,
-    message::Message,
+    message::{DepositMessage, Message, PollMessage},
+    vault_sizing,
     view::{Context, DepositView},
 };
+use crate::ui::component::form;
 
 /// DepositState handles the deposit process.
 /// It gets a deposit address from the revault daemon and
-/// give it to its view in order to be rendered.
+/// give it to its view in order to be rendered. The vaults are
+/// polled to ask for a new address once the current one is used.
 #[derive(Debug)]
 pub struct DepositState {
     revaultd: Arc<RevaultD>,
     address: Option<bitcoin::Address>,
+    /// addresses previously displayed.
+    addresses: DepositAddresses,
+    /// usage of the addresses, None until the vaults are listed.
+    usage: Option<HashMap<String, Usage>>,
+    /// previous address, replaced after it received a deposit.
+    rotated: Option<String>,
+    /// vaults of the last poll, their derivation indexes are used.
+    vaults: Vec<Vault>,
+    /// Target amount of a vault in satoshis, if the organization set one.
+    vault_size: Option<u64>,
+    /// Planned deposit to split in vaults, in BTC.
+    planned: form::Value<String>,
+    /// Amounts and addresses of the deposits of the split.
+    split: Vec<(u64, String)>,
     warning: Option<Error>,
+    /// true if the address is displayed as a BIP21 payment request.
+    bip21: bool,
+    /// Optional amount of the payment request, in BTC.
+    amount: form::Value<String>,
 
     /// The deposit view is rendering the address.
     view: DepositView,
 }
 
 impl DepositState {
-    pub fn new(revaultd: Arc<RevaultD>) -> Self {
+    pub fn new(revaultd: Arc<RevaultD>, vault_size: Option<u64>) -> Self {
+        let mut warning = None;
+        let addresses = DepositAddresses::load_datadir(&revaultd).unwrap_or_else(|e| {
+            warning = Some(e);
+            DepositAddresses::default()
+        });
         DepositState {
             revaultd,
             view: DepositView::new(),
-            warning: None,
+            warning,
             address: None,
+            addresses,
+            usage: None,
+            rotated: None,
+            vaults: Vec::new(),
+            vault_size,
+            planned: form::Value::default(),
+            split: Vec::new(),
+            bip21: false,
+            amount: form::Value::default(),
+        }
+    }
+
+    /// Returns the data displayed by the view: the address,
+    /// or the BIP21 uri of the payment request.
+    fn deposit_data(&self) -> Option<String> {
+        let address = self.address.as_ref()?;
+        if !self.bip21 {
+            return Some(address.to_string());
+        }
+        let amount = if self.amount.valid {
+            conversion::parse_btc(&self.amount.value)
+        } else {
+            None
+        };
+        Some(bip21_uri(address, amount))
+    }
+
+    fn on_deposit_message(&mut self, msg: DepositMessage) {
+        match msg {
+            DepositMessage::Bip21(bip21) => self.bip21 = bip21,
+            DepositMessage::AmountEdited(amount) => {
+                self.amount.valid = amount.is_empty()
+                    || matches!(
+                        conversion::parse_btc(&amount),
+                        Some(a) if a.as_sat() > 0
+                    );
+                self.amount.value = amount;
+            }
+            DepositMessage::PlannedAmountEdited(amount) => {
+                self.planned.valid = amount.is_empty()
+                    || matches!(
+                        conversion::parse_btc(&amount),
+                        Some(a) if a.as_sat() > 0
+                    );
+                self.planned.value = amount;
+                self.split.clear();
+            }
+            DepositMessage::SplitDeposit => {
+                if let Err(e) = self.split_deposit() {
+                    self.warning = Some(e);
+                }
+            }
+        }
+    }
+
+    /// Derives an address for each deposit of the planned amount and
+    /// records them with the displayed addresses.
+    fn split_deposit(&mut self) -> Result<(), Error> {
+        let (size, address) = match (self.vault_size, &self.address) {
+            (Some(size), Some(address)) => (size, address.to_string()),
+            _ => return Ok(()),
+        };
+        let amount = match conversion::parse_btc(&self.planned.value) {
+            Some(amount) if self.planned.valid => amount.as_sat(),
+            _ => return Ok(()),
+        };
+        let deposits = vault_sizing::split(amount, size).map_err(|_| {
+            Error::UnexpectedError(format!(
+                "The planned deposit requires more than {} vaults, split it in several deposits",
+                vault_sizing::MAX_DEPOSITS
+            ))
+        })?;
+        let addresses = vault_sizing::addresses(
+            &self.revaultd.config.scripts_config.deposit_descriptor,
+            self.revaultd.config.bitcoind_config.network,
+            &address,
+            &self.vaults,
+            deposits.len(),
+        )?;
+        let now = Utc::now().timestamp();
+        for address in &addresses {
+            self.addresses.record(address, now)?;
         }
+        self.split = deposits.into_iter().zip(addresses).collect();
+        Ok(())
+    }
+}
+
+/// Returns the BIP21 uri requesting a payment to the address.
+pub fn bip21_uri(address: &bitcoin::Address, amount: Option<bitcoin::Amount>) -> String {
+    match amount {
+        Some(amount) => {
+            let btc = amount.to_string_in(bitcoin::Denomination::Bitcoin);
+            let btc = if btc.contains('.') {
+                btc.trim_end_matches('0').trim_end_matches('.')
+            } else {
+                &btc
+            };
+            format!("bitcoin:{}?amount={}", address, btc)
+        }
+        None => format!("bitcoin:{}", address),
     }
 }
 
 impl State for DepositState {
     fn update(&mut self, message: Message) -> Command<Message> {
-        if let Message::DepositAddress(res) = message {
-            match res {
+        match message {
+            Message::Poll(PollMessage::Tick) => return self.load(),
+            Message::DepositAddress(res) => match res {
                 Ok(address) => {
-                    // Address is loaded directly in the view in order to cache the created qrcode.
-                    self.view.load(&address);
+                    if let Some(previous) = &self.address {
+                        if *previous != address {
+                            self.rotated = Some(previous.to_string());
+                        }
+                    }
+                    if let Err(e) = self
+                        .addresses
+                        .record(&address.to_string(), Utc::now().timestamp())
+                    {
+                        self.warning = Some(e);
+                    }
                     self.address = Some(address);
                 }
                 Err(e) => self.warning = Some(Error::RevaultDError(e)),
-            }
+            },
+            Message::Vaults(res) => match res {
+                Ok(vaults) => {
+                    let usage = deposit_addresses::usage(&vaults);
+                    let used = self
+                        .address
+                        .as_ref()
+                        .map(|address| usage.contains_key(&address.to_string()))
+                        .unwrap_or(false);
+                    self.usage = Some(usage);
+                    self.vaults = vaults;
+                    // revaultd derives the next address once the deposit is seen.
+                    if used {
+                        return Command::perform(
+                            get_deposit_address(self.revaultd.clone()),
+                            Message::DepositAddress,
+                        );
+                    }
+                    return Command::none();
+                }
+                Err(e) => self.warning = Some(Error::RevaultDError(e)),
+            },
+            Message::Deposit(msg) => self.on_deposit_message(msg),
+            _ => return Command::none(),
+        }
+        // Data is loaded directly in the view in order to cache the created qrcode.
+        if let Some(data) = self.deposit_data() {
+            self.view.load(&data);
         }
         Command::none()
     }
 
     fn view(&mut self, ctx: &Context) -> Element<Message> {
-        self.view
-            .view(ctx, self.warning.as_ref(), self.address.as_ref())
+        // The amount is reformatted once the user leaves the field.
+        if self.amount.valid && !self.view.amount_focused() {
+            if let Some(amount) = conversion::normalize_number(&self.amount.value) {
+                self.amount.value = amount;
+            }
+        }
+        let data = self.deposit_data();
+        let current = self.address.as_ref().map(|address| address.to_string());
+        let (planned, split) = (&self.planned, self.split.as_slice());
+        self.view.view(
+            ctx,
+            self.warning.as_ref(),
+            data.as_deref(),
+            self.bip21,
+            &self.amount,
+            &self.revaultd.config,
+            self.rotated.as_deref(),
+            current.as_deref(),
+            self.addresses.all(),
+            self.usage.as_ref(),
+            self.vault_size.map(|size| (size, planned, split)),
+        )
     }
 
     fn load(&self) -> Command<Message> {
-        Command::perform(
-            get_deposit_address(self.revaultd.clone()),
-            Message::DepositAddress,
-        )
+        Command::batch(vec![
+            Command::perform(
+                get_deposit_address(self.revaultd.clone()),
+                Message::DepositAddress,
+            ),
+            Command::perform(
+                list_vaults(self.revaultd.clone(), None, None),
+                Message::Vaults,
+            ),
+        ])
     }
 }
 
@@ -1,33 +1,58 @@
+use std::collections::HashSet;
 use std::convert::From;
 use std::sync::Arc;
 
+use bitcoin::Txid;
 use iced::{Command, Element};
 
 use super::{cmd::list_vaults, State};
 
-use crate::revaultd::{model::VaultStatus, RevaultD};
+use crate::revaultd::{
+    model::{self, VaultStatus},
+    RevaultD,
+};
 
 use crate::app::{
+    actions::{self, ActionKind},
     error::Error,
-    message::Message,
+    message::{EmergencyConfirmMessage, Message},
     state::cmd,
     view::{Context, EmergencyView},
 };
 
+/// Statuses of the vaults that can be swept to the deep vault, their
+/// revocation transactions are fully signed. A vault whose deposit is
+/// unspent is swept by its emergency transaction, an unvaulting or
+/// unvaulted one by its unvault emergency transaction.
+const SWEPT: [VaultStatus; 5] = [
+    VaultStatus::Secured,
+    VaultStatus::Active,
+    VaultStatus::Activating,
+    VaultStatus::Unvaulting,
+    VaultStatus::Unvaulted,
+];
+
 #[derive(Debug)]
 pub struct EmergencyState {
     revaultd: Arc<RevaultD>,
     view: EmergencyView,
 
-    vaults_number: usize,
-    funds_amount: u64,
+    vaults: Vec<model::Vault>,
+    /// Outpoints of the vaults left out of the emergency by the user,
+    /// every vault that can be swept is selected by default.
+    unselected: HashSet<String>,
+
+    /// true once the user confirmed the emergency, the broadcast is not
+    /// started twice.
+    broadcasting: bool,
+    /// Swept vaults with the result of the broadcast of their emergency
+    /// transaction, None while it is broadcasting.
+    swept: Vec<(model::Vault, Option<Result<Txid, Error>>)>,
 
     warning: Option<Error>,
 
     /// loading is true until Message::Vaults is handled
     loading: bool,
-    processing: bool,
-    success: bool,
 }
 
 impl EmergencyState {
@@ -35,12 +60,72 @@ impl EmergencyState {
         EmergencyState {
             revaultd,
             view: EmergencyView::new(),
-            vaults_number: 0,
-            funds_amount: 0,
+            vaults: Vec::new(),
+            unselected: HashSet::new(),
+            broadcasting: false,
+            swept: Vec::new(),
             warning: None,
             loading: true,
-            processing: false,
-            success: false,
+        }
+    }
+
+    fn is_selected(&self, vault: &model::Vault) -> bool {
+        SWEPT.contains(&vault.status) && !self.unselected.contains(&vault.outpoint())
+    }
+
+    /// Broadcasts the emergency transaction of each vault, one command per
+    /// vault so that the result of each broadcast is displayed on its own.
+    fn broadcast(&self, vaults: Vec<model::Vault>) -> Command<Message> {
+        Command::batch(vaults.into_iter().map(|vault| {
+            let outpoint = vault.outpoint();
+            Command::perform(cmd::emergency(self.revaultd.clone(), vault), move |res| {
+                Message::EmergencyBroadcasted(outpoint.clone(), res)
+            })
+        }))
+    }
+
+    fn on_confirm_message(&mut self, msg: EmergencyConfirmMessage) -> Command<Message> {
+        match msg {
+            EmergencyConfirmMessage::SelectVault(outpoint, selected) => {
+                if selected {
+                    self.unselected.remove(&outpoint);
+                } else {
+                    self.unselected.insert(outpoint);
+                }
+                Command::none()
+            }
+            EmergencyConfirmMessage::Broadcast => {
+                if self.broadcasting {
+                    return Command::none();
+                }
+                let vaults: Vec<model::Vault> = self
+                    .vaults
+                    .iter()
+                    .filter(|v| self.is_selected(v))
+                    .cloned()
+                    .collect();
+                if vaults.is_empty() {
+                    return Command::none();
+                }
+                self.broadcasting = true;
+                self.warning = None;
+                self.swept = vaults.iter().map(|v| (v.clone(), None)).collect();
+                self.broadcast(vaults)
+            }
+            EmergencyConfirmMessage::Retry => {
+                // The failed broadcasts are retried once every broadcast ended.
+                if self.swept.iter().any(|(_, res)| res.is_none()) {
+                    return Command::none();
+                }
+                let mut failed = Vec::new();
+                for (vault, res) in &mut self.swept {
+                    if matches!(res, Some(Err(_))) {
+                        *res = None;
+                        failed.push(vault.clone());
+                    }
+                }
+                self.broadcast(failed)
+            }
         }
     }
 }
@@ -51,25 +136,29 @@ impl State for EmergencyState {
             Message::Vaults(res) => match res {
                 Ok(vaults) => {
                     self.loading = false;
-                    self.vaults_number = vaults.len();
-                    self.funds_amount = vaults.into_iter().fold(0, |acc, vault| acc + vault.amount);
+                    let outpoints: HashSet<String> = vaults.iter().map(|v| v.outpoint()).collect();
+                    self.unselected
+                        .retain(|outpoint| outpoints.contains(outpoint));
+                    self.vaults = vaults;
                 }
                 Err(e) => self.warning = Error::from(e).into(),
             },
-            Message::Emergency => {
-                self.processing = true;
-                self.warning = None;
-                return Command::perform(
-                    cmd::emergency(self.revaultd.clone()),
-                    Message::EmergencyBroadcasted,
-                );
-            }
-            Message::EmergencyBroadcasted(res) => {
-                self.processing = false;
-                if let Err(e) = res {
-                    self.warning = Some(Error::RevaultDError(e));
-                } else {
-                    self.success = true;
+            Message::EmergencyConfirm(msg) => return self.on_confirm_message(msg),
+            Message::EmergencyBroadcasted(outpoint, res) => {
+                match &res {
+                    Ok(_) => {
+                        actions::record(&self.revaultd, &[outpoint.clone()], ActionKind::Emergency)
+                    }
+                    Err(e) => {
+                        tracing::error!("Emergency broadcast of vault {} failed: {}", outpoint, e)
+                    }
+                }
+                if let Some((_, result)) = self
+                    .swept
+                    .iter_mut()
+                    .find(|(vault, _)| vault.outpoint() == outpoint)
+                {
+                    *result = Some(res);
                 }
             }
             _ => {}
@@ -78,14 +167,21 @@ impl State for EmergencyState {
     }
 
     fn view(&mut self, ctx: &Context) -> Element<Message> {
+        if self.broadcasting {
+            return self.view.progress(ctx, &self.swept);
+        }
+        let (sweepable, remaining): (Vec<&model::Vault>, Vec<&model::Vault>) =
+            self.vaults.iter().partition(|v| SWEPT.contains(&v.status));
+        let sweepable: Vec<(&model::Vault, bool)> = sweepable
+            .into_iter()
+            .map(|v| (v, self.is_selected(v)))
+            .collect();
         self.view.view(
             ctx,
-            self.vaults_number,
-            self.funds_amount,
+            &sweepable,
+            &remaining,
             self.warning.as_ref(),
             self.loading,
-            self.processing,
-            self.success,
         )
     }
 
@@ -94,6 +190,9 @@ impl State for EmergencyState {
             list_vaults(
                 self.revaultd.clone(),
                 Some(&[
+                    VaultStatus::Unconfirmed,
+                    VaultStatus::Funded,
+                    VaultStatus::Securing,
                     VaultStatus::Secured,
                     VaultStatus::Active,
                     VaultStatus::Activating,
@@ -0,0 +1,116 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use iced::{Command, Element};
+use tracing::Level;
+
+use super::{
+    cmd::{export_logs, read_daemon_logs},
+    State,
+};
+
+use crate::app::{
+    error::Error,
+    log_bundle::{self, LogSource},
+    message::{LogPanelMessage, Message, PollMessage},
+    view::{Context, LogsView},
+};
+use crate::revaultd::RevaultD;
+
+/// LogsState displays the events of the GUI and the tail of the
+/// revaultd log file, filtered by level and by the search.
+#[derive(Debug)]
+pub struct LogsState {
+    revaultd: Arc<RevaultD>,
+    source: LogSource,
+    level: Level,
+    search: String,
+    /// None until the log file is read.
+    daemon_logs: Option<Result<Vec<String>, Error>>,
+    exported: Option<PathBuf>,
+    warning: Option<Error>,
+
+    view: LogsView,
+}
+
+impl LogsState {
+    pub fn new(revaultd: Arc<RevaultD>) -> Self {
+        LogsState {
+            revaultd,
+            source: LogSource::Gui,
+            level: Level::INFO,
+            search: String::new(),
+            daemon_logs: None,
+            exported: None,
+            warning: None,
+            view: LogsView::new(),
+        }
+    }
+}
+
+impl State for LogsState {
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Poll(PollMessage::Tick) => return self.load(),
+            Message::LogPanel(msg) => match msg {
+                LogPanelMessage::SourceSelected(source) => self.source = source,
+                LogPanelMessage::LevelSelected(level) => self.level = level,
+                LogPanelMessage::SearchEdited(search) => self.search = search,
+                LogPanelMessage::DaemonLogs(res) => self.daemon_logs = Some(res),
+                LogPanelMessage::Export => {
+                    self.exported = None;
+                    return Command::perform(export_logs(self.revaultd.clone()), |res| {
+                        Message::LogPanel(LogPanelMessage::Exported(res))
+                    });
+                }
+                LogPanelMessage::Exported(res) => match res {
+                    Ok(path) => {
+                        self.exported = Some(path);
+                        self.warning = None;
+                    }
+                    Err(e) => self.warning = Some(e),
+                },
+            },
+            _ => {}
+        };
+        Command::none()
+    }
+
+    fn view(&mut self, ctx: &Context) -> Element<Message> {
+        let lines = match self.source {
+            LogSource::Gui => Ok(log_bundle::gui_lines()),
+            LogSource::Daemon => match &self.daemon_logs {
+                Some(Ok(lines)) => Ok(log_bundle::daemon_lines(lines)),
+                Some(Err(e)) => Err(e),
+                None => Ok(Vec::new()),
+            },
+        }
+        .map(|lines| {
+            lines
+                .into_iter()
+                .filter(|line| line.matches(self.level, &self.search))
+                .collect()
+        });
+        self.view.view(
+            ctx,
+            self.warning.as_ref(),
+            self.source,
+            self.level,
+            &self.search,
+            lines,
+            self.exported.as_deref(),
+        )
+    }
+
+    fn load(&self) -> Command<Message> {
+        Command::perform(read_daemon_logs(self.revaultd.clone()), |res| {
+            Message::LogPanel(LogPanelMessage::DaemonLogs(res))
+        })
+    }
+}
+
+impl From<LogsState> for Box<dyn State> {
+    fn from(s: LogsState) -> Box<dyn State> {
+        Box::new(s)
+    }
+}
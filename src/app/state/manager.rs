@@ -1,28 +1,56 @@
-use bitcoin::util::psbt::PartiallySignedTransaction as Psbt;
+use bitcoin::util::{bip32::Fingerprint, psbt::PartiallySignedTransaction as Psbt};
+use bitcoin::Script;
 use std::collections::HashMap;
 use std::convert::From;
+use std::net::SocketAddr;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use chrono::Utc;
 use iced::{Command, Element};
 
 use super::{
-    cmd::{get_blockheight, get_spend_tx, list_spend_txs, list_vaults, update_spend_tx},
+    cmd::{
+        check_bitcoind_pruning, coordinator_status, estimate_fees, get_blockheight, get_spend_tx,
+        list_spend_txs, list_vaults, ping_server, update_spend_tx,
+    },
+    list_vaults_by_status,
     vault::{Vault, VaultListItem},
     State,
 };
 
 use crate::revaultd::{
     model::{self, VaultStatus},
-    RevaultD,
+    RevaultD, RevaultDError,
 };
 
+use crate::bitcoind::{BitcoindError, FeeEstimate, PruningCheck};
+use crate::conversion;
+use crate::debounce::Debouncer;
+use crate::net::ServerHealth;
+use crate::participant::{self, Participant};
 use crate::revault::TransactionKind;
 use crate::ui::component::form;
 
 use crate::app::{
+    addressbook::AddressBook,
+    attention::{self, Attention},
+    coin_control,
+    contacts::Contacts,
+    coordinator::CoordinatorStatus,
+    drafts::{self, Draft, Drafts},
     error::Error,
-    message::{InputMessage, Message, RecipientMessage, SignMessage, SpendTxMessage, VaultMessage},
+    fetch::VaultsFetch,
+    labels::Labels,
+    message::{
+        DraftMessage, InputMessage, Message, PollMessage, RecipientMessage, SignMessage,
+        SpendTxMessage, VaultMessage,
+    },
+    notes::Notes,
+    spend_checks::{Pipeline, Spend},
+    spend_hints::spend_hints,
+    spend_outputs,
     state::{sign::SignState, SpendTransactionListItem, SpendTransactionState},
     view::manager::{
         manager_send_input_view, ManagerImportTransactionView, ManagerSelectFeeView,
@@ -42,6 +70,8 @@ pub struct ManagerHomeState {
     blockheight: u64,
     warning: Option<Error>,
 
+    /// vaults of the dashboard, listed by status.
+    vaults_fetch: VaultsFetch,
     moving_vaults: Vec<VaultListItem<VaultListItemView>>,
     spendable_outpoints: HashMap<String, u64>,
     selected_vault: Option<Vault>,
@@ -49,12 +79,31 @@ pub struct ManagerHomeState {
     spend_txs: Vec<model::SpendTx>,
     spend_txs_item: Vec<SpendTransactionListItem>,
     selected_spend_tx: Option<SpendTransactionState>,
-
+    /// keys of the unvault descriptor and the fingerprint of the manager key,
+    /// to flag the spend transactions waiting for its signature.
+    participants: Vec<Participant>,
+    fingerprint: Option<Fingerprint>,
+    /// Actions expected from the manager, by urgency.
+    attention: Vec<Attention>,
+    /// Triggered notes, listed after the actions.
+    noted: Vec<Attention>,
+
+    /// true until the vaults of every status are listed a first time.
     loading_vaults: bool,
 }
 
 impl ManagerHomeState {
     pub fn new(revaultd: Arc<RevaultD>) -> Self {
+        let scripts = &revaultd.config.scripts_config;
+        let participants = participant::unvault_participants(
+            &scripts.deposit_descriptor,
+            &scripts.unvault_descriptor,
+        );
+        let fingerprint = revaultd
+            .config
+            .manager_config
+            .as_ref()
+            .map(|config| config.xpub.fingerprint());
         ManagerHomeState {
             revaultd,
             active_funds: 0,
@@ -62,12 +111,17 @@ impl ManagerHomeState {
             view: ManagerHomeView::new(),
             blockheight: 0,
             spendable_outpoints: HashMap::new(),
+            vaults_fetch: VaultsFetch::new(&VaultStatus::CURRENT),
             moving_vaults: Vec::new(),
             warning: None,
             selected_vault: None,
             spend_txs: Vec::new(),
             spend_txs_item: Vec::new(),
             selected_spend_tx: None,
+            participants,
+            fingerprint,
+            attention: Vec::new(),
+            noted: Vec::new(),
             loading_vaults: true,
         }
     }
@@ -111,9 +165,21 @@ impl ManagerHomeState {
                         s,
                     )
                 })
-                .map(|(vaults_amount, s)| SpendTransactionListItem::new(s, vaults_amount))
+                .map(|(vaults_amount, s)| {
+                    let awaits_signature = self
+                        .fingerprint
+                        .map(|f| participant::awaits_signature(&s.psbt, &self.participants, f))
+                        .unwrap_or(false);
+                    SpendTransactionListItem::new(s, vaults_amount, awaits_signature)
+                })
                 .collect()
         };
+
+        self.attention = attention::manager(
+            self.moving_vaults.iter().map(|item| &item.vault),
+            &self.spend_txs,
+        );
+        self.attention.extend(self.noted.iter().cloned());
     }
 
     pub fn on_spend_tx_select(&mut self, psbt: Psbt) -> Command<Message> {
@@ -137,7 +203,25 @@ impl ManagerHomeState {
         Command::none()
     }
 
-    pub fn update_vaults(&mut self, vaults: Vec<model::Vault>) {
+    /// Records the vaults of the status, the dashboard is rebuilt from the
+    /// vaults received so far.
+    fn receive_vaults(&mut self, status: VaultStatus, vaults: Vec<model::Vault>) {
+        if !self.vaults_fetch.receive(status, vaults) {
+            return;
+        }
+        let vaults = self.vaults_fetch.vaults().to_vec();
+        match Notes::load_datadir(&self.revaultd) {
+            Ok(mut notes) => {
+                if notes.check(&vaults, Utc::now().timestamp()) {
+                    if let Err(e) = notes.save() {
+                        self.warning = Some(e);
+                    }
+                }
+                self.noted = attention::notes(&notes.triggered(), &vaults);
+            }
+            Err(e) => self.warning = Some(e),
+        }
+
         let (active_funds, inactive_funds) =
             vaults.iter().fold((0, 0), |acc, vault| match vault.status {
                 VaultStatus::Active => (acc.0 + vault.amount, acc.1),
@@ -160,22 +244,21 @@ impl ManagerHomeState {
             })
             .collect();
 
-        self.moving_vaults = vaults
+        let moving: Vec<model::Vault> = vaults
             .into_iter()
-            .filter_map(|vlt| {
-                if vlt.status == VaultStatus::Canceling
+            .filter(|vlt| {
+                vlt.status == VaultStatus::Canceling
                     || vlt.status == VaultStatus::Spending
                     || vlt.status == VaultStatus::Unvaulting
                     || vlt.status == VaultStatus::Unvaulted
-                {
-                    Some(VaultListItem::new(vlt))
-                } else {
-                    None
-                }
             })
             .collect();
+        // The vaults of the first load are not highlighted.
+        let now = Some(Utc::now().timestamp()).filter(|_| !self.loading_vaults);
+        self.moving_vaults =
+            VaultListItem::update_list(std::mem::take(&mut self.moving_vaults), moving, now);
 
-        self.loading_vaults = false;
+        self.loading_vaults = !self.vaults_fetch.is_loaded();
 
         // The spendable outpoints changed, let's update the spend txs
         self.update_spend_txs(self.spend_txs.clone());
@@ -214,14 +297,39 @@ impl State for ManagerHomeState {
                     return tx.update(Message::SpendTx(msg));
                 }
             }
+            Message::Poll(PollMessage::Tick) => {
+                let mut cmds = vec![self.load()];
+                if let Some(selected) = &self.selected_vault {
+                    let outpoint = selected.vault.outpoint();
+                    cmds.push(
+                        selected
+                            .poll(self.revaultd.clone())
+                            .map(move |msg| Message::Vault(outpoint.clone(), msg)),
+                    );
+                }
+                return Command::batch(cmds);
+            }
             Message::SpendTransactions(res) => match res {
                 Ok(txs) => self.update_spend_txs(txs),
                 Err(e) => self.warning = Error::from(e).into(),
             },
-            Message::Vaults(res) => match res {
-                Ok(vaults) => self.update_vaults(vaults),
+            Message::VaultsOfStatus(status, res) => match res {
+                Ok(vaults) => self.receive_vaults(status, vaults),
                 Err(e) => self.warning = Error::from(e).into(),
             },
+            Message::DismissNote(id) => {
+                // The notes are reloaded, the vault panel may have saved new ones.
+                if let Err(e) = Notes::load_datadir(&self.revaultd).and_then(|mut notes| {
+                    notes.delete(id);
+                    notes.save()
+                }) {
+                    self.warning = Some(e);
+                }
+                let dismissed =
+                    |a: &Attention| matches!(a.action, Message::DismissNote(i) if i == id);
+                self.noted.retain(|a| !dismissed(a));
+                self.attention.retain(|a| !dismissed(a));
+            }
             Message::Vault(outpoint, VaultMessage::Select) => {
                 return self.on_vault_select(outpoint)
             }
@@ -259,6 +367,7 @@ impl State for ManagerHomeState {
         self.view.view(
             ctx,
             self.warning.as_ref(),
+            &self.attention,
             self.spend_txs_item
                 .iter_mut()
                 .map(|tx| tx.view(ctx).map(Message::SpendTx))
@@ -272,10 +381,7 @@ impl State for ManagerHomeState {
     fn load(&self) -> Command<Message> {
         Command::batch(vec![
             Command::perform(get_blockheight(self.revaultd.clone()), Message::BlockHeight),
-            Command::perform(
-                list_vaults(self.revaultd.clone(), Some(&VaultStatus::CURRENT), None),
-                Message::Vaults,
-            ),
+            list_vaults_by_status(&self.revaultd, self.vaults_fetch.statuses()),
             Command::perform(
                 list_spend_txs(
                     self.revaultd.clone(),
@@ -300,8 +406,8 @@ pub enum ManagerSendState {
 }
 
 impl ManagerSendState {
-    pub fn new(revaultd: Arc<RevaultD>) -> Self {
-        Self::CreateSendTransaction(ManagerCreateSendTransactionState::new(revaultd))
+    pub fn new(revaultd: Arc<RevaultD>, whitelist: Option<Vec<String>>) -> Self {
+        Self::CreateSendTransaction(ManagerCreateSendTransactionState::new(revaultd, whitelist))
     }
 }
 
@@ -406,8 +512,9 @@ impl State for ManagerImportSendTransactionState {
         Command::none()
     }
 
-    fn view(&mut self, _ctx: &Context) -> Element<Message> {
+    fn view(&mut self, ctx: &Context) -> Element<Message> {
         self.view.view(
+            ctx,
             &self.psbt_input,
             self.psbt_imported.as_ref(),
             self.warning.as_ref(),
@@ -419,6 +526,9 @@ impl State for ManagerImportSendTransactionState {
     }
 }
 
+/// Delay between two feerate estimations while the fee is selected.
+const FEE_ESTIMATES_REFRESH: Duration = Duration::from_secs(60);
+
 #[derive(Debug)]
 enum ManagerSendStep {
     WelcomeUser(ManagerSendWelcomeView),
@@ -442,31 +552,206 @@ pub struct ManagerCreateSendTransactionState {
     outputs: Vec<ManagerSendOutput>,
     feerate: Option<u32>,
     psbt: Option<(Psbt, u32)>,
+    /// spend transactions known by revaultd, used by the destination hints.
+    spend_history: Vec<model::SpendTx>,
     processing: bool,
     valid_feerate: bool,
+    /// last feerate estimation, refreshed while the fee is selected.
+    fee_estimates: Option<Result<Vec<FeeEstimate>, BitcoindError>>,
+    /// true if a feerate estimation is pending.
+    estimating_fees: bool,
+    /// time of the last feerate estimation.
+    fees_estimated_at: Option<Instant>,
+    /// checks of the transaction before the signature.
+    checks: Pipeline,
+    /// keys of the unvault descriptor, the managers sign the spend.
+    participants: Vec<Participant>,
+    /// destinations suggested in the recipient fields.
+    address_book: AddressBook,
+    /// true if the outputs are shuffled instead of following the recipients.
+    shuffle_outputs: bool,
+    /// weight of a vault spent by the transaction, None if the unvault
+    /// descriptor cannot be parsed.
+    input_weight: Option<usize>,
+
+    drafts: Drafts,
+    /// draft of the transaction in creation, saved after every change.
+    draft: Draft,
+    /// the edits of the fields are saved once the user stopped typing.
+    draft_debounce: Debouncer,
+    /// drafts saved on disk, listed in the welcome step.
+    saved_drafts: Vec<Draft>,
 
     step: ManagerSendStep,
 }
 
 impl ManagerCreateSendTransactionState {
-    pub fn new(revaultd: Arc<RevaultD>) -> Self {
+    pub fn new(revaultd: Arc<RevaultD>, whitelist: Option<Vec<String>>) -> Self {
+        let scripts = &revaultd.config.scripts_config;
+        let participants = participant::unvault_participants(
+            &scripts.deposit_descriptor,
+            &scripts.unvault_descriptor,
+        );
+        let input_weight = coin_control::input_weight(&scripts.unvault_descriptor);
+        let checks = Pipeline::new(whitelist, &scripts.unvault_descriptor);
+        let drafts = revaultd
+            .config
+            .network_datadir()
+            .map(|path| Drafts::new(path.join(drafts::DEFAULT_DIR_NAME)));
+        let (drafts, warning) = match drafts {
+            Ok(drafts) => (drafts, None),
+            Err(e) => (Drafts::default(), Some(Error::from(e))),
+        };
+        let (saved_drafts, warning) = match drafts.list() {
+            Ok(list) => (list, warning),
+            Err(e) => (Vec::new(), Some(e)),
+        };
+        let address_book = AddressBook::load_datadir(&revaultd).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load the address book: {}", e);
+            AddressBook::default()
+        });
         Self {
             revaultd,
             step: ManagerSendStep::WelcomeUser(ManagerSendWelcomeView::new()),
-            warning: None,
+            warning,
+            drafts,
+            draft: Draft::new(Utc::now().timestamp()),
+            draft_debounce: Debouncer::default(),
+            saved_drafts,
             vaults: Vec::new(),
             outputs: vec![ManagerSendOutput::new()],
             feerate: None,
             psbt: None,
+            spend_history: Vec::new(),
             processing: false,
             valid_feerate: false,
+            fee_estimates: None,
+            estimating_fees: false,
+            fees_estimated_at: None,
+            checks,
+            participants,
+            address_book,
+            shuffle_outputs: false,
+            input_weight,
         }
     }
 
+    /// Merges the recipients of a same address in the first one, with the
+    /// sum of their amounts.
+    fn merge_recipients(&mut self) {
+        let mut merged: Vec<ManagerSendOutput> = Vec::new();
+        for output in self.outputs.drain(..) {
+            let address = output.address.value.clone();
+            let first = merged
+                .iter_mut()
+                .find(|o| !address.is_empty() && o.address.value == address);
+            match (first, output.amount()) {
+                (Some(first), Ok(amount)) => {
+                    let total = first.amount().unwrap_or(0) + amount;
+                    first.update(RecipientMessage::AmountEdited(
+                        conversion::Converter::btc_string(total),
+                    ));
+                }
+                _ => merged.push(output),
+            }
+        }
+        self.outputs = merged;
+    }
+
+    /// Saves the current state of the transaction in its draft.
+    fn save_draft(&mut self) {
+        self.draft.outputs = self
+            .outputs
+            .iter()
+            .map(|output| (output.address.value.clone(), output.amount.value.clone()))
+            .collect();
+        self.draft.inputs = self
+            .selected_inputs()
+            .iter()
+            .map(|vault| vault.outpoint())
+            .collect();
+        self.draft.feerate = self.feerate;
+        self.draft.set_psbt(self.psbt.as_ref());
+        if self.draft.is_empty() {
+            return;
+        }
+        self.draft.updated_at = Utc::now().timestamp();
+        if let Err(e) = self.drafts.save(&self.draft) {
+            self.warning = Some(e);
+        }
+    }
+
+    /// Saves the draft once the user stopped typing, instead of writing
+    /// the file on every keystroke.
+    fn save_draft_debounced(&mut self) -> Command<Message> {
+        self.draft_debounce
+            .trigger(|id| Message::Draft(DraftMessage::SaveDebounced(id)))
+    }
+
+    /// Restores the transaction of a saved draft and continues its edition.
+    fn resume_draft(&mut self, id: i64) {
+        let draft = match self.saved_drafts.iter().find(|draft| draft.id == id) {
+            Some(draft) => draft.clone(),
+            None => return,
+        };
+        self.outputs = draft
+            .outputs
+            .iter()
+            .map(|(address, amount)| {
+                let mut output = ManagerSendOutput::new();
+                output.update(RecipientMessage::AddressEdited(address.clone()));
+                output.update(RecipientMessage::AmountEdited(amount.clone()));
+                output
+            })
+            .collect();
+        if self.outputs.is_empty() {
+            self.outputs.push(ManagerSendOutput::new());
+        }
+        for input in &mut self.vaults {
+            input.selected = draft.inputs.contains(&input.vault.outpoint());
+        }
+        self.feerate = draft.feerate;
+        self.valid_feerate = draft.feerate.is_some();
+        self.psbt = draft.psbt();
+        self.draft = draft;
+        self.step = ManagerSendStep::SelectOutputs(ManagerSelectOutputsView::new());
+    }
+
+    /// Starts a feerate estimation, the estimation is refreshed by the poll
+    /// ticks as long as the fee is selected.
+    fn estimate_fees(&mut self) -> Command<Message> {
+        self.estimating_fees = true;
+        Command::perform(estimate_fees(self.revaultd.clone()), Message::FeeEstimates)
+    }
+
+    /// Returns true if the fee is selected and the last estimation is older
+    /// than the refresh delay.
+    fn fee_estimates_due(&self) -> bool {
+        matches!(self.step, ManagerSendStep::SelectFee(_))
+            && !self.estimating_fees
+            && self
+                .fees_estimated_at
+                .map(|at| at.elapsed() >= FEE_ESTIMATES_REFRESH)
+                .unwrap_or(true)
+    }
+
     pub fn update_vaults(&mut self, mut vaults: Vec<model::Vault>) {
         // Ordering the vaults, the biggest amounts first
         vaults.sort_by(|a, b| b.amount.partial_cmp(&a.amount).unwrap());
-        self.vaults = vaults.into_iter().map(ManagerSendInput::new).collect();
+        let labels = Labels::load_datadir(&self.revaultd).unwrap_or_else(|e| {
+            self.warning = Some(e);
+            Labels::default()
+        });
+        self.vaults = vaults
+            .into_iter()
+            .map(|vault| {
+                let label = labels.get(&vault.outpoint()).cloned();
+                ManagerSendInput::new(vault, label)
+            })
+            .collect();
+        for input in &mut self.vaults {
+            input.selected = self.draft.inputs.contains(&input.vault.outpoint());
+        }
     }
 
     pub fn input_amount(&self) -> u64 {
@@ -511,7 +796,20 @@ impl State for ManagerCreateSendTransactionState {
                 self.processing = false;
                 match res {
                     Ok(tx) => {
-                        self.psbt = Some((tx.spend_tx, tx.feerate));
+                        let mut psbt = tx.spend_tx;
+                        let destinations: Vec<Script> = self
+                            .outputs
+                            .iter()
+                            .filter_map(|output| {
+                                bitcoin::Address::from_str(&output.address.value).ok()
+                            })
+                            .map(|address| address.script_pubkey())
+                            .collect();
+                        if !spend_outputs::order(&mut psbt, &destinations, self.shuffle_outputs) {
+                            tracing::warn!("Failed to shuffle the outputs of the spend");
+                        }
+                        self.psbt = Some((psbt, tx.feerate));
+                        self.save_draft();
                     }
                     Err(e) => self.warning = Some(Error::RevaultDError(e)),
                 }
@@ -543,18 +841,31 @@ impl State for ManagerCreateSendTransactionState {
                 );
             }
             Message::SpendTx(SpendTxMessage::FeerateEdited(feerate)) => {
-                if let Ok(f) = feerate.parse::<u32>() {
+                if let Some(f) = conversion::parse_integer::<u32>(&feerate) {
                     self.feerate = Some(f);
                     self.valid_feerate = true;
                 } else if feerate.is_empty() {
                     self.feerate = None;
                     self.valid_feerate = false;
                 }
+                return self.save_draft_debounced();
+            }
+            Message::FeeEstimates(res) => {
+                self.estimating_fees = false;
+                self.fees_estimated_at = Some(Instant::now());
+                self.fee_estimates = Some(res);
+            }
+            Message::Poll(PollMessage::Tick) if self.fee_estimates_due() => {
+                return self.estimate_fees();
             }
             Message::Vaults(res) => match res {
                 Ok(vlts) => self.update_vaults(vlts),
                 Err(e) => self.warning = Some(Error::RevaultDError(e)),
             },
+            Message::SpendTransactions(res) => match res {
+                Ok(txs) => self.spend_history = txs,
+                Err(e) => self.warning = Some(Error::RevaultDError(e)),
+            },
             Message::SpendTx(SpendTxMessage::Signed(res)) => match res {
                 Ok(_) => {
                     if let ManagerSendStep::Sign { signer, .. } = &mut self.step {
@@ -567,6 +878,10 @@ impl State for ManagerCreateSendTransactionState {
                         signer.update(SignMessage::Success);
                         self.step =
                             ManagerSendStep::Success(ManagerSpendTransactionCreatedView::new());
+                        // revaultd now stores the transaction.
+                        if let Err(e) = self.drafts.delete(self.draft.id) {
+                            self.warning = Some(e);
+                        }
                     };
                 }
                 Err(e) => self.warning = Some(Error::RevaultDError(e)),
@@ -590,6 +905,7 @@ impl State for ManagerCreateSendTransactionState {
                 }
                 ManagerSendStep::SelectOutputs(_) => {
                     self.step = ManagerSendStep::SelectFee(ManagerSelectFeeView::new());
+                    return self.estimate_fees();
                 }
                 ManagerSendStep::SelectInputs(_) => {
                     if let Some((psbt, _)) = &self.psbt {
@@ -616,23 +932,84 @@ impl State for ManagerCreateSendTransactionState {
                         ManagerSendStep::SelectInputs(ManagerSelectInputsView::new())
                     }
                     _ => ManagerSendStep::SelectOutputs(ManagerSelectOutputsView::new()),
+                };
+                if let ManagerSendStep::SelectFee(_) = self.step {
+                    return self.estimate_fees();
                 }
             }
             Message::AddRecipient => self.outputs.push(ManagerSendOutput::new()),
+            Message::MergeRecipients => {
+                self.psbt = None;
+                self.merge_recipients();
+                self.save_draft();
+            }
+            Message::ShuffleOutputs(shuffle) => {
+                self.psbt = None;
+                self.shuffle_outputs = shuffle;
+            }
             Message::Recipient(i, RecipientMessage::Delete) => {
                 self.outputs.remove(i);
+                self.save_draft();
+            }
+            Message::Recipient(i, RecipientMessage::MoveUp) if i > 0 => {
+                self.psbt = None;
+                self.outputs.swap(i - 1, i);
+                self.save_draft();
+            }
+            Message::Recipient(i, RecipientMessage::MoveDown) if i + 1 < self.outputs.len() => {
+                self.psbt = None;
+                self.outputs.swap(i, i + 1);
+                self.save_draft();
+            }
+            Message::AutoSelectInputs => {
+                self.psbt = None;
+                match coin_control::auto_select(
+                    &self
+                        .vaults
+                        .iter()
+                        .map(|input| input.vault.clone())
+                        .collect::<Vec<_>>(),
+                    self.output_amount(),
+                ) {
+                    Some(selection) => {
+                        for input in &mut self.vaults {
+                            input.selected = selection.contains(&input.vault.outpoint());
+                        }
+                    }
+                    None => {
+                        self.warning = Some(Error::UnexpectedError(
+                            "The active vaults are not enough to fund the recipients".to_string(),
+                        ))
+                    }
+                }
+                self.save_draft();
             }
             Message::Input(i, msg) => {
                 self.psbt = None;
                 if let Some(input) = self.vaults.get_mut(i) {
                     input.update(msg);
                 }
+                self.save_draft();
             }
             Message::Recipient(i, msg) => {
                 self.psbt = None;
                 if let Some(output) = self.outputs.get_mut(i) {
                     output.update(msg);
                 }
+                return self.save_draft_debounced();
+            }
+            Message::Draft(DraftMessage::SaveDebounced(id))
+                if self.draft_debounce.is_due(id)
+                    && !matches!(self.step, ManagerSendStep::Success(_)) =>
+            {
+                self.save_draft()
+            }
+            Message::Draft(DraftMessage::Resume(id)) => self.resume_draft(id),
+            Message::Draft(DraftMessage::Delete(id)) => {
+                match self.drafts.delete(id).and_then(|_| self.drafts.list()) {
+                    Ok(list) => self.saved_drafts = list,
+                    Err(e) => self.warning = Some(e),
+                }
             }
             _ => {}
         };
@@ -643,17 +1020,43 @@ impl State for ManagerCreateSendTransactionState {
         let selected_inputs = self.selected_inputs();
         let input_amount = self.input_amount();
         let output_amount = self.output_amount();
+        let selected = selected_inputs.len();
+        // The change and the CPFP outputs are added by revaultd.
+        let max_inputs = self
+            .input_weight
+            .map(|weight| coin_control::max_inputs(weight, self.outputs.len() + 2));
         match &mut self.step {
-            ManagerSendStep::WelcomeUser(v) => v.view(),
+            ManagerSendStep::WelcomeUser(v) => {
+                v.view(ctx, &self.saved_drafts, self.warning.as_ref())
+            }
             ManagerSendStep::SelectOutputs(v) => {
-                let valid = !self.outputs.is_empty() && !self.outputs.iter().any(|o| !o.valid());
+                let duplicates: Vec<String> = spend_outputs::duplicates(
+                    self.outputs
+                        .iter()
+                        .filter(|o| !o.address.value.is_empty())
+                        .map(|o| o.address.value.as_str()),
+                )
+                .into_iter()
+                .map(String::from)
+                .collect();
+                let valid = !self.outputs.is_empty()
+                    && !self.outputs.iter().any(|o| !o.valid())
+                    && duplicates.is_empty();
+                let address_book = &self.address_book;
+                let last = self.outputs.len().saturating_sub(1);
                 v.view(
+                    ctx,
                     self.outputs
                         .iter_mut()
                         .enumerate()
-                        .map(|(i, v)| v.view().map(move |msg| Message::Recipient(i, msg)))
+                        .map(|(i, v)| {
+                            v.view(ctx, address_book, i == 0, i == last)
+                                .map(move |msg| Message::Recipient(i, msg))
+                        })
                         .collect(),
                     valid,
+                    &duplicates,
+                    self.shuffle_outputs,
                 )
             }
             ManagerSendStep::SelectInputs(v) => v.view(
@@ -665,18 +1068,42 @@ impl State for ManagerCreateSendTransactionState {
                     .collect(),
                 input_amount,
                 output_amount,
+                selected,
+                max_inputs,
+                self.warning.as_ref(),
+            ),
+            ManagerSendStep::SelectFee(v) => v.view(
+                ctx,
+                self.feerate,
+                self.valid_feerate,
+                self.fee_estimates.as_ref(),
                 self.warning.as_ref(),
             ),
-            ManagerSendStep::SelectFee(v) => {
-                v.view(self.feerate, self.valid_feerate, self.warning.as_ref())
-            }
             ManagerSendStep::Sign { signer, view } => {
                 let (psbt, feerate) = self.psbt.as_ref().unwrap();
+                let recipients: Vec<String> = self
+                    .outputs
+                    .iter()
+                    .map(|output| output.address.value.clone())
+                    .collect();
+                let checks = self.checks.run(&Spend {
+                    psbt,
+                    feerate: *feerate,
+                    inputs: &selected_inputs,
+                    recipients: &recipients,
+                    fee_estimates: match &self.fee_estimates {
+                        Some(Ok(estimates)) => estimates,
+                        _ => &[],
+                    },
+                    participants: &self.participants,
+                });
                 view.view(
                     ctx,
                     &selected_inputs,
                     &psbt,
                     &feerate,
+                    &spend_hints(psbt, ctx.network, &self.spend_history),
+                    &checks,
                     self.warning.as_ref(),
                     signer
                         .view(ctx)
@@ -685,16 +1112,28 @@ impl State for ManagerCreateSendTransactionState {
             }
             ManagerSendStep::Success(v) => {
                 let (psbt, _) = self.psbt.as_ref().unwrap();
-                v.view(ctx, &selected_inputs, &psbt, &self.feerate.unwrap())
+                v.view(
+                    ctx,
+                    &selected_inputs,
+                    &psbt,
+                    &self.feerate.unwrap(),
+                    &spend_hints(psbt, ctx.network, &self.spend_history),
+                )
             }
         }
     }
 
     fn load(&self) -> Command<Message> {
-        Command::batch(vec![Command::perform(
-            list_vaults(self.revaultd.clone(), Some(&[VaultStatus::Active]), None),
-            Message::Vaults,
-        )])
+        Command::batch(vec![
+            Command::perform(
+                list_vaults(self.revaultd.clone(), Some(&[VaultStatus::Active]), None),
+                Message::Vaults,
+            ),
+            Command::perform(
+                list_spend_txs(self.revaultd.clone(), None),
+                Message::SpendTransactions,
+            ),
+        ])
     }
 }
 
@@ -728,9 +1167,8 @@ impl ManagerSendOutput {
             ));
         }
 
-        let amount =
-            bitcoin::Amount::from_str_in(&self.amount.value, bitcoin::Denomination::Bitcoin)
-                .map_err(|_| Error::UnexpectedError("cannot parse output amount".to_string()))?;
+        let amount = conversion::parse_btc(&self.amount.value)
+            .ok_or_else(|| Error::UnexpectedError("cannot parse output amount".to_string()))?;
 
         if amount.as_sat() == 0 {
             return Err(Error::UnexpectedError(
@@ -769,21 +1207,44 @@ impl ManagerSendOutput {
         };
     }
 
-    fn view(&mut self) -> Element<RecipientMessage> {
-        self.view.view(&self.address, &self.amount)
+    /// The first and the last recipients cannot be moved up and down.
+    fn view(
+        &mut self,
+        ctx: &Context,
+        address_book: &AddressBook,
+        first: bool,
+        last: bool,
+    ) -> Element<RecipientMessage> {
+        // The amount is reformatted once the user leaves the field.
+        if self.amount.valid && !self.view.amount_focused() {
+            if let Some(amount) = conversion::normalize_number(&self.amount.value) {
+                self.amount.value = amount;
+            }
+        }
+        self.view.view(
+            ctx,
+            &self.address,
+            &self.amount,
+            address_book.label(&self.address.value),
+            &address_book.suggestions(&self.address.value),
+            first,
+            last,
+        )
     }
 }
 
 #[derive(Debug, Clone)]
 struct ManagerSendInput {
     vault: model::Vault,
+    label: Option<String>,
     selected: bool,
 }
 
 impl ManagerSendInput {
-    fn new(vault: model::Vault) -> Self {
+    fn new(vault: model::Vault, label: Option<String>) -> Self {
         Self {
             vault,
+            label,
             selected: false,
         }
     }
@@ -793,6 +1254,8 @@ impl ManagerSendInput {
             ctx,
             &self.vault.outpoint(),
             &self.vault.amount,
+            self.vault.received_at,
+            self.label.as_deref(),
             self.selected,
         )
     }
@@ -809,20 +1272,58 @@ pub struct ManagerNetworkState {
     revaultd: Arc<RevaultD>,
 
     blockheight: Option<u64>,
+    pruning: Option<Result<PruningCheck, BitcoindError>>,
+    cosigners: Vec<ServerHealth>,
+    coordinator: Option<Result<CoordinatorStatus, RevaultDError>>,
+    contacts: Contacts,
     warning: Option<Error>,
+    /// SOCKS5 proxy of the health checks, from the GUI configuration.
+    health_check_proxy: Option<SocketAddr>,
 
     view: ManagerNetworkView,
 }
 
 impl ManagerNetworkState {
-    pub fn new(revaultd: Arc<RevaultD>) -> Self {
+    pub fn new(revaultd: Arc<RevaultD>, health_check_proxy: Option<SocketAddr>) -> Self {
+        let cosigners = revaultd
+            .config
+            .manager_config
+            .as_ref()
+            .map(|config| {
+                config
+                    .cosigners
+                    .iter()
+                    .map(|cosigner| ServerHealth::new(cosigner.host.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut warning = None;
+        let contacts = Contacts::load_datadir(&revaultd).unwrap_or_else(|e| {
+            warning = Some(e);
+            Contacts::default()
+        });
         ManagerNetworkState {
             revaultd,
             blockheight: None,
-            warning: None,
+            pruning: None,
+            cosigners,
+            coordinator: None,
+            contacts,
+            warning,
+            health_check_proxy,
             view: ManagerNetworkView::new(),
         }
     }
+
+    fn ping_cosigners(&self) -> Command<Message> {
+        let proxy = self.health_check_proxy;
+        Command::batch(self.cosigners.iter().map(|cosigner| {
+            let host = cosigner.host.clone();
+            Command::perform(ping_server(host.clone(), proxy), move |res| {
+                Message::ServerPinged(host.clone(), res)
+            })
+        }))
+    }
 }
 
 impl State for ManagerNetworkState {
@@ -839,20 +1340,53 @@ impl State for ManagerNetworkState {
                 };
                 Command::none()
             }
+            Message::BitcoindPruning(res) => {
+                self.pruning = Some(res);
+                Command::none()
+            }
+            Message::CoordinatorStatus(res) => {
+                self.coordinator = Some(res);
+                Command::none()
+            }
+            // The cosigners are checked again with the block height.
+            Message::Poll(PollMessage::Tick) => self.load(),
+            Message::PingServers => self.ping_cosigners(),
+            Message::ServerPinged(host, res) => {
+                let now = Utc::now().timestamp();
+                for cosigner in self.cosigners.iter_mut().filter(|c| c.host == host) {
+                    cosigner.record(res.clone(), now);
+                }
+                Command::none()
+            }
             _ => Command::none(),
         }
     }
 
     fn view(&mut self, ctx: &Context) -> Element<Message> {
-        self.view
-            .view(ctx, self.warning.as_ref(), self.blockheight.as_ref())
+        self.view.view(
+            ctx,
+            self.warning.as_ref(),
+            self.blockheight.as_ref(),
+            self.pruning.as_ref(),
+            self.coordinator.as_ref(),
+            &self.cosigners,
+            &self.contacts,
+        )
     }
 
     fn load(&self) -> Command<Message> {
-        Command::batch(vec![Command::perform(
-            get_blockheight(self.revaultd.clone()),
-            Message::BlockHeight,
-        )])
+        Command::batch(vec![
+            Command::perform(get_blockheight(self.revaultd.clone()), Message::BlockHeight),
+            Command::perform(
+                check_bitcoind_pruning(self.revaultd.clone()),
+                Message::BitcoindPruning,
+            ),
+            Command::perform(
+                coordinator_status(self.revaultd.clone(), self.health_check_proxy),
+                Message::CoordinatorStatus,
+            ),
+            self.ping_cosigners(),
+        ])
     }
 }
 
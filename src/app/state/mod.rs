@@ -1,8 +1,11 @@
 pub mod charging;
 mod cmd;
+mod console;
 mod deposit;
 mod emergency;
+mod logs;
 pub mod manager;
+mod secure_batch;
 mod settings;
 mod sign;
 mod spend_transaction;
@@ -10,11 +13,19 @@ pub mod stakeholder;
 mod vault;
 mod vaults;
 
+use std::sync::Arc;
+
 use iced::{Command, Element, Subscription};
 
 pub use charging::ChargingState;
+pub use cmd::{
+    auto_backup, check_alerts, check_daemon, fetch_rates, list_vaults, revault, send_report,
+    stop_daemon,
+};
+pub use console::ConsoleState;
 pub use deposit::DepositState;
 pub use emergency::EmergencyState;
+pub use logs::LogsState;
 pub use manager::{ManagerHomeState, ManagerNetworkState, ManagerSendState};
 pub use settings::SettingsState;
 pub use spend_transaction::{SpendTransactionListItem, SpendTransactionState};
@@ -25,6 +36,18 @@ pub use stakeholder::{
 pub use vaults::VaultsState;
 
 use super::{message::Message, view::Context};
+use crate::revaultd::{model::VaultStatus, RevaultD};
+
+/// Lists the vaults with one call per status, the calls made at the same
+/// time. See fetch::VaultsFetch.
+fn list_vaults_by_status(revaultd: &Arc<RevaultD>, statuses: &[VaultStatus]) -> Command<Message> {
+    Command::batch(statuses.iter().cloned().map(|status| {
+        Command::perform(
+            cmd::list_vaults_of_status(revaultd.clone(), status.clone()),
+            move |res| Message::VaultsOfStatus(status.clone(), res),
+        )
+    }))
+}
 
 pub trait State {
     fn view(&mut self, ctx: &Context) -> Element<Message>;
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use bitcoin::{util::psbt::PartiallySignedTransaction as Psbt, PublicKey};
+use iced::{Command, Element};
+
+use crate::{
+    app::{
+        actions::{self, ActionKind},
+        message::{Message, SecureBatchMessage, SignMessage},
+        state::{cmd::set_revocation_txs_batch, sign::SignState},
+        view::{Context, SecureBatchView},
+    },
+    revault::TransactionKind,
+    revaultd::{model::RevocationTransactions, RevaultD, RevaultDError},
+};
+
+/// BatchVault is a deposit of the batch with its revocation transactions,
+/// the boolean of a transaction is true once it is signed.
+#[derive(Debug)]
+struct BatchVault {
+    outpoint: String,
+    emergency_tx: (Psbt, bool),
+    emergency_unvault_tx: (Psbt, bool),
+    cancel_tx: (Psbt, bool),
+    /// Key of the stakeholder signing the transactions.
+    signer_key: Option<PublicKey>,
+}
+
+impl BatchVault {
+    fn signer(&self, kind: TransactionKind) -> SignState {
+        let psbt = match kind {
+            TransactionKind::EmergencyUnvault => &self.emergency_unvault_tx.0,
+            TransactionKind::Cancel => &self.cancel_tx.0,
+            _ => &self.emergency_tx.0,
+        };
+        SignState::new(psbt.clone(), kind).verified_by(self.signer_key)
+    }
+
+    fn signed(&self) -> usize {
+        [
+            &self.emergency_tx,
+            &self.emergency_unvault_tx,
+            &self.cancel_tx,
+        ]
+        .iter()
+        .filter(|(_, signed)| *signed)
+        .count()
+    }
+}
+
+/// SecureBatch signs the revocation transactions of several deposits
+/// one vault after the other, then shares them all with revaultd at once.
+#[derive(Debug)]
+pub struct SecureBatch {
+    vaults: Vec<BatchVault>,
+    /// Index of the vault being signed.
+    current: usize,
+    /// None once every transaction of the batch is signed.
+    signer: Option<SignState>,
+    pushing: bool,
+    results: Option<Vec<(String, Result<(), RevaultDError>)>>,
+    view: SecureBatchView,
+}
+
+impl SecureBatch {
+    /// The signatures of the transactions of a vault are checked against
+    /// its key, if any.
+    pub fn new(
+        txs: Vec<(String, RevocationTransactions)>,
+        keys: HashMap<String, PublicKey>,
+    ) -> Self {
+        let vaults: Vec<BatchVault> = txs
+            .into_iter()
+            .map(|(outpoint, txs)| BatchVault {
+                signer_key: keys.get(&outpoint).copied(),
+                outpoint,
+                emergency_tx: (txs.emergency_tx, false),
+                emergency_unvault_tx: (txs.emergency_unvault_tx, false),
+                cancel_tx: (txs.cancel_tx, false),
+            })
+            .collect();
+        let signer = vaults
+            .first()
+            .map(|vault| vault.signer(TransactionKind::Emergency));
+        SecureBatch {
+            vaults,
+            current: 0,
+            signer,
+            pushing: false,
+            results: None,
+            view: SecureBatchView::new(),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        revaultd: Arc<RevaultD>,
+        message: SecureBatchMessage,
+    ) -> Command<SecureBatchMessage> {
+        match message {
+            SecureBatchMessage::Sign(msg) => self.on_sign(msg),
+            SecureBatchMessage::Push if self.signer.is_none() && !self.pushing => {
+                self.pushing = true;
+                return Command::perform(
+                    set_revocation_txs_batch(
+                        revaultd,
+                        self.vaults
+                            .iter()
+                            .map(|v| {
+                                (
+                                    v.outpoint.clone(),
+                                    v.emergency_tx.0.clone(),
+                                    v.emergency_unvault_tx.0.clone(),
+                                    v.cancel_tx.0.clone(),
+                                )
+                            })
+                            .collect(),
+                    ),
+                    SecureBatchMessage::Pushed,
+                );
+            }
+            SecureBatchMessage::Pushed(results) => {
+                self.pushing = false;
+                actions::record(
+                    &revaultd,
+                    results
+                        .iter()
+                        .filter(|(_, res)| res.is_ok())
+                        .map(|(outpoint, _)| outpoint),
+                    ActionKind::Secured,
+                );
+                self.results = Some(results);
+            }
+            _ => {}
+        };
+        Command::none()
+    }
+
+    /// Stores the signed transaction and moves the signer to the next
+    /// transaction of the vault, or to the first one of the next vault.
+    fn on_sign(&mut self, msg: SignMessage) {
+        let signer = match &mut self.signer {
+            Some(signer) => signer,
+            None => return,
+        };
+        signer.update(msg);
+        let psbt = match &signer.signed_psbt {
+            Some(psbt) => psbt.clone(),
+            None => return,
+        };
+        let vault = &mut self.vaults[self.current];
+        match signer.transaction_kind {
+            TransactionKind::Emergency => {
+                vault.emergency_tx = (psbt, true);
+                *signer = vault.signer(TransactionKind::EmergencyUnvault);
+            }
+            TransactionKind::EmergencyUnvault => {
+                vault.emergency_unvault_tx = (psbt, true);
+                *signer = vault.signer(TransactionKind::Cancel);
+            }
+            TransactionKind::Cancel => {
+                vault.cancel_tx = (psbt, true);
+                self.current += 1;
+                self.signer = self
+                    .vaults
+                    .get(self.current)
+                    .map(|next| next.signer(TransactionKind::Emergency));
+            }
+            _ => {}
+        }
+    }
+
+    pub fn view<'a>(&'a mut self, ctx: &Context) -> Element<'a, Message> {
+        let vaults: Vec<(String, usize)> = self
+            .vaults
+            .iter()
+            .map(|v| (v.outpoint.clone(), v.signed()))
+            .collect();
+        let transaction_kind = self.signer.as_ref().map(|s| s.transaction_kind);
+        let signer = self
+            .signer
+            .as_mut()
+            .map(|s| s.view(ctx).map(SecureBatchMessage::Sign));
+        self.view
+            .view(
+                ctx,
+                &vaults,
+                self.current,
+                transaction_kind,
+                signer,
+                self.pushing,
+                self.results.as_ref(),
+            )
+            .map(Message::SecureBatch)
+    }
+}
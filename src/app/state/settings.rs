@@ -1,14 +1,38 @@
 use std::convert::From;
+use std::path::PathBuf;
+use std::sync::Arc;
 
+use chrono::Utc;
 use iced::{Command, Element};
 
-use super::State;
+use super::{
+    cmd::{check_daemon, send_report, stop_daemon, test_bitcoind},
+    State,
+};
 
-use crate::revaultd::config::Config;
+use crate::{
+    bitcoind::{BitcoindError, NodeReport},
+    logs, noise,
+    revaultd::{config::Config, RevaultD, RevaultDError},
+};
 
 use crate::app::{
+    addressbook::AddressBook,
+    backup::{Backup, Backups},
+    connection::Instance,
+    contacts::Contacts,
+    daemon_config::ConfigEdit,
     error::Error,
-    message::Message,
+    labels::Labels,
+    message::{
+        AddressBookMessage, BackupMessage, ConfigEditMessage, ContactMessage, DaemonMessage,
+        LogsMessage, Message, MetadataMessage, NoiseKeyMessage, PollMessage, ReportMessage,
+    },
+    metadata::{self, Transfer},
+    metrics::Metrics,
+    notes::Notes,
+    notifications::NotificationSettings,
+    report::Report,
     view::{Context, SettingsView},
 };
 
@@ -16,27 +40,393 @@ use crate::app::{
 pub struct SettingsState {
     view: SettingsView,
     warning: Option<Error>,
+    revaultd: Arc<RevaultD>,
     config: Config,
+    config_path: PathBuf,
+    /// Some while the user edits the connection settings.
+    config_edit: Option<ConfigEdit>,
+    /// true once the configuration is written, until revaultd is stopped.
+    restarting: bool,
+    /// true while the connection to bitcoind is tested.
+    bitcoind_testing: bool,
+    /// Result of the last connection test of bitcoind.
+    bitcoind_test: Option<Result<NodeReport, BitcoindError>>,
+    /// None until the status of revaultd is checked.
+    daemon: Option<Result<Instance, RevaultDError>>,
+    /// Some if the user opted in the local metrics.
+    metrics: Option<Metrics>,
+    /// Some if the user set a backup directory.
+    backups: Option<Backups>,
+    backup_list: Vec<Backup>,
+    /// date of the last restored backup.
+    restored: Option<i64>,
+    /// export and import of the labels and notes.
+    transfer: Transfer,
+    /// operators of the coordinator and the watchtowers.
+    contacts: Contacts,
+    contacts_saved: bool,
+    /// settings of the report mailed to the team.
+    report: Report,
+    report_saved: bool,
+    /// true until the report sent on demand is sent or failed.
+    report_sending: bool,
+    /// events notified on the desktop.
+    notifications: NotificationSettings,
+    /// spend destinations of the managers.
+    address_book: AddressBook,
+    /// label and address of the new entry of the address book.
+    address_book_entry: (String, String),
+    /// descriptor pasted by the user to check its checksum.
+    descriptor_check: String,
+    /// None if the datadir of the daemon could not be found.
+    noise_key: Option<noise::KeyFile>,
 }
 
 impl SettingsState {
-    pub fn new(config: Config) -> Self {
-        SettingsState {
+    pub fn new(
+        revaultd: Arc<RevaultD>,
+        config_path: PathBuf,
+        metrics: Option<Metrics>,
+        backups: Option<Backups>,
+    ) -> Self {
+        let mut state = SettingsState {
             view: SettingsView::new(),
-            config,
+            config: revaultd.config.clone(),
+            revaultd,
+            config_path,
+            config_edit: None,
+            restarting: false,
+            bitcoind_testing: false,
+            bitcoind_test: None,
+            daemon: None,
+            metrics,
+            backups,
+            backup_list: Vec::new(),
+            restored: None,
+            transfer: Transfer::default(),
+            contacts: Contacts::default(),
+            contacts_saved: false,
+            report: Report::default(),
+            report_saved: false,
+            report_sending: false,
+            notifications: NotificationSettings::default(),
+            address_book: AddressBook::default(),
+            address_book_entry: (String::new(), String::new()),
+            descriptor_check: String::new(),
+            noise_key: None,
             warning: None,
+        };
+        match Contacts::load_datadir(&state.revaultd) {
+            Ok(contacts) => state.contacts = contacts,
+            Err(e) => state.warning = Some(e),
+        }
+        match Report::load_datadir(&state.revaultd) {
+            Ok(report) => state.report = report,
+            Err(e) => state.warning = Some(e),
+        }
+        match NotificationSettings::load_datadir(&state.revaultd) {
+            Ok(notifications) => state.notifications = notifications,
+            Err(e) => state.warning = Some(e),
+        }
+        match AddressBook::load_datadir(&state.revaultd) {
+            Ok(book) => state.address_book = book,
+            Err(e) => state.warning = Some(e),
+        }
+        state.refresh_backups();
+        state.refresh_noise_key();
+        state
+    }
+
+    fn refresh_backups(&mut self) {
+        if let Some(backups) = &self.backups {
+            match backups.list() {
+                Ok(list) => self.backup_list = list,
+                Err(e) => self.warning = Some(e),
+            }
+        }
+    }
+
+    fn refresh_noise_key(&mut self) {
+        self.noise_key = self
+            .config
+            .network_datadir()
+            .ok()
+            .map(|dir| noise::KeyFile::inspect(dir.join(noise::KEY_FILE_NAME)));
+    }
+
+    fn on_report_message(&mut self, msg: ReportMessage) -> Command<Message> {
+        match msg {
+            ReportMessage::Edited(field, value) => {
+                self.report_saved = false;
+                self.report.settings.set(field, value);
+            }
+            ReportMessage::Enable(enabled) => {
+                self.report_saved = false;
+                self.report.settings.enabled = enabled;
+            }
+            ReportMessage::Save => {
+                self.warning = self.report.save().err();
+                self.report_saved = self.warning.is_none();
+            }
+            ReportMessage::SendNow => {
+                self.warning = self.report.save().err();
+                self.report_saved = self.warning.is_none();
+                if self.report_saved && self.report.settings.missing().is_empty() {
+                    self.report_sending = true;
+                    return Command::perform(send_report(self.revaultd.clone()), |res| {
+                        Message::Report(ReportMessage::Sent(res))
+                    });
+                }
+            }
+            ReportMessage::Sent(res) => {
+                self.report_sending = false;
+                match res {
+                    Ok(date) => self.report.settings.last_sent = Some(date),
+                    Err(e) => self.warning = Some(e),
+                }
+            }
+        }
+        Command::none()
+    }
+
+    fn on_noise_key_message(&mut self, msg: NoiseKeyMessage) -> Result<(), Error> {
+        let key_file = match &self.noise_key {
+            Some(key_file) => key_file,
+            None => return Ok(()),
+        };
+        match msg {
+            NoiseKeyMessage::FixPermissions => noise::restrict_permissions(&key_file.path),
+            // An existing key is never overwritten, the servers know its public key.
+            NoiseKeyMessage::Create if !key_file.exists() => {
+                noise::generate().and_then(|key| noise::write(&key_file.path, &key))
+            }
+            NoiseKeyMessage::Create => Ok(()),
         }
+        .map_err(|e| Error::UnexpectedError(e.to_string()))
+    }
+
+    fn on_config_edit_message(&mut self, msg: ConfigEditMessage) -> Command<Message> {
+        match msg {
+            ConfigEditMessage::Edit => self.config_edit = Some(ConfigEdit::new(&self.config)),
+            ConfigEditMessage::Cancel => self.config_edit = None,
+            ConfigEditMessage::FieldEdited(field, value) => {
+                if let Some(edit) = &mut self.config_edit {
+                    edit.edit(field, value);
+                    self.bitcoind_test = None;
+                }
+            }
+            ConfigEditMessage::TestBitcoind => {
+                let config = match &mut self.config_edit {
+                    Some(edit) => match edit.apply(&self.config) {
+                        Some(config) => config,
+                        None => return Command::none(),
+                    },
+                    None => self.config.clone(),
+                };
+                self.bitcoind_testing = true;
+                self.bitcoind_test = None;
+                return Command::perform(
+                    test_bitcoind(config.bitcoind_config.clone(), config.unvault_csv()),
+                    |res| Message::ConfigEdit(ConfigEditMessage::BitcoindTested(res)),
+                );
+            }
+            ConfigEditMessage::BitcoindTested(res) => {
+                self.bitcoind_testing = false;
+                self.bitcoind_test = Some(res);
+            }
+            ConfigEditMessage::Save => {
+                let current = &self.config;
+                let config = match self.config_edit.as_mut().and_then(|e| e.apply(current)) {
+                    Some(config) => config,
+                    None => return Command::none(),
+                };
+                if let Err(e) = config.to_file(&self.config_path) {
+                    self.warning = Some(e.into());
+                    return Command::none();
+                }
+                self.config = config;
+                self.config_edit = None;
+                self.restarting = true;
+                return Command::perform(
+                    stop_daemon(self.revaultd.clone()),
+                    Message::DaemonStopped,
+                );
+            }
+        }
+        Command::none()
+    }
+
+    fn on_address_book_message(&mut self, msg: AddressBookMessage) -> Result<(), Error> {
+        match msg {
+            AddressBookMessage::LabelEdited(label) => self.address_book_entry.0 = label,
+            AddressBookMessage::AddressEdited(address) => self.address_book_entry.1 = address,
+            AddressBookMessage::Add => {
+                let (label, address) = &self.address_book_entry;
+                self.address_book.add(label, address)?;
+                self.address_book.save()?;
+                self.address_book_entry = (String::new(), String::new());
+            }
+            AddressBookMessage::Remove(address) => {
+                self.address_book.remove(&address);
+                self.address_book.save()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn on_metadata_message(&mut self, msg: MetadataMessage) -> Result<(), Error> {
+        match msg {
+            MetadataMessage::PathEdited(path) => self.transfer.path = path,
+            MetadataMessage::ResolutionSelected(resolution) => {
+                self.transfer.resolution = resolution
+            }
+            MetadataMessage::Export => {
+                self.transfer.exported = None;
+                let labels = Labels::load_datadir(&self.revaultd)?;
+                let notes = Notes::load_datadir(&self.revaultd)?;
+                let address_book = AddressBook::load_datadir(&self.revaultd)?;
+                let dir = self.config.network_datadir()?;
+                self.transfer.exported = Some(metadata::export(
+                    &dir,
+                    &labels,
+                    &notes,
+                    &address_book,
+                    Utc::now().timestamp(),
+                )?);
+            }
+            MetadataMessage::Import => {
+                self.transfer.imported = None;
+                let imported = metadata::read(&PathBuf::from(self.transfer.path.trim()))?;
+                let mut labels = Labels::load_datadir(&self.revaultd)?;
+                let mut notes = Notes::load_datadir(&self.revaultd)?;
+                let mut address_book = AddressBook::load_datadir(&self.revaultd)?;
+                let report = metadata::import(
+                    imported,
+                    &mut labels,
+                    &mut notes,
+                    &mut address_book,
+                    self.transfer.resolution,
+                );
+                labels.save()?;
+                notes.save()?;
+                address_book.save()?;
+                self.address_book = address_book;
+                self.transfer.imported = Some(report);
+            }
+        }
+        Ok(())
+    }
+
+    fn on_backup_message(&mut self, msg: BackupMessage) -> Result<(), Error> {
+        let backups = match &mut self.backups {
+            Some(backups) => backups,
+            None => return Ok(()),
+        };
+        match msg {
+            BackupMessage::BackupNow => {
+                backups.backup(Utc::now().timestamp())?;
+            }
+            BackupMessage::Restore(date) => {
+                if let Some(backup) = self.backup_list.iter().find(|b| b.date == date) {
+                    backups.restore(backup, Utc::now().timestamp())?;
+                    self.restored = Some(date);
+                }
+            }
+        }
+        Ok(())
     }
 }
 
 impl State for SettingsState {
-    fn update(&mut self, _message: Message) -> Command<Message> {
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Backup(msg) => {
+                self.warning = self.on_backup_message(msg).err();
+                self.refresh_backups();
+            }
+            Message::Contact(ContactMessage::Edited(host, field, value)) => {
+                self.contacts_saved = false;
+                self.contacts.edit(&host, field, value);
+            }
+            Message::Contact(ContactMessage::Save) => {
+                self.warning = self.contacts.save().err();
+                self.contacts_saved = self.warning.is_none();
+            }
+            Message::Report(msg) => return self.on_report_message(msg),
+            Message::Notification(event, enabled) => {
+                self.warning = self.notifications.set(event, enabled).err()
+            }
+            Message::AddressBook(msg) => self.warning = self.on_address_book_message(msg).err(),
+            Message::Metadata(msg) => self.warning = self.on_metadata_message(msg).err(),
+            Message::DescriptorCheckEdited(desc) => self.descriptor_check = desc,
+            Message::Logs(msg) => {
+                self.warning = match msg {
+                    LogsMessage::LevelSelected(level) => logs::set_level(level),
+                    LogsMessage::OpenDir => match logs::dir() {
+                        Some(dir) => logs::open_dir(dir),
+                        None => Ok(()),
+                    },
+                }
+                .map_err(Error::UnexpectedError)
+                .err();
+            }
+            Message::NoiseKey(msg) => {
+                self.warning = self.on_noise_key_message(msg).err();
+                self.refresh_noise_key();
+            }
+            Message::ConfigEdit(msg) => return self.on_config_edit_message(msg),
+            // The app reconnects to revaultd once it is stopped.
+            Message::DaemonStopped(Err(e)) => {
+                self.restarting = false;
+                self.warning = Some(e.into());
+            }
+            Message::Daemon(DaemonMessage::Restart) => self.restarting = true,
+            Message::Daemon(DaemonMessage::Stopped(Err(e))) => self.warning = Some(e.into()),
+            Message::Daemon(DaemonMessage::Status(res)) => self.daemon = Some(res),
+            Message::Poll(PollMessage::Tick) => return self.load(),
+            _ => {}
+        }
         Command::none()
     }
 
+    fn load(&self) -> Command<Message> {
+        Command::perform(check_daemon(self.revaultd.clone()), |res| {
+            Message::Daemon(DaemonMessage::Status(res))
+        })
+    }
+
     fn view(&mut self, ctx: &Context) -> Element<Message> {
-        self.view
-            .view(ctx, self.warning.as_ref(), self.config.clone())
+        let backup_list = &self.backup_list;
+        self.view.view(
+            ctx,
+            self.warning.as_ref(),
+            self.config.clone(),
+            &self.revaultd.api,
+            self.metrics.as_ref().map(|m| m.counters()),
+            self.backups
+                .as_ref()
+                .map(|backups| (backups.dir(), &backup_list[..])),
+            self.restored,
+            &self.transfer,
+            (&self.contacts, self.contacts_saved),
+            (
+                &self.report.settings,
+                self.report_saved,
+                self.report_sending,
+            ),
+            &self.notifications,
+            (
+                &self.address_book,
+                (&self.address_book_entry.0, &self.address_book_entry.1),
+            ),
+            &self.descriptor_check,
+            self.noise_key.as_ref(),
+            (logs::level(), logs::dir()),
+            self.config_edit.as_ref(),
+            (self.bitcoind_testing, self.bitcoind_test.as_ref()),
+            self.restarting,
+            self.daemon.as_ref(),
+        )
     }
 }
 
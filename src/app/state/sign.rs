@@ -1,10 +1,17 @@
-use bitcoin::{base64, consensus::encode, util::psbt::PartiallySignedTransaction as Psbt};
+use std::path::PathBuf;
+
+use bitcoin::{
+    base64, consensus::encode, util::psbt::PartiallySignedTransaction as Psbt, PublicKey,
+    SigHashType,
+};
 
 use iced::{Command, Element};
 
 use crate::{
     app::{
         message::{SignMessage, SignatureSharingStatus},
+        psbt_file,
+        signatures::{self, InputSignature},
         view::{
             sign::{DirectSignatureView, IndirectSignatureView},
             Context,
@@ -21,6 +28,13 @@ pub struct SignState {
     pub transaction_kind: TransactionKind,
     sharing_status: SignatureSharingStatus,
     method: SignMethod,
+    /// Path of the file exchanged with the signing device.
+    path: String,
+    exported: Option<PathBuf>,
+    /// Key whose signatures are checked in the imported PSBT, if any.
+    signer_key: Option<PublicKey>,
+    /// Signatures of the signer key found in the last imported PSBT.
+    signatures: Vec<InputSignature>,
 }
 
 /// SignMethod is the way the user will sign the PSBT.
@@ -34,13 +48,20 @@ pub enum SignMethod {
     IndirectSignature {
         warning: Option<String>,
         psbt_input: String,
-        view: IndirectSignatureView,
+        /// Boxed, the view holds the states of many widgets.
+        view: Box<IndirectSignatureView>,
     },
 }
 
 impl SignState {
     pub fn new(original_psbt: Psbt, transaction_kind: TransactionKind) -> Self {
         SignState {
+            path: psbt_file::default_path(&original_psbt, &transaction_kind)
+                .to_string_lossy()
+                .to_string(),
+            exported: None,
+            signer_key: None,
+            signatures: Vec::new(),
             original_psbt,
             transaction_kind,
             signed_psbt: None,
@@ -51,6 +72,19 @@ impl SignState {
         }
     }
 
+    /// Rejects the imported PSBTs without a valid signature of the key
+    /// in each of their inputs.
+    pub fn verified_by(mut self, key: Option<PublicKey>) -> Self {
+        self.signer_key = key;
+        self
+    }
+
+    /// Returns the key whose signatures are checked and their sighash type.
+    fn signer(&self) -> Option<(PublicKey, SigHashType)> {
+        self.signer_key
+            .map(|key| (key, signatures::sighash_type(&self.transaction_kind)))
+    }
+
     pub fn update(&mut self, message: SignMessage) -> Command<SignMessage> {
         match message {
             SignMessage::Success => {
@@ -68,6 +102,7 @@ impl SignState {
                 }
             }
             SignMessage::Sign => {
+                let signer = self.signer();
                 if let SignMethod::IndirectSignature {
                     psbt_input,
                     warning,
@@ -75,30 +110,69 @@ impl SignState {
                 } = &mut self.method
                 {
                     if !psbt_input.is_empty() {
-                        self.signed_psbt = base64::decode(&psbt_input)
-                            .ok()
-                            .and_then(|bytes| encode::deserialize(&bytes).ok());
-                        if let Some(signed) = &self.signed_psbt {
-                            if signed.global.unsigned_tx.txid()
-                                != self.original_psbt.global.unsigned_tx.txid()
-                            {
+                        match psbt_file::parse(psbt_input.as_bytes()) {
+                            Some(signed) => {
+                                self.signed_psbt = accept(
+                                    &self.original_psbt,
+                                    signed,
+                                    signer,
+                                    &mut self.signatures,
+                                    warning,
+                                )
+                            }
+                            None => {
                                 self.signed_psbt = None;
-                                *warning = Some(
-                                    "PSBT is not the targeted transaction to sign".to_string(),
-                                );
+                                *warning = Some("Please enter valid PSBT".to_string());
                             }
-                        } else {
-                            *warning = Some("Please enter valid PSBT".to_string());
                         }
                     }
                 }
             }
+            SignMessage::PathEdited(edited) => {
+                if let SignMethod::IndirectSignature { warning, .. } = &mut self.method {
+                    *warning = None;
+                    self.path = edited;
+                }
+            }
+            SignMessage::Export => {
+                if let SignMethod::IndirectSignature { warning, .. } = &mut self.method {
+                    let file = PathBuf::from(self.path.trim());
+                    self.exported = None;
+                    match psbt_file::export(&file, &self.original_psbt) {
+                        Ok(()) => self.exported = Some(file),
+                        Err(e) => *warning = Some(e.to_string()),
+                    }
+                }
+            }
+            SignMessage::Import => {
+                let signer = self.signer();
+                if let SignMethod::IndirectSignature {
+                    psbt_input,
+                    warning,
+                    ..
+                } = &mut self.method
+                {
+                    match psbt_file::read(&PathBuf::from(self.path.trim())) {
+                        Ok(signed) => {
+                            *psbt_input = base64::encode(&encode::serialize(&signed));
+                            self.signed_psbt = accept(
+                                &self.original_psbt,
+                                signed,
+                                signer,
+                                &mut self.signatures,
+                                warning,
+                            );
+                        }
+                        Err(e) => *warning = Some(e.to_string()),
+                    }
+                }
+            }
             SignMessage::ChangeMethod => {
                 if let SignMethod::DirectSignature { .. } = self.method {
                     self.method = SignMethod::IndirectSignature {
                         warning: None,
                         psbt_input: "".to_string(),
-                        view: IndirectSignatureView::new(),
+                        view: Box::new(IndirectSignatureView::new()),
                     }
                 } else {
                     self.method = SignMethod::DirectSignature {
@@ -124,8 +198,35 @@ impl SignState {
                 &self.transaction_kind,
                 &self.original_psbt,
                 &psbt_input,
+                (&self.path, self.exported.as_ref()),
+                &self.signatures,
                 warning.as_ref(),
             ),
         }
     }
 }
+
+/// Returns the signed PSBT if it matches the transaction to sign and,
+/// if a key is given, if each of its inputs is signed by the key with
+/// the sighash type.
+fn accept(
+    original: &Psbt,
+    signed: Psbt,
+    key: Option<(PublicKey, SigHashType)>,
+    checked: &mut Vec<InputSignature>,
+    warning: &mut Option<String>,
+) -> Option<Psbt> {
+    checked.clear();
+    if let Err(e) = psbt_file::check_template(original, &signed) {
+        *warning = Some(e);
+        return None;
+    }
+    if let Some((key, sighash_type)) = key {
+        *checked = signatures::check(&signed, &key, sighash_type);
+        if let Some(e) = signatures::error(checked) {
+            *warning = Some(e);
+            return None;
+        }
+    }
+    Some(signed)
+}
@@ -6,8 +6,11 @@ use iced::{Command, Element};
 
 use crate::{
     app::{
+        actions::{self, ActionKind},
         error::Error,
         message::{Message, SignMessage, SpendTxMessage},
+        spend_hints::{spend_hints, SpendHint},
+        spend_review::SpendReview,
         state::{
             cmd::{
                 broadcast_spend_tx, delete_spend_tx, list_spend_txs, list_vaults, update_spend_tx,
@@ -22,6 +25,7 @@ use crate::{
         },
         view::Context,
     },
+    participant::{self, Participant},
     revault::TransactionKind,
     revaultd::{model, RevaultD},
 };
@@ -33,6 +37,13 @@ pub struct SpendTransactionState {
     revaultd: Arc<RevaultD>,
     deposit_outpoints: Vec<String>,
     deposits: Vec<model::Vault>,
+    /// outputs of the change and of the CPFP given by revaultd.
+    change_index: Option<usize>,
+    cpfp_index: Option<usize>,
+    review: Option<SpendReview>,
+    hints: Vec<SpendHint>,
+    /// keys of the unvault descriptor, the managers sign the spend.
+    participants: Vec<Participant>,
     warning: Option<Error>,
 
     action: SpendTransactionAction,
@@ -42,11 +53,21 @@ pub struct SpendTransactionState {
 
 impl SpendTransactionState {
     pub fn new(revaultd: Arc<RevaultD>, psbt: Psbt) -> Self {
+        let scripts = &revaultd.config.scripts_config;
+        let participants = participant::unvault_participants(
+            &scripts.deposit_descriptor,
+            &scripts.unvault_descriptor,
+        );
         Self {
+            participants,
             revaultd,
             psbt,
             deposit_outpoints: Vec::new(),
             deposits: Vec::new(),
+            change_index: None,
+            cpfp_index: None,
+            review: None,
+            hints: Vec::new(),
             action: SpendTransactionAction::new(),
             warning: None,
             view: SpendTransactionView::new(),
@@ -63,15 +84,26 @@ impl State for SpendTransactionState {
                         .into_iter()
                         .filter(|vault| self.deposit_outpoints.contains(&vault.outpoint()))
                         .collect();
+                    self.review = Some(SpendReview::new(
+                        &self.psbt,
+                        &self.deposits,
+                        &self.revaultd.config.scripts_config,
+                        self.revaultd.network(),
+                        self.change_index,
+                        self.cpfp_index,
+                    ));
                 }
                 Err(e) => self.warning = Error::from(e).into(),
             },
             Message::SpendTx(SpendTxMessage::SpendTransactions(res)) => match res {
                 Ok(txs) => {
+                    self.hints = spend_hints(&self.psbt, self.revaultd.network(), &txs);
                     for tx in txs {
                         if tx.psbt.global.unsigned_tx.txid() == self.psbt.global.unsigned_tx.txid()
                         {
                             self.deposit_outpoints = tx.deposit_outpoints;
+                            self.change_index = tx.change_index;
+                            self.cpfp_index = Some(tx.cpfp_index);
                             self.psbt = tx.psbt;
                             return Command::perform(
                                 list_vaults(
@@ -87,6 +119,13 @@ impl State for SpendTransactionState {
                 Err(e) => self.warning = Error::from(e).into(),
             },
             Message::SpendTx(msg) => {
+                if let SpendTxMessage::Broadcasted(Ok(())) = msg {
+                    actions::record(
+                        &self.revaultd,
+                        &self.deposit_outpoints,
+                        ActionKind::SpendAnnounced,
+                    );
+                }
                 return self
                     .action
                     .update(self.revaultd.clone(), &mut self.psbt, msg)
@@ -100,8 +139,9 @@ impl State for SpendTransactionState {
     fn view(&mut self, ctx: &Context) -> Element<Message> {
         self.view.view(
             ctx,
-            &self.psbt,
-            &self.deposits,
+            self.review.as_ref(),
+            &self.hints,
+            &participant::signatures(&self.psbt, &self.participants),
             self.action.view(ctx, &self.psbt),
             self.warning.as_ref(),
         )
@@ -345,6 +385,7 @@ impl SpendTransactionAction {
                 warning,
                 view,
             } => view.view(
+                ctx,
                 signer
                     .view(ctx)
                     .map(|msg| Message::SpendTx(SpendTxMessage::Sign(msg))),
@@ -357,19 +398,26 @@ impl SpendTransactionAction {
                 success,
                 warning,
                 ..
-            } => view.view(&psbt_input, &processing, &success, psbt, warning.as_ref()),
+            } => view.view(
+                ctx,
+                &psbt_input,
+                &processing,
+                &success,
+                psbt,
+                warning.as_ref(),
+            ),
             Self::Broadcast {
                 view,
                 processing,
                 success,
                 warning,
-            } => view.view(&processing, &success, warning.as_ref()),
+            } => view.view(ctx, &processing, &success, warning.as_ref()),
             Self::Delete {
                 view,
                 processing,
                 success,
                 warning,
-            } => view.view(&processing, &success, warning.as_ref()),
+            } => view.view(ctx, &processing, &success, warning.as_ref()),
         }
     }
 }
@@ -379,19 +427,23 @@ pub struct SpendTransactionListItem {
     pub tx: model::SpendTx,
     // Sum of the amounts of the vaults this tx is spending
     pub vaults_amount: u64,
+    /// true if the manager did not sign the tx yet.
+    awaits_signature: bool,
     view: SpendTransactionListItemView,
 }
 
 impl SpendTransactionListItem {
-    pub fn new(tx: model::SpendTx, vaults_amount: u64) -> Self {
+    pub fn new(tx: model::SpendTx, vaults_amount: u64, awaits_signature: bool) -> Self {
         Self {
             tx,
             view: SpendTransactionListItemView::new(),
             vaults_amount,
+            awaits_signature,
         }
     }
 
     pub fn view(&mut self, ctx: &Context) -> Element<SpendTxMessage> {
-        self.view.view(ctx, &self.tx, self.vaults_amount)
+        self.view
+            .view(ctx, &self.tx, self.vaults_amount, self.awaits_signature)
     }
 }
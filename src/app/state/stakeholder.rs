@@ -1,22 +1,44 @@
 use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::sync::Arc;
 
+use chrono::Utc;
 use iced::{Command, Element};
 
+use crate::bitcoind::{BitcoindError, PruningCheck};
+use crate::net::ServerHealth;
 use crate::revaultd::{
     model::{self, VaultStatus},
-    RevaultD,
+    RevaultD, RevaultDError,
 };
+use crate::ui::component::form;
 
 use crate::app::{
+    attention::{self, Attention},
+    budget::DelegationBudget,
+    contacts::Contacts,
+    coordinator::CoordinatorStatus,
+    deadline::CancelDeadline,
     error::Error,
-    message::{Message, VaultMessage},
+    fetch::{self, ChunkedFetch, VaultsFetch},
+    key_checks::{self, KeyChecks},
+    message::{KeyCheckMessage, Message, PollMessage, SecureBatchMessage, VaultMessage},
+    notes::Notes,
+    selection::Selection,
+    signatures,
     state::{
-        cmd::{get_blockheight, get_deposit_address, get_revocation_txs, list_vaults},
+        cmd::{
+            cancel_deadlines, check_bitcoind_pruning, coordinator_status, get_blockheight,
+            get_deposit_address, get_revocation_txs, get_revocation_txs_batch, list_vaults,
+            ping_server,
+        },
+        list_vaults_by_status,
+        secure_batch::SecureBatch,
         vault::{Vault, VaultListItem},
         State,
     },
     view::{
+        selectable_deposit,
         vault::{DelegateVaultListItemView, SecureVaultListItemView, VaultListItemView},
         Context, StakeholderCreateVaultsView, StakeholderDelegateFundsView, StakeholderHomeView,
         StakeholderNetworkView,
@@ -30,40 +52,133 @@ pub struct StakeholderHomeState {
 
     balance: HashMap<VaultStatus, (u64, u64)>,
 
+    /// vaults of the dashboard, listed by status.
+    vaults_fetch: VaultsFetch,
     moving_vaults: Vec<VaultListItem<VaultListItemView>>,
+    /// false until the vaults of every status are listed a first time.
+    vaults_loaded: bool,
     selected_vault: Option<Vault>,
+    /// Actions expected from the stakeholder, by urgency.
+    attention: Vec<Attention>,
+    /// Deadlines of the cancel transactions of the unvaulting vaults.
+    deadlines: Vec<CancelDeadline>,
+
+    key_checks: KeyChecks,
+    /// Number of days between two checks of the emergency signing device.
+    reminder_days: u32,
+    /// Maximum delegated amount, from the GUI configuration.
+    max_delegated_amount: Option<u64>,
+    fingerprint: form::Value<String>,
 
     view: StakeholderHomeView,
 }
 
 impl StakeholderHomeState {
-    pub fn new(revaultd: Arc<RevaultD>) -> Self {
+    pub fn new(
+        revaultd: Arc<RevaultD>,
+        reminder_days: u32,
+        max_delegated_amount: Option<u64>,
+    ) -> Self {
+        let key_checks = revaultd
+            .config
+            .network_datadir()
+            .map_err(Error::from)
+            .and_then(|mut path| {
+                path.push(key_checks::DEFAULT_FILE_NAME);
+                KeyChecks::load(path)
+            });
+        let (key_checks, warning) = match key_checks {
+            Ok(checks) => (checks, None),
+            Err(e) => (KeyChecks::default(), Some(e)),
+        };
         StakeholderHomeState {
             revaultd,
-            warning: None,
+            warning,
             view: StakeholderHomeView::new(),
             balance: HashMap::new(),
+            vaults_fetch: VaultsFetch::new(&VaultStatus::DEPOSIT_AND_CURRENT),
             moving_vaults: Vec::new(),
+            vaults_loaded: false,
             selected_vault: None,
+            attention: Vec::new(),
+            deadlines: Vec::new(),
+            key_checks,
+            reminder_days,
+            max_delegated_amount,
+            fingerprint: form::Value::default(),
+        }
+    }
+
+    fn on_key_check_message(&mut self, msg: KeyCheckMessage) {
+        match msg {
+            KeyCheckMessage::FingerprintEdited(fingerprint) => {
+                self.fingerprint.valid = true;
+                self.fingerprint.value = fingerprint;
+            }
+            KeyCheckMessage::Confirm => {
+                let fingerprint = self.fingerprint.value.trim().to_lowercase();
+                if !key_checks::is_valid_fingerprint(&fingerprint) {
+                    self.fingerprint.valid = false;
+                    return;
+                }
+                match self.key_checks.record(Utc::now().timestamp(), fingerprint) {
+                    Ok(()) => self.fingerprint = form::Value::default(),
+                    Err(e) => self.warning = Some(e),
+                }
+            }
         }
     }
 
-    fn update_vaults(&mut self, vaults: Vec<model::Vault>) {
+    /// Records the vaults of the status, the dashboard is rebuilt from the
+    /// vaults received so far.
+    fn receive_vaults(
+        &mut self,
+        status: VaultStatus,
+        vaults: Vec<model::Vault>,
+    ) -> Command<Message> {
+        if !self.vaults_fetch.receive(status.clone(), vaults) {
+            return Command::none();
+        }
+        let vaults = self.vaults_fetch.vaults().to_vec();
         self.calculate_balance(&vaults);
-        self.moving_vaults = vaults
+        self.attention = attention::stakeholder(vaults.iter());
+        match Notes::load_datadir(&self.revaultd) {
+            Ok(mut notes) => {
+                if notes.check(&vaults, Utc::now().timestamp()) {
+                    if let Err(e) = notes.save() {
+                        self.warning = Some(e);
+                    }
+                }
+                self.attention
+                    .extend(attention::notes(&notes.triggered(), &vaults));
+            }
+            Err(e) => self.warning = Some(e),
+        }
+        let cmd = match self.revaultd.config.unvault_csv() {
+            // The deadlines are only the ones of the unvaulting vaults.
+            Some(csv) if status == VaultStatus::Unvaulting || status == VaultStatus::Unvaulted => {
+                Command::perform(
+                    cancel_deadlines(self.revaultd.clone(), vaults.clone(), csv),
+                    Message::CancelDeadlines,
+                )
+            }
+            _ => Command::none(),
+        };
+        let moving: Vec<model::Vault> = vaults
             .into_iter()
-            .filter_map(|vlt| {
-                if vlt.status == VaultStatus::Canceling
+            .filter(|vlt| {
+                vlt.status == VaultStatus::Canceling
                     || vlt.status == VaultStatus::Spending
                     || vlt.status == VaultStatus::Unvaulting
                     || vlt.status == VaultStatus::Unvaulted
-                {
-                    Some(VaultListItem::new(vlt))
-                } else {
-                    None
-                }
             })
             .collect();
+        // The vaults of the first load are not highlighted.
+        let now = Some(Utc::now().timestamp()).filter(|_| self.vaults_loaded);
+        self.moving_vaults =
+            VaultListItem::update_list(std::mem::take(&mut self.moving_vaults), moving, now);
+        self.vaults_loaded = self.vaults_fetch.is_loaded();
+        cmd
     }
 
     pub fn on_vault_select(&mut self, outpoint: String) -> Command<Message> {
@@ -79,7 +194,8 @@ impl StakeholderHomeState {
             .iter()
             .find(|vlt| vlt.vault.outpoint() == outpoint)
         {
-            let selected_vault = Vault::new(selected.vault.clone());
+            let selected_vault = Vault::new(selected.vault.clone())
+                .with_delegation_budget(self.max_delegated_amount);
             let cmd = selected_vault.load(self.revaultd.clone());
             self.selected_vault = Some(selected_vault);
             return cmd.map(move |msg| Message::Vault(outpoint.clone(), msg));
@@ -110,8 +226,24 @@ impl StakeholderHomeState {
 impl State for StakeholderHomeState {
     fn update(&mut self, message: Message) -> Command<Message> {
         match message {
-            Message::Vaults(res) => match res {
-                Ok(vaults) => self.update_vaults(vaults),
+            Message::Poll(PollMessage::Tick) => {
+                let mut cmds = vec![self.load()];
+                if let Some(selected) = &self.selected_vault {
+                    let outpoint = selected.vault.outpoint();
+                    cmds.push(
+                        selected
+                            .poll(self.revaultd.clone())
+                            .map(move |msg| Message::Vault(outpoint.clone(), msg)),
+                    );
+                }
+                return Command::batch(cmds);
+            }
+            Message::VaultsOfStatus(status, res) => match res {
+                Ok(vaults) => return self.receive_vaults(status, vaults),
+                Err(e) => self.warning = Error::from(e).into(),
+            },
+            Message::CancelDeadlines(res) => match res {
+                Ok(deadlines) => self.deadlines = deadlines,
                 Err(e) => self.warning = Error::from(e).into(),
             },
             Message::Vault(outpoint, VaultMessage::Select) => {
@@ -126,6 +258,18 @@ impl State for StakeholderHomeState {
                     }
                 }
             }
+            Message::EmergencyKeyCheck(msg) => self.on_key_check_message(msg),
+            Message::DismissNote(id) => {
+                // The notes are reloaded, the vault panel may have saved new ones.
+                if let Err(e) = Notes::load_datadir(&self.revaultd).and_then(|mut notes| {
+                    notes.delete(id);
+                    notes.save()
+                }) {
+                    self.warning = Some(e);
+                }
+                self.attention
+                    .retain(|a| !matches!(a.action, Message::DismissNote(i) if i == id));
+            }
             _ => {}
         }
         Command::none()
@@ -136,25 +280,25 @@ impl State for StakeholderHomeState {
             return v.view(ctx);
         }
 
+        let now = Utc::now().timestamp();
         self.view.view(
             ctx,
-            None,
+            self.warning.as_ref(),
+            &self.attention,
             self.moving_vaults.iter_mut().map(|v| v.view(ctx)).collect(),
+            &self.deadlines,
             &self.balance,
+            self.key_checks.last(),
+            self.key_checks.next_due(self.reminder_days),
+            self.key_checks.is_due(self.reminder_days, now),
+            &self.fingerprint,
         )
     }
 
     fn load(&self) -> Command<Message> {
         Command::batch(vec![
             Command::perform(get_blockheight(self.revaultd.clone()), Message::BlockHeight),
-            Command::perform(
-                list_vaults(
-                    self.revaultd.clone(),
-                    Some(&VaultStatus::DEPOSIT_AND_CURRENT),
-                    None,
-                ),
-                Message::Vaults,
-            ),
+            list_vaults_by_status(&self.revaultd, self.vaults_fetch.statuses()),
         ])
     }
 }
@@ -170,20 +314,58 @@ pub struct StakeholderNetworkState {
     revaultd: Arc<RevaultD>,
 
     blockheight: Option<u64>,
+    pruning: Option<Result<PruningCheck, BitcoindError>>,
+    coordinator: Option<Result<CoordinatorStatus, RevaultDError>>,
+    watchtowers: Vec<ServerHealth>,
+    contacts: Contacts,
     warning: Option<Error>,
+    /// SOCKS5 proxy of the health checks, from the GUI configuration.
+    health_check_proxy: Option<SocketAddr>,
 
     view: StakeholderNetworkView,
 }
 
 impl StakeholderNetworkState {
-    pub fn new(revaultd: Arc<RevaultD>) -> Self {
+    pub fn new(revaultd: Arc<RevaultD>, health_check_proxy: Option<SocketAddr>) -> Self {
+        let watchtowers = revaultd
+            .config
+            .stakeholder_config
+            .as_ref()
+            .map(|config| {
+                config
+                    .watchtowers
+                    .iter()
+                    .map(|watchtower| ServerHealth::new(watchtower.host.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut warning = None;
+        let contacts = Contacts::load_datadir(&revaultd).unwrap_or_else(|e| {
+            warning = Some(e);
+            Contacts::default()
+        });
         StakeholderNetworkState {
             revaultd,
             blockheight: None,
-            warning: None,
+            pruning: None,
+            coordinator: None,
+            watchtowers,
+            contacts,
+            warning,
+            health_check_proxy,
             view: StakeholderNetworkView::new(),
         }
     }
+
+    fn ping_watchtowers(&self) -> Command<Message> {
+        let proxy = self.health_check_proxy;
+        Command::batch(self.watchtowers.iter().map(|watchtower| {
+            let host = watchtower.host.clone();
+            Command::perform(ping_server(host.clone(), proxy), move |res| {
+                Message::ServerPinged(host.clone(), res)
+            })
+        }))
+    }
 }
 
 impl State for StakeholderNetworkState {
@@ -200,20 +382,52 @@ impl State for StakeholderNetworkState {
                 };
                 Command::none()
             }
+            Message::BitcoindPruning(res) => {
+                self.pruning = Some(res);
+                Command::none()
+            }
+            Message::CoordinatorStatus(res) => {
+                self.coordinator = Some(res);
+                Command::none()
+            }
+            Message::PingServers => self.ping_watchtowers(),
+            Message::ServerPinged(host, res) => {
+                let now = Utc::now().timestamp();
+                for watchtower in self.watchtowers.iter_mut().filter(|w| w.host == host) {
+                    watchtower.record(res.clone(), now);
+                }
+                Command::none()
+            }
+            Message::Poll(PollMessage::Tick) => self.load(),
             _ => Command::none(),
         }
     }
 
     fn view(&mut self, ctx: &Context) -> Element<Message> {
-        self.view
-            .view(ctx, self.warning.as_ref(), self.blockheight.as_ref())
+        self.view.view(
+            ctx,
+            self.warning.as_ref(),
+            self.blockheight.as_ref(),
+            self.pruning.as_ref(),
+            self.coordinator.as_ref(),
+            &self.watchtowers,
+            &self.contacts,
+        )
     }
 
     fn load(&self) -> Command<Message> {
-        Command::batch(vec![Command::perform(
-            get_blockheight(self.revaultd.clone()),
-            Message::BlockHeight,
-        )])
+        Command::batch(vec![
+            Command::perform(get_blockheight(self.revaultd.clone()), Message::BlockHeight),
+            Command::perform(
+                check_bitcoind_pruning(self.revaultd.clone()),
+                Message::BitcoindPruning,
+            ),
+            Command::perform(
+                coordinator_status(self.revaultd.clone(), self.health_check_proxy),
+                Message::CoordinatorStatus,
+            ),
+            self.ping_watchtowers(),
+        ])
     }
 }
 
@@ -233,6 +447,15 @@ pub struct StakeholderCreateVaultsState {
     deposits: Vec<VaultListItem<SecureVaultListItemView>>,
     selected_vault: Option<Vault>,
 
+    /// Multi-select mode of the deposits to secure in one signing session.
+    selecting: bool,
+    selection: Selection,
+    /// Some while the revocation transactions of the batch are fetched.
+    batch_fetch: Option<ChunkedFetch<(String, model::RevocationTransactions)>>,
+    /// id of the last fetch of the revocation transactions.
+    batch_fetch_id: usize,
+    batch: Option<SecureBatch>,
+
     view: StakeholderCreateVaultsView,
 }
 
@@ -246,10 +469,108 @@ impl StakeholderCreateVaultsState {
             view: StakeholderCreateVaultsView::new(),
             balance: 0,
             selected_vault: None,
+            selecting: false,
+            selection: Selection::default(),
+            batch_fetch: None,
+            batch_fetch_id: 0,
+            batch: None,
         }
     }
 
+    fn on_secure_batch_message(&mut self, msg: SecureBatchMessage) -> Command<Message> {
+        match msg {
+            SecureBatchMessage::ToggleSelection => {
+                self.selecting = !self.selecting;
+                self.selection.clear();
+            }
+            SecureBatchMessage::SelectVault(outpoint, selected) => {
+                let keys: Vec<String> = self.deposits.iter().map(|d| d.vault.outpoint()).collect();
+                self.selection.toggle(&keys, outpoint, selected, false);
+            }
+            SecureBatchMessage::Start => {
+                if !self.selection.is_empty() && self.batch_fetch.is_none() {
+                    self.warning = None;
+                    // Keep the order of the deposit list for the signing session.
+                    let outpoints = self
+                        .deposits
+                        .iter()
+                        .map(|d| d.vault.outpoint())
+                        .filter(|outpoint| self.selection.contains(outpoint))
+                        .collect();
+                    self.batch_fetch_id += 1;
+                    let mut batch_fetch = ChunkedFetch::new(self.batch_fetch_id, outpoints);
+                    let chunks = batch_fetch.next_chunks();
+                    self.batch_fetch = Some(batch_fetch);
+                    return self.fetch_revocation_txs(chunks);
+                }
+            }
+            SecureBatchMessage::RevocationTransactions(id, index, res) => {
+                // The chunks of a failed or previous fetch are ignored.
+                let batch_fetch = match &mut self.batch_fetch {
+                    Some(batch_fetch) if batch_fetch.id() == id => batch_fetch,
+                    _ => return Command::none(),
+                };
+                let txs = match res {
+                    Ok(txs) => txs,
+                    Err(e) => {
+                        self.batch_fetch = None;
+                        self.warning = Some(Error::RevaultDError(e));
+                        return Command::none();
+                    }
+                };
+                let chunks = batch_fetch.receive(id, index, txs);
+                if !batch_fetch.is_done() {
+                    return self.fetch_revocation_txs(chunks);
+                }
+                if let Some(txs) = self.batch_fetch.take().and_then(|fetch| fetch.results()) {
+                    let keys = self
+                        .deposits
+                        .iter()
+                        .filter_map(|d| {
+                            signatures::stakeholder_key(&self.revaultd, &d.vault)
+                                .map(|key| (d.vault.outpoint(), key))
+                        })
+                        .collect();
+                    self.batch = Some(SecureBatch::new(txs, keys))
+                }
+            }
+            SecureBatchMessage::Close => {
+                self.batch = None;
+                self.selecting = false;
+                self.selection.clear();
+                return self.load();
+            }
+            msg => {
+                if let Some(batch) = &mut self.batch {
+                    return batch
+                        .update(self.revaultd.clone(), msg)
+                        .map(Message::SecureBatch);
+                }
+            }
+        };
+        Command::none()
+    }
+
+    /// Fetches the revocation transactions of the vaults of the chunks.
+    fn fetch_revocation_txs(&self, chunks: Vec<fetch::Chunk>) -> Command<Message> {
+        let id = self.batch_fetch_id;
+        Command::batch(chunks.into_iter().map(|(index, outpoints)| {
+            Command::perform(
+                get_revocation_txs_batch(self.revaultd.clone(), outpoints),
+                move |res| {
+                    Message::SecureBatch(SecureBatchMessage::RevocationTransactions(id, index, res))
+                },
+            )
+        }))
+    }
+
     pub fn on_vault_select(&mut self, outpoint: String) -> Command<Message> {
+        if self.selecting {
+            let selected = !self.selection.contains(&outpoint);
+            return self
+                .on_secure_batch_message(SecureBatchMessage::SelectVault(outpoint, selected));
+        }
+
         if let Some(selected) = &self.selected_vault {
             if selected.vault.outpoint() == outpoint {
                 self.selected_vault = None;
@@ -275,7 +596,10 @@ impl StakeholderCreateVaultsState {
 
     fn update_deposits(&mut self, vaults: Vec<model::Vault>) {
         self.calculate_balance(&vaults);
-        self.deposits = vaults.into_iter().map(VaultListItem::new).collect();
+        self.deposits =
+            VaultListItem::update_list(std::mem::take(&mut self.deposits), vaults, None);
+        let keys: Vec<String> = self.deposits.iter().map(|d| d.vault.outpoint()).collect();
+        self.selection.retain(&keys);
     }
 
     fn calculate_balance(&mut self, vaults: &[model::Vault]) {
@@ -316,6 +640,7 @@ impl State for StakeholderCreateVaultsState {
                 }
                 Command::none()
             }
+            Message::SecureBatch(msg) => self.on_secure_batch_message(msg),
             Message::Vaults(res) => match res {
                 Ok(vaults) => {
                     self.update_deposits(vaults);
@@ -331,13 +656,36 @@ impl State for StakeholderCreateVaultsState {
     }
 
     fn view(&mut self, ctx: &Context) -> Element<Message> {
+        if let Some(batch) = &mut self.batch {
+            return batch.view(ctx);
+        }
         if let Some(selected) = &mut self.selected_vault {
             return selected.view(ctx);
         }
+        let selecting = self.selecting;
+        let selection = &self.selection;
+        let deposits = self
+            .deposits
+            .iter_mut()
+            .map(|v| {
+                let outpoint = v.vault.outpoint();
+                let item = v.view(ctx);
+                if selecting {
+                    let selected = selection.contains(&outpoint);
+                    selectable_deposit(item, outpoint, selected)
+                } else {
+                    item
+                }
+            })
+            .collect();
         self.view.view(
             ctx,
-            self.deposits.iter_mut().map(|v| v.view(ctx)).collect(),
+            deposits,
             self.address.as_ref(),
+            self.warning.as_ref(),
+            selecting,
+            self.selection.len(),
+            self.batch_fetch.as_ref().map(|fetch| fetch.progress()),
         )
     }
 
@@ -367,6 +715,8 @@ pub struct StakeholderDelegateFundsState {
 
     active_balance: u64,
     activating_balance: u64,
+    /// Maximum delegated amount, from the GUI configuration.
+    max_delegated_amount: Option<u64>,
     vaults: Vec<VaultListItem<DelegateVaultListItemView>>,
     selected_vault: Option<Vault>,
     warning: Option<Error>,
@@ -375,11 +725,12 @@ pub struct StakeholderDelegateFundsState {
 }
 
 impl StakeholderDelegateFundsState {
-    pub fn new(revaultd: Arc<RevaultD>) -> Self {
+    pub fn new(revaultd: Arc<RevaultD>, max_delegated_amount: Option<u64>) -> Self {
         StakeholderDelegateFundsState {
             revaultd,
             active_balance: 0,
             activating_balance: 0,
+            max_delegated_amount,
             vaults: Vec::new(),
             selected_vault: None,
             warning: None,
@@ -389,7 +740,7 @@ impl StakeholderDelegateFundsState {
 
     pub fn update_vaults(&mut self, vaults: Vec<model::Vault>) {
         self.calculate_balance(&vaults);
-        self.vaults = vaults.into_iter().map(VaultListItem::new).collect();
+        self.vaults = VaultListItem::update_list(std::mem::take(&mut self.vaults), vaults, None);
     }
 
     pub fn on_vault_select(&mut self, outpoint: String) -> Command<Message> {
@@ -405,7 +756,8 @@ impl StakeholderDelegateFundsState {
             .iter()
             .find(|vlt| vlt.vault.outpoint() == outpoint)
         {
-            let selected_vault = Vault::new(selected.vault.clone());
+            let selected_vault = Vault::new(selected.vault.clone())
+                .with_delegation_budget(self.max_delegated_amount);
             let cmd = selected_vault.load(self.revaultd.clone());
             self.selected_vault = Some(selected_vault);
             return cmd.map(move |msg| Message::Vault(outpoint.clone(), msg));
@@ -413,7 +765,15 @@ impl StakeholderDelegateFundsState {
         Command::none()
     }
 
-    pub fn on_vault_delegate(&mut self, outpoint: String) -> Command<Message> {
+    fn budget(&self) -> Option<DelegationBudget> {
+        self.max_delegated_amount.map(|max| DelegationBudget {
+            max,
+            delegated: self.active_balance + self.activating_balance,
+        })
+    }
+
+    /// Delegates the vault, the vault checks the delegation budget.
+    fn delegate(&mut self, outpoint: String) -> Command<Message> {
         if let Some(selected) = &mut self.selected_vault {
             if selected.vault.outpoint() == outpoint {
                 return selected
@@ -427,7 +787,8 @@ impl StakeholderDelegateFundsState {
             .iter()
             .find(|vlt| vlt.vault.outpoint() == outpoint)
         {
-            let mut selected_vault = Vault::new(selected.vault.clone());
+            let mut selected_vault = Vault::new(selected.vault.clone())
+                .with_delegation_budget(self.max_delegated_amount);
             let cmd = selected_vault.update(self.revaultd.clone(), VaultMessage::Delegate);
             self.selected_vault = Some(selected_vault);
             return cmd.map(move |msg| Message::Vault(outpoint.clone(), msg));
@@ -460,7 +821,7 @@ impl State for StakeholderDelegateFundsState {
             },
             Message::Vault(outpoint, msg) => match msg {
                 VaultMessage::Select => return self.on_vault_select(outpoint),
-                VaultMessage::Delegate => return self.on_vault_delegate(outpoint),
+                VaultMessage::Delegate => return self.delegate(outpoint),
                 _ => {
                     if let Some(selected) = &mut self.selected_vault {
                         if selected.vault.outpoint() == outpoint {
@@ -478,6 +839,7 @@ impl State for StakeholderDelegateFundsState {
     }
 
     fn view(&mut self, ctx: &Context) -> Element<Message> {
+        let budget = self.budget();
         if let Some(v) = &mut self.selected_vault {
             return v.view(ctx);
         }
@@ -485,6 +847,7 @@ impl State for StakeholderDelegateFundsState {
             ctx,
             &self.active_balance,
             &self.activating_balance,
+            budget.as_ref(),
             self.vaults
                 .iter_mut()
                 .filter(|v| v.vault.status == VaultStatus::Secured)
@@ -1,21 +1,31 @@
-use bitcoin::util::psbt::PartiallySignedTransaction as Psbt;
-use iced::{Command, Element};
+use bitcoin::{util::psbt::PartiallySignedTransaction as Psbt, PublicKey};
+use chrono::Utc;
+use iced::{Column, Command, Element};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::{
     app::{
+        actions::{self, Action, ActionKind},
+        budget::DelegationBudget,
         error::Error,
-        message::{Message, SignMessage, VaultMessage},
+        journal::{self, Journal},
+        labels::Labels,
+        message::{Message, NoteMessage, SignMessage, VaultLabelMessage, VaultMessage},
+        notes::{Note, Notes},
+        signatures,
         state::{
             cmd::{
-                get_onchain_txs, get_revocation_txs, get_unvault_tx, revault, set_revocation_txs,
-                set_unvault_tx,
+                get_onchain_txs, get_revocation_txs, get_unvault_tx, list_vaults, revault,
+                set_revocation_txs, set_unvault_tx,
             },
             sign::SignState,
         },
         view::{
             vault::{
-                DelegateVaultView, RevaultVaultView, SecureVaultView, VaultModal,
+                highlighted_vault, vault_timeline, DelegateVaultView, OverBudgetView,
+                RevaultVaultView, SecureVaultView, SignaturesPollingView, UnvaultCountdownView,
+                VaultLabelView, VaultListItemView, VaultModal, VaultNotesView,
                 VaultOnChainTransactionsPanel, VaultView,
             },
             Context,
@@ -24,26 +34,77 @@ use crate::{
     revault::TransactionKind,
     revaultd::{
         model::{self, RevocationTransactions, VaultStatus, VaultTransactions},
-        RevaultD,
+        RevaultD, RevaultDError, RpcErrorCode,
     },
 };
 
 #[derive(Debug)]
 pub struct VaultListItem<T> {
     pub vault: model::Vault,
+    /// outpoint of the vault, computed once for the lists of many vaults.
+    pub outpoint: String,
+    /// time of the update of the list which found the vault changed or new,
+    /// the item is highlighted for a while.
+    changed_at: Option<i64>,
     view: T,
 }
 
+/// Seconds a changed vault is highlighted in the lists.
+const CHANGED_HIGHLIGHT_SECS: i64 = 30;
+
 impl<T: VaultView> VaultListItem<T> {
     pub fn new(vault: model::Vault) -> Self {
         Self {
+            outpoint: vault.outpoint(),
             vault,
+            changed_at: None,
             view: T::new(),
         }
     }
 
+    /// Returns the items of the vaults, the items of the vaults which did
+    /// not change are kept with their view. The changed and new vaults are
+    /// highlighted if the time of the update is given, it is not for the
+    /// first load of the list.
+    pub fn update_list(items: Vec<Self>, vaults: Vec<model::Vault>, now: Option<i64>) -> Vec<Self> {
+        let mut items: HashMap<String, Self> = items
+            .into_iter()
+            .map(|item| (item.outpoint.clone(), item))
+            .collect();
+        vaults
+            .into_iter()
+            .map(|vault| match items.remove(&vault.outpoint()) {
+                Some(item)
+                    if item.vault.status == vault.status
+                        && item.vault.updated_at == vault.updated_at =>
+                {
+                    item
+                }
+                _ => Self {
+                    changed_at: now,
+                    ..Self::new(vault)
+                },
+            })
+            .collect()
+    }
+
     pub fn view(&mut self, ctx: &Context) -> Element<Message> {
-        self.view.view(ctx, &self.vault)
+        let highlighted = self
+            .changed_at
+            .map(|time| Utc::now().timestamp() - time < CHANGED_HIGHLIGHT_SECS)
+            .unwrap_or(false);
+        let view = self.view.view(ctx, &self.vault);
+        if highlighted {
+            highlighted_vault(view)
+        } else {
+            view
+        }
+    }
+}
+
+impl VaultListItem<VaultListItemView> {
+    pub fn card<'a>(&'a mut self, ctx: &Context, label: Option<&String>) -> Element<'a, Message> {
+        self.view.card(ctx, &self.vault, label)
     }
 }
 
@@ -54,19 +115,185 @@ pub struct Vault {
     pub vault: model::Vault,
     warning: Option<Error>,
     section: VaultSection,
+    /// Actions performed on the vault from this GUI.
+    actions: Vec<Action>,
+    polling: SignaturesPolling,
+    notes: VaultNotes,
+    label: VaultLabel,
+    countdown: UnvaultCountdown,
+    budget: DelegationLimit,
+    /// Events of the vault, empty until its transactions are loaded.
+    timeline: Vec<journal::Event>,
     view: VaultModal,
 }
 
+/// UnvaultCountdown is the CSV of the unvault of a vault being unvaulted,
+/// counted from the confirmation of the unvault transaction.
+#[derive(Debug)]
+struct UnvaultCountdown {
+    csv: Option<u32>,
+    unvault: Option<model::BroadcastedTransaction>,
+    view: UnvaultCountdownView,
+}
+
+impl UnvaultCountdown {
+    fn is_counting(vault: &model::Vault) -> bool {
+        matches!(
+            vault.status,
+            VaultStatus::Unvaulting | VaultStatus::Unvaulted
+        )
+    }
+}
+
+/// DelegationLimit is the delegation budget checked before the vault is
+/// delegated, whatever the panel the user delegates it from.
+#[derive(Debug)]
+struct DelegationLimit {
+    /// Maximum delegated amount, from the GUI configuration.
+    max: Option<u64>,
+    /// Amount above the budget if the vault is delegated,
+    /// the user has to override the budget.
+    excess: Option<u64>,
+    view: OverBudgetView,
+}
+
+/// VaultLabel is the saved label of the vault and the label edited.
+#[derive(Debug)]
+struct VaultLabel {
+    saved: Option<String>,
+    text: String,
+    view: VaultLabelView,
+}
+
+/// VaultNotes are the notes attached to the vault and the note edited.
+#[derive(Debug)]
+struct VaultNotes {
+    notes: Vec<Note>,
+    text: String,
+    status: VaultStatus,
+    view: VaultNotesView,
+}
+
+/// SignaturesPolling tracks the checks of a vault waiting for the signatures
+/// of the other participants.
+#[derive(Debug)]
+struct SignaturesPolling {
+    /// Unix timestamp of the last state of the vault given by revaultd.
+    last_check: Option<i64>,
+    /// Number of checks in a row that failed.
+    failures: u32,
+    checking: bool,
+    view: SignaturesPollingView,
+}
+
+impl SignaturesPolling {
+    fn is_waiting(vault: &model::Vault) -> bool {
+        matches!(
+            vault.status,
+            VaultStatus::Securing | VaultStatus::Activating
+        )
+    }
+}
+
 impl Vault {
     pub fn new(vault: model::Vault) -> Self {
         Self {
             vault,
             section: VaultSection::Unloaded,
             view: VaultModal::new(),
+            actions: Vec::new(),
+            // The vault was just given by revaultd.
+            polling: SignaturesPolling {
+                last_check: Some(Utc::now().timestamp()),
+                failures: 0,
+                checking: false,
+                view: SignaturesPollingView::new(),
+            },
+            notes: VaultNotes {
+                notes: Vec::new(),
+                text: String::new(),
+                status: VaultStatus::Active,
+                view: VaultNotesView::new(),
+            },
+            label: VaultLabel {
+                saved: None,
+                text: String::new(),
+                view: VaultLabelView::new(),
+            },
+            countdown: UnvaultCountdown {
+                csv: None,
+                unvault: None,
+                view: UnvaultCountdownView::new(),
+            },
+            budget: DelegationLimit {
+                max: None,
+                excess: None,
+                view: OverBudgetView::new(),
+            },
+            timeline: Vec::new(),
             warning: None,
         }
     }
 
+    /// Checks the delegation of the vault against the maximum delegated amount.
+    pub fn with_delegation_budget(mut self, max: Option<u64>) -> Self {
+        self.budget.max = max;
+        self
+    }
+
+    fn on_note_message(&mut self, revaultd: &RevaultD, msg: NoteMessage) {
+        let outpoint = self.vault.outpoint();
+        let res = match msg {
+            NoteMessage::TextEdited(text) => {
+                self.notes.text = text;
+                return;
+            }
+            NoteMessage::StatusSelected(status) => {
+                self.notes.status = status;
+                return;
+            }
+            NoteMessage::Add => Notes::load_datadir(revaultd).and_then(|mut notes| {
+                notes.add(
+                    outpoint.clone(),
+                    self.notes.status.clone(),
+                    &self.notes.text,
+                );
+                notes.save().map(|_| notes)
+            }),
+            NoteMessage::Delete(id) => Notes::load_datadir(revaultd).and_then(|mut notes| {
+                notes.delete(id);
+                notes.save().map(|_| notes)
+            }),
+        };
+        match res {
+            Ok(notes) => {
+                self.notes.notes = notes.get(&outpoint);
+                self.notes.text = String::new();
+            }
+            Err(e) => self.warning = Some(e),
+        }
+    }
+
+    fn on_label_message(&mut self, revaultd: &RevaultD, msg: VaultLabelMessage) {
+        match msg {
+            VaultLabelMessage::Edited(text) => self.label.text = text,
+            VaultLabelMessage::Save => {
+                let outpoint = self.vault.outpoint();
+                let res = Labels::load_datadir(revaultd).and_then(|mut labels| {
+                    labels.set(&outpoint, &self.label.text);
+                    labels.save().map(|_| labels)
+                });
+                match res {
+                    Ok(labels) => {
+                        self.label.saved = labels.get(&outpoint).cloned();
+                        self.label.text = self.label.saved.clone().unwrap_or_default();
+                    }
+                    Err(e) => self.warning = Some(e),
+                }
+            }
+        }
+    }
+
     pub fn update(
         &mut self,
         revaultd: Arc<RevaultD>,
@@ -80,7 +307,33 @@ impl Vault {
                 );
             }
             VaultMessage::OnChainTransactions(res) => match res {
-                Ok(txs) => self.section = VaultSection::new_onchain_txs_section(txs),
+                Ok(txs) => {
+                    self.countdown.csv = revaultd.config.unvault_csv();
+                    self.countdown.unvault = txs.unvault.clone();
+                    match Journal::load_datadir(&revaultd) {
+                        Ok(journal) => {
+                            self.timeline =
+                                journal::timeline(journal.get(&self.vault.outpoint()), &txs)
+                        }
+                        Err(e) => self.warning = e.into(),
+                    }
+                    self.section = VaultSection::new_onchain_txs_section(txs);
+                    match actions::Actions::load_datadir(&revaultd) {
+                        Ok(actions) => self.actions = actions.get(&self.vault.outpoint()).to_vec(),
+                        Err(e) => self.warning = e.into(),
+                    }
+                    match Notes::load_datadir(&revaultd) {
+                        Ok(notes) => self.notes.notes = notes.get(&self.vault.outpoint()),
+                        Err(e) => self.warning = e.into(),
+                    }
+                    match Labels::load_datadir(&revaultd) {
+                        Ok(labels) => {
+                            self.label.saved = labels.get(&self.vault.outpoint()).cloned();
+                            self.label.text = self.label.saved.clone().unwrap_or_default();
+                        }
+                        Err(e) => self.warning = e.into(),
+                    }
+                }
                 Err(e) => self.warning = Error::from(e).into(),
             },
             VaultMessage::UnvaultTransaction(res) => match res {
@@ -88,13 +341,48 @@ impl Vault {
                 Err(e) => self.warning = Error::from(e).into(),
             },
             VaultMessage::RevocationTransactions(res) => match res {
-                Ok(tx) => self.section = VaultSection::new_ack_section(tx),
+                Ok(tx) => {
+                    self.section = VaultSection::new_ack_section(
+                        tx,
+                        signatures::stakeholder_key(&revaultd, &self.vault),
+                    )
+                }
                 Err(e) => self.warning = Error::from(e).into(),
             },
-            VaultMessage::SelectRevault => {
+            VaultMessage::Revault if !matches!(self.section, VaultSection::Revault { .. }) => {
                 self.section = VaultSection::new_revault_section();
+                return self.section.update(revaultd, &mut self.vault, message);
             }
-            VaultMessage::Delegate => {
+            // The budget is checked against the delegated vaults of revaultd,
+            // the panel may not list them.
+            VaultMessage::Delegate if self.budget.max.is_some() => {
+                return Command::perform(
+                    list_vaults(
+                        revaultd,
+                        Some(&[VaultStatus::Active, VaultStatus::Activating]),
+                        None,
+                    ),
+                    VaultMessage::DelegatedVaults,
+                );
+            }
+            VaultMessage::DelegatedVaults(res) => match res {
+                Ok(vaults) => {
+                    let budget = self.budget.max.map(|max| DelegationBudget {
+                        max,
+                        delegated: vaults.iter().map(|v| v.amount).sum(),
+                    });
+                    self.budget.excess = budget.and_then(|b| b.excess(self.vault.amount));
+                    if self.budget.excess.is_none() {
+                        return Command::perform(
+                            get_unvault_tx(revaultd, self.vault.outpoint()),
+                            VaultMessage::UnvaultTransaction,
+                        );
+                    }
+                }
+                Err(e) => self.warning = Error::from(e).into(),
+            },
+            VaultMessage::Delegate | VaultMessage::OverrideBudget => {
+                self.budget.excess = None;
                 return Command::perform(
                     get_unvault_tx(revaultd, self.vault.outpoint()),
                     VaultMessage::UnvaultTransaction,
@@ -106,6 +394,45 @@ impl Vault {
                     VaultMessage::RevocationTransactions,
                 );
             }
+            VaultMessage::Note(msg) => self.on_note_message(&revaultd, msg),
+            VaultMessage::Label(msg) => self.on_label_message(&revaultd, msg),
+            VaultMessage::CheckSignatures => {
+                self.polling.checking = true;
+                return Command::perform(
+                    list_vaults(revaultd, None, Some(vec![self.vault.outpoint()])),
+                    VaultMessage::SignaturesChecked,
+                );
+            }
+            VaultMessage::SignaturesChecked(res) => {
+                self.polling.checking = false;
+                match res {
+                    Ok(vaults) => {
+                        let now = Utc::now().timestamp();
+                        self.polling.last_check = Some(now);
+                        self.polling.failures = 0;
+                        self.warning = None;
+                        let outpoint = self.vault.outpoint();
+                        if let Err(e) = journal::observe(&revaultd, &vaults, now) {
+                            tracing::error!("Failed to record the vault statuses: {}", e);
+                        }
+                        if let Some(vault) = vaults.into_iter().find(|v| v.outpoint() == outpoint) {
+                            let changed = vault.status != self.vault.status;
+                            self.vault = vault;
+                            // The transactions of the vault changed with its status.
+                            if changed {
+                                return Command::perform(
+                                    get_onchain_txs(revaultd, outpoint),
+                                    VaultMessage::OnChainTransactions,
+                                );
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        self.polling.failures += 1;
+                        self.warning = Error::from(e).into();
+                    }
+                }
+            }
             _ => {
                 return self.section.update(revaultd, &mut self.vault, message);
             }
@@ -114,12 +441,51 @@ impl Vault {
     }
 
     pub fn view(&mut self, ctx: &Context) -> Element<Message> {
+        let mut panel = Column::new().spacing(20);
+        if let Some(excess) = self.budget.excess {
+            panel = panel.push(self.budget.view.view(ctx, &self.vault, excess));
+        }
+        if SignaturesPolling::is_waiting(&self.vault) {
+            panel = panel.push(self.polling.view.view(
+                ctx,
+                &self.vault,
+                self.polling.last_check,
+                self.polling.failures,
+                self.polling.checking,
+            ));
+        }
+        if UnvaultCountdown::is_counting(&self.vault) {
+            if let Some(csv) = self.countdown.csv {
+                panel = panel.push(self.countdown.view.view(
+                    ctx,
+                    self.countdown.unvault.as_ref(),
+                    csv,
+                ));
+            }
+        }
         self.view.view(
             ctx,
             &self.vault,
             self.warning.as_ref(),
+            &self.actions,
             self.section.title(&self.vault),
-            self.section.view(ctx, &self.vault),
+            panel
+                .push(self.section.view(ctx, &self.vault))
+                .push(self.label.view.view(
+                    ctx,
+                    &self.vault,
+                    self.label.saved.as_ref(),
+                    &self.label.text,
+                ))
+                .push(self.notes.view.view(
+                    ctx,
+                    &self.vault,
+                    &self.notes.notes,
+                    &self.notes.text,
+                    &self.notes.status,
+                ))
+                .push(vault_timeline(ctx, &self.timeline))
+                .into(),
         )
     }
 
@@ -129,6 +495,18 @@ impl Vault {
             VaultMessage::OnChainTransactions,
         )
     }
+
+    /// Refreshes the vault being unvaulted at each poll, as a check of the
+    /// signatures: its transactions are reloaded once the unvault confirms.
+    pub fn poll(&self, revaultd: Arc<RevaultD>) -> Command<VaultMessage> {
+        if !UnvaultCountdown::is_counting(&self.vault) {
+            return Command::none();
+        }
+        Command::perform(
+            list_vaults(revaultd, None, Some(vec![self.vault.outpoint()])),
+            VaultMessage::SignaturesChecked,
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -147,13 +525,14 @@ pub enum VaultSection {
         emergency_tx: (Psbt, bool),
         emergency_unvault_tx: (Psbt, bool),
         cancel_tx: (Psbt, bool),
+        /// Key of the stakeholder signing the transactions.
+        signer_key: Option<PublicKey>,
         warning: Option<Error>,
         view: SecureVaultView,
         signer: SignState,
     },
-    /// Revault action ask the user if the vault that is unvaulting
-    /// should be revaulted and executes the revault command after
-    /// confirmation from the user.
+    /// Revault action executes the revault command once the user confirmed
+    /// the revault of the vault that is unvaulting.
     Revault {
         processing: bool,
         success: bool,
@@ -200,12 +579,14 @@ impl VaultSection {
         }
     }
 
-    pub fn new_ack_section(txs: RevocationTransactions) -> Self {
+    pub fn new_ack_section(txs: RevocationTransactions, signer_key: Option<PublicKey>) -> Self {
         Self::Secure {
             emergency_tx: (txs.emergency_tx.clone(), false),
             emergency_unvault_tx: (txs.emergency_unvault_tx.clone(), false),
             cancel_tx: (txs.cancel_tx.clone(), false),
-            signer: SignState::new(txs.emergency_tx, TransactionKind::Emergency),
+            signer: SignState::new(txs.emergency_tx, TransactionKind::Emergency)
+                .verified_by(signer_key),
+            signer_key,
             view: SecureVaultView::new(),
             warning: None,
         }
@@ -220,7 +601,8 @@ impl VaultSection {
         match message {
             VaultMessage::Revault => {
                 if let Self::Revault {
-                    processing,
+                    processing: processing @ false,
+                    success: false,
                     warning,
                     ..
                 } = self
@@ -247,6 +629,15 @@ impl VaultSection {
                             *success = true;
                             *warning = None;
                             vault.status = VaultStatus::Canceling;
+                            actions::record(&revaultd, [&vault.outpoint()], ActionKind::Revaulted);
+                        }
+                        // The unvault was spent or canceled since the vault was loaded.
+                        Err(RevaultDError::ServerError(RpcErrorCode::InvalidStatus, _)) => {
+                            *warning = Error::UnexpectedError(
+                                "The vault is no longer unvaulting, reload it to see its status"
+                                    .to_string(),
+                            )
+                            .into()
                         }
                         Err(e) => *warning = Error::from(e).into(),
                     }
@@ -259,6 +650,7 @@ impl VaultSection {
                     Ok(()) => {
                         *warning = None;
                         signer.update(SignMessage::Success);
+                        actions::record(&revaultd, [&vault.outpoint()], ActionKind::Delegated);
                     }
                     Err(e) => {
                         *warning = Some(Error::RevaultDError(e));
@@ -270,6 +662,7 @@ impl VaultSection {
                     Ok(()) => {
                         *warning = None;
                         signer.update(SignMessage::Success);
+                        actions::record(&revaultd, [&vault.outpoint()], ActionKind::Secured);
                     }
                     Err(e) => {
                         *warning = Some(Error::RevaultDError(e));
@@ -295,6 +688,7 @@ impl VaultSection {
                     emergency_tx,
                     emergency_unvault_tx,
                     cancel_tx,
+                    signer_key,
                     warning,
                     ..
                 } => {
@@ -307,12 +701,14 @@ impl VaultSection {
                                 *signer = SignState::new(
                                     emergency_unvault_tx.0.clone(),
                                     TransactionKind::EmergencyUnvault,
-                                );
+                                )
+                                .verified_by(*signer_key);
                             }
                             TransactionKind::EmergencyUnvault => {
                                 *emergency_unvault_tx = (psbt.clone(), true);
                                 *signer =
-                                    SignState::new(cancel_tx.0.clone(), TransactionKind::Cancel);
+                                    SignState::new(cancel_tx.0.clone(), TransactionKind::Cancel)
+                                        .verified_by(*signer_key);
                             }
                             TransactionKind::Cancel => {
                                 *cancel_tx = (psbt.clone(), true);
@@ -356,6 +752,7 @@ impl VaultSection {
                 warning,
                 view,
                 signer,
+                ..
             } => view
                 .view(
                     ctx,
@@ -1,22 +1,43 @@
+use std::collections::BTreeMap;
 use std::convert::From;
 use std::sync::Arc;
+use std::time::Duration;
 
-use iced::{Command, Element};
+use chrono::Utc;
+use iced::{keyboard, Command, Element, Subscription};
 
 use super::{
-    cmd::{get_blockheight, list_vaults},
+    cmd::{export_history, fund_flow, get_blockheight},
+    list_vaults_by_status,
     vault::{Vault, VaultListItem},
     State,
 };
 
+use crate::debounce::Debouncer;
 use crate::revaultd::{model, model::VaultStatus, RevaultD};
 
 use crate::app::{
+    archive::{self, Archive},
     error::Error,
-    message::{Message, VaultFilterMessage, VaultMessage},
-    view::{vault::VaultListItemView, Context, VaultsView},
+    fetch::VaultsFetch,
+    flow::Flow,
+    labels::{self, Labels},
+    message::{
+        ArchiveMessage, FlowMessage, LabelMessage, Message, PollMessage, VaultFilterMessage,
+        VaultLabelMessage, VaultMessage,
+    },
+    preferences::{self, Preferences, VaultLayout},
+    selection::Selection,
+    view::{
+        labeled_vault_card, labeled_vault_list_item, vault::VaultListItemView, Context, FlowView,
+        LabelAssignView, LabelManagerView, VaultsView,
+    },
 };
 
+/// Number of vaults rendered by page, the rows of the next pages are only
+/// built once the user asks for them.
+const VAULTS_PAGE: usize = 100;
+
 #[derive(Debug)]
 pub struct VaultsState {
     revaultd: Arc<RevaultD>,
@@ -25,32 +46,280 @@ pub struct VaultsState {
     blockheight: u64,
 
     vault_status_filter: &'static [VaultStatus],
+    /// vaults returned by revaultd for the status filter, listed by status.
+    fetch: VaultsFetch,
+    /// vaults displayed: the vaults in a final state are either in the
+    /// archive or in the list, depending on the archive filter.
     vaults: Vec<VaultListItem<VaultListItemView>>,
+    /// number of vaults rendered.
+    shown: usize,
     selected_vault: Option<Vault>,
 
+    labels: Labels,
+    /// vaults selected for a bulk action.
+    selection: Selection,
+    /// true if the shift key is pressed, a selection toggle then applies to a range.
+    shift_pressed: bool,
+    new_label: String,
+    /// new names edited in the label manager, by label.
+    label_renames: BTreeMap<String, String>,
+    label_manager: bool,
+    label_assign_view: LabelAssignView,
+    label_manager_view: LabelManagerView,
+    /// graph of the funds flow displayed in place of the list, if opened.
+    flow: Option<Flow>,
+    flow_view: FlowView,
+    /// the vaults layout and sort order are saved in the preferences.
+    preferences: Preferences,
+    archive: Archive,
+    /// number of days after which the vaults in a final state are archived.
+    archive_days: u32,
+    /// Maximum delegated amount, from the GUI configuration.
+    max_delegated_amount: Option<u64>,
+    /// true if the archived vaults are listed.
+    archived: bool,
+    search: String,
+    /// the vaults are filtered once the user stopped typing the search.
+    search_debounce: Debouncer,
+    /// path of the last exported history file.
+    exported_history: Option<std::path::PathBuf>,
+
     warning: Option<Error>,
 
-    /// loading is true until Message::Vaults is handled
+    /// loading is true until the vaults of a status are received
     loading: bool,
 }
 
 impl VaultsState {
-    pub fn new(revaultd: Arc<RevaultD>) -> Self {
+    pub fn new(
+        revaultd: Arc<RevaultD>,
+        archive_days: u32,
+        debounce: Duration,
+        max_delegated_amount: Option<u64>,
+    ) -> Self {
+        let labels = revaultd
+            .config
+            .network_datadir()
+            .map_err(Error::from)
+            .and_then(|mut path| {
+                path.push(labels::DEFAULT_FILE_NAME);
+                Labels::load(path)
+            });
+        let (labels, mut warning) = match labels {
+            Ok(labels) => (labels, None),
+            Err(e) => (Labels::default(), Some(e)),
+        };
+        let preferences = revaultd
+            .config
+            .network_datadir()
+            .map_err(Error::from)
+            .and_then(|mut path| {
+                path.push(preferences::DEFAULT_FILE_NAME);
+                Preferences::load(path)
+            })
+            .unwrap_or_else(|e| {
+                warning = Some(e);
+                Preferences::default()
+            });
+        let archive = Archive::load_datadir(&revaultd).unwrap_or_else(|e| {
+            warning = Some(e);
+            Archive::default()
+        });
         VaultsState {
             revaultd,
             view: VaultsView::new(),
             blockheight: 0,
             vault_status_filter: &VaultStatus::CURRENT,
+            fetch: VaultsFetch::new(&VaultStatus::CURRENT),
             vaults: Vec::new(),
+            shown: VAULTS_PAGE,
             selected_vault: None,
-            warning: None,
+            labels,
+            selection: Selection::default(),
+            shift_pressed: false,
+            new_label: String::new(),
+            label_renames: BTreeMap::new(),
+            label_manager: false,
+            label_assign_view: LabelAssignView::new(),
+            label_manager_view: LabelManagerView::new(),
+            flow: None,
+            flow_view: FlowView::new(),
+            preferences,
+            archive,
+            archive_days,
+            max_delegated_amount,
+            archived: false,
+            search: String::new(),
+            search_debounce: Debouncer::new(debounce),
+            exported_history: None,
+            warning,
             loading: true,
         }
     }
 
-    pub fn update_vaults(&mut self, vaults: Vec<model::Vault>) {
-        self.vaults = vaults.into_iter().map(VaultListItem::new).collect();
-        self.loading = false;
+    /// Records the vaults of the status, the list is rebuilt without
+    /// waiting for the vaults of the other statuses.
+    fn receive_vaults(&mut self, status: VaultStatus, vaults: Vec<model::Vault>) {
+        if self.fetch.receive(status, vaults) {
+            self.refresh();
+            self.loading = false;
+        }
+    }
+
+    /// Rebuilds the displayed list from the fetched vaults with the archive
+    /// filter, the search query and the sort order of the preferences.
+    /// The selected vaults hidden by the search stay selected, only the
+    /// vaults no longer returned by revaultd are unselected.
+    fn refresh(&mut self) {
+        let now = Utc::now().timestamp();
+        let mut vaults: Vec<model::Vault> = self
+            .fetch
+            .vaults()
+            .iter()
+            .filter(|vault| {
+                self.archive.is_archived(vault, now, self.archive_days) == self.archived
+                    && archive::matches(vault, self.labels.get(&vault.outpoint()), &self.search)
+            })
+            .cloned()
+            .collect();
+        self.preferences.vault_sort().sort(&mut vaults);
+        // The list is also rebuilt by the filters, the vaults are not highlighted.
+        self.vaults = VaultListItem::update_list(std::mem::take(&mut self.vaults), vaults, None);
+        let fetched: Vec<String> = self.fetch.vaults().iter().map(|v| v.outpoint()).collect();
+        self.selection.retain(&fetched);
+    }
+
+    fn filter_vaults(&mut self, statuses: &'static [VaultStatus]) -> Command<Message> {
+        self.shown = VAULTS_PAGE;
+        self.vault_status_filter = statuses;
+        // The vaults of the statuses kept by the filter stay displayed.
+        self.fetch.set_statuses(statuses);
+        self.loading = !self.fetch.has_received();
+        self.refresh();
+        list_vaults_by_status(&self.revaultd, statuses)
+    }
+
+    pub fn on_archive_message(&mut self, msg: ArchiveMessage) {
+        match msg {
+            ArchiveMessage::Selection => self
+                .archive
+                .archive(self.fetch.vaults(), self.selection.keys()),
+            ArchiveMessage::Restore => self.archive.restore(self.selection.keys()),
+        }
+        self.selection.clear();
+        if let Err(e) = self.archive.save() {
+            self.warning = Some(e);
+        }
+        self.refresh();
+    }
+
+    fn on_flow_message(&mut self, msg: FlowMessage) -> Command<Message> {
+        match msg {
+            FlowMessage::Toggle => {
+                if self.flow.take().is_none() {
+                    self.flow = Some(Flow::default());
+                    return Command::perform(fund_flow(self.revaultd.clone()), |res| {
+                        Message::Flow(FlowMessage::Loaded(res))
+                    });
+                }
+            }
+            FlowMessage::Loaded(res) => match res {
+                Ok((vaults, spends)) => {
+                    if let Some(f) = &mut self.flow {
+                        f.spends = Some(spends);
+                        f.vaults = vaults;
+                    }
+                }
+                Err(e) => {
+                    self.flow = None;
+                    self.warning = Error::from(e).into();
+                }
+            },
+            FlowMessage::Zoom(zoom_in) => {
+                if let Some(f) = &mut self.flow {
+                    f.zoom(zoom_in);
+                }
+            }
+            FlowMessage::Pan(x, y) => {
+                if let Some(f) = &mut self.flow {
+                    f.pan(x, y);
+                }
+            }
+            FlowMessage::Reset => {
+                if let Some(f) = &mut self.flow {
+                    f.reset();
+                }
+            }
+        }
+        Command::none()
+    }
+
+    fn outpoints(&self) -> Vec<String> {
+        self.vaults.iter().map(|v| v.outpoint.clone()).collect()
+    }
+
+    /// Exports the selected vaults as csv, in the displayed order.
+    /// In the archive, every listed vault is exported if none is selected.
+    fn export_selection(&self) -> String {
+        let mut csv = "outpoint,amount,status,label\n".to_string();
+        for item in &self.vaults {
+            let outpoint = &item.outpoint;
+            if self.selection.contains(outpoint) || (self.archived && self.selection.is_empty()) {
+                csv.push_str(&format!(
+                    "{},{},{},{}\n",
+                    outpoint,
+                    item.vault.amount,
+                    item.vault.status,
+                    self.labels
+                        .get(outpoint)
+                        .map(|l| l.replace(',', " "))
+                        .unwrap_or_default()
+                ));
+            }
+        }
+        csv
+    }
+
+    pub fn on_label_message(&mut self, msg: LabelMessage) {
+        match msg {
+            LabelMessage::SelectVault(outpoint, selected) => {
+                let outpoints = self.outpoints();
+                self.selection
+                    .toggle(&outpoints, outpoint, selected, self.shift_pressed);
+                return;
+            }
+            LabelMessage::LabelEdited(label) => {
+                self.new_label = label;
+                return;
+            }
+            LabelMessage::ToggleManager => {
+                self.label_manager = !self.label_manager;
+                self.label_renames.clear();
+                return;
+            }
+            LabelMessage::RenameEdited(label, name) => {
+                self.label_renames.insert(label, name);
+                return;
+            }
+            LabelMessage::Assign => {
+                self.labels.assign(self.selection.keys(), &self.new_label);
+                self.selection.clear();
+                self.new_label = String::new();
+            }
+            LabelMessage::Rename(label) => {
+                if let Some(name) = self.label_renames.remove(&label) {
+                    self.labels.rename(&label, &name);
+                }
+            }
+            LabelMessage::Merge(label, into) => self.labels.merge(&label, &into),
+            LabelMessage::Delete(label) => {
+                self.label_renames.remove(&label);
+                self.labels.delete(&label);
+            }
+        }
+        if let Err(e) = self.labels.save() {
+            self.warning = Some(e);
+        }
     }
 
     pub fn on_vault_select(&mut self, outpoint: String) -> Command<Message> {
@@ -61,12 +330,15 @@ impl VaultsState {
             }
         }
 
-        if let Some(selected) = self
+        let vault = self
             .vaults
             .iter()
-            .find(|vlt| vlt.vault.outpoint() == outpoint)
-        {
-            let selected_vault = Vault::new(selected.vault.clone());
+            .map(|item| &item.vault)
+            .chain(self.flow.iter().flat_map(|f| f.vaults.iter()))
+            .find(|vlt| vlt.outpoint() == outpoint);
+        if let Some(vault) = vault {
+            let selected_vault =
+                Vault::new(vault.clone()).with_delegation_budget(self.max_delegated_amount);
             let cmd = selected_vault.load(self.revaultd.clone());
             self.selected_vault = Some(selected_vault);
             return cmd.map(move |msg| Message::Vault(outpoint.clone(), msg));
@@ -78,8 +350,20 @@ impl VaultsState {
 impl State for VaultsState {
     fn update(&mut self, message: Message) -> Command<Message> {
         match message {
-            Message::Vaults(res) => match res {
-                Ok(vaults) => self.update_vaults(vaults),
+            Message::Poll(PollMessage::Tick) => {
+                let mut cmds = vec![self.load()];
+                if let Some(selected) = &self.selected_vault {
+                    let outpoint = selected.vault.outpoint();
+                    cmds.push(
+                        selected
+                            .poll(self.revaultd.clone())
+                            .map(move |msg| Message::Vault(outpoint.clone(), msg)),
+                    );
+                }
+                return Command::batch(cmds);
+            }
+            Message::VaultsOfStatus(status, res) => match res {
+                Ok(vaults) => self.receive_vaults(status, vaults),
                 Err(e) => self.warning = Error::from(e).into(),
             },
             Message::Vault(outpoint, VaultMessage::Select) => {
@@ -88,20 +372,70 @@ impl State for VaultsState {
             Message::Vault(outpoint, msg) => {
                 if let Some(selected) = &mut self.selected_vault {
                     if selected.vault.outpoint() == outpoint {
-                        return selected
+                        // The list displays the label saved from the vault panel.
+                        let label_saved =
+                            matches!(msg, VaultMessage::Label(VaultLabelMessage::Save));
+                        let cmd = selected
                             .update(self.revaultd.clone(), msg)
                             .map(move |msg| Message::Vault(outpoint.clone(), msg));
+                        if label_saved {
+                            match Labels::load_datadir(&self.revaultd) {
+                                Ok(labels) => self.labels = labels,
+                                Err(e) => self.warning = Some(e),
+                            }
+                        }
+                        return cmd;
                     }
                 }
             }
+            Message::ShowMoreVaults => self.shown += VAULTS_PAGE,
             Message::FilterVaults(VaultFilterMessage::Status(statuses)) => {
-                self.loading = true;
-                self.vault_status_filter = statuses;
+                self.archived = false;
+                return self.filter_vaults(statuses);
+            }
+            Message::FilterVaults(VaultFilterMessage::Archive) => {
+                self.archived = true;
+                return self.filter_vaults(&VaultStatus::MOVED);
+            }
+            Message::FilterVaults(VaultFilterMessage::Sort(sort)) => {
+                self.preferences.set_vault_sort(sort);
+                if let Err(e) = self.preferences.save() {
+                    self.warning = Some(e);
+                }
+                self.refresh();
+            }
+            Message::FilterVaults(VaultFilterMessage::Search(query)) => {
+                self.search = query;
+                return self
+                    .search_debounce
+                    .trigger(|id| Message::FilterVaults(VaultFilterMessage::SearchDebounced(id)));
+            }
+            Message::FilterVaults(VaultFilterMessage::SearchDebounced(id))
+                if self.search_debounce.is_due(id) =>
+            {
+                self.shown = VAULTS_PAGE;
+                self.refresh();
+            }
+            Message::Flow(msg) => return self.on_flow_message(msg),
+            Message::Label(msg) => self.on_label_message(msg),
+            Message::Archive(msg) => self.on_archive_message(msg),
+            Message::ExportHistory => {
                 return Command::perform(
-                    list_vaults(self.revaultd.clone(), Some(self.vault_status_filter), None),
-                    Message::Vaults,
+                    export_history(self.revaultd.clone()),
+                    Message::HistoryExported,
                 );
             }
+            Message::HistoryExported(res) => match res {
+                Ok(path) => self.exported_history = Some(path),
+                Err(e) => self.warning = Some(e),
+            },
+            Message::VaultLayout(layout) => {
+                self.preferences.set_vault_layout(layout);
+                if let Err(e) = self.preferences.save() {
+                    self.warning = Some(e);
+                }
+            }
+            Message::KeyboardModifiers(modifiers) => self.shift_pressed = modifiers.shift,
             Message::BlockHeight(b) => match b {
                 Ok(height) => self.blockheight = height,
                 Err(e) => self.warning = Error::from(e).into(),
@@ -112,25 +446,73 @@ impl State for VaultsState {
     }
 
     fn view(&mut self, ctx: &Context) -> Element<Message> {
+        let export = if self.selection.is_empty() && !self.archived {
+            None
+        } else {
+            Some(self.export_selection())
+        };
         if let Some(v) = &mut self.selected_vault {
             return v.view(ctx);
         }
+        if let Some(flow) = &self.flow {
+            return self.flow_view.view(ctx, self.warning.as_ref(), flow);
+        }
+        if self.label_manager {
+            return self.label_manager_view.view(
+                ctx,
+                self.warning.as_ref(),
+                &self.labels.usage(),
+                &self.label_renames,
+            );
+        }
+        let labels = &self.labels;
+        let selection = &self.selection;
+        let layout = self.preferences.vault_layout();
+        let total = self.vaults.len();
         self.view.view(
             ctx,
             self.warning.as_ref(),
-            self.vaults.iter_mut().map(|v| v.view(ctx)).collect(),
+            self.label_assign_view
+                .view(ctx, selection.len(), &self.new_label, export),
+            self.vaults
+                .iter_mut()
+                .take(self.shown)
+                .map(|v| {
+                    let outpoint = v.outpoint.clone();
+                    let selected = selection.contains(&outpoint);
+                    let label = labels.get(&outpoint);
+                    match layout {
+                        VaultLayout::List => {
+                            labeled_vault_list_item(v.view(ctx), outpoint, selected, label)
+                        }
+                        VaultLayout::Grid => {
+                            labeled_vault_card(v.card(ctx, label), outpoint, selected)
+                        }
+                    }
+                })
+                .collect(),
+            total,
             self.vault_status_filter,
+            (self.archived, &self.search, self.selection.len()),
+            (layout, self.preferences.vault_sort()),
+            self.exported_history.as_deref(),
             self.loading,
         )
     }
 
+    fn subscription(&self) -> Subscription<Message> {
+        iced_native::subscription::events_with(|event, _status| match event {
+            iced_native::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                Some(Message::KeyboardModifiers(modifiers))
+            }
+            _ => None,
+        })
+    }
+
     fn load(&self) -> Command<Message> {
         Command::batch(vec![
             Command::perform(get_blockheight(self.revaultd.clone()), Message::BlockHeight),
-            Command::perform(
-                list_vaults(self.revaultd.clone(), Some(self.vault_status_filter), None),
-                Message::Vaults,
-            ),
+            list_vaults_by_status(&self.revaultd, self.vault_status_filter),
         ])
     }
 }
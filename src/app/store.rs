@@ -0,0 +1,33 @@
+use std::path::Path;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::error::Error;
+use crate::disk;
+
+/// Reads a json file of the GUI local data,
+/// returns the default value if the file does not exist yet.
+pub fn load<T: DeserializeOwned + Default>(path: &Path) -> Result<T, Error> {
+    match std::fs::read(path) {
+        Ok(content) => serde_json::from_slice(&content).map_err(|e| {
+            Error::UnexpectedError(format!("Failed to parse {}: {}", path.display(), e))
+        }),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(Error::UnexpectedError(format!(
+            "Failed to read {}: {}",
+            path.display(),
+            e
+        ))),
+    }
+}
+
+/// Writes a json file of the GUI local data.
+pub fn save<T: Serialize>(path: &Path, value: &T) -> Result<(), Error> {
+    let content = serde_json::to_vec_pretty(value)
+        .map_err(|e| Error::UnexpectedError(format!("Failed to serialize data: {}", e)))?;
+    if let Some(dir) = path.parent() {
+        disk::check_dir(dir, content.len() as u64)?;
+    }
+    std::fs::write(path, content)
+        .map_err(|e| Error::UnexpectedError(format!("Failed to write {}: {}", path.display(), e)))
+}
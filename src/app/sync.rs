@@ -0,0 +1,54 @@
+//! Progress of the synchronisation of revaultd and of its bitcoind,
+//! displayed until revaultd reaches the tip of the chain.
+
+use std::time::{Duration, Instant};
+
+use crate::bitcoind::{BitcoindError, BlockchainInfo};
+
+#[derive(Debug, Clone)]
+pub struct SyncProgress {
+    /// Progress of revaultd, from 0 to 1.
+    pub revaultd: f64,
+    pub blockheight: u64,
+    /// Chain state of the bitcoind of revaultd, the GUI may fail to reach it.
+    pub bitcoind: Result<BlockchainInfo, BitcoindError>,
+}
+
+impl SyncProgress {
+    pub fn is_synced(&self) -> bool {
+        (self.revaultd - 1.0_f64).abs() < f64::EPSILON
+    }
+
+    /// Returns the number of blocks revaultd has yet to process,
+    /// None if bitcoind is not reachable.
+    pub fn remaining_blocks(&self) -> Option<u64> {
+        self.bitcoind
+            .as_ref()
+            .ok()
+            .map(|info| info.headers.saturating_sub(self.blockheight))
+    }
+}
+
+/// SyncRate measures the blocks processed since the first progress
+/// to estimate the remaining time.
+#[derive(Debug, Clone, Default)]
+pub struct SyncRate {
+    start: Option<(Instant, u64)>,
+}
+
+impl SyncRate {
+    /// Records the progress and returns the estimated time left,
+    /// None until a block was processed.
+    pub fn estimate(&mut self, progress: &SyncProgress, now: Instant) -> Option<Duration> {
+        let (start, height) = *self.start.get_or_insert((now, progress.blockheight));
+        let processed = progress.blockheight.saturating_sub(height);
+        let remaining = progress.remaining_blocks()?;
+        if processed == 0 {
+            return None;
+        }
+        let elapsed = now.duration_since(start).as_secs_f64();
+        Some(Duration::from_secs_f64(
+            elapsed * remaining as f64 / processed as f64,
+        ))
+    }
+}
@@ -0,0 +1,146 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::{error::Error, menu::Menu, store};
+use crate::revault::Role;
+
+pub const DEFAULT_FILE_NAME: &str = "revault_gui_tour.json";
+
+/// The tour script, steps can be edited without touching the view code.
+const SCRIPT: &str = include_str!("../../static/tour/steps.json");
+
+/// TourTarget is the part of the interface a tour step highlights.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TourTarget {
+    RolePicker,
+    Home,
+    Vaults,
+    Deposit,
+    Send,
+    CreateVaults,
+    DelegateFunds,
+    Emergency,
+    Network,
+    Settings,
+}
+
+impl TourTarget {
+    /// The menu displayed to highlight the target, if any.
+    pub fn menu(&self, role: Role) -> Option<Menu> {
+        match self {
+            // Those panels only exist for the stakeholders.
+            Self::CreateVaults | Self::Emergency if role == Role::Manager => None,
+            Self::RolePicker => None,
+            Self::Home => Some(Menu::Home),
+            Self::Vaults => Some(Menu::Vaults),
+            Self::Deposit => Some(Menu::Deposit),
+            Self::Send => Some(Menu::Send),
+            Self::CreateVaults => Some(Menu::CreateVaults),
+            Self::DelegateFunds => Some(Menu::DelegateFunds),
+            Self::Emergency => Some(Menu::Emergency),
+            Self::Network => Some(Menu::Network),
+            Self::Settings => Some(Menu::Settings),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TourStep {
+    pub roles: Vec<Role>,
+    pub target: TourTarget,
+    pub title: String,
+    pub text: String,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Progress {
+    /// roles for which the tour was completed or dismissed.
+    completed: Vec<Role>,
+}
+
+/// Tour is the guided tour displayed on the first launch for each role.
+#[derive(Debug)]
+pub struct Tour {
+    path: PathBuf,
+    progress: Progress,
+    steps: Vec<TourStep>,
+    role: Role,
+    /// index of the current step in the steps of the role,
+    /// None if the tour is not running.
+    current: Option<usize>,
+}
+
+impl Tour {
+    pub fn load(path: PathBuf) -> Result<Self, Error> {
+        let progress = store::load(&path)?;
+        let steps = serde_json::from_str(SCRIPT).map_err(|e| {
+            Error::UnexpectedError(format!("Failed to parse the tour script: {}", e))
+        })?;
+        Ok(Self {
+            path,
+            progress,
+            steps,
+            role: Role::Manager,
+            current: None,
+        })
+    }
+
+    /// Starts the tour of the role if it was never completed.
+    pub fn start(&mut self, role: Role) {
+        self.role = role;
+        self.current = if self.progress.completed.contains(&role) || self.total() == 0 {
+            None
+        } else {
+            Some(0)
+        };
+    }
+
+    fn role_steps(&self) -> impl Iterator<Item = &TourStep> {
+        let role = self.role;
+        self.steps
+            .iter()
+            .filter(move |step| step.roles.contains(&role))
+    }
+
+    pub fn total(&self) -> usize {
+        self.role_steps().count()
+    }
+
+    /// Returns the current step with its index.
+    pub fn step(&self) -> Option<(usize, &TourStep)> {
+        let current = self.current?;
+        self.role_steps().nth(current).map(|step| (current, step))
+    }
+
+    /// Goes to the next step and returns its target,
+    /// the tour is completed after the last step.
+    pub fn next(&mut self) -> Result<Option<TourTarget>, Error> {
+        if let Some(current) = self.current {
+            if current + 1 < self.total() {
+                self.current = Some(current + 1);
+            } else {
+                self.dismiss()?;
+            }
+        }
+        Ok(self.step().map(|(_, step)| step.target))
+    }
+
+    /// Goes to the previous step and returns its target.
+    pub fn previous(&mut self) -> Option<TourTarget> {
+        if let Some(current) = self.current {
+            self.current = Some(current.saturating_sub(1));
+        }
+        self.step().map(|(_, step)| step.target)
+    }
+
+    /// Stops the tour, it is not displayed anymore for the role.
+    pub fn dismiss(&mut self) -> Result<(), Error> {
+        self.current = None;
+        if !self.progress.completed.contains(&self.role) {
+            self.progress.completed.push(self.role);
+        }
+        store::save(&self.path, &self.progress)
+    }
+}
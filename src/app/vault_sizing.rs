@@ -0,0 +1,128 @@
+//! Vault sizing policy: the organization may set a target amount per vault,
+//! a planned deposit is then split in deposits of this amount, each one to
+//! its own address, so that the funds are secured and delegated vault by
+//! vault.
+
+use super::error::Error;
+use crate::{descriptor, revaultd::model::Vault};
+
+/// Maximum number of deposits of a split, the addresses stay well within
+/// the window of addresses revaultd watches after its first unused one.
+pub const MAX_DEPOSITS: usize = 20;
+
+/// Number of indexes searched for the address returned by revaultd,
+/// after the last index used by a vault.
+const SEARCH_WINDOW: u32 = 100;
+
+/// Returns the number of deposits of the planned amount: deposits of the
+/// vault size and a last one of the remainder, if any.
+pub fn count(amount: u64, size: u64) -> u64 {
+    if size == 0 {
+        return 1;
+    }
+    let remainder = amount % size;
+    amount / size + u64::from(remainder > 0)
+}
+
+/// Returns the amounts of the deposits of the planned amount, in satoshis,
+/// or their number if it is above MAX_DEPOSITS.
+pub fn split(amount: u64, size: u64) -> Result<Vec<u64>, u64> {
+    let count = count(amount, size);
+    if count > MAX_DEPOSITS as u64 {
+        return Err(count);
+    }
+    if size == 0 {
+        return Ok(vec![amount]);
+    }
+    let mut deposits = vec![size; (amount / size) as usize];
+    let remainder = amount % size;
+    if remainder > 0 {
+        deposits.push(remainder);
+    }
+    Ok(deposits)
+}
+
+/// Derives the addresses of the deposits, starting from the current deposit
+/// address returned by revaultd, which is the first address not used yet.
+pub fn addresses(
+    deposit_descriptor: &str,
+    network: bitcoin::Network,
+    current: &str,
+    vaults: &[Vault],
+    count: usize,
+) -> Result<Vec<String>, Error> {
+    let start = vaults
+        .iter()
+        .map(|v| v.derivation_index + 1)
+        .max()
+        .unwrap_or(0);
+    let window =
+        descriptor::deposit_addresses(deposit_descriptor, network, start..start + SEARCH_WINDOW)
+            .map_err(Error::UnexpectedError)?;
+    let first = window
+        .iter()
+        .position(|address| address == current)
+        .ok_or_else(|| {
+            Error::UnexpectedError(
+                "The deposit address of revaultd was not derived from the deposit descriptor"
+                    .to_string(),
+            )
+        })? as u32;
+    descriptor::deposit_addresses(
+        deposit_descriptor,
+        network,
+        start + first..start + first + count as u32,
+    )
+    .map_err(Error::UnexpectedError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::revaultd::model::VaultStatus;
+
+    const DEPOSIT_DESCRIPTOR: &str = "wsh(multi(2,xpub6DEzq5DNPx2rPiZJ7wvFhxRKUKDoV1GwjFmFdaxFfbsw9HsHyxc9usoRUMxqJaMrwoXh4apahsGEnjAS4cVCBDgqsx5Groww22AdHbgxVDg/*,xpub6F7Ltmsut73cbUNAzh44DkxncMeQfPtRzx7aoXjFbUdd7yofR2intU4b6QcsXot1jgmVjHB3iMybCLhtqvhAx3L4VPbGUz5fwuyNeTkypUP/*))";
+
+    fn vault(derivation_index: u32) -> Vault {
+        Vault {
+            address: String::new(),
+            amount: 0,
+            derivation_index,
+            received_at: 0,
+            status: VaultStatus::Active,
+            txid: String::new(),
+            updated_at: 0,
+            vout: 0,
+        }
+    }
+
+    #[test]
+    fn split_in_vault_sizes() {
+        assert_eq!(split(300, 100), Ok(vec![100, 100, 100]));
+        assert_eq!(split(250, 100), Ok(vec![100, 100, 50]));
+        assert_eq!(split(50, 100), Ok(vec![50]));
+        assert_eq!(split(0, 100), Ok(vec![]));
+        assert_eq!(split(250, 0), Ok(vec![250]));
+        assert_eq!(split(2000, 100), Ok(vec![100; MAX_DEPOSITS]));
+        // The deposits are counted before they are allocated.
+        assert_eq!(split(2001, 100), Err(21));
+        assert_eq!(split(u64::MAX, 1), Err(u64::MAX));
+    }
+
+    #[test]
+    fn addresses_follow_the_daemon_address() {
+        let network = bitcoin::Network::Bitcoin;
+        let window = descriptor::deposit_addresses(DEPOSIT_DESCRIPTOR, network, 0..6).unwrap();
+
+        let addrs = addresses(DEPOSIT_DESCRIPTOR, network, &window[2], &[], 3).unwrap();
+        assert_eq!(addrs, window[2..5]);
+
+        // The search starts after the last index used by a vault.
+        let vaults = [vault(0), vault(1)];
+        let addrs = addresses(DEPOSIT_DESCRIPTOR, network, &window[3], &vaults, 2).unwrap();
+        assert_eq!(addrs, window[3..5]);
+        assert!(addresses(DEPOSIT_DESCRIPTOR, network, &window[1], &vaults, 2).is_err());
+
+        assert!(addresses(DEPOSIT_DESCRIPTOR, network, "not an address", &[], 1).is_err());
+    }
+}
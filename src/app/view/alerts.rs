@@ -0,0 +1,94 @@
+use chrono::NaiveDateTime;
+use iced::{Align, Column, Container, Element, Length, Row};
+
+use crate::{
+    app::{
+        alerts::{Alert, AlertKind},
+        confirm,
+        message::Message,
+        view::Context,
+    },
+    ui::component::{button, card, text},
+};
+
+/// AlertsView is the list of the unacknowledged critical alerts,
+/// pinned above the panel.
+#[derive(Debug, Default)]
+pub struct AlertsView {
+    acknowledge_buttons: Vec<iced::button::State>,
+    cancel_buttons: Vec<iced::button::State>,
+}
+
+impl AlertsView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn view<'a>(&'a mut self, ctx: &Context, alerts: &[&Alert]) -> Element<'a, Message> {
+        self.acknowledge_buttons
+            .resize_with(alerts.len(), iced::button::State::new);
+        self.cancel_buttons
+            .resize_with(alerts.len(), iced::button::State::new);
+        let mut col = Column::new().spacing(10);
+        for ((alert, state), cancel_state) in alerts
+            .iter()
+            .zip(self.acknowledge_buttons.iter_mut())
+            .zip(self.cancel_buttons.iter_mut())
+        {
+            let mut detail = Column::new()
+                .push(text::bold(text::simple(&format!(
+                    "{}: {}",
+                    ctx.tr(alert.finding.kind.description()),
+                    alert.finding.reference
+                ))))
+                .push(text::small(&alert.finding.detail))
+                .push(text::small(&ctx.trf(
+                    "Since {}",
+                    &[&NaiveDateTime::from_timestamp(alert.since, 0)],
+                )))
+                .width(Length::Fill);
+            if let Some(e) = &alert.cancel_error {
+                detail = detail.push(text::danger(text::small(
+                    &ctx.trf("Failed to cancel: {}", &[&e]),
+                )));
+            }
+            let mut row = Row::new().push(detail);
+            // The funds of an unexpected unvault are sent back to a vault.
+            if alert.finding.kind == AlertKind::UnexpectedUnvault {
+                let mut cancel = button::primary(
+                    cancel_state,
+                    button::button_content(
+                        None,
+                        if alert.canceling {
+                            ctx.tr("Canceling...")
+                        } else {
+                            ctx.tr("Cancel the unvault")
+                        },
+                    ),
+                );
+                // The same confirmation as the revault of the vault panel.
+                if !alert.canceling {
+                    let outpoint = alert.finding.reference.clone();
+                    cancel = cancel.on_press(
+                        confirm::revault(outpoint.clone())
+                            .with_action(Message::CancelUnvault(outpoint))
+                            .ask(),
+                    );
+                }
+                row = row.push(cancel);
+            }
+            col = col.push(card::alert_warning(Container::new(
+                row.push(
+                    button::important(state, button::button_content(None, ctx.tr("Acknowledge")))
+                        .on_press(Message::AcknowledgeAlert(
+                            alert.finding.kind,
+                            alert.finding.reference.clone(),
+                        )),
+                )
+                .spacing(20)
+                .align_items(Align::Center),
+            )));
+        }
+        col.into()
+    }
+}
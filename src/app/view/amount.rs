@@ -0,0 +1,34 @@
+use iced::{
+    tooltip::{self, Tooltip},
+    Element,
+};
+
+use crate::{
+    app::{message::Message, view::Context},
+    conversion::Converter,
+    ui::component::{copy, TooltipStyle},
+};
+
+/// Returns the displayed amount with its exact value in satoshis as a
+/// tooltip, followed by a button copying the exact amount in BTC.
+pub fn exact<'a>(
+    ctx: &Context,
+    state: &'a mut iced::button::State,
+    amount: u64,
+    content: impl Into<Element<'a, Message>>,
+) -> Element<'a, Message> {
+    copy::copyable(
+        state,
+        Tooltip::new(
+            content,
+            ctx.converter.sats_string(amount),
+            tooltip::Position::Top,
+        )
+        .gap(5)
+        .size(15)
+        .padding(10)
+        .style(TooltipStyle),
+        Message::Clipboard(Converter::btc_string(amount)),
+    )
+    .into()
+}
@@ -1,25 +1,145 @@
-use iced::Element;
+use std::time::Duration;
+
+use iced::{Align, Column, Container, Element, Length, Row};
 
 use crate::{
-    app::{message::Message, view::layout},
-    ui::component,
+    app::{
+        message::Message,
+        sync::SyncProgress,
+        view::{layout, Context},
+    },
+    ui::component::{self, card},
 };
 
-pub fn charging_connect_view() -> Element<'static, Message> {
-    layout::cover(component::text::paragraph("Connecting to daemon..."))
+pub fn charging_connect_view(ctx: &Context) -> Element<'static, Message> {
+    layout::cover(component::text::paragraph(
+        ctx.tr("Connecting to daemon..."),
+    ))
+}
+
+pub fn charging_starting_daemon_view(ctx: &Context) -> Element<'static, Message> {
+    layout::cover(component::text::paragraph(ctx.tr("Starting daemon...")))
+}
+
+/// Number of steps of the progress bar.
+const PROGRESS_STEPS: u16 = 1000;
+
+pub fn charging_syncing_view(
+    ctx: &Context,
+    progress: Option<&SyncProgress>,
+    eta: Option<&Duration>,
+    frame: usize,
+) -> Element<'static, Message> {
+    // The dots move at each progress, the user sees that the GUI is alive.
+    let dots: String = (0..3)
+        .map(|i| if i <= frame % 3 { '.' } else { ' ' })
+        .collect();
+    let mut col = Column::new()
+        .push(component::text::bold(component::text::simple(
+            &ctx.trf("Synchronising with the Bitcoin network{}", &[&dots]),
+        )))
+        .spacing(10)
+        .align_items(Align::Center);
+
+    let ratio = progress.map(|p| p.revaultd.clamp(0.0, 1.0)).unwrap_or(0.0);
+    let done = (ratio * PROGRESS_STEPS as f64) as u16;
+    col = col
+        .push(
+            Row::new()
+                .push(
+                    card::progress_done()
+                        .height(Length::Units(10))
+                        .width(Length::FillPortion(done.max(1))),
+                )
+                .push(
+                    card::progress_todo()
+                        .height(Length::Units(10))
+                        .width(Length::FillPortion((PROGRESS_STEPS - done).max(1))),
+                )
+                .width(Length::Units(500)),
+        )
+        .push(component::text::simple(&format!("{:.2}%", ratio * 100.0)));
+
+    if let Some(progress) = progress {
+        match &progress.bitcoind {
+            Ok(info) => {
+                col = col
+                    .push(component::text::simple(&ctx.trf(
+                        "Block {} of {}, {} blocks left",
+                        &[
+                            &progress.blockheight,
+                            &info.headers,
+                            &progress.remaining_blocks().unwrap_or(0),
+                        ],
+                    )))
+                    .push(component::text::small(&ctx.trf(
+                        "bitcoind verification progress: {}%",
+                        &[&format!("{:.2}", info.verificationprogress * 100.0)],
+                    )));
+            }
+            Err(e) => {
+                col = col
+                    .push(component::text::simple(
+                        &ctx.trf("Block {}", &[&progress.blockheight]),
+                    ))
+                    .push(component::text::small(
+                        &ctx.trf("bitcoind is not reachable by the GUI: {}", &[&e]),
+                    ));
+            }
+        }
+    }
+
+    if let Some(eta) = eta {
+        col = col.push(component::text::small(
+            &ctx.trf("About {} left", &[&remaining_time(ctx, eta)]),
+        ));
+    }
+
+    layout::cover(
+        Container::new(col.push(component::text::small(
+            ctx.tr("The wallet opens once revaultd is synced."),
+        )))
+        .max_width(800),
+    )
 }
 
-pub fn charging_starting_daemon_view() -> Element<'static, Message> {
-    layout::cover(component::text::paragraph("Starting daemon..."))
+fn remaining_time(ctx: &Context, eta: &Duration) -> String {
+    let minutes = eta.as_secs() / 60;
+    if minutes >= 60 {
+        format!("{}h{:02}", minutes / 60, minutes % 60)
+    } else if minutes > 0 {
+        ctx.trf("{} min", &[&minutes])
+    } else {
+        ctx.tr("a minute").to_string()
+    }
 }
 
-pub fn charging_syncing_view(progress: &f64) -> Element<'static, Message> {
-    layout::cover(component::text::paragraph(&format!(
-        "Syncing... {}%",
-        progress
+pub fn charging_unresponsive_view(ctx: &Context, error: &str) -> Element<'static, Message> {
+    layout::cover(component::text::paragraph(&ctx.trf(
+        "The daemon is running but does not answer ({}), retrying...",
+        &[&error],
     )))
 }
 
-pub fn charging_error_view(error: &str) -> Element<'static, Message> {
-    layout::cover(component::text::paragraph(&format!("Error: {}", error)))
+pub fn charging_error_view(ctx: &Context, error: &str) -> Element<'static, Message> {
+    layout::cover(component::text::paragraph(&ctx.trf("Error: {}", &[&error])))
+}
+
+pub fn charging_daemon_error_view(ctx: &Context, error: &str) -> Element<'static, Message> {
+    layout::cover(
+        Container::new(
+            Column::new()
+                .push(component::text::bold(component::text::simple(
+                    ctx.tr("The daemon cannot be started"),
+                )))
+                .push(component::text::simple(error))
+                .push(component::text::small(ctx.tr(
+                    "Install a supported revaultd, or set revaultd_path \
+                    in the GUI configuration file to the revaultd binary to use.",
+                )))
+                .spacing(10)
+                .align_items(Align::Center),
+        )
+        .max_width(800),
+    )
 }
@@ -0,0 +1,106 @@
+use iced::{text_input, Align, Column, Container, Element, Length, Row, TextInput};
+
+use crate::{
+    app::{
+        confirm::{ConfirmLevel, Confirmation},
+        message::{ConfirmMessage, Message},
+        view::Context,
+    },
+    ui::{
+        color,
+        component::{button, card, text, ContainerBackgroundStyle},
+        icon::warning_icon,
+    },
+};
+
+/// ConfirmationView is the modal asking the user to confirm an action,
+/// displayed in place of the panel.
+#[derive(Debug)]
+pub struct ConfirmationView {
+    phrase_input: text_input::State,
+    cancel_button: iced::button::State,
+    confirm_button: iced::button::State,
+}
+
+impl ConfirmationView {
+    pub fn new() -> Self {
+        ConfirmationView {
+            phrase_input: text_input::State::focused(),
+            cancel_button: iced::button::State::new(),
+            confirm_button: iced::button::State::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        confirmation: &Confirmation,
+    ) -> Element<'a, Message> {
+        let mut col = Column::new()
+            .push(text::bold(text::simple(&confirmation.title)))
+            .push(text::simple(&confirmation.description))
+            .spacing(20)
+            .align_items(Align::Center);
+        if confirmation.level != ConfirmLevel::Info {
+            col = Column::new()
+                .push(warning_icon().color(color::PRIMARY))
+                .push(col)
+                .spacing(20)
+                .align_items(Align::Center);
+        }
+
+        if let ConfirmLevel::Critical(phrase) = confirmation.level {
+            col = col
+                .push(text::simple(&ctx.trf("Type {} to confirm:", &[&phrase])))
+                .push(
+                    Container::new(
+                        TextInput::new(
+                            &mut self.phrase_input,
+                            phrase,
+                            &confirmation.phrase,
+                            |text| Message::Confirm(ConfirmMessage::PhraseEdited(text)),
+                        )
+                        .size(15)
+                        .padding(10),
+                    )
+                    .width(Length::Units(300)),
+                );
+        }
+
+        let content = button::button_content(None, &confirmation.label);
+        let mut confirm_button = match confirmation.level {
+            ConfirmLevel::Info => button::primary(&mut self.confirm_button, content),
+            _ => button::important(&mut self.confirm_button, content),
+        };
+        if confirmation.can_confirm() {
+            confirm_button = confirm_button.on_press(Message::Confirm(ConfirmMessage::Confirm));
+        }
+
+        col = col.push(
+            Row::new()
+                .push(
+                    button::transparent(
+                        &mut self.cancel_button,
+                        button::button_content(None, ctx.tr("Cancel")),
+                    )
+                    .on_press(Message::Confirm(ConfirmMessage::Cancel)),
+                )
+                .push(confirm_button)
+                .spacing(10),
+        );
+
+        let modal = Container::new(col).padding(20).width(Length::Units(600));
+        Container::new(if confirmation.level == ConfirmLevel::Info {
+            card::white(modal)
+        } else {
+            card::border_primary(modal)
+        })
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .style(ContainerBackgroundStyle)
+        .padding(20)
+        .into()
+    }
+}
@@ -0,0 +1,58 @@
+use chrono::NaiveDateTime;
+use iced::{Column, Container, Element, Length};
+
+use crate::{
+    app::{
+        message::{DaemonMessage, Message},
+        view::Context,
+    },
+    ui::component::{button, card, text},
+};
+
+/// daemon_lost is the banner displayed while revaultd is unreachable,
+/// the panel is loaded again once it is back. A daemon stopped by the
+/// user can be started again from the banner.
+pub fn daemon_lost<'a>(
+    ctx: &Context,
+    start_button: &'a mut iced::button::State,
+    since: i64,
+    stopped: bool,
+) -> Element<'a, Message> {
+    let time = NaiveDateTime::from_timestamp(since, 0).format("%H:%M:%S");
+    let mut col = Column::new().spacing(10);
+    if stopped {
+        col = col
+            .push(text::bold(text::simple(
+                &ctx.trf("revaultd was stopped at {}", &[&time]),
+            )))
+            .push(text::small(ctx.tr(
+                "The vaults are not watched until revaultd is started again.",
+            )))
+            .push(
+                button::primary(
+                    start_button,
+                    button::button_content(None, ctx.tr("Start revaultd")),
+                )
+                .on_press(Message::Daemon(DaemonMessage::Start)),
+            );
+    } else {
+        col = col
+            .push(text::bold(text::simple(&ctx.trf(
+                "The connection to revaultd was lost at {}, reconnecting...",
+                &[&time],
+            ))))
+            .push(text::small(ctx.tr(
+                "The data displayed may be outdated until revaultd is back.",
+            )));
+    }
+    card::alert_warning(Container::new(col))
+        .width(Length::Fill)
+        .into()
+}
+
+/// toast is a short notice displayed above the panel for a few seconds.
+pub fn toast<'a>(ctx: &Context, message: &str) -> Element<'a, Message> {
+    card::success(Container::new(text::small(ctx.tr(message))))
+        .width(Length::Fill)
+        .into()
+}
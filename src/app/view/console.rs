@@ -0,0 +1,192 @@
+use chrono::NaiveDateTime;
+use iced::{scrollable, text_input, Align, Column, Container, Element, Length, Row, TextInput};
+
+use crate::{
+    app::{
+        console::Call,
+        message::{ConsoleMessage, Message},
+        view::{layout, sidebar::Sidebar, Context},
+    },
+    ui::component::{button, card, navbar, scroll, separation, text},
+};
+
+#[derive(Debug)]
+pub struct ConsoleView {
+    sidebar: Sidebar,
+    scroll: scrollable::State,
+    method_input: text_input::State,
+    params_input: text_input::State,
+    send_button: iced::button::State,
+    clear_button: iced::button::State,
+    /// Recall and copy buttons of the calls of the history.
+    call_buttons: Vec<(iced::button::State, iced::button::State)>,
+}
+
+impl ConsoleView {
+    pub fn new() -> Self {
+        ConsoleView {
+            sidebar: Sidebar::new(),
+            scroll: scrollable::State::new(),
+            method_input: text_input::State::new(),
+            params_input: text_input::State::new(),
+            send_button: iced::button::State::new(),
+            clear_button: iced::button::State::new(),
+            call_buttons: Vec::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        method: &str,
+        params: &str,
+        warning: Option<&str>,
+        processing: bool,
+        history: &[Call],
+    ) -> Element<'a, Message> {
+        let send_button = if processing || method.trim().is_empty() {
+            button::primary_disable(
+                &mut self.send_button,
+                button::button_content(
+                    None,
+                    if processing {
+                        ctx.tr("Sending...")
+                    } else {
+                        ctx.tr("Send")
+                    },
+                ),
+            )
+        } else {
+            button::primary(
+                &mut self.send_button,
+                button::button_content(None, ctx.tr("Send")),
+            )
+            .on_press(Message::Console(ConsoleMessage::Send))
+        };
+
+        let mut col = Column::new()
+            .push(
+                Row::new()
+                    .push(
+                        Container::new(text::bold(text::simple(ctx.tr("Developer console"))))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        button::cancel(
+                            &mut self.clear_button,
+                            button::button_content(None, ctx.tr("Clear history")),
+                        )
+                        .on_press(Message::Console(ConsoleMessage::ClearHistory)),
+                    )
+                    .align_items(Align::Center),
+            )
+            .push(card::alert_warning(Container::new(text::small(ctx.tr(
+                "The calls are sent to revaultd as they are, without the checks of the GUI. \
+                The emergency, revault, stop and setspendtx calls are refused: their panels \
+                ask for a confirmation",
+            )))))
+            .push(
+                Row::new()
+                    .push(
+                        TextInput::new(
+                            &mut self.method_input,
+                            ctx.tr("Method"),
+                            method,
+                            |method| Message::Console(ConsoleMessage::MethodEdited(method)),
+                        )
+                        .on_submit(Message::Console(ConsoleMessage::Send))
+                        .padding(10)
+                        .width(Length::FillPortion(1)),
+                    )
+                    .push(
+                        TextInput::new(
+                            &mut self.params_input,
+                            "Params: [\"positional\"] or {\"named\": 1}",
+                            params,
+                            |params| Message::Console(ConsoleMessage::ParamsEdited(params)),
+                        )
+                        .on_submit(Message::Console(ConsoleMessage::Send))
+                        .padding(10)
+                        .width(Length::FillPortion(3)),
+                    )
+                    .push(send_button)
+                    .spacing(10)
+                    .align_items(Align::Center),
+            )
+            .spacing(20);
+
+        if let Some(warning) = warning {
+            col = col.push(text::danger(text::small(warning)));
+        }
+
+        if history.is_empty() {
+            col = col.push(card::simple(Container::new(text::small(
+                ctx.tr("No call yet"),
+            ))));
+        }
+
+        self.call_buttons
+            .resize_with(history.len(), Default::default);
+        for (i, (call, (recall, copy))) in
+            history.iter().zip(self.call_buttons.iter_mut()).enumerate()
+        {
+            col = col.push(call_card(ctx, i, call, recall, copy));
+        }
+
+        layout::dashboard(
+            navbar(layout::navbar_warning(None)),
+            self.sidebar.view(ctx),
+            layout::main_section(Container::new(scroll(
+                &mut self.scroll,
+                Container::new(col),
+            ))),
+        )
+        .into()
+    }
+}
+
+fn call_card<'a>(
+    ctx: &Context,
+    i: usize,
+    call: &Call,
+    recall: &'a mut iced::button::State,
+    copy: &'a mut iced::button::State,
+) -> Container<'a, Message> {
+    let response = match &call.response {
+        Ok(json) => text::small(json),
+        Err(e) => text::danger(text::small(e)),
+    };
+    let content = match &call.response {
+        Ok(json) => json.clone(),
+        Err(e) => e.clone(),
+    };
+    card::simple(Container::new(
+        Column::new()
+            .push(
+                Row::new()
+                    .push(
+                        Container::new(text::small(&format!(
+                            "{} {} {}",
+                            NaiveDateTime::from_timestamp(call.time, 0).format("%H:%M:%S"),
+                            call.method,
+                            call.params
+                        )))
+                        .width(Length::Fill),
+                    )
+                    .push(
+                        button::transparent(
+                            recall,
+                            Container::new(text::small(ctx.tr("Edit again"))),
+                        )
+                        .on_press(Message::Console(ConsoleMessage::Recall(i))),
+                    )
+                    .push(button::clipboard(copy, Message::Clipboard(content)))
+                    .spacing(10)
+                    .align_items(Align::Center),
+            )
+            .push(separation().width(Length::Fill))
+            .push(response)
+            .spacing(10),
+    ))
+    .width(Length::Fill)
+}
@@ -0,0 +1,50 @@
+use iced::{Column, Container, Element, Length, Row};
+
+use crate::{
+    app::{message::Message, view::Context},
+    ui::component::{card, text},
+};
+
+/// degraded_mode is the banner displayed when revaultd cannot reach
+/// the coordinator, it lists the actions still available to the user.
+pub fn degraded_mode<'a>(ctx: &Context) -> Element<'a, Message> {
+    card::alert_warning(Container::new(
+        Column::new()
+            .push(text::bold(text::simple(ctx.tr(
+                "The coordinator is unreachable, Revault is running in degraded mode",
+            ))))
+            .push(
+                Row::new()
+                    .push(
+                        Column::new()
+                            .push(text::bold(text::small(ctx.tr("Still available:"))))
+                            .push(text::small(ctx.tr("- viewing vaults and transactions")))
+                            .push(text::small(ctx.tr("- getting a deposit address")))
+                            .push(text::small(
+                                ctx.tr("- signing revocation transactions locally"),
+                            ))
+                            .push(text::small(ctx.tr("- emergency and revaulting")))
+                            .width(Length::FillPortion(1)),
+                    )
+                    .push(
+                        Column::new()
+                            .push(text::bold(text::small(ctx.tr("Blocked until it is back:"))))
+                            .push(text::small(
+                                ctx.tr("- sharing signatures with the other participants"),
+                            ))
+                            .push(text::small(ctx.tr("- delegating vaults")))
+                            .push(text::small(
+                                ctx.tr("- announcing and broadcasting spend transactions"),
+                            ))
+                            .width(Length::FillPortion(1)),
+                    )
+                    .spacing(20),
+            )
+            .push(text::small(ctx.tr(
+                "The connection is checked again on the next action requiring the coordinator.",
+            )))
+            .spacing(10),
+    ))
+    .width(Length::Fill)
+    .into()
+}
@@ -1,67 +1,154 @@
-use iced::{scrollable, Align, Column, Container, Element, Length, QRCode, Row};
+use std::collections::HashMap;
+
+use iced::{scrollable, text_input, Align, Checkbox, Column, Container, Element, Length, Row};
 
 use crate::{
     app::{
+        deposit_addresses::{DerivedAddress, Usage},
         error::Error,
-        message::Message,
+        message::{DepositMessage, Message},
+        vault_sizing::{self, MAX_DEPOSITS},
         view::{layout, sidebar::Sidebar, Context},
     },
-    ui::component::{button, card, navbar, scroll, text},
+    conversion,
+    revaultd::config::Config,
+    ui::component::{button, card, copy, form, navbar, qr::Qr, scroll, separation, text},
 };
 
+/// Vault size of the organization, planned deposit and its split.
+type Sizing<'a> = (u64, &'a form::Value<String>, &'a [(u64, String)]);
+
 /// DepositView is the view rendering the deposit panel.
 /// this view is used by the Deposit State.
 #[derive(Debug)]
 pub struct DepositView {
     sidebar: Sidebar,
-    qr_code: Option<iced::qr_code::State>,
+    qr_code: Qr,
     scroll: scrollable::State,
     copy_button: iced::button::State,
+    amount_input: text_input::State,
+    planned_input: text_input::State,
+    split_button: iced::button::State,
+    split_copy_buttons: Vec<iced::button::State>,
 }
 
 impl DepositView {
     pub fn new() -> Self {
         DepositView {
-            qr_code: None,
+            qr_code: Qr::new(),
             sidebar: Sidebar::new(),
             scroll: scrollable::State::new(),
             copy_button: iced::button::State::default(),
+            amount_input: text_input::State::new(),
+            planned_input: text_input::State::new(),
+            split_button: iced::button::State::new(),
+            split_copy_buttons: Vec::new(),
         }
     }
 
-    // Address is loaded directly in the view in order to cache the created qrcode.
-    pub fn load(&mut self, address: &bitcoin::Address) {
-        self.qr_code = iced::qr_code::State::new(address.to_string()).ok();
+    pub fn amount_focused(&self) -> bool {
+        self.amount_input.is_focused()
+    }
+
+    // Data is loaded directly in the view in order to cache the created qrcode.
+    pub fn load(&mut self, data: &str) {
+        self.qr_code.set(data);
     }
 
+    /// The data is the deposit address or the BIP21 uri of a payment request.
+    /// The rotated address is the previous one, replaced after a deposit.
+    /// The sizing is the vault size, the planned deposit and its split.
+    #[allow(clippy::too_many_arguments)]
     pub fn view<'a>(
         &'a mut self,
         ctx: &Context,
         warning: Option<&Error>,
-        address: Option<&bitcoin::Address>,
+        data: Option<&str>,
+        bip21: bool,
+        amount: &form::Value<String>,
+        config: &Config,
+        rotated: Option<&str>,
+        current: Option<&str>,
+        addresses: &[DerivedAddress],
+        usage: Option<&HashMap<String, Usage>>,
+        sizing: Option<Sizing>,
     ) -> Element<'a, Message> {
         let mut col = Column::new().align_items(Align::Center).spacing(20);
-        if address.is_some() {
+        if let Some(rotated) = rotated {
+            col = col.push(
+                card::success(Container::new(text::small(&ctx.trf(
+                    "The address {} received a deposit, use the new address below for the next one",
+                    &[&rotated],
+                ))))
+                .width(Length::Fill),
+            );
+        }
+        if data.is_some() {
             col = col.push(text::bold(text::simple(
-                "Please, use this deposit address:",
+                ctx.tr("Please, use this deposit address:"),
             )))
         }
-        if let Some(qr_code) = self.qr_code.as_mut() {
-            col = col.push(Container::new(QRCode::new(qr_code).cell_size(5)));
+        if let Some(qr_code) = self.qr_code.view(5) {
+            col = col.push(Container::new(qr_code));
         }
-        if let Some(addr) = address {
-            col = col.push(Container::new(
-                Row::new()
-                    .push(Container::new(text::bold(text::small(&addr.to_string()))))
-                    .push(
-                        button::clipboard(
-                            &mut self.copy_button,
-                            Message::Clipboard(addr.to_string()),
+        if let Some(data) = data {
+            col = col.push(copy::copyable(
+                &mut self.copy_button,
+                text::bold(text::small(data)),
+                Message::Clipboard(data.to_string()),
+            ));
+            let mut request = Row::new()
+                .push(Checkbox::new(
+                    bip21,
+                    ctx.tr("Payment request (BIP21)"),
+                    |checked| Message::Deposit(DepositMessage::Bip21(checked)),
+                ))
+                .align_items(Align::Center)
+                .spacing(20);
+            if bip21 {
+                request = request.push(
+                    Container::new(
+                        form::Form::new(
+                            &mut self.amount_input,
+                            ctx.tr("Amount in BTC (optional)"),
+                            amount,
+                            |value| Message::Deposit(DepositMessage::AmountEdited(value)),
                         )
-                        .width(Length::Shrink),
+                        .warning(ctx.tr("Please enter a valid amount"))
+                        .size(15)
+                        .padding(10)
+                        .render(),
                     )
-                    .align_items(Align::Center),
-            ));
+                    .width(Length::Units(250)),
+                );
+            }
+            col = col.push(request);
+            if let Some((size, planned, split)) = sizing {
+                col = col
+                    .push(separation().width(Length::Fill))
+                    .push(vault_sizing(
+                        ctx,
+                        &mut self.planned_input,
+                        &mut self.split_button,
+                        &mut self.split_copy_buttons,
+                        size,
+                        planned,
+                        split,
+                    ));
+            }
+            col = col
+                .push(separation().width(Length::Fill))
+                .push(securing_requirements(ctx, config));
+        }
+        let previous: Vec<&DerivedAddress> = addresses
+            .iter()
+            .rev()
+            .filter(|a| Some(a.address.as_str()) != current)
+            .collect();
+        if !previous.is_empty() {
+            col = col
+                .push(separation().width(Length::Fill))
+                .push(address_history(ctx, &previous, usage));
         }
         layout::dashboard(
             navbar(layout::navbar_warning(warning)),
@@ -74,3 +161,213 @@ impl DepositView {
         .into()
     }
 }
+
+/// vault_sizing suggests splitting the planned deposit in deposits of the
+/// vault size of the organization, and lists the addresses of the split.
+fn vault_sizing<'a>(
+    ctx: &Context,
+    planned_input: &'a mut text_input::State,
+    split_button: &'a mut iced::button::State,
+    copy_buttons: &'a mut Vec<iced::button::State>,
+    size: u64,
+    planned: &form::Value<String>,
+    split: &[(u64, String)],
+) -> Container<'a, Message> {
+    let unit = ctx.converter.unit.to_string();
+    let mut col = Column::new()
+        .spacing(10)
+        .push(text::bold(text::simple(&ctx.trf(
+            "Vaults of {} {}",
+            &[&ctx.converter.amount_string(size), &unit],
+        ))))
+        .push(
+            Container::new(
+                form::Form::new(
+                    planned_input,
+                    ctx.tr("Planned deposit in BTC"),
+                    planned,
+                    |value| Message::Deposit(DepositMessage::PlannedAmountEdited(value)),
+                )
+                .warning(ctx.tr("Please enter a valid amount"))
+                .size(15)
+                .padding(10)
+                .render(),
+            )
+            .width(Length::Units(250)),
+        );
+    let deposits = conversion::parse_btc(&planned.value)
+        .filter(|_| planned.valid)
+        .map(|amount| vault_sizing::split(amount.as_sat(), size))
+        .unwrap_or_else(|| Ok(Vec::new()));
+    let deposits = match deposits {
+        Ok(deposits) => deposits,
+        Err(count) => {
+            col = col.push(text::small(&ctx.trf(
+                "The planned deposit requires {} vaults, split it in deposits of at most {} vaults",
+                &[&count, &MAX_DEPOSITS],
+            )));
+            Vec::new()
+        }
+    };
+    if split.is_empty() && !deposits.is_empty() {
+        col = col
+            .push(text::small(
+                &ctx.trf(
+                    "Suggested: {} deposits, {}",
+                    &[
+                        &deposits.len(),
+                        &deposits
+                            .iter()
+                            .map(|amount| {
+                                format!("{} {}", ctx.converter.amount_string(*amount), unit)
+                            })
+                            .collect::<Vec<String>>()
+                            .join(", "),
+                    ],
+                ),
+            ))
+            .push(
+                button::primary(
+                    split_button,
+                    button::button_content(
+                        None,
+                        &ctx.trf("Generate {} addresses", &[&deposits.len()]),
+                    ),
+                )
+                .on_press(Message::Deposit(DepositMessage::SplitDeposit)),
+            );
+    }
+    copy_buttons.resize_with(split.len(), iced::button::State::new);
+    for ((amount, address), copy_button) in split.iter().zip(copy_buttons.iter_mut()) {
+        col = col.push(
+            Row::new()
+                .push(
+                    Container::new(text::small(&format!(
+                        "{} {}",
+                        ctx.converter.amount_string(*amount),
+                        unit
+                    )))
+                    .width(Length::Units(150)),
+                )
+                .push(copy::copyable(
+                    copy_button,
+                    text::small(address),
+                    Message::Clipboard(address.clone()),
+                ))
+                .spacing(10)
+                .align_items(Align::Center),
+        );
+    }
+    Container::new(col).width(Length::Fill)
+}
+
+/// address_history lists the addresses previously displayed, the latest first,
+/// with the deposits they received. An address is never displayed again once
+/// used, a reused address links the deposits onchain.
+fn address_history<'a>(
+    ctx: &Context,
+    addresses: &[&DerivedAddress],
+    usage: Option<&HashMap<String, Usage>>,
+) -> Container<'a, Message> {
+    let mut col = Column::new().spacing(10).push(text::bold(text::simple(
+        ctx.tr("Previous deposit addresses"),
+    )));
+    let reused = addresses
+        .iter()
+        .filter_map(|a| usage.and_then(|usage| usage.get(&a.address)))
+        .any(Usage::is_reused);
+    if reused {
+        col = col.push(card::alert_warning(Container::new(text::small(ctx.tr(
+            "An address received several deposits, reusing an address links the deposits \
+            onchain. Always deposit to the address displayed above.",
+        )))));
+    }
+    for address in addresses {
+        let status = match usage.map(|usage| usage.get(&address.address)) {
+            None => text::small("..."),
+            Some(None) => text::small(ctx.tr("Unused")),
+            Some(Some(u)) => {
+                let status = text::small(&ctx.trf(
+                    if u.deposits > 1 {
+                        "{} {} {} in {} deposits"
+                    } else {
+                        "{} {} {} in {} deposit"
+                    },
+                    &[
+                        &ctx.tr(if u.is_reused() { "Reused:" } else { "Used:" }),
+                        &ctx.converter.amount_string(u.amount),
+                        &ctx.converter.unit,
+                        &u.deposits,
+                    ],
+                ));
+                if u.is_reused() {
+                    text::danger(status)
+                } else {
+                    status
+                }
+            }
+        };
+        col = col.push(
+            Row::new()
+                .push(Container::new(text::small(&address.address)).width(Length::Fill))
+                .push(
+                    Container::new(text::small(&ctx.converter.date_string(address.derived_at)))
+                        .width(Length::Units(150)),
+                )
+                .push(Container::new(status).width(Length::Units(250)))
+                .spacing(10)
+                .align_items(Align::Center),
+        );
+    }
+    Container::new(col).width(Length::Fill)
+}
+
+/// securing_requirements lists what the stakeholders will have to do once
+/// the deposit is confirmed, before the funds can be delegated.
+fn securing_requirements<'a>(ctx: &Context, config: &Config) -> Container<'a, Message> {
+    let mut col = Column::new().spacing(10).push(text::bold(text::simple(
+        ctx.tr("After the deposit is confirmed:"),
+    )));
+
+    col = col.push(text::simple(&match config.stakeholders_number() {
+        Some(n) => ctx.trf(
+            "- all {} stakeholders must sign the revocation transactions to secure the vault",
+            &[&n],
+        ),
+        None => ctx
+            .tr("- all stakeholders must sign the revocation transactions to secure the vault")
+            .to_string(),
+    }));
+
+    if let Some(stakeholder) = &config.stakeholder_config {
+        if stakeholder.watchtowers.is_empty() {
+            col = col.push(text::simple(
+                ctx.tr("- no watchtower is configured for your key"),
+            ));
+        } else {
+            col = col.push(text::simple(ctx.tr(
+                "- the revocation signatures must be acknowledged by your watchtowers:",
+            )));
+            for watchtower in &stakeholder.watchtowers {
+                col = col.push(text::small(&format!("    {}", watchtower.host)));
+            }
+        }
+    } else {
+        col = col.push(text::simple(ctx.tr(
+            "- each stakeholder's watchtowers must acknowledge the revocation signatures",
+        )));
+    }
+
+    col = col.push(text::simple(
+        ctx.tr("- the stakeholders must then delegate the vault to the managers"),
+    ));
+
+    if let Some(csv) = config.unvault_csv() {
+        col = col.push(text::small(&ctx.trf(
+            "A spend of the vault can be revoked during {} blocks after its unvault.",
+            &[&csv],
+        )));
+    }
+
+    Container::new(col).width(Length::Fill)
+}
@@ -1,7 +1,15 @@
-use iced::{scrollable, Align, Column, Container, Element, Length, Row};
+use bitcoin::Txid;
+use iced::{scrollable, Align, Checkbox, Column, Container, Element, Length, Row};
 
 use crate::{
-    app::{error::Error, menu::Menu, message::Message, view::Context},
+    app::{
+        confirm,
+        error::Error,
+        menu::Menu,
+        message::{EmergencyConfirmMessage, Message},
+        view::Context,
+    },
+    revaultd::model::Vault,
     ui::{
         color,
         component::{button, card, scroll, text, ContainerBackgroundStyle},
@@ -14,6 +22,7 @@ pub struct EmergencyView {
     scroll: scrollable::State,
     close_button: iced::button::State,
     emergency_button: iced::button::State,
+    retry_button: iced::button::State,
 }
 
 impl EmergencyView {
@@ -22,32 +31,20 @@ impl EmergencyView {
             scroll: scrollable::State::new(),
             close_button: iced::button::State::new(),
             emergency_button: iced::button::State::new(),
+            retry_button: iced::button::State::new(),
         }
     }
 
     pub fn view<'a>(
         &'a mut self,
         ctx: &Context,
-        vaults_number: usize,
-        funds_amount: u64,
+        sweepable: &[(&Vault, bool)],
+        remaining: &[&Vault],
         warning: Option<&Error>,
         loading: bool,
-        processing: bool,
-        success: bool,
     ) -> Element<'a, Message> {
         let mut col = Column::new()
-            .push(
-                Row::new().push(Column::new().width(Length::Fill)).push(
-                    Container::new(
-                        button::cancel(
-                            &mut self.close_button,
-                            Container::new(text::simple("X Close")).padding(10),
-                        )
-                        .on_press(Message::Menu(Menu::Home)),
-                    )
-                    .width(Length::Shrink),
-                ),
-            )
+            .push(close_row(ctx, &mut self.close_button))
             .spacing(50);
 
         if let Some(error) = warning {
@@ -57,89 +54,251 @@ impl EmergencyView {
             )))))
         }
 
+        let selected: Vec<&Vault> = sweepable
+            .iter()
+            .filter(|(_, selected)| *selected)
+            .map(|(vault, _)| *vault)
+            .collect();
+        let vaults_number = selected.len();
+        let funds_amount: u64 = selected.iter().map(|v| v.amount).sum();
+
         if !loading {
             let mut emergency_button = button::primary(
                 &mut self.emergency_button,
-                button::button_content(None, "Emergency"),
+                button::button_content(None, ctx.tr("Emergency")),
             );
 
-            if !processing {
-                emergency_button = emergency_button.on_press(Message::Emergency);
+            if vaults_number > 0 {
+                emergency_button = emergency_button.on_press(
+                    confirm::emergency(
+                        vaults_number,
+                        &format!(
+                            "{} {}",
+                            ctx.converter.amount_string(funds_amount),
+                            ctx.converter.unit
+                        ),
+                    )
+                    .ask(),
+                );
             }
 
-            if !success {
-                col = col.push(
-                    card::border_primary(Container::new(
-                        Column::new()
-                            .push(warning_icon().color(color::PRIMARY))
-                            .push(
-                                Column::new()
-                                    .push(
-                                        Row::new()
-                                            .push(text::simple("This action will send"))
-                                            .push(text::bold(text::simple(&format!(
-                                                " {} ",
-                                                ctx.converter.converts(funds_amount)
-                                            ))))
-                                            .push(text::simple(&ctx.converter.unit.to_string()))
-                                            .push(text::simple(" from"))
-                                            .push(text::bold(text::simple(&format!(
-                                                " {} ",
-                                                vaults_number
-                                            ))))
-                                            .push(text::simple("vaults")),
-                                    )
-                                    .push(text::simple("to the Emergency Deep Vault"))
-                                    .align_items(Align::Center),
-                            )
-                            .push(emergency_button)
-                            .spacing(30)
-                            .align_items(Align::Center),
-                    ))
-                    .padding(20)
-                    .align_x(Align::Center)
-                    .width(Length::Fill),
-                );
-            } else {
-                col = col.push(
-                    card::border_success(Container::new(
-                        Column::new()
-                            .push(warning_icon().color(color::SUCCESS))
-                            .push(
-                                Column::new()
-                                    .push(
-                                        Row::new()
-                                            .push(text::simple("Sending"))
-                                            .push(text::bold(text::simple(&format!(
-                                                " {} ",
-                                                ctx.converter.converts(funds_amount)
-                                            ))))
-                                            .push(text::simple(&ctx.converter.unit.to_string()))
-                                            .push(text::simple(" from"))
-                                            .push(text::bold(text::simple(&format!(
-                                                " {} ",
-                                                vaults_number
-                                            ))))
-                                            .push(text::simple("vaults")),
-                                    )
-                                    .push(text::simple("to the Emergency Deep Vault"))
-                                    .align_items(Align::Center),
-                            )
-                            .spacing(30)
-                            .align_items(Align::Center),
-                    ))
+            let mut summary_col = Column::new()
+                .push(warning_icon().color(color::PRIMARY))
+                .push(summary(ctx, funds_amount, vaults_number))
+                .spacing(30)
+                .align_items(Align::Center);
+            let left_out = sweepable.len() - vaults_number;
+            if left_out > 0 {
+                summary_col = summary_col.push(text::small(&ctx.trf(
+                    "{} secured vaults are left out and stay where they are",
+                    &[&left_out],
+                )));
+            }
+            col = col.push(
+                card::border_primary(Container::new(summary_col.push(emergency_button)))
                     .padding(20)
                     .align_x(Align::Center)
                     .width(Length::Fill),
-                );
+            );
+            col = col.push(selectable_list(ctx, sweepable));
+            if !remaining.is_empty() {
+                col = col.push(vault_list(
+                    ctx,
+                    "Not secured yet, left out of the emergency",
+                    remaining,
+                ));
             }
         }
 
-        Container::new(scroll(&mut self.scroll, Container::new(col)))
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .style(ContainerBackgroundStyle)
+        layout(&mut self.scroll, col)
+    }
+
+    /// Shows the broadcast of the emergency transaction of each swept vault,
+    /// then the vaults whose broadcast failed.
+    pub fn progress<'a>(
+        &'a mut self,
+        ctx: &Context,
+        swept: &[(Vault, Option<Result<Txid, Error>>)],
+    ) -> Element<'a, Message> {
+        let pending = swept.iter().filter(|(_, res)| res.is_none()).count();
+        let failed = swept
+            .iter()
+            .filter(|(_, res)| matches!(res, Some(Err(_))))
+            .count();
+
+        let mut status = Column::new().spacing(20).align_items(Align::Center);
+        if pending > 0 {
+            status = status
+                .push(warning_icon().color(color::PRIMARY))
+                .push(text::bold(text::simple(&ctx.trf(
+                    "Broadcasting the emergency transactions, {} of {} done",
+                    &[&(swept.len() - pending), &swept.len()],
+                ))));
+        } else if failed > 0 {
+            status = status
+                .push(warning_icon().color(color::PRIMARY))
+                .push(text::bold(text::danger(text::simple(&ctx.trf(
+                    "{} of {} emergency broadcasts failed",
+                    &[&failed, &swept.len()],
+                )))))
+                .push(
+                    button::primary(
+                        &mut self.retry_button,
+                        button::button_content(None, ctx.tr("Retry the failed broadcasts")),
+                    )
+                    .on_press(Message::EmergencyConfirm(EmergencyConfirmMessage::Retry)),
+                );
+        } else {
+            status = status
+                .push(warning_icon().color(color::SUCCESS))
+                .push(text::simple(ctx.tr(
+                    "The emergency transactions of the vaults were broadcast",
+                )))
+                .push(summary(
+                    ctx,
+                    swept.iter().map(|(v, _)| v.amount).sum(),
+                    swept.len(),
+                ));
+        }
+
+        let status = Container::new(status)
             .padding(20)
-            .into()
+            .align_x(Align::Center)
+            .width(Length::Fill);
+        let mut col = Column::new().spacing(50);
+        // Leaving the page would drop the results of the broadcasts.
+        if pending == 0 {
+            col = col.push(close_row(ctx, &mut self.close_button));
+        }
+        col = col.push(if pending == 0 && failed == 0 {
+            card::border_success(status)
+        } else {
+            card::border_primary(status)
+        });
+
+        let mut list = Column::new().spacing(10);
+        for (vault, res) in swept {
+            let result = match res {
+                None => text::small(ctx.tr("Broadcasting")),
+                Some(Ok(txid)) => text::small(&ctx.trf("Broadcast, txid {}", &[txid])),
+                Some(Err(e)) => text::danger(text::small(&ctx.trf("Failed: {}", &[e]))),
+            };
+            list = list.push(
+                Row::new()
+                    .spacing(10)
+                    .align_items(Align::Center)
+                    .push(Container::new(text::small(&vault.address)).width(Length::Fill))
+                    .push(text::bold(text::small(&format!(
+                        "{} {}",
+                        ctx.converter.amount_string(vault.amount),
+                        ctx.converter.unit
+                    ))))
+                    .push(result),
+            );
+        }
+        col = col.push(card::white(Container::new(list)).width(Length::Fill));
+
+        layout(&mut self.scroll, col)
+    }
+}
+
+fn close_row<'a>(ctx: &Context, state: &'a mut iced::button::State) -> Row<'a, Message> {
+    Row::new().push(Column::new().width(Length::Fill)).push(
+        Container::new(
+            button::cancel(
+                state,
+                Container::new(text::simple(ctx.tr("X Close"))).padding(10),
+            )
+            .on_press(Message::Menu(Menu::Home)),
+        )
+        .width(Length::Shrink),
+    )
+}
+
+fn layout<'a>(state: &'a mut scrollable::State, col: Column<'a, Message>) -> Element<'a, Message> {
+    Container::new(scroll(state, Container::new(col)))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .style(ContainerBackgroundStyle)
+        .padding(20)
+        .into()
+}
+
+/// The amount of the vaults sent to the deep vault.
+fn summary<'a>(ctx: &Context, amount: u64, vaults_number: usize) -> Column<'a, Message> {
+    Column::new()
+        .push(
+            Row::new()
+                .push(text::simple(ctx.tr("This action will send")))
+                .push(text::bold(text::simple(&format!(
+                    " {} ",
+                    ctx.converter.amount_string(amount)
+                ))))
+                .push(text::simple(&ctx.converter.unit.to_string()))
+                .push(text::simple(ctx.tr(" from")))
+                .push(text::bold(text::simple(&format!(" {} ", vaults_number))))
+                .push(text::simple(ctx.tr("vaults"))),
+        )
+        .push(text::simple(ctx.tr("to the Emergency Deep Vault")))
+        .align_items(Align::Center)
+}
+
+/// Lists the vaults that can be swept, the checked ones are swept to the
+/// deep vault.
+fn selectable_list<'a>(ctx: &Context, vaults: &[(&Vault, bool)]) -> Element<'a, Message> {
+    let mut col = Column::new()
+        .spacing(10)
+        .push(text::bold(text::simple(&format!(
+            "{} ({})",
+            ctx.tr("Secured vaults, the checked ones are swept to the Emergency Deep Vault"),
+            vaults.len()
+        ))));
+    for (vault, selected) in vaults {
+        let outpoint = vault.outpoint();
+        col = col.push(
+            Row::new()
+                .spacing(10)
+                .align_items(Align::Center)
+                .push(Checkbox::new(*selected, "", move |checked| {
+                    Message::EmergencyConfirm(EmergencyConfirmMessage::SelectVault(
+                        outpoint.clone(),
+                        checked,
+                    ))
+                }))
+                .push(Container::new(text::small(&vault.address)).width(Length::Fill))
+                .push(text::small(&vault.status.to_string()))
+                .push(text::bold(text::small(&format!(
+                    "{} {}",
+                    ctx.converter.amount_string(vault.amount),
+                    ctx.converter.unit
+                )))),
+        );
+    }
+    card::white(Container::new(col)).width(Length::Fill).into()
+}
+
+/// Lists the vaults under the title.
+fn vault_list<'a>(ctx: &Context, title: &str, vaults: &[&Vault]) -> Element<'a, Message> {
+    let mut col = Column::new()
+        .spacing(10)
+        .push(text::bold(text::simple(&format!(
+            "{} ({})",
+            ctx.tr(title),
+            vaults.len()
+        ))));
+    for vault in vaults {
+        col = col.push(
+            Row::new()
+                .spacing(10)
+                .align_items(Align::Center)
+                .push(Container::new(text::small(&vault.address)).width(Length::Fill))
+                .push(text::small(&vault.status.to_string()))
+                .push(text::bold(text::small(&format!(
+                    "{} {}",
+                    ctx.converter.amount_string(vault.amount),
+                    ctx.converter.unit
+                )))),
+        );
     }
+    card::white(Container::new(col)).width(Length::Fill).into()
 }
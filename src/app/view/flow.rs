@@ -0,0 +1,257 @@
+use iced::{scrollable, Align, Column, Container, Element, Length, Row, Space};
+
+use crate::{
+    app::{
+        error::Error,
+        flow::{Flow, FlowOutput, FlowSpend, FlowVault, PAN_STEP},
+        message::{FlowMessage, Message, VaultMessage},
+        view::{layout, sidebar::Sidebar, Context},
+    },
+    ui::{
+        component::{button, card, navbar, scroll, text},
+        font,
+    },
+};
+
+/// Width of the nodes of the graph at the zoom level of 100%.
+const NODE_WIDTH: u16 = 260;
+
+/// FlowView draws the spend transactions of the vaults as a graph: each
+/// spend is a row linking the vaults it spends to its outputs, the change
+/// leading to the vault it created.
+#[derive(Debug)]
+pub struct FlowView {
+    scroll: scrollable::State,
+    sidebar: Sidebar,
+    close_button: iced::button::State,
+    zoom_in_button: iced::button::State,
+    zoom_out_button: iced::button::State,
+    reset_button: iced::button::State,
+    pan_buttons: [iced::button::State; 4],
+    /// one button by vault node, the nodes are in the order of the graph.
+    vault_buttons: Vec<iced::button::State>,
+}
+
+impl FlowView {
+    pub fn new() -> Self {
+        FlowView {
+            scroll: scrollable::State::new(),
+            sidebar: Sidebar::new(),
+            close_button: iced::button::State::new(),
+            zoom_in_button: iced::button::State::new(),
+            zoom_out_button: iced::button::State::new(),
+            reset_button: iced::button::State::new(),
+            pan_buttons: Default::default(),
+            vault_buttons: Vec::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        warning: Option<&Error>,
+        flow: &Flow,
+    ) -> Element<'a, Message> {
+        let [left, right, up, down] = &mut self.pan_buttons;
+        let mut toolbar = Row::new()
+            .push(Container::new(text::bold(text::simple(ctx.tr("Fund flow")))).width(Length::Fill))
+            .spacing(10)
+            .align_items(Align::Center);
+        for (state, label, msg) in [
+            (&mut self.zoom_out_button, "-", FlowMessage::Zoom(false)),
+            (&mut self.zoom_in_button, "+", FlowMessage::Zoom(true)),
+            (left, "<", FlowMessage::Pan(-PAN_STEP, 0)),
+            (right, ">", FlowMessage::Pan(PAN_STEP, 0)),
+            (up, "^", FlowMessage::Pan(0, -PAN_STEP)),
+            (down, "v", FlowMessage::Pan(0, PAN_STEP)),
+        ] {
+            toolbar = toolbar.push(
+                button::transparent(state, button::button_content(None, label))
+                    .on_press(Message::Flow(msg)),
+            );
+        }
+        toolbar = toolbar
+            .push(text::small(&format!("{}%", flow.zoom)))
+            .push(
+                button::transparent(
+                    &mut self.reset_button,
+                    button::button_content(None, ctx.tr("Reset")),
+                )
+                .on_press(Message::Flow(FlowMessage::Reset)),
+            )
+            .push(
+                button::cancel(
+                    &mut self.close_button,
+                    Container::new(text::simple(ctx.tr("X Close"))).padding(10),
+                )
+                .on_press(Message::Flow(FlowMessage::Toggle)),
+            );
+
+        let mut col = Column::new().spacing(20).push(toolbar);
+        match &flow.spends {
+            None => col = col.push(text::simple(ctx.tr("Loading..."))),
+            Some(spends) if spends.is_empty() => {
+                col = col.push(text::simple(ctx.tr("No spend transaction yet")))
+            }
+            Some(spends) => {
+                let nodes = spends
+                    .iter()
+                    .map(|s| {
+                        s.inputs.len() + s.outputs.iter().filter(|o| o.vault.is_some()).count()
+                    })
+                    .sum();
+                self.vault_buttons
+                    .resize_with(nodes, iced::button::State::new);
+                let mut buttons = self.vault_buttons.iter_mut();
+                let mut graph = Column::new().spacing(flow.scaled(30));
+                for spend in spends {
+                    graph = graph.push(spend_row(ctx, flow, spend, &mut buttons));
+                }
+                col = col.push(
+                    Column::new()
+                        .push(Space::with_height(Length::Units(flow.pan.1)))
+                        .push(
+                            Row::new()
+                                .push(Space::with_width(Length::Units(flow.pan.0)))
+                                .push(graph),
+                        ),
+                );
+            }
+        }
+
+        layout::dashboard(
+            navbar(layout::navbar_warning(warning)),
+            self.sidebar.view(ctx),
+            layout::main_section(Container::new(scroll(
+                &mut self.scroll,
+                Container::new(col),
+            ))),
+        )
+        .into()
+    }
+}
+
+fn spend_row<'a>(
+    ctx: &Context,
+    flow: &Flow,
+    spend: &FlowSpend,
+    buttons: &mut impl Iterator<Item = &'a mut iced::button::State>,
+) -> Element<'a, Message> {
+    let size = flow.scaled(font::scaled(15));
+    let mut inputs = Column::new().spacing(flow.scaled(10));
+    for input in &spend.inputs {
+        if let Some(state) = buttons.next() {
+            inputs = inputs.push(vault_node(ctx, flow, input, state));
+        }
+    }
+    let mut outputs = Column::new().spacing(flow.scaled(10));
+    for output in &spend.outputs {
+        let state = if output.vault.is_some() {
+            buttons.next()
+        } else {
+            None
+        };
+        outputs = outputs.push(output_node(ctx, flow, output, state));
+    }
+    let spend_node = card::white(Container::new(
+        Column::new()
+            .push(text::bold(text::small(ctx.tr("Spend"))).size(size))
+            .push(text::small(&short(&spend.txid)).size(size)),
+    ))
+    .width(Length::Units(flow.scaled(NODE_WIDTH)));
+    Row::new()
+        .push(inputs)
+        .push(text::bold(text::simple("→")).size(flow.scaled(font::text_size())))
+        .push(spend_node)
+        .push(text::bold(text::simple("→")).size(flow.scaled(font::text_size())))
+        .push(outputs)
+        .spacing(flow.scaled(20))
+        .align_items(Align::Center)
+        .into()
+}
+
+fn vault_node<'a>(
+    ctx: &Context,
+    flow: &Flow,
+    vault: &FlowVault,
+    state: &'a mut iced::button::State,
+) -> Element<'a, Message> {
+    let size = flow.scaled(font::scaled(15));
+    let mut col = Column::new().push(text::bold(text::small(&short(&vault.outpoint))).size(size));
+    if let Some(amount) = vault.amount {
+        col = col.push(
+            text::small(&format!(
+                "{} {}",
+                ctx.converter.amount_string(amount),
+                ctx.converter.unit
+            ))
+            .size(size),
+        );
+    }
+    col = col.push(
+        text::small(
+            &vault
+                .status
+                .as_ref()
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| ctx.tr("Unknown vault").to_string()),
+        )
+        .size(size),
+    );
+    let node = button::white_card_button(state, Container::new(col))
+        .width(Length::Units(flow.scaled(NODE_WIDTH)));
+    if vault.amount.is_some() {
+        node.on_press(Message::Vault(vault.outpoint.clone(), VaultMessage::Select))
+            .into()
+    } else {
+        node.into()
+    }
+}
+
+fn output_node<'a>(
+    ctx: &Context,
+    flow: &Flow,
+    output: &FlowOutput,
+    state: Option<&'a mut iced::button::State>,
+) -> Element<'a, Message> {
+    let size = flow.scaled(font::scaled(15));
+    let mut col = Column::new()
+        .push(text::bold(text::small(&output.kind.to_string())).size(size))
+        .push(
+            text::small(&format!(
+                "{} {}",
+                ctx.converter.amount_string(output.value),
+                ctx.converter.unit
+            ))
+            .size(size),
+        );
+    if let Some(address) = &output.address {
+        col = col.push(text::small(&short(address)).size(size));
+    }
+    match (&output.vault, state) {
+        (Some(vault), Some(state)) => {
+            let col = col.push(
+                text::success(text::small(
+                    &ctx.trf("→ vault {}", &[&short(&vault.outpoint)]),
+                ))
+                .size(size),
+            );
+            button::white_card_button(state, Container::new(col))
+                .width(Length::Units(flow.scaled(NODE_WIDTH)))
+                .on_press(Message::Vault(vault.outpoint.clone(), VaultMessage::Select))
+                .into()
+        }
+        // the change is not a vault until revaultd saw its deposit.
+        _ => card::white(Container::new(col))
+            .width(Length::Units(flow.scaled(NODE_WIDTH)))
+            .into(),
+    }
+}
+
+/// Shortens a txid, an outpoint or an address to its first and last characters.
+fn short(s: &str) -> String {
+    if s.len() <= 20 {
+        return s.to_string();
+    }
+    format!("{}...{}", &s[..10], &s[s.len() - 8..])
+}
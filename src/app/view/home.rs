@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use chrono::NaiveDateTime;
 use iced::{
     scrollable,
     tooltip::{self, Tooltip},
@@ -8,16 +9,19 @@ use iced::{
 
 use crate::{
     app::{
+        attention::Attention,
+        deadline::{CancelDeadline, CANCEL_FEERATE},
         error::Error,
+        key_checks::KeyCheck,
         menu::Menu,
-        message::Message,
-        view::{layout, sidebar::Sidebar, Context},
+        message::{KeyCheckMessage, Message},
+        view::{amount, layout, sidebar::Sidebar, Context},
     },
     revaultd::model::VaultStatus,
     ui::{
-        color,
-        component::{button, card, navbar, scroll, text, TooltipStyle},
+        component::{button, card, form, navbar, scroll, text, TooltipStyle},
         icon::{history_icon, person_check_icon, shield_check_icon, tooltip_icon},
+        theme,
     },
 };
 
@@ -26,6 +30,9 @@ pub struct ManagerHomeView {
     sidebar: Sidebar,
     scroll: scrollable::State,
     deposit_button: iced::button::State,
+    /// copy buttons of the active and of the inactive funds.
+    copy_buttons: [iced::button::State; 2],
+    attention: AttentionInbox,
 }
 
 impl ManagerHomeView {
@@ -34,28 +41,43 @@ impl ManagerHomeView {
             scroll: scrollable::State::new(),
             sidebar: Sidebar::new(),
             deposit_button: iced::button::State::default(),
+            copy_buttons: Default::default(),
+            attention: AttentionInbox::new(),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn view<'a>(
         &'a mut self,
         ctx: &Context,
         warning: Option<&Error>,
+        attention: &[Attention],
         spend_txs: Vec<Element<'a, Message>>,
         moving_vaults: Vec<Element<'a, Message>>,
         active_funds: u64,
         inactive_funds: u64,
     ) -> Element<'a, Message> {
-        let mut content = Column::new().push(manager_overview(ctx, active_funds, inactive_funds));
+        let mut content = Column::new();
+        if let Some(inbox) = self.attention.view(ctx, attention) {
+            content = content.push(inbox);
+        }
+        content = content.push(manager_overview(
+            ctx,
+            &mut self.copy_buttons,
+            active_funds,
+            inactive_funds,
+        ));
 
         if !spend_txs.is_empty() {
             content = content.push(
                 Column::new()
                     .push(
                         Column::new()
-                            .push(text::bold(text::simple("Pending spend transactions")))
+                            .push(text::bold(text::simple(
+                                ctx.tr("Pending spend transactions"),
+                            )))
                             .push(text::small(
-                                "These transactions are waiting for managers signatures",
+                                ctx.tr("These transactions are waiting for managers signatures"),
                             )),
                     )
                     .push(Column::with_children(spend_txs).spacing(10))
@@ -68,14 +90,14 @@ impl ManagerHomeView {
                 Row::new()
                     .push(
                         Container::new(text::simple(
-                            "No vaults yet, start using Revault by making a deposit",
+                            ctx.tr("No vaults yet, start using Revault by making a deposit"),
                         ))
                         .width(Length::Fill),
                     )
                     .push(
                         button::primary(
                             &mut self.deposit_button,
-                            button::button_content(None, "Deposit"),
+                            button::button_content(None, ctx.tr("Deposit")),
                         )
                         .on_press(Message::Menu(Menu::Deposit)),
                     )
@@ -85,7 +107,7 @@ impl ManagerHomeView {
 
         if !moving_vaults.is_empty() {
             content = content
-                .push(text::bold(text::simple("Funds are moving:")))
+                .push(text::bold(text::simple(ctx.tr("Funds are moving:"))))
                 .push(Column::with_children(moving_vaults).spacing(10))
                 .spacing(20)
         };
@@ -102,31 +124,37 @@ impl ManagerHomeView {
     }
 }
 
-fn manager_overview<'a, T: 'a>(
+fn manager_overview<'a>(
     ctx: &Context,
+    copy_buttons: &'a mut [iced::button::State; 2],
     active_funds: u64,
     inactive_funds: u64,
-) -> Container<'a, T> {
+) -> Container<'a, Message> {
+    let [active_copy, inactive_copy] = copy_buttons;
     card::white(Container::new(
         Column::new()
-            .push(text::bold(text::simple("overview:")))
+            .push(text::bold(text::simple(ctx.tr("overview:"))))
             .push(
                 Column::new()
                     .push(
                         Row::new()
                             .push(Column::new().width(Length::Fill))
-                            .push(
+                            .push(amount::exact(
+                                ctx,
+                                active_copy,
+                                active_funds,
                                 text::bold(text::simple(
-                                    &ctx.converter.converts(active_funds).to_string(),
+                                    &ctx.converter.amount_string(active_funds),
                                 ))
                                 .size(50),
-                            )
+                            ))
                             .push(text::simple(&format!(" {}", ctx.converter.unit)))
                             .align_items(Align::Center),
                     )
+                    .push(fiat_amount(ctx, active_funds))
                     .push(
                         Container::new(
-                            text::simple("are available to managers")
+                            text::simple(ctx.tr("are available to managers"))
                                 .horizontal_alignment(HorizontalAlignment::Right)
                                 .width(Length::Fill),
                         )
@@ -136,19 +164,23 @@ fn manager_overview<'a, T: 'a>(
                     .push(
                         Row::new()
                             .push(Column::new().width(Length::Fill))
-                            .push(
+                            .push(amount::exact(
+                                ctx,
+                                inactive_copy,
+                                inactive_funds,
                                 text::bold(text::simple(
-                                    &ctx.converter.converts(inactive_funds).to_string(),
+                                    &ctx.converter.amount_string(inactive_funds),
                                 ))
-                                .color(color::SECONDARY)
+                                .color(theme::palette().muted)
                                 .size(40),
-                            )
+                            ))
                             .push(text::simple(&format!(" {}", ctx.converter.unit)))
                             .align_items(Align::Center),
                     )
+                    .push(fiat_amount(ctx, inactive_funds))
                     .push(
                         Container::new(
-                            text::simple("are held by stakeholders")
+                            text::simple(ctx.tr("are held by stakeholders"))
                                 .horizontal_alignment(HorizontalAlignment::Right)
                                 .width(Length::Fill),
                         )
@@ -158,13 +190,27 @@ fn manager_overview<'a, T: 'a>(
     ))
 }
 
+/// Displays the amount in the fiat currency of the price feed,
+/// nothing if the price feed is disabled or no rate was fetched yet.
+fn fiat_amount<'a, T: 'a>(ctx: &Context, amount: u64) -> Container<'a, T> {
+    Container::new(
+        text::small(&ctx.converter.fiat_string(amount).unwrap_or_default())
+            .color(theme::palette().muted)
+            .horizontal_alignment(HorizontalAlignment::Right)
+            .width(Length::Fill),
+    )
+    .width(Length::Fill)
+}
+
 #[derive(Debug)]
 pub struct StakeholderHomeView {
     sidebar: Sidebar,
     overview: StakeholderOverview,
+    key_check: EmergencyKeyCheckCard,
     scroll: scrollable::State,
     ack_fund_button: iced::button::State,
     deposit_button: iced::button::State,
+    attention: AttentionInbox,
 }
 
 impl StakeholderHomeView {
@@ -173,32 +219,53 @@ impl StakeholderHomeView {
             scroll: scrollable::State::new(),
             sidebar: Sidebar::new(),
             overview: StakeholderOverview::new(),
+            key_check: EmergencyKeyCheckCard::new(),
             ack_fund_button: iced::button::State::default(),
             deposit_button: iced::button::State::default(),
+            attention: AttentionInbox::new(),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn view<'a>(
         &'a mut self,
         ctx: &Context,
         warning: Option<&Error>,
+        attention: &[Attention],
         moving_vaults: Vec<Element<'a, Message>>,
+        deadlines: &[CancelDeadline],
         balance: &HashMap<VaultStatus, (u64, u64)>,
+        last_key_check: Option<&KeyCheck>,
+        next_key_check: Option<i64>,
+        key_check_due: bool,
+        fingerprint: &form::Value<String>,
     ) -> Element<'a, Message> {
-        let mut col_body = Column::new().push(self.overview.view(ctx, balance));
+        let mut col_body = Column::new();
+        if let Some(inbox) = self.attention.view(ctx, attention) {
+            col_body = col_body.push(inbox);
+        }
+        col_body = col_body
+            .push(self.overview.view(ctx, balance))
+            .push(self.key_check.view(
+                ctx,
+                last_key_check,
+                next_key_check,
+                key_check_due,
+                fingerprint,
+            ));
         if balance.is_empty() {
             col_body = col_body.push(card::simple(Container::new(
                 Row::new()
                     .push(
                         Container::new(text::simple(
-                            "No vaults yet, start using Revault by making a deposit",
+                            ctx.tr("No vaults yet, start using Revault by making a deposit"),
                         ))
                         .width(Length::Fill),
                     )
                     .push(
                         button::primary(
                             &mut self.deposit_button,
-                            button::button_content(None, "Deposit"),
+                            button::button_content(None, ctx.tr("Deposit")),
                         )
                         .on_press(Message::Menu(Menu::CreateVaults)),
                     )
@@ -206,9 +273,13 @@ impl StakeholderHomeView {
             )))
         }
 
+        if let Some(chart) = cancel_deadlines(ctx, deadlines) {
+            col_body = col_body.push(chart);
+        }
+
         if !moving_vaults.is_empty() {
             col_body = col_body
-                .push(text::bold(text::simple("Funds are moving:")))
+                .push(text::bold(text::simple(ctx.tr("Funds are moving:"))))
                 .push(Column::with_children(moving_vaults).spacing(10))
                 .spacing(20)
         };
@@ -225,10 +296,250 @@ impl StakeholderHomeView {
     }
 }
 
+/// Charts the blocks left to cancel each unvaulting vault against the feerate
+/// needed for the cancel transaction to confirm in time.
+fn cancel_deadlines<'a>(
+    ctx: &Context,
+    deadlines: &[CancelDeadline],
+) -> Option<Container<'a, Message>> {
+    if deadlines.is_empty() {
+        return None;
+    }
+    let at_risk = deadlines.iter().filter(|d| d.at_risk()).count();
+    let mut col = Column::new()
+        .push(text::bold(text::simple(ctx.tr("Cancel deadlines of the unvaults"))))
+        .push(text::small(&ctx.trf("The cancel transactions pay {} sats/vbyte and must confirm before the end of the timelock", &[&CANCEL_FEERATE])))
+        .spacing(10);
+    if at_risk > 0 {
+        col = col.push(text::bold(text::danger(text::simple(&ctx.trf(
+            "{} unvault(s) at risk: a cancel may not confirm in time, act now",
+            &[&at_risk],
+        )))));
+    }
+    for deadline in deadlines {
+        // Bar of the remaining blocks over the whole timelock, in percents.
+        let left = (deadline.remaining as u64 * 100 / deadline.csv.max(1) as u64) as u16;
+        let bar = Row::new()
+            .push(
+                if deadline.at_risk() {
+                    card::progress_done()
+                } else {
+                    card::progress_doing()
+                }
+                .width(Length::FillPortion(left.max(1)))
+                .height(Length::Units(10)),
+            )
+            .push(
+                card::progress_todo()
+                    .width(Length::FillPortion(100 - left.min(99)))
+                    .height(Length::Units(10)),
+            )
+            .width(Length::Fill);
+        let feerate = match deadline.feerate {
+            Some(feerate) => ctx.trf("{} sats/vbyte needed", &[&feerate]),
+            None => ctx.tr("feerate unknown").to_string(),
+        };
+        let feerate = if deadline.at_risk() {
+            text::danger(text::small(&feerate))
+        } else {
+            text::small(&feerate)
+        };
+        col =
+            col.push(
+                Column::new()
+                    .push(
+                        Row::new()
+                            .push(
+                                Container::new(
+                                    text::small(&deadline.outpoint).color(theme::palette().muted),
+                                )
+                                .width(Length::Fill),
+                            )
+                            .push(text::bold(text::small(&format!(
+                                "{} {}",
+                                ctx.converter.amount_string(deadline.amount),
+                                ctx.converter.unit
+                            ))))
+                            .spacing(10),
+                    )
+                    .push(
+                        Row::new()
+                            .push(bar)
+                            .push(
+                                Container::new(text::small(&ctx.trf(
+                                    "{}/{} blocks left",
+                                    &[&deadline.remaining, &deadline.csv],
+                                )))
+                                .width(Length::Units(150)),
+                            )
+                            .push(Container::new(feerate).width(Length::Units(180)))
+                            .spacing(10)
+                            .align_items(Align::Center),
+                    )
+                    .spacing(5),
+            );
+    }
+    let card = Container::new(col);
+    Some(
+        if at_risk > 0 {
+            card::alert_warning(card)
+        } else {
+            card::white(card)
+        }
+        .width(Length::Fill),
+    )
+}
+
+/// AttentionInbox lists the actions expected from the user,
+/// each with a button jumping into the corresponding flow.
+#[derive(Debug)]
+struct AttentionInbox {
+    buttons: Vec<iced::button::State>,
+}
+
+impl AttentionInbox {
+    fn new() -> Self {
+        Self {
+            buttons: Vec::new(),
+        }
+    }
+
+    fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        attention: &[Attention],
+    ) -> Option<Container<'a, Message>> {
+        if attention.is_empty() {
+            return None;
+        }
+        self.buttons
+            .resize_with(attention.len(), iced::button::State::new);
+        let mut col = Column::new()
+            .push(text::bold(text::simple(ctx.tr("Needs your attention"))))
+            .spacing(10);
+        for (item, button_state) in attention.iter().zip(self.buttons.iter_mut()) {
+            col = col.push(
+                Row::new()
+                    .push(
+                        Column::new()
+                            .push(text::simple(item.description()))
+                            .push(text::small(&item.reference).color(theme::palette().muted))
+                            .width(Length::Fill),
+                    )
+                    .push(text::bold(text::simple(&format!(
+                        "{} {}",
+                        ctx.converter.amount_string(item.amount),
+                        ctx.converter.unit
+                    ))))
+                    .push(
+                        button::primary(
+                            button_state,
+                            button::button_content(None, item.kind.action()),
+                        )
+                        .on_press(item.action.clone()),
+                    )
+                    .spacing(20)
+                    .align_items(Align::Center),
+            );
+        }
+        Some(card::white(Container::new(col)).width(Length::Fill))
+    }
+}
+
+/// EmergencyKeyCheckCard reminds the stakeholder to periodically verify
+/// the emergency signing device and records the check.
+#[derive(Debug)]
+struct EmergencyKeyCheckCard {
+    fingerprint_input: iced::text_input::State,
+    confirm_button: iced::button::State,
+}
+
+impl EmergencyKeyCheckCard {
+    pub fn new() -> Self {
+        Self {
+            fingerprint_input: iced::text_input::State::new(),
+            confirm_button: iced::button::State::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        last_check: Option<&KeyCheck>,
+        next_check: Option<i64>,
+        due: bool,
+        fingerprint: &form::Value<String>,
+    ) -> Element<'a, Message> {
+        let mut col = Column::new()
+            .push(text::bold(text::simple(ctx.tr("Emergency signing device"))))
+            .spacing(10);
+
+        col = col.push(text::small(&match last_check {
+            Some(check) => ctx.trf(
+                "Last verified on {} with the device {}",
+                &[
+                    &NaiveDateTime::from_timestamp(check.date, 0).date(),
+                    &check.fingerprint,
+                ],
+            ),
+            None => ctx.tr("The device was never verified").to_string(),
+        }));
+
+        if let Some(next) = next_check {
+            col = col.push(text::small(&ctx.trf(
+                "Next verification due on {}",
+                &[&NaiveDateTime::from_timestamp(next, 0).date()],
+            )));
+        }
+
+        if !due {
+            return card::success(Container::new(col))
+                .width(Length::Fill)
+                .into();
+        }
+
+        col = col
+            .push(text::simple(
+                ctx.tr("Please verify your emergency signing device and its backup, then record the check:"),
+            ))
+            .push(
+                Row::new()
+                    .push(
+                        form::Form::new(
+                            &mut self.fingerprint_input,
+                            ctx.tr("Device fingerprint"),
+                            fingerprint,
+                            |f| Message::EmergencyKeyCheck(KeyCheckMessage::FingerprintEdited(f)),
+                        )
+                        .warning(ctx.tr("Please enter the 8 hexadecimal characters fingerprint"))
+                        .size(15)
+                        .padding(10)
+                        .render(),
+                    )
+                    .push(
+                        button::primary(
+                            &mut self.confirm_button,
+                            button::button_content(None, ctx.tr("Device verified")),
+                        )
+                        .on_press(Message::EmergencyKeyCheck(KeyCheckMessage::Confirm))
+                        .width(Length::Units(200)),
+                    )
+                    .spacing(10)
+                    .align_items(Align::Center),
+            );
+
+        card::alert_warning(Container::new(col))
+            .width(Length::Fill)
+            .into()
+    }
+}
+
 #[derive(Debug)]
 struct StakeholderOverview {
     ack_fund_button: iced::button::State,
     delegate_fund_button: iced::button::State,
+    /// copy buttons of the total, of the secured and of the delegated funds.
+    copy_buttons: [iced::button::State; 3],
 }
 
 impl StakeholderOverview {
@@ -236,6 +547,7 @@ impl StakeholderOverview {
         Self {
             ack_fund_button: iced::button::State::new(),
             delegate_fund_button: iced::button::State::new(),
+            copy_buttons: Default::default(),
         }
     }
 
@@ -253,6 +565,7 @@ impl StakeholderOverview {
                 }
             });
 
+        let [total_copy, secured_copy, active_copy] = &mut self.copy_buttons;
         let mut col = Column::new();
 
         if let Some((nb_funded_vaults, funded_amount)) = overview.get(&VaultStatus::Funded) {
@@ -262,16 +575,14 @@ impl StakeholderOverview {
                         .push(
                             Container::new(
                                 Row::new()
-                                    .push(text::bold(text::simple(&format!(
-                                        "{}",
-                                        ctx.converter.converts(*funded_amount),
-                                    ))))
-                                    .push(text::simple(&format!(
-                                        " {} received in ",
-                                        ctx.converter.unit
+                                    .push(text::bold(text::simple(
+                                        &ctx.converter.amount_string(*funded_amount),
                                     )))
+                                    .push(text::simple(
+                                        &ctx.trf(" {} received in ", &[&ctx.converter.unit]),
+                                    ))
                                     .push(text::bold(text::simple(&nb_funded_vaults.to_string())))
-                                    .push(text::simple(" new deposits")),
+                                    .push(text::simple(ctx.tr(" new deposits"))),
                             )
                             .width(Length::Fill)
                             .align_x(iced::Align::Center),
@@ -282,7 +593,7 @@ impl StakeholderOverview {
                                     .push(
                                         button::primary(
                                             &mut self.ack_fund_button,
-                                            button::button_content(None, "+ Create vaults")
+                                            button::button_content(None, ctx.tr("+ Create vaults"))
                                                 .padding(3),
                                         )
                                         .on_press(Message::Menu(Menu::CreateVaults)),
@@ -307,20 +618,24 @@ impl StakeholderOverview {
                         .push(
                             Row::new()
                                 .push(Column::new().width(Length::Fill))
-                                .push(
+                                .push(amount::exact(
+                                    ctx,
+                                    total_copy,
+                                    total_amount,
                                     text::bold(text::simple(
-                                        &ctx.converter.converts(total_amount).to_string(),
+                                        &ctx.converter.amount_string(total_amount),
                                     ))
                                     .size(50),
-                                )
+                                ))
                                 .push(text::simple(&format!(" {}", ctx.converter.unit)))
                                 .align_items(Align::Center),
                         )
+                        .push(fiat_amount(ctx, total_amount))
                         .push(
                             Row::new()
                                 .push(Column::new().width(Length::Fill))
                                 .push(text::bold(text::simple(&format!("{}", nb_total_vaults))))
-                                .push(text::simple(" vaults")),
+                                .push(text::simple(ctx.tr(" vaults"))),
                         ),
                 )
                 .push(
@@ -328,6 +643,7 @@ impl StakeholderOverview {
                         .push(
                             secured_funds_overview_card(
                                 ctx,
+                                secured_copy,
                                 overview.get(&VaultStatus::Secured),
                                 overview.get(&VaultStatus::Securing),
                                 overview.get(&VaultStatus::Activating),
@@ -335,8 +651,12 @@ impl StakeholderOverview {
                             .width(Length::FillPortion(1)),
                         )
                         .push(
-                            active_funds_overview_card(ctx, overview.get(&VaultStatus::Active))
-                                .width(Length::FillPortion(1)),
+                            active_funds_overview_card(
+                                ctx,
+                                active_copy,
+                                overview.get(&VaultStatus::Active),
+                            )
+                            .width(Length::FillPortion(1)),
                         )
                         .spacing(10),
                 )
@@ -347,10 +667,11 @@ impl StakeholderOverview {
     }
 }
 
-fn active_funds_overview_card<'a, T: 'a>(
+fn active_funds_overview_card<'a>(
     ctx: &Context,
+    copy_button: &'a mut iced::button::State,
     active: Option<&(u64, u64)>,
-) -> Container<'a, T> {
+) -> Container<'a, Message> {
     let (nb_active_vaults, active_amount) = active.unwrap_or(&(0, 0));
     let col = Column::new()
         .push(
@@ -359,7 +680,7 @@ fn active_funds_overview_card<'a, T: 'a>(
                     Container::new(
                         Row::new()
                             .push(person_check_icon())
-                            .push(text::bold(text::simple("  Delegated funds")))
+                            .push(text::bold(text::simple(ctx.tr("  Delegated funds"))))
                             .align_items(Align::Center),
                     )
                     .width(Length::Fill),
@@ -367,7 +688,7 @@ fn active_funds_overview_card<'a, T: 'a>(
                 .push(
                     Tooltip::new(
                         tooltip_icon().size(10),
-                        "Delegated funds can be spent by managers,\n but you can still revert any undesired transaction.",
+                        ctx.tr("Delegated funds can be spent by managers,\n but you can still revert any undesired transaction."),
                         tooltip::Position::Left,
                     )
                     .gap(5)
@@ -381,12 +702,15 @@ fn active_funds_overview_card<'a, T: 'a>(
                 .push(
                     Container::new(
                         Row::new()
-                            .push(
+                            .push(amount::exact(
+                                ctx,
+                                copy_button,
+                                *active_amount,
                                 text::bold(text::simple(
-                                    &ctx.converter.converts(*active_amount).to_string(),
+                                    &ctx.converter.amount_string(*active_amount),
                                 ))
                                 .size(20),
-                            )
+                            ))
                             .push(text::simple(&format!(
                                 " {:<6}",
                                 // to_string is needed to use format alignment feature
@@ -400,7 +724,7 @@ fn active_funds_overview_card<'a, T: 'a>(
                     Container::new(
                         Row::new()
                             .push(text::bold(text::simple(&nb_active_vaults.to_string())))
-                            .push(text::simple(" vaults")),
+                            .push(text::simple(ctx.tr(" vaults"))),
                     )
                     .width(Length::Fill)
                     .align_x(Align::End),
@@ -409,12 +733,13 @@ fn active_funds_overview_card<'a, T: 'a>(
     card::white(Container::new(col.spacing(20)))
 }
 
-fn secured_funds_overview_card<'a, T: 'a>(
+fn secured_funds_overview_card<'a>(
     ctx: &Context,
+    copy_button: &'a mut iced::button::State,
     secure: Option<&(u64, u64)>,
     securing: Option<&(u64, u64)>,
     activating: Option<&(u64, u64)>,
-) -> Container<'a, T> {
+) -> Container<'a, Message> {
     let (nb_secured_vaults, secured_amount) = secure.unwrap_or(&(0, 0));
     let (nb_activating_vaults, activating_amount) = activating.unwrap_or(&(0, 0));
     let mut col = Column::new()
@@ -424,7 +749,7 @@ fn secured_funds_overview_card<'a, T: 'a>(
                     Container::new(
                         Row::new()
                             .push(shield_check_icon())
-                            .push(text::bold(text::simple("  Secured funds")))
+                            .push(text::bold(text::simple(ctx.tr("  Secured funds"))))
                             .align_items(Align::Center),
                     )
                     .width(Length::Fill),
@@ -432,7 +757,7 @@ fn secured_funds_overview_card<'a, T: 'a>(
                 .push(
                     Tooltip::new(
                         tooltip_icon().size(10),
-                        "Secured funds are controlled by stakeholders only",
+                        ctx.tr("Secured funds are controlled by stakeholders only"),
                         tooltip::Position::Left,
                     )
                     .gap(5)
@@ -446,14 +771,16 @@ fn secured_funds_overview_card<'a, T: 'a>(
                 .push(
                     Container::new(
                         Row::new()
-                            .push(
+                            .push(amount::exact(
+                                ctx,
+                                copy_button,
+                                *secured_amount + *activating_amount,
                                 text::bold(text::simple(
                                     &ctx.converter
-                                        .converts(*secured_amount + *activating_amount)
-                                        .to_string(),
+                                        .amount_string(*secured_amount + *activating_amount),
                                 ))
                                 .size(20),
-                            )
+                            ))
                             .push(text::simple(&format!(
                                 " {:<6}",
                                 // to_string is needed to use format alignment feature
@@ -469,7 +796,7 @@ fn secured_funds_overview_card<'a, T: 'a>(
                             .push(text::bold(text::simple(
                                 &(nb_secured_vaults + nb_activating_vaults).to_string(),
                             )))
-                            .push(text::simple(" vaults")),
+                            .push(text::simple(ctx.tr(" vaults"))),
                     )
                     .width(Length::Fill)
                     .align_x(Align::End),
@@ -483,14 +810,14 @@ fn secured_funds_overview_card<'a, T: 'a>(
                     .push(Column::new().width(Length::Fill))
                     .push(text::bold(text::small("+ ")))
                     .push(text::bold(text::small(
-                        &ctx.converter.converts(*securing_amount).to_string(),
+                        &ctx.converter.amount_string(*securing_amount),
                     )))
                     .push(text::small(&format!(" {}, ", ctx.converter.unit)))
                     .push(text::bold(text::small(&nb_securing_vaults.to_string())))
-                    .push(text::small(" vaults "))
+                    .push(text::small(ctx.tr(" vaults ")))
                     .push(history_icon().size(10))
                     .align_items(Align::End),
-                "Waiting for other stakeholders' signatures",
+                ctx.tr("Waiting for other stakeholders' signatures"),
                 tooltip::Position::Bottom,
             )
             .gap(5)
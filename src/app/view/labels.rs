@@ -0,0 +1,272 @@
+use std::collections::BTreeMap;
+
+use iced::{
+    pick_list, scrollable, text_input, Align, Checkbox, Column, Container, Element, Length, Row,
+    TextInput,
+};
+
+use crate::{
+    app::{
+        error::Error,
+        message::{LabelMessage, Message},
+        view::{layout, sidebar::Sidebar, Context},
+    },
+    ui::component::{button, card, navbar, scroll, text, TransparentPickListStyle},
+};
+
+/// labeled_vault_list_item wraps a vault list item with a checkbox
+/// to select it for a bulk label assignment and the current label of the vault.
+pub fn labeled_vault_list_item<'a>(
+    item: Element<'a, Message>,
+    outpoint: String,
+    selected: bool,
+    label: Option<&String>,
+) -> Element<'a, Message> {
+    Row::new()
+        .push(Checkbox::new(selected, "", move |checked| {
+            Message::Label(LabelMessage::SelectVault(outpoint.clone(), checked))
+        }))
+        .push(Container::new(item).width(Length::Fill))
+        .push(
+            Container::new(text::small(label.map(|l| l.as_str()).unwrap_or("")))
+                .width(Length::Units(150)),
+        )
+        .spacing(10)
+        .align_items(Align::Center)
+        .into()
+}
+
+/// labeled_vault_card is the labeled_vault_list_item of the vaults grid,
+/// the label is already displayed by the card.
+pub fn labeled_vault_card<'a>(
+    card: Element<'a, Message>,
+    outpoint: String,
+    selected: bool,
+) -> Element<'a, Message> {
+    Column::new()
+        .push(Checkbox::new(selected, "", move |checked| {
+            Message::Label(LabelMessage::SelectVault(outpoint.clone(), checked))
+        }))
+        .push(card)
+        .spacing(5)
+        .width(Length::Fill)
+        .into()
+}
+
+/// LabelAssignView is the bar displayed above the vault list,
+/// it assigns a label to the selected vaults or exports them.
+#[derive(Debug)]
+pub struct LabelAssignView {
+    label_input: text_input::State,
+    assign_button: iced::button::State,
+    export_button: iced::button::State,
+    manage_button: iced::button::State,
+}
+
+impl LabelAssignView {
+    pub fn new() -> Self {
+        LabelAssignView {
+            label_input: text_input::State::new(),
+            assign_button: iced::button::State::new(),
+            export_button: iced::button::State::new(),
+            manage_button: iced::button::State::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        selected: usize,
+        label: &str,
+        export: Option<String>,
+    ) -> Element<'a, Message> {
+        let mut row = Row::new().spacing(10).align_items(Align::Center);
+        if selected > 0 {
+            let mut assign_button = button::primary(
+                &mut self.assign_button,
+                button::button_content(None, ctx.tr("Assign label")),
+            );
+            if !label.trim().is_empty() {
+                assign_button = assign_button.on_press(Message::Label(LabelMessage::Assign));
+            }
+            row = row
+                .push(text::simple(&ctx.trf("{} selected", &[&selected])))
+                .push(
+                    TextInput::new(&mut self.label_input, ctx.tr("Label"), label, |value| {
+                        Message::Label(LabelMessage::LabelEdited(value))
+                    })
+                    .padding(10)
+                    .width(Length::Fill),
+                )
+                .push(assign_button.width(Length::Units(200)));
+        } else {
+            row = row.push(
+                Container::new(text::small(ctx.tr(
+                    "Select vaults to label or export them, hold shift to select a range",
+                )))
+                .width(Length::Fill),
+            );
+        }
+        if let Some(csv) = export {
+            row = row.push(
+                button::transparent(
+                    &mut self.export_button,
+                    button::button_content(None, ctx.tr("Copy as CSV")),
+                )
+                .on_press(Message::Clipboard(csv))
+                .width(Length::Units(200)),
+            );
+        }
+        row.push(
+            button::transparent(
+                &mut self.manage_button,
+                button::button_content(None, ctx.tr("Manage labels")),
+            )
+            .on_press(Message::Label(LabelMessage::ToggleManager))
+            .width(Length::Units(200)),
+        )
+        .into()
+    }
+}
+
+#[derive(Debug)]
+struct LabelRowView {
+    rename_input: text_input::State,
+    rename_button: iced::button::State,
+    delete_button: iced::button::State,
+    merge_pick: pick_list::State<String>,
+}
+
+impl LabelRowView {
+    fn new() -> Self {
+        LabelRowView {
+            rename_input: text_input::State::new(),
+            rename_button: iced::button::State::new(),
+            delete_button: iced::button::State::new(),
+            merge_pick: pick_list::State::default(),
+        }
+    }
+}
+
+/// LabelManagerView lists the labels with their usage
+/// and allows to rename, merge and delete them.
+#[derive(Debug)]
+pub struct LabelManagerView {
+    scroll: scrollable::State,
+    sidebar: Sidebar,
+    close_button: iced::button::State,
+    rows: Vec<LabelRowView>,
+}
+
+impl LabelManagerView {
+    pub fn new() -> Self {
+        LabelManagerView {
+            scroll: scrollable::State::new(),
+            sidebar: Sidebar::new(),
+            close_button: iced::button::State::new(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        warning: Option<&Error>,
+        usage: &BTreeMap<String, usize>,
+        renames: &BTreeMap<String, String>,
+    ) -> Element<'a, Message> {
+        if self.rows.len() != usage.len() {
+            self.rows = usage.iter().map(|_| LabelRowView::new()).collect();
+        }
+
+        let labels: Vec<String> = usage.keys().cloned().collect();
+        let mut col = Column::new().spacing(10).push(
+            Row::new()
+                .push(
+                    Container::new(text::bold(text::simple(ctx.tr("Labels")))).width(Length::Fill),
+                )
+                .push(
+                    button::cancel(
+                        &mut self.close_button,
+                        Container::new(text::simple(ctx.tr("X Close"))).padding(10),
+                    )
+                    .on_press(Message::Label(LabelMessage::ToggleManager)),
+                )
+                .align_items(Align::Center),
+        );
+
+        if usage.is_empty() {
+            col = col.push(text::simple(ctx.tr("No label yet")));
+        }
+
+        for ((label, count), row) in usage.iter().zip(self.rows.iter_mut()) {
+            let rename = renames.get(label).cloned().unwrap_or_default();
+            let others: Vec<String> = labels.iter().filter(|l| *l != label).cloned().collect();
+            let mut rename_button = button::primary(
+                &mut row.rename_button,
+                button::button_content(None, ctx.tr("Rename")),
+            );
+            if !rename.trim().is_empty() {
+                rename_button =
+                    rename_button.on_press(Message::Label(LabelMessage::Rename(label.clone())));
+            }
+            let edited = label.clone();
+            let merged = label.clone();
+            col = col.push(card::white(Container::new(
+                Row::new()
+                    .push(
+                        Column::new()
+                            .push(text::bold(text::simple(label)))
+                            .push(text::small(&ctx.trf(
+                                if *count > 1 { "{} vaults" } else { "{} vault" },
+                                &[&count],
+                            )))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        TextInput::new(
+                            &mut row.rename_input,
+                            ctx.tr("New name"),
+                            &rename,
+                            move |value| {
+                                Message::Label(LabelMessage::RenameEdited(edited.clone(), value))
+                            },
+                        )
+                        .padding(10)
+                        .width(Length::Units(200)),
+                    )
+                    .push(rename_button.width(Length::Units(120)))
+                    .push(text::small(ctx.tr("Merge into")))
+                    .push(
+                        pick_list::PickList::new(&mut row.merge_pick, others, None, move |into| {
+                            Message::Label(LabelMessage::Merge(merged.clone(), into))
+                        })
+                        .text_size(15)
+                        .padding(10)
+                        .width(Length::Units(150))
+                        .style(TransparentPickListStyle),
+                    )
+                    .push(
+                        button::cancel(
+                            &mut row.delete_button,
+                            button::button_content(None, ctx.tr("Delete")),
+                        )
+                        .on_press(Message::Label(LabelMessage::Delete(label.clone())))
+                        .width(Length::Units(120)),
+                    )
+                    .spacing(10)
+                    .align_items(Align::Center),
+            )));
+        }
+
+        layout::dashboard(
+            navbar(layout::navbar_warning(warning)),
+            self.sidebar.view(ctx),
+            layout::main_section(Container::new(scroll(
+                &mut self.scroll,
+                Container::new(col),
+            ))),
+        )
+        .into()
+    }
+}
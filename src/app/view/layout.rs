@@ -1,8 +1,8 @@
 use crate::{
     app::error::Error,
     ui::{
-        color,
         component::{card, image::revault_colored_logo, text},
+        theme,
     },
 };
 
@@ -70,9 +70,10 @@ pub struct SidebarStyle;
 impl container::StyleSheet for SidebarStyle {
     fn style(&self) -> container::Style {
         container::Style {
-            background: color::FOREGROUND.into(),
+            background: theme::palette().foreground.into(),
             border_width: 1.0,
-            border_color: color::SECONDARY,
+            border_color: theme::palette().secondary,
+            text_color: theme::palette().text.into(),
             ..container::Style::default()
         }
     }
@@ -82,7 +83,8 @@ pub struct SidebarMenuStyle;
 impl container::StyleSheet for SidebarMenuStyle {
     fn style(&self) -> container::Style {
         container::Style {
-            background: color::FOREGROUND.into(),
+            background: theme::palette().foreground.into(),
+            text_color: theme::palette().text.into(),
             ..container::Style::default()
         }
     }
@@ -109,7 +111,8 @@ pub struct MainSectionStyle;
 impl container::StyleSheet for MainSectionStyle {
     fn style(&self) -> container::Style {
         container::Style {
-            background: color::BACKGROUND.into(),
+            background: theme::palette().background.into(),
+            text_color: theme::palette().text.into(),
             ..container::Style::default()
         }
     }
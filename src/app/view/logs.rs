@@ -0,0 +1,141 @@
+use std::path::Path;
+
+use iced::{
+    pick_list, scrollable, text_input, Align, Column, Container, Element, Length, PickList, Row,
+    TextInput,
+};
+use tracing::Level;
+
+use crate::{
+    app::{
+        error::Error,
+        log_bundle::{Line, LogSource, LEVELS},
+        message::{LogPanelMessage, Message},
+        view::{layout, sidebar::Sidebar, Context},
+    },
+    ui::{
+        color,
+        component::{button, card, navbar, scroll, text},
+    },
+};
+
+/// Number of matching lines displayed, the latest ones.
+const MAX_DISPLAYED: usize = 500;
+
+#[derive(Debug)]
+pub struct LogsView {
+    sidebar: Sidebar,
+    scroll: scrollable::State,
+    source_list: pick_list::State<LogSource>,
+    level_list: pick_list::State<Level>,
+    search_input: text_input::State,
+    export_button: iced::button::State,
+}
+
+impl LogsView {
+    pub fn new() -> Self {
+        LogsView {
+            sidebar: Sidebar::new(),
+            scroll: scrollable::State::new(),
+            source_list: pick_list::State::default(),
+            level_list: pick_list::State::default(),
+            search_input: text_input::State::new(),
+            export_button: iced::button::State::new(),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        warning: Option<&Error>,
+        source: LogSource,
+        level: Level,
+        search: &str,
+        lines: Result<Vec<Line>, &Error>,
+        exported: Option<&Path>,
+    ) -> Element<'a, Message> {
+        let mut col = Column::new()
+            .push(
+                Row::new()
+                    .push(
+                        Container::new(text::bold(text::simple(ctx.tr("Logs"))))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        PickList::new(
+                            &mut self.source_list,
+                            &LogSource::ALL[..],
+                            Some(source),
+                            |source| Message::LogPanel(LogPanelMessage::SourceSelected(source)),
+                        )
+                        .text_size(15),
+                    )
+                    .push(
+                        PickList::new(&mut self.level_list, &LEVELS[..], Some(level), |level| {
+                            Message::LogPanel(LogPanelMessage::LevelSelected(level))
+                        })
+                        .text_size(15),
+                    )
+                    .push(
+                        button::primary(
+                            &mut self.export_button,
+                            button::button_content(None, ctx.tr("Export logs")),
+                        )
+                        .on_press(Message::LogPanel(LogPanelMessage::Export)),
+                    )
+                    .spacing(10)
+                    .align_items(Align::Center),
+            )
+            .push(
+                TextInput::new(&mut self.search_input, ctx.tr("Search"), search, |query| {
+                    Message::LogPanel(LogPanelMessage::SearchEdited(query))
+                })
+                .padding(10),
+            )
+            .spacing(20);
+
+        if let Some(path) = exported {
+            col = col.push(card::success(Container::new(text::small(&ctx.trf(
+                "The logs were exported to {}, keys, addresses and transaction ids are redacted",
+                &[&path.display()],
+            )))));
+        }
+
+        col = col.push(match lines {
+            Ok(lines) if lines.is_empty() => card::simple(Container::new(text::small(
+                ctx.tr("No log matches the filter"),
+            ))),
+            Ok(lines) => card::simple(Container::new(
+                lines
+                    .iter()
+                    .rev()
+                    .take(MAX_DISPLAYED)
+                    .fold(Column::new().spacing(2), |col, line| {
+                        col.push(line_text(line))
+                    }),
+            ))
+            .width(Length::Fill),
+            Err(e) => card::alert_warning(Container::new(text::small(&e.to_string()))),
+        });
+
+        layout::dashboard(
+            navbar(layout::navbar_warning(warning)),
+            self.sidebar.view(ctx),
+            layout::main_section(Container::new(scroll(
+                &mut self.scroll,
+                Container::new(col),
+            ))),
+        )
+        .into()
+    }
+}
+
+fn line_text(line: &Line) -> iced::Text {
+    let t = text::small(&line.text);
+    match line.level {
+        Some(Level::ERROR) => text::danger(t),
+        Some(Level::WARN) => t.color(color::WARNING),
+        _ => t,
+    }
+}
@@ -5,18 +5,25 @@ use iced::{
     TextInput,
 };
 
+use chrono::{NaiveDateTime, Utc};
+
+use crate::bitcoind::{BitcoindError, FeeEstimate};
 use crate::revaultd::model;
 
 use crate::{
     app::{
+        drafts::Draft,
         error::Error,
         menu::Menu,
-        message::{InputMessage, Message, RecipientMessage, SpendTxMessage},
+        message::{DraftMessage, InputMessage, Message, RecipientMessage, SpendTxMessage},
+        spend_checks::{self, CheckResult, CheckStatus},
+        spend_hints::SpendHint,
         view::Context,
     },
     ui::{
+        color,
         component::{button, card, form, scroll, separation, text, ContainerBackgroundStyle},
-        icon::trash_icon,
+        icon::{arrow_down_icon, arrow_up_icon, trash_icon},
     },
 };
 
@@ -40,18 +47,22 @@ impl ManagerImportTransactionView {
 
     pub fn view<'a>(
         &'a mut self,
+        ctx: &Context,
         psbt_input: &str,
         psbt_imported: Option<&Psbt>,
         warning: Option<&String>,
     ) -> Element<'a, Message> {
         let mut col = Column::new()
             .spacing(20)
-            .push(text::bold(text::simple("Import spend transaction")))
-            .push(text::simple("Enter PSBT:"))
+            .push(text::bold(text::simple(ctx.tr("Import spend transaction"))))
+            .push(text::simple(ctx.tr("Enter PSBT:")))
             .push(
-                TextInput::new(&mut self.psbt_input, "Signed PSBT", &psbt_input, |p| {
-                    Message::SpendTx(SpendTxMessage::PsbtEdited(p))
-                })
+                TextInput::new(
+                    &mut self.psbt_input,
+                    ctx.tr("Signed PSBT"),
+                    &psbt_input,
+                    |p| Message::SpendTx(SpendTxMessage::PsbtEdited(p)),
+                )
                 .size(15)
                 .width(Length::Fill)
                 .padding(10),
@@ -63,11 +74,11 @@ impl ManagerImportTransactionView {
         if let Some(psbt) = psbt_imported {
             col = col.push(card::success(Container::new(
                 Column::new()
-                    .push(text::simple("Transaction imported"))
+                    .push(text::simple(ctx.tr("Transaction imported")))
                     .push(
                         button::success(
                             &mut self.import_button,
-                            button::button_content(None, "See transaction detail"),
+                            button::button_content(None, ctx.tr("See transaction detail")),
                         )
                         .on_press(Message::SpendTx(SpendTxMessage::Select(psbt.clone()))),
                     )
@@ -77,7 +88,7 @@ impl ManagerImportTransactionView {
             col = col.push(
                 button::primary(
                     &mut self.import_button,
-                    button::button_content(None, "Import transaction"),
+                    button::button_content(None, ctx.tr("Import transaction")),
                 )
                 .on_press(Message::SpendTx(SpendTxMessage::Import)),
             );
@@ -91,7 +102,7 @@ impl ManagerImportTransactionView {
                             Container::new(
                                 button::cancel(
                                     &mut self.cancel_button,
-                                    Container::new(text::simple("X Close")).padding(10),
+                                    Container::new(text::simple(ctx.tr("X Close"))).padding(10),
                                 )
                                 .on_press(Message::Menu(Menu::Home)),
                             )
@@ -124,6 +135,8 @@ pub struct ManagerSendWelcomeView {
     cancel_button: iced::button::State,
     crate_transaction_button: iced::button::State,
     import_transaction_button: iced::button::State,
+    /// resume and delete buttons of the drafts.
+    draft_buttons: Vec<(iced::button::State, iced::button::State)>,
 }
 
 impl ManagerSendWelcomeView {
@@ -133,10 +146,56 @@ impl ManagerSendWelcomeView {
             scroll: scrollable::State::new(),
             crate_transaction_button: iced::button::State::new(),
             import_transaction_button: iced::button::State::new(),
+            draft_buttons: Vec::new(),
         }
     }
 
-    pub fn view(&mut self) -> Element<'_, Message> {
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        drafts: &[Draft],
+        warning: Option<&Error>,
+    ) -> Element<'a, Message> {
+        self.draft_buttons
+            .resize_with(drafts.len(), Default::default);
+
+        let mut col = Column::new()
+            .push(
+                button::primary(
+                    &mut self.crate_transaction_button,
+                    button::button_content(None, ctx.tr("Create spend transaction")),
+                )
+                .on_press(Message::Next),
+            )
+            .push(
+                button::primary(
+                    &mut self.import_transaction_button,
+                    button::button_content(None, ctx.tr("Import spend transaction")),
+                )
+                .on_press(Message::SpendTx(SpendTxMessage::Import)),
+            )
+            .spacing(20)
+            .align_items(Align::Center);
+
+        if let Some(error) = warning {
+            col = col.push(card::alert_warning(Container::new(text::simple(&format!(
+                "{}",
+                error
+            )))));
+        }
+
+        if !drafts.is_empty() {
+            let mut list = Column::new()
+                .push(text::bold(text::simple(ctx.tr("Drafts"))))
+                .spacing(10);
+            for (draft, (resume_button, delete_button)) in
+                drafts.iter().zip(self.draft_buttons.iter_mut())
+            {
+                list = list.push(draft_view(ctx, draft, resume_button, delete_button));
+            }
+            col = col.push(Container::new(list).max_width(800).width(Length::Fill));
+        }
+
         Container::new(scroll(
             &mut self.scroll,
             Container::new(
@@ -146,7 +205,7 @@ impl ManagerSendWelcomeView {
                             Container::new(
                                 button::cancel(
                                     &mut self.cancel_button,
-                                    Container::new(text::simple("X Close"))
+                                    Container::new(text::simple(ctx.tr("X Close")))
                                         .padding(10)
                                         .width(Length::Units(100))
                                         .align_x(Align::Center),
@@ -157,26 +216,9 @@ impl ManagerSendWelcomeView {
                         ),
                     )
                     .push(
-                        Container::new(
-                            Column::new()
-                                .push(
-                                    button::primary(
-                                        &mut self.crate_transaction_button,
-                                        button::button_content(None, "Create spend transaction"),
-                                    )
-                                    .on_press(Message::Next),
-                                )
-                                .push(
-                                    button::primary(
-                                        &mut self.import_transaction_button,
-                                        button::button_content(None, "Import spend transaction"),
-                                    )
-                                    .on_press(Message::SpendTx(SpendTxMessage::Import)),
-                                )
-                                .spacing(20),
-                        )
-                        .width(Length::Fill)
-                        .align_x(Align::Center),
+                        Container::new(col)
+                            .width(Length::Fill)
+                            .align_x(Align::Center),
                     )
                     .spacing(20),
             ),
@@ -189,17 +231,73 @@ impl ManagerSendWelcomeView {
     }
 }
 
+fn draft_view<'a>(
+    ctx: &Context,
+    draft: &Draft,
+    resume_button: &'a mut iced::button::State,
+    delete_button: &'a mut iced::button::State,
+) -> Element<'a, Message> {
+    let destinations: Vec<&str> = draft
+        .outputs
+        .iter()
+        .map(|(address, _)| address.as_str())
+        .filter(|address| !address.is_empty())
+        .collect();
+    card::white(Container::new(
+        Row::new()
+            .push(
+                Column::new()
+                    .push(text::bold(text::small(&ctx.trf(
+                        "Saved {}",
+                        &[&NaiveDateTime::from_timestamp(draft.updated_at, 0)],
+                    ))))
+                    .push(text::small(&ctx.trf(
+                        "{} destination(s), {} vault(s) selected{}",
+                        &[
+                            &destinations.len(),
+                            &draft.inputs.len(),
+                            &if draft.psbt.is_some() {
+                                ctx.tr(", transaction generated")
+                            } else {
+                                ""
+                            },
+                        ],
+                    )))
+                    .push(text::small(&destinations.join(", ")))
+                    .spacing(5)
+                    .width(Length::Fill),
+            )
+            .push(
+                button::primary(
+                    resume_button,
+                    button::button_content(None, ctx.tr("Resume")),
+                )
+                .on_press(Message::Draft(DraftMessage::Resume(draft.id))),
+            )
+            .push(
+                button::transparent(delete_button, Container::new(trash_icon()))
+                    .on_press(Message::Draft(DraftMessage::Delete(draft.id))),
+            )
+            .spacing(10)
+            .align_items(Align::Center),
+    ))
+    .width(Length::Fill)
+    .into()
+}
+
 #[derive(Debug)]
 pub struct ManagerSelectOutputsView {
     scroll: scrollable::State,
     cancel_button: iced::button::State,
     next_button: iced::button::State,
     new_output_button: iced::button::State,
+    merge_button: iced::button::State,
 }
 
 impl ManagerSelectOutputsView {
     pub fn new() -> Self {
         ManagerSelectOutputsView {
+            merge_button: iced::button::State::new(),
             cancel_button: iced::button::State::new(),
             next_button: iced::button::State::new(),
             scroll: scrollable::State::new(),
@@ -207,20 +305,25 @@ impl ManagerSelectOutputsView {
         }
     }
 
+    /// The duplicates are the addresses of several recipients, they must be
+    /// merged before the transaction is generated.
     pub fn view<'a>(
         &'a mut self,
+        ctx: &Context,
         selected_outputs: Vec<Element<'a, Message>>,
         valid: bool,
+        duplicates: &[String],
+        shuffle_outputs: bool,
     ) -> Element<'a, Message> {
         let header = Row::new()
             .push(Column::new().width(Length::Fill))
-            .push(crate::ui::component::ProgressBar::spend_bar().draw(0))
+            .push(crate::ui::component::ProgressBar::spend_bar().draw(ctx.converter.language, 0))
             .push(
                 Column::new()
                     .push(
                         button::cancel(
                             &mut self.cancel_button,
-                            Container::new(text::simple("X Close"))
+                            Container::new(text::simple(ctx.tr("X Close")))
                                 .padding(10)
                                 .width(Length::Units(100))
                                 .align_x(Align::Center),
@@ -244,6 +347,38 @@ impl ManagerSelectOutputsView {
             }
             col_outputs = col_outputs.push(element);
         }
+        if !duplicates.is_empty() {
+            col_outputs = col_outputs.push(card::alert_warning(Container::new(
+                Row::new()
+                    .push(
+                        Column::new()
+                            .push(text::simple(
+                                ctx.tr("Several recipients have the same address, \
+                                 only one output per address can be created:"),
+                            ))
+                            .push(text::small(&duplicates.join(", ")))
+                            .spacing(5)
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        button::primary(
+                            &mut self.merge_button,
+                            button::button_content(None, ctx.tr("Merge")),
+                        )
+                        .on_press(Message::MergeRecipients),
+                    )
+                    .align_items(Align::Center)
+                    .spacing(20),
+            )));
+        }
+        col_outputs = col_outputs.push(
+            Checkbox::new(
+                shuffle_outputs,
+                ctx.tr("Shuffle the order of the outputs"),
+                Message::ShuffleOutputs,
+            )
+            .text_size(15),
+        );
         let element: Element<_> = col_outputs.max_width(1000).into();
 
         let mut footer = Row::new()
@@ -252,7 +387,7 @@ impl ManagerSelectOutputsView {
             .push(
                 button::cancel(
                     &mut self.new_output_button,
-                    Container::new(text::simple("Add recipient"))
+                    Container::new(text::simple(ctx.tr("Add recipient")))
                         .width(Length::Units(200))
                         .align_x(Align::Center)
                         .padding(10),
@@ -264,7 +399,7 @@ impl ManagerSelectOutputsView {
             footer = footer.push(Container::new(
                 button::primary(
                     &mut self.next_button,
-                    Container::new(text::simple("Continue"))
+                    Container::new(text::simple(ctx.tr("Continue")))
                         .width(Length::Units(200))
                         .align_x(Align::Center)
                         .padding(10),
@@ -274,7 +409,7 @@ impl ManagerSelectOutputsView {
         } else {
             footer = footer.push(Container::new(button::primary_disable(
                 &mut self.next_button,
-                Container::new(text::simple("Continue"))
+                Container::new(text::simple(ctx.tr("Continue")))
                     .width(Length::Units(200))
                     .align_x(Align::Center)
                     .padding(10),
@@ -286,7 +421,7 @@ impl ManagerSelectOutputsView {
             Column::new()
                 .push(header)
                 .push(
-                    Container::new(text::bold(text::simple("Add recipients")))
+                    Container::new(text::bold(text::simple(ctx.tr("Add recipients"))))
                         .width(Length::Fill)
                         .align_x(Align::Center),
                 )
@@ -321,6 +456,9 @@ pub struct ManagerSendOutputView {
     address_input: text_input::State,
     amount_input: text_input::State,
     delete_button: iced::button::State,
+    up_button: iced::button::State,
+    down_button: iced::button::State,
+    suggestion_buttons: Vec<iced::button::State>,
 }
 
 impl ManagerSendOutputView {
@@ -329,22 +467,61 @@ impl ManagerSendOutputView {
             address_input: text_input::State::focused(),
             amount_input: text_input::State::new(),
             delete_button: iced::button::State::new(),
+            up_button: iced::button::State::new(),
+            down_button: iced::button::State::new(),
+            suggestion_buttons: Vec::new(),
         }
     }
+
+    pub fn amount_focused(&self) -> bool {
+        self.amount_input.is_focused()
+    }
+
+    /// The label is the one of the address in the address book, the
+    /// suggestions are the entries matching the address field.
+    /// The first and the last recipients cannot be moved up and down.
+    #[allow(clippy::too_many_arguments)]
     pub fn view(
         &mut self,
+        ctx: &Context,
         address: &form::Value<String>,
         amount: &form::Value<String>,
+        label: Option<&String>,
+        suggestions: &[(&String, &String)],
+        first: bool,
+        last: bool,
     ) -> Element<RecipientMessage> {
-        Row::new()
+        let mut up_button =
+            button::transparent(&mut self.up_button, Container::new(arrow_up_icon()));
+        if !first {
+            up_button = up_button.on_press(RecipientMessage::MoveUp);
+        }
+        let mut down_button =
+            button::transparent(&mut self.down_button, Container::new(arrow_down_icon()));
+        if !last {
+            down_button = down_button.on_press(RecipientMessage::MoveDown);
+        }
+        if self.suggestion_buttons.len() != suggestions.len() {
+            self.suggestion_buttons = suggestions
+                .iter()
+                .map(|_| iced::button::State::new())
+                .collect();
+        }
+        let row = Row::new()
+            .push(
+                Column::new()
+                    .push(up_button)
+                    .push(down_button)
+                    .width(Length::Shrink),
+            )
             .push(
                 form::Form::new(
                     &mut self.address_input,
-                    "Address",
+                    ctx.tr("Address"),
                     &address,
                     RecipientMessage::AddressEdited,
                 )
-                .warning("Please enter a valid bitcoin address")
+                .warning(ctx.tr("Please enter a valid bitcoin address"))
                 .padding(10)
                 .render()
                 .width(Length::FillPortion(2)),
@@ -352,11 +529,11 @@ impl ManagerSendOutputView {
             .push(
                 form::Form::new(
                     &mut self.amount_input,
-                    "Amount in BTC, ex: 0.123",
+                    ctx.tr("Amount in BTC, ex: 0.123 or 0,123"),
                     &amount,
                     RecipientMessage::AmountEdited,
                 )
-                .warning("Please enter a valid amount")
+                .warning(ctx.tr("Please enter a valid amount"))
                 .padding(10)
                 .render()
                 .width(Length::FillPortion(1)),
@@ -369,8 +546,25 @@ impl ManagerSendOutputView {
                 .width(Length::Shrink)
                 .align_x(Align::End),
             )
-            .spacing(20)
-            .into()
+            .spacing(20);
+        let mut col = Column::new().push(row).spacing(5);
+        if let Some(label) = label {
+            col = col.push(text::success(text::small(
+                &ctx.trf("Address book: {}", &[&label]),
+            )));
+        }
+        for ((label, address), button_state) in
+            suggestions.iter().zip(self.suggestion_buttons.iter_mut())
+        {
+            col = col.push(
+                button::transparent(
+                    button_state,
+                    button::button_content(None, &format!("{}: {}", label, address)),
+                )
+                .on_press(RecipientMessage::AddressEdited(address.to_string())),
+            );
+        }
+        col.into()
     }
 }
 
@@ -381,11 +575,13 @@ pub struct ManagerSelectInputsView {
     cancel_button: iced::button::State,
     next_button: iced::button::State,
     new_output_button: iced::button::State,
+    auto_select_button: iced::button::State,
 }
 
 impl ManagerSelectInputsView {
     pub fn new() -> Self {
         ManagerSelectInputsView {
+            auto_select_button: iced::button::State::new(),
             cancel_button: iced::button::State::new(),
             back_button: iced::button::State::new(),
             next_button: iced::button::State::new(),
@@ -394,12 +590,17 @@ impl ManagerSelectInputsView {
         }
     }
 
+    /// max_inputs is the number of vaults the transaction can spend, None
+    /// if it is unknown.
+    #[allow(clippy::too_many_arguments)]
     pub fn view<'a>(
         &'a mut self,
         ctx: &Context,
         inputs: Vec<Element<'a, Message>>,
         input_amount: u64,
         output_amount: u64,
+        selected: usize,
+        max_inputs: Option<usize>,
         warning: Option<&Error>,
     ) -> Element<'a, Message> {
         let header = Row::new()
@@ -408,7 +609,7 @@ impl ManagerSelectInputsView {
                     .push(
                         button::transparent(
                             &mut self.back_button,
-                            Container::new(text::simple("< Go back"))
+                            Container::new(text::simple(ctx.tr("< Go back")))
                                 .padding(10)
                                 .width(Length::Units(100))
                                 .align_x(Align::Center),
@@ -417,13 +618,13 @@ impl ManagerSelectInputsView {
                     )
                     .width(Length::Fill),
             )
-            .push(crate::ui::component::ProgressBar::spend_bar().draw(2))
+            .push(crate::ui::component::ProgressBar::spend_bar().draw(ctx.converter.language, 2))
             .push(
                 Column::new()
                     .push(
                         button::cancel(
                             &mut self.cancel_button,
-                            Container::new(text::simple("X Close"))
+                            Container::new(text::simple(ctx.tr("X Close")))
                                 .padding(10)
                                 .width(Length::Units(100))
                                 .align_x(Align::Center),
@@ -449,19 +650,60 @@ impl ManagerSelectInputsView {
         }
         let element: Element<_> = col_inputs.max_width(1000).into();
 
+        let summary = Row::new()
+            .push(
+                Container::new(text::simple(&ctx.trf(
+                    "{} vaults selected: {} / {} {}",
+                    &[
+                        &selected,
+                        &ctx.converter.amount_string(input_amount),
+                        &ctx.converter.amount_string(output_amount),
+                        &ctx.converter.unit,
+                    ],
+                )))
+                .width(Length::Fill),
+            )
+            .push(
+                button::cancel(
+                    &mut self.auto_select_button,
+                    button::button_content(None, ctx.tr("Auto-select")),
+                )
+                .on_press(Message::AutoSelectInputs),
+            )
+            .align_items(Align::Center)
+            .spacing(20)
+            .max_width(1000);
+
         let mut footer = Column::new().spacing(10).align_items(Align::Center);
         if let Some(error) = warning {
             footer = footer.push(card::alert_warning(Container::new(text::small(
                 &error.to_string(),
             ))));
         }
-        if input_amount < output_amount {
+        let too_many = max_inputs.map(|max| selected > max).unwrap_or(false);
+        if too_many {
+            footer = footer.push(card::alert_warning(Container::new(text::small(&ctx.trf(
+                "The transaction can spend at most {} vaults, it would exceed the standard size",
+                &[&max_inputs.unwrap_or(0)],
+            )))));
+        }
+        if too_many {
             footer = footer.push(Container::new(button::primary_disable(
                 &mut self.next_button,
-                Container::new(text::simple(&format!(
+                Container::new(text::simple(ctx.tr("Continue")))
+                    .width(Length::Units(200))
+                    .align_x(Align::Center)
+                    .padding(10),
+            )));
+        } else if input_amount < output_amount {
+            footer = footer.push(Container::new(button::primary_disable(
+                &mut self.next_button,
+                Container::new(text::simple(&ctx.trf(
                     "Missing {} {}",
-                    &ctx.converter.converts(output_amount - input_amount),
-                    ctx.converter.unit
+                    &[
+                        &&ctx.converter.amount_string(output_amount - input_amount),
+                        &ctx.converter.unit,
+                    ],
                 )))
                 .width(Length::Units(200))
                 .align_x(Align::Center)
@@ -471,7 +713,7 @@ impl ManagerSelectInputsView {
             footer = footer.push(Container::new(
                 button::primary(
                     &mut self.next_button,
-                    Container::new(text::simple("Continue"))
+                    Container::new(text::simple(ctx.tr("Continue")))
                         .padding(10)
                         .width(Length::Units(200))
                         .align_x(Align::Center),
@@ -484,14 +726,21 @@ impl ManagerSelectInputsView {
             Column::new()
                 .push(header)
                 .push(
-                    Container::new(text::bold(text::simple(&format!(
+                    Container::new(text::bold(text::simple(&ctx.trf(
                         "Select coins worth at least {} {}",
-                        &ctx.converter.converts(output_amount),
-                        ctx.converter.unit
+                        &[
+                            &&ctx.converter.amount_string(output_amount),
+                            &ctx.converter.unit,
+                        ],
                     ))))
                     .width(Length::Fill)
                     .align_x(Align::Center),
                 )
+                .push(
+                    Container::new(summary)
+                        .width(Length::Fill)
+                        .align_x(Align::Center),
+                )
                 .push(
                     scroll(
                         &mut self.scroll,
@@ -528,24 +777,37 @@ pub fn manager_send_input_view<'a>(
     ctx: &Context,
     outpoint: &str,
     amount: &u64,
+    received_at: i64,
+    label: Option<&str>,
     selected: bool,
 ) -> Element<'a, InputMessage> {
+    let age = (Utc::now().timestamp() - received_at).max(0) / (24 * 60 * 60);
     let checkbox = Checkbox::new(selected, "", InputMessage::Selected).text_size(10);
     let row = Row::new()
         .push(checkbox)
         .push(
             Container::new(
                 Row::new()
-                    .push(text::bold(text::simple(&format!(
-                        "{}",
-                        ctx.converter.converts(*amount)
-                    ))))
+                    .push(text::bold(text::simple(
+                        &ctx.converter.amount_string(*amount),
+                    )))
                     .push(text::small(&ctx.converter.unit.to_string()))
                     .align_items(Align::Center),
             )
             .width(Length::Fill),
         )
-        .push((Column::new().push(text::bold(text::small(outpoint)))).width(Length::Shrink))
+        .push(
+            Column::new()
+                .push(text::bold(text::small(outpoint)))
+                .push(text::small(label.unwrap_or(ctx.tr("No label"))))
+                .push(text::small(&match age {
+                    0 => ctx.tr("Received today").to_string(),
+                    1 => ctx.tr("Received 1 day ago").to_string(),
+                    _ => ctx.trf("Received {} days ago", &[&age]),
+                }))
+                .align_items(Align::End)
+                .width(Length::Shrink),
+        )
         .align_items(Align::Center)
         .spacing(20);
     card::white(Container::new(row)).width(Length::Fill).into()
@@ -560,6 +822,7 @@ pub struct ManagerSelectFeeView {
     slider: iced::slider::State,
     generate_button: iced::button::State,
     feerate_input: iced::text_input::State,
+    estimate_buttons: [iced::button::State; 3],
 }
 
 impl ManagerSelectFeeView {
@@ -572,13 +835,16 @@ impl ManagerSelectFeeView {
             slider: iced::slider::State::new(),
             generate_button: iced::button::State::new(),
             feerate_input: iced::text_input::State::new(),
+            estimate_buttons: Default::default(),
         }
     }
 
     pub fn view<'a>(
         &'a mut self,
+        ctx: &Context,
         feerate: Option<u32>,
         valid_feerate: bool,
+        fee_estimates: Option<&Result<Vec<FeeEstimate>, BitcoindError>>,
         warning: Option<&Error>,
     ) -> Element<'a, Message> {
         let header = Row::new()
@@ -587,7 +853,7 @@ impl ManagerSelectFeeView {
                     .push(
                         button::transparent(
                             &mut self.back_button,
-                            Container::new(text::simple("< Go back"))
+                            Container::new(text::simple(ctx.tr("< Go back")))
                                 .padding(10)
                                 .width(Length::Units(100))
                                 .align_x(Align::Center),
@@ -596,13 +862,13 @@ impl ManagerSelectFeeView {
                     )
                     .width(Length::Fill),
             )
-            .push(crate::ui::component::ProgressBar::spend_bar().draw(1))
+            .push(crate::ui::component::ProgressBar::spend_bar().draw(ctx.converter.language, 1))
             .push(
                 Column::new()
                     .push(Container::new(
                         button::cancel(
                             &mut self.cancel_button,
-                            Container::new(text::simple("X Close"))
+                            Container::new(text::simple(ctx.tr("X Close")))
                                 .padding(10)
                                 .width(Length::Units(100))
                                 .align_x(Align::Center),
@@ -619,7 +885,7 @@ impl ManagerSelectFeeView {
         let fee_button = if valid_feerate {
             button::primary(
                 &mut self.generate_button,
-                Container::new(text::simple("Continue"))
+                Container::new(text::simple(ctx.tr("Continue")))
                     .padding(10)
                     .width(Length::Units(200))
                     .align_x(Align::Center),
@@ -628,7 +894,7 @@ impl ManagerSelectFeeView {
         } else {
             button::primary_disable(
                 &mut self.generate_button,
-                Container::new(text::simple("Continue"))
+                Container::new(text::simple(ctx.tr("Continue")))
                     .padding(10)
                     .width(Length::Units(200))
                     .align_x(Align::Center),
@@ -637,7 +903,7 @@ impl ManagerSelectFeeView {
 
         let mut col_fee = Column::new()
             .push(
-                Container::new(text::bold(text::simple("Select fee")))
+                Container::new(text::bold(text::simple(ctx.tr("Select fee"))))
                     .width(Length::Fill)
                     .align_x(Align::Center),
             )
@@ -656,12 +922,17 @@ impl ManagerSelectFeeView {
                             .width(Length::Units(70))
                             .padding(10),
                         )
-                        .push(text::simple("sats/vbyte"))
+                        .push(text::simple(ctx.tr("sats/vbyte")))
                         .spacing(5)
                         .align_items(Align::Center),
                 )
                 .height(Length::Fill),
             )
+            .push(fee_estimates_view(
+                ctx,
+                &mut self.estimate_buttons,
+                fee_estimates,
+            ))
             .spacing(20)
             .align_items(Align::Center);
 
@@ -696,37 +967,59 @@ impl ManagerSelectFeeView {
     }
 }
 
+/// Displays the suggested feerates, a suggestion fills the feerate input.
+fn fee_estimates_view<'a>(
+    ctx: &Context,
+    buttons: &'a mut [iced::button::State; 3],
+    fee_estimates: Option<&Result<Vec<FeeEstimate>, BitcoindError>>,
+) -> Element<'a, Message> {
+    match fee_estimates {
+        None => text::small(ctx.tr("Estimating feerates...")).into(),
+        Some(Err(e)) => text::small(&ctx.trf("Feerate estimation unavailable: {}", &[&e])).into(),
+        Some(Ok(estimates)) if estimates.is_empty() => {
+            text::small(ctx.tr("bitcoind has not enough data to estimate feerates yet")).into()
+        }
+        Some(Ok(estimates)) => {
+            let mut row = Row::new().spacing(10);
+            for (estimate, button_state) in estimates.iter().zip(buttons.iter_mut()) {
+                row = row.push(
+                    button::white_card_button(
+                        button_state,
+                        Container::new(
+                            Column::new()
+                                .push(text::bold(text::small(&estimate.level.to_string())))
+                                .push(text::simple(
+                                    &ctx.trf("{} sats/vbyte", &[&estimate.feerate]),
+                                ))
+                                .push(text::small(
+                                    &ctx.trf("Confirmation in ~{} blocks", &[&estimate.blocks]),
+                                ))
+                                .align_items(Align::Center),
+                        ),
+                    )
+                    .on_press(Message::SpendTx(SpendTxMessage::FeerateEdited(
+                        estimate.feerate.to_string(),
+                    ))),
+                );
+            }
+            row.into()
+        }
+    }
+}
+
 pub fn spend_tx_with_feerate_view<'a, T: 'a>(
     ctx: &Context,
     inputs: &[model::Vault],
     psbt: &Psbt,
     feerate: Option<&u32>,
+    hints: &[SpendHint],
 ) -> Container<'a, T> {
     // TODO: This is not the total fees, as it is missing the unvault fees
     // (and it's different from the one displayed in the home, which is
     // confusing)
-    let mut total_fees = 0;
-    let mut col_input = Column::new()
-        .push(text::bold(text::simple("Inputs")))
-        .spacing(10);
-    for input in inputs {
-        total_fees += input.amount;
-        col_input = col_input.push(card::simple(Container::new(
-            Row::new()
-                .push(Container::new(text::small(&input.address.to_string())).width(Length::Fill))
-                .push(
-                    Container::new(text::bold(text::small(&format!(
-                        "{}",
-                        ctx.converter.converts(input.amount),
-                    ))))
-                    .width(Length::Shrink),
-                )
-                .spacing(5)
-                .align_items(Align::Center),
-        )));
-    }
+    let mut total_fees: u64 = inputs.iter().map(|input| input.amount).sum();
     let mut col_output = Column::new()
-        .push(text::bold(text::simple("Outputs")))
+        .push(text::bold(text::simple(ctx.tr("Outputs"))))
         .spacing(10);
     for output in &psbt.global.unsigned_tx.output {
         if total_fees > output.value {
@@ -739,47 +1032,116 @@ pub fn spend_tx_with_feerate_view<'a, T: 'a>(
             Row::new()
                 .push(Container::new(text::small(&addr.to_string())).width(Length::Fill))
                 .push(Container::new(
-                    text::bold(text::small(&format!(
-                        "{}",
-                        ctx.converter.converts(output.value)
-                    )))
-                    .width(Length::Shrink),
+                    text::bold(text::small(&ctx.converter.amount_string(output.value)))
+                        .width(Length::Shrink),
                 ))
                 .spacing(5)
                 .align_items(Align::Center),
         )));
     }
+    let fee_shares = fee_attribution(inputs, total_fees);
+    let mut col_input = Column::new()
+        .push(text::bold(text::simple(ctx.tr("Inputs"))))
+        .spacing(10);
+    for (input, fee_share) in inputs.iter().zip(fee_shares) {
+        col_input = col_input.push(card::simple(Container::new(
+            Column::new()
+                .push(
+                    Row::new()
+                        .push(
+                            Container::new(text::small(&input.address.to_string()))
+                                .width(Length::Fill),
+                        )
+                        .push(
+                            Container::new(text::bold(text::small(
+                                &ctx.converter.amount_string(input.amount),
+                            )))
+                            .width(Length::Shrink),
+                        )
+                        .spacing(5)
+                        .align_items(Align::Center),
+                )
+                .push(
+                    Row::new()
+                        .push(Container::new(text::small(ctx.tr("Fee share:"))).width(Length::Fill))
+                        .push(
+                            Container::new(text::small(&format!(
+                                "{} {}",
+                                ctx.converter.amount_string(fee_share),
+                                ctx.converter.unit,
+                            )))
+                            .width(Length::Shrink),
+                        )
+                        .spacing(5)
+                        .align_items(Align::Center),
+                )
+                .spacing(5),
+        )));
+    }
     let mut column_fee = Column::new();
     if let Some(feerate) = feerate {
-        column_fee = column_fee.push(
-            Row::new()
-                .push(text::simple("Feerate: "))
-                .push(text::bold(text::simple(&format!("{} sats/vbyte", feerate)))),
-        )
+        column_fee = column_fee.push(Row::new().push(text::simple(ctx.tr("Feerate: "))).push(
+            text::bold(text::simple(&ctx.trf("{} sats/vbyte", &[&feerate]))),
+        ))
+    }
+    let mut col = Column::new().spacing(20);
+    if !hints.is_empty() {
+        let mut col_hints = Column::new()
+            .push(text::bold(text::small(ctx.tr(
+                "Please review the destinations, these hints are advisory only:",
+            ))))
+            .spacing(5);
+        for hint in hints {
+            col_hints = col_hints.push(text::small(&hint.to_string()));
+        }
+        col = col.push(card::alert_warning(Container::new(col_hints)));
     }
     Container::new(
-        Column::new()
-            .push(
-                column_fee.push(
-                    Row::new()
-                        .push(text::simple("Total fees: "))
-                        .push(text::bold(text::simple(&format!(
-                            "{}",
-                            ctx.converter.converts(total_fees)
-                        ))))
-                        .push(text::simple(&format!(" {}", ctx.converter.unit))),
-                ),
-            )
-            .push(
+        col.push(
+            column_fee.push(
                 Row::new()
-                    .push(col_input.width(Length::FillPortion(1)))
-                    .push(col_output.width(Length::FillPortion(1)))
-                    .spacing(20),
-            )
-            .spacing(20),
+                    .push(text::simple(ctx.tr("Total fees: ")))
+                    .push(text::bold(text::simple(
+                        &ctx.converter.amount_string(total_fees),
+                    )))
+                    .push(text::simple(&format!(" {}", ctx.converter.unit))),
+            ),
+        )
+        .push(
+            Row::new()
+                .push(col_input.width(Length::FillPortion(1)))
+                .push(col_output.width(Length::FillPortion(1)))
+                .spacing(20),
+        ),
     )
 }
 
+/// Splits the transaction fees between the vault inputs, proportionally to
+/// their amount. Remaining satoshis after the integer division are given to
+/// the inputs with the largest remainders, so that the shares always sum up
+/// to the total fees.
+fn fee_attribution(inputs: &[model::Vault], total_fees: u64) -> Vec<u64> {
+    let total_amount: u128 = inputs.iter().map(|input| input.amount as u128).sum();
+    if total_amount == 0 {
+        return vec![0; inputs.len()];
+    }
+
+    let mut shares = Vec::with_capacity(inputs.len());
+    let mut remainders = Vec::with_capacity(inputs.len());
+    for (i, input) in inputs.iter().enumerate() {
+        let weighted = input.amount as u128 * total_fees as u128;
+        shares.push((weighted / total_amount) as u64);
+        remainders.push((weighted % total_amount, i));
+    }
+
+    let distributed: u64 = shares.iter().sum();
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    for (_, i) in remainders.iter().take((total_fees - distributed) as usize) {
+        shares[*i] += 1;
+    }
+    shares
+}
+
 #[derive(Debug)]
 pub struct ManagerSignView {
     scroll: scrollable::State,
@@ -798,12 +1160,15 @@ impl ManagerSignView {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn view<'a>(
         &'a mut self,
         ctx: &Context,
         inputs: &[model::Vault],
         psbt: &Psbt,
         feerate: &u32,
+        hints: &[SpendHint],
+        checks: &[CheckResult],
         warning: Option<&Error>,
         signer: Element<'a, Message>,
     ) -> Element<'a, Message> {
@@ -813,7 +1178,7 @@ impl ManagerSignView {
                     .push(
                         button::transparent(
                             &mut self.back_button,
-                            Container::new(text::simple("< Go back"))
+                            Container::new(text::simple(ctx.tr("< Go back")))
                                 .padding(10)
                                 .width(Length::Units(100))
                                 .align_x(Align::Center),
@@ -822,13 +1187,13 @@ impl ManagerSignView {
                     )
                     .width(Length::Fill),
             )
-            .push(crate::ui::component::ProgressBar::spend_bar().draw(3))
+            .push(crate::ui::component::ProgressBar::spend_bar().draw(ctx.converter.language, 3))
             .push(
                 Column::new()
                     .push(
                         button::cancel(
                             &mut self.cancel_button,
-                            Container::new(text::simple("X Close"))
+                            Container::new(text::simple(ctx.tr("X Close")))
                                 .padding(10)
                                 .width(Length::Units(100))
                                 .align_x(Align::Center),
@@ -844,7 +1209,13 @@ impl ManagerSignView {
             .padding(10)
             .spacing(10);
         let mut col = Column::new()
-            .push(spend_tx_with_feerate_view(ctx, inputs, psbt, Some(feerate)))
+            .push(spend_tx_with_feerate_view(
+                ctx,
+                inputs,
+                psbt,
+                Some(feerate),
+                hints,
+            ))
             .spacing(20)
             .max_width(1000);
         if let Some(error) = warning {
@@ -852,12 +1223,19 @@ impl ManagerSignView {
                 &error.to_string(),
             ))));
         }
-        col = col.push(card::white(Container::new(signer)));
+        col = col.push(checks_card(ctx, checks));
+        if spend_checks::has_failure(checks) {
+            col = col.push(card::alert_warning(Container::new(text::simple(
+                ctx.tr("Fix the failed checks before signing the transaction"),
+            ))));
+        } else {
+            col = col.push(card::white(Container::new(signer)));
+        }
         Container::new(
             Column::new()
                 .push(header)
                 .push(
-                    Container::new(text::bold(text::simple("Sign transaction")))
+                    Container::new(text::bold(text::simple(ctx.tr("Sign transaction"))))
                         .width(Length::Fill)
                         .align_x(Align::Center),
                 )
@@ -905,19 +1283,23 @@ impl ManagerSpendTransactionCreatedView {
         inputs: &[model::Vault],
         psbt: &Psbt,
         feerate: &u32,
+        hints: &[SpendHint],
     ) -> Element<'a, Message> {
         Container::new(
             Column::new()
                 .push(
                     Row::new()
                         .push(Column::new().width(Length::Fill))
-                        .push(crate::ui::component::ProgressBar::spend_bar().draw(4))
+                        .push(
+                            crate::ui::component::ProgressBar::spend_bar()
+                                .draw(ctx.converter.language, 4),
+                        )
                         .push(
                             Column::new()
                                 .push(
                                     button::cancel(
                                         &mut self.cancel_button,
-                                        Container::new(text::simple("X Close"))
+                                        Container::new(text::simple(ctx.tr("X Close")))
                                             .padding(10)
                                             .width(Length::Units(100))
                                             .align_x(Align::Center),
@@ -936,7 +1318,13 @@ impl ManagerSpendTransactionCreatedView {
                         &mut self.scroll,
                         Container::new(
                             Column::new()
-                                .push(spend_tx_with_feerate_view(ctx, inputs, psbt, Some(feerate)))
+                                .push(spend_tx_with_feerate_view(
+                                    ctx,
+                                    inputs,
+                                    psbt,
+                                    Some(feerate),
+                                    hints,
+                                ))
                                 .spacing(20)
                                 .max_width(1000),
                         )
@@ -949,7 +1337,7 @@ impl ManagerSpendTransactionCreatedView {
                 )
                 .push(
                     Container::new(text::success(text::simple(
-                        "Your transaction has been saved",
+                        ctx.tr("Your transaction has been saved"),
                     )))
                     .width(Length::Fill)
                     .align_x(Align::Center),
@@ -963,3 +1351,30 @@ impl ManagerSpendTransactionCreatedView {
         .into()
     }
 }
+
+/// checks_card is the validation summary of the transaction to sign.
+fn checks_card<'a>(ctx: &Context, checks: &[CheckResult]) -> Container<'a, Message> {
+    let mut col = Column::new()
+        .push(text::bold(text::simple(ctx.tr("Validation summary"))))
+        .spacing(10);
+    for check in checks {
+        let status = match check.status {
+            CheckStatus::Pass => text::small(ctx.tr("Pass")).color(color::SUCCESS),
+            CheckStatus::Warn => text::small(ctx.tr("Warn")).color(color::WARNING),
+            CheckStatus::Fail => text::small(ctx.tr("Fail")).color(color::CANCEL),
+        };
+        col = col.push(
+            Row::new()
+                .push(Container::new(status).width(Length::Units(50)))
+                .push(
+                    Column::new()
+                        .push(text::bold(text::small(check.name)))
+                        .push(text::small(&check.detail))
+                        .width(Length::Fill),
+                )
+                .spacing(10)
+                .align_items(Align::Center),
+        );
+    }
+    card::white(Container::new(col))
+}
@@ -1,32 +1,63 @@
+mod alerts;
+mod amount;
 pub mod charging;
+mod confirm;
+mod connection;
+mod console;
+mod coordinator;
 mod deposit;
 mod emergency;
+mod flow;
 mod home;
+mod labels;
 mod layout;
+mod logs;
 pub mod manager;
 mod network;
+mod palette;
+mod screenshot;
 mod settings;
 mod sidebar;
 pub mod sign;
 pub mod spend_transaction;
 pub mod stakeholder;
+mod tour;
+mod training;
 pub mod vault;
 mod vaults;
 
+pub use alerts::AlertsView;
+pub use confirm::ConfirmationView;
+pub use connection::{daemon_lost, toast};
+pub use console::ConsoleView;
+pub use coordinator::degraded_mode;
 pub use deposit::DepositView;
 pub use emergency::EmergencyView;
+pub use flow::FlowView;
 pub use home::{ManagerHomeView, StakeholderHomeView};
+pub use labels::{labeled_vault_card, labeled_vault_list_item, LabelAssignView, LabelManagerView};
+pub use logs::LogsView;
 pub use network::{ManagerNetworkView, StakeholderNetworkView};
+pub use palette::PaletteView;
+pub use screenshot::banner as screenshot_banner;
 pub use settings::SettingsView;
 pub use spend_transaction::{SpendTransactionListItemView, SpendTransactionView};
-pub use stakeholder::{StakeholderCreateVaultsView, StakeholderDelegateFundsView};
+pub use stakeholder::{
+    selectable_deposit, SecureBatchView, StakeholderCreateVaultsView, StakeholderDelegateFundsView,
+};
+pub use tour::TourView;
+pub use training::watermark as training_watermark;
 pub use vault::VaultView;
 pub use vaults::VaultsView;
 
 use bitcoin::Network;
 
-use super::menu::Menu;
-use crate::{conversion::Converter, revault::Role};
+use super::{config::DEFAULT_SPEND_FINAL_CONFIRMATIONS, menu::Menu};
+use crate::{
+    conversion::Converter,
+    revault::Role,
+    ui::theme::{Theme, ThemePreference},
+};
 
 /// Context stores display informations and features
 /// used directly by views. It does not store anything
@@ -35,9 +66,25 @@ pub struct Context {
     pub converter: Converter,
     pub network: Network,
     pub network_up: bool,
+    /// false if revaultd could not reach the coordinator, the app is then
+    /// in a degraded mode.
+    pub coordinator_up: bool,
+    /// Last block height returned by revaultd.
+    pub blockheight: u64,
+    /// Number of confirmations before a spend is displayed as final.
+    pub spend_final_confirmations: u32,
     pub menu: Menu,
     pub role: Role,
     pub role_edit: bool,
+    /// Theme setting of the user, the theme is resolved from it.
+    pub theme_preference: ThemePreference,
+    pub theme: Theme,
+    /// Time of the last refresh of the vaults.
+    pub last_update: Option<i64>,
+    /// true if the user paused the background refresh.
+    pub poll_paused: bool,
+    /// true if the developer console is displayed in the sidebar.
+    pub developer_console: bool,
 }
 
 impl Context {
@@ -47,6 +94,7 @@ impl Context {
         role_edit: bool,
         role: Role,
         menu: Menu,
+        theme: Theme,
     ) -> Self {
         Self {
             converter,
@@ -55,8 +103,27 @@ impl Context {
             menu,
             network,
             network_up: false,
+            coordinator_up: true,
+            blockheight: 0,
+            spend_final_confirmations: DEFAULT_SPEND_FINAL_CONFIRMATIONS,
+            theme_preference: ThemePreference::default(),
+            theme,
+            last_update: None,
+            poll_paused: false,
+            developer_console: false,
         }
     }
+
+    /// Returns the text in the language of the interface.
+    pub fn tr<'a>(&self, text: &'a str) -> &'a str {
+        self.converter.language.tr(text)
+    }
+
+    /// Returns the text in the language of the interface, each `{}` of the
+    /// text is replaced by the next argument.
+    pub fn trf(&self, text: &str, args: &[&dyn std::fmt::Display]) -> String {
+        self.converter.language.trf(text, args)
+    }
 }
 
 impl std::default::Default for Context {
@@ -65,9 +132,17 @@ impl std::default::Default for Context {
             converter: Converter::new(Network::Bitcoin),
             network: Network::Bitcoin,
             network_up: false,
+            coordinator_up: true,
+            blockheight: 0,
+            spend_final_confirmations: DEFAULT_SPEND_FINAL_CONFIRMATIONS,
             role: Role::Manager,
             menu: Menu::Home,
             role_edit: false,
+            theme_preference: ThemePreference::default(),
+            theme: Theme::default(),
+            last_update: None,
+            poll_paused: false,
+            developer_console: false,
         }
     }
 }
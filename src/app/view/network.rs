@@ -1,14 +1,20 @@
-use iced::{scrollable, Column, Container, Element, Length, Row};
+use chrono::NaiveDateTime;
+use iced::{scrollable, Align, Column, Container, Element, Length, Row};
 
 use crate::{
     app::{
+        contacts::{Contact, Contacts},
+        coordinator::CoordinatorStatus,
         error::Error,
         message::Message,
         view::{layout, sidebar::Sidebar, Context},
     },
+    bitcoind::{BitcoindError, PruningCheck},
+    net::ServerHealth,
+    revaultd::{model::VaultStatus, RevaultDError},
     ui::{
         color,
-        component::{badge, card, navbar, scroll, text},
+        component::{badge, button, card, navbar, scroll, separation, text},
         icon::dot_icon,
     },
 };
@@ -17,6 +23,7 @@ use crate::{
 pub struct ManagerNetworkView {
     sidebar: Sidebar,
     scroll: scrollable::State,
+    ping_button: iced::button::State,
 }
 
 impl ManagerNetworkView {
@@ -24,14 +31,20 @@ impl ManagerNetworkView {
         ManagerNetworkView {
             scroll: scrollable::State::new(),
             sidebar: Sidebar::new(),
+            ping_button: iced::button::State::new(),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn view<'a>(
         &'a mut self,
         ctx: &Context,
         warning: Option<&Error>,
         blockheight: Option<&u64>,
+        pruning: Option<&Result<PruningCheck, BitcoindError>>,
+        coordinator: Option<&Result<CoordinatorStatus, RevaultDError>>,
+        cosigners: &[ServerHealth],
+        contacts: &Contacts,
     ) -> Element<'a, Message> {
         layout::dashboard(
             navbar(layout::navbar_warning(warning)),
@@ -40,7 +53,15 @@ impl ManagerNetworkView {
                 &mut self.scroll,
                 Container::new(
                     Column::new()
-                        .push(bitcoin_core_card(blockheight))
+                        .push(bitcoin_core_card(ctx, blockheight, pruning))
+                        .push(coordinator_card(ctx, coordinator, contacts))
+                        .push(servers_card(
+                            ctx,
+                            "Cosigners",
+                            &mut self.ping_button,
+                            cosigners,
+                            contacts,
+                        ))
                         .spacing(20),
                 ),
             ))),
@@ -49,10 +70,191 @@ impl ManagerNetworkView {
     }
 }
 
+/// servers_card displays the reachability of the servers, with the contact
+/// of the operator of the unreachable ones.
+fn servers_card<'a>(
+    ctx: &Context,
+    title: &str,
+    ping_button: &'a mut iced::button::State,
+    servers: &[ServerHealth],
+    contacts: &Contacts,
+) -> Container<'a, Message> {
+    let mut col = Column::new()
+        .push(
+            Row::new()
+                .push(Container::new(text::bold(text::simple(ctx.tr(title)))).width(Length::Fill))
+                .push(
+                    button::transparent(
+                        ping_button,
+                        button::button_content(None, ctx.tr("Check again")),
+                    )
+                    .on_press(Message::PingServers),
+                )
+                .align_items(Align::Center),
+        )
+        .spacing(10);
+    if servers.is_empty() {
+        col = col.push(text::small(ctx.tr("No server is configured")));
+    }
+    for server in servers {
+        let (status, color) = match &server.latency {
+            None => (ctx.tr("Checking...").to_string(), color::DARK_GREY),
+            Some(Ok(latency)) => (
+                ctx.trf("Reachable ({} ms)", &[&latency.as_millis()]),
+                color::SUCCESS,
+            ),
+            Some(Err(_)) => (ctx.tr("Unreachable").to_string(), color::WARNING),
+        };
+        let mut details = Column::new().push(text::bold(text::small(&server.host)));
+        if let Some(Err(e)) = &server.latency {
+            details = details.push(text::small(&e.to_string()));
+            if let Some(contact) = contacts.get(&server.host) {
+                details = details.push(contact_text(ctx, contact));
+            }
+        }
+        details = details.push(text::small(&match server.last_seen {
+            Some(time) => ctx.trf("Last seen: {}", &[&NaiveDateTime::from_timestamp(time, 0)]),
+            None => ctx.tr("Last seen: never").to_string(),
+        }));
+        col = col.push(separation().width(Length::Fill)).push(
+            Row::new()
+                .push(Container::new(details).width(Length::Fill))
+                .push(
+                    Row::new()
+                        .push(dot_icon().size(5).color(color))
+                        .push(text::small(&status).color(color))
+                        .align_items(Align::Center),
+                )
+                .align_items(Align::Center),
+        );
+    }
+    card::simple(Container::new(col))
+}
+
+fn contact_text(ctx: &Context, contact: &Contact) -> iced::Text {
+    text::small(&ctx.trf("Contact: {}", &[&contact])).color(color::WARNING)
+}
+
+/// coordinator_card displays whether the signatures shared by this GUI
+/// can reach the other participants through the coordinator.
+fn coordinator_card<'a, T: 'a>(
+    ctx: &Context,
+    coordinator: Option<&Result<CoordinatorStatus, RevaultDError>>,
+    contacts: &Contacts,
+) -> Container<'a, T> {
+    let status = match coordinator {
+        None => {
+            return card::simple(Container::new(text::bold(text::simple(
+                ctx.tr("Coordinator"),
+            ))))
+        }
+        Some(Err(e)) => {
+            return card::simple(Container::new(
+                Column::new()
+                    .push(text::bold(text::simple(ctx.tr("Coordinator"))))
+                    .push(text::small(
+                        &ctx.trf("Coordinator status could not be retrieved: {}", &[&e]),
+                    ))
+                    .spacing(10),
+            ))
+        }
+        Some(Ok(status)) => status,
+    };
+    let (state, color) = match (&status.probe, status.reachable) {
+        (Ok(latency), true) => (
+            ctx.trf("Reachable ({} ms)", &[&latency.as_millis()]),
+            color::SUCCESS,
+        ),
+        (Ok(_), false) => (
+            ctx.tr("Not reached by revaultd").to_string(),
+            color::WARNING,
+        ),
+        (Err(_), _) => (ctx.tr("Unreachable").to_string(), color::WARNING),
+    };
+    let mut col = Column::new()
+        .push(
+            Row::new()
+                .push(
+                    Container::new(text::bold(text::simple(ctx.tr("Coordinator"))))
+                        .width(Length::Fill),
+                )
+                .push(
+                    Row::new()
+                        .push(dot_icon().size(5).color(color))
+                        .push(text::small(&state).color(color))
+                        .align_items(Align::Center),
+                )
+                .align_items(Align::Center),
+        )
+        .push(text::small(&status.host))
+        .spacing(10);
+    if let Err(e) = &status.probe {
+        col = col.push(text::small(&e.to_string()));
+    } else if !status.reachable {
+        col = col.push(text::small(
+            ctx.tr("The last request of revaultd to the coordinator failed, \
+             the signatures are shared again once it is reachable."),
+        ));
+    }
+    if status.probe.is_err() || !status.reachable {
+        if let Some(contact) = contacts.get(&status.host) {
+            col = col.push(contact_text(ctx, contact));
+        }
+    }
+    col = col.push(text::small(&match &status.last_exchange {
+        Some(action) => ctx.trf(
+            "Last exchange: {} ({})",
+            &[&NaiveDateTime::from_timestamp(action.date, 0), &action.kind],
+        ),
+        None => ctx.tr("Last exchange: none from this computer").to_string(),
+    }));
+
+    col = col.push(separation().width(Length::Fill));
+    let pending = [
+        (
+            VaultStatus::Securing,
+            "wait for the revocation signatures of the other stakeholders",
+        ),
+        (
+            VaultStatus::Activating,
+            "wait for the unvault signatures of the other stakeholders",
+        ),
+    ];
+    let mut waiting = false;
+    for (vault_status, description) in &pending {
+        let vaults: Vec<_> = status
+            .pending
+            .iter()
+            .filter(|v| v.status == *vault_status)
+            .collect();
+        if vaults.is_empty() {
+            continue;
+        }
+        waiting = true;
+        let amount: u64 = vaults.iter().map(|v| v.amount).sum();
+        col = col.push(text::small(&ctx.trf(
+            "{} vaults ({} {}) {}",
+            &[
+                &vaults.len(),
+                &ctx.converter.amount_string(amount),
+                &ctx.converter.unit,
+                &ctx.tr(description),
+            ],
+        )));
+    }
+    if !waiting {
+        col = col.push(text::small(
+            ctx.tr("Nothing waits for the signatures of the other participants"),
+        ));
+    }
+    card::simple(Container::new(col))
+}
+
 #[derive(Debug)]
 pub struct StakeholderNetworkView {
     sidebar: Sidebar,
     scroll: scrollable::State,
+    ping_button: iced::button::State,
 }
 
 impl StakeholderNetworkView {
@@ -60,14 +262,20 @@ impl StakeholderNetworkView {
         StakeholderNetworkView {
             scroll: scrollable::State::new(),
             sidebar: Sidebar::new(),
+            ping_button: iced::button::State::new(),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn view<'a>(
         &'a mut self,
         ctx: &Context,
         warning: Option<&Error>,
         blockheight: Option<&u64>,
+        pruning: Option<&Result<PruningCheck, BitcoindError>>,
+        coordinator: Option<&Result<CoordinatorStatus, RevaultDError>>,
+        watchtowers: &[ServerHealth],
+        contacts: &Contacts,
     ) -> Element<'a, Message> {
         layout::dashboard(
             navbar(layout::navbar_warning(warning)),
@@ -76,7 +284,15 @@ impl StakeholderNetworkView {
                 &mut self.scroll,
                 Container::new(
                     Column::new()
-                        .push(bitcoin_core_card(blockheight))
+                        .push(bitcoin_core_card(ctx, blockheight, pruning))
+                        .push(coordinator_card(ctx, coordinator, contacts))
+                        .push(servers_card(
+                            ctx,
+                            "Watchtowers",
+                            &mut self.ping_button,
+                            watchtowers,
+                            contacts,
+                        ))
                         .spacing(20),
                 ),
             ))),
@@ -85,16 +301,23 @@ impl StakeholderNetworkView {
     }
 }
 
-fn bitcoin_core_card<'a, T: 'a>(blockheight: Option<&u64>) -> Container<'a, T> {
+fn bitcoin_core_card<'a, T: 'a>(
+    ctx: &Context,
+    blockheight: Option<&u64>,
+    pruning: Option<&Result<PruningCheck, BitcoindError>>,
+) -> Container<'a, T> {
     let mut col = Column::new()
         .push(
             Row::new()
-                .push(Container::new(text::bold(text::simple("Bitcoin Core"))).width(Length::Fill))
+                .push(
+                    Container::new(text::bold(text::simple(ctx.tr("Bitcoin Core"))))
+                        .width(Length::Fill),
+                )
                 .push(
                     Container::new(
                         Row::new()
                             .push(dot_icon().size(5).color(color::SUCCESS))
-                            .push(text::small("Running").color(color::SUCCESS))
+                            .push(text::small(ctx.tr("Running")).color(color::SUCCESS))
                             .align_items(iced::Align::Center),
                     )
                     .width(Length::Shrink),
@@ -107,11 +330,32 @@ fn bitcoin_core_card<'a, T: 'a>(blockheight: Option<&u64>) -> Container<'a, T> {
                 .push(badge::block())
                 .push(
                     Column::new()
-                        .push(text::bold(text::simple("Block Height")))
+                        .push(text::bold(text::simple(ctx.tr("Block Height"))))
                         .push(text::simple(&b.to_string())),
                 )
                 .spacing(10),
         );
     }
+    match pruning {
+        Some(Ok(PruningCheck::NotPruned)) => {
+            col = col.push(text::small(ctx.tr("Pruning: disabled")));
+        }
+        Some(Ok(PruningCheck::Compatible { depth })) => {
+            col = col.push(text::small(
+                &ctx.trf("Pruning: enabled, the last {} blocks are kept", &[&depth]),
+            ));
+        }
+        Some(Ok(check)) => {
+            col = col.push(card::alert_warning(Container::new(text::small(
+                &check.guidance(ctx.converter.language).unwrap_or_default(),
+            ))));
+        }
+        Some(Err(e)) => {
+            col = col.push(text::small(
+                &ctx.trf("Pruning configuration could not be checked: {}", &[&e]),
+            ));
+        }
+        None => {}
+    }
     card::simple(Container::new(col))
 }
@@ -0,0 +1,80 @@
+use iced::{text_input, Align, Column, Container, Element, Length, Row, TextInput};
+
+use crate::{
+    app::{
+        message::{Message, ShortcutMessage},
+        shortcuts::PaletteEntry,
+        view::Context,
+    },
+    ui::component::{button, card, text, ContainerBackgroundStyle},
+};
+
+/// PaletteView is the command palette opened with Ctrl+K,
+/// displayed in place of the panel.
+#[derive(Debug)]
+pub struct PaletteView {
+    query_input: text_input::State,
+    entry_buttons: Vec<iced::button::State>,
+}
+
+impl PaletteView {
+    pub fn new() -> Self {
+        PaletteView {
+            query_input: text_input::State::focused(),
+            entry_buttons: Vec::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        query: &str,
+        entries: &[PaletteEntry],
+    ) -> Element<'a, Message> {
+        let mut col = Column::new()
+            .push(
+                TextInput::new(
+                    &mut self.query_input,
+                    ctx.tr("Type an action"),
+                    query,
+                    |query| Message::Shortcut(ShortcutMessage::PaletteQueryEdited(query)),
+                )
+                .on_submit(Message::Shortcut(ShortcutMessage::RunFirst))
+                .size(15)
+                .padding(10),
+            )
+            .spacing(10);
+
+        if entries.is_empty() {
+            col = col.push(text::small(ctx.tr("No action found")));
+        }
+        self.entry_buttons
+            .resize_with(entries.len(), iced::button::State::new);
+        for (entry, state) in entries.iter().zip(self.entry_buttons.iter_mut()) {
+            let mut row = Row::new()
+                .push(text::simple(&entry.label).width(Length::Fill))
+                .align_items(Align::Center);
+            if let Some(keys) = entry.keys {
+                row = row.push(text::small(keys));
+            }
+            col = col.push(
+                button::transparent(state, Container::new(row).padding(5))
+                    .on_press(Message::Shortcut(ShortcutMessage::Run(Box::new(
+                        entry.message.clone(),
+                    ))))
+                    .width(Length::Fill),
+            );
+        }
+        col = col.push(text::small(ctx.tr("Escape to close")));
+
+        Container::new(card::white(
+            Container::new(col).padding(20).width(Length::Units(600)),
+        ))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .style(ContainerBackgroundStyle)
+        .padding(100)
+        .into()
+    }
+}
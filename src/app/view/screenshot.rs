@@ -0,0 +1,17 @@
+use iced::{Align, Container, Element, Length};
+
+use crate::{
+    app::{message::Message, view::Context},
+    ui::component::{card, text},
+};
+
+/// banner is displayed on every screen while the screenshot mode is enabled,
+/// the placeholders must not be mistaken for the data of the wallet.
+pub fn banner<'a>(ctx: &Context) -> Element<'a, Message> {
+    card::alert_warning(Container::new(text::bold(text::simple(
+        ctx.tr("SCREENSHOT MODE - the transaction ids, addresses and keys displayed are placeholders, do not use them"),
+    ))))
+    .width(Length::Fill)
+    .align_x(Align::Center)
+    .into()
+}
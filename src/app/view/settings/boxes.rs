@@ -1,17 +1,51 @@
-use iced::{Align, Column, Container, Length, Row};
+use std::path::Path;
+
+use chrono::NaiveDateTime;
+use iced::{
+    pick_list, text_input, Align, Checkbox, Column, Container, Length, PickList, Row, TextInput,
+};
 
 use crate::{
-    app::message::Message,
-    ui::component::{card, separation, text},
+    app::{
+        addressbook::AddressBook,
+        backup::Backup,
+        confirm,
+        connection::Instance,
+        contacts::{ContactField, Contacts},
+        daemon_config::{ConfigEdit, ConfigField},
+        message::{
+            AddressBookMessage, BackupMessage, ConfigEditMessage, ContactMessage, DaemonMessage,
+            LogsMessage, Message, MetadataMessage, NoiseKeyMessage, ReportMessage,
+        },
+        metadata::{Resolution, Transfer},
+        metrics::Counters,
+        notifications::{NotificationEvent, NotificationSettings},
+        report::{ReportField, ReportSettings},
+        view::Context,
+    },
+    bitcoind::{BitcoindError, NodeReport, Severity},
+    descriptor,
+    i18n::Language,
+    logs::LogLevel,
+    noise::KeyFile,
+    participant,
+    ui::{
+        component::{badge, button, card, copy, form, separation, text},
+        theme::ThemePreference,
+    },
 };
 
-use crate::revaultd::config::Config;
+use crate::revaultd::{
+    config::Config,
+    daemon::{Api, API_VERSION},
+    RevaultDError,
+};
 
 pub trait SettingsBox {
     fn title(&self) -> &'static str;
     fn description(&self) -> &'static str;
-    fn body<'a>(&self, config: &Config) -> Column<'a, Message>;
-    fn display<'a>(&self, config: &Config) -> Container<'a, Message> {
+    fn body<'a>(&self, ctx: &Context, config: &Config) -> Column<'a, Message>;
+    fn display<'a>(&self, ctx: &Context, config: &Config) -> Container<'a, Message> {
         card::simple(Container::new(
             Column::new()
                 .push(
@@ -21,8 +55,8 @@ pub trait SettingsBox {
                                 Row::new()
                                     .push(
                                         Column::new()
-                                            .push(text::bold(text::simple(self.title())))
-                                            .push(text::small(self.description())),
+                                            .push(text::bold(text::simple(ctx.tr(self.title()))))
+                                            .push(text::small(ctx.tr(self.description()))),
                                     )
                                     .spacing(20),
                             )
@@ -32,7 +66,7 @@ pub trait SettingsBox {
                         .align_items(Align::Center),
                 )
                 .push(separation().width(Length::Fill))
-                .push(self.body(config))
+                .push(self.body(ctx, config))
                 .spacing(20),
         ))
         .width(Length::Fill)
@@ -60,19 +94,15 @@ impl SettingsBox for GeneralBox {
         ""
     }
 
-    fn body<'a>(&self, config: &Config) -> Column<'a, Message> {
+    fn body<'a>(&self, ctx: &Context, config: &Config) -> Column<'a, Message> {
         let rows = vec![
             ("Coordinator host", config.coordinator_host.clone()),
-            (
-                "Coordinator noise key",
-                config.coordinator_noise_key.clone(),
-            ),
             (
                 "Coordinator poll",
                 config
                     .coordinator_poll_seconds
-                    .map(|p| format!("{} seconds", p))
-                    .unwrap_or_else(|| "Not set".to_string()),
+                    .map(|p| ctx.trf("{} seconds", &[&p]))
+                    .unwrap_or_else(|| ctx.tr("Not set").to_string()),
             ),
             (
                 "Data dir",
@@ -80,28 +110,28 @@ impl SettingsBox for GeneralBox {
                     .data_dir
                     .clone()
                     .map(|d| format!("{:?}", d))
-                    .unwrap_or_else(|| "Not set".to_string()),
+                    .unwrap_or_else(|| ctx.tr("Not set").to_string()),
             ),
             (
                 "Daemon",
                 config
                     .daemon
                     .map(|d| d.to_string())
-                    .unwrap_or_else(|| "Not set".to_string()),
+                    .unwrap_or_else(|| ctx.tr("Not set").to_string()),
             ),
             (
                 "Log level",
                 config
                     .log_level
                     .clone()
-                    .unwrap_or_else(|| "Not set".to_string()),
+                    .unwrap_or_else(|| ctx.tr("Not set").to_string()),
             ),
         ];
         let mut column = Column::new();
         for (k, v) in rows {
             column = column.push(
                 Row::new()
-                    .push(Container::new(text::small(k)).width(Length::Fill))
+                    .push(Container::new(text::small(ctx.tr(k))).width(Length::Fill))
                     .push(text::small(&v)),
             );
         }
@@ -121,7 +151,7 @@ impl SettingsBox for BitcoindBox {
         ""
     }
 
-    fn body<'a>(&self, config: &Config) -> Column<'a, Message> {
+    fn body<'a>(&self, ctx: &Context, config: &Config) -> Column<'a, Message> {
         let config = &config.bitcoind_config;
         let rows = vec![
             ("Network", config.network.to_string()),
@@ -134,15 +164,15 @@ impl SettingsBox for BitcoindBox {
                 "Poll interval",
                 config
                     .poll_interval_secs
-                    .map(|p| format!("{} seconds", p))
-                    .unwrap_or_else(|| "Not set".to_string()),
+                    .map(|p| ctx.trf("{} seconds", &[&p]))
+                    .unwrap_or_else(|| ctx.tr("Not set").to_string()),
             ),
         ];
         let mut column = Column::new();
         for (k, v) in rows {
             column = column.push(
                 Row::new()
-                    .push(Container::new(text::small(k)).width(Length::Fill))
+                    .push(Container::new(text::small(ctx.tr(k))).width(Length::Fill))
                     .push(text::small(&v)),
             );
         }
@@ -162,30 +192,47 @@ impl SettingsBox for ScriptsBox {
         ""
     }
 
-    fn body<'a>(&self, config: &Config) -> Column<'a, Message> {
+    fn body<'a>(&self, ctx: &Context, config: &Config) -> Column<'a, Message> {
         Column::new()
-            .push(
-                Column::new()
-                    .spacing(5)
-                    .push(text::bold(text::small("Deposit descriptor")))
-                    .push(text::small(&config.scripts_config.deposit_descriptor)),
-            )
-            .push(
-                Column::new()
-                    .spacing(5)
-                    .push(text::bold(text::small("Unvault descriptor")))
-                    .push(text::small(&config.scripts_config.unvault_descriptor)),
-            )
-            .push(
-                Column::new()
-                    .spacing(5)
-                    .push(text::bold(text::small("CPFP descriptor")))
-                    .push(text::small(&config.scripts_config.cpfp_descriptor)),
-            )
+            .push(badge::participants(
+                ctx.converter.language,
+                &participant::unvault_participants(
+                    &config.scripts_config.deposit_descriptor,
+                    &config.scripts_config.unvault_descriptor,
+                ),
+            ))
+            .push(separation().width(Length::Fill))
+            .push(descriptor_column(
+                ctx,
+                "Deposit descriptor",
+                &config.scripts_config.deposit_descriptor,
+            ))
+            .push(descriptor_column(
+                ctx,
+                "Unvault descriptor",
+                &config.scripts_config.unvault_descriptor,
+            ))
+            .push(descriptor_column(
+                ctx,
+                "CPFP descriptor",
+                &config.scripts_config.cpfp_descriptor,
+            ))
             .spacing(10)
     }
 }
 
+fn descriptor_column<'a>(ctx: &Context, name: &str, desc: &str) -> Column<'a, Message> {
+    let checksum = match descriptor::verify(desc) {
+        Ok(checksum) => text::small(&ctx.trf("Checksum: {}", &[&checksum])),
+        Err(e) => text::danger(text::small(&e.to_string())),
+    };
+    Column::new()
+        .spacing(5)
+        .push(text::bold(text::small(ctx.tr(name))))
+        .push(text::small(desc))
+        .push(checksum)
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct StakeholderBox {}
 
@@ -198,17 +245,21 @@ impl SettingsBox for StakeholderBox {
         "Stakeholder-specific parameters, such as the xpub, the emergency_address, the watchtowers"
     }
 
-    fn body<'a>(&self, config: &Config) -> Column<'a, Message> {
+    fn body<'a>(&self, ctx: &Context, config: &Config) -> Column<'a, Message> {
         let config = config.stakeholder_config.as_ref().unwrap();
         let rows = vec![
             ("xpub", config.xpub.to_string()),
             ("Emergency address", config.emergency_address.clone()),
         ];
-        let mut general_column = Column::new();
+        let mut general_column = Column::new().push(
+            Row::new()
+                .push(Container::new(text::small(ctx.tr("Fingerprint"))).width(Length::Fill))
+                .push(badge::participant(&config.xpub.fingerprint())),
+        );
         for (k, v) in rows {
             general_column = general_column.push(
                 Row::new()
-                    .push(Container::new(text::small(k)).width(Length::Fill))
+                    .push(Container::new(text::small(ctx.tr(k))).width(Length::Fill))
                     .push(text::small(&v)),
             );
         }
@@ -227,7 +278,9 @@ impl SettingsBox for StakeholderBox {
             .push(separation().width(Length::Fill))
             .push(
                 Column::new()
-                    .push(Container::new(text::bold(text::small("Watchtowers"))))
+                    .push(Container::new(text::bold(text::small(
+                        ctx.tr("Watchtowers"),
+                    ))))
                     .push(watchtowers_column)
                     .spacing(8),
             )
@@ -247,7 +300,7 @@ impl SettingsBox for ManagerBox {
         "Manager-specific parameters, such as the xpub and the cosigners"
     }
 
-    fn body<'a>(&self, config: &Config) -> Column<'a, Message> {
+    fn body<'a>(&self, ctx: &Context, config: &Config) -> Column<'a, Message> {
         let config = config.manager_config.as_ref().unwrap();
         let mut cosigners_column = Column::new();
         for c in &config.cosigners {
@@ -259,6 +312,11 @@ impl SettingsBox for ManagerBox {
         }
 
         Column::new()
+            .push(
+                Row::new()
+                    .push(Container::new(text::small(ctx.tr("Fingerprint"))).width(Length::Fill))
+                    .push(badge::participant(&config.xpub.fingerprint())),
+            )
             .push(
                 Row::new()
                     .push(Container::new(text::small("xpub")).width(Length::Fill))
@@ -267,10 +325,1379 @@ impl SettingsBox for ManagerBox {
             .push(separation().width(Length::Fill))
             .push(
                 Column::new()
-                    .push(Container::new(text::bold(text::small("Cosigners"))))
+                    .push(Container::new(text::bold(text::small(ctx.tr("Cosigners")))))
                     .push(cosigners_column)
                     .spacing(8),
             )
             .spacing(20)
     }
 }
+
+/// LauncherBox leaves the configuration for the launcher,
+/// where another configuration can be opened.
+#[derive(Debug)]
+pub struct LauncherBox {
+    open_button: iced::button::State,
+}
+
+impl LauncherBox {
+    pub fn new() -> Self {
+        LauncherBox {
+            open_button: iced::button::State::new(),
+        }
+    }
+
+    pub fn view<'a>(&'a mut self, ctx: &Context) -> Container<'a, Message> {
+        card::simple(Container::new(
+            Row::new()
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple(ctx.tr("Configurations"))))
+                        .push(text::small(
+                            ctx.tr("Open another configuration in this window, \
+                            the revaultd of this one keeps running"),
+                        ))
+                        .width(Length::Fill),
+                )
+                .push(
+                    button::transparent(
+                        &mut self.open_button,
+                        button::button_content(None, ctx.tr("Switch configuration")),
+                    )
+                    .on_press(Message::OpenLauncher),
+                )
+                .align_items(Align::Center),
+        ))
+        .width(Length::Fill)
+    }
+}
+
+/// Notice displayed when the daemon does not expose the RPC API the GUI is
+/// written for, None if it does.
+pub fn daemon_api_box<'a>(ctx: &Context, api: &Api) -> Option<Container<'a, Message>> {
+    let (title, notice) = match api {
+        Api::Current(_) => return None,
+        Api::Deprecated(version) => (
+            ctx.tr("Deprecated daemon API"),
+            ctx.trf(
+                "revaultd {} exposes a deprecated RPC API, the next GUI releases may drop \
+                 its support: upgrade revaultd to {} or later.",
+                &[version, &API_VERSION],
+            ),
+        ),
+        Api::Untested(version) => (
+            ctx.tr("Untested daemon API"),
+            ctx.trf(
+                "revaultd {} is newer than revaultd {} the GUI is written for, \
+                 its RPC API was not checked against the GUI.",
+                &[version, &API_VERSION],
+            ),
+        ),
+        Api::Unknown => (
+            ctx.tr("Untested daemon API"),
+            ctx.trf(
+                "The revaultd version could not be detected, the GUI is written for revaultd {}.",
+                &[&API_VERSION],
+            ),
+        ),
+    };
+    Some(card::alert_warning(Container::new(
+        Column::new()
+            .push(text::bold(text::simple(title)))
+            .push(text::small(&notice))
+            .spacing(10),
+    )))
+}
+
+/// NotificationsBox toggles the desktop notifications of each event.
+pub fn notifications_box<'a>(
+    ctx: &Context,
+    settings: &NotificationSettings,
+) -> Container<'a, Message> {
+    let mut body = Column::new().spacing(10);
+    for event in NotificationEvent::ALL {
+        body = body.push(
+            Column::new()
+                .push(Checkbox::new(
+                    settings.is_enabled(event),
+                    &event.to_string(),
+                    move |enabled| Message::Notification(event, enabled),
+                ))
+                .push(text::small(event.description()))
+                .spacing(5),
+        );
+    }
+    card::simple(Container::new(
+        Column::new()
+            .push(
+                Column::new()
+                    .push(text::bold(text::simple(ctx.tr("Desktop notifications"))))
+                    .push(text::small(ctx.tr(
+                        "The vaults are checked every 30 seconds while the GUI is running",
+                    ))),
+            )
+            .push(separation().width(Length::Fill))
+            .push(body)
+            .spacing(20),
+    ))
+    .width(Length::Fill)
+}
+
+pub fn screenshot_box<'a>(ctx: &Context, enabled: bool) -> Container<'a, Message> {
+    card::simple(Container::new(
+        Column::new()
+            .push(
+                Column::new()
+                    .push(text::bold(text::simple(ctx.tr("Screenshot mode"))))
+                    .push(text::small(ctx.tr(
+                        "The transaction ids, addresses and keys are displayed as placeholders \
+                        until the GUI is closed, the copy buttons still copy the real values",
+                    ))),
+            )
+            .push(separation().width(Length::Fill))
+            .push(Checkbox::new(
+                enabled,
+                ctx.tr("Enabled"),
+                Message::ScreenshotMode,
+            ))
+            .spacing(20),
+    ))
+    .width(Length::Fill)
+}
+
+pub fn developer_console_box<'a>(ctx: &Context, enabled: bool) -> Container<'a, Message> {
+    card::simple(Container::new(
+        Column::new()
+            .push(
+                Column::new()
+                    .push(text::bold(text::simple(ctx.tr("Developer console"))))
+                    .push(text::small(ctx.tr(
+                        "Displays a console in the sidebar to send any JSON-RPC call to \
+                        revaultd and read its raw response, for debugging purposes",
+                    ))),
+            )
+            .push(separation().width(Length::Fill))
+            .push(Checkbox::new(
+                enabled,
+                ctx.tr("Enabled"),
+                Message::DeveloperConsole,
+            ))
+            .spacing(20),
+    ))
+    .width(Length::Fill)
+}
+
+/// metrics_box displays the local metrics if the user opted in,
+/// and the privacy stance of the feature.
+pub fn metrics_box<'a>(ctx: &Context, metrics: Option<&Counters>) -> Container<'a, Message> {
+    let mut body = Column::new().spacing(10).push(text::small(ctx.tr(
+        "Metrics are disabled by default. When enabled, they only count the features used \
+        and the errors met, in a file of the revaultd data directory. They are never uploaded \
+        or shared.",
+    )));
+
+    match metrics {
+        None => {
+            body = body.push(text::small(ctx.tr(
+                "Metrics are disabled. Set 'local_metrics = true' in the GUI configuration file \
+                to enable them.",
+            )));
+        }
+        Some(counters) => {
+            body = body
+                .push(text::bold(text::small(ctx.tr("Features used"))))
+                .push(counters_rows(ctx, &counters.usage))
+                .push(text::bold(text::small(ctx.tr("Errors"))))
+                .push(counters_rows(ctx, &counters.errors));
+        }
+    }
+
+    card::simple(Container::new(
+        Column::new()
+            .push(
+                Column::new()
+                    .push(text::bold(text::simple(ctx.tr("Local metrics"))))
+                    .push(text::small(
+                        ctx.tr("Usage statistics kept on this computer only"),
+                    )),
+            )
+            .push(separation().width(Length::Fill))
+            .push(body)
+            .spacing(20),
+    ))
+    .width(Length::Fill)
+}
+
+fn counters_rows<'a>(
+    ctx: &Context,
+    counters: &std::collections::BTreeMap<String, u64>,
+) -> Column<'a, Message> {
+    if counters.is_empty() {
+        return Column::new().push(text::small(ctx.tr("Nothing recorded yet")));
+    }
+    counters
+        .iter()
+        .fold(Column::new().spacing(5), |col, (name, count)| {
+            col.push(
+                Row::new()
+                    .push(Container::new(text::small(name)).width(Length::Fill))
+                    .push(text::small(&count.to_string())),
+            )
+        })
+}
+
+/// BackupBox displays the backups of the GUI local data
+/// and allows to backup now or to restore a backup.
+#[derive(Debug)]
+pub struct BackupBox {
+    backup_button: iced::button::State,
+    restore_buttons: Vec<iced::button::State>,
+}
+
+impl BackupBox {
+    pub fn new() -> Self {
+        BackupBox {
+            backup_button: iced::button::State::new(),
+            restore_buttons: Vec::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        backups: Option<(&Path, &[Backup])>,
+        restored: Option<i64>,
+    ) -> Container<'a, Message> {
+        let mut body = Column::new().spacing(10).push(text::small(ctx.tr(
+            "Labels and emergency key checks are saved on a regular basis to the backup \
+            directory, only the last backups are kept.",
+        )));
+
+        match backups {
+            None => {
+                body = body.push(text::small(ctx.tr(
+                    "Backups are disabled. Set 'backup_dir' in the GUI configuration file \
+                    to enable them.",
+                )));
+            }
+            Some((dir, list)) => {
+                if self.restore_buttons.len() != list.len() {
+                    self.restore_buttons =
+                        list.iter().map(|_| iced::button::State::new()).collect();
+                }
+                body = body.push(
+                    Row::new()
+                        .push(
+                            Container::new(text::small(
+                                &ctx.trf("Backup directory: {}", &[&dir.display()]),
+                            ))
+                            .width(Length::Fill),
+                        )
+                        .push(
+                            button::primary(
+                                &mut self.backup_button,
+                                button::button_content(None, ctx.tr("Backup now")),
+                            )
+                            .on_press(Message::Backup(BackupMessage::BackupNow)),
+                        )
+                        .align_items(Align::Center),
+                );
+                if let Some(date) = restored {
+                    body = body.push(text::success(text::small(&ctx.trf(
+                        "Backup of {} restored, the previous data was saved in a new backup.",
+                        &[&NaiveDateTime::from_timestamp(date, 0)],
+                    ))));
+                }
+                if list.is_empty() {
+                    body = body.push(text::small(ctx.tr("No backup yet")));
+                }
+                for (backup, restore_button) in list.iter().zip(self.restore_buttons.iter_mut()) {
+                    body = body.push(
+                        Row::new()
+                            .push(
+                                Container::new(text::small(
+                                    &NaiveDateTime::from_timestamp(backup.date, 0).to_string(),
+                                ))
+                                .width(Length::Fill),
+                            )
+                            .push(
+                                button::transparent(
+                                    restore_button,
+                                    button::button_content(None, ctx.tr("Restore")),
+                                )
+                                .on_press(confirm::restore(backup.date).ask()),
+                            )
+                            .align_items(Align::Center),
+                    );
+                }
+            }
+        }
+
+        card::simple(Container::new(
+            Column::new()
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple(ctx.tr("Backups"))))
+                        .push(text::small(ctx.tr("Backups of the data kept by the GUI"))),
+                )
+                .push(separation().width(Length::Fill))
+                .push(body)
+                .spacing(20),
+        ))
+        .width(Length::Fill)
+    }
+}
+
+/// TransferBox exports the labels and the notes to a portable file
+/// and imports the file of another member of the team.
+#[derive(Debug)]
+pub struct TransferBox {
+    export_button: iced::button::State,
+    path_input: text_input::State,
+    resolution_list: pick_list::State<Resolution>,
+    import_button: iced::button::State,
+}
+
+impl TransferBox {
+    pub fn new() -> Self {
+        TransferBox {
+            export_button: iced::button::State::new(),
+            path_input: text_input::State::new(),
+            resolution_list: pick_list::State::default(),
+            import_button: iced::button::State::new(),
+        }
+    }
+
+    pub fn view<'a>(&'a mut self, ctx: &Context, transfer: &Transfer) -> Container<'a, Message> {
+        let mut body = Column::new().spacing(10).push(
+            Row::new()
+                .push(
+                    Container::new(text::small(ctx.tr(
+                        "Writes the labels and the notes of the vaults and the address book \
+                        to a json file of the revaultd data directory.",
+                    )))
+                    .width(Length::Fill),
+                )
+                .push(
+                    button::primary(
+                        &mut self.export_button,
+                        button::button_content(None, ctx.tr("Export")),
+                    )
+                    .on_press(Message::Metadata(MetadataMessage::Export)),
+                )
+                .align_items(Align::Center),
+        );
+        if let Some(path) = &transfer.exported {
+            body = body.push(text::success(text::small(
+                &ctx.trf("Exported to {}", &[&path.display()]),
+            )));
+        }
+
+        body = body
+            .push(separation().width(Length::Fill))
+            .push(
+                TextInput::new(
+                    &mut self.path_input,
+                    ctx.tr("Path of the file to import"),
+                    &transfer.path,
+                    |path| Message::Metadata(MetadataMessage::PathEdited(path)),
+                )
+                .size(15)
+                .padding(10),
+            )
+            .push(
+                Row::new()
+                    .push(
+                        Container::new(text::small(
+                            ctx.tr("For the vaults and the addresses with other local data:"),
+                        ))
+                        .width(Length::Fill),
+                    )
+                    .push(
+                        PickList::new(
+                            &mut self.resolution_list,
+                            &Resolution::ALL[..],
+                            Some(transfer.resolution),
+                            |resolution| {
+                                Message::Metadata(MetadataMessage::ResolutionSelected(resolution))
+                            },
+                        )
+                        .text_size(15),
+                    )
+                    .push(
+                        button::primary(
+                            &mut self.import_button,
+                            button::button_content(None, ctx.tr("Import")),
+                        )
+                        .on_press(Message::Metadata(MetadataMessage::Import)),
+                    )
+                    .spacing(10)
+                    .align_items(Align::Center),
+            );
+        if let Some(report) = &transfer.imported {
+            body = body.push(text::success(text::small(&ctx.trf(
+                "{} labels, {} notes and {} addresses imported, {} vaults or addresses had \
+                other local data",
+                &[
+                    &report.labels,
+                    &report.notes,
+                    &report.addresses,
+                    &report.conflicts,
+                ],
+            ))));
+        }
+
+        card::simple(Container::new(
+            Column::new()
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple(ctx.tr("Labels and notes"))))
+                        .push(text::small(ctx.tr(
+                            "Share the labels, the notes and the address book with the team",
+                        ))),
+                )
+                .push(separation().width(Length::Fill))
+                .push(body)
+                .spacing(20),
+        ))
+        .width(Length::Fill)
+    }
+}
+
+#[derive(Debug)]
+pub struct DescriptorCheckBox {
+    input: iced::text_input::State,
+}
+
+impl DescriptorCheckBox {
+    pub fn new() -> Self {
+        DescriptorCheckBox {
+            input: iced::text_input::State::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        config: &Config,
+        pasted: &str,
+    ) -> Container<'a, Message> {
+        let mut body = Column::new().spacing(10).push(
+            form::Form::new(
+                &mut self.input,
+                ctx.tr("Paste a descriptor"),
+                &form::Value {
+                    value: pasted.to_string(),
+                    valid: true,
+                },
+                Message::DescriptorCheckEdited,
+            )
+            .padding(10)
+            .size(15)
+            .render(),
+        );
+
+        if !pasted.trim().is_empty() {
+            let configured = [
+                ("deposit", &config.scripts_config.deposit_descriptor),
+                ("unvault", &config.scripts_config.unvault_descriptor),
+                ("CPFP", &config.scripts_config.cpfp_descriptor),
+            ];
+            body = body.push(match descriptor::verify(pasted) {
+                Err(e) => text::danger(text::small(&e.to_string())),
+                Ok(checksum) => match configured
+                    .iter()
+                    .find(|(_, desc)| descriptor::checksum(desc).as_ref() == Some(&checksum))
+                {
+                    Some((name, _)) => text::success(text::small(&ctx.trf(
+                        "Checksum {} matches the configured {} descriptor",
+                        &[&checksum, &ctx.tr(name)],
+                    ))),
+                    None => text::danger(text::small(&ctx.trf(
+                        "Checksum {} does not match any configured descriptor",
+                        &[&checksum],
+                    ))),
+                },
+            });
+        }
+
+        card::simple(Container::new(
+            Column::new()
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple(ctx.tr("Descriptor check"))))
+                        .push(text::small(
+                            ctx.tr("Check that a descriptor copied from another machine \
+                            is the same as the configured one"),
+                        )),
+                )
+                .push(separation().width(Length::Fill))
+                .push(body)
+                .spacing(20),
+        ))
+        .width(Length::Fill)
+    }
+}
+
+/// NoiseKeyBox displays the noise key file of the daemon, its public key
+/// and whether it is only readable by its owner, and the coordinator key.
+#[derive(Debug)]
+pub struct NoiseKeyBox {
+    fix_button: iced::button::State,
+    create_button: iced::button::State,
+    copy_public_key_button: iced::button::State,
+    copy_coordinator_key_button: iced::button::State,
+}
+
+impl NoiseKeyBox {
+    pub fn new() -> Self {
+        NoiseKeyBox {
+            fix_button: iced::button::State::new(),
+            create_button: iced::button::State::new(),
+            copy_public_key_button: iced::button::State::new(),
+            copy_coordinator_key_button: iced::button::State::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        config: &Config,
+        key_file: Option<&KeyFile>,
+    ) -> Container<'a, Message> {
+        let mut body = Column::new().spacing(10).push(
+            Column::new()
+                .push(text::small(ctx.tr("Coordinator public key:")))
+                .push(copy::copyable(
+                    &mut self.copy_coordinator_key_button,
+                    text::small(&config.coordinator_noise_key),
+                    Message::Clipboard(config.coordinator_noise_key.clone()),
+                )),
+        );
+        match key_file {
+            None => {
+                body = body.push(text::danger(text::small(
+                    ctx.tr("The datadir of the daemon could not be found"),
+                )));
+            }
+            Some(key_file) => {
+                body = body.push(text::small(
+                    &ctx.trf("Key file: {}", &[&key_file.path.display()]),
+                ));
+                if !key_file.exists() {
+                    body = body.push(
+                        Row::new()
+                            .push(
+                                Container::new(text::danger(text::small(
+                                    ctx.tr("The key file is missing, the daemon cannot \
+                                    authenticate to the coordinator"),
+                                )))
+                                .width(Length::Fill),
+                            )
+                            .push(
+                                button::primary(
+                                    &mut self.create_button,
+                                    button::button_content(None, ctx.tr("Create a new key")),
+                                )
+                                .on_press(Message::NoiseKey(NoiseKeyMessage::Create)),
+                            )
+                            .spacing(10)
+                            .align_items(Align::Center),
+                    );
+                } else {
+                    body = body.push(match &key_file.public_key {
+                        Ok(public_key) => Column::new()
+                            .push(text::small(ctx.tr("Public key:")))
+                            .push(copy::copyable(
+                                &mut self.copy_public_key_button,
+                                text::small(public_key),
+                                Message::Clipboard(public_key.clone()),
+                            )),
+                        Err(e) => Column::new().push(text::danger(text::small(&e.to_string()))),
+                    });
+                    body = body.push(match &key_file.permissions {
+                        Ok(()) => Row::new().push(text::success(text::small(
+                            ctx.tr("The key file is only readable by its owner"),
+                        ))),
+                        Err(e) => Row::new()
+                            .push(
+                                Container::new(text::danger(text::small(&e.to_string())))
+                                    .width(Length::Fill),
+                            )
+                            .push(
+                                button::primary(
+                                    &mut self.fix_button,
+                                    button::button_content(None, ctx.tr("Fix permissions")),
+                                )
+                                .on_press(Message::NoiseKey(NoiseKeyMessage::FixPermissions)),
+                            )
+                            .spacing(10)
+                            .align_items(Align::Center),
+                    });
+                }
+                body = body.push(text::small(ctx.tr(
+                    "The public key must be known by the coordinator and the servers, \
+                    a new key requires a restart of the daemon.",
+                )));
+            }
+        }
+
+        card::simple(Container::new(
+            Column::new()
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple(ctx.tr("Noise key"))))
+                        .push(text::small(ctx.tr(
+                            "Static key authenticating the daemon to the coordinator",
+                        ))),
+                )
+                .push(separation().width(Length::Fill))
+                .push(body)
+                .spacing(20),
+        ))
+        .width(Length::Fill)
+    }
+}
+
+/// LanguageBox allows to change the language of the interface.
+#[derive(Debug)]
+pub struct LanguageBox {
+    language_list: pick_list::State<Language>,
+}
+
+impl LanguageBox {
+    pub fn new() -> Self {
+        LanguageBox {
+            language_list: pick_list::State::default(),
+        }
+    }
+
+    pub fn view(&mut self, language: Language) -> Container<Message> {
+        card::simple(Container::new(
+            Column::new()
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple(language.tr("Language"))))
+                        .push(text::small(
+                            language.tr("The language is kept until the GUI is closed, \
+                            set language in the GUI configuration file to keep it"),
+                        )),
+                )
+                .push(separation().width(Length::Fill))
+                .push(
+                    PickList::new(
+                        &mut self.language_list,
+                        &Language::ALL[..],
+                        Some(language),
+                        Message::Language,
+                    )
+                    .text_size(15),
+                )
+                .spacing(20),
+        ))
+        .width(Length::Fill)
+    }
+}
+
+/// ThemeBox allows to change the theme of the interface.
+#[derive(Debug)]
+pub struct ThemeBox {
+    theme_list: pick_list::State<ThemePreference>,
+}
+
+impl ThemeBox {
+    pub fn new() -> Self {
+        ThemeBox {
+            theme_list: pick_list::State::default(),
+        }
+    }
+
+    pub fn view(&mut self, preference: ThemePreference, language: Language) -> Container<Message> {
+        card::simple(Container::new(
+            Column::new()
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple(language.tr("Theme"))))
+                        .push(text::small(language.tr(
+                            "System follows the light or dark preference of the operating system",
+                        ))),
+                )
+                .push(separation().width(Length::Fill))
+                .push(
+                    PickList::new(
+                        &mut self.theme_list,
+                        &ThemePreference::ALL[..],
+                        Some(preference),
+                        Message::Theme,
+                    )
+                    .text_size(15),
+                )
+                .spacing(20),
+        ))
+        .width(Length::Fill)
+    }
+}
+
+/// LogsBox allows to change the level of the logs and to open their directory.
+#[derive(Debug)]
+pub struct LogsBox {
+    level_list: pick_list::State<LogLevel>,
+    open_button: iced::button::State,
+}
+
+impl LogsBox {
+    pub fn new() -> Self {
+        LogsBox {
+            level_list: pick_list::State::default(),
+            open_button: iced::button::State::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        level: LogLevel,
+        dir: Option<&Path>,
+        language: Language,
+    ) -> Container<'a, Message> {
+        let mut body = Column::new().spacing(10).push(
+            Row::new()
+                .push(Container::new(text::small(language.tr("Level"))).width(Length::Fill))
+                .push(
+                    PickList::new(
+                        &mut self.level_list,
+                        &LogLevel::ALL[..],
+                        Some(level),
+                        |level| Message::Logs(LogsMessage::LevelSelected(level)),
+                    )
+                    .text_size(15),
+                )
+                .align_items(Align::Center),
+        );
+
+        body = match dir {
+            Some(dir) => body.push(
+                Row::new()
+                    .push(
+                        Container::new(text::small(&ctx.trf("Directory: {}", &[&dir.display()])))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        button::transparent(
+                            &mut self.open_button,
+                            button::button_content(None, language.tr("Open")),
+                        )
+                        .on_press(Message::Logs(LogsMessage::OpenDir)),
+                    )
+                    .align_items(Align::Center),
+            ),
+            None => body.push(text::small(
+                ctx.tr("The logs are only written to the standard output"),
+            )),
+        };
+
+        card::simple(Container::new(
+            Column::new()
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple(language.tr("Logs"))))
+                        .push(text::small(
+                            ctx.tr("The level is kept until the GUI is closed, \
+                            set log_level in the GUI configuration file to keep it"),
+                        )),
+                )
+                .push(separation().width(Length::Fill))
+                .push(body)
+                .spacing(20),
+        ))
+        .width(Length::Fill)
+    }
+}
+
+/// ContactsBox edits the operators to contact when the coordinator
+/// or a watchtower is unreachable.
+#[derive(Debug)]
+pub struct ContactsBox {
+    inputs: Vec<text_input::State>,
+    save_button: iced::button::State,
+}
+
+impl ContactsBox {
+    pub fn new() -> Self {
+        ContactsBox {
+            inputs: Vec::new(),
+            save_button: iced::button::State::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        config: &Config,
+        contacts: &Contacts,
+        saved: bool,
+    ) -> Container<'a, Message> {
+        let mut servers = vec![("Coordinator", config.coordinator_host.clone())];
+        if let Some(stakeholder) = &config.stakeholder_config {
+            for watchtower in &stakeholder.watchtowers {
+                servers.push(("Watchtower", watchtower.host.clone()));
+            }
+        }
+        let count = servers.len() * ContactField::ALL.len();
+        if self.inputs.len() != count {
+            self.inputs = (0..count).map(|_| text_input::State::new()).collect();
+        }
+
+        let mut body = Column::new().spacing(10);
+        let mut inputs = self.inputs.iter_mut();
+        for (kind, host) in servers {
+            let contact = contacts.get(&host).cloned().unwrap_or_default();
+            let mut row = Row::new().spacing(10);
+            for field in ContactField::ALL {
+                let input = inputs.next().expect("one input per field of every server");
+                let host = host.clone();
+                row = row.push(
+                    TextInput::new(
+                        input,
+                        ctx.tr(field.label()),
+                        contact.get(field),
+                        move |value| {
+                            Message::Contact(ContactMessage::Edited(host.clone(), field, value))
+                        },
+                    )
+                    .size(15)
+                    .padding(10),
+                );
+            }
+            body = body
+                .push(text::small(&format!("{}: {}", ctx.tr(kind), host)))
+                .push(row);
+        }
+
+        let mut footer = Row::new().push(Column::new().width(Length::Fill));
+        if saved {
+            footer = footer.push(text::success(text::small(ctx.tr("Saved"))));
+        }
+        body = body.push(
+            footer
+                .push(
+                    button::primary(
+                        &mut self.save_button,
+                        button::button_content(None, ctx.tr("Save")),
+                    )
+                    .on_press(Message::Contact(ContactMessage::Save)),
+                )
+                .spacing(10)
+                .align_items(Align::Center),
+        );
+
+        card::simple(Container::new(
+            Column::new()
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple(ctx.tr("Operator contacts"))))
+                        .push(text::small(ctx.tr(
+                            "Displayed on the network panel when the server is unreachable",
+                        ))),
+                )
+                .push(separation().width(Length::Fill))
+                .push(body)
+                .spacing(20),
+        ))
+        .width(Length::Fill)
+    }
+}
+
+/// ReportBox configures the wallet report mailed on a schedule
+/// through the local SMTP relay.
+#[derive(Debug)]
+pub struct ReportBox {
+    inputs: [text_input::State; 4],
+    save_button: iced::button::State,
+    send_button: iced::button::State,
+}
+
+impl ReportBox {
+    pub fn new() -> Self {
+        ReportBox {
+            inputs: Default::default(),
+            save_button: iced::button::State::new(),
+            send_button: iced::button::State::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        settings: &ReportSettings,
+        saved: bool,
+        sending: bool,
+    ) -> Container<'a, Message> {
+        let mut body = Column::new().spacing(10).push(Checkbox::new(
+            settings.enabled,
+            ctx.tr("Send the report on a schedule"),
+            |enabled| Message::Report(ReportMessage::Enable(enabled)),
+        ));
+        for (input, field) in self.inputs.iter_mut().zip(ReportField::ALL) {
+            body = body.push(
+                Column::new()
+                    .push(text::small(field.label()))
+                    .push(
+                        TextInput::new(input, field.label(), &settings.get(field), move |value| {
+                            Message::Report(ReportMessage::Edited(field, value))
+                        })
+                        .size(15)
+                        .padding(10),
+                    )
+                    .spacing(5),
+            );
+        }
+        if let Some(last) = settings.last_sent {
+            body = body.push(text::small(&ctx.trf(
+                "Last report sent on {}",
+                &[&NaiveDateTime::from_timestamp(last, 0)],
+            )));
+        }
+
+        let missing = settings.missing();
+        let mut footer = Row::new().push(Column::new().width(Length::Fill));
+        if sending {
+            footer = footer.push(text::small(ctx.tr("Sending...")));
+        } else if saved {
+            footer = footer.push(text::success(text::small(ctx.tr("Saved"))));
+        }
+        let mut send_button = button::transparent(
+            &mut self.send_button,
+            button::button_content(None, ctx.tr("Send now")),
+        );
+        if missing.is_empty() && !sending {
+            send_button = send_button.on_press(Message::Report(ReportMessage::SendNow));
+        }
+        body = body.push(
+            footer
+                .push(send_button)
+                .push(
+                    button::primary(
+                        &mut self.save_button,
+                        button::button_content(None, ctx.tr("Save")),
+                    )
+                    .on_press(Message::Report(ReportMessage::Save)),
+                )
+                .spacing(10)
+                .align_items(Align::Center),
+        );
+        if !missing.is_empty() {
+            body = body.push(text::small(
+                &ctx.trf(
+                    "Missing: {}",
+                    &[&missing
+                        .iter()
+                        .map(|field| field.label())
+                        .collect::<Vec<&str>>()
+                        .join(", ")],
+                ),
+            ));
+        }
+
+        card::simple(Container::new(
+            Column::new()
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple(ctx.tr("Wallet report"))))
+                        .push(text::small(
+                            ctx.tr("Balances and activity mailed through a SMTP relay \
+                            of this machine, without encryption or authentication"),
+                        )),
+                )
+                .push(separation().width(Length::Fill))
+                .push(body)
+                .spacing(20),
+        ))
+        .width(Length::Fill)
+    }
+}
+
+/// AddressBookBox manages the spend destinations suggested to the manager
+/// when entering the recipients of a spend transaction.
+#[derive(Debug)]
+pub struct AddressBookBox {
+    label_input: text_input::State,
+    address_input: text_input::State,
+    add_button: iced::button::State,
+    remove_buttons: Vec<iced::button::State>,
+}
+
+impl AddressBookBox {
+    pub fn new() -> Self {
+        AddressBookBox {
+            label_input: text_input::State::new(),
+            address_input: text_input::State::new(),
+            add_button: iced::button::State::new(),
+            remove_buttons: Vec::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        book: &AddressBook,
+        (label, address): (&str, &str),
+    ) -> Container<'a, Message> {
+        let entries = book.entries();
+        if self.remove_buttons.len() != entries.len() {
+            self.remove_buttons = entries.iter().map(|_| iced::button::State::new()).collect();
+        }
+
+        let mut body = Column::new().spacing(10);
+        if entries.is_empty() {
+            body = body.push(text::small(ctx.tr("No address yet")));
+        }
+        for ((label, address), button_state) in
+            entries.into_iter().zip(self.remove_buttons.iter_mut())
+        {
+            body = body.push(
+                Row::new()
+                    .push(Container::new(text::bold(text::small(label))).width(Length::Units(200)))
+                    .push(Container::new(text::small(address)).width(Length::Fill))
+                    .push(
+                        button::transparent(
+                            button_state,
+                            button::button_content(None, ctx.tr("Remove")),
+                        )
+                        .on_press(Message::AddressBook(
+                            AddressBookMessage::Remove(address.clone()),
+                        )),
+                    )
+                    .spacing(10)
+                    .align_items(Align::Center),
+            );
+        }
+
+        let mut add_button = button::primary(
+            &mut self.add_button,
+            button::button_content(None, ctx.tr("Add")),
+        );
+        if !label.trim().is_empty() && !address.trim().is_empty() {
+            add_button = add_button.on_press(Message::AddressBook(AddressBookMessage::Add));
+        }
+        body = body.push(
+            Row::new()
+                .push(
+                    TextInput::new(&mut self.label_input, ctx.tr("Label"), label, |value| {
+                        Message::AddressBook(AddressBookMessage::LabelEdited(value))
+                    })
+                    .size(15)
+                    .padding(10)
+                    .width(Length::Units(200)),
+                )
+                .push(
+                    TextInput::new(
+                        &mut self.address_input,
+                        ctx.tr("Address"),
+                        address,
+                        |value| Message::AddressBook(AddressBookMessage::AddressEdited(value)),
+                    )
+                    .size(15)
+                    .padding(10)
+                    .width(Length::Fill),
+                )
+                .push(add_button)
+                .spacing(10)
+                .align_items(Align::Center),
+        );
+
+        card::simple(Container::new(
+            Column::new()
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple(ctx.tr("Address book"))))
+                        .push(text::small(ctx.tr(
+                            "Suggested when entering the recipients of a spend transaction",
+                        ))),
+                )
+                .push(separation().width(Length::Fill))
+                .push(body)
+                .spacing(20),
+        ))
+        .width(Length::Fill)
+    }
+}
+
+/// ConfigEditBox edits the connection settings of the revaultd
+/// configuration file, the daemon is restarted once the file is written.
+#[derive(Debug)]
+pub struct ConfigEditBox {
+    edit_button: iced::button::State,
+    cancel_button: iced::button::State,
+    save_button: iced::button::State,
+    test_button: iced::button::State,
+    inputs: Vec<text_input::State>,
+}
+
+impl ConfigEditBox {
+    pub fn new() -> Self {
+        ConfigEditBox {
+            edit_button: iced::button::State::new(),
+            cancel_button: iced::button::State::new(),
+            save_button: iced::button::State::new(),
+            test_button: iced::button::State::new(),
+            inputs: ConfigField::ALL
+                .iter()
+                .map(|_| text_input::State::new())
+                .collect(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        edit: Option<&ConfigEdit>,
+        (testing, bitcoind_test): (bool, Option<&Result<NodeReport, BitcoindError>>),
+        restarting: bool,
+    ) -> Container<'a, Message> {
+        let test_button = if testing {
+            button::primary_disable(
+                &mut self.test_button,
+                button::button_content(None, ctx.tr("Testing...")),
+            )
+        } else {
+            button::transparent(
+                &mut self.test_button,
+                button::button_content(None, ctx.tr("Test bitcoind connection")),
+            )
+            .on_press(Message::ConfigEdit(ConfigEditMessage::TestBitcoind))
+        };
+        let body = if restarting {
+            Column::new().push(text::small(ctx.tr("Restarting revaultd...")))
+        } else if let Some(edit) = edit {
+            let mut body = Column::new().spacing(10);
+            for ((field, value), input) in edit.fields.iter().zip(self.inputs.iter_mut()) {
+                let field = *field;
+                body = body.push(
+                    Column::new()
+                        .push(text::small(field.label()))
+                        .push(
+                            form::Form::new(input, field.label(), value, move |value| {
+                                Message::ConfigEdit(ConfigEditMessage::FieldEdited(field, value))
+                            })
+                            .warning(field.warning())
+                            .size(15)
+                            .padding(10)
+                            .render(),
+                        )
+                        .spacing(5),
+                );
+            }
+            body.push(
+                Row::new()
+                    .push(Column::new().width(Length::Fill))
+                    .push(test_button)
+                    .push(
+                        button::transparent(
+                            &mut self.cancel_button,
+                            button::button_content(None, ctx.tr("Cancel")),
+                        )
+                        .on_press(Message::ConfigEdit(ConfigEditMessage::Cancel)),
+                    )
+                    .push(
+                        button::important(
+                            &mut self.save_button,
+                            button::button_content(None, ctx.tr("Save and restart")),
+                        )
+                        .on_press(confirm::config_overwrite().ask()),
+                    )
+                    .spacing(10)
+                    .align_items(Align::Center),
+            )
+        } else {
+            Column::new().push(
+                Row::new()
+                    .push(
+                        Container::new(text::small(
+                            ctx.tr("Bitcoind and coordinator addresses, keys and poll intervals"),
+                        ))
+                        .width(Length::Fill),
+                    )
+                    .push(test_button)
+                    .push(
+                        button::primary(
+                            &mut self.edit_button,
+                            button::button_content(None, ctx.tr("Edit")),
+                        )
+                        .on_press(Message::ConfigEdit(ConfigEditMessage::Edit)),
+                    )
+                    .spacing(10)
+                    .align_items(Align::Center),
+            )
+        };
+        let body = match bitcoind_test {
+            Some(test) if !restarting => body.push(bitcoind_test_result(ctx, test)),
+            _ => body,
+        };
+
+        card::simple(Container::new(
+            Column::new()
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple(ctx.tr("Connection settings"))))
+                        .push(text::small(
+                            ctx.tr("The revaultd configuration file is written, \
+                            the previous one is kept with the .bak extension"),
+                        )),
+                )
+                .push(separation().width(Length::Fill))
+                .push(body)
+                .spacing(20),
+        ))
+        .width(Length::Fill)
+    }
+}
+
+/// Displays the error of the connection test of bitcoind with its guidance,
+/// or the checks of the node.
+fn bitcoind_test_result<'a>(
+    ctx: &Context,
+    test: &Result<NodeReport, BitcoindError>,
+) -> Container<'a, Message> {
+    match test {
+        Err(e) => {
+            let mut col = Column::new().push(text::danger(text::small(&e.to_string())));
+            if let Some(guidance) = e.guidance() {
+                col = col.push(text::small(ctx.tr(guidance)));
+            }
+            Container::new(col.spacing(5))
+        }
+        Ok(report) => Container::new(
+            Column::with_children(
+                report
+                    .diagnostics
+                    .iter()
+                    .map(|d| match d.severity {
+                        Severity::Passed => {
+                            text::success(text::small(&d.message(ctx.converter.language))).into()
+                        }
+                        Severity::Warning => text::small(&d.message(ctx.converter.language)).into(),
+                        Severity::Failed => {
+                            text::danger(text::small(&d.message(ctx.converter.language))).into()
+                        }
+                    })
+                    .collect(),
+            )
+            .spacing(5),
+        ),
+    }
+}
+
+/// DaemonBox displays the status of revaultd and allows to stop or
+/// restart it, the app reconnects once it is started again.
+#[derive(Debug)]
+pub struct DaemonBox {
+    stop_button: iced::button::State,
+    restart_button: iced::button::State,
+}
+
+impl DaemonBox {
+    pub fn new() -> Self {
+        DaemonBox {
+            stop_button: iced::button::State::new(),
+            restart_button: iced::button::State::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        status: Option<&Result<Instance, RevaultDError>>,
+        restarting: bool,
+    ) -> Container<'a, Message> {
+        let row = |label: &str, value: String| {
+            Row::new()
+                .push(Container::new(text::small(ctx.tr(label))).width(Length::Fill))
+                .push(text::small(&value))
+        };
+        let mut body = Column::new().spacing(10);
+        body = match status {
+            None => body.push(text::small(ctx.tr("Checking revaultd..."))),
+            Some(Err(e)) => body.push(row("Status", ctx.trf("unreachable ({})", &[&e]))),
+            Some(Ok(instance)) => body
+                .push(row("Status", ctx.tr("running").to_string()))
+                .push(row("Version", instance.version.clone()))
+                .push(row(
+                    "PID",
+                    instance.pid.map(|pid| pid.to_string()).unwrap_or_else(|| {
+                        ctx.tr("unknown, revaultd is not daemonized").to_string()
+                    }),
+                ))
+                .push(row(
+                    "Uptime",
+                    instance
+                        .started_at
+                        .map(|start| uptime(chrono::Utc::now().timestamp() - start))
+                        .unwrap_or_else(|| ctx.tr("unknown").to_string()),
+                ))
+                .push(row(
+                    "Blockheight",
+                    ctx.trf(
+                        "{} ({}% synced)",
+                        &[
+                            &instance.blockheight,
+                            &format!("{:.0}", instance.sync * 100.0),
+                        ],
+                    ),
+                )),
+        };
+        body = if restarting {
+            body.push(text::small(ctx.tr("Restarting revaultd...")))
+        } else {
+            body.push(
+                Row::new()
+                    .push(Column::new().width(Length::Fill))
+                    .push(
+                        button::transparent(
+                            &mut self.stop_button,
+                            button::button_content(None, ctx.tr("Stop")),
+                        )
+                        .on_press(confirm::stop_daemon().ask()),
+                    )
+                    .push(
+                        button::primary(
+                            &mut self.restart_button,
+                            button::button_content(None, ctx.tr("Restart")),
+                        )
+                        .on_press(Message::Daemon(DaemonMessage::Restart)),
+                    )
+                    .spacing(10)
+                    .align_items(Align::Center),
+            )
+        };
+
+        card::simple(Container::new(
+            Column::new()
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple("revaultd")))
+                        .push(text::small(ctx.tr(
+                            "The daemon watching the vaults, it is started by the GUI \
+                            if it is not running",
+                        ))),
+                )
+                .push(separation().width(Length::Fill))
+                .push(body)
+                .spacing(20),
+        ))
+        .width(Length::Fill)
+    }
+}
+
+/// Formats the seconds as days, hours and minutes, e.g. "2d 3h 15m".
+fn uptime(secs: i64) -> String {
+    let secs = secs.max(0);
+    let (days, hours, minutes) = (secs / 86400, secs % 86400 / 3600, secs % 3600 / 60);
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
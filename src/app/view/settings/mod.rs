@@ -1,16 +1,31 @@
+use std::path::Path;
+
 use iced::{scrollable, Column, Container, Element};
 
 use crate::revault::Role;
 use crate::{
     app::{
+        addressbook::AddressBook,
+        backup::Backup,
+        connection::Instance,
+        contacts::Contacts,
+        daemon_config::ConfigEdit,
         error::Error,
         message::Message,
+        metadata::Transfer,
+        metrics::Counters,
+        notifications::NotificationSettings,
+        report::ReportSettings,
         view::{layout, sidebar::Sidebar, Context},
     },
+    bitcoind::{BitcoindError, NodeReport},
+    logs::LogLevel,
+    noise::KeyFile,
+    screenshot,
     ui::component::{navbar, scroll},
 };
 
-use crate::revaultd::config::Config;
+use crate::revaultd::{config::Config, daemon::Api, RevaultDError};
 
 mod boxes;
 use boxes::*;
@@ -19,6 +34,19 @@ use boxes::*;
 pub struct SettingsView {
     scroll: scrollable::State,
     sidebar: Sidebar,
+    backup_box: BackupBox,
+    transfer_box: TransferBox,
+    contacts_box: ContactsBox,
+    report_box: ReportBox,
+    address_book_box: AddressBookBox,
+    launcher_box: LauncherBox,
+    descriptor_check_box: DescriptorCheckBox,
+    noise_key_box: NoiseKeyBox,
+    logs_box: LogsBox,
+    language_box: LanguageBox,
+    theme_box: ThemeBox,
+    config_edit_box: ConfigEditBox,
+    daemon_box: DaemonBox,
 }
 
 impl SettingsView {
@@ -26,22 +54,94 @@ impl SettingsView {
         SettingsView {
             sidebar: Sidebar::new(),
             scroll: scrollable::State::new(),
+            backup_box: BackupBox::new(),
+            transfer_box: TransferBox::new(),
+            contacts_box: ContactsBox::new(),
+            report_box: ReportBox::new(),
+            address_book_box: AddressBookBox::new(),
+            launcher_box: LauncherBox::new(),
+            descriptor_check_box: DescriptorCheckBox::new(),
+            noise_key_box: NoiseKeyBox::new(),
+            logs_box: LogsBox::new(),
+            language_box: LanguageBox::new(),
+            theme_box: ThemeBox::new(),
+            config_edit_box: ConfigEditBox::new(),
+            daemon_box: DaemonBox::new(),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn view<'a>(
         &'a mut self,
         ctx: &Context,
         warning: Option<&Error>,
         config: Config,
+        api: &Api,
+        metrics: Option<&Counters>,
+        backups: Option<(&Path, &[Backup])>,
+        restored: Option<i64>,
+        transfer: &Transfer,
+        contacts: (&Contacts, bool),
+        report: (&ReportSettings, bool, bool),
+        notifications: &NotificationSettings,
+        address_book: (&AddressBook, (&str, &str)),
+        descriptor_check: &str,
+        noise_key: Option<&KeyFile>,
+        logs: (LogLevel, Option<&Path>),
+        config_edit: Option<&ConfigEdit>,
+        bitcoind_test: (bool, Option<&Result<NodeReport, BitcoindError>>),
+        restarting: bool,
+        daemon: Option<&Result<Instance, RevaultDError>>,
     ) -> Element<'a, Message> {
+        let mut boxes = Column::new().spacing(20);
+        if let Some(notice) = daemon_api_box(ctx, api) {
+            boxes = boxes.push(notice);
+        }
+        let mut boxes = boxes
+            .push(SettingsView::display_boxes(&ctx, &config))
+            .push(self.launcher_box.view(ctx))
+            .push(self.daemon_box.view(ctx, daemon, restarting))
+            .push(
+                self.config_edit_box
+                    .view(ctx, config_edit, bitcoind_test, restarting),
+            )
+            .push(self.contacts_box.view(ctx, &config, contacts.0, contacts.1))
+            .push(self.report_box.view(ctx, report.0, report.1, report.2))
+            .push(notifications_box(ctx, notifications));
+        if ctx.role == Role::Manager {
+            boxes = boxes.push(
+                self.address_book_box
+                    .view(ctx, address_book.0, address_book.1),
+            );
+        }
         layout::dashboard(
             navbar(layout::navbar_warning(warning)),
             self.sidebar.view(ctx),
             layout::main_section(Container::new(
                 scroll(
                     &mut self.scroll,
-                    Container::new(SettingsView::display_boxes(&ctx, &config)),
+                    Container::new(
+                        boxes
+                            .push(
+                                self.descriptor_check_box
+                                    .view(ctx, &config, descriptor_check),
+                            )
+                            .push(self.noise_key_box.view(ctx, &config, noise_key))
+                            .push(self.language_box.view(ctx.converter.language))
+                            .push(
+                                self.theme_box
+                                    .view(ctx.theme_preference, ctx.converter.language),
+                            )
+                            .push(screenshot_box(ctx, screenshot::enabled()))
+                            .push(
+                                self.logs_box
+                                    .view(ctx, logs.0, logs.1, ctx.converter.language),
+                            )
+                            .push(developer_console_box(ctx, ctx.developer_console))
+                            .push(metrics_box(ctx, metrics))
+                            .push(self.backup_box.view(ctx, backups, restored))
+                            .push(self.transfer_box.view(ctx, transfer)),
+                    ),
                 )
                 .spacing(8),
             )),
@@ -52,18 +152,18 @@ impl SettingsView {
     pub fn display_boxes<'a>(ctx: &Context, config: &Config) -> Column<'a, Message> {
         let boxes = SettingsBoxes::default();
         let mut column = Column::new()
-            .push(boxes.general.display(config))
-            .push(boxes.bitcoind.display(config));
+            .push(boxes.general.display(ctx, config))
+            .push(boxes.bitcoind.display(ctx, config));
 
         match ctx.role {
             Role::Manager => {
-                column = column.push(boxes.manager.display(config));
+                column = column.push(boxes.manager.display(ctx, config));
             }
             Role::Stakeholder => {
-                column = column.push(boxes.stakeholder.display(config));
+                column = column.push(boxes.stakeholder.display(ctx, config));
             }
         };
 
-        column.push(boxes.scripts.display(config)).spacing(20)
+        column.push(boxes.scripts.display(ctx, config)).spacing(20)
     }
 }
@@ -1,18 +1,19 @@
+use chrono::NaiveDateTime;
 use iced::{pick_list, Column, Container, Length, Row};
 
 use crate::revault::Role;
 use crate::{
     app::{
         menu::Menu,
-        message::Message,
+        message::{Message, PollMessage},
         view::{layout, Context},
     },
     ui::{
         color,
         component::{button, separation, text, TransparentPickListStyle},
         icon::{
-            deposit_icon, dot_icon, home_icon, network_icon, person_check_icon, plus_icon,
-            send_icon, settings_icon, vaults_icon, warning_icon,
+            deposit_icon, dot_icon, history_icon, home_icon, network_icon, person_check_icon,
+            plus_icon, send_icon, settings_icon, terminal_icon, vaults_icon, warning_icon,
         },
     },
 };
@@ -27,7 +28,10 @@ pub struct Sidebar {
     vaults_menu_button: iced::button::State,
     network_menu_button: iced::button::State,
     spend_menu_button: iced::button::State,
+    logs_menu_button: iced::button::State,
+    console_menu_button: iced::button::State,
     settings_menu_button: iced::button::State,
+    poll_button: iced::button::State,
 }
 
 impl Sidebar {
@@ -40,7 +44,10 @@ impl Sidebar {
             vaults_menu_button: iced::button::State::new(),
             network_menu_button: iced::button::State::new(),
             spend_menu_button: iced::button::State::new(),
+            logs_menu_button: iced::button::State::new(),
+            console_menu_button: iced::button::State::new(),
             settings_menu_button: iced::button::State::new(),
+            poll_button: iced::button::State::new(),
             pick_role: pick_list::State::default(),
         }
     }
@@ -64,39 +71,39 @@ impl Sidebar {
         let home_button = if context.menu == Menu::Home {
             button::primary(
                 &mut self.home_menu_button,
-                button::button_content(Some(home_icon()), "Home"),
+                button::button_content(Some(home_icon()), context.tr("Home")),
             )
             .on_press(Message::Menu(Menu::Home))
         } else {
             button::transparent(
                 &mut self.home_menu_button,
-                button::button_content(Some(home_icon()), "Home"),
+                button::button_content(Some(home_icon()), context.tr("Home")),
             )
             .on_press(Message::Menu(Menu::Home))
         };
         let vaults_button = if context.menu == Menu::Vaults {
             button::primary(
                 &mut self.vaults_menu_button,
-                button::button_content(Some(vaults_icon()), "Vaults"),
+                button::button_content(Some(vaults_icon()), context.tr("Vaults")),
             )
             .on_press(Message::Menu(Menu::Vaults))
         } else {
             button::transparent(
                 &mut self.vaults_menu_button,
-                button::button_content(Some(vaults_icon()), "Vaults"),
+                button::button_content(Some(vaults_icon()), context.tr("Vaults")),
             )
             .on_press(Message::Menu(Menu::Vaults))
         };
         let network_button = if context.menu == Menu::Network {
             button::primary(
                 &mut self.network_menu_button,
-                button::button_content(Some(network_icon()), "Network"),
+                button::button_content(Some(network_icon()), context.tr("Network")),
             )
             .on_press(Message::Menu(Menu::Network))
         } else {
             let mut row = Row::new()
                 .push(network_icon())
-                .push(text::simple("Network"))
+                .push(text::simple(context.tr("Network")))
                 .spacing(10)
                 .align_items(iced::Align::Center);
 
@@ -113,17 +120,49 @@ impl Sidebar {
             .on_press(Message::Menu(Menu::Network))
         };
 
+        let logs_button = if context.menu == Menu::Logs {
+            button::primary(
+                &mut self.logs_menu_button,
+                button::button_content(Some(history_icon()), context.tr("Logs")),
+            )
+            .on_press(Message::Menu(Menu::Logs))
+            .width(iced::Length::Units(200))
+        } else {
+            button::transparent(
+                &mut self.logs_menu_button,
+                button::button_content(Some(history_icon()), context.tr("Logs")),
+            )
+            .on_press(Message::Menu(Menu::Logs))
+            .width(iced::Length::Units(200))
+        };
+
+        let console_button = if context.menu == Menu::Console {
+            button::primary(
+                &mut self.console_menu_button,
+                button::button_content(Some(terminal_icon()), context.tr("Console")),
+            )
+            .on_press(Message::Menu(Menu::Console))
+            .width(iced::Length::Units(200))
+        } else {
+            button::transparent(
+                &mut self.console_menu_button,
+                button::button_content(Some(terminal_icon()), context.tr("Console")),
+            )
+            .on_press(Message::Menu(Menu::Console))
+            .width(iced::Length::Units(200))
+        };
+
         let settings_button = if context.menu == Menu::Settings {
             button::primary(
                 &mut self.settings_menu_button,
-                button::button_content(Some(settings_icon()), "Settings"),
+                button::button_content(Some(settings_icon()), context.tr("Settings")),
             )
             .on_press(Message::Menu(Menu::Settings))
             .width(iced::Length::Units(200))
         } else {
             button::transparent(
                 &mut self.settings_menu_button,
-                button::button_content(Some(settings_icon()), "Settings"),
+                button::button_content(Some(settings_icon()), context.tr("Settings")),
             )
             .on_press(Message::Menu(Menu::Settings))
             .width(iced::Length::Units(200))
@@ -134,14 +173,14 @@ impl Sidebar {
             let deposit_button = if context.menu == Menu::Deposit {
                 button::primary(
                     &mut self.deposit_menu_button,
-                    button::button_content(Some(deposit_icon()), "Deposit"),
+                    button::button_content(Some(deposit_icon()), context.tr("Deposit")),
                 )
                 .on_press(Message::Menu(Menu::Deposit))
                 .width(Length::Units(200))
             } else {
                 button::transparent(
                     &mut self.deposit_menu_button,
-                    button::button_content(Some(deposit_icon()), "Deposit"),
+                    button::button_content(Some(deposit_icon()), context.tr("Deposit")),
                 )
                 .on_press(Message::Menu(Menu::Deposit))
                 .width(Length::Units(200))
@@ -150,7 +189,7 @@ impl Sidebar {
             actions = actions.push(deposit_button).push(Container::new(
                 button::transparent(
                     &mut self.spend_menu_button,
-                    button::button_content(Some(send_icon()), "Send"),
+                    button::button_content(Some(send_icon()), context.tr("Send")),
                 )
                 .on_press(Message::Menu(Menu::Send))
                 .width(iced::Length::Units(200)),
@@ -160,7 +199,10 @@ impl Sidebar {
                 Container::new(
                     button::primary(
                         &mut self.delegate_menu_button,
-                        button::button_content(Some(person_check_icon()), "Delegate funds"),
+                        button::button_content(
+                            Some(person_check_icon()),
+                            context.tr("Delegate funds"),
+                        ),
                     )
                     .on_press(Message::Menu(Menu::DelegateFunds))
                     .width(iced::Length::Units(200)),
@@ -169,7 +211,10 @@ impl Sidebar {
                 Container::new(
                     button::transparent(
                         &mut self.delegate_menu_button,
-                        button::button_content(Some(person_check_icon()), "Delegate funds"),
+                        button::button_content(
+                            Some(person_check_icon()),
+                            context.tr("Delegate funds"),
+                        ),
                     )
                     .on_press(Message::Menu(Menu::DelegateFunds))
                     .width(iced::Length::Units(200)),
@@ -179,7 +224,7 @@ impl Sidebar {
                 .push(
                     button::transparent(
                         &mut self.deposit_menu_button,
-                        button::button_content(Some(plus_icon()), "Create vault"),
+                        button::button_content(Some(plus_icon()), context.tr("Create vault")),
                     )
                     .on_press(Message::Menu(Menu::CreateVaults))
                     .width(iced::Length::Units(200)),
@@ -191,7 +236,7 @@ impl Sidebar {
                         Container::new(
                             Row::new()
                                 .push(warning_icon().color(color::PRIMARY))
-                                .push(text::simple("Emergency").color(color::PRIMARY))
+                                .push(text::simple(context.tr("Emergency")).color(color::PRIMARY))
                                 .spacing(10)
                                 .align_items(iced::Align::Center),
                         )
@@ -201,6 +246,12 @@ impl Sidebar {
                     .width(iced::Length::Units(200)),
                 ));
         }
+        let mut footer = Column::new()
+            .push(poll_status(&mut self.poll_button, context))
+            .push(logs_button);
+        if context.developer_console {
+            footer = footer.push(console_button);
+        }
         layout::sidebar(
             layout::sidebar_menu(vec![
                 role.width(Length::Units(200)),
@@ -211,7 +262,37 @@ impl Sidebar {
                 separation().width(Length::Units(200)),
                 Container::new(actions.width(Length::Units(200))),
             ]),
-            Container::new(settings_button),
+            Container::new(footer.push(settings_button).spacing(10)),
         )
     }
 }
+
+/// Time of the last refresh and the button pausing the background refresh.
+fn poll_status<'a>(
+    state: &'a mut iced::button::State,
+    context: &Context,
+) -> Container<'a, Message> {
+    let updated = match context.last_update {
+        Some(time) => format!(
+            "{} {}",
+            context.tr("Updated at"),
+            NaiveDateTime::from_timestamp(time, 0).format("%H:%M:%S")
+        ),
+        None => context.tr("Not updated yet").to_string(),
+    };
+    let label = if context.poll_paused {
+        context.tr("Resume")
+    } else {
+        context.tr("Pause")
+    };
+    Container::new(
+        Row::new()
+            .push(Container::new(text::small(&updated)).width(Length::Fill))
+            .push(
+                button::transparent(state, Container::new(text::small(label)))
+                    .on_press(Message::Poll(PollMessage::TogglePause)),
+            )
+            .align_items(iced::Align::Center),
+    )
+    .width(Length::Units(200))
+}
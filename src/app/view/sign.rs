@@ -1,13 +1,16 @@
+use std::path::PathBuf;
+
 use bitcoin::util::psbt::PartiallySignedTransaction as Psbt;
 use iced::{Align, Column, Container, Element, Length, Row, TextInput};
 
 use crate::{
     app::{
         message::{SignMessage, SignatureSharingStatus},
+        signatures::InputSignature,
         view::Context,
     },
     revault::TransactionKind,
-    ui::component::{button, card, separation, text},
+    ui::component::{button, card, copy, separation, text},
 };
 
 #[derive(Debug)]
@@ -26,17 +29,21 @@ impl DirectSignatureView {
 
     pub fn view(
         &mut self,
-        _ctx: &Context,
+        ctx: &Context,
         transaction_kind: &TransactionKind,
     ) -> Element<SignMessage> {
         let title = match transaction_kind {
-            TransactionKind::Emergency => text::bold(text::simple("Sign emergency transaction")),
+            TransactionKind::Emergency => {
+                text::bold(text::simple(ctx.tr("Sign emergency transaction")))
+            }
             TransactionKind::EmergencyUnvault => {
-                text::bold(text::simple("Sign emergency unvault transaction"))
+                text::bold(text::simple(ctx.tr("Sign emergency unvault transaction")))
+            }
+            TransactionKind::Cancel => text::bold(text::simple(ctx.tr("Sign cancel transaction"))),
+            TransactionKind::Spend => text::bold(text::simple(ctx.tr("Sign spend transaction"))),
+            TransactionKind::Unvault => {
+                text::bold(text::simple(ctx.tr("Sign unvault transaction")))
             }
-            TransactionKind::Cancel => text::bold(text::simple("Sign cancel transaction")),
-            TransactionKind::Spend => text::bold(text::simple("Sign spend transaction")),
-            TransactionKind::Unvault => text::bold(text::simple("Sign unvault transaction")),
         };
 
         let col = Column::new()
@@ -46,7 +53,7 @@ impl DirectSignatureView {
                     .push(
                         button::transparent(
                             &mut self.indirect_button,
-                            button::button_content(None, "Use PSBT"),
+                            button::button_content(None, ctx.tr("Use PSBT")),
                         )
                         .on_press(SignMessage::ChangeMethod)
                         .width(Length::Shrink),
@@ -55,7 +62,7 @@ impl DirectSignatureView {
             )
             .push(separation().width(Length::Fill))
             .push(
-                Container::new(text::simple("Connect device"))
+                Container::new(text::simple(ctx.tr("Connect device")))
                     .padding(20)
                     .width(Length::Fill)
                     .align_x(Align::Center),
@@ -64,7 +71,7 @@ impl DirectSignatureView {
                 Container::new(
                     button::primary(
                         &mut self.sign_button,
-                        button::button_content(None, " Sign transaction "),
+                        button::button_content(None, ctx.tr(" Sign transaction ")),
                     )
                     .on_press(SignMessage::ChangeMethod),
                 )
@@ -82,6 +89,9 @@ pub struct IndirectSignatureView {
     sign_button: iced::button::State,
     copy_button: iced::button::State,
     psbt_input: iced::text_input::State,
+    path_input: iced::text_input::State,
+    export_button: iced::button::State,
+    import_button: iced::button::State,
 }
 
 impl IndirectSignatureView {
@@ -91,26 +101,36 @@ impl IndirectSignatureView {
             sign_button: iced::button::State::default(),
             copy_button: iced::button::State::default(),
             psbt_input: iced::text_input::State::new(),
+            path_input: iced::text_input::State::new(),
+            export_button: iced::button::State::default(),
+            import_button: iced::button::State::default(),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn view(
         &mut self,
-        _ctx: &Context,
+        ctx: &Context,
         sharing_status: &SignatureSharingStatus,
         transaction_kind: &TransactionKind,
         psbt: &Psbt,
         psbt_input: &str,
+        file: (&str, Option<&PathBuf>),
+        signatures: &[InputSignature],
         warning: Option<&String>,
     ) -> Element<SignMessage> {
         let title = match transaction_kind {
-            TransactionKind::Emergency => text::bold(text::simple("Sign emergency transaction")),
+            TransactionKind::Emergency => {
+                text::bold(text::simple(ctx.tr("Sign emergency transaction")))
+            }
             TransactionKind::EmergencyUnvault => {
-                text::bold(text::simple("Sign emergency unvault transaction"))
+                text::bold(text::simple(ctx.tr("Sign emergency unvault transaction")))
+            }
+            TransactionKind::Cancel => text::bold(text::simple(ctx.tr("Sign cancel transaction"))),
+            TransactionKind::Spend => text::bold(text::simple(ctx.tr("Sign spend transaction"))),
+            TransactionKind::Unvault => {
+                text::bold(text::simple(ctx.tr("Sign unvault transaction")))
             }
-            TransactionKind::Cancel => text::bold(text::simple("Sign cancel transaction")),
-            TransactionKind::Spend => text::bold(text::simple("Sign spend transaction")),
-            TransactionKind::Unvault => text::bold(text::simple("Sign unvault transaction")),
         };
 
         let psbt_str = bitcoin::base64::encode(&bitcoin::consensus::serialize(psbt));
@@ -122,7 +142,7 @@ impl IndirectSignatureView {
                     .push(
                         button::transparent(
                             &mut self.direct_button,
-                            button::button_content(None, "Use hardware module"),
+                            button::button_content(None, ctx.tr("Use hardware module")),
                         )
                         .on_press(SignMessage::ChangeMethod)
                         .width(Length::Shrink),
@@ -131,20 +151,61 @@ impl IndirectSignatureView {
             )
             .push(separation().width(Length::Fill))
             .push(
-                Container::new(
-                    Row::new()
-                        .push(Container::new(text::small(&psbt_str)).width(Length::Fill))
-                        .push(
-                            button::clipboard(
-                                &mut self.copy_button,
-                                SignMessage::Clipboard(psbt_str),
-                            )
-                            .width(Length::Shrink),
-                        )
-                        .align_items(Align::Center),
-                )
+                Container::new(copy::copyable(
+                    &mut self.copy_button,
+                    Container::new(text::small(&psbt_str)).width(Length::Fill),
+                    SignMessage::Clipboard(psbt_str),
+                ))
                 .width(Length::Fill),
             );
+
+        let (path, exported) = file;
+        let mut file_row = Row::new()
+            .push(
+                TextInput::new(
+                    &mut self.path_input,
+                    ctx.tr("PSBT file"),
+                    path,
+                    SignMessage::PathEdited,
+                )
+                .size(15)
+                .width(Length::Fill)
+                .padding(10),
+            )
+            .push(
+                button::transparent(
+                    &mut self.export_button,
+                    button::button_content(None, ctx.tr("Export unsigned")),
+                )
+                .on_press(SignMessage::Export),
+            )
+            .spacing(10)
+            .align_items(Align::Center);
+        if let SignatureSharingStatus::Unshared = sharing_status {
+            file_row = file_row.push(
+                button::transparent(
+                    &mut self.import_button,
+                    button::button_content(None, ctx.tr("Import signed")),
+                )
+                .on_press(SignMessage::Import),
+            );
+        }
+        col = col.push(file_row);
+        if let Some(exported) = exported {
+            col = col.push(text::small(
+                &ctx.trf("Unsigned PSBT written to {}", &[&exported.display()]),
+            ));
+        }
+
+        for (i, signature) in signatures.iter().enumerate() {
+            let line = text::small(&ctx.trf("Input {}: {}", &[&i, &signature]));
+            col = col.push(if *signature == InputSignature::Signed {
+                text::success(line)
+            } else {
+                text::danger(line)
+            });
+        }
+
         if let Some(message) = warning {
             col = col.push(card::alert_warning(Container::new(text::simple(message))));
         }
@@ -154,9 +215,11 @@ impl IndirectSignatureView {
                 col = col
                     .push(Container::new(text::small(&psbt_input.to_string())))
                     .push(
-                        Container::new(card::success(Container::new(text::simple("success"))))
-                            .width(Length::Fill)
-                            .align_x(Align::Center),
+                        Container::new(card::success(Container::new(text::simple(
+                            ctx.tr("success"),
+                        ))))
+                        .width(Length::Fill)
+                        .align_x(Align::Center),
                     );
             }
             SignatureSharingStatus::Processing => {
@@ -165,7 +228,7 @@ impl IndirectSignatureView {
                     .push(Container::new(
                         button::primary_disable(
                             &mut self.sign_button,
-                            button::button_content(None, " Processing "),
+                            button::button_content(None, ctx.tr(" Processing ")),
                         )
                         .on_press(SignMessage::Sign),
                     ));
@@ -175,7 +238,7 @@ impl IndirectSignatureView {
                     .push(
                         TextInput::new(
                             &mut self.psbt_input,
-                            "Signed PSBT",
+                            ctx.tr("Signed PSBT"),
                             &psbt_input,
                             SignMessage::PsbtEdited,
                         )
@@ -187,7 +250,7 @@ impl IndirectSignatureView {
                         Container::new(
                             button::primary(
                                 &mut self.sign_button,
-                                button::button_content(None, " Sign transaction "),
+                                button::button_content(None, ctx.tr(" Sign transaction ")),
                             )
                             .on_press(SignMessage::Sign),
                         )
@@ -7,10 +7,17 @@ use crate::{
         error::Error,
         menu::Menu,
         message::{Message, SpendTxMessage},
-        view::{manager::spend_tx_with_feerate_view, Context},
+        spend_hints::SpendHint,
+        spend_review::{OutputKind, SpendReview},
+        view::Context,
     },
+    participant::Participant,
+    revault::Role,
     revaultd::model,
-    ui::component::{badge, button, card, scroll, text, ContainerBackgroundStyle},
+    ui::{
+        color,
+        component::{badge, button, card, copy, scroll, text, ContainerBackgroundStyle},
+    },
 };
 
 #[derive(Debug)]
@@ -31,11 +38,13 @@ impl SpendTransactionView {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn view<'a>(
         &'a mut self,
         ctx: &Context,
-        psbt: &Psbt,
-        spent_vaults: &[model::Vault],
+        review: Option<&SpendReview>,
+        hints: &[SpendHint],
+        signatures: &[(&Participant, bool)],
         action: Element<'a, Message>,
         warning: Option<&Error>,
     ) -> Element<'a, Message> {
@@ -46,7 +55,8 @@ impl SpendTransactionView {
             ))))
         }
         col = col
-            .push(spend_tx_with_feerate_view(ctx, spent_vaults, psbt, None))
+            .push(review_card(ctx, review, hints))
+            .push(signatures_card(ctx, signatures))
             .push(action);
         Container::new(scroll(
             &mut self.scroll,
@@ -57,7 +67,7 @@ impl SpendTransactionView {
                             Container::new(
                                 button::cancel(
                                     &mut self.cancel_button,
-                                    Container::new(text::simple("X Close")).padding(10),
+                                    Container::new(text::simple(ctx.tr("X Close"))).padding(10),
                                 )
                                 .on_press(Message::Menu(Menu::Home)),
                             )
@@ -80,6 +90,165 @@ impl SpendTransactionView {
     }
 }
 
+/// review_card displays what the spend transaction takes from the vaults
+/// and where it sends it, the change coming back to the deposit descriptor.
+fn review_card<'a>(
+    ctx: &Context,
+    review: Option<&SpendReview>,
+    hints: &[SpendHint],
+) -> Container<'a, Message> {
+    let review = if let Some(review) = review {
+        review
+    } else {
+        return card::white(Container::new(text::small(
+            ctx.tr("Loading the spent vaults..."),
+        )))
+        .width(Length::Fill);
+    };
+    let amount = |value: u64| {
+        Row::new()
+            .push(text::bold(text::small(&ctx.converter.amount_string(value))))
+            .push(text::small(&format!(" {}", ctx.converter.unit)))
+    };
+    let mut col = Column::new().spacing(20);
+    if !hints.is_empty() {
+        let mut col_hints = Column::new()
+            .push(text::bold(text::small(ctx.tr(
+                "Please review the destinations, these hints are advisory only:",
+            ))))
+            .spacing(5);
+        for hint in hints {
+            col_hints = col_hints.push(text::small(&hint.to_string()));
+        }
+        col = col.push(card::alert_warning(Container::new(col_hints)));
+    }
+
+    let mut col_input = Column::new()
+        .push(text::bold(text::simple(ctx.tr("Inputs"))))
+        .spacing(10);
+    for input in &review.inputs {
+        col_input = col_input.push(card::simple(Container::new(
+            Row::new()
+                .push(
+                    Container::new(text::small(
+                        input.vault.as_deref().unwrap_or(ctx.tr("Unknown vault")),
+                    ))
+                    .width(Length::Fill),
+                )
+                .push(match input.value {
+                    Some(value) => amount(value),
+                    None => Row::new().push(text::small(ctx.tr("Unknown amount"))),
+                })
+                .spacing(5)
+                .align_items(Align::Center),
+        )));
+    }
+
+    let mut col_output = Column::new()
+        .push(text::bold(text::simple(ctx.tr("Outputs"))))
+        .spacing(10);
+    for output in &review.outputs {
+        let kind = match output.kind {
+            OutputKind::Payment => text::small(&output.kind.to_string()),
+            kind => text::success(text::small(&ctx.trf("{} (back to the wallet)", &[&kind]))),
+        };
+        col_output = col_output.push(card::simple(Container::new(
+            Column::new()
+                .push(
+                    Row::new()
+                        .push(
+                            Container::new(text::small(
+                                output
+                                    .address
+                                    .as_deref()
+                                    .unwrap_or(ctx.tr("Unknown script")),
+                            ))
+                            .width(Length::Fill),
+                        )
+                        .push(amount(output.value))
+                        .spacing(5)
+                        .align_items(Align::Center),
+                )
+                .push(kind)
+                .spacing(5),
+        )));
+    }
+
+    let mut summary = Column::new()
+        .push(
+            Row::new()
+                .push(Container::new(text::simple(ctx.tr("Sent: "))).width(Length::Fill))
+                .push(amount(review.total(OutputKind::Payment))),
+        )
+        .push(
+            Row::new()
+                .push(Container::new(text::simple(ctx.tr("Change: "))).width(Length::Fill))
+                .push(amount(review.total(OutputKind::Change))),
+        )
+        .push(
+            Row::new()
+                .push(Container::new(text::simple(ctx.tr("CPFP output: "))).width(Length::Fill))
+                .push(amount(review.total(OutputKind::Cpfp))),
+        )
+        .spacing(5);
+    summary = summary.push(
+        Row::new()
+            .push(Container::new(text::simple(ctx.tr("Fees: "))).width(Length::Fill))
+            .push(match review.fee {
+                Some(fee) => amount(fee),
+                None => Row::new().push(text::small(ctx.tr("Unknown, an input amount is missing"))),
+            }),
+    );
+    if let Some(feerate) = review.feerate {
+        summary = summary.push(
+            Row::new()
+                .push(Container::new(text::simple(ctx.tr("Feerate: "))).width(Length::Fill))
+                .push(text::bold(text::small(
+                    &ctx.trf("at least {} sats/vbyte", &[&feerate]),
+                ))),
+        );
+    }
+
+    card::white(Container::new(
+        col.push(card::simple(Container::new(summary))).push(
+            Row::new()
+                .push(col_input.width(Length::FillPortion(1)))
+                .push(col_output.width(Length::FillPortion(1)))
+                .spacing(20),
+        ),
+    ))
+    .width(Length::Fill)
+}
+
+/// signatures_card displays which managers signed the spend transaction.
+fn signatures_card<'a>(
+    ctx: &Context,
+    signatures: &[(&Participant, bool)],
+) -> Container<'a, Message> {
+    let mut col = Column::new()
+        .push(text::bold(text::simple(ctx.tr("Manager signatures"))))
+        .spacing(10);
+    for (participant, signed) in signatures
+        .iter()
+        .filter(|(p, _)| p.role == Some(Role::Manager))
+    {
+        col = col.push(
+            Row::new()
+                .push(
+                    Container::new(badge::participant(&participant.fingerprint))
+                        .width(Length::Fill),
+                )
+                .push(if *signed {
+                    text::success(text::small(ctx.tr("Signed")))
+                } else {
+                    text::small(ctx.tr("Not signed yet"))
+                })
+                .align_items(Align::Center),
+        );
+    }
+    card::white(Container::new(col)).width(Length::Fill)
+}
+
 #[derive(Debug)]
 pub struct SpendTransactionSharePsbtView {
     share_button: iced::button::State,
@@ -106,6 +275,7 @@ impl SpendTransactionSharePsbtView {
 
     pub fn view(
         &mut self,
+        ctx: &Context,
         psbt_input: &str,
         processing: &bool,
         success: &bool,
@@ -117,43 +287,47 @@ impl SpendTransactionSharePsbtView {
                 .push(
                     button::primary(
                         &mut self.share_button,
-                        button::button_content(None, "Share and update"),
+                        button::button_content(None, ctx.tr("Share and update")),
                     )
                     .on_press(Message::SpendTx(SpendTxMessage::SelectShare)),
                 )
                 .push(
                     button::transparent(
                         &mut self.sign_button,
-                        button::button_content(None, "Sign"),
+                        button::button_content(None, ctx.tr("Sign")),
                     )
                     .on_press(Message::SpendTx(SpendTxMessage::SelectSign)),
                 )
                 .push(
                     button::transparent(
                         &mut self.broadcast_button,
-                        button::button_content(None, "Broadcast"),
+                        button::button_content(None, ctx.tr("Broadcast")),
                     )
                     .on_press(Message::SpendTx(SpendTxMessage::SelectBroadcast)),
                 )
                 .push(
                     button::transparent(
                         &mut self.delete_button,
-                        button::button_content(None, "Delete"),
+                        button::button_content(None, ctx.tr("Delete")),
                     )
                     .on_press(Message::SpendTx(SpendTxMessage::SelectDelete)),
                 ),
         );
 
         let psbt_str = bitcoin::base64::encode(&bitcoin::consensus::serialize(psbt));
-        let mut col_action = Column::new().spacing(20).push(
+        let mut col_action = Column::new().spacing(20).push(text::small(ctx.tr(
+            "revaultd only announces the spend to the coordinator once all the managers \
+            signed it. Send this PSBT to the other managers, then enter their signed PSBT \
+            below to add their signatures.",
+        )));
+        col_action = col_action.push(
             Column::new().push(
-                Row::new()
-                    .push(Container::new(text::small(&psbt_str)).width(Length::Fill))
-                    .push(
-                        button::clipboard(&mut self.copy_button, Message::Clipboard(psbt_str))
-                            .width(Length::Shrink),
-                    )
-                    .width(Length::Fill),
+                copy::copyable(
+                    &mut self.copy_button,
+                    Container::new(text::small(&psbt_str)).width(Length::Fill),
+                    Message::Clipboard(psbt_str),
+                )
+                .width(Length::Fill),
             ),
         );
         if let Some(error) = warning {
@@ -164,23 +338,27 @@ impl SpendTransactionSharePsbtView {
 
         let mut button_update_action = button::important(
             &mut self.confirm_button,
-            button::button_content(None, "Update transaction"),
+            button::button_content(None, ctx.tr("Update transaction")),
         );
         if !*processing {
             button_update_action =
                 button_update_action.on_press(Message::SpendTx(SpendTxMessage::Update));
         }
         if *success {
-            col_action = col_action.push(text::success(text::simple("Transaction updated")));
+            col_action =
+                col_action.push(text::success(text::simple(ctx.tr("Transaction updated"))));
         }
         Container::new(
             col.push(card::white(Container::new(
                 col_action
-                    .push(text::simple("Enter PSBT:"))
+                    .push(text::simple(ctx.tr("Enter PSBT:")))
                     .push(
-                        TextInput::new(&mut self.psbt_input, "Signed PSBT", &psbt_input, |p| {
-                            Message::SpendTx(SpendTxMessage::PsbtEdited(p))
-                        })
+                        TextInput::new(
+                            &mut self.psbt_input,
+                            ctx.tr("Signed PSBT"),
+                            &psbt_input,
+                            |p| Message::SpendTx(SpendTxMessage::PsbtEdited(p)),
+                        )
                         .size(15)
                         .width(Length::Fill)
                         .padding(10),
@@ -215,6 +393,7 @@ impl SpendTransactionSignView {
 
     pub fn view<'a>(
         &'a mut self,
+        ctx: &Context,
         signer: Element<'a, Message>,
         warning: Option<&Error>,
     ) -> Element<'a, Message> {
@@ -223,25 +402,28 @@ impl SpendTransactionSignView {
                 .push(
                     button::transparent(
                         &mut self.share_button,
-                        button::button_content(None, "Share and update"),
+                        button::button_content(None, ctx.tr("Share and update")),
                     )
                     .on_press(Message::SpendTx(SpendTxMessage::SelectShare)),
                 )
                 .push(
-                    button::primary(&mut self.sign_button, button::button_content(None, "Sign"))
-                        .on_press(Message::SpendTx(SpendTxMessage::SelectSign)),
+                    button::primary(
+                        &mut self.sign_button,
+                        button::button_content(None, ctx.tr("Sign")),
+                    )
+                    .on_press(Message::SpendTx(SpendTxMessage::SelectSign)),
                 )
                 .push(
                     button::transparent(
                         &mut self.broadcast_button,
-                        button::button_content(None, "Broadcast"),
+                        button::button_content(None, ctx.tr("Broadcast")),
                     )
                     .on_press(Message::SpendTx(SpendTxMessage::SelectBroadcast)),
                 )
                 .push(
                     button::transparent(
                         &mut self.delete_button,
-                        button::button_content(None, "Delete"),
+                        button::button_content(None, ctx.tr("Delete")),
                     )
                     .on_press(Message::SpendTx(SpendTxMessage::SelectDelete)),
                 ),
@@ -284,6 +466,7 @@ impl SpendTransactionDeleteView {
 
     pub fn view(
         &mut self,
+        ctx: &Context,
         processing: &bool,
         success: &bool,
         warning: Option<&Error>,
@@ -291,38 +474,40 @@ impl SpendTransactionDeleteView {
         let button_delete_action = if *processing {
             button::important(
                 &mut self.confirm_button,
-                button::button_content(None, "Deleting"),
+                button::button_content(None, ctx.tr("Deleting")),
             )
         } else if *success {
             button::success(
                 &mut self.confirm_button,
-                button::button_content(None, "Deleted"),
+                button::button_content(None, ctx.tr("Deleted")),
             )
         } else {
             button::important(
                 &mut self.confirm_button,
-                button::button_content(None, "Delete transaction"),
+                button::button_content(None, ctx.tr("Delete transaction")),
             )
             .on_press(Message::SpendTx(SpendTxMessage::Delete))
         };
 
         let mut button_share = button::transparent(
             &mut self.share_button,
-            button::button_content(None, "Share and update"),
+            button::button_content(None, ctx.tr("Share and update")),
         );
         if !*success {
             button_share = button_share.on_press(Message::SpendTx(SpendTxMessage::SelectShare));
         }
 
-        let mut button_sign =
-            button::transparent(&mut self.sign_button, button::button_content(None, "Sign"));
+        let mut button_sign = button::transparent(
+            &mut self.sign_button,
+            button::button_content(None, ctx.tr("Sign")),
+        );
         if !*success {
             button_sign = button_sign.on_press(Message::SpendTx(SpendTxMessage::SelectSign));
         }
 
         let mut button_broadcast = button::transparent(
             &mut self.broadcast_button,
-            button::button_content(None, "Broadcast"),
+            button::button_content(None, ctx.tr("Broadcast")),
         );
         if !*success {
             button_broadcast =
@@ -331,7 +516,7 @@ impl SpendTransactionDeleteView {
 
         let mut button_delete = button::primary(
             &mut self.delete_button,
-            button::button_content(None, "Delete"),
+            button::button_content(None, ctx.tr("Delete")),
         );
         if !*success {
             button_delete = button_delete.on_press(Message::SpendTx(SpendTxMessage::SelectDelete));
@@ -357,7 +542,7 @@ impl SpendTransactionDeleteView {
                     card::white(Container::new(
                         col_action
                             .push(text::simple(
-                                "Are you sure you want to delete this transaction?",
+                                ctx.tr("Are you sure you want to delete this transaction?"),
                             ))
                             .push(button_delete_action)
                             .align_items(Align::Center)
@@ -395,6 +580,7 @@ impl SpendTransactionBroadcastView {
 
     pub fn view(
         &mut self,
+        ctx: &Context,
         processing: &bool,
         success: &bool,
         warning: Option<&Error>,
@@ -402,38 +588,40 @@ impl SpendTransactionBroadcastView {
         let button_broadcast_action = if *processing {
             button::important(
                 &mut self.confirm_button,
-                button::button_content(None, "Broadcasting"),
+                button::button_content(None, ctx.tr("Broadcasting")),
             )
         } else if *success {
             button::success(
                 &mut self.confirm_button,
-                button::button_content(None, "Broadcasted"),
+                button::button_content(None, ctx.tr("Broadcasted")),
             )
         } else {
             button::important(
                 &mut self.confirm_button,
-                button::button_content(None, "Yes broadcast"),
+                button::button_content(None, ctx.tr("Yes broadcast")),
             )
             .on_press(Message::SpendTx(SpendTxMessage::Broadcast))
         };
 
         let mut button_share = button::transparent(
             &mut self.share_button,
-            button::button_content(None, "Share and update"),
+            button::button_content(None, ctx.tr("Share and update")),
         );
         if !*success {
             button_share = button_share.on_press(Message::SpendTx(SpendTxMessage::SelectShare));
         }
 
-        let mut button_sign =
-            button::transparent(&mut self.sign_button, button::button_content(None, "Sign"));
+        let mut button_sign = button::transparent(
+            &mut self.sign_button,
+            button::button_content(None, ctx.tr("Sign")),
+        );
         if !*success {
             button_sign = button_sign.on_press(Message::SpendTx(SpendTxMessage::SelectSign));
         }
 
         let mut button_broadcast = button::primary(
             &mut self.broadcast_button,
-            button::button_content(None, "Broadcast"),
+            button::button_content(None, ctx.tr("Broadcast")),
         );
         if !*success {
             button_broadcast =
@@ -442,7 +630,7 @@ impl SpendTransactionBroadcastView {
 
         let mut button_delete = button::transparent(
             &mut self.delete_button,
-            button::button_content(None, "Delete"),
+            button::button_content(None, ctx.tr("Delete")),
         );
         if !*success {
             button_delete = button_delete.on_press(Message::SpendTx(SpendTxMessage::SelectDelete));
@@ -468,7 +656,7 @@ impl SpendTransactionBroadcastView {
                     card::white(Container::new(
                         col_action
                             .push(text::simple(
-                                "Are you sure you want to broadcast this transaction ?",
+                                ctx.tr("Are you sure you want to broadcast this transaction ?"),
                             ))
                             .push(button_broadcast_action)
                             .align_items(Align::Center)
@@ -501,6 +689,7 @@ impl SpendTransactionListItemView {
         ctx: &Context,
         tx: &model::SpendTx,
         vaults_amount: u64,
+        awaits_signature: bool,
     ) -> Element<SpendTxMessage> {
         let spend_amount = tx
             .psbt
@@ -522,6 +711,14 @@ impl SpendTransactionListItemView {
         } else {
             vaults_amount - spend_amount - change_amount
         };
+        let mut txid_col = Column::new().push(text::bold(text::small(&ctx.trf(
+            "txid: {}",
+            &[&tx.psbt.global.unsigned_tx.txid().to_string()],
+        ))));
+        if awaits_signature {
+            txid_col = txid_col
+                .push(text::small(ctx.tr("Waiting for your signature")).color(color::WARNING));
+        }
         button::white_card_button(
             &mut self.select_button,
             Container::new(
@@ -530,10 +727,7 @@ impl SpendTransactionListItemView {
                         Container::new(
                             Row::new()
                                 .push(badge::pending_spent_tx())
-                                .push(Column::new().push(text::bold(text::small(&format!(
-                                    "txid: {}",
-                                    tx.psbt.global.unsigned_tx.txid().to_string()
-                                )))))
+                                .push(txid_col)
                                 .spacing(20),
                         )
                         .width(Length::Fill),
@@ -543,18 +737,17 @@ impl SpendTransactionListItemView {
                             Column::new()
                                 .push(
                                     Row::new()
-                                        .push(text::bold(text::simple(&format!(
-                                            "{}",
-                                            ctx.converter.converts(spend_amount),
-                                        ))))
+                                        .push(text::bold(text::simple(
+                                            &ctx.converter.amount_string(spend_amount),
+                                        )))
                                         .push(text::small(&format!(" {}", ctx.converter.unit)))
                                         .align_items(Align::Center),
                                 )
                                 .push(
                                     Row::new()
-                                        .push(text::small(&format!(
+                                        .push(text::small(&ctx.trf(
                                             "Fees: {}",
-                                            ctx.converter.converts(fees),
+                                            &[&ctx.converter.amount_string(fees)],
                                         )))
                                         .push(text::small(&format!(" {}", ctx.converter.unit)))
                                         .align_items(Align::Center),
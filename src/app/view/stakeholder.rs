@@ -1,14 +1,23 @@
 use iced::{
     scrollable,
     tooltip::{self, Tooltip},
-    Align, Column, Container, Element, Length, QRCode, Row,
+    Align, Checkbox, Column, Container, Element, Length, Row, Space,
 };
 
 use crate::{
-    app::{error::Error, menu::Menu, message::Message, view::Context},
+    app::{
+        budget::DelegationBudget,
+        error::Error,
+        menu::Menu,
+        message::{Message, SecureBatchMessage},
+        view::Context,
+    },
+    revault::TransactionKind,
+    revaultd::RevaultDError,
     ui::{
         component::{
-            button, card, scroll, separation, text, ContainerBackgroundStyle, TooltipStyle,
+            button, card, copy, qr::Qr, scroll, separation, text, ContainerBackgroundStyle,
+            TooltipStyle,
         },
         icon,
     },
@@ -17,85 +26,129 @@ use crate::{
 #[derive(Debug)]
 pub struct StakeholderCreateVaultsView {
     scroll: scrollable::State,
-    qr_code: Option<iced::qr_code::State>,
+    qr_code: Qr,
     close_button: iced::button::State,
     copy_button: iced::button::State,
+    selection_button: iced::button::State,
+    secure_batch_button: iced::button::State,
 }
 
 impl StakeholderCreateVaultsView {
     pub fn new() -> Self {
         StakeholderCreateVaultsView {
-            qr_code: None,
+            qr_code: Qr::new(),
             scroll: scrollable::State::new(),
             close_button: iced::button::State::new(),
             copy_button: iced::button::State::new(),
+            selection_button: iced::button::State::new(),
+            secure_batch_button: iced::button::State::new(),
         }
     }
 
     // Address is loaded directly in the view in order to cache the created qrcode.
     pub fn load(&mut self, address: &bitcoin::Address) {
-        self.qr_code = iced::qr_code::State::new(address.to_string()).ok();
+        self.qr_code.set(&address.to_string());
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn view<'a>(
         &'a mut self,
-        _ctx: &Context,
+        ctx: &Context,
         deposits: Vec<Element<'a, Message>>,
         address: Option<&bitcoin::Address>,
+        warning: Option<&Error>,
+        selecting: bool,
+        selected: usize,
+        loading: Option<(usize, usize)>,
     ) -> Element<'a, Message> {
         let mut content = Column::new()
             .max_width(800)
-            .push(text::bold(text::simple("Create some vaults")).size(50))
+            .push(text::bold(text::simple(ctx.tr("Create some vaults"))).size(50))
             .spacing(20);
 
+        if let Some(error) = warning {
+            content = content.push(card::alert_warning(Container::new(text::simple(&format!(
+                "{}",
+                error
+            )))))
+        }
+
         if !deposits.is_empty() {
+            let mut toolbar = Row::new()
+                .push(
+                    Container::new(text::simple(if selecting {
+                        ctx.tr(" Select the deposits to secure in one signing session:")
+                    } else {
+                        ctx.tr(" Click on a deposit to create a vault:")
+                    }))
+                    .width(Length::Fill),
+                )
+                .spacing(10)
+                .align_items(Align::Center);
+            if selecting {
+                let label = match loading {
+                    Some((fetched, total)) => ctx.trf("Loading {}/{}...", &[&fetched, &total]),
+                    None => ctx.trf("Secure {} vaults", &[&selected]),
+                };
+                let mut secure_button = button::primary(
+                    &mut self.secure_batch_button,
+                    button::button_content(None, &label),
+                );
+                if selected > 0 && loading.is_none() {
+                    secure_button =
+                        secure_button.on_press(Message::SecureBatch(SecureBatchMessage::Start));
+                }
+                toolbar = toolbar.push(secure_button).push(
+                    button::transparent(
+                        &mut self.selection_button,
+                        button::button_content(None, ctx.tr("Cancel selection")),
+                    )
+                    .on_press(Message::SecureBatch(SecureBatchMessage::ToggleSelection)),
+                );
+            } else {
+                toolbar = toolbar.push(
+                    button::transparent(
+                        &mut self.selection_button,
+                        button::button_content(None, ctx.tr("Select several")),
+                    )
+                    .on_press(Message::SecureBatch(SecureBatchMessage::ToggleSelection)),
+                );
+            }
             content = content.push(Container::new(
                 Column::new()
-                    .push(text::simple(" Click on a deposit to create a vault:"))
+                    .push(toolbar)
                     .push(Column::with_children(deposits).spacing(5))
                     .spacing(20),
             ))
         } else {
-            content = content.push(Container::new(text::simple("No deposits")).padding(5))
+            content = content.push(Container::new(text::simple(ctx.tr("No deposits"))).padding(5))
         }
 
-        if let Some(qr_code) = self.qr_code.as_mut() {
+        if let Some(qr_code) = self.qr_code.view(5) {
             if let Some(addr) = address {
                 content = content.push(separation().width(Length::Fill)).push(
                     card::white(Container::new(
                         Row::new()
                             .push(
                                 Column::new()
-                                    .push(text::simple(
+                                    .push(text::simple(ctx.tr(
                                         "Bitcoin deposits are needed in order to create a vault\n",
-                                    ))
+                                    )))
                                     .push(
                                         Column::new()
                                             .push(text::bold(text::simple(
-                                                "Please, use this deposit address:",
+                                                ctx.tr("Please, use this deposit address:"),
                                             )))
-                                            .push(
-                                                Row::new()
-                                                    .push(Container::new(text::bold(text::small(
-                                                        &addr.to_string(),
-                                                    ))))
-                                                    .push(
-                                                        button::clipboard(
-                                                            &mut self.copy_button,
-                                                            Message::Clipboard(addr.to_string()),
-                                                        )
-                                                        .width(Length::Shrink),
-                                                    )
-                                                    .align_items(Align::Center),
-                                            ),
+                                            .push(copy::copyable(
+                                                &mut self.copy_button,
+                                                text::bold(text::small(&addr.to_string())),
+                                                Message::Clipboard(addr.to_string()),
+                                            )),
                                     )
                                     .spacing(30)
                                     .width(Length::Fill),
                             )
-                            .push(
-                                Container::new(QRCode::new(qr_code).cell_size(5))
-                                    .width(Length::Shrink),
-                            )
+                            .push(Container::new(qr_code).width(Length::Shrink))
                             .spacing(10),
                     ))
                     .width(Length::Fill),
@@ -109,8 +162,8 @@ impl StakeholderCreateVaultsView {
                             Tooltip::new(
                                 Row::new()
                                     .push(icon::tooltip_icon().size(15))
-                                    .push(text::small(" Help")),
-                                "A vault is a deposit with revocation transactions\nsigned and shared between stakeholders",
+                                    .push(text::small(ctx.tr(" Help"))),
+                                ctx.tr("A vault is a deposit with revocation transactions\nsigned and shared between stakeholders"),
                                 tooltip::Position::Right,
                             )
                             .gap(5)
@@ -122,7 +175,7 @@ impl StakeholderCreateVaultsView {
                     Container::new(
                         button::cancel(
                             &mut self.close_button,
-                            Container::new(text::simple("X Close")).padding(10),
+                            Container::new(text::simple(ctx.tr("X Close"))).padding(10),
                         )
                         .on_press(Message::Menu(Menu::Home)),
                     )
@@ -139,6 +192,179 @@ impl StakeholderCreateVaultsView {
     }
 }
 
+/// selectable_deposit wraps a deposit list item with a checkbox
+/// to add it to the next batch of vaults to secure.
+pub fn selectable_deposit<'a>(
+    item: Element<'a, Message>,
+    outpoint: String,
+    selected: bool,
+) -> Element<'a, Message> {
+    Row::new()
+        .push(Checkbox::new(selected, "", move |checked| {
+            Message::SecureBatch(SecureBatchMessage::SelectVault(outpoint.clone(), checked))
+        }))
+        .push(Container::new(item).width(Length::Fill))
+        .spacing(10)
+        .align_items(Align::Center)
+        .into()
+}
+
+/// SecureBatchView guides the stakeholder through the signature of the
+/// revocation transactions of several deposits, vault after vault.
+#[derive(Debug)]
+pub struct SecureBatchView {
+    scroll: scrollable::State,
+    close_button: iced::button::State,
+    push_button: iced::button::State,
+}
+
+impl SecureBatchView {
+    pub fn new() -> Self {
+        SecureBatchView {
+            scroll: scrollable::State::new(),
+            close_button: iced::button::State::new(),
+            push_button: iced::button::State::new(),
+        }
+    }
+
+    /// `vaults` are the outpoints of the batch with their number of signed
+    /// transactions, `current` is the index of the vault being signed and
+    /// `signer` is None once every transaction is signed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        vaults: &[(String, usize)],
+        current: usize,
+        transaction_kind: Option<TransactionKind>,
+        signer: Option<Element<'a, SecureBatchMessage>>,
+        pushing: bool,
+        results: Option<&Vec<(String, Result<(), RevaultDError>)>>,
+    ) -> Element<'a, SecureBatchMessage> {
+        let mut col = Column::new()
+            .push(text::bold(text::simple(&ctx.trf("Secure {} vaults", &[&vaults.len()]))).size(50))
+            .spacing(20);
+
+        let mut list = Column::new().spacing(5);
+        for (i, (outpoint, signed)) in vaults.iter().enumerate() {
+            let failure = results.and_then(|results| {
+                results
+                    .iter()
+                    .find(|(o, _)| o == outpoint)
+                    .and_then(|(_, res)| res.as_ref().err())
+            });
+            let status = if let Some(e) = failure {
+                text::danger(text::small(&format!("{}", e)))
+            } else if results.is_some() {
+                text::success(text::small(ctx.tr("Secured")))
+            } else if *signed == 3 {
+                text::success(text::small(ctx.tr("Signed")))
+            } else {
+                text::small(&ctx.trf("{}/3 signed", &[&signed]))
+            };
+            let row = Row::new()
+                .push(if *signed == 3 && failure.is_none() {
+                    text::success(icon::shield_check_icon())
+                } else {
+                    icon::shield_icon()
+                })
+                .push(Container::new(text::small(outpoint)).width(Length::Fill))
+                .push(status)
+                .spacing(10)
+                .align_items(Align::Center);
+            list = list.push(if i == current && signer.is_some() {
+                card::border_primary(Container::new(row))
+            } else {
+                card::white(Container::new(row))
+            });
+        }
+        col = col.push(list);
+
+        if let Some(results) = results {
+            let secured = results.iter().filter(|(_, res)| res.is_ok()).count();
+            col = col.push(if secured == results.len() {
+                card::success(Container::new(text::success(text::simple(&ctx.trf("The {} vaults are secured", &[&secured])))))
+            } else {
+                card::alert_warning(Container::new(text::simple(&ctx.trf("{} of {} vaults are secured, the others can be secured again from the deposit list", &[&secured, &results.len()]))))
+            });
+        } else if let Some(signer) = signer {
+            col = col
+                .push(text::bold(text::simple(&ctx.trf(
+                    "Vault {}/{}: sign the {} transaction",
+                    &[
+                        &(current + 1),
+                        &vaults.len(),
+                        &ctx.tr(match transaction_kind {
+                            Some(TransactionKind::EmergencyUnvault) => "emergency unvault",
+                            Some(TransactionKind::Cancel) => "cancel",
+                            _ => "emergency",
+                        }),
+                    ],
+                ))))
+                .push(signer);
+        } else {
+            let mut push_button = button::primary(
+                &mut self.push_button,
+                button::button_content(
+                    None,
+                    &if pushing {
+                        ctx.tr("Sharing the signatures...").to_string()
+                    } else {
+                        ctx.trf("Share the signatures of the {} vaults", &[&vaults.len()])
+                    },
+                ),
+            );
+            if !pushing {
+                push_button = push_button.on_press(SecureBatchMessage::Push);
+            }
+            col = col.push(
+                card::white(Container::new(
+                    Column::new()
+                        .push(text::simple(
+                            ctx.tr("All the revocation transactions are signed.\nThe signatures are shared with the other stakeholders at once."),
+                        ))
+                        .push(push_button)
+                        .spacing(20),
+                ))
+                .width(Length::Fill),
+            );
+        }
+
+        let mut close_button = button::cancel(
+            &mut self.close_button,
+            Container::new(text::simple(if results.is_some() {
+                ctx.tr("Done")
+            } else {
+                ctx.tr("X Cancel")
+            }))
+            .padding(10),
+        );
+        if !pushing {
+            close_button = close_button.on_press(SecureBatchMessage::Close);
+        }
+
+        let modal = Column::new()
+            .push(
+                Row::new()
+                    .push(Space::with_width(Length::Fill))
+                    .push(Container::new(close_button).width(Length::Shrink)),
+            )
+            .push(
+                Container::new(col.max_width(800))
+                    .width(Length::Fill)
+                    .align_x(Align::Center),
+            )
+            .spacing(50);
+
+        Container::new(scroll(&mut self.scroll, Container::new(modal)))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(ContainerBackgroundStyle)
+            .padding(20)
+            .into()
+    }
+}
+
 #[derive(Debug)]
 pub struct StakeholderDelegateFundsView {
     scroll: scrollable::State,
@@ -158,6 +384,7 @@ impl StakeholderDelegateFundsView {
         ctx: &Context,
         active_balance: &u64,
         activating_balance: &u64,
+        budget: Option<&DelegationBudget>,
         vaults: Vec<Element<'a, Message>>,
         warning: Option<&Error>,
     ) -> Element<'a, Message> {
@@ -172,7 +399,10 @@ impl StakeholderDelegateFundsView {
         col = col
             .push(
                 Column::new()
-                    .push(text::bold(text::simple("Delegate funds to your manager team")).size(50))
+                    .push(
+                        text::bold(text::simple(ctx.tr("Delegate funds to your manager team")))
+                            .size(50),
+                    )
                     .spacing(5),
             )
             .push(
@@ -181,14 +411,13 @@ impl StakeholderDelegateFundsView {
                         Row::new()
                             .push(
                                 text::bold(text::simple(
-                                    &ctx.converter.converts(*active_balance).to_string(),
+                                    &ctx.converter.amount_string(*active_balance),
                                 ))
                                 .size(30),
                             )
-                            .push(text::simple(&format!(
-                                " {} are allocated to managers",
-                                ctx.converter.unit
-                            )))
+                            .push(text::simple(
+                                &ctx.trf(" {} are allocated to managers", &[&ctx.converter.unit]),
+                            ))
                             .align_items(Align::Center),
                     )
                     .push(
@@ -196,28 +425,42 @@ impl StakeholderDelegateFundsView {
                             .push(
                                 text::bold(text::simple(&format!(
                                     "+ {}",
-                                    ctx.converter.converts(*activating_balance)
+                                    ctx.converter.amount_string(*activating_balance)
                                 )))
                                 .size(20),
                             )
-                            .push(text::simple(&format!(
+                            .push(text::simple(&ctx.trf(
                                 " {} are waiting for other stakeholders' approval",
-                                ctx.converter.unit
+                                &[&ctx.converter.unit],
                             )))
                             .align_items(Align::Center),
                     ),
             );
+
+        if let Some(budget) = budget {
+            col = col.push(text::simple(&ctx.trf(
+                "{} {} left of the {} {} delegation budget",
+                &[
+                    &ctx.converter.amount_string(budget.remaining()),
+                    &ctx.converter.unit,
+                    &ctx.converter.amount_string(budget.max),
+                    &ctx.converter.unit,
+                ],
+            )));
+        }
         if !vaults.is_empty() {
             col = col.push(Container::new(
                 Column::new()
-                    .push(text::simple(" Click on the vaults to delegate:"))
+                    .push(text::simple(ctx.tr(" Click on the vaults to delegate:")))
                     .push(Column::with_children(vaults).spacing(5))
                     .spacing(20),
             ))
         } else {
             col = col.push(
-                Container::new(text::simple("No more funds can be delegated to managers"))
-                    .padding(5),
+                Container::new(text::simple(
+                    ctx.tr("No more funds can be delegated to managers"),
+                ))
+                .padding(5),
             )
         }
 
@@ -229,8 +472,8 @@ impl StakeholderDelegateFundsView {
                             Tooltip::new(
                                 Row::new()
                                     .push(icon::tooltip_icon().size(15))
-                                    .push(text::small(" Help")),
-                                "By delegating you allow managers to spend the funds,\n but you can still revert any undesired transaction.",
+                                    .push(text::small(ctx.tr(" Help"))),
+                                ctx.tr("By delegating you allow managers to spend the funds,\n but you can still revert any undesired transaction."),
                                 tooltip::Position::Right,
                             )
                             .gap(5)
@@ -244,7 +487,7 @@ impl StakeholderDelegateFundsView {
                         Container::new(
                             button::cancel(
                                 &mut self.close_button,
-                                Container::new(text::simple("X Close")).padding(10),
+                                Container::new(text::simple(ctx.tr("X Close"))).padding(10),
                             )
                             .on_press(Message::Menu(Menu::Home)),
                         )
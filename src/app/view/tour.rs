@@ -0,0 +1,109 @@
+use iced::{Align, Column, Container, Element, Length, Row};
+
+use crate::{
+    app::{
+        message::{Message, TourMessage},
+        tour::{TourStep, TourTarget},
+        view::Context,
+    },
+    ui::component::{button, card, text},
+};
+
+/// TourView is the callout of the current step of the guided tour,
+/// displayed above the panel.
+#[derive(Debug)]
+pub struct TourView {
+    previous_button: iced::button::State,
+    next_button: iced::button::State,
+    dismiss_button: iced::button::State,
+}
+
+impl TourView {
+    pub fn new() -> Self {
+        TourView {
+            previous_button: iced::button::State::new(),
+            next_button: iced::button::State::new(),
+            dismiss_button: iced::button::State::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        step: &TourStep,
+        index: usize,
+        total: usize,
+    ) -> Element<'a, Message> {
+        let mut buttons = Row::new().spacing(10).align_items(Align::Center).push(
+            button::transparent(
+                &mut self.dismiss_button,
+                button::button_content(None, ctx.tr("Skip the tour")),
+            )
+            .on_press(Message::Tour(TourMessage::Dismiss)),
+        );
+        if index > 0 {
+            buttons = buttons.push(
+                button::transparent(
+                    &mut self.previous_button,
+                    button::button_content(None, ctx.tr("Previous")),
+                )
+                .on_press(Message::Tour(TourMessage::Previous)),
+            );
+        }
+        buttons = buttons.push(
+            button::primary(
+                &mut self.next_button,
+                button::button_content(
+                    None,
+                    if index + 1 < total {
+                        ctx.tr("Next")
+                    } else {
+                        ctx.tr("Done")
+                    },
+                ),
+            )
+            .on_press(Message::Tour(TourMessage::Next)),
+        );
+
+        card::white(Container::new(
+            Row::new()
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple(&format!(
+                            "{} ({}/{})",
+                            ctx.tr(&step.title),
+                            index + 1,
+                            total
+                        ))))
+                        .push(text::small(&format!(
+                            "{} {}",
+                            ctx.tr(location(step.target)),
+                            ctx.tr(&step.text)
+                        )))
+                        .spacing(5)
+                        .width(Length::Fill),
+                )
+                .push(buttons)
+                .spacing(20)
+                .align_items(Align::Center),
+        ))
+        .width(Length::Fill)
+        .into()
+    }
+}
+
+/// location tells the user where the highlighted target is.
+fn location(target: TourTarget) -> &'static str {
+    match target {
+        TourTarget::RolePicker => "At the top of the sidebar:",
+        TourTarget::Home => "Sidebar, Home:",
+        TourTarget::Vaults => "Sidebar, Vaults:",
+        TourTarget::Deposit => "Sidebar, Deposit:",
+        TourTarget::Send => "Sidebar, Send:",
+        TourTarget::CreateVaults => "Sidebar, Create vault:",
+        TourTarget::DelegateFunds => "Sidebar, Delegate funds:",
+        TourTarget::Emergency => "Sidebar, Emergency:",
+        TourTarget::Network => "Sidebar, Network:",
+        TourTarget::Settings => "Sidebar, Settings:",
+    }
+}
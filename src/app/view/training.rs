@@ -0,0 +1,17 @@
+use iced::{Align, Container, Element, Length};
+
+use crate::{
+    app::{message::Message, view::Context},
+    ui::component::{card, text},
+};
+
+/// watermark is the banner displayed on every screen of the training profile,
+/// so that a training session is never mistaken for a real one.
+pub fn watermark<'a>(ctx: &Context) -> Element<'a, Message> {
+    card::border_primary(Container::new(text::bold(text::simple(ctx.tr(
+        "TRAINING - regtest sandbox, the funds and the actions have no real value",
+    )))))
+    .width(Length::Fill)
+    .align_x(Align::Center)
+    .into()
+}
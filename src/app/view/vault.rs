@@ -1,21 +1,31 @@
 use bitcoin::util::psbt::PartiallySignedTransaction as Psbt;
-use chrono::NaiveDateTime;
-use iced::{scrollable, Align, Column, Container, Element, Length, Row};
+use chrono::{NaiveDateTime, Utc};
+use iced::{
+    pick_list, scrollable, text_input, Align, Column, Container, Element, Length, PickList,
+    ProgressBar, Row, TextInput,
+};
 
 use crate::{
     app::{
+        actions::Action,
+        confirm, deadline,
         error::Error,
-        message::{Message, SignMessage, VaultMessage},
+        journal::Event,
+        message::{Message, NoteMessage, SignMessage, VaultLabelMessage, VaultMessage},
+        notes::{Note, TRIGGER_STATUSES},
         view::Context,
     },
     ui::{
-        component::{badge, button, card, scroll, separation, text, ContainerBackgroundStyle},
-        icon,
+        component::{
+            badge, button, card, copy, scroll, separation, text, ContainerBackgroundStyle,
+        },
+        icon, theme,
     },
 };
 
 use crate::{
-    revault::Role,
+    conversion,
+    revault::{Role, TransactionKind},
     revaultd::model::{BroadcastedTransaction, Vault, VaultStatus, VaultTransactions},
 };
 
@@ -40,6 +50,7 @@ impl VaultModal {
         ctx: &Context,
         vlt: &Vault,
         warning: Option<&Error>,
+        actions: &[Action],
         panel_title: &str,
         panel: Element<'a, Message>,
     ) -> Element<'a, Message> {
@@ -56,7 +67,7 @@ impl VaultModal {
             Container::new(
                 button::cancel(
                     &mut self.cancel_button,
-                    Container::new(text::simple("X Close")).padding(10),
+                    Container::new(text::simple(ctx.tr("X Close"))).padding(10),
                 )
                 .on_press(Message::Vault(vlt.outpoint(), VaultMessage::Select)),
             )
@@ -76,6 +87,7 @@ impl VaultModal {
                                         .align_x(Align::Center),
                                 )
                                 .push(Container::new(vault(ctx, &mut self.copy_button, vlt)))
+                                .push(actions_card(ctx, actions))
                                 .push(Container::new(panel))
                                 .spacing(20),
                         )
@@ -114,23 +126,19 @@ fn vault<'a>(
                                 .push(vault_badge(&vlt))
                                 .push(
                                     Column::new()
-                                        .push(
-                                            Row::new()
-                                                .push(text::bold(text::simple(&vlt.txid)))
-                                                .push(button::clipboard(
-                                                    copy_button,
-                                                    Message::Clipboard(vlt.txid.to_string()),
-                                                ))
-                                                .align_items(Align::Center),
-                                        )
-                                        .push(text::simple(&format!(
+                                        .push(copy::copyable(
+                                            copy_button,
+                                            text::bold(text::simple(&vlt.outpoint())),
+                                            Message::Clipboard(vlt.outpoint()),
+                                        ))
+                                        .push(text::simple(&ctx.trf(
                                             "received at {}",
-                                            NaiveDateTime::from_timestamp(vlt.received_at, 0)
+                                            &[&ctx.converter.date_string(vlt.received_at)],
                                         )))
                                         .push(text::simple(&format!(
                                             "{} ( {} )",
                                             &vlt.status,
-                                            NaiveDateTime::from_timestamp(vlt.updated_at, 0)
+                                            ctx.converter.date_string(vlt.updated_at)
                                         ))),
                                 )
                                 .align_items(Align::Center)
@@ -141,10 +149,9 @@ fn vault<'a>(
                     .push(
                         Container::new(
                             Row::new()
-                                .push(text::bold(text::simple(&format!(
-                                    "{}",
-                                    ctx.converter.converts(vlt.amount),
-                                ))))
+                                .push(text::bold(text::simple(
+                                    &ctx.converter.amount_string(vlt.amount),
+                                )))
                                 .push(text::simple(&ctx.converter.unit.to_string())),
                         )
                         .width(Length::Shrink),
@@ -156,6 +163,412 @@ fn vault<'a>(
     ))
 }
 
+/// Lists the actions performed on the vault from this GUI,
+/// the card is empty if there is none.
+fn actions_card<'a>(ctx: &Context, actions: &[Action]) -> Container<'a, Message> {
+    if actions.is_empty() {
+        return Container::new(Column::new());
+    }
+    let mut col = Column::new()
+        .push(text::bold(text::simple(ctx.tr("Actions from this GUI"))))
+        .spacing(10);
+    for action in actions {
+        col = col.push(
+            Row::new()
+                .push(
+                    Container::new(text::small(&format!(
+                        "{}",
+                        NaiveDateTime::from_timestamp(action.date, 0)
+                    )))
+                    .width(Length::Units(200)),
+                )
+                .push(text::small(&action.kind.to_string())),
+        );
+    }
+    card::simple(Container::new(col))
+}
+
+/// OverBudgetView warns that delegating the vault exceeds the delegation
+/// budget, the user can delegate it anyway.
+#[derive(Debug, Default)]
+pub struct OverBudgetView {
+    override_button: iced::button::State,
+}
+
+impl OverBudgetView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        vlt: &Vault,
+        excess: u64,
+    ) -> Container<'a, Message> {
+        card::alert_warning(Container::new(
+            Row::new()
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple(
+                            ctx.tr("Delegating this vault exceeds the delegation budget"),
+                        )))
+                        .push(text::small(&ctx.trf(
+                            "{} {} above the budget agreed by the stakeholders",
+                            &[&ctx.converter.amount_string(excess), &ctx.converter.unit],
+                        )))
+                        .width(Length::Fill),
+                )
+                .push(
+                    button::important(
+                        &mut self.override_button,
+                        button::button_content(None, ctx.tr("Delegate anyway")),
+                    )
+                    .on_press(Message::Vault(vlt.outpoint(), VaultMessage::OverrideBudget)),
+                )
+                .spacing(20)
+                .align_items(Align::Center),
+        ))
+    }
+}
+
+/// SignaturesPollingView displays the checks of a vault waiting for the
+/// signatures of the other participants. revaultd fetches them from the
+/// coordinator in the background and does not report its polling, the user
+/// can only ask revaultd again for the state of the vault.
+#[derive(Debug, Default)]
+pub struct SignaturesPollingView {
+    check_button: iced::button::State,
+}
+
+impl SignaturesPollingView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        vlt: &Vault,
+        last_check: Option<i64>,
+        failures: u32,
+        checking: bool,
+    ) -> Container<'a, Message> {
+        let mut col = Column::new()
+            .push(text::bold(text::simple(&ctx.trf(
+                "Waiting for the signatures of the other participants since {}",
+                &[&NaiveDateTime::from_timestamp(vlt.updated_at, 0)],
+            ))))
+            .push(text::small(ctx.tr(
+                "revaultd fetches the signatures from the coordinator in the background.",
+            )))
+            .spacing(10);
+        if let Some(last_check) = last_check {
+            col = col.push(text::small(&ctx.trf(
+                "Last checked at {}",
+                &[&NaiveDateTime::from_timestamp(last_check, 0)],
+            )));
+        }
+        if failures > 0 {
+            col = col.push(text::small(&ctx.trf(
+                if failures > 1 {
+                    "{} failed checks in a row"
+                } else {
+                    "{} failed check in a row"
+                },
+                &[&failures],
+            )));
+        }
+        let mut check_button = button::primary(
+            &mut self.check_button,
+            button::button_content(
+                None,
+                if checking {
+                    ctx.tr("Checking...")
+                } else {
+                    ctx.tr("Check now")
+                },
+            ),
+        );
+        if !checking {
+            check_button = check_button.on_press(Message::Vault(
+                vlt.outpoint(),
+                VaultMessage::CheckSignatures,
+            ));
+        }
+        card::simple(Container::new(
+            Row::new()
+                .push(col.width(Length::Fill))
+                .push(check_button)
+                .align_items(Align::Center)
+                .spacing(20),
+        ))
+    }
+}
+
+/// UnvaultCountdownView displays the blocks left before the spend of a vault
+/// being unvaulted is valid: the managers wait for them, the stakeholders
+/// can cancel the unvault until then.
+#[derive(Debug, Default)]
+pub struct UnvaultCountdownView {}
+
+impl UnvaultCountdownView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        unvault: Option<&BroadcastedTransaction>,
+        csv: u32,
+    ) -> Container<'a, Message> {
+        let confirmed = unvault.map(|tx| tx.blockheight.is_some()).unwrap_or(false);
+        let remaining = deadline::remaining_blocks(unvault, ctx.blockheight, csv);
+        let title = if !confirmed {
+            ctx.trf("The unvault transaction is not confirmed yet, the {} blocks of the delay start at its confirmation", &[&csv])
+        } else if remaining == 0 {
+            match ctx.role {
+                Role::Manager => ctx
+                    .tr("The delay expired, the spend transaction is valid")
+                    .to_string(),
+                Role::Stakeholder => ctx
+                    .tr("The delay expired, the vault can be spent")
+                    .to_string(),
+            }
+        } else {
+            match ctx.role {
+                Role::Manager => ctx.trf(
+                    "The spend transaction is valid in {} blocks ({})",
+                    &[
+                        &remaining,
+                        &conversion::blocks_duration(remaining, ctx.converter.language),
+                    ],
+                ),
+                Role::Stakeholder => ctx.trf(
+                    "{} blocks ({}) left to cancel the unvault",
+                    &[
+                        &remaining,
+                        &conversion::blocks_duration(remaining, ctx.converter.language),
+                    ],
+                ),
+            }
+        };
+        let mut col = Column::new()
+            .push(text::bold(text::simple(&title)))
+            .push(
+                ProgressBar::new(0.0..=csv as f32, (csv - remaining) as f32)
+                    .height(Length::Units(10)),
+            )
+            .spacing(10);
+        if confirmed {
+            col = col.push(text::small(&ctx.trf(
+                "{} of {} blocks elapsed at block {}",
+                &[&(csv - remaining), &csv, &ctx.blockheight],
+            )));
+        }
+        card::simple(Container::new(col))
+    }
+}
+
+/// VaultNotesView lists the notes attached to the vault and adds a note
+/// surfaced once the vault reaches the selected status.
+#[derive(Debug, Default)]
+pub struct VaultNotesView {
+    text_input: text_input::State,
+    status_list: pick_list::State<VaultStatus>,
+    add_button: iced::button::State,
+    delete_buttons: Vec<iced::button::State>,
+}
+
+impl VaultNotesView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        vlt: &Vault,
+        notes: &[Note],
+        text: &str,
+        status: &VaultStatus,
+    ) -> Container<'a, Message> {
+        let outpoint = vlt.outpoint();
+        let mut col = Column::new()
+            .push(text::bold(text::simple(ctx.tr("Notes"))))
+            .spacing(10);
+        self.delete_buttons
+            .resize_with(notes.len(), iced::button::State::new);
+        for (note, delete_button) in notes.iter().zip(self.delete_buttons.iter_mut()) {
+            let when = match note.triggered_at {
+                Some(date) => ctx.trf(
+                    "{} since {}",
+                    &[&note.status, &NaiveDateTime::from_timestamp(date, 0)],
+                ),
+                None => ctx.trf("When the vault is {}", &[&note.status]),
+            };
+            col = col.push(
+                Row::new()
+                    .push(
+                        Column::new()
+                            .push(text::small(&note.text))
+                            .push(text::small(&when).color(theme::palette().muted))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        button::transparent(
+                            delete_button,
+                            button::button_content(None, ctx.tr("Delete")),
+                        )
+                        .on_press(Message::Vault(
+                            outpoint.clone(),
+                            VaultMessage::Note(NoteMessage::Delete(note.id)),
+                        )),
+                    )
+                    .align_items(Align::Center),
+            );
+        }
+
+        let edit_outpoint = outpoint.clone();
+        let select_outpoint = outpoint.clone();
+        let mut add_button = button::primary(
+            &mut self.add_button,
+            button::button_content(None, ctx.tr("Add")),
+        );
+        if !text.trim().is_empty() {
+            add_button = add_button.on_press(Message::Vault(
+                outpoint,
+                VaultMessage::Note(NoteMessage::Add),
+            ));
+        }
+        col = col.push(
+            Row::new()
+                .push(
+                    TextInput::new(&mut self.text_input, ctx.tr("Note"), text, move |text| {
+                        Message::Vault(
+                            edit_outpoint.clone(),
+                            VaultMessage::Note(NoteMessage::TextEdited(text)),
+                        )
+                    })
+                    .size(15)
+                    .padding(10)
+                    .width(Length::Fill),
+                )
+                .push(text::small(ctx.tr("when the vault is")))
+                .push(
+                    PickList::new(
+                        &mut self.status_list,
+                        &TRIGGER_STATUSES[..],
+                        Some(status.clone()),
+                        move |status| {
+                            Message::Vault(
+                                select_outpoint.clone(),
+                                VaultMessage::Note(NoteMessage::StatusSelected(status)),
+                            )
+                        },
+                    )
+                    .text_size(15)
+                    .padding(10),
+                )
+                .push(add_button)
+                .spacing(10)
+                .align_items(Align::Center),
+        );
+        card::simple(Container::new(col))
+    }
+}
+
+/// vault_timeline displays the events of the vault, the oldest at the top.
+pub fn vault_timeline<'a>(ctx: &Context, events: &[Event]) -> Container<'a, Message> {
+    let mut col = Column::new()
+        .push(text::bold(text::simple(ctx.tr("Timeline"))))
+        .spacing(10);
+    if events.is_empty() {
+        col = col.push(text::small(ctx.tr("No event recorded yet")));
+    }
+    for event in events {
+        col = col.push(
+            Row::new()
+                .push(icon::dot_icon().size(10).color(theme::palette().muted))
+                .push(
+                    Column::new()
+                        .push(text::bold(text::small(&format!(
+                            "{} {}",
+                            NaiveDateTime::from_timestamp(event.time, 0),
+                            event.title
+                        ))))
+                        .push(text::small(&event.detail).color(theme::palette().muted)),
+                )
+                .spacing(10)
+                .align_items(Align::Center),
+        );
+    }
+    card::simple(Container::new(col))
+}
+
+/// VaultLabelView edits the label of the vault, the label is kept
+/// locally and displayed in the vaults list and in the spend inputs.
+#[derive(Debug, Default)]
+pub struct VaultLabelView {
+    text_input: text_input::State,
+    save_button: iced::button::State,
+}
+
+impl VaultLabelView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        ctx: &Context,
+        vlt: &Vault,
+        label: Option<&String>,
+        text: &str,
+    ) -> Container<'a, Message> {
+        let outpoint = vlt.outpoint();
+        let edit_outpoint = outpoint.clone();
+        let mut save_button = button::primary(
+            &mut self.save_button,
+            button::button_content(None, ctx.tr("Save")),
+        );
+        if label.map(|l| l.as_str()).unwrap_or("") != text.trim() {
+            save_button = save_button.on_press(Message::Vault(
+                outpoint,
+                VaultMessage::Label(VaultLabelMessage::Save),
+            ));
+        }
+        card::simple(Container::new(
+            Column::new()
+                .push(text::bold(text::simple(ctx.tr("Label"))))
+                .push(
+                    Row::new()
+                        .push(
+                            TextInput::new(
+                                &mut self.text_input,
+                                ctx.tr("Label, e.g. Q3 payroll buffer"),
+                                text,
+                                move |text| {
+                                    Message::Vault(
+                                        edit_outpoint.clone(),
+                                        VaultMessage::Label(VaultLabelMessage::Edited(text)),
+                                    )
+                                },
+                            )
+                            .size(15)
+                            .padding(10)
+                            .width(Length::Fill),
+                        )
+                        .push(save_button)
+                        .spacing(10)
+                        .align_items(Align::Center),
+                )
+                .spacing(10),
+        ))
+    }
+}
+
 /// This panel is the default view of a vault.
 /// It lists the onchain transactions and suggest a call to action to
 /// the user according to the vault status:
@@ -189,7 +602,7 @@ impl VaultOnChainTransactionsPanel {
                         Row::new()
                             .push(
                                 Container::new(text::simple(
-                                    "Do you want to create a vault from this deposit?",
+                                    ctx.tr("Do you want to create a vault from this deposit?"),
                                 ))
                                 .width(Length::Fill),
                             )
@@ -197,7 +610,7 @@ impl VaultOnChainTransactionsPanel {
                                 Container::new(
                                     button::important(
                                         &mut self.action_button,
-                                        button::button_content(None, "Create vault"),
+                                        button::button_content(None, ctx.tr("Create vault")),
                                     )
                                     .on_press(Message::Vault(
                                         vault.outpoint(),
@@ -214,7 +627,7 @@ impl VaultOnChainTransactionsPanel {
                         Row::new()
                             .push(
                                 Container::new(text::simple(
-                                    "Do you want to delegate vault to manager ? ",
+                                    ctx.tr("Do you want to delegate vault to manager ? "),
                                 ))
                                 .width(Length::Fill),
                             )
@@ -222,12 +635,9 @@ impl VaultOnChainTransactionsPanel {
                                 Container::new(
                                     button::important(
                                         &mut self.action_button,
-                                        button::button_content(None, "Delegate vault"),
+                                        button::button_content(None, ctx.tr("Delegate vault")),
                                     )
-                                    .on_press(Message::Vault(
-                                        vault.outpoint(),
-                                        VaultMessage::Delegate,
-                                    )),
+                                    .on_press(confirm::delegate(vault.outpoint()).ask()),
                                 )
                                 .width(Length::Shrink),
                             )
@@ -239,7 +649,7 @@ impl VaultOnChainTransactionsPanel {
                         Row::new()
                             .push(
                                 Container::new(text::simple(
-                                    "Funds are moving, do you want to revault them?",
+                                    ctx.tr("Funds are moving, do you want to revault them?"),
                                 ))
                                 .width(Length::Fill),
                             )
@@ -247,12 +657,9 @@ impl VaultOnChainTransactionsPanel {
                                 Container::new(
                                     button::primary(
                                         &mut self.action_button,
-                                        button::button_content(None, "Revault"),
+                                        button::button_content(None, ctx.tr("Revault")),
                                     )
-                                    .on_press(Message::Vault(
-                                        vault.outpoint(),
-                                        VaultMessage::SelectRevault,
-                                    )),
+                                    .on_press(confirm::revault(vault.outpoint()).ask()),
                                 )
                                 .width(Length::Shrink),
                             )
@@ -267,7 +674,7 @@ impl VaultOnChainTransactionsPanel {
                 Row::new()
                     .push(
                         Container::new(text::simple(
-                            "Funds are moving, do you want to revault them?",
+                            ctx.tr("Funds are moving, do you want to revault them?"),
                         ))
                         .width(Length::Fill),
                     )
@@ -275,12 +682,9 @@ impl VaultOnChainTransactionsPanel {
                         Container::new(
                             button::primary(
                                 &mut self.action_button,
-                                button::button_content(None, "Revault"),
+                                button::button_content(None, ctx.tr("Revault")),
                             )
-                            .on_press(Message::Vault(
-                                vault.outpoint(),
-                                VaultMessage::SelectRevault,
-                            )),
+                            .on_press(confirm::revault(vault.outpoint()).ask()),
                         )
                         .width(Length::Shrink),
                     )
@@ -288,11 +692,14 @@ impl VaultOnChainTransactionsPanel {
             )))
         }
 
+        col = col.push(transaction_chain(ctx, vault, txs));
         col = col.push(Container::new(text::bold(text::simple(
-            "Onchain transactions:",
+            ctx.tr("Onchain transactions:"),
         ))));
         if let Some(tx) = &txs.spend {
-            col = col.push(transaction(ctx, "Spend transaction", &tx));
+            col = col
+                .push(transaction(ctx, "Spend transaction", &tx))
+                .push(spend_finality(ctx, tx));
         }
         if let Some(tx) = &txs.cancel {
             col = col.push(transaction(ctx, "Cancel transaction", &tx));
@@ -324,7 +731,8 @@ fn transaction<'a, T: 'a>(
                     .push(
                         Row::new()
                             .push(
-                                Container::new(text::bold(text::simple(title))).width(Length::Fill),
+                                Container::new(text::bold(text::simple(ctx.tr(title))))
+                                    .width(Length::Fill),
                             )
                             .push(
                                 Container::new(text::bold(text::small(
@@ -333,15 +741,15 @@ fn transaction<'a, T: 'a>(
                                 .width(Length::Shrink),
                             ),
                     )
-                    .push(text::small(&format!(
+                    .push(text::small(&ctx.trf(
                         "Received at {}",
-                        NaiveDateTime::from_timestamp(transaction.received_at, 0)
+                        &[&NaiveDateTime::from_timestamp(transaction.received_at, 0)],
                     )))
                     .push(text::small(
                         &if let Some(blockheight) = &transaction.blockheight {
-                            format!("Blockheight: {}", blockheight)
+                            ctx.trf("Blockheight: {}", &[&blockheight])
                         } else {
-                            "Not in a block".to_string()
+                            ctx.tr("Not in a block").to_string()
                         },
                     )),
             )
@@ -359,7 +767,7 @@ fn input_and_outputs<'a, T: 'a>(
     broadcasted: &BroadcastedTransaction,
 ) -> Container<'a, T> {
     let mut col_input = Column::new()
-        .push(text::bold(text::simple("Inputs")))
+        .push(text::bold(text::simple(ctx.tr("Inputs"))))
         .spacing(10);
     for input in &broadcasted.tx.input {
         col_input = col_input
@@ -373,7 +781,7 @@ fn input_and_outputs<'a, T: 'a>(
             .width(Length::FillPortion(1));
     }
     let mut col_output = Column::new()
-        .push(text::bold(text::simple("Outputs")))
+        .push(text::bold(text::simple(ctx.tr("Outputs"))))
         .spacing(10);
     for output in &broadcasted.tx.output {
         let addr = bitcoin::Address::from_script(&output.script_pubkey, ctx.network);
@@ -386,7 +794,7 @@ fn input_and_outputs<'a, T: 'a>(
         col_output = col_output
             .push(
                 card::simple(Container::new(col.push(text::bold(text::small(
-                    &ctx.converter.converts(output.value).to_string(),
+                    &ctx.converter.amount_string(output.value),
                 )))))
                 .width(Length::Fill),
             )
@@ -395,6 +803,132 @@ fn input_and_outputs<'a, T: 'a>(
     Container::new(Row::new().push(col_input).push(col_output).spacing(20))
 }
 
+/// transaction_chain renders the pre-signed transactions of the vault,
+/// from the deposit to the transaction moving its funds, with the state
+/// of each of them.
+fn transaction_chain<'a, T: 'a>(
+    ctx: &Context,
+    vault: &Vault,
+    txs: &VaultTransactions,
+) -> Container<'a, T> {
+    let node = |depth: u16,
+                name: &str,
+                kind: Option<TransactionKind>,
+                tx: Option<&BroadcastedTransaction>| {
+        let state = match tx {
+            Some(tx) if tx.blockheight.is_some() && ctx.blockheight != 0 => {
+                let confirmations = tx.confirmations(ctx.blockheight);
+                ctx.trf(
+                    if confirmations > 1 {
+                        "{} confirmations"
+                    } else {
+                        "{} confirmation"
+                    },
+                    &[&confirmations],
+                )
+            }
+            Some(tx) if tx.blockheight.is_some() => ctx.tr("Confirmed").to_string(),
+            Some(_) => ctx.tr("Broadcast, not confirmed yet").to_string(),
+            None => kind
+                .map(|kind| presigned_state(vault, kind))
+                .unwrap_or("Not created")
+                .to_string(),
+        };
+        let mut col = Column::new()
+            .push(text::bold(text::small(ctx.tr(name))))
+            .push(text::small(ctx.tr(&state)));
+        if let Some(tx) = tx {
+            if let Some(feerate) = txs.feerate(&tx.tx) {
+                col = col.push(text::small(&ctx.trf("{} sat/vbyte", &[&feerate])));
+            }
+            col = col.push(text::small(&tx.tx.txid().to_string()));
+        }
+        let content = Container::new(col.spacing(5)).width(Length::Fill);
+        Row::new()
+            .push(
+                Container::new(text::simple(if depth == 0 { "" } else { "->" }))
+                    .width(Length::Units(40 * depth)),
+            )
+            .push(if tx.is_some() {
+                card::simple(content)
+            } else {
+                card::grey(content)
+            })
+            .align_items(Align::Center)
+    };
+    card::white(Container::new(
+        Column::new()
+            .push(text::bold(text::simple(ctx.tr("Transaction chain:"))))
+            .push(node(0, "Deposit", None, Some(&txs.deposit)))
+            .push(node(
+                1,
+                "Unvault",
+                Some(TransactionKind::Unvault),
+                txs.unvault.as_ref(),
+            ))
+            .push(node(2, "Spend", None, txs.spend.as_ref()))
+            .push(node(
+                2,
+                "Cancel",
+                Some(TransactionKind::Cancel),
+                txs.cancel.as_ref(),
+            ))
+            .push(node(
+                2,
+                "Unvault Emergency",
+                Some(TransactionKind::EmergencyUnvault),
+                txs.unvault_emergency.as_ref(),
+            ))
+            .push(node(
+                1,
+                "Emergency",
+                Some(TransactionKind::Emergency),
+                txs.emergency.as_ref(),
+            ))
+            .spacing(10),
+    ))
+}
+
+/// presigned_state returns the state of the signatures of a transaction of
+/// the vault which is not broadcast, according to the vault status.
+fn presigned_state(vault: &Vault, kind: TransactionKind) -> &'static str {
+    let revocation = match vault.status {
+        VaultStatus::Unconfirmed | VaultStatus::Funded => "Not signed",
+        VaultStatus::Securing => "Waiting for the signatures of the other stakeholders",
+        _ => "Signed",
+    };
+    match kind {
+        TransactionKind::Unvault => match vault.status {
+            VaultStatus::Activating => "Waiting for the signatures of the other stakeholders",
+            VaultStatus::Unconfirmed
+            | VaultStatus::Funded
+            | VaultStatus::Securing
+            | VaultStatus::Secured => "Not signed",
+            _ => "Signed",
+        },
+        TransactionKind::Spend => "Not created",
+        _ => revocation,
+    }
+}
+
+/// spend_finality distinguishes a confirmed spend from a final one, which has
+/// at least the number of confirmations set in the GUI configuration.
+fn spend_finality<'a, T: 'a>(ctx: &Context, tx: &BroadcastedTransaction) -> Container<'a, T> {
+    let confirmations = tx.confirmations(ctx.blockheight);
+    let required = ctx.spend_final_confirmations as u64;
+    let status = if tx.blockheight.is_none() {
+        text::small(ctx.tr("Spend not confirmed yet"))
+    } else if ctx.blockheight == 0 || confirmations < required {
+        text::small(&ctx.trf(
+            "Spend confirmed ({}/{} confirmations before final)",
+            &[&confirmations.min(required), &required],
+        ))
+    } else {
+        text::success(text::bold(text::small(ctx.tr("Spend final"))))
+    };
+    Container::new(status)
+}
+
 /// vault_badge returns a badge headlining the vault status.
 fn vault_badge<'a, T: 'a>(vault: &Vault) -> Container<'a, T> {
     match &vault.status {
@@ -416,6 +950,12 @@ fn vault_badge<'a, T: 'a>(vault: &Vault) -> Container<'a, T> {
     }
 }
 
+/// Frames the item of a vault which changed since the previous update of
+/// the list.
+pub fn highlighted_vault(item: Element<'_, Message>) -> Element<'_, Message> {
+    card::border_primary(Container::new(item)).padding(2).into()
+}
+
 pub trait VaultView {
     fn new() -> Self;
     fn view(&mut self, ctx: &Context, vault: &Vault) -> Element<Message>;
@@ -424,17 +964,26 @@ pub trait VaultView {
 #[derive(Debug, Clone)]
 pub struct VaultListItemView {
     state: iced::button::State,
+    /// status and date of the last update, the view is dropped with the vault.
+    status: Option<String>,
 }
 
 impl VaultView for VaultListItemView {
     fn new() -> Self {
         VaultListItemView {
             state: iced::button::State::new(),
+            status: None,
         }
     }
 
     fn view(&mut self, ctx: &Context, vault: &Vault) -> iced::Element<Message> {
-        let updated_at = NaiveDateTime::from_timestamp(vault.updated_at, 0);
+        let status = self.status.get_or_insert_with(|| {
+            format!(
+                "{} ( {} )",
+                &vault.status,
+                NaiveDateTime::from_timestamp(vault.updated_at, 0)
+            )
+        });
         button::white_card_button(
             &mut self.state,
             Container::new(
@@ -446,10 +995,7 @@ impl VaultView for VaultListItemView {
                                 .push(
                                     Column::new()
                                         .push(text::bold(text::small(&vault.address)))
-                                        .push(text::small(&format!(
-                                            "{} ( {} )",
-                                            &vault.status, updated_at
-                                        ))),
+                                        .push(text::small(status)),
                                 )
                                 .spacing(20),
                         )
@@ -458,10 +1004,9 @@ impl VaultView for VaultListItemView {
                     .push(
                         Container::new(
                             Row::new()
-                                .push(text::bold(text::simple(&format!(
-                                    "{}",
-                                    ctx.converter.converts(vault.amount),
-                                ))))
+                                .push(text::bold(text::simple(
+                                    &ctx.converter.amount_string(vault.amount),
+                                )))
                                 .push(text::small(&format!(" {}", ctx.converter.unit)))
                                 .align_items(Align::Center),
                         )
@@ -477,6 +1022,55 @@ impl VaultView for VaultListItemView {
     }
 }
 
+impl VaultListItemView {
+    /// Renders the vault as a big card of the vaults grid.
+    pub fn card<'a>(
+        &'a mut self,
+        ctx: &Context,
+        vault: &Vault,
+        label: Option<&String>,
+    ) -> Element<'a, Message> {
+        let age = (Utc::now().timestamp() - vault.received_at).max(0) / (24 * 60 * 60);
+        button::white_card_button(
+            &mut self.state,
+            Container::new(
+                Column::new()
+                    .push(
+                        Row::new()
+                            .push(vault_badge(vault))
+                            .push(text::small(&vault.status.to_string()))
+                            .spacing(10)
+                            .align_items(Align::Center),
+                    )
+                    .push(
+                        Row::new()
+                            .push(
+                                text::bold(text::simple(
+                                    &ctx.converter.amount_string(vault.amount),
+                                ))
+                                .size(30),
+                            )
+                            .push(text::simple(&format!(" {}", ctx.converter.unit)))
+                            .align_items(Align::Center),
+                    )
+                    .push(text::bold(text::small(
+                        label.map(|l| l.as_str()).unwrap_or(ctx.tr("No label")),
+                    )))
+                    .push(text::small(&match age {
+                        0 => ctx.tr("Received today").to_string(),
+                        1 => ctx.tr("Received 1 day ago").to_string(),
+                        _ => ctx.trf("Received {} days ago", &[&age]),
+                    }))
+                    .spacing(10),
+            )
+            .padding(10),
+        )
+        .on_press(Message::Vault(vault.outpoint(), VaultMessage::Select))
+        .width(Length::Fill)
+        .into()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SecureVaultListItemView {
     select_button: iced::button::State,
@@ -521,10 +1115,9 @@ fn vault_ack_signed<'a, T: 'a>(ctx: &Context, deposit: &Vault) -> Element<'a, T>
             .push(
                 Container::new(
                     Row::new()
-                        .push(text::success(text::bold(text::simple(&format!(
-                            "{}",
-                            ctx.converter.converts(deposit.amount),
-                        )))))
+                        .push(text::success(text::bold(text::simple(
+                            &ctx.converter.amount_string(deposit.amount),
+                        ))))
                         .push(text::small(&format!(" {}", ctx.converter.unit)))
                         .align_items(Align::Center),
                 )
@@ -562,10 +1155,9 @@ fn vault_ack_pending<'a>(
                     .push(
                         Container::new(
                             Row::new()
-                                .push(text::bold(text::simple(&format!(
-                                    "{}",
-                                    ctx.converter.converts(deposit.amount),
-                                ))))
+                                .push(text::bold(text::simple(
+                                    &ctx.converter.amount_string(deposit.amount),
+                                )))
                                 .push(text::small(&format!(" {}", ctx.converter.unit)))
                                 .align_items(Align::Center),
                         )
@@ -627,10 +1219,9 @@ fn vault_delegate<'a>(
                     .push(
                         Container::new(
                             Row::new()
-                                .push(text::bold(text::simple(&format!(
-                                    "{}",
-                                    ctx.converter.converts(deposit.amount),
-                                ))))
+                                .push(text::bold(text::simple(
+                                    &ctx.converter.amount_string(deposit.amount),
+                                )))
                                 .push(text::small(&format!(" {}", ctx.converter.unit)))
                                 .align_items(Align::Center),
                         )
@@ -674,7 +1265,9 @@ impl SecureVaultView {
                 card::success(Container::new(
                     Row::new()
                         .push(text::success(icon::shield_check_icon()))
-                        .push(text::success(text::bold(text::simple("   Emergency TX")))),
+                        .push(text::success(text::bold(text::simple(
+                            ctx.tr("   Emergency TX"),
+                        )))),
                 ))
                 .width(Length::FillPortion(1)),
             );
@@ -683,7 +1276,7 @@ impl SecureVaultView {
                 card::border_black(Container::new(
                     Row::new()
                         .push(icon::shield_icon())
-                        .push(text::bold(text::simple("   Emergency TX"))),
+                        .push(text::bold(text::simple(ctx.tr("   Emergency TX")))),
                 ))
                 .width(Length::FillPortion(1)),
             );
@@ -696,7 +1289,7 @@ impl SecureVaultView {
                     Row::new()
                         .push(text::success(icon::shield_check_icon()))
                         .push(text::success(text::bold(text::simple(
-                            "   Emergency unvault TX",
+                            ctx.tr("   Emergency unvault TX"),
                         )))),
                 ))
                 .width(Length::FillPortion(1)),
@@ -706,7 +1299,7 @@ impl SecureVaultView {
                 card::border_black(Container::new(
                     Row::new()
                         .push(icon::shield_icon())
-                        .push(text::bold(text::simple("   Emergency Unvault TX"))),
+                        .push(text::bold(text::simple(ctx.tr("   Emergency Unvault TX")))),
                 ))
                 .width(Length::FillPortion(1)),
             );
@@ -715,7 +1308,7 @@ impl SecureVaultView {
                 card::grey(Container::new(
                     Row::new()
                         .push(icon::shield_icon())
-                        .push(text::bold(text::simple("   Emergency Unvault TX"))),
+                        .push(text::bold(text::simple(ctx.tr("   Emergency Unvault TX")))),
                 ))
                 .width(Length::FillPortion(1)),
             );
@@ -727,7 +1320,9 @@ impl SecureVaultView {
                 card::success(Container::new(
                     Row::new()
                         .push(text::success(icon::shield_check_icon()))
-                        .push(text::success(text::bold(text::simple("   Cancel TX")))),
+                        .push(text::success(text::bold(text::simple(
+                            ctx.tr("   Cancel TX"),
+                        )))),
                 ))
                 .width(Length::FillPortion(1)),
             );
@@ -736,7 +1331,7 @@ impl SecureVaultView {
                 card::border_black(Container::new(
                     Row::new()
                         .push(icon::shield_icon())
-                        .push(text::bold(text::simple("   Cancel TX"))),
+                        .push(text::bold(text::simple(ctx.tr("   Cancel TX")))),
                 ))
                 .width(Length::FillPortion(1)),
             );
@@ -745,7 +1340,7 @@ impl SecureVaultView {
                 card::grey(Container::new(
                     Row::new()
                         .push(icon::shield_icon())
-                        .push(text::bold(text::simple("   Cancel TX"))),
+                        .push(text::bold(text::simple(ctx.tr("   Cancel TX")))),
                 ))
                 .width(Length::FillPortion(1)),
             );
@@ -770,10 +1365,9 @@ impl SecureVaultView {
                     .push(
                         Container::new(
                             Row::new()
-                                .push(text::bold(text::simple(&format!(
-                                    "{}",
-                                    ctx.converter.converts(deposit.amount)
-                                ))))
+                                .push(text::bold(text::simple(
+                                    &ctx.converter.amount_string(deposit.amount),
+                                )))
                                 .push(text::small(&format!(" {}", ctx.converter.unit)))
                                 .align_items(Align::Center),
                         )
@@ -791,14 +1385,13 @@ impl SecureVaultView {
         if let Some(error) = warning {
             col = col.push(card::alert_warning(Container::new(
                 Column::new()
-                    .push(Container::new(text::simple(&format!(
-                        "Failed to connect to revaultd: {}",
-                        error
-                    ))))
+                    .push(Container::new(text::simple(
+                        &ctx.trf("Failed to connect to revaultd: {}", &[&error]),
+                    )))
                     .push(
                         button::primary(
                             &mut self.retry_button,
-                            button::button_content(None, "Retry"),
+                            button::button_content(None, ctx.tr("Retry")),
                         )
                         .on_press(VaultMessage::Retry),
                     )
@@ -824,7 +1417,7 @@ impl DelegateVaultView {
 
     pub fn view<'a>(
         &'a mut self,
-        _ctx: &Context,
+        ctx: &Context,
         vault: &Vault,
         warning: Option<&Error>,
         signer: Element<'a, SignMessage>,
@@ -838,14 +1431,14 @@ impl DelegateVaultView {
         }
         col.push(button::transparent(
                 &mut self.back_button,
-                Container::new(text::small("< vault transactions")),
+                Container::new(text::small(ctx.tr("< vault transactions"))),
             ).on_press(Message::Vault(vault.outpoint(), VaultMessage::ListOnchainTransaction)))
             .push(card::white(Container::new(
                 Column::new()
                     .push(
                         Column::new()
-                            .push(text::bold(text::simple("Delegate vault to manager")))
-                            .push(text::simple("the unvault transaction must be signed in order to delegate the fund to the managers.")),
+                            .push(text::bold(text::simple(ctx.tr("Delegate vault to manager"))))
+                            .push(text::simple(ctx.tr("the unvault transaction must be signed in order to delegate the fund to the managers."))),
                     )
                     .push(signer.map(move |msg| match msg {
                         SignMessage::Clipboard(s) => Message::Clipboard(s),
@@ -857,9 +1450,8 @@ impl DelegateVaultView {
     }
 }
 
-/// RevaultVaultView displays a section with a button asking if the user wants to revault the
-/// unvaulting vault. The view displays the sucess message or the failure after the processing
-/// state.
+/// RevaultVaultView displays the processing of the revault confirmed by the user,
+/// then the sucess message or the failure with a button to try again.
 #[derive(Debug, Clone)]
 pub struct RevaultVaultView {
     back_button: iced::button::State,
@@ -876,7 +1468,7 @@ impl RevaultVaultView {
 
     pub fn view<'a>(
         &'a mut self,
-        _ctx: &Context,
+        ctx: &Context,
         vault: &Vault,
         processing: &bool,
         success: &bool,
@@ -890,27 +1482,30 @@ impl RevaultVaultView {
         }
 
         let button_broadcast_action = if *processing {
-            col = col.push(text::simple("waiting for revauld..."));
+            col = col.push(text::simple(ctx.tr("waiting for revauld...")));
             button::primary(
                 &mut self.broadcast_button,
-                button::button_content(None, "Broadcasting"),
+                button::button_content(None, ctx.tr("Broadcasting")),
             )
         } else if *success {
-            col = col.push(text::simple("The cancel transaction is broadcasted"));
+            col = col.push(text::simple(
+                ctx.tr("The cancel transaction is broadcasted"),
+            ));
             button::success(
                 &mut self.broadcast_button,
-                button::button_content(None, "Broadcasted"),
+                button::button_content(None, ctx.tr("Broadcasted")),
             )
         } else {
             col = col
-                .push(text::bold(text::simple("Revault vault")))
-                .push(text::simple("The cancel transaction will be broadcast"))
-                .push(text::simple("Are you sure to revault ?"));
+                .push(text::bold(text::simple(ctx.tr("Revault vault"))))
+                .push(text::simple(
+                    ctx.tr("The cancel transaction was not broadcast"),
+                ));
             button::primary(
                 &mut self.broadcast_button,
-                button::button_content(None, "Yes Revault"),
+                button::button_content(None, ctx.tr("Retry")),
             )
-            .on_press(Message::Vault(vault.outpoint(), VaultMessage::Revault))
+            .on_press(confirm::revault(vault.outpoint()).ask())
         };
 
         let col = col
@@ -921,7 +1516,7 @@ impl RevaultVaultView {
             .push(
                 button::transparent(
                     &mut self.back_button,
-                    Container::new(text::small("< vault transactions")),
+                    Container::new(text::small(ctx.tr("< vault transactions"))),
                 )
                 .on_press(Message::Vault(
                     vault.outpoint(),
@@ -1,31 +1,52 @@
-use iced::{pick_list, scrollable, Align, Column, Container, Element, Length, Row};
+use std::path::Path;
+
+use iced::{
+    pick_list, scrollable, text_input, Align, Column, Container, Element, Length, Row, TextInput,
+};
 
 use crate::{
     app::{
         error::Error,
-        message::{Message, VaultFilterMessage},
+        message::{ArchiveMessage, FlowMessage, Message, VaultFilterMessage},
+        preferences::{VaultLayout, VaultSort},
         view::{layout, sidebar::Sidebar, Context},
     },
     revaultd::model::VaultStatus,
-    ui::component::{navbar, scroll, text, TransparentPickListStyle},
+    ui::component::{button, navbar, scroll, text, TransparentPickListStyle},
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum VaultsFilter {
     Current,
+    Funded,
+    Secured,
+    Active,
     Moving,
     Moved,
+    Archive,
 }
 
 impl VaultsFilter {
-    pub const ALL: [VaultsFilter; 3] = [
+    pub const ALL: [VaultsFilter; 7] = [
         VaultsFilter::Current,
+        VaultsFilter::Funded,
+        VaultsFilter::Secured,
+        VaultsFilter::Active,
         VaultsFilter::Moving,
         VaultsFilter::Moved,
+        VaultsFilter::Archive,
     ];
 
-    pub fn new(statuses: &[VaultStatus]) -> VaultsFilter {
-        if statuses == VaultStatus::MOVING {
+    pub fn new(statuses: &[VaultStatus], archived: bool) -> VaultsFilter {
+        if archived {
+            VaultsFilter::Archive
+        } else if statuses == VaultStatus::FUNDED {
+            VaultsFilter::Funded
+        } else if statuses == VaultStatus::SECURED {
+            VaultsFilter::Secured
+        } else if statuses == VaultStatus::ACTIVE {
+            VaultsFilter::Active
+        } else if statuses == VaultStatus::MOVING {
             VaultsFilter::Moving
         } else if statuses == VaultStatus::MOVED {
             VaultsFilter::Moved
@@ -34,12 +55,16 @@ impl VaultsFilter {
         }
     }
 
-    pub fn statuses(&self) -> &'static [VaultStatus] {
-        match self {
-            Self::Current => &VaultStatus::CURRENT,
-            Self::Moving => &VaultStatus::MOVING,
-            Self::Moved => &VaultStatus::MOVED,
-        }
+    pub fn message(&self) -> Message {
+        Message::FilterVaults(match self {
+            Self::Current => VaultFilterMessage::Status(&VaultStatus::CURRENT),
+            Self::Funded => VaultFilterMessage::Status(&VaultStatus::FUNDED),
+            Self::Secured => VaultFilterMessage::Status(&VaultStatus::SECURED),
+            Self::Active => VaultFilterMessage::Status(&VaultStatus::ACTIVE),
+            Self::Moving => VaultFilterMessage::Status(&VaultStatus::MOVING),
+            Self::Moved => VaultFilterMessage::Status(&VaultStatus::MOVED),
+            Self::Archive => VaultFilterMessage::Archive,
+        })
     }
 }
 
@@ -47,71 +72,201 @@ impl std::fmt::Display for VaultsFilter {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::Current => write!(f, "Current"),
+            Self::Funded => write!(f, "Funded"),
+            Self::Secured => write!(f, "Secured"),
+            Self::Active => write!(f, "Active"),
             Self::Moving => write!(f, "Moving"),
             Self::Moved => write!(f, "Moved"),
+            Self::Archive => write!(f, "Archive"),
         }
     }
 }
 
-/// VaultsView renders a list of vaults filtered by the status filter.
+/// VaultsView renders a list of vaults filtered by the status filter
+/// and the search query, in the sort order picked by the user.
 /// If the loading field is true, only the status pick_list component is displayed.
 #[derive(Debug)]
 pub struct VaultsView {
     scroll: scrollable::State,
     sidebar: Sidebar,
     pick_filter: pick_list::State<VaultsFilter>,
+    pick_sort: pick_list::State<VaultSort>,
+    layout_button: iced::button::State,
+    export_button: iced::button::State,
+    flow_button: iced::button::State,
+    more_button: iced::button::State,
+    archive_button: iced::button::State,
+    search_input: text_input::State,
 }
 
+/// Number of vault cards by row of the grid layout.
+const GRID_COLUMNS: usize = 3;
+
 impl VaultsView {
     pub fn new() -> Self {
         VaultsView {
             sidebar: Sidebar::new(),
             scroll: scrollable::State::new(),
             pick_filter: pick_list::State::default(),
+            pick_sort: pick_list::State::default(),
+            layout_button: iced::button::State::new(),
+            export_button: iced::button::State::new(),
+            flow_button: iced::button::State::new(),
+            more_button: iced::button::State::new(),
+            archive_button: iced::button::State::new(),
+            search_input: text_input::State::new(),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn view<'a>(
         &'a mut self,
         ctx: &Context,
         warning: Option<&Error>,
+        labels: Element<'a, Message>,
         vaults: Vec<Element<'a, Message>>,
+        total: usize,
         vault_status_filter: &[VaultStatus],
+        (archived, search, selected): (bool, &str, usize),
+        (layout, sort): (VaultLayout, VaultSort),
+        exported_history: Option<&Path>,
         loading: bool,
     ) -> Element<'a, Message> {
         let mut col = Column::new();
 
         if !loading {
-            col = col
-                .push(
-                    Row::new()
-                        .push(
-                            Container::new(
-                                Row::new()
-                                    .push(text::bold(text::simple(&format!(" {}", vaults.len()))))
-                                    .push(text::simple(" vaults")),
-                            )
-                            .width(Length::Fill),
+            let (toggle, next_layout) = match layout {
+                VaultLayout::List => (ctx.tr("Grid view"), VaultLayout::Grid),
+                VaultLayout::Grid => (ctx.tr("List view"), VaultLayout::List),
+            };
+            let shown = vaults.len();
+            let vaults: Element<'a, Message> = match layout {
+                VaultLayout::List => Column::with_children(vaults).spacing(5).into(),
+                VaultLayout::Grid => {
+                    let mut grid = Column::new().spacing(10);
+                    let mut cards = vaults.into_iter().peekable();
+                    while cards.peek().is_some() {
+                        let mut row = Row::new().spacing(10);
+                        for i in 0..GRID_COLUMNS {
+                            row = match cards.next() {
+                                Some(card) => row.push(Container::new(card).width(Length::Fill)),
+                                // fills the last row, so that the cards keep the same width.
+                                None if i > 0 => row.push(Column::new().width(Length::Fill)),
+                                None => row,
+                            };
+                        }
+                        grid = grid.push(row);
+                    }
+                    grid.into()
+                }
+            };
+            col = col.push(
+                Row::new()
+                    .push(
+                        Container::new(
+                            Row::new()
+                                .push(text::bold(text::simple(&format!(" {}", total))))
+                                .push(text::simple(&format!(" {}", ctx.tr("vaults")))),
                         )
-                        .push(
-                            pick_list::PickList::new(
-                                &mut self.pick_filter,
-                                &VaultsFilter::ALL[..],
-                                Some(VaultsFilter::new(vault_status_filter)),
-                                |filter| {
-                                    Message::FilterVaults(VaultFilterMessage::Status(
-                                        filter.statuses(),
-                                    ))
-                                },
-                            )
-                            .text_size(15)
-                            .padding(10)
-                            .width(Length::Units(200))
-                            .style(TransparentPickListStyle),
+                        .width(Length::Fill),
+                    )
+                    .push(
+                        button::transparent(
+                            &mut self.export_button,
+                            button::button_content(None, ctx.tr("Export history")),
                         )
-                        .align_items(Align::Center),
+                        .on_press(Message::ExportHistory),
+                    )
+                    .push(
+                        button::transparent(
+                            &mut self.flow_button,
+                            button::button_content(None, ctx.tr("Fund flow")),
+                        )
+                        .on_press(Message::Flow(FlowMessage::Toggle)),
+                    )
+                    .push(
+                        button::transparent(
+                            &mut self.layout_button,
+                            button::button_content(None, toggle),
+                        )
+                        .on_press(Message::VaultLayout(next_layout)),
+                    )
+                    .push(
+                        pick_list::PickList::new(
+                            &mut self.pick_sort,
+                            &VaultSort::ALL[..],
+                            Some(sort),
+                            |sort| Message::FilterVaults(VaultFilterMessage::Sort(sort)),
+                        )
+                        .text_size(15)
+                        .padding(10)
+                        .width(Length::Units(150))
+                        .style(TransparentPickListStyle),
+                    )
+                    .push(
+                        pick_list::PickList::new(
+                            &mut self.pick_filter,
+                            &VaultsFilter::ALL[..],
+                            Some(VaultsFilter::new(vault_status_filter, archived)),
+                            |filter| filter.message(),
+                        )
+                        .text_size(15)
+                        .padding(10)
+                        .width(Length::Units(200))
+                        .style(TransparentPickListStyle),
+                    )
+                    .align_items(Align::Center),
+            );
+            col = col.push(
+                TextInput::new(
+                    &mut self.search_input,
+                    ctx.tr("Search by txid, outpoint, address or label"),
+                    search,
+                    |query| Message::FilterVaults(VaultFilterMessage::Search(query)),
                 )
-                .push(Column::with_children(vaults).spacing(5));
+                .padding(10),
+            );
+            col = col.push(labels);
+            if selected > 0 && (archived || vault_status_filter == VaultStatus::MOVED) {
+                let (label, msg) = if archived {
+                    (ctx.tr("Restore selected vaults"), ArchiveMessage::Restore)
+                } else {
+                    (ctx.tr("Archive selected vaults"), ArchiveMessage::Selection)
+                };
+                col = col.push(
+                    button::transparent(
+                        &mut self.archive_button,
+                        button::button_content(None, label),
+                    )
+                    .on_press(Message::Archive(msg)),
+                );
+            }
+            col = col.push(vaults);
+            if shown < total {
+                col = col.push(
+                    Container::new(
+                        button::transparent(
+                            &mut self.more_button,
+                            button::button_content(
+                                None,
+                                &ctx.trf(
+                                    "Show more ({} of {} vaults displayed)",
+                                    &[&shown, &total],
+                                ),
+                            ),
+                        )
+                        .on_press(Message::ShowMoreVaults),
+                    )
+                    .width(Length::Fill)
+                    .center_x(),
+                );
+            }
+            if let Some(path) = exported_history {
+                col = col.push(text::success(text::small(&ctx.trf(
+                    "History of all the vaults exported to {}",
+                    &[&path.display()],
+                ))));
+            }
         } else {
             col = col.push(
                 Row::new()
@@ -120,10 +275,8 @@ impl VaultsView {
                         pick_list::PickList::new(
                             &mut self.pick_filter,
                             &VaultsFilter::ALL[..],
-                            Some(VaultsFilter::new(vault_status_filter)),
-                            |filter| {
-                                Message::FilterVaults(VaultFilterMessage::Status(filter.statuses()))
-                            },
+                            Some(VaultsFilter::new(vault_status_filter, archived)),
+                            |filter| filter.message(),
                         )
                         .text_size(15)
                         .padding(10)
@@ -0,0 +1,472 @@
+//! Minimal client for the bitcoind JSON-RPC interface, used to check the
+//! node configured for revaultd before the daemon relies on it.
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::path::Path;
+use std::time::Duration;
+
+use bitcoin::{base64, consensus::encode, Network, Transaction, Txid};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::json;
+
+use crate::i18n::Language;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Number of blocks a pruned node must keep for revaultd: two weeks of
+/// blocks, so the daemon can catch up after being offline.
+pub const MIN_PRUNE_DEPTH: u64 = 2016;
+
+#[derive(Debug, Clone)]
+pub enum BitcoindError {
+    Cookie(String),
+    Connection(String),
+    /// bitcoind refused the credentials.
+    Unauthorized,
+    Response(String),
+    Rpc(String),
+}
+
+impl BitcoindError {
+    /// Guidance for the user to solve the error of the connection.
+    pub fn guidance(&self) -> Option<&'static str> {
+        match self {
+            Self::Cookie(_) => Some(
+                "bitcoind writes the cookie file in its datadir once started, \
+                check that it runs and the path of the file.",
+            ),
+            Self::Connection(_) => Some(
+                "Check that bitcoind runs with 'server=1' and that the address is \
+                its 'rpcbind' and 'rpcport'.",
+            ),
+            Self::Unauthorized => Some(
+                "Check that the cookie file is the one of the bitcoind listening on \
+                this address, bitcoind writes it again each time it starts.",
+            ),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for BitcoindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Cookie(e) => write!(f, "Failed to read bitcoind cookie file: {}", e),
+            Self::Connection(e) => write!(f, "Failed to connect to bitcoind: {}", e),
+            Self::Unauthorized => write!(f, "bitcoind refused the credentials"),
+            Self::Response(e) => write!(f, "Unexpected bitcoind response: {}", e),
+            Self::Rpc(e) => write!(f, "bitcoind error: {}", e),
+        }
+    }
+}
+
+/// getblockchaininfo response, only the fields used by the GUI.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BlockchainInfo {
+    /// Name of the chain: main, test or regtest.
+    pub chain: String,
+    pub blocks: u64,
+    /// Height of the best known header, the blocks are downloaded up to it.
+    pub headers: u64,
+    /// Estimate of the verified part of the chain, from 0 to 1.
+    pub verificationprogress: f64,
+    pub pruned: bool,
+    pub pruneheight: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PruningCheck {
+    NotPruned,
+    /// The node is pruned but keeps enough blocks.
+    Compatible {
+        depth: u64,
+    },
+    /// The node does not keep enough blocks for revaultd.
+    Incompatible {
+        depth: u64,
+        required: u64,
+    },
+}
+
+impl BlockchainInfo {
+    /// Checks the prune depth of the node against the number of blocks
+    /// revaultd needs, which is at least MIN_PRUNE_DEPTH and the unvault
+    /// timelock if it is known.
+    pub fn pruning_check(&self, unvault_csv: Option<u32>) -> PruningCheck {
+        if !self.pruned {
+            return PruningCheck::NotPruned;
+        }
+        let depth = self
+            .blocks
+            .saturating_sub(self.pruneheight.unwrap_or(self.blocks));
+        let required = MIN_PRUNE_DEPTH.max(unvault_csv.unwrap_or(0) as u64);
+        if depth >= required {
+            PruningCheck::Compatible { depth }
+        } else {
+            PruningCheck::Incompatible { depth, required }
+        }
+    }
+}
+
+/// Guidance for an incompatible pruning, with the number of blocks kept
+/// by the node and the number of blocks needed by revaultd.
+const PRUNING_GUIDANCE: &str = "bitcoind is pruned and only keeps the last {} blocks, revaultd \
+    needs at least {}. Increase the 'prune' value of bitcoin.conf or set 'prune=0' \
+    (a reindex is then required) before starting revaultd.";
+
+impl PruningCheck {
+    /// Guidance for the user to solve an incompatible configuration.
+    pub fn guidance(&self, language: Language) -> Option<String> {
+        match self {
+            PruningCheck::Incompatible { depth, required } => {
+                Some(language.trf(PRUNING_GUIDANCE, &[depth, required]))
+            }
+            _ => None,
+        }
+    }
+}
+
+pub fn get_blockchain_info(
+    addr: &SocketAddr,
+    cookie_path: &Path,
+) -> Result<BlockchainInfo, BitcoindError> {
+    call(addr, cookie_path, "getblockchaininfo", json!([]))
+}
+
+/// Broadcasts the transaction and returns its txid.
+pub fn send_raw_transaction(
+    addr: &SocketAddr,
+    cookie_path: &Path,
+    tx: &Transaction,
+) -> Result<Txid, BitcoindError> {
+    call(
+        addr,
+        cookie_path,
+        "sendrawtransaction",
+        json!([encode::serialize_hex(tx)]),
+    )
+}
+
+/// Returns the name of the chain of the network in the bitcoind responses.
+pub fn chain_name(network: Network) -> &'static str {
+    match network {
+        Network::Bitcoin => "main",
+        Network::Testnet => "test",
+        Network::Regtest => "regtest",
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Passed,
+    /// revaultd can run, but with limitations.
+    Warning,
+    /// revaultd cannot run with the node.
+    Failed,
+}
+
+/// Diagnostic is a check of the connection test of the node.
+/// Its text is kept with its arguments to be displayed in the language
+/// of the user.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    text: &'static str,
+    args: Vec<String>,
+}
+
+impl Diagnostic {
+    fn new(severity: Severity, text: &'static str, args: Vec<String>) -> Self {
+        Self {
+            severity,
+            text,
+            args,
+        }
+    }
+
+    pub fn message(&self, language: Language) -> String {
+        let args: Vec<&dyn std::fmt::Display> = self
+            .args
+            .iter()
+            .map(|arg| arg as &dyn std::fmt::Display)
+            .collect();
+        language.trf(self.text, &args)
+    }
+}
+
+/// Diagnostics of a node reachable with the credentials.
+#[derive(Debug, Clone)]
+pub struct NodeReport {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl NodeReport {
+    /// Returns true if no check failed.
+    pub fn passed(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .all(|d| d.severity != Severity::Failed)
+    }
+}
+
+/// getindexinfo response, only the transaction index.
+#[derive(Deserialize)]
+struct IndexInfo {
+    txindex: Option<Index>,
+}
+
+#[derive(Deserialize)]
+struct Index {
+    synced: bool,
+}
+
+/// Tests the connection to the node and checks that revaultd can run with
+/// it: its chain against the network, its sync, its pruning against the
+/// unvault timelock if known, and its transaction index.
+pub fn test_node(
+    addr: &SocketAddr,
+    cookie_path: &Path,
+    network: Network,
+    unvault_csv: Option<u32>,
+) -> Result<NodeReport, BitcoindError> {
+    let info = get_blockchain_info(addr, cookie_path)?;
+    let mut diagnostics = vec![Diagnostic::new(
+        Severity::Passed,
+        "bitcoind is reachable at {} with the credentials",
+        vec![addr.to_string()],
+    )];
+
+    let expected = chain_name(network);
+    diagnostics.push(if info.chain == expected {
+        Diagnostic::new(
+            Severity::Passed,
+            "The node runs the {} chain",
+            vec![expected.to_string()],
+        )
+    } else {
+        Diagnostic::new(
+            Severity::Failed,
+            "The node runs the {} chain instead of the {} one of the {} network, \
+            check the 'chain' of bitcoin.conf or the network of the configuration",
+            vec![
+                info.chain.clone(),
+                expected.to_string(),
+                network.to_string(),
+            ],
+        )
+    });
+
+    diagnostics.push(if info.verificationprogress < 0.9999 {
+        Diagnostic::new(
+            Severity::Warning,
+            "The node is syncing ({}%, block {} of {}), revaultd waits for the end \
+            of the sync",
+            vec![
+                format!("{:.2}", info.verificationprogress * 100.0),
+                info.blocks.to_string(),
+                info.headers.to_string(),
+            ],
+        )
+    } else {
+        Diagnostic::new(
+            Severity::Passed,
+            "The node is synced at block {}",
+            vec![info.blocks.to_string()],
+        )
+    });
+
+    diagnostics.push(match info.pruning_check(unvault_csv) {
+        PruningCheck::NotPruned => {
+            Diagnostic::new(Severity::Passed, "The node is not pruned", Vec::new())
+        }
+        PruningCheck::Compatible { depth } => Diagnostic::new(
+            Severity::Passed,
+            "The node is pruned but keeps enough blocks ({})",
+            vec![depth.to_string()],
+        ),
+        PruningCheck::Incompatible { depth, required } => Diagnostic::new(
+            Severity::Failed,
+            PRUNING_GUIDANCE,
+            vec![depth.to_string(), required.to_string()],
+        ),
+    });
+
+    // getindexinfo is missing before bitcoind 0.21.
+    diagnostics.push(
+        match call::<IndexInfo>(addr, cookie_path, "getindexinfo", json!([])) {
+            Ok(IndexInfo {
+                txindex: Some(Index { synced: true }),
+            }) => Diagnostic::new(
+                Severity::Passed,
+                "The transaction index is enabled",
+                Vec::new(),
+            ),
+            Ok(IndexInfo {
+                txindex: Some(Index { synced: false }),
+            }) => Diagnostic::new(
+                Severity::Warning,
+                "The transaction index is being built",
+                Vec::new(),
+            ),
+            Ok(IndexInfo { txindex: None }) => Diagnostic::new(
+                Severity::Warning,
+                "The transaction index is missing, set 'txindex=1' in bitcoin.conf \
+                to look up the transactions out of the wallet of revaultd",
+                Vec::new(),
+            ),
+            Err(_) => Diagnostic::new(
+                Severity::Warning,
+                "The transaction index could not be checked, bitcoind is older than 0.21",
+                Vec::new(),
+            ),
+        },
+    );
+
+    Ok(NodeReport { diagnostics })
+}
+
+/// FeeLevel is a suggested feerate level of the spend creation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeLevel {
+    Low,
+    Medium,
+    High,
+}
+
+impl FeeLevel {
+    pub const ALL: [FeeLevel; 3] = [FeeLevel::Low, FeeLevel::Medium, FeeLevel::High];
+
+    /// Confirmation target in blocks of the estimation.
+    pub fn target(&self) -> u16 {
+        match self {
+            Self::Low => 144,
+            Self::Medium => 6,
+            Self::High => 2,
+        }
+    }
+}
+
+impl std::fmt::Display for FeeLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Low => write!(f, "Low"),
+            Self::Medium => write!(f, "Medium"),
+            Self::High => write!(f, "High"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FeeEstimate {
+    pub level: FeeLevel,
+    /// feerate in sats/vbyte.
+    pub feerate: u32,
+    /// Number of blocks the estimation is valid for, it can be greater
+    /// than the requested target if bitcoind lacks data.
+    pub blocks: u16,
+}
+
+/// estimatesmartfee response.
+#[derive(Deserialize)]
+struct SmartFee {
+    /// feerate in BTC/kvB.
+    feerate: Option<f64>,
+    blocks: u16,
+}
+
+/// Estimates the feerate of each fee level, the levels bitcoind has
+/// not enough data for are omitted.
+pub fn estimate_fees(
+    addr: &SocketAddr,
+    cookie_path: &Path,
+) -> Result<Vec<FeeEstimate>, BitcoindError> {
+    let mut estimates = Vec::new();
+    for level in FeeLevel::ALL {
+        if let Some((feerate, blocks)) = estimate_feerate(addr, cookie_path, level.target())? {
+            estimates.push(FeeEstimate {
+                level,
+                feerate,
+                blocks,
+            });
+        }
+    }
+    Ok(estimates)
+}
+
+/// Maximum confirmation target of estimatesmartfee.
+pub const MAX_FEE_TARGET: u16 = 1008;
+
+/// Returns the feerate in sats/vbyte for a transaction to confirm within
+/// the target and the number of blocks of the estimation, None if bitcoind
+/// has not enough data.
+pub fn estimate_feerate(
+    addr: &SocketAddr,
+    cookie_path: &Path,
+    target: u16,
+) -> Result<Option<(u32, u16)>, BitcoindError> {
+    let res: SmartFee = call(
+        addr,
+        cookie_path,
+        "estimatesmartfee",
+        json!([target.clamp(1, MAX_FEE_TARGET)]),
+    )?;
+    // BTC/kvB to sats/vbyte, rounded up.
+    Ok(res
+        .feerate
+        .map(|feerate| (((feerate * 100_000.0).ceil() as u32).max(1), res.blocks)))
+}
+
+fn call<T: DeserializeOwned>(
+    addr: &SocketAddr,
+    cookie_path: &Path,
+    method: &str,
+    params: serde_json::Value,
+) -> Result<T, BitcoindError> {
+    let cookie =
+        std::fs::read_to_string(cookie_path).map_err(|e| BitcoindError::Cookie(e.to_string()))?;
+    let body = json!({"jsonrpc": "1.0", "id": "revault-gui", "method": method, "params": params})
+        .to_string();
+    let request = format!(
+        "POST / HTTP/1.1\r\nHost: {}\r\nAuthorization: Basic {}\r\n\
+        Content-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        addr,
+        base64::encode(cookie.trim()),
+        body.len(),
+        body
+    );
+
+    let mut stream = TcpStream::connect_timeout(addr, TIMEOUT)
+        .map_err(|e| BitcoindError::Connection(e.to_string()))?;
+    stream
+        .set_read_timeout(Some(TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(TIMEOUT)))
+        .and_then(|_| stream.write_all(request.as_bytes()))
+        .map_err(|e| BitcoindError::Connection(e.to_string()))?;
+
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .map_err(|e| BitcoindError::Connection(e.to_string()))?;
+
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| BitcoindError::Response("missing http body".to_string()))?;
+    if head.starts_with("HTTP/1.1 401") {
+        return Err(BitcoindError::Unauthorized);
+    }
+
+    #[derive(Deserialize)]
+    struct Response<T> {
+        result: Option<T>,
+        error: Option<serde_json::Value>,
+    }
+
+    let res: Response<T> =
+        serde_json::from_str(body).map_err(|e| BitcoindError::Response(e.to_string()))?;
+    match (res.result, res.error) {
+        (_, Some(e)) if !e.is_null() => Err(BitcoindError::Rpc(e.to_string())),
+        (Some(result), _) => Ok(result),
+        _ => Err(BitcoindError::Response("no result".to_string())),
+    }
+}
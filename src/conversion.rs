@@ -1,9 +1,20 @@
-use bitcoin::Network;
+use bitcoin::{Amount, Denomination, Network};
+
+use crate::{
+    i18n::Language,
+    price::{FiatCurrency, Rates},
+};
 
 /// Converter purpose is to give a Conversion from a given amount in satoshis according to its
 /// parameters.
 pub struct Converter {
     pub unit: Unit,
+    /// Fiat currency displayed alongside bitcoin amounts, if the price feed is enabled.
+    pub currency: FiatCurrency,
+    /// Last rates fetched from the price feed.
+    pub rates: Option<Rates>,
+    /// Language of the interface, giving the format of the numbers and the dates.
+    pub language: Language,
 }
 
 impl Converter {
@@ -13,13 +24,67 @@ impl Converter {
             Network::Bitcoin => Unit::Bitcoin,
             Network::Regtest => Unit::RegtestBitcoin,
         };
-        Self { unit }
+        Self {
+            unit,
+            currency: FiatCurrency::default(),
+            rates: None,
+            language: Language::default(),
+        }
     }
 
     /// converts amount in satoshis to BTC float.
     pub fn converts(&self, amount: u64) -> f64 {
         bitcoin::Amount::from_sat(amount).as_btc()
     }
+
+    /// converts amount in satoshis to the fiat currency,
+    /// None if no rate of the currency was fetched.
+    pub fn converts_fiat(&self, amount: u64) -> Option<f64> {
+        let rate = self.rates.as_ref()?.get(self.currency)?;
+        Some(self.converts(amount) * rate)
+    }
+
+    /// Formats the amount in satoshis in BTC with the separators of the
+    /// language of the interface, e.g. "1,234.5" or "1 234,5".
+    pub fn amount_string(&self, amount: u64) -> String {
+        self.language.localize_number(&Self::btc_string(amount))
+    }
+
+    /// Formats the amount in satoshis in BTC without the float conversion,
+    /// so that no digit is lost, e.g. "0.0015". It is the copied format.
+    pub fn btc_string(amount: u64) -> String {
+        let btc = Amount::from_sat(amount).to_string_in(Denomination::Bitcoin);
+        if btc.contains('.') {
+            btc.trim_end_matches('0').trim_end_matches('.').to_string()
+        } else {
+            btc
+        }
+    }
+
+    /// Formats the exact amount in satoshis, e.g. "150,000 sats".
+    pub fn sats_string(&self, amount: u64) -> String {
+        format!(
+            "{} sats",
+            self.language.localize_number(&amount.to_string())
+        )
+    }
+
+    /// Formats the amount in satoshis in the fiat currency, e.g. "1,234.56 USD".
+    pub fn fiat_string(&self, amount: u64) -> Option<String> {
+        self.converts_fiat(amount).map(|value| {
+            format!(
+                "{} {}",
+                self.language.format_number(value, 2),
+                self.currency
+            )
+        })
+    }
+
+    /// Formats the timestamp in seconds as a date in the format of the
+    /// language of the interface.
+    pub fn date_string(&self, timestamp: i64) -> String {
+        self.language.format_date(timestamp)
+    }
 }
 
 /// Unit is the bitcoin ticker according to the network used.
@@ -38,3 +103,131 @@ impl std::fmt::Display for Unit {
         }
     }
 }
+
+/// Returns the number typed by the user in the format of the program, a dot
+/// as decimal separator and no thousand separator. The spaces and apostrophes
+/// are thousand separators. A comma or a dot found once is the decimal
+/// separator, found several times it is a thousand separator; if both are
+/// found, the last one is the decimal separator. As for parse_integer, the
+/// thousand separators must be followed by groups of three digits.
+/// Returns None if the input is not a positive number.
+pub fn normalize_number(input: &str) -> Option<String> {
+    let digits: String = input
+        .trim()
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '\'' && *c != '\u{2019}')
+        .collect();
+    let decimal = match (digits.rfind(','), digits.rfind('.')) {
+        (Some(comma), Some(dot)) => Some(if comma > dot { ',' } else { '.' }),
+        (Some(_), None) if digits.matches(',').count() == 1 => Some(','),
+        (None, Some(_)) if digits.matches('.').count() == 1 => Some('.'),
+        _ => None,
+    };
+    let (integer, fraction) = match decimal.and_then(|sep| digits.rfind(sep)) {
+        Some(i) => (&digits[..i], Some(&digits[i + 1..])),
+        None => (&digits[..], None),
+    };
+    let mut groups = integer.split([',', '.']);
+    let mut number = groups.next().unwrap_or_default().to_string();
+    for group in groups {
+        if number.is_empty() || group.len() != 3 {
+            return None;
+        }
+        number.push_str(group);
+    }
+    if let Some(fraction) = fraction {
+        number.push('.');
+        number.push_str(fraction);
+    }
+    if number.is_empty()
+        || number == "."
+        || !number.chars().all(|c| c.is_ascii_digit() || c == '.')
+        || number.matches('.').count() > 1
+    {
+        return None;
+    }
+    Some(number)
+}
+
+/// Parses an amount in BTC typed by the user, see normalize_number.
+pub fn parse_btc(input: &str) -> Option<bitcoin::Amount> {
+    bitcoin::Amount::from_str_in(&normalize_number(input)?, bitcoin::Denomination::Bitcoin).ok()
+}
+
+/// Parses an integer typed by the user, the commas and dots being thousand
+/// separators followed by groups of three digits.
+pub fn parse_integer<T: std::str::FromStr>(input: &str) -> Option<T> {
+    let digits: String = input
+        .trim()
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '\'' && *c != '\u{2019}')
+        .collect();
+    let mut groups = digits.split([',', '.']);
+    let mut number = groups.next().filter(|first| !first.is_empty())?.to_string();
+    for group in groups {
+        if group.len() != 3 {
+            return None;
+        }
+        number.push_str(group);
+    }
+    if !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    number.parse().ok()
+}
+
+/// Returns the approximate duration of the blocks at 10 minutes per block
+/// in the language, e.g. "about 1 day 6 hours".
+pub fn blocks_duration(blocks: u32, language: Language) -> String {
+    let minutes = blocks as u64 * 10;
+    let (days, hours, minutes) = (minutes / 1440, minutes % 1440 / 60, minutes % 60);
+    let plural = |n: u64, one: &'static str, many: &'static str| {
+        language.trf(if n > 1 { many } else { one }, &[&n])
+    };
+    let mut parts = Vec::new();
+    if days > 0 {
+        parts.push(plural(days, "{} day", "{} days"));
+    }
+    if hours > 0 {
+        parts.push(plural(hours, "{} hour", "{} hours"));
+    }
+    // The minutes are only shown for the delays under a day.
+    if minutes > 0 && days == 0 {
+        parts.push(plural(minutes, "{} minute", "{} minutes"));
+    }
+    if parts.is_empty() {
+        return language.tr("no delay").to_string();
+    }
+    language.trf("about {}", &[&parts.join(" ")])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_separators() {
+        assert_eq!(normalize_number("1,5").as_deref(), Some("1.5"));
+        assert_eq!(normalize_number("1.5").as_deref(), Some("1.5"));
+        assert_eq!(normalize_number("1 234,5").as_deref(), Some("1234.5"));
+        assert_eq!(normalize_number("1.234,5").as_deref(), Some("1234.5"));
+        assert_eq!(normalize_number("1,234.5").as_deref(), Some("1234.5"));
+        assert_eq!(normalize_number("1.234.567").as_deref(), Some("1234567"));
+        assert_eq!(
+            normalize_number("1'234'567,25").as_deref(),
+            Some("1234567.25")
+        );
+    }
+
+    #[test]
+    fn normalize_rejects_bad_groups() {
+        assert_eq!(normalize_number("0..5"), None);
+        assert_eq!(normalize_number("0.1.5"), None);
+        assert_eq!(normalize_number("1,23.5"), None);
+        assert_eq!(normalize_number(",234.5"), None);
+        assert_eq!(normalize_number("1.2,5"), None);
+        assert_eq!(normalize_number("1,5,"), None);
+        assert_eq!(normalize_number("."), None);
+        assert_eq!(normalize_number("-1"), None);
+    }
+}
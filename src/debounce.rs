@@ -0,0 +1,64 @@
+//! Debouncing of the work triggered by the keystrokes: the parsing or the
+//! filtering is done once the user stopped typing for the interval.
+
+use std::time::Duration;
+
+use iced::Command;
+use iced_native::futures::channel::oneshot;
+
+/// Default number of milliseconds without keystroke before the work is done.
+pub const DEFAULT_INTERVAL_MS: u64 = 300;
+
+#[derive(Debug, Clone)]
+pub struct Debouncer {
+    interval: Duration,
+    /// Id of the last wait, the previous ones are expired.
+    last: u64,
+}
+
+impl Debouncer {
+    pub fn new(interval: Duration) -> Self {
+        Self { interval, last: 0 }
+    }
+
+    /// Starts a new wait expiring the previous ones, the command returns
+    /// the message with the id of the wait once the interval elapsed.
+    pub fn trigger<M, F>(&mut self, f: F) -> Command<M>
+    where
+        M: 'static + Send,
+        F: 'static + Send + Fn(u64) -> M,
+    {
+        self.last += 1;
+        let (interval, id) = (self.interval, self.last);
+        Command::perform(
+            async move {
+                delay(interval).await;
+                id
+            },
+            f,
+        )
+    }
+
+    /// Returns true if no other wait started after the one with the given id.
+    pub fn is_due(&self, id: u64) -> bool {
+        id == self.last
+    }
+}
+
+impl Default for Debouncer {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(DEFAULT_INTERVAL_MS))
+    }
+}
+
+/// Waits for the interval without blocking the executor of the commands.
+/// iced is built without an async runtime providing timers, the wait ends
+/// with a thread sleeping for the interval.
+pub async fn delay(interval: Duration) {
+    let (sender, receiver) = oneshot::channel();
+    std::thread::spawn(move || {
+        std::thread::sleep(interval);
+        let _ = sender.send(());
+    });
+    let _ = receiver.await;
+}
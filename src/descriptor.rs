@@ -0,0 +1,178 @@
+//! Output script descriptor checksums, as defined by BIP-380.
+//! The checksum of a descriptor is displayed with it, so that users can
+//! check that a descriptor was not corrupted when copied between machines.
+//! The deposit addresses are derived from the deposit descriptor as well.
+
+use std::ops::Range;
+use std::str::FromStr;
+
+use miniscript::descriptor::DescriptorTrait;
+use revault_tx::{
+    bitcoin::{secp256k1::Secp256k1, util::bip32::ChildNumber},
+    scripts::DepositDescriptor,
+};
+
+const INPUT_CHARSET: &str =
+    "0123456789()[],'/*abcdefgh@:$%{}IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~ijklmnopqrstuvwxyzABCDEFGH`#\"\\ ";
+const CHECKSUM_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+fn poly_mod(mut c: u64, val: u64) -> u64 {
+    let c0 = c >> 35;
+    c = ((c & 0x7ffffffff) << 5) ^ val;
+    if c0 & 1 > 0 {
+        c ^= 0xf5dee51989
+    };
+    if c0 & 2 > 0 {
+        c ^= 0xa9fdca3312
+    };
+    if c0 & 4 > 0 {
+        c ^= 0x1bab10e32d
+    };
+    if c0 & 8 > 0 {
+        c ^= 0x3706b1677a
+    };
+    if c0 & 16 > 0 {
+        c ^= 0x644d626ffd
+    };
+    c
+}
+
+/// Derives the deposit addresses of the descriptor at the indexes, as the daemon does.
+pub fn deposit_addresses(
+    descriptor: &str,
+    network: bitcoin::Network,
+    indexes: Range<u32>,
+) -> Result<Vec<String>, String> {
+    // revault_tx depends on another version of the bitcoin crate.
+    let network =
+        revault_tx::bitcoin::Network::from_str(&network.to_string()).map_err(|e| e.to_string())?;
+    let descriptor = DepositDescriptor::from_str(descriptor)
+        .map_err(|e| format!("Invalid deposit descriptor: {}", e))?;
+    let secp = Secp256k1::verification_only();
+    indexes
+        .map(|index| {
+            let child = ChildNumber::from_normal_idx(index).map_err(|e| e.to_string())?;
+            descriptor
+                .derive(child, &secp)
+                .into_inner()
+                .address(network)
+                .map(|address| address.to_string())
+                .map_err(|e| e.to_string())
+        })
+        .collect()
+}
+
+/// Splits the descriptor from its checksum, if the descriptor has one.
+pub fn split(desc: &str) -> (&str, Option<&str>) {
+    let mut parts = desc.trim().splitn(2, '#');
+    (parts.next().unwrap_or_default(), parts.next())
+}
+
+/// Computes the checksum of the descriptor, ignoring its current checksum if any.
+/// Returns None if the descriptor has a character not allowed in descriptors.
+pub fn checksum(desc: &str) -> Option<String> {
+    let (desc, _) = split(desc);
+    let mut c = 1;
+    let mut cls = 0;
+    let mut clscount = 0;
+    for ch in desc.chars() {
+        let pos = INPUT_CHARSET.find(ch)? as u64;
+        c = poly_mod(c, pos & 31);
+        cls = cls * 3 + (pos >> 5);
+        clscount += 1;
+        if clscount == 3 {
+            c = poly_mod(c, cls);
+            cls = 0;
+            clscount = 0;
+        }
+    }
+    if clscount > 0 {
+        c = poly_mod(c, cls);
+    }
+    (0..8).for_each(|_| c = poly_mod(c, 0));
+    c ^= 1;
+
+    Some(
+        (0..8)
+            .map(|j| CHECKSUM_CHARSET[((c >> (5 * (7 - j))) & 31) as usize] as char)
+            .collect(),
+    )
+}
+
+/// ChecksumError is the reason a descriptor checksum could not be verified.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChecksumError {
+    InvalidCharacter,
+    /// The descriptor has a checksum which is not the one of its content.
+    Mismatch {
+        found: String,
+        expected: String,
+    },
+}
+
+impl std::fmt::Display for ChecksumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::InvalidCharacter => write!(f, "The descriptor has an invalid character"),
+            Self::Mismatch { found, expected } => write!(
+                f,
+                "The descriptor checksum is {} but its content gives {}, it may be corrupted",
+                found, expected
+            ),
+        }
+    }
+}
+
+/// Returns the checksum of the descriptor after checking
+/// that it matches the checksum the descriptor ends with, if any.
+pub fn verify(desc: &str) -> Result<String, ChecksumError> {
+    let expected = checksum(desc).ok_or(ChecksumError::InvalidCharacter)?;
+    match split(desc).1 {
+        Some(found) if found != expected => Err(ChecksumError::Mismatch {
+            found: found.to_string(),
+            expected,
+        }),
+        _ => Ok(expected),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn descriptor_checksum() {
+        // Vectors of the descriptors documentation of Bitcoin Core.
+        assert_eq!(
+            checksum("addr(mkmZxiEcEd8ZqjQWVZuC6so5dFMKEFpN2j)").as_deref(),
+            Some("02wpgw69")
+        );
+        assert_eq!(checksum("raw(deadbeef)").as_deref(), Some("89f8spxm"));
+        // The current checksum is ignored.
+        assert_eq!(
+            checksum("raw(deadbeef)#aaaaaaaa").as_deref(),
+            Some("89f8spxm")
+        );
+        assert_eq!(checksum("raw(deadbeef)é"), None);
+    }
+
+    #[test]
+    fn descriptor_verify() {
+        assert_eq!(
+            verify("addr(mkmZxiEcEd8ZqjQWVZuC6so5dFMKEFpN2j)#02wpgw69"),
+            Ok("02wpgw69".to_string())
+        );
+        assert_eq!(verify(" raw(deadbeef) "), Ok("89f8spxm".to_string()));
+        assert_eq!(
+            verify("raw(deadbeef)#89f8spxn"),
+            Err(ChecksumError::Mismatch {
+                found: "89f8spxn".to_string(),
+                expected: "89f8spxm".to_string(),
+            })
+        );
+        assert_eq!(
+            verify("raw(deadbeef)é#89f8spxm"),
+            Err(ChecksumError::InvalidCharacter)
+        );
+    }
+}
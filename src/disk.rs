@@ -0,0 +1,109 @@
+//! Checks run before writing to a directory, so that a read-only or full
+//! disk is reported with the directory at fault instead of a bare io error.
+
+use std::path::{Path, PathBuf};
+
+/// Free space required in the datadir by the installer, revaultd stores
+/// its database next to the configuration.
+pub const MIN_DATADIR_SPACE: u64 = 100 * 1024 * 1024;
+
+const PROBE_FILE_NAME: &str = ".revault_gui_write_check";
+
+#[derive(Debug, Clone)]
+pub enum DiskError {
+    NotWritable {
+        path: PathBuf,
+        reason: String,
+    },
+    NotEnoughSpace {
+        path: PathBuf,
+        available: u64,
+        required: u64,
+    },
+}
+
+impl std::fmt::Display for DiskError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::NotWritable { path, reason } => write!(
+                f,
+                "directory not writable: {} ({}), check its permissions",
+                path.display(),
+                reason
+            ),
+            Self::NotEnoughSpace {
+                path,
+                available,
+                required,
+            } => write!(
+                f,
+                "not enough disk space in {}: {} available, {} required",
+                path.display(),
+                human_size(*available),
+                human_size(*required)
+            ),
+        }
+    }
+}
+
+fn human_size(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+    } else {
+        format!("{:.1} KiB", bytes as f64 / 1024.0)
+    }
+}
+
+/// Checks that `required` bytes can be written in the directory.
+/// A directory which does not exist yet is checked through its nearest
+/// existing parent, where it will be created.
+pub fn check_dir(dir: &Path, required: u64) -> Result<(), DiskError> {
+    let existing = dir
+        .ancestors()
+        .find(|p| p.is_dir())
+        .unwrap_or(dir)
+        .to_path_buf();
+
+    let probe = existing.join(PROBE_FILE_NAME);
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(&probe)
+        .map_err(|e| DiskError::NotWritable {
+            path: existing.clone(),
+            reason: e.to_string(),
+        })?;
+    let _ = std::fs::remove_file(&probe);
+
+    if let Some(available) = available_space(&existing) {
+        if available < required {
+            return Err(DiskError::NotEnoughSpace {
+                path: existing,
+                available,
+                required,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Returns the space available to the user in the filesystem of the path.
+#[cfg(unix)]
+fn available_space(path: &Path) -> Option<u64> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = std::ffi::CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(path.as_ptr(), &mut stat) } != 0 {
+        return None;
+    }
+    Some(stat.f_bavail as u64 * stat.f_frsize as u64)
+}
+
+/// The free space is not checked on other platforms,
+/// the write itself reports a full disk.
+#[cfg(not(unix))]
+fn available_space(_path: &Path) -> Option<u64> {
+    None
+}
@@ -0,0 +1,1405 @@
+//! French translations of the texts of the interface, grouped by view.
+
+pub const FRENCH: &[(&str, &str)] = &[
+    // durations
+    ("no delay", "aucun délai"),
+    ("about {}", "environ {}"),
+    ("{} day", "{} jour"),
+    ("{} days", "{} jours"),
+    ("{} hour", "{} heure"),
+    ("{} hours", "{} heures"),
+    ("{} minute", "{} minute"),
+    ("{} minutes", "{} minutes"),
+    // sidebar
+    ("Home", "Accueil"),
+    ("Vaults", "Coffres"),
+    ("Network", "Réseau"),
+    ("Logs", "Journaux"),
+    ("Console", "Console"),
+    ("Settings", "Paramètres"),
+    ("Deposit", "Dépôt"),
+    ("Send", "Envoyer"),
+    ("Delegate funds", "Déléguer des fonds"),
+    ("Create vault", "Créer un coffre"),
+    ("Emergency", "Urgence"),
+    ("Updated at", "Mis à jour à"),
+    ("Not updated yet", "Pas encore mis à jour"),
+    ("Resume", "Reprendre"),
+    ("Pause", "Pause"),
+    // tour
+    ("Skip the tour", "Passer la visite"),
+    ("Previous", "Précédent"),
+    ("Next", "Suivant"),
+    ("Done", "Terminé"),
+    ("At the top of the sidebar:", "En haut de la barre latérale :"),
+    ("Sidebar, Home:", "Barre latérale, Accueil :"),
+    ("Sidebar, Vaults:", "Barre latérale, Coffres :"),
+    ("Sidebar, Deposit:", "Barre latérale, Dépôt :"),
+    ("Sidebar, Send:", "Barre latérale, Envoyer :"),
+    ("Sidebar, Create vault:", "Barre latérale, Créer un coffre :"),
+    ("Sidebar, Delegate funds:", "Barre latérale, Déléguer des fonds :"),
+    ("Sidebar, Emergency:", "Barre latérale, Urgence :"),
+    ("Sidebar, Network:", "Barre latérale, Réseau :"),
+    ("Sidebar, Settings:", "Barre latérale, Paramètres :"),
+    ("Your role", "Votre rôle"),
+    (
+        "The top of the sidebar shows the role you are using Revault with. If you are both a \
+         stakeholder and a manager, switch between the two roles there.",
+        "Le haut de la barre latérale indique le rôle avec lequel vous utilisez Revault. Si vous \
+         êtes à la fois partie prenante et gestionnaire, passez d'un rôle à l'autre à cet \
+         endroit.",
+    ),
+    (
+        "The home sums up your funds: the vaults being secured, the active vaults and the pending \
+         moves.",
+        "L'accueil résume vos fonds : les coffres en cours de sécurisation, les coffres actifs et \
+         les mouvements en attente.",
+    ),
+    (
+        "Every vault is listed here with its status. Select a vault to see its onchain \
+         transactions and the actions available.",
+        "Chaque coffre est listé ici avec son statut. Sélectionnez un coffre pour voir ses \
+         transactions onchain et les actions disponibles.",
+    ),
+    ("Create vaults", "Créer des coffres"),
+    (
+        "Deposits must be secured: sign their revocation transactions here so that every move of \
+         the funds can be canceled.",
+        "Les dépôts doivent être sécurisés : signez ici leurs transactions de révocation afin que \
+         chaque mouvement des fonds puisse être annulé.",
+    ),
+    (
+        "Secured vaults are delegated to the managers by signing their unvault transaction here.",
+        "Les coffres sécurisés sont délégués aux gestionnaires en signant ici leur transaction \
+         d'unvault.",
+    ),
+    (
+        "In case of a threat, the emergency action sends every vault to the emergency deep vault. \
+         It cannot be undone.",
+        "En cas de menace, l'action d'urgence envoie chaque coffre vers l'emergency deep vault. \
+         Elle est irréversible.",
+    ),
+    (
+        "Get a new deposit address here. Deposits become usable once the stakeholders secured and \
+         delegated them.",
+        "Obtenez ici une nouvelle adresse de dépôt. Les dépôts deviennent utilisables une fois \
+         sécurisés et délégués par les parties prenantes.",
+    ),
+    (
+        "Create a spend transaction from the delegated vaults, then share it with the other \
+         managers to sign it.",
+        "Créez une transaction de dépense à partir des coffres délégués, puis partagez-la avec les \
+         autres gestionnaires pour qu'ils la signent.",
+    ),
+    (
+        "The settings show the configuration of revaultd and of the GUI. You are ready to go!",
+        "Les paramètres affichent la configuration de revaultd et de l'interface. Vous êtes prêt !",
+    ),
+    // banners
+    (
+        "The coordinator is unreachable, Revault is running in degraded mode",
+        "Le coordinateur est injoignable, Revault fonctionne en mode dégradé",
+    ),
+    ("Still available:", "Toujours disponible :"),
+    ("- viewing vaults and transactions", "- consulter les coffres et les transactions"),
+    ("- getting a deposit address", "- obtenir une adresse de dépôt"),
+    (
+        "- signing revocation transactions locally",
+        "- signer localement les transactions de révocation",
+    ),
+    ("- emergency and revaulting", "- l'urgence et le revault"),
+    ("Blocked until it is back:", "Bloqué jusqu'à son retour :"),
+    (
+        "- sharing signatures with the other participants",
+        "- partager les signatures avec les autres participants",
+    ),
+    ("- delegating vaults", "- déléguer des coffres"),
+    (
+        "- announcing and broadcasting spend transactions",
+        "- annoncer et diffuser les transactions de dépense",
+    ),
+    (
+        "The connection is checked again on the next action requiring the coordinator.",
+        "La connexion est vérifiée à nouveau lors de la prochaine action nécessitant le \
+         coordinateur.",
+    ),
+    (
+        "SCREENSHOT MODE - the transaction ids, addresses and keys displayed are placeholders, do \
+         not use them",
+        "MODE CAPTURE D'ÉCRAN - les identifiants de transaction, adresses et clés affichés sont \
+         fictifs, ne les utilisez pas",
+    ),
+    (
+        "TRAINING - regtest sandbox, the funds and the actions have no real value",
+        "ENTRAÎNEMENT - bac à sable regtest, les fonds et les actions n'ont aucune valeur réelle",
+    ),
+    ("revaultd was stopped at {}", "revaultd a été arrêté à {}"),
+    (
+        "The vaults are not watched until revaultd is started again.",
+        "Les coffres ne sont pas surveillés tant que revaultd n'est pas redémarré.",
+    ),
+    ("Start revaultd", "Démarrer revaultd"),
+    (
+        "The connection to revaultd was lost at {}, reconnecting...",
+        "La connexion à revaultd a été perdue à {}, reconnexion...",
+    ),
+    (
+        "The data displayed may be outdated until revaultd is back.",
+        "Les données affichées peuvent être obsolètes jusqu'au retour de revaultd.",
+    ),
+    ("revaultd restarted, reconnected to the daemon", "revaultd a redémarré, reconnecté au démon"),
+    ("Reconnected to revaultd", "Reconnecté à revaultd"),
+    ("Type {} to confirm:", "Tapez {} pour confirmer :"),
+    ("Cancel", "Annuler"),
+    ("Type an action", "Tapez une action"),
+    ("No action found", "Aucune action trouvée"),
+    ("Escape to close", "Échap pour fermer"),
+    ("Go to home", "Aller à l'accueil"),
+    ("Go to vaults", "Aller aux coffres"),
+    ("Go to network", "Aller au réseau"),
+    ("Go to settings", "Aller aux paramètres"),
+    ("Go to logs", "Aller aux journaux"),
+    ("Go to deposit", "Aller au dépôt"),
+    ("New spend", "Nouvelle dépense"),
+    ("Switch to the {} role", "Passer au rôle {}"),
+    ("Resume the refresh", "Reprendre l'actualisation"),
+    ("Pause the refresh", "Mettre en pause l'actualisation"),
+    ("Enable the screenshot mode", "Activer le mode capture d'écran"),
+    ("Disable the screenshot mode", "Désactiver le mode capture d'écran"),
+    // alerts
+    ("Unexpected unvault", "Unvault inattendu"),
+    ("Watchtower down", "Watchtower hors service"),
+    ("Since {}", "Depuis {}"),
+    ("Failed to cancel: {}", "Échec de l'annulation : {}"),
+    ("Canceling...", "Annulation..."),
+    ("Cancel the unvault", "Annuler l'unvault"),
+    ("Acknowledge", "Acquitter"),
+    // charging
+    ("Connecting to daemon...", "Connexion au démon..."),
+    ("Starting daemon...", "Démarrage du démon..."),
+    ("Synchronising with the Bitcoin network{}", "Synchronisation avec le réseau Bitcoin{}"),
+    ("Block {} of {}, {} blocks left", "Bloc {} sur {}, {} blocs restants"),
+    ("bitcoind verification progress: {}%", "progression de la vérification de bitcoind : {} %"),
+    ("Block {}", "Bloc {}"),
+    (
+        "bitcoind is not reachable by the GUI: {}",
+        "bitcoind n'est pas joignable par l'interface : {}",
+    ),
+    ("About {} left", "Environ {} restant"),
+    (
+        "The wallet opens once revaultd is synced.",
+        "Le portefeuille s'ouvre une fois revaultd synchronisé.",
+    ),
+    ("{} min", "{} min"),
+    ("a minute", "une minute"),
+    (
+        "The daemon is running but does not answer ({}), retrying...",
+        "Le démon tourne mais ne répond pas ({}), nouvelle tentative...",
+    ),
+    ("Error: {}", "Erreur : {}"),
+    ("The daemon cannot be started", "Le démon ne peut pas être démarré"),
+    (
+        "Install a supported revaultd, or set revaultd_path in the GUI configuration file to the \
+         revaultd binary to use.",
+        "Installez un revaultd pris en charge, ou renseignez revaultd_path dans le fichier de \
+         configuration de l'interface avec le binaire revaultd à utiliser.",
+    ),
+    // home
+    ("Pending spend transactions", "Transactions de dépense en attente"),
+    (
+        "These transactions are waiting for managers signatures",
+        "Ces transactions attendent les signatures des gestionnaires",
+    ),
+    (
+        "No vaults yet, start using Revault by making a deposit",
+        "Aucun coffre pour l'instant, commencez à utiliser Revault en effectuant un dépôt",
+    ),
+    ("Funds are moving:", "Des fonds sont en mouvement :"),
+    ("overview:", "aperçu :"),
+    ("are available to managers", "sont disponibles pour les gestionnaires"),
+    ("are held by stakeholders", "sont détenus par les parties prenantes"),
+    ("Cancel deadlines of the unvaults", "Échéances d'annulation des unvaults"),
+    (
+        "The cancel transactions pay {} sats/vbyte and must confirm before the end of the timelock",
+        "Les transactions d'annulation paient {} sats/vbyte et doivent être confirmées avant la \
+         fin du timelock",
+    ),
+    (
+        "{} unvault(s) at risk: a cancel may not confirm in time, act now",
+        "{} unvault(s) à risque : une annulation pourrait ne pas être confirmée à temps, agissez \
+         maintenant",
+    ),
+    ("{} sats/vbyte needed", "{} sats/vbyte nécessaires"),
+    ("feerate unknown", "taux de frais inconnu"),
+    ("{}/{} blocks left", "{}/{} blocs restants"),
+    ("Needs your attention", "Requiert votre attention"),
+    ("Emergency signing device", "Appareil de signature d'urgence"),
+    ("Last verified on {} with the device {}", "Dernière vérification le {} avec l'appareil {}"),
+    ("The device was never verified", "L'appareil n'a jamais été vérifié"),
+    ("Next verification due on {}", "Prochaine vérification prévue le {}"),
+    (
+        "Please verify your emergency signing device and its backup, then record the check:",
+        "Veuillez vérifier votre appareil de signature d'urgence et sa sauvegarde, puis \
+         enregistrez la vérification :",
+    ),
+    ("Device fingerprint", "Empreinte de l'appareil"),
+    (
+        "Please enter the 8 hexadecimal characters fingerprint",
+        "Veuillez saisir l'empreinte de 8 caractères hexadécimaux",
+    ),
+    ("Device verified", "Appareil vérifié"),
+    (" {} received in ", " {} reçus dans "),
+    (" new deposits", " nouveaux dépôts"),
+    ("+ Create vaults", "+ Créer des coffres"),
+    (" vaults", " coffres"),
+    ("  Delegated funds", "  Fonds délégués"),
+    (
+        "Delegated funds can be spent by managers,\n but you can still revert any undesired \
+         transaction.",
+        "Les fonds délégués peuvent être dépensés par les gestionnaires,\n mais vous pouvez \
+         toujours annuler toute transaction indésirable.",
+    ),
+    ("  Secured funds", "  Fonds sécurisés"),
+    (
+        "Secured funds are controlled by stakeholders only",
+        "Les fonds sécurisés sont contrôlés uniquement par les parties prenantes",
+    ),
+    (" vaults ", " coffres "),
+    (
+        "Waiting for other stakeholders' signatures",
+        "En attente des signatures des autres parties prenantes",
+    ),
+    // logs and console
+    ("Export logs", "Exporter les journaux"),
+    ("Search", "Rechercher"),
+    (
+        "The logs were exported to {}, keys, addresses and transaction ids are redacted",
+        "Les journaux ont été exportés vers {}, les clés, adresses et identifiants de transaction \
+         sont masqués",
+    ),
+    ("No log matches the filter", "Aucun journal ne correspond au filtre"),
+    ("Sending...", "Envoi..."),
+    ("Developer console", "Console développeur"),
+    ("Clear history", "Effacer l'historique"),
+    (
+        "The calls are sent to revaultd as they are, without the checks of the GUI. The \
+         emergency, revault, stop and setspendtx calls are refused: their panels ask for a \
+         confirmation",
+        "Les appels sont envoyés à revaultd tels quels, sans les vérifications de l'interface. \
+         Les appels emergency, revault, stop et setspendtx sont refusés : leurs panneaux \
+         demandent une confirmation",
+    ),
+    ("Method", "Méthode"),
+    ("No call yet", "Aucun appel pour l'instant"),
+    ("Edit again", "Modifier à nouveau"),
+    // emergency
+    ("X Close", "X Fermer"),
+    ("Broadcasted", "Diffusé"),
+    ("vaults", "coffres"),
+    (
+        "Broadcasting the emergency transactions, {} of {} done",
+        "Diffusion des transactions d'urgence, {} sur {} terminées",
+    ),
+    (
+        "{} of {} emergency broadcasts failed",
+        "{} diffusions d'urgence sur {} ont échoué",
+    ),
+    ("Retry the failed broadcasts", "Relancer les diffusions échouées"),
+    (
+        "The emergency transactions of the vaults were broadcast",
+        "Les transactions d'urgence des coffres ont été diffusées",
+    ),
+    ("Broadcast, txid {}", "Diffusée, txid {}"),
+    ("Failed: {}", "Échec : {}"),
+    ("This action will send", "Cette action va envoyer"),
+    (" from", " depuis"),
+    ("to the Emergency Deep Vault", "vers l'Emergency Deep Vault"),
+    (
+        "{} secured vaults are left out and stay where they are",
+        "{} coffres sécurisés sont laissés de côté et restent en place",
+    ),
+    (
+        "Secured vaults, the checked ones are swept to the Emergency Deep Vault",
+        "Coffres sécurisés, ceux cochés sont transférés vers l'Emergency Deep Vault",
+    ),
+    (
+        "Not secured yet, left out of the emergency",
+        "Pas encore sécurisés, laissés hors de l'urgence",
+    ),
+    // manager
+    ("Import spend transaction", "Importer une transaction de dépense"),
+    ("Enter PSBT:", "Saisissez le PSBT :"),
+    ("Signed PSBT", "PSBT signé"),
+    ("Transaction imported", "Transaction importée"),
+    ("See transaction detail", "Voir le détail de la transaction"),
+    ("Import transaction", "Importer la transaction"),
+    ("Create spend transaction", "Créer une transaction de dépense"),
+    ("Drafts", "Brouillons"),
+    ("Saved {}", "Enregistré le {}"),
+    (
+        "{} destination(s), {} vault(s) selected{}",
+        "{} destination(s), {} coffre(s) sélectionné(s){}",
+    ),
+    (", transaction generated", ", transaction générée"),
+    (
+        "Several recipients have the same address, only one output per address can be created:",
+        "Plusieurs destinataires ont la même adresse, une seule sortie par adresse peut être créée \
+         :",
+    ),
+    ("Merge", "Fusionner"),
+    ("Shuffle the order of the outputs", "Mélanger l'ordre des sorties"),
+    ("Add recipient", "Ajouter un destinataire"),
+    ("Continue", "Continuer"),
+    ("Add recipients", "Ajouter les destinataires"),
+    ("Address", "Adresse"),
+    ("Please enter a valid bitcoin address", "Veuillez saisir une adresse bitcoin valide"),
+    ("Amount in BTC, ex: 0.123 or 0,123", "Montant en BTC, ex : 0.123 ou 0,123"),
+    ("Address book: {}", "Carnet d'adresses : {}"),
+    ("< Go back", "< Retour"),
+    ("{} vaults selected: {} / {} {}", "{} coffres sélectionnés : {} / {} {}"),
+    ("Auto-select", "Sélection automatique"),
+    (
+        "The transaction can spend at most {} vaults, it would exceed the standard size",
+        "La transaction peut dépenser au plus {} coffres, elle dépasserait la taille standard",
+    ),
+    ("Missing {} {}", "Il manque {} {}"),
+    ("Select coins worth at least {} {}", "Sélectionnez des pièces d'au moins {} {}"),
+    ("Select coins", "Sélectionner les pièces"),
+    ("No label", "Pas d'étiquette"),
+    ("Received today", "Reçu aujourd'hui"),
+    ("Received 1 day ago", "Reçu il y a 1 jour"),
+    ("Received {} days ago", "Reçu il y a {} jours"),
+    ("Select fee", "Choisir les frais"),
+    ("sats/vbyte", "sats/vbyte"),
+    ("Estimating feerates...", "Estimation des taux de frais..."),
+    ("Feerate estimation unavailable: {}", "Estimation des taux de frais indisponible : {}"),
+    (
+        "bitcoind has not enough data to estimate feerates yet",
+        "bitcoind n'a pas encore assez de données pour estimer les taux de frais",
+    ),
+    ("{} sats/vbyte", "{} sats/vbyte"),
+    ("Confirmation in ~{} blocks", "Confirmation dans ~{} blocs"),
+    ("Outputs", "Sorties"),
+    ("Inputs", "Entrées"),
+    ("Fee share:", "Part des frais :"),
+    ("Feerate: ", "Taux de frais : "),
+    (
+        "Please review the destinations, these hints are advisory only:",
+        "Veuillez vérifier les destinations, ces indications sont purement consultatives :",
+    ),
+    ("Total fees: ", "Frais totaux : "),
+    (
+        "Fix the failed checks before signing the transaction",
+        "Corrigez les vérifications en échec avant de signer la transaction",
+    ),
+    ("Sign transaction", "Signer la transaction"),
+    ("Your transaction has been saved", "Votre transaction a été enregistrée"),
+    ("Validation summary", "Résumé de la validation"),
+    ("Pass", "Réussi"),
+    ("Warn", "Attention"),
+    ("Fail", "Échec"),
+    // stakeholder
+    ("Create some vaults", "Créer des coffres"),
+    (
+        " Select the deposits to secure in one signing session:",
+        " Sélectionnez les dépôts à sécuriser en une seule session de signature :",
+    ),
+    (" Click on a deposit to create a vault:", " Cliquez sur un dépôt pour créer un coffre :"),
+    ("Loading {}/{}...", "Chargement {}/{}..."),
+    ("Secure {} vaults", "Sécuriser {} coffres"),
+    ("Cancel selection", "Annuler la sélection"),
+    ("Select several", "En sélectionner plusieurs"),
+    ("No deposits", "Aucun dépôt"),
+    (
+        "Bitcoin deposits are needed in order to create a vault\n",
+        "Des dépôts en bitcoin sont nécessaires pour créer un coffre\n",
+    ),
+    (" Help", " Aide"),
+    (
+        "A vault is a deposit with revocation transactions\nsigned and shared between stakeholders",
+        "Un coffre est un dépôt dont les transactions de révocation sont\nsignées et partagées \
+         entre les parties prenantes",
+    ),
+    ("Secured", "Sécurisé"),
+    ("{}/3 signed", "{}/3 signées"),
+    ("The {} vaults are secured", "Les {} coffres sont sécurisés"),
+    (
+        "{} of {} vaults are secured, the others can be secured again from the deposit list",
+        "{} des {} coffres sont sécurisés, les autres peuvent être sécurisés à nouveau depuis la \
+         liste des dépôts",
+    ),
+    ("Vault {}/{}: sign the {} transaction", "Coffre {}/{} : signez la transaction {}"),
+    ("emergency unvault", "d'unvault d'urgence"),
+    ("cancel", "d'annulation"),
+    ("emergency", "d'urgence"),
+    ("Sharing the signatures...", "Partage des signatures..."),
+    ("Share the signatures of the {} vaults", "Partager les signatures des {} coffres"),
+    (
+        "All the revocation transactions are signed.\nThe signatures are shared with the other \
+         stakeholders at once.",
+        "Toutes les transactions de révocation sont signées.\nLes signatures sont partagées en une \
+         fois avec les autres parties prenantes.",
+    ),
+    ("X Cancel", "X Annuler"),
+    ("Delegate funds to your manager team", "Déléguer des fonds à votre équipe de gestionnaires"),
+    (" {} are allocated to managers", " {} sont alloués aux gestionnaires"),
+    (
+        " {} are waiting for other stakeholders' approval",
+        " {} attendent l'approbation des autres parties prenantes",
+    ),
+    (
+        "{} {} left of the {} {} delegation budget",
+        "{} {} restants sur le budget de délégation de {} {}",
+    ),
+    (" Click on the vaults to delegate:", " Cliquez sur les coffres à déléguer :"),
+    (
+        "No more funds can be delegated to managers",
+        "Plus aucun fonds ne peut être délégué aux gestionnaires",
+    ),
+    (
+        "By delegating you allow managers to spend the funds,\n but you can still revert any \
+         undesired transaction.",
+        "En déléguant, vous permettez aux gestionnaires de dépenser les fonds,\n mais vous pouvez \
+         toujours annuler toute transaction indésirable.",
+    ),
+    // network
+    ("Check again", "Vérifier à nouveau"),
+    ("No server is configured", "Aucun serveur n'est configuré"),
+    ("Checking...", "Vérification..."),
+    ("Reachable ({} ms)", "Joignable ({} ms)"),
+    ("Unreachable", "Injoignable"),
+    ("Last seen: {}", "Vu pour la dernière fois : {}"),
+    ("Last seen: never", "Vu pour la dernière fois : jamais"),
+    ("Contact: {}", "Contact : {}"),
+    ("Coordinator", "Coordinateur"),
+    (
+        "Coordinator status could not be retrieved: {}",
+        "Le statut du coordinateur n'a pas pu être récupéré : {}",
+    ),
+    ("Not reached by revaultd", "Non joint par revaultd"),
+    (
+        "The last request of revaultd to the coordinator failed, the signatures are shared again \
+         once it is reachable.",
+        "La dernière requête de revaultd au coordinateur a échoué, les signatures sont partagées à \
+         nouveau dès qu'il est joignable.",
+    ),
+    ("Last exchange: {} ({})", "Dernier échange : {} ({})"),
+    ("Last exchange: none from this computer", "Dernier échange : aucun depuis cet ordinateur"),
+    ("{} vaults ({} {}) {}", "{} coffres ({} {}) {}"),
+    (
+        "wait for the revocation signatures of the other stakeholders",
+        "attendent les signatures de révocation des autres parties prenantes",
+    ),
+    (
+        "wait for the unvault signatures of the other stakeholders",
+        "attendent les signatures d'unvault des autres parties prenantes",
+    ),
+    (
+        "Nothing waits for the signatures of the other participants",
+        "Rien n'attend les signatures des autres participants",
+    ),
+    ("Bitcoin Core", "Bitcoin Core"),
+    ("Running", "En marche"),
+    ("Block Height", "Hauteur de bloc"),
+    ("Pruning: disabled", "Élagage : désactivé"),
+    (
+        "Pruning: enabled, the last {} blocks are kept",
+        "Élagage : activé, les {} derniers blocs sont conservés",
+    ),
+    (
+        "Pruning configuration could not be checked: {}",
+        "La configuration de l'élagage n'a pas pu être vérifiée : {}",
+    ),
+    ("Watchtowers", "Watchtowers"),
+    ("Cosigners", "Cosignataires"),
+    // deposit
+    ("Please enter a valid amount", "Veuillez saisir un montant valide"),
+    ("Please, use this deposit address:", "Veuillez utiliser cette adresse de dépôt :"),
+    (
+        "The address {} received a deposit, use the new address below for the next one",
+        "L'adresse {} a reçu un dépôt, utilisez la nouvelle adresse ci-dessous pour le suivant",
+    ),
+    ("Payment request (BIP21)", "Demande de paiement (BIP21)"),
+    ("Amount in BTC (optional)", "Montant en BTC (facultatif)"),
+    ("Vaults of {} {}", "Coffres de {} {}"),
+    ("Planned deposit in BTC", "Dépôt prévu en BTC"),
+    (
+        "The planned deposit requires {} vaults, split it in deposits of at most {} vaults",
+        "Le dépôt prévu nécessite {} coffres, répartissez-le en dépôts d'au plus {} coffres",
+    ),
+    ("Suggested: {} deposits, {}", "Suggestion : {} dépôts, {}"),
+    ("Generate {} addresses", "Générer {} adresses"),
+    ("Previous deposit addresses", "Adresses de dépôt précédentes"),
+    (
+        "An address received several deposits, reusing an address links the deposits onchain. \
+         Always deposit to the address displayed above.",
+        "Une adresse a reçu plusieurs dépôts, réutiliser une adresse lie les dépôts onchain. \
+         Déposez toujours à l'adresse affichée ci-dessus.",
+    ),
+    ("Unused", "Inutilisée"),
+    ("{} {} {} in {} deposits", "{} {} {} en {} dépôts"),
+    ("{} {} {} in {} deposit", "{} {} {} en {} dépôt"),
+    ("Reused:", "Réutilisée :"),
+    ("Used:", "Utilisée :"),
+    ("After the deposit is confirmed:", "Une fois le dépôt confirmé :"),
+    (
+        "- all {} stakeholders must sign the revocation transactions to secure the vault",
+        "- les {} parties prenantes doivent toutes signer les transactions de révocation pour \
+         sécuriser le coffre",
+    ),
+    (
+        "- all stakeholders must sign the revocation transactions to secure the vault",
+        "- toutes les parties prenantes doivent signer les transactions de révocation pour \
+         sécuriser le coffre",
+    ),
+    (
+        "- no watchtower is configured for your key",
+        "- aucune watchtower n'est configurée pour votre clé",
+    ),
+    (
+        "- the revocation signatures must be acknowledged by your watchtowers:",
+        "- les signatures de révocation doivent être acquittées par vos watchtowers :",
+    ),
+    (
+        "- each stakeholder's watchtowers must acknowledge the revocation signatures",
+        "- les watchtowers de chaque partie prenante doivent acquitter les signatures de \
+         révocation",
+    ),
+    (
+        "- the stakeholders must then delegate the vault to the managers",
+        "- les parties prenantes doivent ensuite déléguer le coffre aux gestionnaires",
+    ),
+    (
+        "A spend of the vault can be revoked during {} blocks after its unvault.",
+        "Une dépense du coffre peut être révoquée pendant {} blocs après son unvault.",
+    ),
+    // vault
+    ("received at {}", "reçu le {}"),
+    ("Actions from this GUI", "Actions depuis cette interface"),
+    (
+        "Delegating this vault exceeds the delegation budget",
+        "Déléguer ce coffre dépasse le budget de délégation",
+    ),
+    (
+        "{} {} above the budget agreed by the stakeholders",
+        "{} {} au-dessus du budget convenu par les parties prenantes",
+    ),
+    ("Delegate anyway", "Déléguer quand même"),
+    (
+        "Waiting for the signatures of the other participants since {}",
+        "En attente des signatures des autres participants depuis {}",
+    ),
+    (
+        "revaultd fetches the signatures from the coordinator in the background.",
+        "revaultd récupère les signatures auprès du coordinateur en arrière-plan.",
+    ),
+    ("Last checked at {}", "Dernière vérification à {}"),
+    ("{} failed checks in a row", "{} vérifications échouées d'affilée"),
+    ("{} failed check in a row", "{} vérification échouée d'affilée"),
+    ("Check now", "Vérifier maintenant"),
+    (
+        "The unvault transaction is not confirmed yet, the {} blocks of the delay start at its \
+         confirmation",
+        "La transaction d'unvault n'est pas encore confirmée, les {} blocs du délai commencent à \
+         sa confirmation",
+    ),
+    (
+        "The delay expired, the spend transaction is valid",
+        "Le délai a expiré, la transaction de dépense est valide",
+    ),
+    ("The delay expired, the vault can be spent", "Le délai a expiré, le coffre peut être dépensé"),
+    (
+        "The spend transaction is valid in {} blocks ({})",
+        "La transaction de dépense est valide dans {} blocs ({})",
+    ),
+    ("{} blocks ({}) left to cancel the unvault", "{} blocs ({}) restants pour annuler l'unvault"),
+    ("{} of {} blocks elapsed at block {}", "{} blocs sur {} écoulés au bloc {}"),
+    ("Notes", "Notes"),
+    ("{} since {}", "{} depuis {}"),
+    ("When the vault is {}", "Quand le coffre est {}"),
+    ("Note", "Note"),
+    ("when the vault is", "quand le coffre est"),
+    ("Timeline", "Chronologie"),
+    ("No event recorded yet", "Aucun événement enregistré pour l'instant"),
+    ("Label, e.g. Q3 payroll buffer", "Étiquette, ex. réserve salaires T3"),
+    (
+        "Do you want to create a vault from this deposit?",
+        "Voulez-vous créer un coffre à partir de ce dépôt ?",
+    ),
+    (
+        "Do you want to delegate vault to manager ? ",
+        "Voulez-vous déléguer le coffre aux gestionnaires ? ",
+    ),
+    ("Delegate vault", "Déléguer le coffre"),
+    (
+        "Funds are moving, do you want to revault them?",
+        "Les fonds sont en mouvement, voulez-vous les revaulter ?",
+    ),
+    ("Revault", "Revault"),
+    ("Onchain transactions:", "Transactions onchain :"),
+    ("Received at {}", "Reçu le {}"),
+    ("Blockheight: {}", "Hauteur de bloc : {}"),
+    ("Not in a block", "Pas dans un bloc"),
+    ("{} confirmations", "{} confirmations"),
+    ("{} confirmation", "{} confirmation"),
+    ("Confirmed", "Confirmée"),
+    ("Broadcast, not confirmed yet", "Diffusée, pas encore confirmée"),
+    ("{} sat/vbyte", "{} sat/vbyte"),
+    ("Transaction chain:", "Chaîne de transactions :"),
+    ("Spend not confirmed yet", "Dépense pas encore confirmée"),
+    (
+        "Spend confirmed ({}/{} confirmations before final)",
+        "Dépense confirmée ({}/{} confirmations avant d'être définitive)",
+    ),
+    ("Spend final", "Dépense définitive"),
+    ("   Emergency TX", "   TX d'urgence"),
+    ("   Emergency unvault TX", "   TX d'unvault d'urgence"),
+    ("   Emergency Unvault TX", "   TX d'unvault d'urgence"),
+    ("   Cancel TX", "   TX d'annulation"),
+    ("Failed to connect to revaultd: {}", "Échec de la connexion à revaultd : {}"),
+    ("Retry", "Réessayer"),
+    ("< vault transactions", "< transactions du coffre"),
+    ("Delegate vault to manager", "Déléguer le coffre aux gestionnaires"),
+    (
+        "the unvault transaction must be signed in order to delegate the fund to the managers.",
+        "la transaction d'unvault doit être signée pour déléguer les fonds aux gestionnaires.",
+    ),
+    ("waiting for revauld...", "en attente de revaultd..."),
+    ("The cancel transaction is broadcasted", "La transaction d'annulation est diffusée"),
+    ("Revault vault", "Revaulter le coffre"),
+    (
+        "The cancel transaction was not broadcast",
+        "La transaction d'annulation n'a pas été diffusée",
+    ),
+    ("Spend transaction", "Transaction de dépense"),
+    ("Cancel transaction", "Transaction d'annulation"),
+    ("Unvault Emergency transaction", "Transaction d'unvault d'urgence"),
+    ("Emergency transaction", "Transaction d'urgence"),
+    ("Unvault transaction", "Transaction d'unvault"),
+    ("Deposit transaction", "Transaction de dépôt"),
+    ("Not created", "Non créée"),
+    ("Unvault", "Unvault"),
+    ("Unvault Emergency", "Unvault d'urgence"),
+    ("Spend", "Dépense"),
+    ("Not signed", "Non signée"),
+    (
+        "Waiting for the signatures of the other stakeholders",
+        "En attente des signatures des autres parties prenantes",
+    ),
+    // labels
+    ("Delete", "Supprimer"),
+    ("Label", "Étiquette"),
+    ("Assign label", "Attribuer l'étiquette"),
+    ("{} selected", "{} sélectionné(s)"),
+    (
+        "Select vaults to label or export them, hold shift to select a range",
+        "Sélectionnez des coffres pour les étiqueter ou les exporter, maintenez Maj pour \
+         sélectionner une plage",
+    ),
+    ("Copy as CSV", "Copier en CSV"),
+    ("Manage labels", "Gérer les étiquettes"),
+    ("Labels", "Étiquettes"),
+    ("No label yet", "Aucune étiquette pour l'instant"),
+    ("Rename", "Renommer"),
+    ("{} vaults", "{} coffres"),
+    ("{} vault", "{} coffre"),
+    ("New name", "Nouveau nom"),
+    ("Merge into", "Fusionner dans"),
+    // vaults
+    ("Fund flow", "Flux des fonds"),
+    ("Grid view", "Vue en grille"),
+    ("List view", "Vue en liste"),
+    ("Export history", "Exporter l'historique"),
+    (
+        "Search by txid, outpoint, address or label",
+        "Rechercher par txid, outpoint, adresse ou étiquette",
+    ),
+    ("Restore selected vaults", "Restaurer les coffres sélectionnés"),
+    ("Archive selected vaults", "Archiver les coffres sélectionnés"),
+    ("Show more ({} of {} vaults displayed)", "Afficher plus ({} coffres affichés sur {})"),
+    ("History of all the vaults exported to {}", "Historique de tous les coffres exporté vers {}"),
+    ("Unknown vault", "Coffre inconnu"),
+    ("Reset", "Réinitialiser"),
+    ("Loading...", "Chargement..."),
+    ("No spend transaction yet", "Aucune transaction de dépense pour l'instant"),
+    ("→ vault {}", "→ coffre {}"),
+    // spend transaction
+    ("Broadcasting", "Diffusion"),
+    ("Loading the spent vaults...", "Chargement des coffres dépensés..."),
+    ("Unknown amount", "Montant inconnu"),
+    ("{} (back to the wallet)", "{} (retour au portefeuille)"),
+    ("Unknown script", "Script inconnu"),
+    ("Sent: ", "Envoyé : "),
+    ("Change: ", "Monnaie : "),
+    ("CPFP output: ", "Sortie CPFP : "),
+    ("Fees: ", "Frais : "),
+    ("Unknown, an input amount is missing", "Inconnu, le montant d'une entrée manque"),
+    ("at least {} sats/vbyte", "au moins {} sats/vbyte"),
+    ("Manager signatures", "Signatures des gestionnaires"),
+    ("Signed", "Signée"),
+    ("Not signed yet", "Pas encore signée"),
+    ("Share and update", "Partager et mettre à jour"),
+    ("Sign", "Signer"),
+    ("Broadcast", "Diffuser"),
+    (
+        "revaultd only announces the spend to the coordinator once all the managers signed it. \
+         Send this PSBT to the other managers, then enter their signed PSBT below to add their \
+         signatures.",
+        "revaultd n'annonce la dépense au coordinateur qu'une fois signée par tous les \
+         gestionnaires. Envoyez ce PSBT aux autres gestionnaires, puis saisissez leur PSBT signé \
+         ci-dessous pour ajouter leurs signatures.",
+    ),
+    ("Update transaction", "Mettre à jour la transaction"),
+    ("Transaction updated", "Transaction mise à jour"),
+    ("Deleting", "Suppression"),
+    ("Deleted", "Supprimée"),
+    ("Delete transaction", "Supprimer la transaction"),
+    (
+        "Are you sure you want to delete this transaction?",
+        "Voulez-vous vraiment supprimer cette transaction ?",
+    ),
+    ("Yes broadcast", "Oui, diffuser"),
+    (
+        "Are you sure you want to broadcast this transaction ?",
+        "Voulez-vous vraiment diffuser cette transaction ?",
+    ),
+    ("txid: {}", "txid : {}"),
+    ("Waiting for your signature", "En attente de votre signature"),
+    ("Fees: {}", "Frais : {}"),
+    // sign
+    ("Sign emergency transaction", "Signer la transaction d'urgence"),
+    ("Sign emergency unvault transaction", "Signer la transaction d'unvault d'urgence"),
+    ("Sign cancel transaction", "Signer la transaction d'annulation"),
+    ("Sign spend transaction", "Signer la transaction de dépense"),
+    ("Sign unvault transaction", "Signer la transaction d'unvault"),
+    ("Use PSBT", "Utiliser un PSBT"),
+    ("Connect device", "Connecter l'appareil"),
+    (" Sign transaction ", " Signer la transaction "),
+    ("Use hardware module", "Utiliser le module matériel"),
+    ("PSBT file", "Fichier PSBT"),
+    ("Export unsigned", "Exporter non signé"),
+    ("Import signed", "Importer signé"),
+    ("Unsigned PSBT written to {}", "PSBT non signé écrit dans {}"),
+    ("Input {}: {}", "Entrée {} : {}"),
+    ("success", "succès"),
+    (" Processing ", " Traitement "),
+    // settings
+    ("General", "Général"),
+    ("Coordinator host", "Hôte du coordinateur"),
+    ("Coordinator poll", "Interrogation du coordinateur"),
+    ("Data dir", "Répertoire de données"),
+    ("Daemon", "Démon"),
+    ("Log level", "Niveau de journalisation"),
+    ("Bitcoind", "Bitcoind"),
+    ("Cookie file path", "Chemin du fichier cookie"),
+    ("Socket address", "Adresse de socket"),
+    ("Poll interval", "Intervalle d'interrogation"),
+    ("Bitcoin scripts", "Scripts Bitcoin"),
+    ("Deposit descriptor", "Descripteur de dépôt"),
+    ("Unvault descriptor", "Descripteur d'unvault"),
+    ("CPFP descriptor", "Descripteur CPFP"),
+    ("My stakeholder info", "Mes informations de partie prenante"),
+    (
+        "Stakeholder-specific parameters, such as the xpub, the emergency_address, the watchtowers",
+        "Paramètres propres aux parties prenantes, comme la xpub, l'emergency_address, les \
+         watchtowers",
+    ),
+    ("Emergency address", "Adresse d'urgence"),
+    ("My manager info", "Mes informations de gestionnaire"),
+    (
+        "Manager-specific parameters, such as the xpub and the cosigners",
+        "Paramètres propres aux gestionnaires, comme la xpub et les cosignataires",
+    ),
+    ("deposit", "de dépôt"),
+    ("unvault", "d'unvault"),
+    ("Watchtower", "Watchtower"),
+    ("Status", "Statut"),
+    ("Version", "Version"),
+    ("Uptime", "Temps de fonctionnement"),
+    ("Blockheight", "Hauteur de bloc"),
+    ("Stakeholders", "Parties prenantes"),
+    ("Managers", "Gestionnaires"),
+    ("Name", "Nom"),
+    ("Email or matrix", "E-mail ou matrix"),
+    ("Phone", "Téléphone"),
+    ("Add", "Ajouter"),
+    ("Save", "Enregistrer"),
+    ("{} seconds", "{} secondes"),
+    ("Not set", "Non défini"),
+    ("Checksum: {}", "Somme de contrôle : {}"),
+    ("Fingerprint", "Empreinte"),
+    ("Configurations", "Configurations"),
+    (
+        "Open another configuration in this window, the revaultd of this one keeps running",
+        "Ouvrir une autre configuration dans cette fenêtre, le revaultd de celle-ci continue de \
+         tourner",
+    ),
+    ("Switch configuration", "Changer de configuration"),
+    ("unknown", "inconnu"),
+    ("Deprecated daemon API", "API du démon obsolète"),
+    ("Untested daemon API", "API du démon non testée"),
+    (
+        "revaultd {} exposes a deprecated RPC API, the next GUI releases may drop its support: \
+         upgrade revaultd to {} or later.",
+        "revaultd {} expose une API RPC obsolète, les prochaines versions de l'interface \
+         pourraient ne plus la prendre en charge : mettez revaultd à jour vers {} ou une version \
+         ultérieure.",
+    ),
+    (
+        "revaultd {} is newer than revaultd {} the GUI is written for, its RPC API was not \
+         checked against the GUI.",
+        "revaultd {} est plus récent que revaultd {} pour lequel l'interface est écrite, son API \
+         RPC n'a pas été vérifiée avec l'interface.",
+    ),
+    (
+        "The revaultd version could not be detected, the GUI is written for revaultd {}.",
+        "La version de revaultd n'a pas pu être détectée, l'interface est écrite pour revaultd {}.",
+    ),
+    ("Desktop notifications", "Notifications de bureau"),
+    (
+        "The vaults are checked every 30 seconds while the GUI is running",
+        "Les coffres sont vérifiés toutes les 30 secondes tant que l'interface est ouverte",
+    ),
+    ("Screenshot mode", "Mode capture d'écran"),
+    (
+        "The transaction ids, addresses and keys are displayed as placeholders until the GUI is \
+         closed, the copy buttons still copy the real values",
+        "Les identifiants de transaction, adresses et clés sont remplacés par des valeurs fictives \
+         jusqu'à la fermeture de l'interface, les boutons de copie copient toujours les vraies \
+         valeurs",
+    ),
+    ("Enabled", "Activé"),
+    (
+        "Displays a console in the sidebar to send any JSON-RPC call to revaultd and read its raw \
+         response, for debugging purposes",
+        "Affiche une console dans la barre latérale pour envoyer n'importe quel appel JSON-RPC à \
+         revaultd et lire sa réponse brute, à des fins de débogage",
+    ),
+    (
+        "Metrics are disabled by default. When enabled, they only count the features used and the \
+         errors met, in a file of the revaultd data directory. They are never uploaded or shared.",
+        "Les métriques sont désactivées par défaut. Une fois activées, elles comptent uniquement \
+         les fonctionnalités utilisées et les erreurs rencontrées, dans un fichier du répertoire \
+         de données de revaultd. Elles ne sont jamais envoyées ni partagées.",
+    ),
+    (
+        "Metrics are disabled. Set 'local_metrics = true' in the GUI configuration file to enable \
+         them.",
+        "Les métriques sont désactivées. Renseignez 'local_metrics = true' dans le fichier de \
+         configuration de l'interface pour les activer.",
+    ),
+    ("Features used", "Fonctionnalités utilisées"),
+    ("Errors", "Erreurs"),
+    ("Local metrics", "Métriques locales"),
+    (
+        "Usage statistics kept on this computer only",
+        "Statistiques d'utilisation conservées sur cet ordinateur uniquement",
+    ),
+    ("Nothing recorded yet", "Rien d'enregistré pour l'instant"),
+    (
+        "Labels and emergency key checks are saved on a regular basis to the backup directory, \
+         only the last backups are kept.",
+        "Les étiquettes et les vérifications de la clé d'urgence sont sauvegardées régulièrement \
+         dans le répertoire de sauvegarde, seules les dernières sauvegardes sont conservées.",
+    ),
+    (
+        "Backups are disabled. Set 'backup_dir' in the GUI configuration file to enable them.",
+        "Les sauvegardes sont désactivées. Renseignez 'backup_dir' dans le fichier de \
+         configuration de l'interface pour les activer.",
+    ),
+    ("Backup directory: {}", "Répertoire de sauvegarde : {}"),
+    ("Backup now", "Sauvegarder maintenant"),
+    (
+        "Backup of {} restored, the previous data was saved in a new backup.",
+        "Sauvegarde du {} restaurée, les données précédentes ont été enregistrées dans une \
+         nouvelle sauvegarde.",
+    ),
+    ("No backup yet", "Aucune sauvegarde pour l'instant"),
+    ("Restore", "Restaurer"),
+    ("Backups", "Sauvegardes"),
+    ("Backups of the data kept by the GUI", "Sauvegardes des données conservées par l'interface"),
+    (
+        "Writes the labels and the notes of the vaults and the address book to a json file of \
+         the revaultd data directory.",
+        "Écrit les étiquettes et les notes des coffres et le carnet d'adresses dans un fichier \
+         json du répertoire de données de revaultd.",
+    ),
+    ("Export", "Exporter"),
+    ("Exported to {}", "Exporté vers {}"),
+    ("Path of the file to import", "Chemin du fichier à importer"),
+    (
+        "For the vaults and the addresses with other local data:",
+        "Pour les coffres et les adresses ayant d'autres données locales :",
+    ),
+    ("Import", "Importer"),
+    (
+        "{} labels, {} notes and {} addresses imported, {} vaults or addresses had other local \
+         data",
+        "{} étiquettes, {} notes et {} adresses importées, {} coffres ou adresses avaient \
+         d'autres données locales",
+    ),
+    ("Labels and notes", "Étiquettes et notes"),
+    (
+        "Share the labels, the notes and the address book with the team",
+        "Partager les étiquettes, les notes et le carnet d'adresses avec l'équipe",
+    ),
+    ("Paste a descriptor", "Collez un descripteur"),
+    (
+        "Checksum {} matches the configured {} descriptor",
+        "La somme de contrôle {} correspond au descripteur {} configuré",
+    ),
+    (
+        "Checksum {} does not match any configured descriptor",
+        "La somme de contrôle {} ne correspond à aucun descripteur configuré",
+    ),
+    ("Descriptor check", "Vérification de descripteur"),
+    (
+        "Check that a descriptor copied from another machine is the same as the configured one",
+        "Vérifiez qu'un descripteur copié depuis une autre machine est identique à celui configuré",
+    ),
+    ("Coordinator public key:", "Clé publique du coordinateur :"),
+    (
+        "The datadir of the daemon could not be found",
+        "Le répertoire de données du démon est introuvable",
+    ),
+    ("Key file: {}", "Fichier de clé : {}"),
+    (
+        "The key file is missing, the daemon cannot authenticate to the coordinator",
+        "Le fichier de clé est absent, le démon ne peut pas s'authentifier auprès du coordinateur",
+    ),
+    ("Create a new key", "Créer une nouvelle clé"),
+    ("Public key:", "Clé publique :"),
+    (
+        "The key file is only readable by its owner",
+        "Le fichier de clé n'est lisible que par son propriétaire",
+    ),
+    ("Fix permissions", "Corriger les permissions"),
+    (
+        "The public key must be known by the coordinator and the servers, a new key requires a \
+         restart of the daemon.",
+        "La clé publique doit être connue du coordinateur et des serveurs, une nouvelle clé \
+         nécessite un redémarrage du démon.",
+    ),
+    ("Noise key", "Clé noise"),
+    (
+        "Static key authenticating the daemon to the coordinator",
+        "Clé statique authentifiant le démon auprès du coordinateur",
+    ),
+    ("Language", "Langue"),
+    (
+        "The language is kept until the GUI is closed, set language in the GUI configuration file \
+         to keep it",
+        "La langue est conservée jusqu'à la fermeture de l'interface, renseignez language dans le \
+         fichier de configuration pour la garder",
+    ),
+    ("Theme", "Thème"),
+    (
+        "System follows the light or dark preference of the operating system",
+        "Système suit la préférence claire ou sombre du système d'exploitation",
+    ),
+    ("Level", "Niveau"),
+    ("Directory: {}", "Répertoire : {}"),
+    ("Open", "Ouvrir"),
+    (
+        "The logs are only written to the standard output",
+        "Les journaux sont uniquement écrits sur la sortie standard",
+    ),
+    (
+        "The level is kept until the GUI is closed, set log_level in the GUI configuration file to \
+         keep it",
+        "Le niveau est conservé jusqu'à la fermeture de l'interface, renseignez log_level dans le \
+         fichier de configuration pour le garder",
+    ),
+    ("Saved", "Enregistré"),
+    ("Operator contacts", "Contacts des opérateurs"),
+    (
+        "Displayed on the network panel when the server is unreachable",
+        "Affichés sur le panneau réseau lorsque le serveur est injoignable",
+    ),
+    ("Send the report on a schedule", "Envoyer le rapport selon un calendrier"),
+    ("Last report sent on {}", "Dernier rapport envoyé le {}"),
+    ("Send now", "Envoyer maintenant"),
+    ("Missing: {}", "Manquant : {}"),
+    ("Wallet report", "Rapport du portefeuille"),
+    (
+        "Balances and activity mailed through a SMTP relay of this machine, without encryption or \
+         authentication",
+        "Soldes et activité envoyés par e-mail via un relais SMTP de cette machine, sans \
+         chiffrement ni authentification",
+    ),
+    ("No address yet", "Aucune adresse pour l'instant"),
+    ("Remove", "Retirer"),
+    ("Address book", "Carnet d'adresses"),
+    (
+        "Suggested when entering the recipients of a spend transaction",
+        "Suggérées lors de la saisie des destinataires d'une transaction de dépense",
+    ),
+    ("Testing...", "Test en cours..."),
+    ("Test bitcoind connection", "Tester la connexion à bitcoind"),
+    ("Restarting revaultd...", "Redémarrage de revaultd..."),
+    ("Save and restart", "Enregistrer et redémarrer"),
+    (
+        "Bitcoind and coordinator addresses, keys and poll intervals",
+        "Adresses, clés et intervalles d'interrogation de bitcoind et du coordinateur",
+    ),
+    ("Edit", "Modifier"),
+    ("Connection settings", "Paramètres de connexion"),
+    (
+        "The revaultd configuration file is written, the previous one is kept with the .bak \
+         extension",
+        "Le fichier de configuration de revaultd est écrit, le précédent est conservé avec \
+         l'extension .bak",
+    ),
+    ("Checking revaultd...", "Vérification de revaultd..."),
+    ("unreachable ({})", "injoignable ({})"),
+    ("running", "en marche"),
+    ("unknown, revaultd is not daemonized", "inconnu, revaultd n'est pas démonisé"),
+    ("{} ({}% synced)", "{} ({} % synchronisé)"),
+    ("Stop", "Arrêter"),
+    ("Restart", "Redémarrer"),
+    (
+        "The daemon watching the vaults, it is started by the GUI if it is not running",
+        "Le démon qui surveille les coffres, il est démarré par l'interface s'il ne tourne pas",
+    ),
+    // bitcoind
+    (
+        "bitcoind writes the cookie file in its datadir once started, check that it runs and the \
+         path of the file.",
+        "bitcoind écrit le fichier cookie dans son répertoire de données une fois démarré, \
+         vérifiez qu'il tourne et le chemin du fichier.",
+    ),
+    (
+        "Check that bitcoind runs with 'server=1' and that the address is its 'rpcbind' and \
+         'rpcport'.",
+        "Vérifiez que bitcoind tourne avec 'server=1' et que l'adresse correspond à ses 'rpcbind' \
+         et 'rpcport'.",
+    ),
+    (
+        "Check that the cookie file is the one of the bitcoind listening on this address, \
+         bitcoind writes it again each time it starts.",
+        "Vérifiez que le fichier cookie est celui du bitcoind qui écoute à cette adresse, bitcoind \
+         le réécrit à chaque démarrage.",
+    ),
+    (
+        "bitcoind is pruned and only keeps the last {} blocks, revaultd needs at least {}. \
+         Increase the 'prune' value of bitcoin.conf or set 'prune=0' (a reindex is then required) \
+         before starting revaultd.",
+        "bitcoind est élagué et ne conserve que les {} derniers blocs, revaultd en nécessite au \
+         moins {}. Augmentez la valeur 'prune' de bitcoin.conf ou renseignez 'prune=0' (une \
+         réindexation est alors nécessaire) avant de démarrer revaultd.",
+    ),
+    (
+        "bitcoind is reachable at {} with the credentials",
+        "bitcoind est joignable à {} avec les identifiants",
+    ),
+    ("The node runs the {} chain", "Le nœud suit la chaîne {}"),
+    (
+        "The node runs the {} chain instead of the {} one of the {} network, check the 'chain' of \
+         bitcoin.conf or the network of the configuration",
+        "Le nœud suit la chaîne {} au lieu de la chaîne {} du réseau {}, vérifiez le 'chain' de \
+         bitcoin.conf ou le réseau de la configuration",
+    ),
+    (
+        "The node is syncing ({}%, block {} of {}), revaultd waits for the end of the sync",
+        "Le nœud se synchronise ({} %, bloc {} sur {}), revaultd attend la fin de la \
+         synchronisation",
+    ),
+    ("The node is synced at block {}", "Le nœud est synchronisé au bloc {}"),
+    ("The node is not pruned", "Le nœud n'est pas élagué"),
+    (
+        "The node is pruned but keeps enough blocks ({})",
+        "Le nœud est élagué mais conserve assez de blocs ({})",
+    ),
+    ("The transaction index is enabled", "L'index des transactions est activé"),
+    (
+        "The transaction index is being built",
+        "L'index des transactions est en cours de construction",
+    ),
+    (
+        "The transaction index is missing, set 'txindex=1' in bitcoin.conf to look up the \
+         transactions out of the wallet of revaultd",
+        "L'index des transactions est absent, renseignez 'txindex=1' dans bitcoin.conf pour \
+         rechercher les transactions hors du portefeuille de revaultd",
+    ),
+    (
+        "The transaction index could not be checked, bitcoind is older than 0.21",
+        "L'index des transactions n'a pas pu être vérifié, bitcoind est antérieur à 0.21",
+    ),
+    // launcher
+    ("Choose the configuration to open", "Choisissez la configuration à ouvrir"),
+    (
+        "No configuration found in the default datadir",
+        "Aucune configuration trouvée dans le répertoire de données par défaut",
+    ),
+    (
+        "Path of a revault_gui.toml file",
+        "Chemin d'un fichier revault_gui.toml",
+    ),
+    ("training", "entraînement"),
+    ("New window", "Nouvelle fenêtre"),
+    ("No file found at {}", "Aucun fichier trouvé à {}"),
+    (
+        "Failed to open a new window: {}",
+        "Échec de l'ouverture d'une nouvelle fenêtre : {}",
+    ),
+    ("Failed to open {}: {}", "Échec de l'ouverture de {} : {}"),
+    (
+        "Failed to locate the default datadir",
+        "Impossible de trouver le répertoire de données par défaut",
+    ),
+    ("Failed to parse {}: {}", "Échec de la lecture du contenu de {} : {}"),
+    ("Failed to read {}: {}", "Échec de la lecture de {} : {}"),
+    (
+        "Failed to serialize the paths: {}",
+        "Échec de la sérialisation des chemins : {}",
+    ),
+    ("Failed to create {}: {}", "Échec de la création de {} : {}"),
+    ("Failed to write {}: {}", "Échec de l'écriture de {} : {}"),
+    // installer
+    ("Install", "Installer"),
+    ("Import existing configuration", "Importer une configuration existante"),
+    (
+        "Path to the revaultd configuration file:",
+        "Chemin du fichier de configuration de revaultd :",
+    ),
+    (
+        "Please enter the path of a valid revaultd configuration file",
+        "Veuillez saisir le chemin d'un fichier de configuration de revaultd valide",
+    ),
+    (
+        "Every next step is filled with the values of the configuration, check and edit them \
+         before the installation.",
+        "Chaque étape suivante est remplie avec les valeurs de la configuration, vérifiez-les et \
+         modifiez-les avant l'installation.",
+    ),
+    ("Import an existing configuration", "Importer une configuration existante"),
+    ("Stakeholder", "Partie prenante"),
+    ("Stakeholder & Manager", "Partie prenante et gestionnaire"),
+    ("Manager", "Gestionnaire"),
+    (
+        "Expert mode: paste the descriptors generated with external tooling",
+        "Mode expert : collez les descripteurs générés par des outils externes",
+    ),
+    ("Xpub", "Xpub"),
+    ("Please enter a valid xpub", "Veuillez saisir une xpub valide"),
+    ("Key", "Clé"),
+    ("Please enter a valid key", "Veuillez saisir une clé valide"),
+    (
+        "The noise key authenticates your daemon to the coordinator and to the other servers of \
+         the deployment. It is written to the noise_secret file of the datadir, only readable by \
+         your user.",
+        "La clé noise authentifie votre démon auprès du coordinateur et des autres serveurs du \
+         déploiement. Elle est écrite dans le fichier noise_secret du répertoire de données, \
+         lisible uniquement par votre utilisateur.",
+    ),
+    ("Generate a new key", "Générer une nouvelle clé"),
+    ("Or import an existing noise_secret file:", "Ou importez un fichier noise_secret existant :"),
+    (
+        "Please enter the path of a 32 bytes noise_secret file",
+        "Veuillez saisir le chemin d'un fichier noise_secret de 32 octets",
+    ),
+    (
+        "Share it with the coordinator and the servers operators.",
+        "Partagez-la avec les opérateurs du coordinateur et des serveurs.",
+    ),
+    ("Set your noise key", "Définissez votre clé noise"),
+    ("Use this key", "Utiliser cette clé"),
+    (
+        "This key is less secure than a key kept by a signing device",
+        "Cette clé est moins sûre qu'une clé conservée par un appareil de signature",
+    ),
+    (
+        "The mnemonic below is the only backup of your key: write it down on paper and keep it \
+         offline. The GUI does not store it, anyone reading it can sign for you.",
+        "La phrase mnémonique ci-dessous est la seule sauvegarde de votre clé : notez-la sur \
+         papier et conservez-la hors ligne. L'interface ne la stocke pas, quiconque la lit peut \
+         signer à votre place.",
+    ),
+    ("Network of the key:", "Réseau de la clé :"),
+    ("Account {} xpub: {}", "Xpub du compte {} : {}"),
+    ("I wrote down the mnemonic", "J'ai noté la phrase mnémonique"),
+    ("Discard", "Abandonner"),
+    ("No signing device? Generate a key", "Pas d'appareil de signature ? Générez une clé"),
+    ("Stakeholders information", "Informations des parties prenantes"),
+    ("Your stakeholder xpub:", "Votre xpub de partie prenante :"),
+    ("Your stakeholder xpub", "Votre xpub de partie prenante"),
+    ("Other stakeholders xpubs:", "Xpubs des autres parties prenantes :"),
+    ("Add stakeholder", "Ajouter une partie prenante"),
+    ("Stakeholders xpubs:", "Xpubs des parties prenantes :"),
+    ("Managers threshold:", "Seuil des gestionnaires :"),
+    ("Impossible threshold", "Seuil impossible"),
+    ("Spending delay:", "Délai de dépense :"),
+    ("Blocks", "Blocs"),
+    ("blocks", "blocs"),
+    ("Hours", "Heures"),
+    ("hours", "heures"),
+    (
+        "Spending delay cannot exceed {} blocks ({})",
+        "Le délai de dépense ne peut pas dépasser {} blocs ({})",
+    ),
+    ("Spending delay cannot be equal to zero", "Le délai de dépense ne peut pas être nul"),
+    (
+        "A spending delay under {} blocks ({}) leaves the watchtowers little time to react to an \
+         unvault",
+        "Un délai de dépense inférieur à {} blocs ({}) laisse peu de temps aux watchtowers pour \
+         réagir à un unvault",
+    ),
+    ("Your manager xpub:", "Votre xpub de gestionnaire :"),
+    ("Your manager xpub", "Votre xpub de gestionnaire"),
+    ("Define managers", "Définir les gestionnaires"),
+    ("Other Managers xpubs:", "Xpubs des autres gestionnaires :"),
+    ("Add manager", "Ajouter un gestionnaire"),
+    ("Cosigning servers keys:", "Clés des serveurs de cosignature :"),
+    ("Fund management configuration", "Configuration de la gestion des fonds"),
+    ("Managers xpubs:", "Xpubs des gestionnaires :"),
+    ("Managers CPFP xpubs:", "Xpubs CPFP des gestionnaires :"),
+    ("Fill in the managers CPFP xpubs", "Renseignez les xpubs CPFP des gestionnaires"),
+    ("Descriptor", "Descripteur"),
+    (
+        "Please enter a descriptor with a valid checksum",
+        "Veuillez saisir un descripteur avec une somme de contrôle valide",
+    ),
+    (
+        "Please enter an xpub of the deposit descriptor",
+        "Veuillez saisir une xpub du descripteur de dépôt",
+    ),
+    ("Please enter an xpub of the CPFP descriptor", "Veuillez saisir une xpub du descripteur CPFP"),
+    ("Paste the descriptors", "Collez les descripteurs"),
+    (
+        "The descriptors are checked against each other, the checksum is optional",
+        "Les descripteurs sont vérifiés les uns par rapport aux autres, la somme de contrôle est \
+         facultative",
+    ),
+    ("Noise key:", "Clé noise :"),
+    ("Fingerprint: {}", "Empreinte : {}"),
+    (
+        "Compare the fingerprint with the other participants before saving the configuration",
+        "Comparez l'empreinte avec les autres participants avant d'enregistrer la configuration",
+    ),
+    ("Fill in coordinator information", "Renseignez les informations du coordinateur"),
+    ("The host is an ip address with a port", "L'hôte est une adresse ip avec un port"),
+    ("Host:", "Hôte :"),
+    ("Host", "Hôte"),
+    ("Incorrect format for a socket address", "Format incorrect pour une adresse de socket"),
+    (
+        "Set up a proxy for the health checks",
+        "Configurer un proxy pour les vérifications de connexion",
+    ),
+    (
+        "The GUI checks that the coordinator, the cosigners and the watchtowers are reachable.\n\
+         These health checks can be routed through a SOCKS5 proxy, like the one of a Tor daemon.\n\
+         The proxy only covers them: revaultd connects to the servers directly.",
+        "L'interface vérifie que le coordinateur, les cosignataires et les watchtowers sont \
+         joignables.\nCes vérifications peuvent passer par un proxy SOCKS5, comme celui d'un \
+         démon Tor.\nLe proxy ne couvre qu'elles : revaultd se connecte directement aux serveurs.",
+    ),
+    (
+        "Run the health checks through a SOCKS5 proxy",
+        "Faire passer les vérifications par un proxy SOCKS5",
+    ),
+    ("Check the proxy", "Vérifier le proxy"),
+    ("Proxy address:", "Adresse du proxy :"),
+    ("The proxy accepts SOCKS5 connections", "Le proxy accepte les connexions SOCKS5"),
+    ("Address:", "Adresse :"),
+    ("Please enter a valid address", "Veuillez saisir une adresse valide"),
+    ("Fill in emergency information", "Renseignez les informations d'urgence"),
+    ("Please enter a valid host:port address", "Veuillez saisir une adresse hôte:port valide"),
+    (
+        "Please enter a valid noise key of 64 hex characters",
+        "Veuillez saisir une clé noise valide de 64 caractères hexadécimaux",
+    ),
+    ("Fill in watchtowers information", "Renseignez les informations des watchtowers"),
+    ("Your watchtowers:", "Vos watchtowers :"),
+    ("Add a watchtower", "Ajouter une watchtower"),
+    ("Please enter a valid host", "Veuillez saisir un hôte valide"),
+    (
+        "key must be hex encoded and 32 bytes long",
+        "la clé doit être encodée en hexadécimal et faire 32 octets",
+    ),
+    (
+        "Fill in cosigning servers information",
+        "Renseignez les informations des serveurs de cosignature",
+    ),
+    ("The cosigning servers:", "Les serveurs de cosignature :"),
+    ("Test connection", "Tester la connexion"),
+    ("Please enter correct address", "Veuillez saisir une adresse correcte"),
+    ("Cookie path:", "Chemin du cookie :"),
+    ("Cookie path", "Chemin du cookie"),
+    ("No cookie file found at this path", "Aucun fichier cookie trouvé à ce chemin"),
+    (
+        "revaultd and the GUI authenticate to bitcoind with its cookie file.",
+        "revaultd et l'interface s'authentifient auprès de bitcoind avec son fichier cookie.",
+    ),
+    (
+        "Set up connection to the Bitcoin full node",
+        "Configurez la connexion au nœud Bitcoin complet",
+    ),
+    (
+        "Test the connection to go to the next step",
+        "Testez la connexion pour passer à l'étape suivante",
+    ),
+    ("First deposit addresses on {}", "Premières adresses de dépôt sur {}"),
+    ("Review the configuration", "Vérifiez la configuration"),
+    (
+        "Before writing the configuration, check over another channel that every participant got \
+         the same descriptor checksums and the same deposit addresses.",
+        "Avant d'écrire la configuration, vérifiez par un autre canal que chaque participant a \
+         obtenu les mêmes sommes de contrôle de descripteurs et les mêmes adresses de dépôt.",
+    ),
+    ("Participants", "Participants"),
+    (
+        "Each participant has the color of its key fingerprint on every screen and every computer",
+        "Chaque participant a la couleur de l'empreinte de sa clé sur chaque écran et chaque \
+         ordinateur",
+    ),
+    ("They are the same", "Elles sont identiques"),
+    ("Installing ...", "Installation ..."),
+    ("Installed !", "Installé !"),
+    ("Start", "Démarrer"),
+    ("Finalize installation", "Finaliser l'installation"),
+    ("Descriptors", "Descripteurs"),
+    (
+        "Check with the other participants that the descriptor checksums are the same",
+        "Vérifiez avec les autres participants que les sommes de contrôle des descripteurs sont \
+         identiques",
+    ),
+    ("< Previous", "< Précédent"),
+    (
+        "The proxy requires an authentication, which is not supported",
+        "Le proxy exige une authentification, qui n'est pas prise en charge",
+    ),
+    ("The proxy is not a SOCKS5 proxy", "Le proxy n'est pas un proxy SOCKS5"),
+    ("Generate or import a noise key", "Générez ou importez une clé noise"),
+    ("Deposit descriptor:", "Descripteur de dépôt :"),
+    ("Unvault descriptor:", "Descripteur d'unvault :"),
+    ("CPFP descriptor:", "Descripteur CPFP :"),
+    // readiness
+    ("stakeholder", "partie prenante"),
+    ("manager", "gestionnaire"),
+    ("cosigning server", "serveur de cosignature"),
+    ("{} {} key invalid", "{} clé de {} invalide"),
+    ("{} {} keys invalid", "{} clés de {} invalides"),
+    ("{} {} key entered, need at least {}", "{} clé de {} saisie, au moins {} nécessaires"),
+    ("{} {} keys entered, need at least {}", "{} clés de {} saisies, au moins {} nécessaires"),
+    ("{} of {} {} keys entered", "{} sur {} clés de {} saisies"),
+    ("{} {} key entered", "{} clé de {} saisie"),
+    ("{} {} keys entered", "{} clés de {} saisies"),
+    (
+        "Threshold currently invalid: {} of {} manager",
+        "Seuil actuellement invalide : {} sur {} gestionnaire",
+    ),
+    (
+        "Threshold currently invalid: {} of {} managers",
+        "Seuil actuellement invalide : {} sur {} gestionnaires",
+    ),
+    ("Threshold of {} of {} manager", "Seuil de {} sur {} gestionnaire"),
+    ("Threshold of {} of {} managers", "Seuil de {} sur {} gestionnaires"),
+    ("Spending delay must be at least 1 block", "Le délai de dépense doit être d'au moins 1 bloc"),
+    (
+        "Spending delay must be at most {} blocks",
+        "Le délai de dépense doit être d'au plus {} blocs",
+    ),
+    ("Spending delay of {} block", "Délai de dépense de {} bloc"),
+    ("Spending delay of {} blocks", "Délai de dépense de {} blocs"),
+    ("1 week", "1 semaine"),
+];
@@ -0,0 +1,164 @@
+//! Translations of the interface. The texts of the views are written in
+//! English and looked up in the table of the language picked by the user,
+//! a text missing from the table is displayed in English.
+//! The language is held by the `Converter` of the app context, which also
+//! formats the numbers and the dates with it.
+//!
+//! The installer holds its own language, picked on its welcome screen and
+//! written to the GUI configuration. Some texts stay in English: the errors
+//! returned by revaultd and bitcoind, the errors of the installer quoting a
+//! key or a descriptor, and the options of the pick lists.
+
+mod french;
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    English,
+    French,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::French];
+
+    /// Table of the translations of the language, indexed by the English text.
+    fn translations(&self) -> Option<&'static HashMap<&'static str, &'static str>> {
+        static FRENCH: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+        match self {
+            Self::English => None,
+            Self::French => Some(FRENCH.get_or_init(|| french::FRENCH.iter().copied().collect())),
+        }
+    }
+
+    /// Separators of the decimals and of the thousands.
+    fn separators(&self) -> (char, char) {
+        match self {
+            Self::English => ('.', ','),
+            // narrow no-break space
+            Self::French => (',', '\u{202f}'),
+        }
+    }
+
+    fn date_format(&self) -> &'static str {
+        match self {
+            Self::English => "%Y-%m-%d %H:%M",
+            Self::French => "%d/%m/%Y %H:%M",
+        }
+    }
+
+    /// Returns the text in the language.
+    pub fn tr<'a>(&self, text: &'a str) -> &'a str {
+        self.translations()
+            .and_then(|translations| translations.get(text).copied())
+            .unwrap_or(text)
+    }
+
+    /// Returns the text in the language, each `{}` of the text is replaced
+    /// by the next argument.
+    pub fn trf(&self, text: &str, args: &[&dyn std::fmt::Display]) -> String {
+        let mut parts = self.tr(text).split("{}");
+        let mut res = parts.next().unwrap_or_default().to_string();
+        let mut args = args.iter();
+        for part in parts {
+            if let Some(arg) = args.next() {
+                res.push_str(&arg.to_string());
+            }
+            res.push_str(part);
+        }
+        res
+    }
+
+    /// Formats the number with the given number of decimals and the
+    /// separators of the language, e.g. "1,234.56" or "1 234,56".
+    pub fn format_number(&self, value: f64, decimals: usize) -> String {
+        self.localize_number(&format!("{:.*}", decimals, value))
+    }
+
+    /// Replaces the separators of a number formatted by the program,
+    /// e.g. "1234.5", with the separators of the language.
+    pub fn localize_number(&self, number: &str) -> String {
+        let (decimal, thousands) = self.separators();
+        let (sign, number) = match number.strip_prefix('-') {
+            Some(number) => ("-", number),
+            None => ("", number),
+        };
+        let (integer, fraction) = match number.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (number, None),
+        };
+        let mut res = sign.to_string();
+        for (i, c) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                res.push(thousands);
+            }
+            res.push(c);
+        }
+        if let Some(fraction) = fraction {
+            res.push(decimal);
+            res.push_str(fraction);
+        }
+        res
+    }
+
+    /// Formats the timestamp in seconds as a date in the format of the language.
+    pub fn format_date(&self, timestamp: i64) -> String {
+        NaiveDateTime::from_timestamp(timestamp, 0)
+            .format(self.date_format())
+            .to_string()
+    }
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::English => write!(f, "English"),
+            Self::French => write!(f, "Français"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trf_replaces_the_placeholders_in_order() {
+        assert_eq!(
+            Language::English.trf("Block {} of {}, {} blocks left", &[&1, &10, &9]),
+            "Block 1 of 10, 9 blocks left"
+        );
+        assert_eq!(
+            Language::French.trf("Block {} of {}, {} blocks left", &[&1, &10, &9]),
+            "Bloc 1 sur 10, 9 blocs restants"
+        );
+        // A text missing from the table is displayed in English.
+        assert_eq!(Language::French.trf("{} and {}", &[&"a", &"b"]), "a and b");
+        // The extra placeholders are removed.
+        assert_eq!(Language::English.trf("{} and {}", &[&"a"]), "a and ");
+    }
+
+    #[test]
+    fn french_translations_keep_the_placeholders() {
+        for (english, french) in french::FRENCH {
+            assert_eq!(
+                english.matches("{}").count(),
+                french.matches("{}").count(),
+                "{}",
+                english
+            );
+        }
+    }
+
+    #[test]
+    fn french_translations_are_unique() {
+        let translations = Language::French.translations().unwrap();
+        assert_eq!(translations.len(), french::FRENCH.len());
+    }
+}
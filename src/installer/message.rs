@@ -1,7 +1,11 @@
 use std::path::PathBuf;
 
 use super::Error;
-use crate::revault::Role;
+use crate::{
+    bitcoind::{BitcoindError, NodeReport},
+    i18n::Language,
+    revault::Role,
+};
 
 #[derive(Debug, Clone)]
 pub enum Message {
@@ -11,15 +15,41 @@ pub enum Message {
     Install,
     Installed(Result<PathBuf, Error>),
     Role(&'static [Role]),
-    PrivateNoiseKey(String),
+    /// The user stopped typing, see debounce::Debouncer.
+    Debounced(u64),
+    /// The user pastes the descriptors instead of entering the xpubs.
+    ExpertMode(bool),
+    /// Language picked on the welcome screen.
+    Language(Language),
+    DefineDescriptors(DefineDescriptors),
+    DefineNoiseKey(DefineNoiseKey),
     DefineStakeholderXpubs(DefineStakeholderXpubs),
     DefineManagerXpubs(DefineManagerXpubs),
     DefineCpfpDescriptor(DefineCpfpDescriptor),
+    DefineCheckProxy(DefineCheckProxy),
     DefineCoordinator(DefineCoordinator),
     DefineEmergencyAddress(String),
     DefineWatchtowers(DefineWatchtowers),
     DefineCosigners(usize, DefineCosigner),
     DefineBitcoind(DefineBitcoind),
+    ImportConfig(ImportConfig),
+}
+
+#[derive(Debug, Clone)]
+pub enum DefineNoiseKey {
+    Generate,
+    PathEdited(String),
+    /// Import the key of an existing noise_secret file.
+    Import,
+}
+
+#[derive(Debug, Clone)]
+pub enum ImportConfig {
+    /// Go to the import step from the welcome screen.
+    Start,
+    PathEdited(String),
+    Import(PathBuf),
+    Failed(String),
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +57,10 @@ pub enum DefineBitcoind {
     NetworkEdited(bitcoin::Network),
     CookiePathEdited(String),
     AddressEdited(String),
+    /// Test the connection to bitcoind and diagnose the node.
+    CheckNode,
+    /// Result of the connection test with the given id.
+    NodeChecked(usize, Result<NodeReport, BitcoindError>),
 }
 
 #[derive(Debug, Clone)]
@@ -48,12 +82,32 @@ pub enum DefineWatchtower {
     Delete,
 }
 
+#[derive(Debug, Clone)]
+pub enum DefineCheckProxy {
+    Enabled(bool),
+    AddressEdited(String),
+    /// Connect to the proxy and check that it accepts SOCKS5 connections.
+    CheckProxy,
+    /// Result of the check of the proxy with the given id.
+    ProxyChecked(usize, Result<(), String>),
+}
+
 #[derive(Debug, Clone)]
 pub enum DefineCoordinator {
     HostEdited(String),
     NoiseKeyEdited(String),
 }
 
+/// Edits of the pasted descriptors and of the xpubs of the user.
+#[derive(Debug, Clone)]
+pub enum DefineDescriptors {
+    Deposit(String),
+    Unvault(String),
+    Cpfp(String),
+    StakeholderXpub(String),
+    ManagerXpub(String),
+}
+
 #[derive(Debug, Clone)]
 pub enum DefineCpfpDescriptor {
     ManagerXpub(usize, String),
@@ -64,12 +118,22 @@ pub enum DefineStakeholderXpubs {
     OurXpubEdited(String),
     StakeholderXpub(usize, ParticipantXpub),
     AddXpub,
+    GenerateKey,
+    KeyNetworkEdited(bitcoin::Network),
+    KeyBackedUp(bool),
+    UseGeneratedKey,
+    DiscardGeneratedKey,
 }
 
 #[derive(Debug, Clone)]
 pub enum DefineManagerXpubs {
     ManagersThreshold(Action),
-    SpendingDelay(Action),
+    /// Spending delay typed in blocks.
+    SpendingDelayEdited(String),
+    /// Spending delay typed in hours, converted to blocks.
+    SpendingDelayHoursEdited(String),
+    /// Sets the spending delay to the number of blocks of a preset.
+    SpendingDelayPreset(u32),
     OurXpubEdited(String),
     ManagerXpub(usize, ParticipantXpub),
     CosignerKey(usize, String),
@@ -7,12 +7,18 @@ use iced::{Clipboard, Command, Element, Subscription};
 use std::io::Write;
 use std::path::PathBuf;
 
-use crate::{app::config as gui_config, revault::Role, revaultd::config as revaultd_config};
+use crate::{
+    app::config as gui_config, debounce::Debouncer, disk, i18n::Language, noise, revault::Role,
+    revaultd::config as revaultd_config,
+};
 
 pub use message::Message;
+#[cfg(feature = "descriptor-vectors")]
+pub use step::vectors;
 use step::{
-    manager, stakeholder, Context, DefineBitcoind, DefineCoordinator, DefineCpfpDescriptor,
-    DefinePrivateNoiseKey, DefineRole, Final, Step, Welcome,
+    manager, stakeholder, Context, DefineBitcoind, DefineCheckProxy, DefineCoordinator,
+    DefineCpfpDescriptor, DefineDescriptors, DefinePrivateNoiseKey, DefineRole, Final,
+    ImportConfig, ImportedConfig, Review, Step, Welcome,
 };
 
 pub struct Installer {
@@ -22,6 +28,12 @@ pub struct Installer {
     /// Context is data passed through each step.
     context: Context,
     config: revaultd_config::Config,
+    /// true if the descriptors are pasted instead of built from the xpubs.
+    expert: bool,
+    /// the fields of the current step are checked once the user stopped typing.
+    debounce: Debouncer,
+    /// Language of the installer, written to the GUI configuration.
+    language: Language,
 }
 
 impl Installer {
@@ -35,9 +47,29 @@ impl Installer {
         if self.current > 0 {
             self.current -= 1;
         }
+        // Back to the welcome screen, the user may choose between
+        // a new installation or an import.
+        if self.current == 0 {
+            self.steps = vec![Welcome::new().into(), DefineRole::new().into()];
+        }
+    }
+
+    /// Fills the steps with the imported configuration
+    /// and goes to the first step after the role definition.
+    fn import(&mut self, imported: ImportedConfig) {
+        let mut config = imported.config.clone();
+        config.data_dir = self.config.data_dir.clone();
+        config.daemon = Some(true);
+        self.config = config;
+
+        self.update_steps(imported.roles());
+        for step in &mut self.steps {
+            step.load_config(&imported);
+        }
+        self.current = 2;
     }
 
-    fn update_steps(&mut self, role: &[Role]) {
+    fn update_steps(&mut self, role: &'static [Role]) {
         if role == Role::MANAGER_ONLY {
             self.steps = vec![
                 Welcome::new().into(),
@@ -46,9 +78,11 @@ impl Installer {
                 manager::DefineStakeholderXpubs::new().into(),
                 manager::DefineManagerXpubs::new().into(),
                 DefineCpfpDescriptor::new().into(),
+                DefineCheckProxy::new().into(),
                 DefineCoordinator::new().into(),
                 manager::DefineCosigners::new().into(),
                 DefineBitcoind::new().into(),
+                Review::new().into(),
                 Final::new().into(),
             ];
         } else if role == Role::STAKEHOLDER_ONLY {
@@ -59,9 +93,12 @@ impl Installer {
                 stakeholder::DefineStakeholderXpubs::new().into(),
                 stakeholder::DefineManagerXpubs::new().into(),
                 DefineCpfpDescriptor::new().into(),
+                DefineCheckProxy::new().into(),
                 DefineCoordinator::new().into(),
+                stakeholder::DefineWatchtowers::new().into(),
                 DefineBitcoind::new().into(),
                 stakeholder::DefineEmergencyAddress::new().into(),
+                Review::new().into(),
                 Final::new().into(),
             ];
         } else {
@@ -72,14 +109,21 @@ impl Installer {
                 stakeholder::DefineStakeholderXpubs::new().into(),
                 manager::DefineManagerXpubs::new().into(),
                 DefineCpfpDescriptor::new().into(),
+                DefineCheckProxy::new().into(),
                 DefineCoordinator::new().into(),
                 stakeholder::DefineWatchtowers::new().into(),
                 manager::DefineCosigners::new().into(),
                 DefineBitcoind::new().into(),
                 stakeholder::DefineEmergencyAddress::new().into(),
+                Review::new().into(),
                 Final::new().into(),
             ];
         }
+        // The xpubs steps and the CPFP descriptor step follow the noise key step.
+        if self.expert {
+            self.steps
+                .splice(3..6, std::iter::once(DefineDescriptors::new(role).into()));
+        }
     }
 
     fn current_step(&mut self) -> &mut Box<dyn Step> {
@@ -98,6 +142,9 @@ impl Installer {
                 current: 0,
                 steps: vec![Welcome::new().into(), DefineRole::new().into()],
                 context: Context::new(),
+                expert: false,
+                debounce: Debouncer::default(),
+                language: Language::default(),
             },
             Command::none(),
         )
@@ -115,6 +162,8 @@ impl Installer {
                     .get_mut(self.current)
                     .expect("There is always a step");
                 if current_step.apply(&mut self.context, &mut self.config) {
+                    self.context.scripts_config = self.config.scripts_config.clone();
+                    self.context.network = self.config.bitcoind_config.network;
                     self.next();
                     // calculate new current_step.
                     let current_step = self
@@ -127,6 +176,10 @@ impl Installer {
             Message::Previous => {
                 self.previous();
             }
+            Message::Language(language) => {
+                self.language = language;
+                self.context.language = language;
+            }
             Message::Role(role) => {
                 // reset config
                 let mut config = revaultd_config::Config::new();
@@ -137,6 +190,24 @@ impl Installer {
                 self.update_steps(role);
                 self.next();
             }
+            Message::ExpertMode(expert) => {
+                self.expert = expert;
+                return self.current_step().update(message);
+            }
+            Message::ImportConfig(message::ImportConfig::Start) => {
+                self.steps = vec![Welcome::new().into(), ImportConfig::new().into()];
+                self.next();
+            }
+            Message::ImportConfig(message::ImportConfig::Import(path)) => {
+                match ImportedConfig::from_file(&path) {
+                    Ok(imported) => self.import(imported),
+                    Err(e) => {
+                        return self
+                            .current_step()
+                            .update(Message::ImportConfig(message::ImportConfig::Failed(e)));
+                    }
+                }
+            }
             Message::Install => {
                 self.current_step().update(message);
                 return Command::perform(
@@ -144,19 +215,28 @@ impl Installer {
                     Message::Installed,
                 );
             }
+            Message::Debounced(id) => {
+                if self.debounce.is_due(id) {
+                    self.current_step().check();
+                }
+            }
             _ => {
-                self.current_step().update(message);
+                let cmd = self.current_step().update(message);
+                return Command::batch(vec![cmd, self.debounce.trigger(Message::Debounced)]);
             }
         };
         Command::none()
     }
 
     pub fn view(&mut self) -> Element<Message> {
-        self.current_step().view()
+        let language = self.language;
+        self.current_step().view(language)
     }
 }
 
-fn append_network_suffix(name: &str, network: &bitcoin::Network) -> String {
+/// Returns the name of the configuration file of the network, the mainnet one
+/// has no suffix.
+pub fn append_network_suffix(name: &str, network: &bitcoin::Network) -> String {
     if *network == bitcoin::Network::Bitcoin {
         name.to_string()
     } else {
@@ -166,6 +246,7 @@ fn append_network_suffix(name: &str, network: &bitcoin::Network) -> String {
 
 pub async fn install(ctx: Context, cfg: revaultd_config::Config) -> Result<PathBuf, Error> {
     let datadir_path = cfg.data_dir.clone().unwrap();
+    disk::check_dir(&datadir_path, disk::MIN_DATADIR_SPACE).map_err(Error::DiskError)?;
     std::fs::create_dir_all(&datadir_path)
         .map_err(|e| Error::CannotCreateDatadir(e.to_string()))?;
 
@@ -192,15 +273,11 @@ pub async fn install(ctx: Context, cfg: revaultd_config::Config) -> Result<PathB
     std::fs::create_dir_all(&network_datadir)
         .map_err(|e| Error::CannotCreateDatadir(e.to_string()))?;
 
-    // create noise_secret file
-    let mut noise_secret_path = network_datadir;
-    noise_secret_path.push("noise_secret");
-    let mut noise_secret_file = std::fs::File::create(&noise_secret_path)
-        .map_err(|e| Error::CannotCreateFile(e.to_string()))?;
-
-    noise_secret_file
-        .write_all(ctx.private_noise_key.as_bytes())
-        .map_err(|e| Error::CannotWriteToFile(e.to_string()))?;
+    // create noise_secret file, only readable by the user.
+    if let Some(key) = &ctx.private_noise_key {
+        noise::write(&network_datadir.join(noise::KEY_FILE_NAME), key)
+            .map_err(|e| Error::CannotWriteToFile(e.to_string()))?;
+    }
 
     // create revault GUI configuration file
     let mut gui_config_path = datadir_path;
@@ -209,15 +286,26 @@ pub async fn install(ctx: Context, cfg: revaultd_config::Config) -> Result<PathB
         &cfg.bitcoind_config.network,
     ));
 
-    let mut gui_config_file = std::fs::File::create(&gui_config_path)
+    // The file may hold the rpcpassword of bitcoind, it is only readable
+    // by its owner.
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut gui_config_file = options
+        .open(&gui_config_path)
+        .map_err(|e| Error::CannotCreateFile(e.to_string()))?;
+    noise::restrict_permissions(&gui_config_path)
         .map_err(|e| Error::CannotCreateFile(e.to_string()))?;
 
+    let mut gui_config = gui_config::Config::new(revaultd_config_path);
+    gui_config.health_check_proxy = ctx.health_check_proxy;
+    gui_config.language = Some(ctx.language);
     gui_config_file
-        .write_all(
-            toml::to_string(&gui_config::Config::new(revaultd_config_path))
-                .unwrap()
-                .as_bytes(),
-        )
+        .write_all(toml::to_string(&gui_config).unwrap().as_bytes())
         .map_err(|e| Error::CannotWriteToFile(e.to_string()))?;
 
     Ok(gui_config_path)
@@ -225,6 +313,7 @@ pub async fn install(ctx: Context, cfg: revaultd_config::Config) -> Result<PathB
 
 #[derive(Debug, Clone)]
 pub enum Error {
+    DiskError(disk::DiskError),
     CannotCreateDatadir(String),
     CannotCreateFile(String),
     CannotWriteToFile(String),
@@ -233,6 +322,7 @@ pub enum Error {
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
+            Self::DiskError(e) => write!(f, "Cannot install in the datadir: {}", e),
             Self::CannotCreateDatadir(e) => write!(f, "Failed to create datadir: {}", e),
             Self::CannotWriteToFile(e) => write!(f, "Failed to write to file: {}", e),
             Self::CannotCreateFile(e) => write!(f, "Failed to create file: {}", e),
@@ -1,10 +1,42 @@
 use crate::{
+    conversion,
+    i18n::Language,
     installer::{message, view},
     ui::component::form,
 };
 
+use std::str::FromStr;
+
 use iced::{button::State as Button, text_input, Element};
 
+/// Checks the value typed by the user, an empty field
+/// is only an error once the step is applied.
+pub fn check<T: FromStr>(value: &mut form::Value<String>) {
+    value.valid = value.value.is_empty() || T::from_str(&value.value).is_ok();
+}
+
+/// Sets the spending delay typed in units of the given number of blocks,
+/// an empty field is a zero delay and a text which is not a number is
+/// ignored.
+pub fn edit_spending_delay(delay: &mut form::Value<u32>, typed: &str, blocks_per_unit: u32) {
+    let units = if typed.is_empty() {
+        0
+    } else {
+        match conversion::parse_integer::<u32>(typed) {
+            Some(units) => units,
+            None => return,
+        }
+    };
+    delay.value = units.saturating_mul(blocks_per_unit);
+    check_spending_delay(delay);
+}
+
+/// The spending delay is the relative timelock of the unvault descriptor,
+/// it cannot exceed the blocks encodable in a CSV.
+pub fn check_spending_delay(delay: &mut form::Value<u32>) {
+    delay.valid = delay.value <= view::MAX_SPENDING_DELAY;
+}
+
 #[derive(Clone)]
 pub struct ParticipantXpub {
     pub xpub: form::Value<String>,
@@ -22,6 +54,12 @@ impl ParticipantXpub {
         }
     }
 
+    pub fn with_xpub(xpub: String) -> Self {
+        let mut participant = Self::new();
+        participant.xpub.value = xpub;
+        participant
+    }
+
     pub fn update(&mut self, msg: message::ParticipantXpub) {
         if let message::ParticipantXpub::XpubEdited(xpub) = msg {
             self.xpub.value = xpub;
@@ -29,8 +67,13 @@ impl ParticipantXpub {
         }
     }
 
-    pub fn view(&mut self) -> Element<message::ParticipantXpub> {
-        view::participant_xpub(&self.xpub, &mut self.xpub_input, &mut self.delete_button)
+    pub fn view(&mut self, language: Language) -> Element<message::ParticipantXpub> {
+        view::participant_xpub(
+            language,
+            &self.xpub,
+            &mut self.xpub_input,
+            &mut self.delete_button,
+        )
     }
 }
 
@@ -49,13 +92,19 @@ impl RequiredXpub {
         }
     }
 
+    pub fn with_xpub(xpub: String) -> Self {
+        let mut required = Self::new();
+        required.xpub.value = xpub;
+        required
+    }
+
     pub fn update(&mut self, msg: String) {
         self.xpub.value = msg;
         self.xpub.valid = true;
     }
 
-    pub fn view(&mut self) -> Element<String> {
-        view::required_xpub(&self.xpub, &mut self.xpub_input)
+    pub fn view(&mut self, language: Language) -> Element<String> {
+        view::required_xpub(language, &self.xpub, &mut self.xpub_input)
     }
 }
 
@@ -73,12 +122,18 @@ impl CosignerKey {
         }
     }
 
+    pub fn with_key(key: String) -> Self {
+        let mut cosigner = Self::new();
+        cosigner.key.value = key;
+        cosigner
+    }
+
     pub fn update(&mut self, key: String) {
         self.key.value = key;
         self.key.valid = true;
     }
 
-    pub fn view(&mut self) -> Element<String> {
-        view::cosigner_key(&self.key, &mut self.key_input)
+    pub fn view(&mut self, language: Language) -> Element<String> {
+        view::cosigner_key(language, &self.key, &mut self.key_input)
     }
 }
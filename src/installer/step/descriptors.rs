@@ -0,0 +1,414 @@
+use std::str::FromStr;
+
+use bitcoin::util::bip32::ExtendedPubKey;
+use iced::{Command, Element};
+use miniscript::DescriptorPublicKey;
+use revault_tx::scripts::{CpfpDescriptor, DepositDescriptor, UnvaultDescriptor};
+
+use crate::{
+    descriptor,
+    i18n::Language,
+    installer::{
+        message::{self, Message},
+        step::{Context, ImportedConfig, Step},
+        view,
+    },
+    revault::Role,
+    revaultd::config,
+    ui::component::form,
+};
+
+/// Descriptors are the checked descriptors pasted by the user, with the
+/// participants read from their keys.
+struct Descriptors {
+    deposit: DepositDescriptor,
+    unvault: UnvaultDescriptor,
+    cpfp: CpfpDescriptor,
+    stakeholders: Vec<DescriptorPublicKey>,
+    managers: Vec<DescriptorPublicKey>,
+    cosigners: usize,
+}
+
+/// Parses the descriptor after checking its checksum, if it has one.
+fn parse<T: FromStr>(name: &str, desc: &str) -> Result<T, String>
+where
+    T::Err: std::fmt::Display,
+{
+    descriptor::verify(desc).map_err(|e| format!("{} descriptor: {}", name, e))?;
+    T::from_str(desc.trim()).map_err(|e| format!("Invalid {} descriptor: {}", name, e))
+}
+
+/// Checks that the descriptors describe the same deployment: the
+/// stakeholders of the deposit descriptor and the managers of the CPFP
+/// descriptor are the keys of the unvault descriptor, with one cosigner
+/// by stakeholder.
+fn check(deposit: &str, unvault: &str, cpfp: &str) -> Result<Descriptors, String> {
+    let deposit: DepositDescriptor = parse("Deposit", deposit)?;
+    let unvault: UnvaultDescriptor = parse("Unvault", unvault)?;
+    let cpfp: CpfpDescriptor = parse("CPFP", cpfp)?;
+
+    let stakeholders = deposit.xpubs();
+    let managers = cpfp.xpubs();
+    let keys = unvault.xpubs();
+    for key in stakeholders.iter().chain(managers.iter()) {
+        if !keys.contains(key) {
+            return Err(format!(
+                "The key {} is missing from the unvault descriptor",
+                key
+            ));
+        }
+    }
+    let mut cosigners = 0;
+    for key in &keys {
+        if stakeholders.contains(key) || managers.contains(key) {
+            continue;
+        }
+        match key {
+            DescriptorPublicKey::SinglePub(_) => cosigners += 1,
+            DescriptorPublicKey::XPub(_) => {
+                return Err(format!(
+                    "The xpub {} of the unvault descriptor is neither a stakeholder of the \
+                    deposit descriptor nor a manager of the CPFP descriptor",
+                    key
+                ))
+            }
+        }
+    }
+    if cosigners != stakeholders.len() {
+        return Err(format!(
+            "The unvault descriptor has {} cosigning servers for {} stakeholders",
+            cosigners,
+            stakeholders.len()
+        ));
+    }
+
+    Ok(Descriptors {
+        deposit,
+        unvault,
+        cpfp,
+        stakeholders,
+        managers,
+        cosigners,
+    })
+}
+
+/// Checks the descriptor typed by the user, an empty field
+/// is only an error once the step is applied.
+fn check_descriptor<T: FromStr>(name: &str, value: &mut form::Value<String>)
+where
+    T::Err: std::fmt::Display,
+{
+    value.valid = value.value.is_empty() || parse::<T>(name, &value.value).is_ok();
+}
+
+/// The installer steps expect the xpubs without the derivation wildcard.
+fn xpub(key: &DescriptorPublicKey) -> String {
+    let key = key.to_string();
+    key.strip_suffix("/*").unwrap_or(&key).to_string()
+}
+
+/// Returns the extended key of the descriptor key, without its origin and
+/// its derivation path. None for a single key. The string is compared
+/// because miniscript depends on another version of the bitcoin crate.
+fn extended_key(key: &DescriptorPublicKey) -> Option<String> {
+    match key {
+        DescriptorPublicKey::XPub(xkey) => Some(xkey.xkey.to_string()),
+        DescriptorPublicKey::SinglePub(_) => None,
+    }
+}
+
+/// Checks that our xpub is one of the given keys. The xpub may be typed
+/// with its origin and its derivation path, only the extended keys are
+/// compared.
+fn our_xpub(
+    xpub_value: &mut form::Value<String>,
+    keys: &[DescriptorPublicKey],
+) -> Option<ExtendedPubKey> {
+    let our = DescriptorPublicKey::from_str(xpub_value.value.trim())
+        .ok()
+        .as_ref()
+        .and_then(extended_key);
+    xpub_value.valid = our
+        .as_ref()
+        .map(|our| {
+            keys.iter()
+                .any(|key| extended_key(key).as_ref() == Some(our))
+        })
+        .unwrap_or(false);
+    our.filter(|_| xpub_value.valid)
+        .and_then(|our| ExtendedPubKey::from_str(&our).ok())
+}
+
+/// DefineDescriptors is the expert path replacing the xpub steps: the user
+/// pastes the descriptors generated with external tooling.
+pub struct DefineDescriptors {
+    roles: &'static [Role],
+    deposit: form::Value<String>,
+    unvault: form::Value<String>,
+    cpfp: form::Value<String>,
+    stakeholder_xpub: form::Value<String>,
+    manager_xpub: form::Value<String>,
+    warning: Option<String>,
+
+    view: view::DefineDescriptors,
+}
+
+impl DefineDescriptors {
+    pub fn new(roles: &'static [Role]) -> Self {
+        Self {
+            roles,
+            deposit: form::Value::default(),
+            unvault: form::Value::default(),
+            cpfp: form::Value::default(),
+            stakeholder_xpub: form::Value::default(),
+            manager_xpub: form::Value::default(),
+            warning: None,
+            view: view::DefineDescriptors::new(),
+        }
+    }
+}
+
+impl Step for DefineDescriptors {
+    fn load_config(&mut self, imported: &ImportedConfig) {
+        let config = &imported.config;
+        self.deposit.value = config.scripts_config.deposit_descriptor.clone();
+        self.unvault.value = config.scripts_config.unvault_descriptor.clone();
+        self.cpfp.value = config.scripts_config.cpfp_descriptor.clone();
+        if let Some(stakeholder_config) = &config.stakeholder_config {
+            self.stakeholder_xpub.value = stakeholder_config.xpub.to_string();
+        }
+        if let Some(manager_config) = &config.manager_config {
+            self.manager_xpub.value = manager_config.xpub.to_string();
+        }
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        if let Message::DefineDescriptors(msg) = message {
+            let value = match msg {
+                message::DefineDescriptors::Deposit(desc) => {
+                    self.deposit.value = desc;
+                    &mut self.deposit
+                }
+                message::DefineDescriptors::Unvault(desc) => {
+                    self.unvault.value = desc;
+                    &mut self.unvault
+                }
+                message::DefineDescriptors::Cpfp(desc) => {
+                    self.cpfp.value = desc;
+                    &mut self.cpfp
+                }
+                message::DefineDescriptors::StakeholderXpub(xpub) => {
+                    self.stakeholder_xpub.value = xpub;
+                    &mut self.stakeholder_xpub
+                }
+                message::DefineDescriptors::ManagerXpub(xpub) => {
+                    self.manager_xpub.value = xpub;
+                    &mut self.manager_xpub
+                }
+            };
+            value.valid = true;
+        }
+        Command::none()
+    }
+
+    fn check(&mut self) {
+        check_descriptor::<DepositDescriptor>("Deposit", &mut self.deposit);
+        check_descriptor::<UnvaultDescriptor>("Unvault", &mut self.unvault);
+        check_descriptor::<CpfpDescriptor>("CPFP", &mut self.cpfp);
+        self.warning = if [&self.deposit, &self.unvault, &self.cpfp]
+            .iter()
+            .any(|desc| desc.value.is_empty())
+        {
+            None
+        } else {
+            check(&self.deposit.value, &self.unvault.value, &self.cpfp.value).err()
+        };
+    }
+
+    fn apply(&mut self, ctx: &mut Context, config: &mut config::Config) -> bool {
+        self.deposit.valid = descriptor::verify(&self.deposit.value).is_ok();
+        self.unvault.valid = descriptor::verify(&self.unvault.value).is_ok();
+        self.cpfp.valid = descriptor::verify(&self.cpfp.value).is_ok();
+        let descriptors = match check(&self.deposit.value, &self.unvault.value, &self.cpfp.value) {
+            Ok(descriptors) => descriptors,
+            Err(e) => {
+                self.warning = Some(e);
+                return false;
+            }
+        };
+        self.warning = None;
+
+        let stakeholder_xpub = if self.roles.contains(&Role::Stakeholder) {
+            match our_xpub(&mut self.stakeholder_xpub, &descriptors.stakeholders) {
+                Some(xpub) => Some(xpub),
+                None => return false,
+            }
+        } else {
+            None
+        };
+        let manager_xpub = if self.roles.contains(&Role::Manager) {
+            match our_xpub(&mut self.manager_xpub, &descriptors.managers) {
+                Some(xpub) => Some(xpub),
+                None => return false,
+            }
+        } else {
+            None
+        };
+
+        config.stakeholder_config = stakeholder_xpub.map(|xpub| config::StakeholderConfig {
+            xpub,
+            watchtowers: Vec::new(),
+            emergency_address: "".to_string(),
+        });
+        config.manager_config = manager_xpub.map(|xpub| config::ManagerConfig {
+            xpub,
+            cosigners: Vec::new(),
+        });
+        config.scripts_config = config::ScriptsConfig {
+            deposit_descriptor: descriptors.deposit.to_string(),
+            unvault_descriptor: descriptors.unvault.to_string(),
+            cpfp_descriptor: descriptors.cpfp.to_string(),
+        };
+
+        ctx.stakeholders_xpubs = descriptors.stakeholders.iter().map(xpub).collect();
+        ctx.number_managers = descriptors.managers.len();
+        ctx.number_cosigners = descriptors.cosigners;
+        true
+    }
+
+    fn view(&mut self, language: Language) -> Element<Message> {
+        let stakeholder_xpub = if self.roles.contains(&Role::Stakeholder) {
+            Some(&self.stakeholder_xpub)
+        } else {
+            None
+        };
+        let manager_xpub = if self.roles.contains(&Role::Manager) {
+            Some(&self.manager_xpub)
+        } else {
+            None
+        };
+        self.view.render(
+            language,
+            (&self.deposit, &self.unvault, &self.cpfp),
+            stakeholder_xpub,
+            manager_xpub,
+            self.warning.as_ref(),
+        )
+    }
+}
+
+impl From<DefineDescriptors> for Box<dyn Step> {
+    fn from(s: DefineDescriptors) -> Box<dyn Step> {
+        Box::new(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::{
+        secp256k1::{PublicKey, Secp256k1, SecretKey},
+        util::bip32::ExtendedPrivKey,
+        Network,
+    };
+
+    fn test_xpub(seed: u8) -> ExtendedPubKey {
+        let secp = Secp256k1::new();
+        let xpriv = ExtendedPrivKey::new_master(Network::Testnet, &[seed; 32]).unwrap();
+        ExtendedPubKey::from_private(&secp, &xpriv)
+    }
+
+    fn xpub_key(seed: u8) -> DescriptorPublicKey {
+        DescriptorPublicKey::from_str(&format!("{}/*", test_xpub(seed))).unwrap()
+    }
+
+    fn single_key(seed: u8) -> DescriptorPublicKey {
+        let secp = Secp256k1::new();
+        let key = PublicKey::from_secret_key(&secp, &SecretKey::from_slice(&[seed; 32]).unwrap());
+        DescriptorPublicKey::from_str(&key.to_string()).unwrap()
+    }
+
+    /// Returns the descriptors of 2 stakeholders and 2 managers, the
+    /// deposit and the CPFP descriptors have the given keys.
+    fn descriptors(
+        stakeholders: &[u8],
+        managers: &[u8],
+    ) -> (DepositDescriptor, UnvaultDescriptor, CpfpDescriptor) {
+        let unvault = UnvaultDescriptor::new(
+            vec![xpub_key(1), xpub_key(2)],
+            vec![xpub_key(3), xpub_key(4)],
+            1,
+            vec![single_key(5), single_key(6)],
+            144,
+        )
+        .unwrap();
+        let deposit =
+            DepositDescriptor::new(stakeholders.iter().map(|s| xpub_key(*s)).collect()).unwrap();
+        let cpfp = CpfpDescriptor::new(managers.iter().map(|m| xpub_key(*m)).collect()).unwrap();
+        (deposit, unvault, cpfp)
+    }
+
+    fn check_descriptors(
+        (deposit, unvault, cpfp): (DepositDescriptor, UnvaultDescriptor, CpfpDescriptor),
+    ) -> Result<Descriptors, String> {
+        check(
+            &deposit.to_string(),
+            &unvault.to_string(),
+            &cpfp.to_string(),
+        )
+    }
+
+    #[test]
+    fn check_same_deployment() {
+        let res = check_descriptors(descriptors(&[1, 2], &[3, 4])).unwrap();
+        assert_eq!(res.stakeholders.len(), 2);
+        assert_eq!(res.managers.len(), 2);
+        assert_eq!(res.cosigners, 2);
+    }
+
+    #[test]
+    fn check_key_missing_from_unvault() {
+        let err = check_descriptors(descriptors(&[1, 7], &[3, 4]))
+            .err()
+            .unwrap();
+        assert!(
+            err.contains("missing from the unvault descriptor"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn check_unknown_xpub_of_unvault() {
+        let err = check_descriptors(descriptors(&[1, 2], &[3])).err().unwrap();
+        assert!(err.contains("neither a stakeholder"), "{}", err);
+    }
+
+    #[test]
+    fn check_invalid_descriptor() {
+        let (deposit, unvault, cpfp) = descriptors(&[1, 2], &[3, 4]);
+        assert!(check(&deposit.to_string(), "wsh(0)", &cpfp.to_string()).is_err());
+        // A wrong checksum is refused.
+        let unvault = unvault.to_string();
+        let wrong = format!("{}#aaaaaaaa", unvault.split('#').next().unwrap());
+        assert!(check(&deposit.to_string(), &wrong, &cpfp.to_string()).is_err());
+    }
+
+    #[test]
+    fn our_xpub_with_origin_and_path() {
+        let keys = vec![xpub_key(1), xpub_key(2)];
+        let mut value = form::Value {
+            value: format!("[aabbccdd/48'/1'/0'/2']{}/*", test_xpub(2)),
+            valid: false,
+        };
+        assert_eq!(our_xpub(&mut value, &keys), Some(test_xpub(2)));
+        assert!(value.valid);
+
+        value.value = test_xpub(1).to_string();
+        assert_eq!(our_xpub(&mut value, &keys), Some(test_xpub(1)));
+
+        value.value = test_xpub(3).to_string();
+        assert_eq!(our_xpub(&mut value, &keys), None);
+        assert!(!value.valid);
+    }
+}
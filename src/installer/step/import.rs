@@ -0,0 +1,199 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use iced::{button::State as Button, scrollable, text_input, Command, Element};
+use revault_tx::scripts::{CpfpDescriptor, DepositDescriptor, UnvaultDescriptor};
+
+use crate::{
+    i18n::Language,
+    installer::{
+        message::{self, Message},
+        step::{Context, Step},
+        view,
+    },
+    noise,
+    revault::Role,
+    revaultd::config,
+    ui::component::form,
+};
+
+/// ImportedConfig is an existing revaultd configuration,
+/// with the values the installer steps are filled with.
+pub struct ImportedConfig {
+    pub config: config::Config,
+    /// Key of the noise_secret file of the configuration datadir, if found.
+    pub private_noise_key: Option<[u8; 32]>,
+    pub stakeholders_xpubs: Vec<String>,
+    pub managers_xpubs: Vec<String>,
+    pub cosigners_keys: Vec<String>,
+    /// None if the threshold could not be found from the unvault descriptor.
+    pub managers_threshold: Option<usize>,
+    pub spending_delay: u32,
+}
+
+impl ImportedConfig {
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let config = config::Config::from_file(path).map_err(|e| e.to_string())?;
+        let scripts = &config.scripts_config;
+        let deposit = DepositDescriptor::from_str(&scripts.deposit_descriptor)
+            .map_err(|e| format!("Invalid deposit descriptor: {}", e))?;
+        let unvault = UnvaultDescriptor::from_str(&scripts.unvault_descriptor)
+            .map_err(|e| format!("Invalid unvault descriptor: {}", e))?;
+        let cpfp = CpfpDescriptor::from_str(&scripts.cpfp_descriptor)
+            .map_err(|e| format!("Invalid cpfp descriptor: {}", e))?;
+
+        let stakeholders = deposit.xpubs();
+        let managers = cpfp.xpubs();
+        // The unvault descriptor keys which are neither stakeholders
+        // nor managers keys are the cosigners keys.
+        let mut cosigners = Vec::new();
+        for key in unvault.xpubs() {
+            if !stakeholders.contains(&key) && !managers.contains(&key) && !cosigners.contains(&key)
+            {
+                cosigners.push(key);
+            }
+        }
+
+        // The threshold is not part of the descriptor API,
+        // it is the one giving back the same descriptor.
+        let managers_threshold = (1..=managers.len()).find(|threshold| {
+            UnvaultDescriptor::new(
+                stakeholders.clone(),
+                managers.clone(),
+                *threshold,
+                cosigners.clone(),
+                unvault.csv_value(),
+            )
+            .map(|desc| desc.to_string() == scripts.unvault_descriptor)
+            .unwrap_or(false)
+        });
+
+        let private_noise_key = config
+            .network_datadir()
+            .ok()
+            .and_then(|path| noise::read(&path.join(noise::KEY_FILE_NAME)).ok());
+
+        Ok(Self {
+            private_noise_key,
+            stakeholders_xpubs: stakeholders.iter().map(xpub).collect(),
+            managers_xpubs: managers.iter().map(xpub).collect(),
+            cosigners_keys: cosigners.iter().map(|key| key.to_string()).collect(),
+            managers_threshold,
+            spending_delay: unvault.csv_value(),
+            config,
+        })
+    }
+
+    pub fn roles(&self) -> &'static [Role] {
+        match (&self.config.stakeholder_config, &self.config.manager_config) {
+            (Some(_), Some(_)) => &Role::STAKEHOLDER_AND_MANAGER,
+            (Some(_), None) => &Role::STAKEHOLDER_ONLY,
+            _ => &Role::MANAGER_ONLY,
+        }
+    }
+
+    /// Xpubs of the other stakeholders.
+    pub fn other_stakeholders_xpubs(&self) -> Vec<String> {
+        let ours = self
+            .config
+            .stakeholder_config
+            .as_ref()
+            .map(|cfg| cfg.xpub.to_string());
+        others(&self.stakeholders_xpubs, ours)
+    }
+
+    /// Xpubs of the other managers.
+    pub fn other_managers_xpubs(&self) -> Vec<String> {
+        let ours = self
+            .config
+            .manager_config
+            .as_ref()
+            .map(|cfg| cfg.xpub.to_string());
+        others(&self.managers_xpubs, ours)
+    }
+}
+
+/// The installer steps expect the xpubs without the derivation wildcard.
+fn xpub(key: &miniscript::DescriptorPublicKey) -> String {
+    let key = key.to_string();
+    key.strip_suffix("/*").unwrap_or(&key).to_string()
+}
+
+fn others(xpubs: &[String], ours: Option<String>) -> Vec<String> {
+    xpubs
+        .iter()
+        .filter(|xpub| Some(*xpub) != ours.as_ref())
+        .cloned()
+        .collect()
+}
+
+pub struct ImportConfig {
+    path: form::Value<String>,
+    warning: Option<String>,
+
+    path_input: text_input::State,
+    import_button: Button,
+    previous_button: Button,
+    scroll: scrollable::State,
+}
+
+impl ImportConfig {
+    pub fn new() -> Self {
+        Self {
+            path: form::Value::default(),
+            warning: None,
+            path_input: text_input::State::new(),
+            import_button: Button::new(),
+            previous_button: Button::new(),
+            scroll: scrollable::State::new(),
+        }
+    }
+}
+
+impl Step for ImportConfig {
+    fn update(&mut self, message: Message) -> Command<Message> {
+        if let Message::ImportConfig(msg) = message {
+            match msg {
+                message::ImportConfig::PathEdited(path) => {
+                    self.path.value = path;
+                    self.path.valid = true;
+                    self.warning = None;
+                }
+                message::ImportConfig::Failed(e) => {
+                    self.path.valid = false;
+                    self.warning = Some(e);
+                }
+                _ => {}
+            }
+        }
+        Command::none()
+    }
+
+    fn apply(&mut self, _ctx: &mut Context, _config: &mut config::Config) -> bool {
+        false
+    }
+
+    fn view(&mut self, language: Language) -> Element<Message> {
+        view::import_config(
+            language,
+            &self.path,
+            self.warning.as_ref(),
+            &mut self.path_input,
+            &mut self.import_button,
+            &mut self.previous_button,
+            &mut self.scroll,
+        )
+    }
+}
+
+impl Default for ImportConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<ImportConfig> for Box<dyn Step> {
+    fn from(s: ImportConfig) -> Box<dyn Step> {
+        Box::new(s)
+    }
+}
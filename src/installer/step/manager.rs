@@ -1,22 +1,23 @@
 use std::cmp::Ordering;
-use std::net::SocketAddr;
 use std::str::FromStr;
 
-use bitcoin::hashes::hex::FromHex;
 use bitcoin::util::bip32::ExtendedPubKey;
-use iced::{button::State as Button, scrollable, Element};
+use iced::{button::State as Button, scrollable, Command, Element};
 use miniscript::DescriptorPublicKey;
 use revault_tx::scripts::{DepositDescriptor, UnvaultDescriptor};
 
 use crate::{
+    i18n::Language,
     installer::{
         message::{self, Message},
         step::{
-            common::{CosignerKey, ParticipantXpub},
-            Context, Step,
+            common::{self, CosignerKey, ParticipantXpub},
+            readiness::{self, Readiness},
+            valid_host, Context, ImportedConfig, Step,
         },
         view,
     },
+    noise,
     revaultd::config,
     ui::component::form,
 };
@@ -28,6 +29,7 @@ pub struct DefineStakeholderXpubs {
     previous_button: Button,
     save_button: Button,
     warning: Option<String>,
+    readiness: Readiness,
 }
 
 impl DefineStakeholderXpubs {
@@ -39,8 +41,19 @@ impl DefineStakeholderXpubs {
             previous_button: Button::new(),
             save_button: Button::new(),
             warning: None,
+            readiness: Readiness::default(),
         }
     }
+
+    fn readiness(&self) -> Readiness {
+        let xpubs: Vec<&form::Value<String>> =
+            self.stakeholder_xpubs.iter().map(|p| &p.xpub).collect();
+        Readiness::default().keys::<ExtendedPubKey>(
+            "stakeholder",
+            &xpubs,
+            readiness::MIN_STAKEHOLDERS,
+        )
+    }
 }
 
 impl Default for DefineStakeholderXpubs {
@@ -50,7 +63,19 @@ impl Default for DefineStakeholderXpubs {
 }
 
 impl Step for DefineStakeholderXpubs {
-    fn update(&mut self, message: Message) {
+    fn load_config(&mut self, imported: &ImportedConfig) {
+        self.stakeholder_xpubs = imported
+            .stakeholders_xpubs
+            .iter()
+            .map(|xpub| ParticipantXpub::with_xpub(xpub.clone()))
+            .collect();
+    }
+
+    fn load_context(&mut self, _ctx: &Context) {
+        self.readiness = self.readiness();
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
         if let Message::DefineStakeholderXpubs(msg) = message {
             match msg {
                 message::DefineStakeholderXpubs::StakeholderXpub(
@@ -70,6 +95,14 @@ impl Step for DefineStakeholderXpubs {
                 _ => (),
             };
         };
+        Command::none()
+    }
+
+    fn check(&mut self) {
+        for participant in &mut self.stakeholder_xpubs {
+            common::check::<ExtendedPubKey>(&mut participant.xpub);
+        }
+        self.readiness = self.readiness();
     }
 
     fn apply(&mut self, ctx: &mut Context, config: &mut config::Config) -> bool {
@@ -120,14 +153,15 @@ impl Step for DefineStakeholderXpubs {
         true
     }
 
-    fn view(&mut self) -> Element<Message> {
+    fn view(&mut self, language: Language) -> Element<Message> {
         return view::define_stakeholder_xpubs_as_manager_only(
+            language,
             &mut self.add_xpub_button,
             self.stakeholder_xpubs
                 .iter_mut()
                 .enumerate()
                 .map(|(i, xpub)| {
-                    xpub.view().map(move |msg| {
+                    xpub.view(language).map(move |msg| {
                         Message::DefineStakeholderXpubs(
                             message::DefineStakeholderXpubs::StakeholderXpub(i, msg),
                         )
@@ -137,6 +171,7 @@ impl Step for DefineStakeholderXpubs {
             &mut self.scroll,
             &mut self.previous_button,
             &mut self.save_button,
+            &self.readiness,
             self.warning.as_ref(),
         );
     }
@@ -155,6 +190,7 @@ pub struct DefineManagerXpubs {
     managers_threshold: form::Value<usize>,
     spending_delay: form::Value<u32>,
     warning: Option<String>,
+    readiness: Readiness,
 
     view: view::DefineManagerXpubsAsManager,
 
@@ -179,8 +215,20 @@ impl DefineManagerXpubs {
             view: view::DefineManagerXpubsAsManager::new(),
             stakeholder_xpubs: Vec::new(),
             warning: None,
+            readiness: Readiness::default(),
         }
     }
+
+    fn readiness(&self) -> Readiness {
+        let mut xpubs: Vec<&form::Value<String>> = vec![&self.our_xpub];
+        xpubs.extend(self.other_xpubs.iter().map(|p| &p.xpub));
+        let cosigners: Vec<&form::Value<String>> = self.cosigners.iter().map(|c| &c.key).collect();
+        Readiness::default()
+            .keys::<DescriptorPublicKey>("manager", &xpubs, 1)
+            .threshold(self.managers_threshold.value, xpubs.len())
+            .spending_delay(self.spending_delay.value)
+            .keys::<DescriptorPublicKey>("cosigning server", &cosigners, cosigners.len())
+    }
 }
 
 impl Default for DefineManagerXpubs {
@@ -190,6 +238,26 @@ impl Default for DefineManagerXpubs {
 }
 
 impl Step for DefineManagerXpubs {
+    fn load_config(&mut self, imported: &ImportedConfig) {
+        if let Some(manager_config) = &imported.config.manager_config {
+            self.our_xpub.value = manager_config.xpub.to_string();
+        }
+        self.other_xpubs = imported
+            .other_managers_xpubs()
+            .into_iter()
+            .map(ParticipantXpub::with_xpub)
+            .collect();
+        self.cosigners = imported
+            .cosigners_keys
+            .iter()
+            .map(|key| CosignerKey::with_key(key.clone()))
+            .collect();
+        if let Some(threshold) = imported.managers_threshold {
+            self.managers_threshold.value = threshold;
+        }
+        self.spending_delay.value = imported.spending_delay;
+    }
+
     fn load_context(&mut self, ctx: &Context) {
         self.stakeholder_xpubs = ctx.stakeholders_xpubs.clone();
         while self.cosigners.len() != ctx.number_cosigners {
@@ -201,9 +269,10 @@ impl Step for DefineManagerXpubs {
                 Ordering::Equal => (),
             }
         }
+        self.readiness = self.readiness();
     }
 
-    fn update(&mut self, message: Message) {
+    fn update(&mut self, message: Message) -> Command<Message> {
         if let Message::DefineManagerXpubs(msg) = message {
             match msg {
                 message::DefineManagerXpubs::OurXpubEdited(xpub) => {
@@ -238,20 +307,34 @@ impl Step for DefineManagerXpubs {
                         }
                     }
                 },
-                message::DefineManagerXpubs::SpendingDelay(action) => match action {
-                    message::Action::Increment => {
-                        self.spending_delay.valid = true;
-                        self.spending_delay.value += 1;
-                    }
-                    message::Action::Decrement => {
-                        self.spending_delay.valid = true;
-                        if self.spending_delay.value > 0 {
-                            self.spending_delay.value -= 1;
-                        }
-                    }
-                },
+                message::DefineManagerXpubs::SpendingDelayEdited(blocks) => {
+                    common::edit_spending_delay(&mut self.spending_delay, &blocks, 1);
+                }
+                message::DefineManagerXpubs::SpendingDelayHoursEdited(hours) => {
+                    common::edit_spending_delay(
+                        &mut self.spending_delay,
+                        &hours,
+                        view::BLOCKS_PER_HOUR,
+                    );
+                }
+                message::DefineManagerXpubs::SpendingDelayPreset(blocks) => {
+                    self.spending_delay.value = blocks;
+                    common::check_spending_delay(&mut self.spending_delay);
+                }
             };
         };
+        Command::none()
+    }
+
+    fn check(&mut self) {
+        for participant in &mut self.other_xpubs {
+            common::check::<DescriptorPublicKey>(&mut participant.xpub);
+        }
+        common::check::<DescriptorPublicKey>(&mut self.our_xpub);
+        for cosigner in &mut self.cosigners {
+            common::check::<DescriptorPublicKey>(&mut cosigner.key);
+        }
+        self.readiness = self.readiness();
     }
 
     fn apply(&mut self, ctx: &mut Context, config: &mut config::Config) -> bool {
@@ -268,7 +351,8 @@ impl Step for DefineManagerXpubs {
         // If user is manager, other_xpubs can be equal to zero and threshold equal to 1.
         self.managers_threshold.valid = self.managers_threshold.value != 0
             && self.managers_threshold.value <= self.other_xpubs.len() + 1;
-        self.spending_delay.valid = self.spending_delay.value != 0;
+        common::check_spending_delay(&mut self.spending_delay);
+        self.spending_delay.valid &= self.spending_delay.value != 0;
 
         if !self.our_xpub.valid
             || self
@@ -347,8 +431,9 @@ impl Step for DefineManagerXpubs {
         self.warning.is_none()
     }
 
-    fn view(&mut self) -> Element<Message> {
+    fn view(&mut self, language: Language) -> Element<Message> {
         return self.view.render(
+            language,
             &self.managers_threshold,
             &self.spending_delay,
             &self.our_xpub,
@@ -356,7 +441,7 @@ impl Step for DefineManagerXpubs {
                 .iter_mut()
                 .enumerate()
                 .map(|(i, xpub)| {
-                    xpub.view().map(move |msg| {
+                    xpub.view(language).map(move |msg| {
                         Message::DefineManagerXpubs(message::DefineManagerXpubs::ManagerXpub(
                             i, msg,
                         ))
@@ -367,13 +452,14 @@ impl Step for DefineManagerXpubs {
                 .iter_mut()
                 .enumerate()
                 .map(|(i, xpub)| {
-                    xpub.view().map(move |msg| {
+                    xpub.view(language).map(move |msg| {
                         Message::DefineManagerXpubs(message::DefineManagerXpubs::CosignerKey(
                             i, msg,
                         ))
                     })
                 })
                 .collect(),
+            &self.readiness,
             self.warning.as_ref(),
         );
     }
@@ -414,8 +500,8 @@ impl Cosigner {
         }
     }
 
-    pub fn view(&mut self) -> Element<message::DefineCosigner> {
-        self.view.render(&self.host, &self.noise_key)
+    pub fn view(&mut self, language: Language) -> Element<message::DefineCosigner> {
+        self.view.render(language, &self.host, &self.noise_key)
     }
 }
 
@@ -440,6 +526,21 @@ impl DefineCosigners {
 }
 
 impl Step for DefineCosigners {
+    fn load_config(&mut self, imported: &ImportedConfig) {
+        if let Some(manager_config) = &imported.config.manager_config {
+            self.cosigners = manager_config
+                .cosigners
+                .iter()
+                .map(|cfg| {
+                    let mut cosigner = Cosigner::new();
+                    cosigner.host.value = cfg.host.clone();
+                    cosigner.noise_key.value = cfg.noise_key.clone();
+                    cosigner
+                })
+                .collect();
+        }
+    }
+
     fn load_context(&mut self, ctx: &Context) {
         while self.cosigners.len() != ctx.number_cosigners {
             match self.cosigners.len().cmp(&ctx.number_cosigners) {
@@ -452,25 +553,22 @@ impl Step for DefineCosigners {
         }
     }
 
-    fn update(&mut self, message: Message) {
+    fn update(&mut self, message: Message) -> Command<Message> {
         if let Message::DefineCosigners(i, msg) = message {
             if let Some(cosigner) = self.cosigners.get_mut(i) {
                 cosigner.update(msg);
             }
         };
+        Command::none()
     }
 
     fn apply(&mut self, _ctx: &mut Context, config: &mut config::Config) -> bool {
         for cosigner in &mut self.cosigners {
-            if let Ok(bytes) = Vec::from_hex(&cosigner.noise_key.value) {
-                if bytes.len() != 32 {
-                    cosigner.noise_key.valid = false;
-                }
-            } else {
+            if noise::parse_public_key(&cosigner.noise_key.value).is_err() {
                 cosigner.noise_key.valid = false;
             }
 
-            cosigner.host.valid = SocketAddr::from_str(&cosigner.host.value).is_ok();
+            cosigner.host.valid = valid_host(&cosigner.host.value);
         }
 
         if self
@@ -495,12 +593,16 @@ impl Step for DefineCosigners {
         true
     }
 
-    fn view(&mut self) -> Element<Message> {
+    fn view(&mut self, language: Language) -> Element<Message> {
         self.view.render(
+            language,
             self.cosigners
                 .iter_mut()
                 .enumerate()
-                .map(|(i, xpub)| xpub.view().map(move |msg| Message::DefineCosigners(i, msg)))
+                .map(|(i, xpub)| {
+                    xpub.view(language)
+                        .map(move |msg| Message::DefineCosigners(i, msg))
+                })
                 .collect(),
         )
     }
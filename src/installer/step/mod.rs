@@ -1,32 +1,49 @@
 mod common;
+mod descriptors;
+mod import;
 pub mod manager;
+mod readiness;
 pub mod stakeholder;
+#[cfg(feature = "descriptor-vectors")]
+pub mod vectors;
 
 use std::cmp::Ordering;
+use std::io::{Read, Write};
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::str::FromStr;
 
-use bitcoin::hashes::hex::FromHex;
 use bitcoin::util::bip32::ExtendedPubKey;
-use iced::{button::State as Button, scrollable, Element};
+use iced::{button::State as Button, pick_list, scrollable, Command, Element};
 use miniscript::DescriptorPublicKey;
 use revault_tx::scripts::CpfpDescriptor;
 
+pub use descriptors::DefineDescriptors;
+pub use import::{ImportConfig, ImportedConfig};
+pub use readiness::Readiness;
+
 use crate::{
+    bitcoind::{self, BitcoindError, NodeReport},
+    descriptor,
+    i18n::Language,
     installer::{
         message::{self, Message},
         step::common::RequiredXpub,
         view,
     },
+    noise,
     revaultd::config,
     ui::component::form,
 };
 
 pub trait Step {
-    fn update(&mut self, message: Message);
-    fn view(&mut self) -> Element<Message>;
+    fn update(&mut self, message: Message) -> Command<Message>;
+    fn view(&mut self, language: Language) -> Element<Message>;
     fn load_context(&mut self, _ctx: &Context) {}
+    /// Fills the step with the values of an imported configuration.
+    fn load_config(&mut self, _imported: &ImportedConfig) {}
+    /// Checks the fields typed by the user, once the user stopped typing.
+    fn check(&mut self) {}
     fn apply(&mut self, _ctx: &mut Context, _config: &mut config::Config) -> bool {
         true
     }
@@ -34,19 +51,37 @@ pub trait Step {
 
 #[derive(Clone)]
 pub struct Context {
-    pub private_noise_key: String,
+    /// Noise static key written to the noise_secret file of the datadir.
+    pub private_noise_key: Option<[u8; 32]>,
     pub number_managers: usize,
     pub number_cosigners: usize,
     pub stakeholders_xpubs: Vec<String>,
+    /// SOCKS5 proxy of the GUI health checks of the servers, written to the
+    /// GUI configuration.
+    pub health_check_proxy: Option<SocketAddr>,
+    /// Descriptors of the configuration, reviewed before the installation.
+    pub scripts_config: config::ScriptsConfig,
+    /// Network of the configuration, the reviewed addresses are derived for it.
+    pub network: bitcoin::Network,
+    /// Language picked on the welcome screen.
+    pub language: Language,
 }
 
 impl Context {
     pub fn new() -> Self {
         Self {
-            private_noise_key: "".to_string(),
+            private_noise_key: None,
             number_managers: 0,
             number_cosigners: 0,
             stakeholders_xpubs: Vec::new(),
+            health_check_proxy: None,
+            scripts_config: config::ScriptsConfig {
+                deposit_descriptor: "".to_string(),
+                unvault_descriptor: "".to_string(),
+                cpfp_descriptor: "".to_string(),
+            },
+            network: bitcoin::Network::Bitcoin,
+            language: Language::default(),
         }
     }
 }
@@ -58,21 +93,32 @@ impl Default for Context {
 }
 
 pub struct Welcome {
+    language_list: pick_list::State<Language>,
     install_button: Button,
+    import_button: Button,
 }
 
 impl Welcome {
     pub fn new() -> Self {
         Self {
+            language_list: pick_list::State::default(),
             install_button: Button::new(),
+            import_button: Button::new(),
         }
     }
 }
 
 impl Step for Welcome {
-    fn update(&mut self, _message: Message) {}
-    fn view(&mut self) -> Element<Message> {
-        view::welcome(&mut self.install_button)
+    fn update(&mut self, _message: Message) -> Command<Message> {
+        Command::none()
+    }
+    fn view(&mut self, language: Language) -> Element<Message> {
+        view::welcome(
+            language,
+            &mut self.language_list,
+            &mut self.install_button,
+            &mut self.import_button,
+        )
     }
 }
 
@@ -89,6 +135,8 @@ impl From<Welcome> for Box<dyn Step> {
 }
 
 pub struct DefineRole {
+    /// true if the user pastes the descriptors.
+    expert: bool,
     stakeholder_button: Button,
     manager_button: Button,
     stakeholder_manager_button: Button,
@@ -98,6 +146,7 @@ pub struct DefineRole {
 impl DefineRole {
     pub fn new() -> Self {
         Self {
+            expert: false,
             stakeholder_button: Button::new(),
             manager_button: Button::new(),
             stakeholder_manager_button: Button::new(),
@@ -107,9 +156,16 @@ impl DefineRole {
 }
 
 impl Step for DefineRole {
-    fn update(&mut self, _message: Message) {}
-    fn view(&mut self) -> Element<Message> {
+    fn update(&mut self, message: Message) -> Command<Message> {
+        if let Message::ExpertMode(expert) = message {
+            self.expert = expert;
+        }
+        Command::none()
+    }
+    fn view(&mut self, language: Language) -> Element<Message> {
         view::define_role(
+            language,
+            self.expert,
             &mut self.stakeholder_button,
             &mut self.manager_button,
             &mut self.stakeholder_manager_button,
@@ -131,33 +187,80 @@ impl From<DefineRole> for Box<dyn Step> {
 }
 
 pub struct DefinePrivateNoiseKey {
-    key: form::Value<String>,
+    key: Option<[u8; 32]>,
+    path: form::Value<String>,
+    warning: Option<String>,
     view: view::DefinePrivateNoiseKey,
 }
 
 impl DefinePrivateNoiseKey {
     pub fn new() -> Self {
         Self {
-            key: form::Value::default(),
+            key: None,
+            path: form::Value::default(),
+            warning: None,
             view: view::DefinePrivateNoiseKey::new(),
         }
     }
 }
 
 impl Step for DefinePrivateNoiseKey {
-    fn update(&mut self, message: Message) {
-        if let Message::PrivateNoiseKey(msg) = message {
-            self.key.value = msg;
-            self.key.valid = self.key.value.as_bytes().len() == 32;
+    fn load_config(&mut self, imported: &ImportedConfig) {
+        if let Some(key) = imported.private_noise_key {
+            self.key = Some(key);
+        }
+    }
+    fn update(&mut self, message: Message) -> Command<Message> {
+        if let Message::DefineNoiseKey(msg) = message {
+            match msg {
+                message::DefineNoiseKey::Generate => match noise::generate() {
+                    Ok(key) => {
+                        self.key = Some(key);
+                        self.warning = None;
+                    }
+                    Err(e) => self.warning = Some(e.to_string()),
+                },
+                message::DefineNoiseKey::PathEdited(path) => {
+                    self.path.value = path;
+                    self.path.valid = true;
+                }
+                message::DefineNoiseKey::Import => {
+                    let path = PathBuf::from(&self.path.value);
+                    match noise::read(&path) {
+                        Ok(key) => {
+                            self.key = Some(key);
+                            // The installer writes a copy with the right
+                            // permissions, but the original may have leaked.
+                            self.warning = noise::check_permissions(&path).err().map(|e| {
+                                format!("{}, the key may have been read by other users", e)
+                            });
+                        }
+                        Err(e) => {
+                            self.path.valid = false;
+                            self.warning = Some(e.to_string());
+                        }
+                    }
+                }
+            }
         }
+        Command::none()
     }
     fn apply(&mut self, ctx: &mut Context, _config: &mut config::Config) -> bool {
-        self.key.valid = self.key.value.as_bytes().len() == 32;
-        ctx.private_noise_key = self.key.value.clone();
-        self.key.valid
+        if self.key.is_none() {
+            self.warning = Some("Generate or import a noise key".to_string());
+            return false;
+        }
+        ctx.private_noise_key = self.key;
+        true
     }
-    fn view(&mut self) -> Element<Message> {
-        self.view.render(&self.key)
+    fn view(&mut self, language: Language) -> Element<Message> {
+        let public_key = self.key.as_ref().map(noise::public_key_hex);
+        self.view.render(
+            language,
+            public_key.as_deref(),
+            &self.path,
+            self.warning.as_ref(),
+        )
     }
 }
 
@@ -176,6 +279,7 @@ impl From<DefinePrivateNoiseKey> for Box<dyn Step> {
 pub struct DefineCpfpDescriptor {
     manager_xpubs: Vec<RequiredXpub>,
     warning: Option<String>,
+    readiness: Readiness,
 
     view: view::DefineCpfpDescriptorView,
 }
@@ -185,12 +289,26 @@ impl DefineCpfpDescriptor {
         Self {
             manager_xpubs: Vec::new(),
             warning: None,
+            readiness: Readiness::default(),
             view: view::DefineCpfpDescriptorView::new(),
         }
     }
+
+    fn readiness(&self) -> Readiness {
+        let xpubs: Vec<&form::Value<String>> = self.manager_xpubs.iter().map(|p| &p.xpub).collect();
+        Readiness::default().keys::<ExtendedPubKey>("manager", &xpubs, xpubs.len())
+    }
 }
 
 impl Step for DefineCpfpDescriptor {
+    fn load_config(&mut self, imported: &ImportedConfig) {
+        self.manager_xpubs = imported
+            .managers_xpubs
+            .iter()
+            .map(|xpub| RequiredXpub::with_xpub(xpub.clone()))
+            .collect();
+    }
+
     fn load_context(&mut self, ctx: &Context) {
         while self.manager_xpubs.len() != ctx.number_managers {
             match self.manager_xpubs.len().cmp(&ctx.number_managers) {
@@ -201,9 +319,10 @@ impl Step for DefineCpfpDescriptor {
                 Ordering::Equal => (),
             }
         }
+        self.readiness = self.readiness();
     }
 
-    fn update(&mut self, message: Message) {
+    fn update(&mut self, message: Message) -> Command<Message> {
         if let Message::DefineCpfpDescriptor(msg) = message {
             match msg {
                 message::DefineCpfpDescriptor::ManagerXpub(i, msg) => {
@@ -213,6 +332,14 @@ impl Step for DefineCpfpDescriptor {
                 }
             };
         };
+        Command::none()
+    }
+
+    fn check(&mut self) {
+        for participant in &mut self.manager_xpubs {
+            common::check::<ExtendedPubKey>(&mut participant.xpub);
+        }
+        self.readiness = self.readiness();
     }
 
     fn apply(&mut self, _ctx: &mut Context, config: &mut config::Config) -> bool {
@@ -249,19 +376,21 @@ impl Step for DefineCpfpDescriptor {
         self.warning.is_none()
     }
 
-    fn view(&mut self) -> Element<Message> {
+    fn view(&mut self, language: Language) -> Element<Message> {
         return self.view.render(
+            language,
             self.manager_xpubs
                 .iter_mut()
                 .enumerate()
                 .map(|(i, xpub)| {
-                    xpub.view().map(move |msg| {
+                    xpub.view(language).map(move |msg| {
                         Message::DefineCpfpDescriptor(message::DefineCpfpDescriptor::ManagerXpub(
                             i, msg,
                         ))
                     })
                 })
                 .collect(),
+            &self.readiness,
             self.warning.as_ref(),
         );
     }
@@ -279,9 +408,153 @@ impl From<DefineCpfpDescriptor> for Box<dyn Step> {
     }
 }
 
+/// Returns true if the host is a socket address, revaultd does not parse
+/// host names.
+pub fn valid_host(host: &str) -> bool {
+    SocketAddr::from_str(host).is_ok()
+}
+
+/// Opens a SOCKS5 session with the proxy, without authentication.
+async fn check_proxy(addr: SocketAddr) -> Result<(), String> {
+    let timeout = std::time::Duration::from_secs(5);
+    let mut stream = std::net::TcpStream::connect_timeout(&addr, timeout)
+        .map_err(|e| format!("Failed to connect to the proxy: {}", e))?;
+    let mut response = [0u8; 2];
+    stream
+        .set_read_timeout(Some(timeout))
+        .and_then(|_| stream.write_all(&[5, 1, 0]))
+        .and_then(|_| stream.read_exact(&mut response))
+        .map_err(|e| format!("The proxy did not answer the SOCKS5 greeting: {}", e))?;
+    match response {
+        [5, 0] => Ok(()),
+        [5, _] => Err("The proxy requires an authentication, which is not supported".to_string()),
+        _ => Err("The proxy is not a SOCKS5 proxy".to_string()),
+    }
+}
+
+pub struct DefineCheckProxy {
+    enabled: bool,
+    address: form::Value<String>,
+    /// Result of the last check of the proxy.
+    proxy_check: Option<Result<(), String>>,
+    /// true while the check of the proxy runs.
+    checking: bool,
+    /// Id of the last check, the result of a check started before the
+    /// fields were edited is dropped.
+    check_id: usize,
+
+    view: view::DefineCheckProxy,
+}
+
+impl DefineCheckProxy {
+    pub fn new() -> Self {
+        Self {
+            enabled: false,
+            // Default port of the Tor daemon.
+            address: form::Value {
+                value: "127.0.0.1:9050".to_string(),
+                valid: true,
+            },
+            proxy_check: None,
+            checking: false,
+            check_id: 0,
+            view: view::DefineCheckProxy::new(),
+        }
+    }
+
+    /// Drops the result of the last check, the fields were edited.
+    fn reset_check(&mut self) {
+        self.proxy_check = None;
+        self.checking = false;
+        self.check_id += 1;
+    }
+}
+
+impl Step for DefineCheckProxy {
+    fn update(&mut self, message: Message) -> Command<Message> {
+        if let Message::DefineCheckProxy(msg) = message {
+            match msg {
+                message::DefineCheckProxy::Enabled(enabled) => {
+                    self.enabled = enabled;
+                    self.reset_check();
+                }
+                message::DefineCheckProxy::AddressEdited(address) => {
+                    self.address.value = address;
+                    self.address.valid = true;
+                    self.reset_check();
+                }
+                message::DefineCheckProxy::CheckProxy => {
+                    match SocketAddr::from_str(&self.address.value) {
+                        Ok(addr) => {
+                            self.reset_check();
+                            self.checking = true;
+                            let id = self.check_id;
+                            return Command::perform(check_proxy(addr), move |res| {
+                                Message::DefineCheckProxy(message::DefineCheckProxy::ProxyChecked(
+                                    id, res,
+                                ))
+                            });
+                        }
+                        Err(_) => self.address.valid = false,
+                    }
+                }
+                message::DefineCheckProxy::ProxyChecked(id, res) => {
+                    if id == self.check_id {
+                        self.checking = false;
+                        self.proxy_check = Some(res);
+                    }
+                }
+            };
+        };
+        Command::none()
+    }
+
+    fn apply(&mut self, ctx: &mut Context, _config: &mut config::Config) -> bool {
+        if !self.enabled {
+            ctx.health_check_proxy = None;
+            return true;
+        }
+
+        match SocketAddr::from_str(&self.address.value) {
+            Ok(addr) => {
+                ctx.health_check_proxy = Some(addr);
+                true
+            }
+            Err(_) => {
+                self.address.valid = false;
+                false
+            }
+        }
+    }
+
+    fn view(&mut self, language: Language) -> Element<'_, Message> {
+        self.view.render(
+            language,
+            self.enabled,
+            &self.address,
+            (self.checking, self.proxy_check.as_ref()),
+        )
+    }
+}
+
+impl Default for DefineCheckProxy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<DefineCheckProxy> for Box<dyn Step> {
+    fn from(s: DefineCheckProxy) -> Box<dyn Step> {
+        Box::new(s)
+    }
+}
+
 pub struct DefineCoordinator {
     host: form::Value<String>,
     noise_key: form::Value<String>,
+    noise_key_warning: String,
+    /// fingerprint of the valid noise key, compared out-of-band by the participants.
+    fingerprint: Option<String>,
 
     view: view::DefineCoordinator,
 }
@@ -291,13 +564,35 @@ impl DefineCoordinator {
         Self {
             host: form::Value::default(),
             noise_key: form::Value::default(),
+            noise_key_warning: String::new(),
+            fingerprint: None,
             view: view::DefineCoordinator::new(),
         }
     }
+
+    fn check_noise_key(&mut self) {
+        match noise::parse_public_key(&self.noise_key.value) {
+            Ok(key) => {
+                self.noise_key.valid = true;
+                self.fingerprint = Some(noise::fingerprint(&key));
+            }
+            Err(e) => {
+                self.noise_key.valid = false;
+                self.noise_key_warning = e.to_string();
+                self.fingerprint = None;
+            }
+        }
+    }
 }
 
 impl Step for DefineCoordinator {
-    fn update(&mut self, message: Message) {
+    fn load_config(&mut self, imported: &ImportedConfig) {
+        self.host.value = imported.config.coordinator_host.clone();
+        self.noise_key.value = imported.config.coordinator_noise_key.clone();
+        self.check();
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
         if let Message::DefineCoordinator(msg) = message {
             match msg {
                 message::DefineCoordinator::HostEdited(host) => {
@@ -307,21 +602,23 @@ impl Step for DefineCoordinator {
                 message::DefineCoordinator::NoiseKeyEdited(key) => {
                     self.noise_key.value = key;
                     self.noise_key.valid = true;
+                    self.fingerprint = None;
                 }
             };
         };
+        Command::none()
     }
 
-    fn apply(&mut self, _ctx: &mut Context, config: &mut config::Config) -> bool {
-        if let Ok(bytes) = Vec::from_hex(&self.noise_key.value) {
-            if bytes.len() != 32 {
-                self.noise_key.valid = false;
-            }
-        } else {
-            self.noise_key.valid = false;
+    fn check(&mut self) {
+        if !self.noise_key.value.is_empty() {
+            self.check_noise_key();
         }
+    }
 
-        self.host.valid = SocketAddr::from_str(&self.host.value).is_ok();
+    fn apply(&mut self, _ctx: &mut Context, config: &mut config::Config) -> bool {
+        self.check_noise_key();
+
+        self.host.valid = valid_host(&self.host.value);
 
         if !self.host.valid || !self.noise_key.valid {
             return false;
@@ -332,8 +629,16 @@ impl Step for DefineCoordinator {
         true
     }
 
-    fn view(&mut self) -> Element<Message> {
-        self.view.render(&self.host, &self.noise_key)
+    fn view(&mut self, language: Language) -> Element<Message> {
+        self.view.render(
+            language,
+            &self.host,
+            (
+                &self.noise_key,
+                &self.noise_key_warning,
+                self.fingerprint.as_ref(),
+            ),
+        )
     }
 }
 
@@ -353,6 +658,13 @@ pub struct DefineBitcoind {
     network: bitcoin::Network,
     cookie_path: form::Value<String>,
     address: form::Value<String>,
+    /// Result of the last connection test of the node.
+    node_check: Option<Result<NodeReport, BitcoindError>>,
+    /// true while the connection test runs.
+    checking: bool,
+    /// Id of the last connection test, the result of a test started before
+    /// the fields were edited is dropped.
+    check_id: usize,
 
     view: view::DefineBitcoind,
 }
@@ -388,66 +700,127 @@ impl DefineBitcoind {
                 value: "127.0.0.1:8332".to_string(),
                 valid: true,
             },
+            node_check: None,
+            checking: false,
+            check_id: 0,
             view: view::DefineBitcoind::new(),
         }
     }
+
+    /// Returns the address and the cookie file of the fields of the form,
+    /// the invalid fields are marked.
+    fn node(&mut self) -> Option<(SocketAddr, PathBuf)> {
+        let addr = SocketAddr::from_str(&self.address.value).ok();
+        self.address.valid = addr.is_some();
+        let path = PathBuf::from(&self.cookie_path.value);
+        self.cookie_path.valid = path.is_file();
+        addr.zip(Some(path).filter(|_| self.cookie_path.valid))
+    }
+
+    /// Drops the result of the last connection test, the fields were edited.
+    fn reset_check(&mut self) {
+        self.node_check = None;
+        self.checking = false;
+        self.check_id += 1;
+    }
+
+    /// Returns true if the last connection test of the current fields passed.
+    fn check_passed(&self) -> bool {
+        !self.checking && matches!(&self.node_check, Some(Ok(report)) if report.passed())
+    }
 }
 
 impl Step for DefineBitcoind {
-    fn update(&mut self, message: Message) {
+    fn load_config(&mut self, imported: &ImportedConfig) {
+        let bitcoind = &imported.config.bitcoind_config;
+        self.network = bitcoind.network;
+        self.address.value = bitcoind.addr.to_string();
+        self.cookie_path.value = bitcoind.cookie_path.to_string_lossy().to_string();
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
         if let Message::DefineBitcoind(msg) = message {
             match msg {
                 message::DefineBitcoind::AddressEdited(address) => {
                     self.address.value = address;
                     self.address.valid = true;
+                    self.reset_check();
                 }
                 message::DefineBitcoind::CookiePathEdited(path) => {
                     self.cookie_path.value = path;
-                    self.address.valid = true;
+                    self.cookie_path.valid = true;
+                    self.reset_check();
                 }
                 message::DefineBitcoind::NetworkEdited(network) => {
                     self.network = network;
+                    self.reset_check();
+                }
+                message::DefineBitcoind::CheckNode => {
+                    if let Some((addr, cookie_path)) = self.node() {
+                        self.reset_check();
+                        self.checking = true;
+                        let id = self.check_id;
+                        return Command::perform(
+                            test_node(addr, cookie_path, self.network),
+                            move |res| {
+                                Message::DefineBitcoind(message::DefineBitcoind::NodeChecked(
+                                    id, res,
+                                ))
+                            },
+                        );
+                    }
+                }
+                message::DefineBitcoind::NodeChecked(id, res) => {
+                    if id == self.check_id {
+                        self.checking = false;
+                        self.node_check = Some(res);
+                    }
                 }
             };
         };
+        Command::none()
     }
 
     fn apply(&mut self, _ctx: &mut Context, config: &mut config::Config) -> bool {
-        match (
-            PathBuf::from_str(&self.cookie_path.value),
-            std::net::SocketAddr::from_str(&self.address.value),
-        ) {
-            (Err(_), Ok(_)) => {
-                self.cookie_path.valid = false;
-                false
-            }
-            (Ok(_), Err(_)) => {
-                self.address.valid = false;
-                false
-            }
-            (Err(_), Err(_)) => {
-                self.cookie_path.valid = false;
-                self.address.valid = false;
-                false
-            }
-            (Ok(path), Ok(addr)) => {
-                config.bitcoind_config = config::BitcoindConfig {
-                    network: self.network,
-                    cookie_path: path,
-                    poll_interval_secs: None,
-                    addr,
-                };
-                true
-            }
+        // The installer does not proceed until a connection test of the
+        // fields passed, the test is reset when a field is edited.
+        if !self.check_passed() {
+            return false;
         }
+        let (addr, cookie_path) = match self.node() {
+            Some(node) => node,
+            None => return false,
+        };
+        config.bitcoind_config = config::BitcoindConfig {
+            network: self.network,
+            cookie_path,
+            poll_interval_secs: config.bitcoind_config.poll_interval_secs,
+            addr,
+        };
+        true
     }
 
-    fn view(&mut self) -> Element<Message> {
-        self.view
-            .render(&self.network, &self.address, &self.cookie_path)
+    fn view(&mut self, language: Language) -> Element<Message> {
+        self.view.render(
+            language,
+            &self.network,
+            &self.address,
+            &self.cookie_path,
+            (self.checking, self.node_check.as_ref()),
+        )
     }
 }
 
+/// Tests the connection to the node, the unvault timelock is not known by
+/// the installer.
+async fn test_node(
+    addr: SocketAddr,
+    cookie_path: PathBuf,
+    network: bitcoin::Network,
+) -> Result<NodeReport, BitcoindError> {
+    bitcoind::test_node(&addr, &cookie_path, network, None)
+}
+
 impl Default for DefineBitcoind {
     fn default() -> Self {
         Self::new()
@@ -460,6 +833,68 @@ impl From<DefineBitcoind> for Box<dyn Step> {
     }
 }
 
+/// Number of deposit addresses displayed by the review step.
+const REVIEW_ADDRESSES: u32 = 5;
+
+/// Review displays the descriptors of the configuration and their first
+/// deposit addresses, to be checked out-of-band with the other participants
+/// before the configuration is written.
+pub struct Review {
+    scripts_config: config::ScriptsConfig,
+    network: bitcoin::Network,
+    /// First deposit addresses, or the reason they could not be derived.
+    addresses: Result<Vec<String>, String>,
+    view: view::Review,
+}
+
+impl Review {
+    pub fn new() -> Self {
+        Self {
+            scripts_config: Context::new().scripts_config,
+            network: bitcoin::Network::Bitcoin,
+            addresses: Ok(Vec::new()),
+            view: view::Review::new(),
+        }
+    }
+}
+
+impl Step for Review {
+    fn load_context(&mut self, ctx: &Context) {
+        self.scripts_config = ctx.scripts_config.clone();
+        self.network = ctx.network;
+        self.addresses = descriptor::deposit_addresses(
+            &self.scripts_config.deposit_descriptor,
+            self.network,
+            0..REVIEW_ADDRESSES,
+        );
+    }
+
+    fn update(&mut self, _message: Message) -> Command<Message> {
+        Command::none()
+    }
+
+    fn view(&mut self, language: Language) -> Element<Message> {
+        self.view.render(
+            language,
+            &self.scripts_config,
+            self.network,
+            self.addresses.as_ref(),
+        )
+    }
+}
+
+impl Default for Review {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Review> for Box<dyn Step> {
+    fn from(s: Review) -> Box<dyn Step> {
+        Box::new(s)
+    }
+}
+
 pub struct Final {
     generating: bool,
     warning: Option<String>,
@@ -479,7 +914,7 @@ impl Final {
 }
 
 impl Step for Final {
-    fn update(&mut self, message: Message) {
+    fn update(&mut self, message: Message) -> Command<Message> {
         match message {
             Message::Installed(res) => {
                 self.generating = false;
@@ -498,10 +933,12 @@ impl Step for Final {
             }
             _ => {}
         };
+        Command::none()
     }
 
-    fn view(&mut self) -> Element<Message> {
+    fn view(&mut self, language: Language) -> Element<Message> {
         self.view.render(
+            language,
             self.generating,
             self.config_path.as_ref(),
             self.warning.as_ref(),
@@ -624,7 +1061,7 @@ mod tests {
         let mut ctx = Context::new();
         let mut manager_step = manager::DefineManagerXpubs::new();
         manager_step.load_context(&Context {
-            private_noise_key: "".to_string(),
+            private_noise_key: None,
             number_managers: 1,
             number_cosigners: 4,
             stakeholders_xpubs: vec![
@@ -633,6 +1070,7 @@ mod tests {
                 STAKEHOLDERS_XPUBS[0].to_string(),
                 STAKEHOLDERS_XPUBS[3].to_string(),
             ],
+            ..Context::new()
         });
 
         load_managers_xpubs(&mut manager_step, vec![MANAGERS_XPUBS[0].to_string()]);
@@ -654,7 +1092,7 @@ mod tests {
             DefineManagerXpubs::ManagersThreshold(Action::Increment),
         ));
         manager_step.update(Message::DefineManagerXpubs(
-            DefineManagerXpubs::SpendingDelay(Action::Increment),
+            DefineManagerXpubs::SpendingDelayEdited("11".to_string()),
         ));
 
         let mut manager_config = Config::new();
@@ -662,7 +1100,7 @@ mod tests {
 
         let mut stakeholder_step = stakeholder::DefineManagerXpubs::new();
         stakeholder_step.load_context(&Context {
-            private_noise_key: "".to_string(),
+            private_noise_key: None,
             number_managers: 1,
             number_cosigners: 4,
             stakeholders_xpubs: vec![
@@ -671,6 +1109,7 @@ mod tests {
                 STAKEHOLDERS_XPUBS[0].to_string(),
                 STAKEHOLDERS_XPUBS[1].to_string(),
             ],
+            ..Context::new()
         });
 
         load_managers_xpubs(
@@ -690,7 +1129,7 @@ mod tests {
             DefineManagerXpubs::ManagersThreshold(Action::Increment),
         ));
         stakeholder_step.update(Message::DefineManagerXpubs(
-            DefineManagerXpubs::SpendingDelay(Action::Increment),
+            DefineManagerXpubs::SpendingDelayEdited("11".to_string()),
         ));
 
         let mut stakeholder_config = Config::new();
@@ -735,4 +1174,10 @@ mod tests {
             cpfp_2_config.scripts_config.cpfp_descriptor,
         );
     }
+
+    #[cfg(feature = "descriptor-vectors")]
+    #[test]
+    fn descriptor_vectors() {
+        super::vectors::run(50, 0).unwrap();
+    }
 }
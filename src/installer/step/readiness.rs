@@ -0,0 +1,142 @@
+//! Summary of the keys entered in the xpubs steps, listing what remains
+//! before the descriptors can be built. The requirements are the ones of
+//! the revault_tx descriptors.
+
+use std::str::FromStr;
+
+use crate::{i18n::Language, ui::component::form};
+
+/// Minimum number of stakeholders of the deposit descriptor.
+pub const MIN_STAKEHOLDERS: usize = 2;
+
+/// The relative locktime of the unvault descriptor is a number of blocks
+/// encoded on 16 bits.
+const MAX_SPENDING_DELAY: u32 = 0xffff;
+
+/// Argument of the text of an item, a name is translated with the text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Arg {
+    Number(usize),
+    Name(&'static str),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    pub done: bool,
+    /// English text of the item, each `{}` is replaced by the next argument.
+    text: &'static str,
+    args: Vec<Arg>,
+}
+
+impl Item {
+    /// Returns the text of the item in the language.
+    pub fn text(&self, language: Language) -> String {
+        let args: Vec<String> = self
+            .args
+            .iter()
+            .map(|arg| match arg {
+                Arg::Number(n) => n.to_string(),
+                Arg::Name(name) => language.tr(name).to_string(),
+            })
+            .collect();
+        let args: Vec<&dyn std::fmt::Display> =
+            args.iter().map(|a| a as &dyn std::fmt::Display).collect();
+        language.trf(self.text, &args)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Readiness {
+    items: Vec<Item>,
+}
+
+/// Returns the singular text if n is one, else the plural one.
+fn plural(n: usize, one: &'static str, many: &'static str) -> &'static str {
+    if n == 1 {
+        one
+    } else {
+        many
+    }
+}
+
+impl Readiness {
+    fn push(mut self, done: bool, text: &'static str, args: Vec<Arg>) -> Self {
+        self.items.push(Item { done, text, args });
+        self
+    }
+
+    /// Counts the keys of the fields, an empty field being a key to enter.
+    pub fn keys<T: FromStr>(
+        self,
+        name: &'static str,
+        fields: &[&form::Value<String>],
+        min: usize,
+    ) -> Self {
+        let entered = fields.iter().filter(|f| !f.value.is_empty()).count();
+        let invalid = fields
+            .iter()
+            .filter(|f| !f.value.is_empty() && T::from_str(&f.value).is_err())
+            .count();
+        if invalid > 0 {
+            let text = plural(invalid, "{} {} key invalid", "{} {} keys invalid");
+            self.push(false, text, vec![Arg::Number(invalid), Arg::Name(name)])
+        } else if fields.len() < min {
+            let text = plural(
+                entered,
+                "{} {} key entered, need at least {}",
+                "{} {} keys entered, need at least {}",
+            );
+            let args = vec![Arg::Number(entered), Arg::Name(name), Arg::Number(min)];
+            self.push(false, text, args)
+        } else if entered < fields.len() {
+            let args = vec![
+                Arg::Number(entered),
+                Arg::Number(fields.len()),
+                Arg::Name(name),
+            ];
+            self.push(false, "{} of {} {} keys entered", args)
+        } else {
+            let text = plural(entered, "{} {} key entered", "{} {} keys entered");
+            self.push(true, text, vec![Arg::Number(entered), Arg::Name(name)])
+        }
+    }
+
+    pub fn threshold(self, threshold: usize, managers: usize) -> Self {
+        let args = vec![Arg::Number(threshold), Arg::Number(managers)];
+        if threshold == 0 || threshold > managers {
+            let text = plural(
+                managers,
+                "Threshold currently invalid: {} of {} manager",
+                "Threshold currently invalid: {} of {} managers",
+            );
+            self.push(false, text, args)
+        } else {
+            let text = plural(
+                managers,
+                "Threshold of {} of {} manager",
+                "Threshold of {} of {} managers",
+            );
+            self.push(true, text, args)
+        }
+    }
+
+    pub fn spending_delay(self, delay: u32) -> Self {
+        if delay == 0 {
+            self.push(false, "Spending delay must be at least 1 block", Vec::new())
+        } else if delay > MAX_SPENDING_DELAY {
+            let args = vec![Arg::Number(MAX_SPENDING_DELAY as usize)];
+            self.push(false, "Spending delay must be at most {} blocks", args)
+        } else {
+            let text = plural(
+                delay as usize,
+                "Spending delay of {} block",
+                "Spending delay of {} blocks",
+            );
+            self.push(true, text, vec![Arg::Number(delay as usize)])
+        }
+    }
+
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+}
@@ -1,21 +1,23 @@
 use std::cmp::Ordering;
 use std::str::FromStr;
 
-use bitcoin::hashes::hex::FromHex;
 use bitcoin::util::bip32::ExtendedPubKey;
-use iced::Element;
+use iced::{Command, Element};
 use miniscript::DescriptorPublicKey;
 use revault_tx::scripts::{DepositDescriptor, UnvaultDescriptor};
 
 use crate::{
+    i18n::Language,
     installer::{
         message::{self, Message},
         step::{
-            common::{CosignerKey, ParticipantXpub},
-            Context, Step,
+            common::{self, CosignerKey, ParticipantXpub},
+            readiness::{self, Readiness},
+            valid_host, Context, ImportedConfig, Step,
         },
         view,
     },
+    keys, noise,
     revaultd::config,
     ui::component::form,
 };
@@ -24,23 +26,65 @@ pub struct DefineStakeholderXpubs {
     other_xpubs: Vec<ParticipantXpub>,
     our_xpub: form::Value<String>,
     warning: Option<String>,
+    readiness: Readiness,
+    /// Network of the configuration, the default of the generated key.
+    network: bitcoin::Network,
+    key_setup: Option<KeySetup>,
 
     view: view::DefineStakeholderXpubsAsStakeholder,
 }
 
+/// KeySetup is the mnemonic generated for the user without a signing device,
+/// it is dropped once its xpub is used or discarded.
+struct KeySetup {
+    mnemonic: keys::Mnemonic,
+    network: bitcoin::Network,
+    xpub: ExtendedPubKey,
+    backed_up: bool,
+}
+
 impl DefineStakeholderXpubs {
     pub fn new() -> Self {
         Self {
             warning: None,
+            readiness: Readiness::default(),
             our_xpub: form::Value::default(),
             other_xpubs: Vec::new(),
+            network: bitcoin::Network::Bitcoin,
+            key_setup: None,
             view: view::DefineStakeholderXpubsAsStakeholder::new(),
         }
     }
+
+    fn readiness(&self) -> Readiness {
+        let mut xpubs: Vec<&form::Value<String>> = vec![&self.our_xpub];
+        xpubs.extend(self.other_xpubs.iter().map(|p| &p.xpub));
+        Readiness::default().keys::<ExtendedPubKey>(
+            "stakeholder",
+            &xpubs,
+            readiness::MIN_STAKEHOLDERS,
+        )
+    }
 }
 
 impl Step for DefineStakeholderXpubs {
-    fn update(&mut self, message: Message) {
+    fn load_config(&mut self, imported: &ImportedConfig) {
+        if let Some(stakeholder_config) = &imported.config.stakeholder_config {
+            self.our_xpub.value = stakeholder_config.xpub.to_string();
+        }
+        self.other_xpubs = imported
+            .other_stakeholders_xpubs()
+            .into_iter()
+            .map(ParticipantXpub::with_xpub)
+            .collect();
+    }
+
+    fn load_context(&mut self, ctx: &Context) {
+        self.network = ctx.network;
+        self.readiness = self.readiness();
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
         if let Message::DefineStakeholderXpubs(msg) = message {
             match msg {
                 message::DefineStakeholderXpubs::OurXpubEdited(xpub) => {
@@ -61,8 +105,61 @@ impl Step for DefineStakeholderXpubs {
                 message::DefineStakeholderXpubs::AddXpub => {
                     self.other_xpubs.push(ParticipantXpub::new());
                 }
+                message::DefineStakeholderXpubs::GenerateKey => {
+                    self.key_setup = None;
+                    match keys::Mnemonic::generate().and_then(|mnemonic| {
+                        let xpub = mnemonic.account_xpub(self.network)?;
+                        Ok(KeySetup {
+                            mnemonic,
+                            network: self.network,
+                            xpub,
+                            backed_up: false,
+                        })
+                    }) {
+                        Ok(setup) => {
+                            self.key_setup = Some(setup);
+                            self.warning = None;
+                        }
+                        Err(e) => self.warning = Some(e.to_string()),
+                    }
+                }
+                message::DefineStakeholderXpubs::KeyNetworkEdited(network) => {
+                    if let Some(setup) = &mut self.key_setup {
+                        match setup.mnemonic.account_xpub(network) {
+                            Ok(xpub) => {
+                                setup.network = network;
+                                setup.xpub = xpub;
+                            }
+                            Err(e) => self.warning = Some(e.to_string()),
+                        }
+                    }
+                }
+                message::DefineStakeholderXpubs::KeyBackedUp(backed_up) => {
+                    if let Some(setup) = &mut self.key_setup {
+                        setup.backed_up = backed_up;
+                    }
+                }
+                message::DefineStakeholderXpubs::UseGeneratedKey => {
+                    if let Some(setup) = self.key_setup.take() {
+                        self.our_xpub.value = setup.xpub.to_string();
+                        self.our_xpub.valid = true;
+                        self.readiness = self.readiness();
+                    }
+                }
+                message::DefineStakeholderXpubs::DiscardGeneratedKey => {
+                    self.key_setup = None;
+                }
             };
         };
+        Command::none()
+    }
+
+    fn check(&mut self) {
+        for participant in &mut self.other_xpubs {
+            common::check::<ExtendedPubKey>(&mut participant.xpub);
+        }
+        common::check::<ExtendedPubKey>(&mut self.our_xpub);
+        self.readiness = self.readiness();
     }
 
     fn apply(&mut self, ctx: &mut Context, config: &mut config::Config) -> bool {
@@ -118,21 +215,26 @@ impl Step for DefineStakeholderXpubs {
         self.warning.is_none()
     }
 
-    fn view(&mut self) -> Element<Message> {
+    fn view(&mut self, language: Language) -> Element<Message> {
         return self.view.render(
+            language,
             &self.our_xpub,
             self.other_xpubs
                 .iter_mut()
                 .enumerate()
                 .map(|(i, xpub)| {
-                    xpub.view().map(move |msg| {
+                    xpub.view(language).map(move |msg| {
                         Message::DefineStakeholderXpubs(
                             message::DefineStakeholderXpubs::StakeholderXpub(i, msg),
                         )
                     })
                 })
                 .collect(),
+            &self.readiness,
             self.warning.as_ref(),
+            self.key_setup
+                .as_ref()
+                .map(|setup| (&setup.mnemonic, setup.network, &setup.xpub, setup.backed_up)),
         );
     }
 }
@@ -155,6 +257,7 @@ pub struct DefineManagerXpubs {
     manager_xpubs: Vec<ParticipantXpub>,
     cosigners: Vec<CosignerKey>,
     warning: Option<String>,
+    readiness: Readiness,
     view: view::DefineManagerXpubsAsStakeholderOnly,
 
     /// from previous step
@@ -177,10 +280,39 @@ impl DefineManagerXpubs {
             view: view::DefineManagerXpubsAsStakeholderOnly::new(),
             stakeholder_xpubs: Vec::new(),
             warning: None,
+            readiness: Readiness::default(),
         }
     }
+
+    fn readiness(&self) -> Readiness {
+        let xpubs: Vec<&form::Value<String>> = self.manager_xpubs.iter().map(|p| &p.xpub).collect();
+        let cosigners: Vec<&form::Value<String>> = self.cosigners.iter().map(|c| &c.key).collect();
+        Readiness::default()
+            .keys::<ExtendedPubKey>("manager", &xpubs, 1)
+            .threshold(self.managers_threshold.value, xpubs.len())
+            .spending_delay(self.spending_delay.value)
+            .keys::<DescriptorPublicKey>("cosigning server", &cosigners, cosigners.len())
+    }
 }
+
 impl Step for DefineManagerXpubs {
+    fn load_config(&mut self, imported: &ImportedConfig) {
+        self.manager_xpubs = imported
+            .managers_xpubs
+            .iter()
+            .map(|xpub| ParticipantXpub::with_xpub(xpub.clone()))
+            .collect();
+        self.cosigners = imported
+            .cosigners_keys
+            .iter()
+            .map(|key| CosignerKey::with_key(key.clone()))
+            .collect();
+        if let Some(threshold) = imported.managers_threshold {
+            self.managers_threshold.value = threshold;
+        }
+        self.spending_delay.value = imported.spending_delay;
+    }
+
     fn load_context(&mut self, ctx: &Context) {
         self.stakeholder_xpubs = ctx.stakeholders_xpubs.clone();
         while self.cosigners.len() != ctx.number_cosigners {
@@ -192,9 +324,10 @@ impl Step for DefineManagerXpubs {
                 Ordering::Equal => (),
             }
         }
+        self.readiness = self.readiness();
     }
 
-    fn update(&mut self, message: Message) {
+    fn update(&mut self, message: Message) -> Command<Message> {
         if let Message::DefineManagerXpubs(msg) = message {
             match msg {
                 message::DefineManagerXpubs::ManagerXpub(i, message::ParticipantXpub::Delete) => {
@@ -225,21 +358,34 @@ impl Step for DefineManagerXpubs {
                         }
                     }
                 },
-                message::DefineManagerXpubs::SpendingDelay(action) => match action {
-                    message::Action::Increment => {
-                        self.spending_delay.value += 1;
-                        self.spending_delay.valid = true;
-                    }
-                    message::Action::Decrement => {
-                        self.spending_delay.valid = false;
-                        if self.spending_delay.value > 0 {
-                            self.spending_delay.value -= 1;
-                        }
-                    }
-                },
+                message::DefineManagerXpubs::SpendingDelayEdited(blocks) => {
+                    common::edit_spending_delay(&mut self.spending_delay, &blocks, 1);
+                }
+                message::DefineManagerXpubs::SpendingDelayHoursEdited(hours) => {
+                    common::edit_spending_delay(
+                        &mut self.spending_delay,
+                        &hours,
+                        view::BLOCKS_PER_HOUR,
+                    );
+                }
+                message::DefineManagerXpubs::SpendingDelayPreset(blocks) => {
+                    self.spending_delay.value = blocks;
+                    common::check_spending_delay(&mut self.spending_delay);
+                }
                 _ => {}
             };
         };
+        Command::none()
+    }
+
+    fn check(&mut self) {
+        for participant in &mut self.manager_xpubs {
+            common::check::<ExtendedPubKey>(&mut participant.xpub);
+        }
+        for cosigner in &mut self.cosigners {
+            common::check::<DescriptorPublicKey>(&mut cosigner.key);
+        }
+        self.readiness = self.readiness();
     }
 
     fn apply(&mut self, ctx: &mut Context, config: &mut config::Config) -> bool {
@@ -253,7 +399,8 @@ impl Step for DefineManagerXpubs {
 
         self.managers_threshold.valid = self.managers_threshold.value != 0
             && self.managers_threshold.value <= self.manager_xpubs.len();
-        self.spending_delay.valid = self.spending_delay.value != 0;
+        common::check_spending_delay(&mut self.spending_delay);
+        self.spending_delay.valid &= self.spending_delay.value != 0;
 
         if self
             .manager_xpubs
@@ -325,15 +472,16 @@ impl Step for DefineManagerXpubs {
         self.warning.is_none()
     }
 
-    fn view(&mut self) -> Element<Message> {
+    fn view(&mut self, language: Language) -> Element<Message> {
         self.view.render(
+            language,
             &self.managers_threshold,
             &self.spending_delay,
             self.manager_xpubs
                 .iter_mut()
                 .enumerate()
                 .map(|(i, xpub)| {
-                    xpub.view().map(move |msg| {
+                    xpub.view(language).map(move |msg| {
                         Message::DefineManagerXpubs(message::DefineManagerXpubs::ManagerXpub(
                             i, msg,
                         ))
@@ -344,13 +492,14 @@ impl Step for DefineManagerXpubs {
                 .iter_mut()
                 .enumerate()
                 .map(|(i, xpub)| {
-                    xpub.view().map(move |msg| {
+                    xpub.view(language).map(move |msg| {
                         Message::DefineManagerXpubs(message::DefineManagerXpubs::CosignerKey(
                             i, msg,
                         ))
                     })
                 })
                 .collect(),
+            &self.readiness,
             self.warning.as_ref(),
         )
     }
@@ -386,12 +535,19 @@ impl DefineEmergencyAddress {
 }
 
 impl Step for DefineEmergencyAddress {
-    fn update(&mut self, message: Message) {
+    fn load_config(&mut self, imported: &ImportedConfig) {
+        if let Some(stakeholder_config) = &imported.config.stakeholder_config {
+            self.address.value = stakeholder_config.emergency_address.clone();
+        }
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
         if let Message::DefineEmergencyAddress(address) = message {
             self.address.value = address;
             self.address.valid = true;
             self.warning = None;
         };
+        Command::none()
     }
 
     fn apply(&mut self, _ctx: &mut Context, config: &mut config::Config) -> bool {
@@ -417,8 +573,9 @@ impl Step for DefineEmergencyAddress {
         }
     }
 
-    fn view(&mut self) -> Element<Message> {
-        self.view.render(&self.address, self.warning.as_ref())
+    fn view(&mut self, language: Language) -> Element<Message> {
+        self.view
+            .render(language, &self.address, self.warning.as_ref())
     }
 }
 
@@ -464,8 +621,8 @@ impl Watchtower {
         }
     }
 
-    pub fn view(&mut self) -> Element<message::DefineWatchtower> {
-        self.view.render(&self.host, &self.noise_key)
+    pub fn view(&mut self, language: Language) -> Element<message::DefineWatchtower> {
+        self.view.render(language, &self.host, &self.noise_key)
     }
 }
 
@@ -490,7 +647,22 @@ impl DefineWatchtowers {
 }
 
 impl Step for DefineWatchtowers {
-    fn update(&mut self, message: Message) {
+    fn load_config(&mut self, imported: &ImportedConfig) {
+        if let Some(stakeholder_config) = &imported.config.stakeholder_config {
+            self.watchtowers = stakeholder_config
+                .watchtowers
+                .iter()
+                .map(|cfg| {
+                    let mut watchtower = Watchtower::new();
+                    watchtower.host.value = cfg.host.clone();
+                    watchtower.noise_key.value = cfg.noise_key.clone();
+                    watchtower
+                })
+                .collect();
+        }
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
         if let Message::DefineWatchtowers(msg) = message {
             match msg {
                 message::DefineWatchtowers::EditWatchtower(
@@ -509,17 +681,22 @@ impl Step for DefineWatchtowers {
                 }
             };
         };
+        Command::none()
     }
 
     fn apply(&mut self, _ctx: &mut Context, config: &mut config::Config) -> bool {
         for watchtower in &mut self.watchtowers {
-            if let Ok(bytes) = Vec::from_hex(&watchtower.noise_key.value) {
-                if bytes.len() != 32 {
-                    watchtower.noise_key.valid = false;
-                }
-            } else {
-                watchtower.noise_key.valid = false;
-            }
+            watchtower.noise_key.valid =
+                noise::parse_public_key(&watchtower.noise_key.value).is_ok();
+            watchtower.host.valid = valid_host(&watchtower.host.value);
+        }
+
+        if self
+            .watchtowers
+            .iter()
+            .any(|watchtower| !watchtower.noise_key.valid || !watchtower.host.valid)
+        {
+            return false;
         }
 
         if let Some(stakeholder_config) = &mut config.stakeholder_config {
@@ -536,13 +713,14 @@ impl Step for DefineWatchtowers {
         true
     }
 
-    fn view(&mut self) -> Element<Message> {
+    fn view(&mut self, language: Language) -> Element<Message> {
         self.view.render(
+            language,
             self.watchtowers
                 .iter_mut()
                 .enumerate()
                 .map(|(i, xpub)| {
-                    xpub.view().map(move |msg| {
+                    xpub.view(language).map(move |msg| {
                         Message::DefineWatchtowers(message::DefineWatchtowers::EditWatchtower(
                             i, msg,
                         ))
@@ -0,0 +1,302 @@
+//! Developer command generating deterministic test vectors for the descriptor
+//! steps of the installer: for random sets of participants, the manager and
+//! stakeholder sides enter the same keys in different orders and must end
+//! with identical descriptors. Enabled by the `descriptor-vectors` feature:
+//!
+//! cargo run --features descriptor-vectors -- --descriptor-vectors <runs> [<seed>]
+
+use bitcoin::{
+    secp256k1::{All, Secp256k1},
+    util::bip32::{ExtendedPrivKey, ExtendedPubKey},
+    Network,
+};
+
+use super::{manager, stakeholder, Context, DefineCpfpDescriptor, Step};
+use crate::{
+    installer::message::{self, Action, Message, ParticipantXpub},
+    revaultd::config::{Config, ScriptsConfig},
+};
+
+/// Splitmix64 generator, a seed gives the same vectors on every machine.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number between min and max included.
+    fn range(&mut self, min: usize, max: usize) -> usize {
+        min + (self.next() % (max - min + 1) as u64) as usize
+    }
+
+    fn shuffled<T: Clone>(&mut self, items: &[T]) -> Vec<T> {
+        let mut items = items.to_vec();
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0, i));
+        }
+        items
+    }
+
+    fn xpub(&mut self, secp: &Secp256k1<All>) -> ExtendedPubKey {
+        let seed: Vec<u8> = (0..4).flat_map(|_| self.next().to_le_bytes()).collect();
+        let xpriv = ExtendedPrivKey::new_master(Network::Bitcoin, &seed).expect("valid seed");
+        ExtendedPubKey::from_private(secp, &xpriv)
+    }
+}
+
+/// Participants is a random set of keys of a Revault deployment.
+struct Participants {
+    stakeholders_xpubs: Vec<String>,
+    managers_xpubs: Vec<String>,
+    cosigners_keys: Vec<String>,
+    managers_threshold: usize,
+    spending_delay: u32,
+}
+
+impl Participants {
+    fn generate(rng: &mut Rng, secp: &Secp256k1<All>) -> Self {
+        let number_stakeholders = rng.range(2, 6);
+        let number_managers = rng.range(1, 5);
+        Self {
+            stakeholders_xpubs: (0..number_stakeholders)
+                .map(|_| rng.xpub(secp).to_string())
+                .collect(),
+            managers_xpubs: (0..number_managers)
+                .map(|_| rng.xpub(secp).to_string())
+                .collect(),
+            cosigners_keys: (0..number_stakeholders)
+                .map(|_| rng.xpub(secp).public_key.to_string())
+                .collect(),
+            managers_threshold: rng.range(1, number_managers),
+            spending_delay: rng.range(1, 144) as u32,
+        }
+    }
+}
+
+/// Runs the descriptor steps on `runs` random sets of participants and prints
+/// one vector per line. Fails on the first set for which the manager side and
+/// the stakeholder side disagree.
+pub fn run(runs: usize, seed: u64) -> Result<(), String> {
+    let secp = Secp256k1::new();
+    let mut rng = Rng(seed);
+    for i in 0..runs {
+        let participants = Participants::generate(&mut rng, &secp);
+        let scripts = check(&mut rng, &participants).map_err(|e| {
+            format!(
+                "run {} of seed {}: {}\n{}",
+                i,
+                seed,
+                e,
+                vector(&participants, None)
+            )
+        })?;
+        println!("{}", vector(&participants, Some(&scripts)));
+    }
+    Ok(())
+}
+
+fn vector(participants: &Participants, scripts: Option<&ScriptsConfig>) -> serde_json::Value {
+    serde_json::json!({
+        "stakeholders_xpubs": participants.stakeholders_xpubs,
+        "managers_xpubs": participants.managers_xpubs,
+        "cosigners_keys": participants.cosigners_keys,
+        "managers_threshold": participants.managers_threshold,
+        "spending_delay": participants.spending_delay,
+        "deposit_descriptor": scripts.map(|s| &s.deposit_descriptor),
+        "unvault_descriptor": scripts.map(|s| &s.unvault_descriptor),
+        "cpfp_descriptor": scripts.map(|s| &s.cpfp_descriptor),
+    })
+}
+
+/// Applies the manager and the stakeholder steps with the participants keys
+/// entered in random orders, returns the descriptors if both sides agree.
+fn check(rng: &mut Rng, participants: &Participants) -> Result<ScriptsConfig, String> {
+    let mut manager_config = Config::new();
+    let mut stakeholder_config = Config::new();
+
+    // deposit descriptor
+    let mut ctx = Context::new();
+    let mut manager_step = manager::DefineStakeholderXpubs::new();
+    add_stakeholders_xpubs(
+        &mut manager_step,
+        rng.shuffled(&participants.stakeholders_xpubs),
+    );
+    apply(
+        &mut manager_step,
+        &mut ctx,
+        &mut manager_config,
+        "manager deposit",
+    )?;
+
+    let mut stakeholders_xpubs = rng.shuffled(&participants.stakeholders_xpubs);
+    let our_xpub = stakeholders_xpubs.remove(rng.range(0, stakeholders_xpubs.len() - 1));
+    let mut stakeholder_step = stakeholder::DefineStakeholderXpubs::new();
+    add_stakeholders_xpubs(&mut stakeholder_step, stakeholders_xpubs);
+    stakeholder_step.update(Message::DefineStakeholderXpubs(
+        message::DefineStakeholderXpubs::OurXpubEdited(our_xpub),
+    ));
+    apply(
+        &mut stakeholder_step,
+        &mut ctx,
+        &mut stakeholder_config,
+        "stakeholder deposit",
+    )?;
+
+    // unvault descriptor
+    let mut manager_step = manager::DefineManagerXpubs::new();
+    manager_step.load_context(&Context {
+        number_cosigners: participants.cosigners_keys.len(),
+        stakeholders_xpubs: rng.shuffled(&participants.stakeholders_xpubs),
+        ..Context::new()
+    });
+    let mut managers_xpubs = rng.shuffled(&participants.managers_xpubs);
+    let our_xpub = managers_xpubs.remove(rng.range(0, managers_xpubs.len() - 1));
+    add_managers_xpubs(&mut manager_step, managers_xpubs);
+    manager_step.update(Message::DefineManagerXpubs(
+        message::DefineManagerXpubs::OurXpubEdited(our_xpub),
+    ));
+    set_cosigners_keys(
+        &mut manager_step,
+        rng.shuffled(&participants.cosigners_keys),
+    );
+    set_unvault_parameters(&mut manager_step, participants);
+    apply(
+        &mut manager_step,
+        &mut ctx,
+        &mut manager_config,
+        "manager unvault",
+    )?;
+
+    let mut stakeholder_step = stakeholder::DefineManagerXpubs::new();
+    stakeholder_step.load_context(&Context {
+        number_cosigners: participants.cosigners_keys.len(),
+        stakeholders_xpubs: rng.shuffled(&participants.stakeholders_xpubs),
+        ..Context::new()
+    });
+    add_managers_xpubs(
+        &mut stakeholder_step,
+        rng.shuffled(&participants.managers_xpubs),
+    );
+    set_cosigners_keys(
+        &mut stakeholder_step,
+        rng.shuffled(&participants.cosigners_keys),
+    );
+    set_unvault_parameters(&mut stakeholder_step, participants);
+    apply(
+        &mut stakeholder_step,
+        &mut ctx,
+        &mut stakeholder_config,
+        "stakeholder unvault",
+    )?;
+
+    // cpfp descriptor, entered by the stakeholder as well.
+    for config in [&mut manager_config, &mut stakeholder_config] {
+        let mut ctx = Context {
+            number_managers: participants.managers_xpubs.len(),
+            ..Context::new()
+        };
+        let mut step = DefineCpfpDescriptor::new();
+        step.load_context(&ctx);
+        for (i, xpub) in rng
+            .shuffled(&participants.managers_xpubs)
+            .into_iter()
+            .enumerate()
+        {
+            step.update(Message::DefineCpfpDescriptor(
+                message::DefineCpfpDescriptor::ManagerXpub(i, xpub),
+            ));
+        }
+        apply(&mut step, &mut ctx, config, "cpfp")?;
+    }
+
+    let (manager, stakeholder) = (
+        manager_config.scripts_config,
+        stakeholder_config.scripts_config,
+    );
+    for (name, m, s) in [
+        (
+            "deposit",
+            &manager.deposit_descriptor,
+            &stakeholder.deposit_descriptor,
+        ),
+        (
+            "unvault",
+            &manager.unvault_descriptor,
+            &stakeholder.unvault_descriptor,
+        ),
+        (
+            "cpfp",
+            &manager.cpfp_descriptor,
+            &stakeholder.cpfp_descriptor,
+        ),
+    ] {
+        if m != s {
+            return Err(format!(
+                "{} descriptors differ:\nmanager:     {}\nstakeholder: {}",
+                name, m, s
+            ));
+        }
+    }
+    Ok(manager)
+}
+
+fn apply(
+    step: &mut dyn Step,
+    ctx: &mut Context,
+    config: &mut Config,
+    name: &str,
+) -> Result<(), String> {
+    if step.apply(ctx, config) {
+        Ok(())
+    } else {
+        Err(format!("{} step rejected the participants", name))
+    }
+}
+
+fn add_stakeholders_xpubs(step: &mut dyn Step, xpubs: Vec<String>) {
+    for (i, xpub) in xpubs.into_iter().enumerate() {
+        step.update(Message::DefineStakeholderXpubs(
+            message::DefineStakeholderXpubs::AddXpub,
+        ));
+        step.update(Message::DefineStakeholderXpubs(
+            message::DefineStakeholderXpubs::StakeholderXpub(i, ParticipantXpub::XpubEdited(xpub)),
+        ));
+    }
+}
+
+fn add_managers_xpubs(step: &mut dyn Step, xpubs: Vec<String>) {
+    for (i, xpub) in xpubs.into_iter().enumerate() {
+        step.update(Message::DefineManagerXpubs(
+            message::DefineManagerXpubs::AddXpub,
+        ));
+        step.update(Message::DefineManagerXpubs(
+            message::DefineManagerXpubs::ManagerXpub(i, ParticipantXpub::XpubEdited(xpub)),
+        ));
+    }
+}
+
+fn set_cosigners_keys(step: &mut dyn Step, keys: Vec<String>) {
+    for (i, key) in keys.into_iter().enumerate() {
+        step.update(Message::DefineManagerXpubs(
+            message::DefineManagerXpubs::CosignerKey(i, key),
+        ));
+    }
+}
+
+/// Sets the threshold with the buttons of the step, starting from its
+/// default value, and types the delay.
+fn set_unvault_parameters(step: &mut dyn Step, participants: &Participants) {
+    for _ in 1..participants.managers_threshold {
+        step.update(Message::DefineManagerXpubs(
+            message::DefineManagerXpubs::ManagersThreshold(Action::Increment),
+        ));
+    }
+    step.update(Message::DefineManagerXpubs(
+        message::DefineManagerXpubs::SpendingDelayEdited(participants.spending_delay.to_string()),
+    ));
+}
@@ -1,20 +1,37 @@
+use std::path::PathBuf;
+
+use bitcoin::util::bip32::ExtendedPubKey;
 use iced::{
-    button::State as Button, pick_list, scrollable, text_input, Align, Column, Container, Element,
-    Length, Row,
+    button::State as Button, pick_list, scrollable, text_input, Align, Checkbox, Column, Container,
+    Element, Length, Row,
 };
 
 use crate::{
-    installer::message::{self, Message},
+    bitcoind::{BitcoindError, NodeReport, Severity},
+    conversion, descriptor,
+    i18n::Language,
+    installer::{
+        message::{self, Message},
+        step::Readiness,
+    },
+    keys, participant,
     revault::Role,
+    revaultd::config::ScriptsConfig,
     ui::{
         component::{
-            button, card, form, image::revault_colored_logo, scroll, text, ContainerBackgroundStyle,
+            badge, button, card, form, image::revault_colored_logo, scroll, text,
+            ContainerBackgroundStyle,
         },
         icon,
     },
 };
 
-pub fn welcome(install_button: &mut Button) -> Element<Message> {
+pub fn welcome<'a>(
+    language: Language,
+    language_list: &'a mut pick_list::State<Language>,
+    install_button: &'a mut Button,
+    import_button: &'a mut Button,
+) -> Element<'a, Message> {
     Container::new(Container::new(
         Column::new()
             .push(Container::new(
@@ -23,9 +40,32 @@ pub fn welcome(install_button: &mut Button) -> Element<Message> {
                     .height(Length::Fill),
             ))
             .push(
-                button::primary(install_button, button::button_content(None, "Install"))
-                    .on_press(Message::Next)
-                    .min_width(200),
+                button::primary(
+                    install_button,
+                    button::button_content(None, language.tr("Install")),
+                )
+                .on_press(Message::Next)
+                .min_width(200),
+            )
+            .push(
+                button::transparent(
+                    import_button,
+                    button::button_content(None, language.tr("Import existing configuration")),
+                )
+                .on_press(Message::ImportConfig(message::ImportConfig::Start))
+                .min_width(200),
+            )
+            .push(
+                Row::new()
+                    .push(text::simple(language.tr("Language")))
+                    .push(pick_list::PickList::new(
+                        language_list,
+                        &Language::ALL[..],
+                        Some(language),
+                        Message::Language,
+                    ))
+                    .spacing(10)
+                    .align_items(Align::Center),
             )
             .width(Length::Fill)
             .height(Length::Fill)
@@ -40,7 +80,75 @@ pub fn welcome(install_button: &mut Button) -> Element<Message> {
     .into()
 }
 
+pub fn import_config<'a>(
+    language: Language,
+    path: &form::Value<String>,
+    warning: Option<&String>,
+    path_input: &'a mut text_input::State,
+    import_button: &'a mut Button,
+    previous_button: &'a mut Button,
+    scroll: &'a mut scrollable::State,
+) -> Element<'a, Message> {
+    let mut import_button = button::primary(
+        import_button,
+        button::button_content(None, language.tr("Import")),
+    )
+    .min_width(200);
+    if !path.value.is_empty() {
+        import_button = import_button.on_press(Message::ImportConfig(
+            message::ImportConfig::Import(PathBuf::from(&path.value)),
+        ));
+    }
+    let mut col = Column::new()
+        .push(text::bold(text::simple(
+            language.tr("Path to the revaultd configuration file:"),
+        )))
+        .push(
+            form::Form::new(path_input, "revaultd.toml", path, |value| {
+                Message::ImportConfig(message::ImportConfig::PathEdited(value))
+            })
+            .warning(language.tr("Please enter the path of a valid revaultd configuration file"))
+            .size(15)
+            .padding(10)
+            .render(),
+        )
+        .push(text::small(language.tr(
+            "Every next step is filled with the values of the configuration, \
+            check and edit them before the installation.",
+        )))
+        .spacing(10);
+
+    if let Some(error) = warning {
+        col = col.push(card::alert_warning(Container::new(text::simple(
+            language.tr(error),
+        ))));
+    }
+
+    layout(
+        language,
+        scroll,
+        previous_button,
+        Column::new()
+            .push(
+                text::bold(text::simple(
+                    language.tr("Import an existing configuration"),
+                ))
+                .size(50),
+            )
+            .push(col)
+            .push(import_button)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(100)
+            .spacing(50)
+            .align_items(Align::Center)
+            .into(),
+    )
+}
+
 pub fn define_role<'a>(
+    language: Language,
+    expert: bool,
     stakeholder_button: &'a mut Button,
     manager_button: &'a mut Button,
     stakeholder_manager_button: &'a mut Button,
@@ -54,26 +162,31 @@ pub fn define_role<'a>(
                     .push(
                         button::white_card_button(
                             stakeholder_button,
-                            button::button_content(None, "Stakeholder"),
+                            button::button_content(None, language.tr("Stakeholder")),
                         )
                         .on_press(Message::Role(&Role::STAKEHOLDER_ONLY)),
                     )
                     .push(
                         button::white_card_button(
                             stakeholder_manager_button,
-                            button::button_content(None, "Stakeholder & Manager"),
+                            button::button_content(None, language.tr("Stakeholder & Manager")),
                         )
                         .on_press(Message::Role(&Role::STAKEHOLDER_AND_MANAGER)),
                     )
                     .push(
                         button::white_card_button(
                             manager_button,
-                            button::button_content(None, "Manager"),
+                            button::button_content(None, language.tr("Manager")),
                         )
                         .on_press(Message::Role(&Role::MANAGER_ONLY)),
                     )
                     .spacing(20),
             )
+            .push(Checkbox::new(
+                expert,
+                language.tr("Expert mode: paste the descriptors generated with external tooling"),
+                Message::ExpertMode,
+            ))
             .width(Length::Fill)
             .height(Length::Fill)
             .padding(100)
@@ -84,6 +197,7 @@ pub fn define_role<'a>(
 }
 
 pub fn participant_xpub<'a>(
+    language: Language,
     xpub: &form::Value<String>,
     xpub_input: &'a mut text_input::State,
     delete_button: &'a mut Button,
@@ -95,11 +209,11 @@ pub fn participant_xpub<'a>(
                     .push(
                         form::Form::new(
                             xpub_input,
-                            "Xpub",
+                            language.tr("Xpub"),
                             xpub,
                             message::ParticipantXpub::XpubEdited,
                         )
-                        .warning("Please enter a valid xpub")
+                        .warning(language.tr("Please enter a valid xpub"))
                         .size(15)
                         .padding(10)
                         .render(),
@@ -117,14 +231,15 @@ pub fn participant_xpub<'a>(
 }
 
 pub fn required_xpub<'a>(
+    language: Language,
     xpub: &form::Value<String>,
     xpub_input: &'a mut text_input::State,
 ) -> Element<'a, String> {
     Container::new(
         Column::new()
             .push(
-                form::Form::new(xpub_input, "Xpub", xpub, |msg| msg)
-                    .warning("Please enter a valid xpub")
+                form::Form::new(xpub_input, language.tr("Xpub"), xpub, |msg| msg)
+                    .warning(language.tr("Please enter a valid xpub"))
                     .size(15)
                     .padding(10)
                     .render(),
@@ -135,6 +250,7 @@ pub fn required_xpub<'a>(
 }
 
 pub fn cosigner_key<'a>(
+    language: Language,
     key: &form::Value<String>,
     key_input: &'a mut text_input::State,
 ) -> Element<'a, String> {
@@ -143,8 +259,8 @@ pub fn cosigner_key<'a>(
             .push(
                 Row::new()
                     .push(
-                        form::Form::new(key_input, "Key", key, |msg| msg)
-                            .warning("Please enter a valid key")
+                        form::Form::new(key_input, language.tr("Key"), key, |msg| msg)
+                            .warning(language.tr("Please enter a valid key"))
                             .size(15)
                             .padding(10)
                             .render(),
@@ -158,7 +274,9 @@ pub fn cosigner_key<'a>(
 }
 
 pub struct DefinePrivateNoiseKey {
-    key_input: text_input::State,
+    path_input: text_input::State,
+    generate_button: Button,
+    import_button: Button,
     next_button: Button,
     previous_button: Button,
     scroll: scrollable::State,
@@ -167,32 +285,98 @@ pub struct DefinePrivateNoiseKey {
 impl DefinePrivateNoiseKey {
     pub fn new() -> Self {
         Self {
-            key_input: text_input::State::new(),
+            path_input: text_input::State::new(),
+            generate_button: Button::new(),
+            import_button: Button::new(),
             next_button: Button::new(),
             previous_button: Button::new(),
             scroll: scrollable::State::new(),
         }
     }
 
-    pub fn render<'a>(&'a mut self, key: &form::Value<String>) -> Element<Message> {
+    pub fn render<'a>(
+        &'a mut self,
+        language: Language,
+        public_key: Option<&str>,
+        path: &form::Value<String>,
+        warning: Option<&String>,
+    ) -> Element<'a, Message> {
+        let mut import_button = button::transparent(
+            &mut self.import_button,
+            button::button_content(None, language.tr("Import")),
+        );
+        if !path.value.is_empty() {
+            import_button =
+                import_button.on_press(Message::DefineNoiseKey(message::DefineNoiseKey::Import));
+        }
+        let mut col = Column::new()
+            .push(text::simple(language.tr(
+                "The noise key authenticates your daemon to the coordinator and to the \
+                other servers of the deployment. It is written to the noise_secret file \
+                of the datadir, only readable by your user.",
+            )))
+            .push(
+                button::primary(
+                    &mut self.generate_button,
+                    button::button_content(None, language.tr("Generate a new key")),
+                )
+                .on_press(Message::DefineNoiseKey(message::DefineNoiseKey::Generate))
+                .min_width(200),
+            )
+            .push(text::bold(text::simple(
+                language.tr("Or import an existing noise_secret file:"),
+            )))
+            .push(
+                Row::new()
+                    .push(
+                        form::Form::new(&mut self.path_input, "noise_secret", path, |value| {
+                            Message::DefineNoiseKey(message::DefineNoiseKey::PathEdited(value))
+                        })
+                        .warning(
+                            language.tr("Please enter the path of a 32 bytes noise_secret file"),
+                        )
+                        .size(15)
+                        .padding(10)
+                        .render(),
+                    )
+                    .push(import_button)
+                    .spacing(10)
+                    .align_items(Align::Center),
+            )
+            .spacing(20);
+
+        if let Some(public_key) = public_key {
+            col = col.push(card::white(Container::new(
+                Column::new()
+                    .push(text::bold(text::simple(language.tr("Public key:"))))
+                    .push(text::small(public_key))
+                    .push(text::small(language.tr(
+                        "Share it with the coordinator and the servers operators.",
+                    )))
+                    .spacing(5),
+            )));
+        }
+
+        if let Some(error) = warning {
+            col = col.push(card::alert_warning(Container::new(text::simple(
+                language.tr(error),
+            ))));
+        }
+
         layout(
+            language,
             &mut self.scroll,
             &mut self.previous_button,
             Column::new()
-                .push(text::bold(text::simple("Fill your private noise key:")).size(50))
-                .push(
-                    Column::new().spacing(10).push(
-                        form::Form::new(&mut self.key_input, "", key, Message::PrivateNoiseKey)
-                            .warning("Noise key must be 32 bytes long")
-                            .size(15)
-                            .padding(10)
-                            .render(),
-                    ),
-                )
+                .push(text::bold(text::simple(language.tr("Set your noise key"))).size(50))
+                .push(col)
                 .push(
-                    button::primary(&mut self.next_button, button::button_content(None, "Next"))
-                        .on_press(Message::Next)
-                        .min_width(200),
+                    button::primary(
+                        &mut self.next_button,
+                        button::button_content(None, language.tr("Next")),
+                    )
+                    .on_press(Message::Next)
+                    .min_width(200),
                 )
                 .width(Length::Fill)
                 .height(Length::Fill)
@@ -204,11 +388,134 @@ impl DefinePrivateNoiseKey {
     }
 }
 
+/// Lists what remains to enter before the step can be applied.
+fn readiness<'a>(language: Language, readiness: &Readiness) -> Element<'a, Message> {
+    Column::with_children(
+        readiness
+            .items()
+            .iter()
+            .map(|item| {
+                if item.done {
+                    text::success(text::small(&item.text(language))).into()
+                } else {
+                    text::small(&item.text(language)).into()
+                }
+            })
+            .collect(),
+    )
+    .spacing(5)
+    .into()
+}
+
+/// Card of the mnemonic generated by the installer, its xpub can be used
+/// once the user confirmed the backup of the words.
+#[allow(clippy::too_many_arguments)]
+fn key_setup_card<'a>(
+    language: Language,
+    network_input: &'a mut pick_list::State<bitcoin::Network>,
+    use_button: &'a mut Button,
+    discard_button: &'a mut Button,
+    mnemonic: &keys::Mnemonic,
+    network: bitcoin::Network,
+    xpub: &ExtendedPubKey,
+    backed_up: bool,
+) -> Container<'a, Message> {
+    let mut words = Column::new().spacing(5);
+    let mut row = Row::new().spacing(10);
+    for (i, word) in mnemonic.words().enumerate() {
+        row = row.push(
+            Container::new(text::simple(&format!("{}. {}", i + 1, word))).width(Length::Units(150)),
+        );
+        if (i + 1) % 4 == 0 {
+            words = words.push(row);
+            row = Row::new().spacing(10);
+        }
+    }
+    words = words.push(row);
+
+    let use_button = if backed_up {
+        button::primary(
+            use_button,
+            button::button_content(None, language.tr("Use this key")),
+        )
+        .on_press(Message::DefineStakeholderXpubs(
+            message::DefineStakeholderXpubs::UseGeneratedKey,
+        ))
+    } else {
+        button::primary_disable(
+            use_button,
+            button::button_content(None, language.tr("Use this key")),
+        )
+    };
+
+    card::alert_warning(Container::new(
+        Column::new()
+            .push(text::bold(text::simple(language.tr(
+                "This key is less secure than a key kept by a signing device",
+            ))))
+            .push(text::small(language.tr(
+                "The mnemonic below is the only backup of your key: write it down on \
+                paper and keep it offline. The GUI does not store it, anyone reading it \
+                can sign for you.",
+            )))
+            .push(
+                Row::new()
+                    .push(text::simple(language.tr("Network of the key:")))
+                    .push(pick_list::PickList::new(
+                        network_input,
+                        &NETWORKS[..],
+                        Some(network),
+                        |network| {
+                            Message::DefineStakeholderXpubs(
+                                message::DefineStakeholderXpubs::KeyNetworkEdited(network),
+                            )
+                        },
+                    ))
+                    .spacing(10)
+                    .align_items(Align::Center),
+            )
+            .push(words)
+            .push(text::small(&language.trf(
+                "Account {} xpub: {}",
+                &[&keys::account_path(network), xpub],
+            )))
+            .push(Checkbox::new(
+                backed_up,
+                language.tr("I wrote down the mnemonic"),
+                |checked| {
+                    Message::DefineStakeholderXpubs(message::DefineStakeholderXpubs::KeyBackedUp(
+                        checked,
+                    ))
+                },
+            ))
+            .push(
+                Row::new()
+                    .push(use_button)
+                    .push(
+                        button::cancel(
+                            discard_button,
+                            button::button_content(None, language.tr("Discard")),
+                        )
+                        .on_press(Message::DefineStakeholderXpubs(
+                            message::DefineStakeholderXpubs::DiscardGeneratedKey,
+                        )),
+                    )
+                    .spacing(10),
+            )
+            .spacing(20),
+    ))
+    .width(Length::Fill)
+}
+
 pub struct DefineStakeholderXpubsAsStakeholder {
     our_xpub_input: text_input::State,
     previous_button: Button,
     save_button: Button,
     add_xpub_button: Button,
+    generate_key_button: Button,
+    use_key_button: Button,
+    discard_key_button: Button,
+    key_network_input: pick_list::State<bitcoin::Network>,
     scroll: scrollable::State,
 }
 
@@ -217,6 +524,10 @@ impl DefineStakeholderXpubsAsStakeholder {
         Self {
             our_xpub_input: text_input::State::new(),
             add_xpub_button: Button::new(),
+            generate_key_button: Button::new(),
+            use_key_button: Button::new(),
+            discard_key_button: Button::new(),
+            key_network_input: pick_list::State::default(),
             scroll: scrollable::State::new(),
             previous_button: Button::new(),
             save_button: Button::new(),
@@ -224,19 +535,45 @@ impl DefineStakeholderXpubsAsStakeholder {
     }
     pub fn render<'a>(
         &'a mut self,
+        language: Language,
         our_xpub: &form::Value<String>,
         other_xpubs: Vec<Element<'a, Message>>,
+        summary: &Readiness,
         warning: Option<&String>,
+        key_setup: Option<(&keys::Mnemonic, bitcoin::Network, &ExtendedPubKey, bool)>,
     ) -> Element<'a, Message> {
+        let key_setup = match key_setup {
+            Some((mnemonic, network, xpub, backed_up)) => key_setup_card(
+                language,
+                &mut self.key_network_input,
+                &mut self.use_key_button,
+                &mut self.discard_key_button,
+                mnemonic,
+                network,
+                xpub,
+                backed_up,
+            ),
+            None => Container::new(
+                button::white_card_button(
+                    &mut self.generate_key_button,
+                    button::button_content(None, language.tr("No signing device? Generate a key")),
+                )
+                .on_press(Message::DefineStakeholderXpubs(
+                    message::DefineStakeholderXpubs::GenerateKey,
+                )),
+            ),
+        };
         let mut content = Column::new()
-            .push(text::bold(text::simple("Stakeholders information")).size(50))
+            .push(text::bold(text::simple(language.tr("Stakeholders information"))).size(50))
             .push(
                 Column::new()
-                    .push(text::bold(text::simple("Your stakeholder xpub:")))
+                    .push(text::bold(text::simple(
+                        language.tr("Your stakeholder xpub:"),
+                    )))
                     .push(
                         form::Form::new(
                             &mut self.our_xpub_input,
-                            "Your stakeholder xpub",
+                            language.tr("Your stakeholder xpub"),
                             our_xpub,
                             |msg| {
                                 Message::DefineStakeholderXpubs(
@@ -244,23 +581,29 @@ impl DefineStakeholderXpubsAsStakeholder {
                                 )
                             },
                         )
-                        .warning("Please enter a valid xpub")
+                        .warning(language.tr("Please enter a valid xpub"))
                         .size(15)
                         .padding(10)
                         .render(),
                     )
+                    .push(key_setup)
                     .spacing(10),
             )
             .push(
                 Column::new()
                     .spacing(10)
-                    .push(text::bold(text::simple("Other stakeholders xpubs:")))
+                    .push(text::bold(text::simple(
+                        language.tr("Other stakeholders xpubs:"),
+                    )))
                     .push(Column::with_children(other_xpubs).spacing(10))
                     .push(
                         Container::new(
                             button::white_card_button(
                                 &mut self.add_xpub_button,
-                                button::button_content(Some(icon::plus_icon()), "Add stakeholder"),
+                                button::button_content(
+                                    Some(icon::plus_icon()),
+                                    language.tr("Add stakeholder"),
+                                ),
                             )
                             .on_press(Message::DefineStakeholderXpubs(
                                 message::DefineStakeholderXpubs::AddXpub,
@@ -274,7 +617,7 @@ impl DefineStakeholderXpubsAsStakeholder {
                     .push(
                         button::primary(
                             &mut self.save_button,
-                            button::button_content(None, "Next"),
+                            button::button_content(None, language.tr("Next")),
                         )
                         .on_press(Message::Next)
                         .min_width(200),
@@ -283,11 +626,15 @@ impl DefineStakeholderXpubsAsStakeholder {
                     .spacing(20),
             );
 
+        content = content.push(readiness(language, summary));
         if let Some(error) = warning {
-            content = content.push(card::alert_warning(Container::new(text::simple(&error))));
+            content = content.push(card::alert_warning(Container::new(text::simple(
+                language.tr(&error),
+            ))));
         }
 
         layout(
+            language,
             &mut self.scroll,
             &mut self.previous_button,
             content
@@ -301,36 +648,46 @@ impl DefineStakeholderXpubsAsStakeholder {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn define_stakeholder_xpubs_as_manager_only<'a>(
+    language: Language,
     add_xpub_button: &'a mut Button,
     stakeholder_xpubs: Vec<Element<'a, Message>>,
     scroll: &'a mut scrollable::State,
     previous_button: &'a mut Button,
     save_button: &'a mut Button,
+    summary: &Readiness,
     warning: Option<&String>,
 ) -> Element<'a, Message> {
     let mut row = Row::new().align_items(Align::Center).spacing(20);
     if stakeholder_xpubs.is_empty() {
         row = row.push(
-            button::primary(save_button, button::button_content(None, "Next")).min_width(200),
+            button::primary(
+                save_button,
+                button::button_content(None, language.tr("Next")),
+            )
+            .min_width(200),
         );
     } else {
         row = row.push(
-            button::primary(save_button, button::button_content(None, "Next"))
-                .on_press(Message::Next)
-                .min_width(200),
+            button::primary(
+                save_button,
+                button::button_content(None, language.tr("Next")),
+            )
+            .on_press(Message::Next)
+            .min_width(200),
         );
     }
 
     let mut content = Column::new()
         .spacing(10)
-        .push(text::bold(text::simple("Stakeholders xpubs:")))
+        .push(text::bold(text::simple(language.tr("Stakeholders xpubs:"))))
         .push(Column::with_children(stakeholder_xpubs).spacing(10))
         .push(
             Container::new(
                 button::white_card_button(
                     add_xpub_button,
-                    button::button_content(Some(icon::plus_icon()), "Add stakeholder"),
+                    button::button_content(Some(icon::plus_icon()), language.tr("Add stakeholder")),
                 )
                 .on_press(Message::DefineStakeholderXpubs(
                     message::DefineStakeholderXpubs::AddXpub,
@@ -339,15 +696,19 @@ pub fn define_stakeholder_xpubs_as_manager_only<'a>(
             .width(Length::Fill),
         );
 
+    content = content.push(readiness(language, summary));
     if let Some(error) = warning {
-        content = content.push(card::alert_warning(Container::new(text::simple(&error))));
+        content = content.push(card::alert_warning(Container::new(text::simple(
+            language.tr(&error),
+        ))));
     }
 
     layout(
+        language,
         scroll,
         previous_button,
         Column::new()
-            .push(text::bold(text::simple("Stakeholders information")).size(50))
+            .push(text::bold(text::simple(language.tr("Stakeholders information"))).size(50))
             .push(content)
             .push(row)
             .width(Length::Fill)
@@ -372,9 +733,13 @@ impl ManagersThreshold {
         }
     }
 
-    pub fn render(&mut self, managers_threshold: &form::Value<usize>) -> Container<Message> {
+    pub fn render(
+        &mut self,
+        language: Language,
+        managers_threshold: &form::Value<usize>,
+    ) -> Container<Message> {
         let mut col = Column::new()
-            .push(text::bold(text::simple("Managers threshold:")))
+            .push(text::bold(text::simple(language.tr("Managers threshold:"))))
             .push(
                 Row::new()
                     .push(text::simple(&format!("{}", managers_threshold.value)).size(50))
@@ -416,70 +781,154 @@ impl ManagersThreshold {
 
         if !managers_threshold.valid {
             col = col.push(card::alert_warning(Container::new(text::small(
-                "Impossible threshold",
+                language.tr("Impossible threshold"),
             ))))
         }
         Container::new(col)
     }
 }
 
+/// Presets of the spending delay, in blocks of 10 minutes.
+const SPENDING_DELAY_PRESETS: [(&str, u32); 4] =
+    [("6h", 36), ("24h", 144), ("48h", 288), ("1 week", 1008)];
+
+/// Spending delay under which the watchtowers may not have the time to
+/// react to an unvault: 2 hours.
+const MIN_REACTION_BLOCKS: u32 = 12;
+
+/// Largest relative timelock in blocks a CSV can encode.
+pub const MAX_SPENDING_DELAY: u32 = revault_tx::scripts::SEQUENCE_LOCKTIME_MASK;
+
+pub const BLOCKS_PER_HOUR: u32 = 6;
+
 pub struct SpendingDelay {
-    increment_button: Button,
-    decrement_button: Button,
+    blocks_input: text_input::State,
+    hours_input: text_input::State,
+    preset_buttons: [Button; 4],
 }
 
 impl SpendingDelay {
     pub fn new() -> Self {
         Self {
-            increment_button: Button::new(),
-            decrement_button: Button::new(),
+            blocks_input: text_input::State::new(),
+            hours_input: text_input::State::new(),
+            preset_buttons: Default::default(),
         }
     }
 
-    pub fn render(&mut self, spending_delay: &form::Value<u32>) -> Container<Message> {
+    /// The delay is typed in blocks or in hours, a delay of hours which
+    /// are not whole is displayed rounded to the nearest hour.
+    pub fn render(
+        &mut self,
+        language: Language,
+        spending_delay: &form::Value<u32>,
+    ) -> Container<Message> {
+        let blocks = form::Value {
+            value: match spending_delay.value {
+                0 => String::new(),
+                blocks => blocks.to_string(),
+            },
+            valid: spending_delay.valid,
+        };
+        let hours = form::Value {
+            value: match spending_delay.value {
+                0 => String::new(),
+                blocks => ((blocks + BLOCKS_PER_HOUR / 2) / BLOCKS_PER_HOUR).to_string(),
+            },
+            valid: spending_delay.valid,
+        };
         let mut col = Column::new()
-            .push(text::bold(text::simple("Spending delay in blocks:")))
+            .push(text::bold(text::simple(language.tr("Spending delay:"))))
             .push(
                 Row::new()
-                    .push(text::simple(&format!("{}", spending_delay.value)).size(50))
                     .push(
                         Column::new()
                             .push(
-                                button::transparent(
-                                    &mut self.increment_button,
-                                    Container::new(text::simple("+")),
+                                form::Form::new(
+                                    &mut self.blocks_input,
+                                    language.tr("Blocks"),
+                                    &blocks,
+                                    |msg| {
+                                        Message::DefineManagerXpubs(
+                                            message::DefineManagerXpubs::SpendingDelayEdited(msg),
+                                        )
+                                    },
                                 )
-                                .on_press(
-                                    Message::DefineManagerXpubs(
-                                        message::DefineManagerXpubs::SpendingDelay(
-                                            message::Action::Increment,
-                                        ),
-                                    ),
-                                ),
+                                .size(20)
+                                .padding(10)
+                                .render(),
                             )
+                            .push(text::small(language.tr("blocks")))
+                            .width(Length::Units(120)),
+                    )
+                    .push(
+                        Column::new()
                             .push(
-                                button::transparent(
-                                    &mut self.decrement_button,
-                                    Container::new(text::simple("-")),
+                                form::Form::new(
+                                    &mut self.hours_input,
+                                    language.tr("Hours"),
+                                    &hours,
+                                    |msg| {
+                                        Message::DefineManagerXpubs(
+                                            message::DefineManagerXpubs::SpendingDelayHoursEdited(
+                                                msg,
+                                            ),
+                                        )
+                                    },
                                 )
-                                .on_press(
-                                    Message::DefineManagerXpubs(
-                                        message::DefineManagerXpubs::SpendingDelay(
-                                            message::Action::Decrement,
-                                        ),
-                                    ),
-                                ),
+                                .size(20)
+                                .padding(10)
+                                .render(),
                             )
-                            .align_items(Align::Center),
+                            .push(text::small(language.tr("hours")))
+                            .width(Length::Units(120)),
                     )
-                    .align_items(Align::Center)
                     .spacing(20),
             )
+            .push(text::small(&conversion::blocks_duration(
+                spending_delay.value,
+                language,
+            )))
             .align_items(Align::Center)
             .spacing(10);
-        if !spending_delay.valid {
+        let mut presets = Row::new().spacing(10);
+        for (state, (label, blocks)) in self
+            .preset_buttons
+            .iter_mut()
+            .zip(SPENDING_DELAY_PRESETS.iter())
+        {
+            presets = presets.push(
+                button::transparent(state, Container::new(text::small(language.tr(label))))
+                    .on_press(Message::DefineManagerXpubs(
+                        message::DefineManagerXpubs::SpendingDelayPreset(*blocks),
+                    )),
+            );
+        }
+        col = col.push(presets);
+        if spending_delay.value > MAX_SPENDING_DELAY {
+            col = col.push(card::alert_warning(Container::new(text::small(
+                &language.trf(
+                    "Spending delay cannot exceed {} blocks ({})",
+                    &[
+                        &MAX_SPENDING_DELAY,
+                        &conversion::blocks_duration(MAX_SPENDING_DELAY, language),
+                    ],
+                ),
+            ))))
+        } else if !spending_delay.valid {
+            col = col.push(card::alert_warning(Container::new(text::small(
+                language.tr("Spending delay cannot be equal to zero"),
+            ))))
+        } else if spending_delay.value > 0 && spending_delay.value < MIN_REACTION_BLOCKS {
             col = col.push(card::alert_warning(Container::new(text::small(
-                "Spending delay cannot be equal to zero",
+                &language.trf(
+                    "A spending delay under {} blocks ({}) leaves the watchtowers \
+                little time to react to an unvault",
+                    &[
+                        &MIN_REACTION_BLOCKS,
+                        &conversion::blocks_duration(MIN_REACTION_BLOCKS, language),
+                    ],
+                ),
             ))))
         }
         Container::new(col)
@@ -509,27 +958,30 @@ impl DefineManagerXpubsAsManager {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn render<'a>(
         &'a mut self,
+        language: Language,
         managers_threshold: &form::Value<usize>,
         spending_delay: &form::Value<u32>,
         our_xpub: &form::Value<String>,
         other_xpubs: Vec<Element<'a, Message>>,
         cosigners: Vec<Element<'a, Message>>,
+        summary: &Readiness,
         warning: Option<&String>,
     ) -> Element<'a, Message> {
         let manager_xpub_col = Column::new()
-            .push(text::bold(text::simple("Your manager xpub:")))
+            .push(text::bold(text::simple(language.tr("Your manager xpub:"))))
             .push(
                 form::Form::new(
                     &mut self.our_xpub_input,
-                    "Your manager xpub",
+                    language.tr("Your manager xpub"),
                     our_xpub,
                     |msg| {
                         Message::DefineManagerXpubs(message::DefineManagerXpubs::OurXpubEdited(msg))
                     },
                 )
-                .warning("Please enter a valid xpub")
+                .warning(language.tr("Please enter a valid xpub"))
                 .size(15)
                 .padding(10)
                 .render(),
@@ -537,16 +989,18 @@ impl DefineManagerXpubsAsManager {
             .spacing(10);
 
         let mut content = Column::new()
-            .push(text::bold(text::simple("Define managers")).size(50))
+            .push(text::bold(text::simple(language.tr("Define managers"))).size(50))
             .push(
                 Row::new()
                     .push(
-                        Container::new(self.managers_threshold.render(managers_threshold))
-                            .width(Length::FillPortion(1))
-                            .align_x(Align::Center),
+                        Container::new(
+                            self.managers_threshold.render(language, managers_threshold),
+                        )
+                        .width(Length::FillPortion(1))
+                        .align_x(Align::Center),
                     )
                     .push(
-                        Container::new(self.spending_delay.render(spending_delay))
+                        Container::new(self.spending_delay.render(language, spending_delay))
                             .width(Length::FillPortion(1))
                             .align_x(Align::Center),
                     )
@@ -555,13 +1009,18 @@ impl DefineManagerXpubsAsManager {
             .push(manager_xpub_col)
             .push(
                 Column::new()
-                    .push(text::bold(text::simple("Other Managers xpubs:")))
+                    .push(text::bold(text::simple(
+                        language.tr("Other Managers xpubs:"),
+                    )))
                     .push(Column::with_children(other_xpubs).spacing(10))
                     .push(
                         Container::new(
                             button::white_card_button(
                                 &mut self.add_xpub_button,
-                                button::button_content(Some(icon::plus_icon()), "Add manager"),
+                                button::button_content(
+                                    Some(icon::plus_icon()),
+                                    language.tr("Add manager"),
+                                ),
                             )
                             .on_press(Message::DefineManagerXpubs(
                                 message::DefineManagerXpubs::AddXpub,
@@ -573,7 +1032,9 @@ impl DefineManagerXpubsAsManager {
             )
             .push(
                 Column::new()
-                    .push(text::bold(text::simple("Cosigning servers keys:")))
+                    .push(text::bold(text::simple(
+                        language.tr("Cosigning servers keys:"),
+                    )))
                     .push(Column::with_children(cosigners).spacing(10))
                     .spacing(10),
             )
@@ -582,7 +1043,7 @@ impl DefineManagerXpubsAsManager {
                     .push(
                         button::primary(
                             &mut self.save_button,
-                            button::button_content(None, "Next"),
+                            button::button_content(None, language.tr("Next")),
                         )
                         .on_press(Message::Next)
                         .min_width(200),
@@ -591,11 +1052,15 @@ impl DefineManagerXpubsAsManager {
                     .spacing(20),
             );
 
+        content = content.push(readiness(language, summary));
         if let Some(error) = warning {
-            content = content.push(card::alert_warning(Container::new(text::simple(&error))));
+            content = content.push(card::alert_warning(Container::new(text::simple(
+                language.tr(&error),
+            ))));
         }
 
         layout(
+            language,
             &mut self.scroll,
             &mut self.previous_button,
             content
@@ -629,25 +1094,31 @@ impl DefineManagerXpubsAsStakeholderOnly {
             add_xpub_button: Button::new(),
         }
     }
+    #[allow(clippy::too_many_arguments)]
     pub fn render<'a>(
         &'a mut self,
+        language: Language,
         managers_threshold: &form::Value<usize>,
         spending_delay: &form::Value<u32>,
         manager_xpubs: Vec<Element<'a, Message>>,
         cosigners: Vec<Element<'a, Message>>,
+        summary: &Readiness,
         warning: Option<&String>,
     ) -> Element<'a, Message> {
         let mut row = Row::new().align_items(Align::Center).spacing(20);
         if manager_xpubs.is_empty() {
             row = row.push(
-                button::primary(&mut self.save_button, button::button_content(None, "Next"))
-                    .min_width(200),
+                button::primary(
+                    &mut self.save_button,
+                    button::button_content(None, language.tr("Next")),
+                )
+                .min_width(200),
             );
         } else {
             row = row.push(
                 button::primary(
                     &mut self.save_button,
-                    button::button_content(None, "Next").width(Length::Fill),
+                    button::button_content(None, language.tr("Next")).width(Length::Fill),
                 )
                 .on_press(Message::Next)
                 .min_width(200),
@@ -655,16 +1126,18 @@ impl DefineManagerXpubsAsStakeholderOnly {
         }
 
         let mut content = Column::new()
-            .push(text::bold(text::simple("Fund management configuration")).size(50))
+            .push(text::bold(text::simple(language.tr("Fund management configuration"))).size(50))
             .push(
                 Row::new()
                     .push(
-                        Container::new(self.managers_threshold.render(managers_threshold))
-                            .width(Length::FillPortion(1))
-                            .align_x(Align::Center),
+                        Container::new(
+                            self.managers_threshold.render(language, managers_threshold),
+                        )
+                        .width(Length::FillPortion(1))
+                        .align_x(Align::Center),
                     )
                     .push(
-                        Container::new(self.spending_delay.render(spending_delay))
+                        Container::new(self.spending_delay.render(language, spending_delay))
                             .width(Length::FillPortion(1))
                             .align_x(Align::Center),
                     )
@@ -673,13 +1146,16 @@ impl DefineManagerXpubsAsStakeholderOnly {
             .push(
                 Column::new()
                     .spacing(10)
-                    .push(text::bold(text::simple("Managers xpubs:")))
+                    .push(text::bold(text::simple(language.tr("Managers xpubs:"))))
                     .push(Column::with_children(manager_xpubs).spacing(10))
                     .push(
                         Container::new(
                             button::white_card_button(
                                 &mut self.add_xpub_button,
-                                button::button_content(Some(icon::plus_icon()), "Add manager"),
+                                button::button_content(
+                                    Some(icon::plus_icon()),
+                                    language.tr("Add manager"),
+                                ),
                             )
                             .on_press(Message::DefineManagerXpubs(
                                 message::DefineManagerXpubs::AddXpub,
@@ -691,7 +1167,9 @@ impl DefineManagerXpubsAsStakeholderOnly {
             .push(
                 Column::new()
                     .spacing(10)
-                    .push(text::bold(text::simple("Cosigning servers keys:")))
+                    .push(text::bold(text::simple(
+                        language.tr("Cosigning servers keys:"),
+                    )))
                     .push(Column::with_children(cosigners).spacing(10)),
             )
             .width(Length::Fill)
@@ -700,11 +1178,15 @@ impl DefineManagerXpubsAsStakeholderOnly {
             .spacing(50)
             .align_items(Align::Center);
 
+        content = content.push(readiness(language, summary));
         if let Some(error) = warning {
-            content = content.push(card::alert_warning(Container::new(text::simple(&error))));
+            content = content.push(card::alert_warning(Container::new(text::simple(
+                language.tr(&error),
+            ))));
         }
 
         layout(
+            language,
             &mut self.scroll,
             &mut self.previous_button,
             content.push(row).into(),
@@ -729,37 +1211,54 @@ impl DefineCpfpDescriptorView {
 
     pub fn render<'a>(
         &'a mut self,
+        language: Language,
         manager_xpubs: Vec<Element<'a, Message>>,
+        summary: &Readiness,
         warning: Option<&String>,
     ) -> Element<'a, Message> {
         let mut row = Row::new().align_items(Align::Center).spacing(20);
         if manager_xpubs.is_empty() {
             row = row.push(
-                button::primary(&mut self.save_button, button::button_content(None, "Next"))
-                    .min_width(200),
+                button::primary(
+                    &mut self.save_button,
+                    button::button_content(None, language.tr("Next")),
+                )
+                .min_width(200),
             );
         } else {
             row = row.push(
-                button::primary(&mut self.save_button, button::button_content(None, "Next"))
-                    .on_press(Message::Next)
-                    .min_width(200),
+                button::primary(
+                    &mut self.save_button,
+                    button::button_content(None, language.tr("Next")),
+                )
+                .on_press(Message::Next)
+                .min_width(200),
             );
         }
 
         let mut content = Column::new()
             .spacing(10)
-            .push(text::bold(text::simple("Managers CPFP xpubs:")))
+            .push(text::bold(text::simple(
+                language.tr("Managers CPFP xpubs:"),
+            )))
             .push(Column::with_children(manager_xpubs).spacing(10));
 
+        content = content.push(readiness(language, summary));
         if let Some(error) = warning {
-            content = content.push(card::alert_warning(Container::new(text::simple(&error))));
+            content = content.push(card::alert_warning(Container::new(text::simple(
+                language.tr(&error),
+            ))));
         }
 
         layout(
+            language,
             &mut self.scroll,
             &mut self.previous_button,
             Column::new()
-                .push(text::bold(text::simple("Fill in the managers CPFP xpubs")).size(50))
+                .push(
+                    text::bold(text::simple(language.tr("Fill in the managers CPFP xpubs")))
+                        .size(50),
+                )
                 .push(content)
                 .push(row)
                 .width(Length::Fill)
@@ -772,6 +1271,162 @@ impl DefineCpfpDescriptorView {
     }
 }
 
+pub struct DefineDescriptors {
+    deposit_input: text_input::State,
+    unvault_input: text_input::State,
+    cpfp_input: text_input::State,
+    stakeholder_xpub_input: text_input::State,
+    manager_xpub_input: text_input::State,
+    scroll: scrollable::State,
+    previous_button: Button,
+    save_button: Button,
+}
+
+impl DefineDescriptors {
+    pub fn new() -> Self {
+        Self {
+            deposit_input: text_input::State::new(),
+            unvault_input: text_input::State::new(),
+            cpfp_input: text_input::State::new(),
+            stakeholder_xpub_input: text_input::State::new(),
+            manager_xpub_input: text_input::State::new(),
+            scroll: scrollable::State::new(),
+            previous_button: Button::new(),
+            save_button: Button::new(),
+        }
+    }
+
+    /// The xpubs are asked for the roles of the user only.
+    pub fn render<'a>(
+        &'a mut self,
+        language: Language,
+        (deposit, unvault, cpfp): (
+            &form::Value<String>,
+            &form::Value<String>,
+            &form::Value<String>,
+        ),
+        stakeholder_xpub: Option<&form::Value<String>>,
+        manager_xpub: Option<&form::Value<String>>,
+        warning: Option<&String>,
+    ) -> Element<'a, Message> {
+        let mut content = Column::new().spacing(20);
+        for (name, input, value, msg) in [
+            (
+                "Deposit descriptor:",
+                &mut self.deposit_input,
+                deposit,
+                message::DefineDescriptors::Deposit as fn(String) -> _,
+            ),
+            (
+                "Unvault descriptor:",
+                &mut self.unvault_input,
+                unvault,
+                message::DefineDescriptors::Unvault,
+            ),
+            (
+                "CPFP descriptor:",
+                &mut self.cpfp_input,
+                cpfp,
+                message::DefineDescriptors::Cpfp,
+            ),
+        ] {
+            content = content.push(
+                Column::new()
+                    .push(text::bold(text::simple(language.tr(name))))
+                    .push(
+                        form::Form::new(input, language.tr("Descriptor"), value, move |desc| {
+                            Message::DefineDescriptors(msg(desc))
+                        })
+                        .warning(language.tr("Please enter a descriptor with a valid checksum"))
+                        .size(15)
+                        .padding(10)
+                        .render(),
+                    )
+                    .spacing(10),
+            );
+        }
+        if let Some(xpub) = stakeholder_xpub {
+            content = content.push(
+                Column::new()
+                    .push(text::bold(text::simple(
+                        language.tr("Your stakeholder xpub:"),
+                    )))
+                    .push(
+                        form::Form::new(
+                            &mut self.stakeholder_xpub_input,
+                            language.tr("Xpub"),
+                            xpub,
+                            |xpub| {
+                                Message::DefineDescriptors(
+                                    message::DefineDescriptors::StakeholderXpub(xpub),
+                                )
+                            },
+                        )
+                        .warning(language.tr("Please enter an xpub of the deposit descriptor"))
+                        .size(15)
+                        .padding(10)
+                        .render(),
+                    )
+                    .spacing(10),
+            );
+        }
+        if let Some(xpub) = manager_xpub {
+            content = content.push(
+                Column::new()
+                    .push(text::bold(text::simple(language.tr("Your manager xpub:"))))
+                    .push(
+                        form::Form::new(
+                            &mut self.manager_xpub_input,
+                            language.tr("Xpub"),
+                            xpub,
+                            |xpub| {
+                                Message::DefineDescriptors(message::DefineDescriptors::ManagerXpub(
+                                    xpub,
+                                ))
+                            },
+                        )
+                        .warning(language.tr("Please enter an xpub of the CPFP descriptor"))
+                        .size(15)
+                        .padding(10)
+                        .render(),
+                    )
+                    .spacing(10),
+            );
+        }
+        if let Some(error) = warning {
+            content = content.push(card::alert_warning(Container::new(text::simple(
+                language.tr(error),
+            ))));
+        }
+
+        layout(
+            language,
+            &mut self.scroll,
+            &mut self.previous_button,
+            Column::new()
+                .push(text::bold(text::simple(language.tr("Paste the descriptors"))).size(50))
+                .push(text::small(language.tr(
+                    "The descriptors are checked against each other, the checksum is optional",
+                )))
+                .push(content)
+                .push(
+                    button::primary(
+                        &mut self.save_button,
+                        button::button_content(None, language.tr("Next")),
+                    )
+                    .on_press(Message::Next)
+                    .min_width(200),
+                )
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding(100)
+                .spacing(50)
+                .align_items(Align::Center)
+                .into(),
+        )
+    }
+}
+
 pub struct DefineCoordinator {
     host_input: text_input::State,
     noise_key_input: text_input::State,
@@ -792,55 +1447,79 @@ impl DefineCoordinator {
     }
     pub fn render<'a>(
         &'a mut self,
+        language: Language,
         host: &form::Value<String>,
-        noise_key: &form::Value<String>,
+        (noise_key, noise_key_warning, fingerprint): (
+            &form::Value<String>,
+            &'a str,
+            Option<&String>,
+        ),
     ) -> Element<'a, Message> {
+        let mut noise_key_col = Column::new()
+            .push(text::bold(text::simple(language.tr("Noise key:"))))
+            .push(
+                form::Form::new(
+                    &mut self.noise_key_input,
+                    language.tr("Noise key"),
+                    noise_key,
+                    |msg| {
+                        Message::DefineCoordinator(message::DefineCoordinator::NoiseKeyEdited(msg))
+                    },
+                )
+                .warning(noise_key_warning)
+                .size(15)
+                .padding(10)
+                .render(),
+            )
+            .spacing(10);
+        if let Some(fingerprint) = fingerprint {
+            noise_key_col = noise_key_col
+                .push(text::simple(&language.trf("Fingerprint: {}", &[fingerprint])))
+                .push(text::small(
+                    language.tr("Compare the fingerprint with the other participants before saving the configuration"),
+                ));
+        }
         layout(
+            language,
             &mut self.scroll,
             &mut self.previous_button,
             Column::new()
-                .push(text::bold(text::simple("Fill in coordinator information")).size(50))
                 .push(
-                    Column::new()
-                        .push(text::bold(text::simple("Host:")))
-                        .push(
-                            form::Form::new(&mut self.host_input, "Host", host, |msg| {
-                                Message::DefineCoordinator(message::DefineCoordinator::HostEdited(
-                                    msg,
-                                ))
-                            })
-                            .warning("Incorrect format for a socket address")
-                            .size(15)
-                            .padding(10)
-                            .render(),
-                        )
-                        .spacing(10),
+                    text::bold(text::simple(language.tr("Fill in coordinator information")))
+                        .size(50),
                 )
+                .push(text::small(
+                    language.tr("The host is an ip address with a port"),
+                ))
                 .push(
                     Column::new()
-                        .push(text::bold(text::simple("Noise key:")))
+                        .push(text::bold(text::simple(language.tr("Host:"))))
                         .push(
                             form::Form::new(
-                                &mut self.noise_key_input,
-                                "Noise key",
-                                noise_key,
+                                &mut self.host_input,
+                                language.tr("Host"),
+                                host,
                                 |msg| {
                                     Message::DefineCoordinator(
-                                        message::DefineCoordinator::NoiseKeyEdited(msg),
+                                        message::DefineCoordinator::HostEdited(msg),
                                     )
                                 },
                             )
-                            .warning("key must be hex encoded and 32 bytes long")
+                            .warning(language.tr("Incorrect format for a socket address"))
                             .size(15)
                             .padding(10)
                             .render(),
                         )
                         .spacing(10),
                 )
+                .push(noise_key_col)
                 .push(
-                    button::primary(&mut self.save_button, button::button_content(None, "Next"))
-                        .on_press(Message::Next)
-                        .min_width(200),
+                    button::primary(
+                        &mut self.save_button,
+                        button::button_content(None, language.tr("Next")),
+                    )
+                    .on_press(Message::Next)
+                    .min_width(200),
                 )
                 .width(Length::Fill)
                 .height(Length::Fill)
@@ -852,6 +1531,122 @@ impl DefineCoordinator {
     }
 }
 
+pub struct DefineCheckProxy {
+    address_input: text_input::State,
+    scroll: scrollable::State,
+    previous_button: Button,
+    check_button: Button,
+    save_button: Button,
+}
+
+impl DefineCheckProxy {
+    pub fn new() -> Self {
+        Self {
+            address_input: text_input::State::new(),
+            scroll: scrollable::State::new(),
+            previous_button: Button::new(),
+            check_button: Button::new(),
+            save_button: Button::new(),
+        }
+    }
+
+    pub fn render<'a>(
+        &'a mut self,
+        language: Language,
+        enabled: bool,
+        address: &form::Value<String>,
+        (checking, proxy_check): (bool, Option<&Result<(), String>>),
+    ) -> Element<'a, Message> {
+        let mut col = Column::new()
+            .push(text::bold(text::simple(language.tr("Set up a proxy for the health checks"))).size(50))
+            .push(text::simple(
+                language.tr("The GUI checks that the coordinator, the cosigners and the watchtowers are reachable.\nThese health checks can be routed through a SOCKS5 proxy, like the one of a Tor daemon.\nThe proxy only covers them: revaultd connects to the servers directly."),
+            ))
+            .push(Checkbox::new(
+                enabled,
+                language.tr("Run the health checks through a SOCKS5 proxy"),
+                |enabled| Message::DefineCheckProxy(message::DefineCheckProxy::Enabled(enabled)),
+            ));
+
+        if enabled {
+            let mut check_button = button::transparent(
+                &mut self.check_button,
+                button::button_content(
+                    None,
+                    if checking {
+                        language.tr("Checking...")
+                    } else {
+                        language.tr("Check the proxy")
+                    },
+                ),
+            )
+            .min_width(200);
+            if !checking {
+                check_button = check_button.on_press(Message::DefineCheckProxy(
+                    message::DefineCheckProxy::CheckProxy,
+                ));
+            }
+            col = col
+                .push(
+                    Column::new()
+                        .push(text::bold(text::simple(language.tr("Proxy address:"))))
+                        .push(
+                            form::Form::new(
+                                &mut self.address_input,
+                                "127.0.0.1:9050",
+                                address,
+                                |msg| {
+                                    Message::DefineCheckProxy(
+                                        message::DefineCheckProxy::AddressEdited(msg),
+                                    )
+                                },
+                            )
+                            .warning(language.tr("Incorrect format for a socket address"))
+                            .size(15)
+                            .padding(10)
+                            .render(),
+                        )
+                        .spacing(10),
+                )
+                .push(
+                    Column::new()
+                        .push(check_button)
+                        .push(match proxy_check {
+                            None => Container::new(Column::new()),
+                            Some(Ok(())) => Container::new(text::success(text::small(
+                                language.tr("The proxy accepts SOCKS5 connections"),
+                            ))),
+                            Some(Err(e)) => {
+                                card::alert_warning(Container::new(text::small(language.tr(e))))
+                            }
+                        })
+                        .spacing(10)
+                        .align_items(Align::Center),
+                );
+        }
+
+        layout(
+            language,
+            &mut self.scroll,
+            &mut self.previous_button,
+            col.push(
+                button::primary(
+                    &mut self.save_button,
+                    button::button_content(None, language.tr("Next")),
+                )
+                .on_press(Message::Next)
+                .min_width(200),
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(100)
+            .spacing(50)
+            .align_items(Align::Center)
+            .into(),
+        )
+    }
+}
+
 pub struct DefineEmergencyAddress {
     address_input: text_input::State,
     scroll: scrollable::State,
@@ -870,24 +1665,31 @@ impl DefineEmergencyAddress {
     }
     pub fn render<'a>(
         &'a mut self,
+        language: Language,
         address: &form::Value<String>,
         warning: Option<&String>,
     ) -> Element<'a, Message> {
         let mut row = Row::new().align_items(Align::Center).spacing(20);
         if !address.valid {
             row = row.push(
-                button::primary(&mut self.save_button, button::button_content(None, "Next"))
-                    .min_width(200),
+                button::primary(
+                    &mut self.save_button,
+                    button::button_content(None, language.tr("Next")),
+                )
+                .min_width(200),
             );
         } else {
             row = row.push(
-                button::primary(&mut self.save_button, button::button_content(None, "Next"))
-                    .on_press(Message::Next)
-                    .min_width(200),
+                button::primary(
+                    &mut self.save_button,
+                    button::button_content(None, language.tr("Next")),
+                )
+                .on_press(Message::Next)
+                .min_width(200),
             );
         }
         let mut col = Column::new()
-            .push(text::bold(text::simple("Address:")))
+            .push(text::bold(text::simple(language.tr("Address:"))))
             .push(
                 form::Form::new(
                     &mut self.address_input,
@@ -895,7 +1697,7 @@ impl DefineEmergencyAddress {
                     address,
                     Message::DefineEmergencyAddress,
                 )
-                .warning("Please enter a valid address")
+                .warning(language.tr("Please enter a valid address"))
                 .size(15)
                 .padding(10)
                 .render(),
@@ -903,14 +1705,19 @@ impl DefineEmergencyAddress {
             .spacing(10);
 
         if let Some(error) = warning {
-            col = col.push(card::alert_warning(Container::new(text::simple(&error))));
+            col = col.push(card::alert_warning(Container::new(text::simple(
+                language.tr(&error),
+            ))));
         }
 
         layout(
+            language,
             &mut self.scroll,
             &mut self.previous_button,
             Column::new()
-                .push(text::bold(text::simple("Fill in emergency information")).size(50))
+                .push(
+                    text::bold(text::simple(language.tr("Fill in emergency information"))).size(50),
+                )
                 .push(col)
                 .push(row)
                 .width(Length::Fill)
@@ -939,6 +1746,7 @@ impl Watchtower {
     }
     pub fn render(
         &mut self,
+        language: Language,
         host: &form::Value<String>,
         noise_key: &form::Value<String>,
     ) -> Element<message::DefineWatchtower> {
@@ -949,11 +1757,11 @@ impl Watchtower {
                         .push(
                             form::Form::new(
                                 &mut self.host_input,
-                                "Host",
+                                language.tr("Host"),
                                 host,
                                 message::DefineWatchtower::HostEdited,
                             )
-                            .warning("Please enter a valid Host")
+                            .warning(language.tr("Please enter a valid host:port address"))
                             .size(15)
                             .padding(10)
                             .render(),
@@ -961,11 +1769,13 @@ impl Watchtower {
                         .push(
                             form::Form::new(
                                 &mut self.noise_key_input,
-                                "Noise key",
+                                language.tr("Noise key"),
                                 noise_key,
                                 message::DefineWatchtower::NoiseKeyEdited,
                             )
-                            .warning("Please enter a valid noise key with length > 66")
+                            .warning(
+                                language.tr("Please enter a valid noise key of 64 hex characters"),
+                            )
                             .size(15)
                             .padding(10)
                             .render(),
@@ -1004,24 +1814,34 @@ impl DefineWatchtowers {
     }
     pub fn render<'a>(
         &'a mut self,
+        language: Language,
         watchtowers: Vec<Element<'a, Message>>,
     ) -> Element<'a, Message> {
         layout(
+            language,
             &mut self.scroll,
             &mut self.previous_button,
             Column::new()
-                .push(text::bold(text::simple("Fill in watchtowers information")).size(50))
+                .push(
+                    text::bold(text::simple(language.tr("Fill in watchtowers information")))
+                        .size(50),
+                )
                 .push(
                     Column::new()
                         .push(
-                            Container::new(text::bold(text::simple("Your watchtowers:")))
-                                .width(Length::Fill),
+                            Container::new(text::bold(text::simple(
+                                language.tr("Your watchtowers:"),
+                            )))
+                            .width(Length::Fill),
                         )
                         .push(Column::with_children(watchtowers).spacing(10))
                         .push(
                             button::transparent(
                                 &mut self.add_watchtower_button,
-                                button::button_content(Some(icon::plus_icon()), "Add a watchtower"),
+                                button::button_content(
+                                    Some(icon::plus_icon()),
+                                    language.tr("Add a watchtower"),
+                                ),
                             )
                             .on_press(Message::DefineWatchtowers(
                                 message::DefineWatchtowers::AddWatchtower,
@@ -1030,9 +1850,12 @@ impl DefineWatchtowers {
                         .spacing(10),
                 )
                 .push(
-                    button::primary(&mut self.save_button, button::button_content(None, "Next"))
-                        .on_press(Message::Next)
-                        .min_width(200),
+                    button::primary(
+                        &mut self.save_button,
+                        button::button_content(None, language.tr("Next")),
+                    )
+                    .on_press(Message::Next)
+                    .min_width(200),
                 )
                 .width(Length::Fill)
                 .height(Length::Fill)
@@ -1058,6 +1881,7 @@ impl Cosigner {
     }
     pub fn render(
         &mut self,
+        language: Language,
         host: &form::Value<String>,
         noise_key: &form::Value<String>,
     ) -> Element<message::DefineCosigner> {
@@ -1066,11 +1890,11 @@ impl Cosigner {
                 .push(
                     form::Form::new(
                         &mut self.host_input,
-                        "Host",
+                        language.tr("Host"),
                         host,
                         message::DefineCosigner::HostEdited,
                     )
-                    .warning("Please enter a valid host")
+                    .warning(language.tr("Please enter a valid host"))
                     .size(15)
                     .padding(10)
                     .render(),
@@ -1078,11 +1902,11 @@ impl Cosigner {
                 .push(
                     form::Form::new(
                         &mut self.noise_key_input,
-                        "Noise key",
+                        language.tr("Noise key"),
                         noise_key,
                         message::DefineCosigner::NoiseKeyEdited,
                     )
-                    .warning("key must be hex encoded and 32 bytes long")
+                    .warning(language.tr("key must be hex encoded and 32 bytes long"))
                     .size(15)
                     .padding(10)
                     .render(),
@@ -1108,25 +1932,40 @@ impl DefineCosigners {
             save_button: Button::new(),
         }
     }
-    pub fn render<'a>(&'a mut self, cosigners: Vec<Element<'a, Message>>) -> Element<'a, Message> {
+    pub fn render<'a>(
+        &'a mut self,
+        language: Language,
+        cosigners: Vec<Element<'a, Message>>,
+    ) -> Element<'a, Message> {
         layout(
+            language,
             &mut self.scroll,
             &mut self.previous_button,
             Column::new()
-                .push(text::bold(text::simple("Fill in cosigning servers information")).size(50))
+                .push(
+                    text::bold(text::simple(
+                        language.tr("Fill in cosigning servers information"),
+                    ))
+                    .size(50),
+                )
                 .push(
                     Column::new()
                         .push(
-                            Container::new(text::bold(text::simple("The cosigning servers:")))
-                                .width(Length::Fill),
+                            Container::new(text::bold(text::simple(
+                                language.tr("The cosigning servers:"),
+                            )))
+                            .width(Length::Fill),
                         )
                         .push(Column::with_children(cosigners).spacing(10))
                         .spacing(10),
                 )
                 .push(
-                    button::primary(&mut self.save_button, button::button_content(None, "Next"))
-                        .on_press(Message::Next)
-                        .min_width(200),
+                    button::primary(
+                        &mut self.save_button,
+                        button::button_content(None, language.tr("Next")),
+                    )
+                    .on_press(Message::Next)
+                    .min_width(200),
                 )
                 .width(Length::Fill)
                 .height(Length::Fill)
@@ -1149,6 +1988,7 @@ pub struct DefineBitcoind {
     cookie_path_input: text_input::State,
     scroll: scrollable::State,
     previous_button: Button,
+    check_button: Button,
     save_button: Button,
 }
 
@@ -1160,22 +2000,53 @@ impl DefineBitcoind {
             cookie_path_input: text_input::State::new(),
             scroll: scrollable::State::new(),
             previous_button: Button::new(),
+            check_button: Button::new(),
             save_button: Button::new(),
         }
     }
     pub fn render<'a>(
         &'a mut self,
+        language: Language,
         network: &bitcoin::Network,
         address: &form::Value<String>,
         cookie_path: &form::Value<String>,
+        (checking, node_check): (bool, Option<&Result<NodeReport, BitcoindError>>),
     ) -> Element<'a, Message> {
+        let mut check_button = button::transparent(
+            &mut self.check_button,
+            button::button_content(
+                None,
+                if checking {
+                    language.tr("Testing...")
+                } else {
+                    language.tr("Test connection")
+                },
+            ),
+        )
+        .min_width(200);
+        if !checking {
+            check_button =
+                check_button.on_press(Message::DefineBitcoind(message::DefineBitcoind::CheckNode));
+        }
+        let passed = !checking && matches!(node_check, Some(Ok(report)) if report.passed());
+        let mut next_button = button::primary(
+            &mut self.save_button,
+            button::button_content(None, language.tr("Next")),
+        )
+        .min_width(200);
+        if passed {
+            next_button = next_button.on_press(Message::Next);
+        }
         let col_address = Column::new()
-            .push(text::bold(text::simple("Address:")))
+            .push(text::bold(text::simple(language.tr("Address:"))))
             .push(
-                form::Form::new(&mut self.address_input, "Address", address, |msg| {
-                    Message::DefineBitcoind(message::DefineBitcoind::AddressEdited(msg))
-                })
-                .warning("Please enter correct address")
+                form::Form::new(
+                    &mut self.address_input,
+                    language.tr("Address"),
+                    address,
+                    |msg| Message::DefineBitcoind(message::DefineBitcoind::AddressEdited(msg)),
+                )
+                .warning(language.tr("Please enter correct address"))
                 .size(15)
                 .padding(10)
                 .render(),
@@ -1183,27 +2054,34 @@ impl DefineBitcoind {
             .spacing(10);
 
         let col_cookie = Column::new()
-            .push(text::bold(text::simple("Cookie path:")))
+            .push(text::bold(text::simple(language.tr("Cookie path:"))))
             .push(
                 form::Form::new(
                     &mut self.cookie_path_input,
-                    "Cookie path",
+                    language.tr("Cookie path"),
                     cookie_path,
                     |msg| Message::DefineBitcoind(message::DefineBitcoind::CookiePathEdited(msg)),
                 )
-                .warning("Please enter correct path")
+                .warning(language.tr("No cookie file found at this path"))
                 .size(15)
                 .padding(10)
                 .render(),
             )
+            .push(text::small(language.tr(
+                "revaultd and the GUI authenticate to bitcoind with its cookie file.",
+            )))
             .spacing(10);
 
         layout(
+            language,
             &mut self.scroll,
             &mut self.previous_button,
             Column::new()
                 .push(
-                    text::bold(text::simple("Set up connection to the Bitcoin full node")).size(50),
+                    text::bold(text::simple(
+                        language.tr("Set up connection to the Bitcoin full node"),
+                    ))
+                    .size(50),
                 )
                 .push(Container::new(
                     pick_list::PickList::new(
@@ -1217,9 +2095,144 @@ impl DefineBitcoind {
                 .push(col_address)
                 .push(col_cookie)
                 .push(
-                    button::primary(&mut self.save_button, button::button_content(None, "Next"))
-                        .on_press(Message::Next)
-                        .min_width(200),
+                    Column::new()
+                        .push(check_button)
+                        .push(node_check_result(language, node_check))
+                        .spacing(10)
+                        .align_items(Align::Center),
+                )
+                .push(
+                    Column::new()
+                        .push(next_button)
+                        .push(if passed {
+                            text::small("")
+                        } else {
+                            text::small(language.tr("Test the connection to go to the next step"))
+                        })
+                        .spacing(10)
+                        .align_items(Align::Center),
+                )
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding(100)
+                .spacing(50)
+                .align_items(Align::Center)
+                .into(),
+        )
+    }
+}
+
+fn node_check_result<'a>(
+    language: Language,
+    node_check: Option<&Result<NodeReport, BitcoindError>>,
+) -> Container<'a, Message> {
+    match node_check {
+        None => Container::new(Column::new()),
+        Some(Err(e)) => {
+            let mut col = Column::new().push(text::small(language.tr(&e.to_string())));
+            if let Some(guidance) = e.guidance() {
+                col = col.push(text::small(language.tr(guidance)));
+            }
+            card::alert_warning(Container::new(col.spacing(5)))
+        }
+        Some(Ok(report)) => Container::new(
+            Column::with_children(
+                report
+                    .diagnostics
+                    .iter()
+                    .map(|d| match d.severity {
+                        Severity::Passed => text::success(text::small(&d.message(language))).into(),
+                        Severity::Warning => text::small(&d.message(language)).into(),
+                        Severity::Failed => text::danger(text::small(&d.message(language))).into(),
+                    })
+                    .collect(),
+            )
+            .spacing(5),
+        ),
+    }
+}
+
+pub struct Review {
+    scroll: scrollable::State,
+    previous_button: Button,
+    next_button: Button,
+}
+
+impl Review {
+    pub fn new() -> Self {
+        Self {
+            scroll: scrollable::State::new(),
+            previous_button: Button::new(),
+            next_button: Button::new(),
+        }
+    }
+
+    pub fn render<'a>(
+        &'a mut self,
+        language: Language,
+        scripts: &ScriptsConfig,
+        network: bitcoin::Network,
+        addresses: Result<&Vec<String>, &String>,
+    ) -> Element<'a, Message> {
+        let addresses = match addresses {
+            Ok(addresses) => addresses.iter().enumerate().fold(
+                Column::new().spacing(5).push(text::bold(text::simple(
+                    &language.trf("First deposit addresses on {}", &[&network]),
+                ))),
+                |col, (index, address)| {
+                    col.push(
+                        Row::new()
+                            .push(
+                                Container::new(text::small(&index.to_string()))
+                                    .width(Length::Units(30)),
+                            )
+                            .push(text::small(address)),
+                    )
+                },
+            ),
+            Err(e) => Column::new().push(text::danger(text::small(e))),
+        };
+
+        layout(
+            language,
+            &mut self.scroll,
+            &mut self.previous_button,
+            Column::new()
+                .push(text::bold(text::simple(language.tr("Review the configuration"))).size(50))
+                .push(text::simple(language.tr(
+                    "Before writing the configuration, check over another channel \
+                    that every participant got the same descriptor checksums and \
+                    the same deposit addresses.",
+                )))
+                .push(
+                    card::simple(Container::new(
+                        Column::new()
+                            .spacing(20)
+                            .push(text::bold(text::simple(language.tr("Participants"))))
+                            .push(text::small(
+                                language
+                                    .tr("Each participant has the color of its key fingerprint \
+                                on every screen and every computer"),
+                            ))
+                            .push(badge::participants(
+                                language,
+                                &participant::unvault_participants(
+                                    &scripts.deposit_descriptor,
+                                    &scripts.unvault_descriptor,
+                                ),
+                            )),
+                    ))
+                    .width(Length::Fill),
+                )
+                .push(descriptors_review(language, scripts))
+                .push(card::simple(Container::new(addresses)).width(Length::Fill))
+                .push(
+                    button::primary(
+                        &mut self.next_button,
+                        button::button_content(None, language.tr("They are the same")),
+                    )
+                    .on_press(Message::Next)
+                    .min_width(200),
                 )
                 .width(Length::Fill)
                 .height(Length::Fill)
@@ -1248,6 +2261,7 @@ impl Final {
 
     pub fn render(
         &mut self,
+        language: Language,
         generating: bool,
         config_path: Option<&std::path::PathBuf>,
         warning: Option<&String>,
@@ -1260,14 +2274,16 @@ impl Final {
             .align_items(Align::Center);
 
         if let Some(error) = warning {
-            col = col.push(card::alert_warning(Container::new(text::simple(error))));
+            col = col.push(card::alert_warning(Container::new(text::simple(
+                language.tr(error),
+            ))));
         }
 
         if generating {
             col = col.push(
                 button::primary(
                     &mut self.action_button,
-                    button::button_content(None, "Installing ..."),
+                    button::button_content(None, language.tr("Installing ...")),
                 )
                 .min_width(200),
             )
@@ -1275,11 +2291,11 @@ impl Final {
             col = col.push(card::border_success(
                 Container::new(
                     Column::new()
-                        .push(Container::new(text::simple("Installed !")))
+                        .push(Container::new(text::simple(language.tr("Installed !"))))
                         .push(Container::new(
                             button::primary(
                                 &mut self.action_button,
-                                button::button_content(None, "Start"),
+                                button::button_content(None, language.tr("Start")),
                             )
                             .on_press(Message::Exit(path.clone()))
                             .min_width(200),
@@ -1295,18 +2311,56 @@ impl Final {
             col = col.push(
                 button::primary(
                     &mut self.action_button,
-                    button::button_content(None, "Finalize installation"),
+                    button::button_content(None, language.tr("Finalize installation")),
                 )
                 .on_press(Message::Install)
                 .min_width(200),
             );
         }
 
-        layout(&mut self.scroll, &mut self.previous_button, col.into())
+        layout(
+            language,
+            &mut self.scroll,
+            &mut self.previous_button,
+            col.into(),
+        )
+    }
+}
+
+/// Displays the descriptors of the configuration with their checksums,
+/// so that they can be compared with the other participants ones.
+fn descriptors_review<'a>(language: Language, scripts: &ScriptsConfig) -> Element<'a, Message> {
+    let mut col = Column::new()
+        .spacing(20)
+        .push(text::bold(text::simple(language.tr("Descriptors"))))
+        .push(text::small(language.tr(
+            "Check with the other participants that the descriptor checksums are the same",
+        )));
+    for (name, desc) in [
+        ("Deposit descriptor", &scripts.deposit_descriptor),
+        ("Unvault descriptor", &scripts.unvault_descriptor),
+        ("CPFP descriptor", &scripts.cpfp_descriptor),
+    ] {
+        if desc.is_empty() {
+            continue;
+        }
+        let checksum = match descriptor::verify(desc) {
+            Ok(checksum) => text::bold(text::simple(&language.trf("Checksum: {}", &[&checksum]))),
+            Err(e) => text::danger(text::small(&e.to_string())),
+        };
+        col = col.push(
+            Column::new()
+                .spacing(5)
+                .push(text::bold(text::small(language.tr(name))))
+                .push(text::small(desc))
+                .push(checksum),
+        );
     }
+    card::simple(Container::new(col)).width(Length::Fill).into()
 }
 
 fn layout<'a>(
+    language: Language,
     scroll_state: &'a mut scrollable::State,
     previous_button: &'a mut Button,
     content: Element<'a, Message>,
@@ -1318,7 +2372,7 @@ fn layout<'a>(
                 .push(
                     button::transparent(
                         previous_button,
-                        button::button_content(None, "< Previous"),
+                        button::button_content(None, language.tr("< Previous")),
                     )
                     .on_press(Message::Previous),
                 )
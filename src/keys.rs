@@ -0,0 +1,157 @@
+//! Key setup for the stakeholders without a signing device: a BIP39 mnemonic
+//! is generated and the account xpub is derived from its seed. The mnemonic
+//! then lives in the memory of the GUI and in the backup of the user, which
+//! is less secure than a key generated and kept by a hardware device.
+//!
+//! The mnemonic, the entropy, the seed and the extended private keys of
+//! this module are zeroized once they are dropped. Some copies of the
+//! secrets are not overwritten:
+//! - the states of the HMAC engines of the PBKDF2 of the bip39 crate,
+//! - the intermediate values of `ExtendedPrivKey::new_master` and of
+//!   `derive_priv`: the HMAC outputs and the private keys of the parent
+//!   levels of the account path, dropped inside the bitcoin library,
+//! - the copies left on the stack when the values are moved,
+//! - the texts of the widgets displaying the mnemonic in the installer.
+
+use std::str::FromStr;
+
+use bitcoin::{
+    secp256k1::Secp256k1,
+    util::bip32::{DerivationPath, ExtendedPrivKey, ExtendedPubKey},
+    Network,
+};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Bytes of entropy of the generated mnemonics, 24 words.
+const ENTROPY_LEN: usize = 32;
+
+/// Returns the derivation path of the account: the BIP48 path of the P2WSH
+/// multisig accounts, with the coin type of the network.
+pub fn account_path(network: Network) -> DerivationPath {
+    let coin_type = match network {
+        Network::Bitcoin => 0,
+        _ => 1,
+    };
+    DerivationPath::from_str(&format!("m/48'/{}'/0'/2'", coin_type)).expect("valid path")
+}
+
+/// Mnemonic is a BIP39 english mnemonic, zeroized when dropped.
+pub struct Mnemonic(bip39::Mnemonic);
+
+impl Mnemonic {
+    /// Generates a mnemonic from the entropy of the operating system.
+    pub fn generate() -> Result<Self, KeyError> {
+        let mut entropy = Zeroizing::new([0u8; ENTROPY_LEN]);
+        getrandom::getrandom(&mut entropy[..]).map_err(|e| KeyError(e.to_string()))?;
+        Self::from_entropy(&entropy[..])
+    }
+
+    fn from_entropy(entropy: &[u8]) -> Result<Self, KeyError> {
+        bip39::Mnemonic::from_entropy(entropy)
+            .map(Self)
+            .map_err(|e| KeyError(e.to_string()))
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.0.word_iter()
+    }
+
+    /// Returns the BIP39 seed of the mnemonic, without passphrase.
+    fn seed(&self) -> Zeroizing<[u8; 64]> {
+        Zeroizing::new(self.0.to_seed_normalized(""))
+    }
+
+    /// Derives the xpub of the account of the network.
+    pub fn account_xpub(&self, network: Network) -> Result<ExtendedPubKey, KeyError> {
+        let secp = Secp256k1::new();
+        let seed = self.seed();
+        let mut master =
+            ExtendedPrivKey::new_master(network, &seed[..]).map_err(|e| KeyError(e.to_string()))?;
+        let res = master.derive_priv(&secp, &account_path(network));
+        wipe_xpriv(&mut master);
+        let mut account = res.map_err(|e| KeyError(e.to_string()))?;
+        let xpub = ExtendedPubKey::from_private(&secp, &account);
+        wipe_xpriv(&mut account);
+        Ok(xpub)
+    }
+}
+
+impl std::fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Mnemonic(<redacted>)")
+    }
+}
+
+/// Zeroizes the private key and the chain code of the extended key.
+fn wipe_xpriv(xpriv: &mut ExtendedPrivKey) {
+    let key = xpriv.private_key.key.as_mut_ptr();
+    let chain_code = xpriv.chain_code.as_mut_ptr();
+    unsafe {
+        std::slice::from_raw_parts_mut(key, 32).zeroize();
+        std::slice::from_raw_parts_mut(chain_code, 32).zeroize();
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KeyError(String);
+
+impl std::fmt::Display for KeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Key generation failed: {}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::hashes::hex::ToHex;
+
+    #[test]
+    fn mnemonic_from_entropy() {
+        // Test vectors of BIP39.
+        let vectors: [(&[u8], &str); 4] = [
+            (
+                &[0x00; 16],
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            ),
+            (
+                &[0x7f; 16],
+                "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            ),
+            (
+                &[0x80; 16],
+                "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+            ),
+            (
+                &[0xff; 32],
+                "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+            ),
+        ];
+        for (entropy, phrase) in vectors.iter() {
+            assert_eq!(
+                Mnemonic::from_entropy(entropy).unwrap().0.to_string(),
+                *phrase
+            );
+        }
+    }
+
+    #[test]
+    fn mnemonic_seed_and_xpub() {
+        let mnemonic = Mnemonic::from_entropy(&[0x00; 16]).unwrap();
+        assert_eq!(
+            mnemonic.seed().to_hex(),
+            "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc1\
+             9a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4"
+        );
+        assert_eq!(
+            mnemonic.account_xpub(Network::Bitcoin).unwrap().to_string(),
+            "xpub6DkFAXWQ2dHxq2vatrt9qyA3bXYU4ToWQwCHbf5XB2mSTexcHZCeKS1VZYcPoBd5X8yVcbXFHJR9R8UCVpt82VX1VhR28mCyxUFL4r6KFrf"
+        );
+    }
+
+    #[test]
+    fn generated_mnemonic_has_24_words() {
+        let mnemonic = Mnemonic::generate().unwrap();
+        assert_eq!(mnemonic.words().count(), 24);
+    }
+}
@@ -0,0 +1,298 @@
+//! Launcher listing the known GUI configurations, the user opens one of
+//! them in this window or in a new one. The launcher is displayed in the
+//! language of the first configuration setting one.
+
+mod view;
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use iced::{Command, Element};
+
+use crate::{
+    app::config::{self as gui_config, DEFAULT_FILE_NAME},
+    i18n::Language,
+    installer::append_network_suffix,
+    revault::Role,
+    revaultd::config::{self as revaultd_config, default_datadir},
+};
+
+use view::LauncherView;
+
+/// File of the default datadir keeping the paths added by the user.
+const PATHS_FILE_NAME: &str = "revault_gui_launcher.json";
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    PathEdited(String),
+    /// Add the edited path to the known configurations.
+    Add,
+    Remove(PathBuf),
+    /// Run the configuration in this window.
+    Open(PathBuf),
+    /// Run the configuration in a new GUI process.
+    OpenWindow(PathBuf),
+}
+
+/// Entry is a known GUI configuration file.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub path: PathBuf,
+    /// true if the path was added by the user.
+    pub added: bool,
+    pub summary: Result<Summary, String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Summary {
+    pub network: bitcoin::Network,
+    pub roles: Vec<Role>,
+    pub training: bool,
+    pub language: Option<Language>,
+}
+
+impl Summary {
+    fn read(path: &Path) -> Result<Self, String> {
+        let config = gui_config::Config::from_file(path).map_err(|e| e.to_string())?;
+        let revaultd = revaultd_config::Config::from_file(&config.revaultd_config_path)
+            .map_err(|e| e.to_string())?;
+        let mut roles = Vec::new();
+        if revaultd.stakeholder_config.is_some() {
+            roles.push(Role::Stakeholder);
+        }
+        if revaultd.manager_config.is_some() {
+            roles.push(Role::Manager);
+        }
+        Ok(Self {
+            network: revaultd.bitcoind_config.network,
+            roles,
+            training: config.is_training(),
+            language: config.language,
+        })
+    }
+}
+
+pub struct Launcher {
+    entries: Vec<Entry>,
+    path: String,
+    warning: Option<String>,
+    language: Language,
+    view: LauncherView,
+}
+
+impl Launcher {
+    pub fn new() -> Self {
+        let mut launcher = Self {
+            entries: Vec::new(),
+            path: String::new(),
+            warning: None,
+            language: Language::default(),
+            view: LauncherView::new(),
+        };
+        launcher.refresh();
+        launcher
+    }
+
+    /// Returns true if there is a choice to make at startup: several
+    /// configurations are known or the user added one.
+    pub fn has_choice(&self) -> bool {
+        self.entries.len() > 1 || self.entries.iter().any(|e| e.added)
+    }
+
+    fn refresh(&mut self) {
+        let added = match load_paths(self.language) {
+            Ok(paths) => paths,
+            Err(e) => {
+                self.warning = Some(e);
+                Vec::new()
+            }
+        };
+        let mut entries: Vec<Entry> = scan()
+            .into_iter()
+            .map(|path| (path, false))
+            .chain(added.into_iter().map(|path| (path, true)))
+            .map(|(path, added)| Entry {
+                summary: Summary::read(&path),
+                path,
+                added,
+            })
+            .collect();
+        let mut seen = HashSet::new();
+        entries.retain(|e| seen.insert(e.path.clone()));
+        self.language = entries
+            .iter()
+            .find_map(|e| e.summary.as_ref().ok().and_then(|s| s.language))
+            .unwrap_or_default();
+        self.entries = entries;
+    }
+
+    fn added_paths(&self) -> Vec<PathBuf> {
+        self.entries
+            .iter()
+            .filter(|e| e.added)
+            .map(|e| e.path.clone())
+            .collect()
+    }
+
+    pub fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::PathEdited(path) => self.path = path,
+            Message::Add => {
+                let path = PathBuf::from(self.path.trim());
+                if !path.is_file() {
+                    self.warning =
+                        Some(self.language.trf("No file found at {}", &[&path.display()]));
+                    return Command::none();
+                }
+                let mut paths = self.added_paths();
+                if !self.entries.iter().any(|e| e.path == path) {
+                    paths.push(path);
+                }
+                self.warning = save_paths(&paths, self.language).err();
+                self.path = String::new();
+                self.refresh();
+            }
+            Message::Remove(path) => {
+                let paths: Vec<PathBuf> = self
+                    .added_paths()
+                    .into_iter()
+                    .filter(|p| *p != path)
+                    .collect();
+                self.warning = save_paths(&paths, self.language).err();
+                self.refresh();
+            }
+            Message::OpenWindow(path) => {
+                self.warning = std::env::current_exe()
+                    .and_then(|exe| {
+                        std::process::Command::new(exe)
+                            .arg("--conf")
+                            .arg(&path)
+                            .spawn()
+                    })
+                    .map(|_| ())
+                    .map_err(|e| self.language.trf("Failed to open a new window: {}", &[&e]))
+                    .err();
+            }
+            // The configuration is run by the GUI in place of the launcher.
+            Message::Open(_) => {}
+        }
+        Command::none()
+    }
+
+    /// Displays the failure to run the configuration.
+    pub fn failed(&mut self, path: &Path, e: &dyn std::fmt::Display) {
+        self.warning = Some(
+            self.language
+                .trf("Failed to open {}: {}", &[&path.display(), e]),
+        );
+    }
+
+    pub fn view(&mut self) -> Element<Message> {
+        self.view.view(
+            &self.entries,
+            &self.path,
+            self.warning.as_ref(),
+            self.language,
+        )
+    }
+}
+
+/// Lists the configurations of the default datadir and the ones
+/// of its direct subdirectories.
+fn scan() -> Vec<PathBuf> {
+    match default_datadir() {
+        Ok(datadir) => scan_dir(&datadir),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Lists the configurations of the directory and of its direct
+/// subdirectories, one per network as the installer names them.
+fn scan_dir(datadir: &Path) -> Vec<PathBuf> {
+    let names: Vec<String> = [
+        bitcoin::Network::Bitcoin,
+        bitcoin::Network::Testnet,
+        bitcoin::Network::Regtest,
+    ]
+    .iter()
+    .map(|network| append_network_suffix(DEFAULT_FILE_NAME, network))
+    .collect();
+    let mut dirs = vec![datadir.to_path_buf()];
+    if let Ok(entries) = std::fs::read_dir(datadir) {
+        let mut subdirs: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect();
+        subdirs.sort();
+        dirs.extend(subdirs);
+    }
+    dirs.iter()
+        .flat_map(|dir| names.iter().map(move |name| dir.join(name)))
+        .filter(|path| path.is_file())
+        .collect()
+}
+
+fn paths_file(language: Language) -> Result<PathBuf, String> {
+    default_datadir()
+        .map(|datadir| datadir.join(PATHS_FILE_NAME))
+        .map_err(|_| {
+            language
+                .tr("Failed to locate the default datadir")
+                .to_string()
+        })
+}
+
+fn load_paths(language: Language) -> Result<Vec<PathBuf>, String> {
+    let path = paths_file(language)?;
+    match std::fs::read(&path) {
+        Ok(content) => serde_json::from_slice(&content)
+            .map_err(|e| language.trf("Failed to parse {}: {}", &[&path.display(), &e])),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(language.trf("Failed to read {}: {}", &[&path.display(), &e])),
+    }
+}
+
+fn save_paths(paths: &[PathBuf], language: Language) -> Result<(), String> {
+    let path = paths_file(language)?;
+    let content = serde_json::to_vec_pretty(paths)
+        .map_err(|e| language.trf("Failed to serialize the paths: {}", &[&e]))?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| language.trf("Failed to create {}: {}", &[&dir.display(), &e]))?;
+    }
+    std::fs::write(&path, content)
+        .map_err(|e| language.trf("Failed to write {}: {}", &[&path.display(), &e]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scan_the_configurations_of_every_network() {
+        let datadir =
+            std::env::temp_dir().join(format!("revault_gui_test_launcher_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&datadir);
+        std::fs::create_dir_all(datadir.join("other")).unwrap();
+        for name in &[
+            "revault_gui.toml",
+            "revault_gui_testnet.toml",
+            "revaultd_testnet.toml",
+            "other/revault_gui_regtest.toml",
+            "other/revault_gui_history_1.csv",
+        ] {
+            std::fs::write(datadir.join(name), "").unwrap();
+        }
+        let paths = scan_dir(&datadir);
+        std::fs::remove_dir_all(&datadir).unwrap();
+        assert_eq!(
+            paths,
+            vec![
+                datadir.join("revault_gui.toml"),
+                datadir.join("revault_gui_testnet.toml"),
+                datadir.join("other/revault_gui_regtest.toml"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use iced::{
+    button::State as Button, scrollable, text_input, Align, Column, Container, Element, Length,
+    Row, TextInput,
+};
+
+use super::{Entry, Message};
+use crate::{
+    i18n::Language,
+    ui::{
+        color,
+        component::{button, card, scroll, separation, text},
+    },
+};
+
+#[derive(Debug)]
+pub struct LauncherView {
+    scroll: scrollable::State,
+    path_input: text_input::State,
+    add_button: Button,
+    /// open, new window and remove buttons of every entry.
+    entry_buttons: Vec<(Button, Button, Button)>,
+}
+
+impl LauncherView {
+    pub fn new() -> Self {
+        LauncherView {
+            scroll: scrollable::State::new(),
+            path_input: text_input::State::new(),
+            add_button: Button::new(),
+            entry_buttons: Vec::new(),
+        }
+    }
+
+    pub fn view<'a>(
+        &'a mut self,
+        entries: &[Entry],
+        path: &str,
+        warning: Option<&String>,
+        language: Language,
+    ) -> Element<'a, Message> {
+        if self.entry_buttons.len() != entries.len() {
+            self.entry_buttons = entries
+                .iter()
+                .map(|_| (Button::new(), Button::new(), Button::new()))
+                .collect();
+        }
+
+        let mut col = Column::new()
+            .push(text::bold(text::simple("Revault GUI")).size(50))
+            .push(text::simple(
+                language.tr("Choose the configuration to open"),
+            ))
+            .spacing(20);
+
+        if entries.is_empty() {
+            col = col.push(text::small(
+                language.tr("No configuration found in the default datadir"),
+            ));
+        }
+        for (entry, (open, window, remove)) in entries.iter().zip(self.entry_buttons.iter_mut()) {
+            col = col.push(entry_card(entry, open, window, remove, language));
+        }
+
+        col = col.push(separation().width(Length::Fill)).push(
+            Row::new()
+                .push(
+                    TextInput::new(
+                        &mut self.path_input,
+                        language.tr("Path of a revault_gui.toml file"),
+                        path,
+                        Message::PathEdited,
+                    )
+                    .size(15)
+                    .padding(10),
+                )
+                .push(
+                    button::transparent(
+                        &mut self.add_button,
+                        button::button_content(None, language.tr("Add")),
+                    )
+                    .on_press(Message::Add),
+                )
+                .spacing(10)
+                .align_items(Align::Center),
+        );
+        if let Some(warning) = warning {
+            col = col.push(card::alert_warning(Container::new(text::small(warning))));
+        }
+
+        Container::new(scroll(
+            &mut self.scroll,
+            Container::new(col.max_width(1000)).padding(50),
+        ))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .into()
+    }
+}
+
+fn entry_card<'a>(
+    entry: &Entry,
+    open: &'a mut Button,
+    window: &'a mut Button,
+    remove: &'a mut Button,
+    language: Language,
+) -> Container<'a, Message> {
+    let mut details =
+        Column::new().push(text::bold(text::small(&entry.path.display().to_string())));
+    let mut buttons = Row::new().spacing(10).align_items(Align::Center);
+    match &entry.summary {
+        Ok(summary) => {
+            let mut description = summary.network.to_string();
+            for role in &summary.roles {
+                description.push_str(", ");
+                description.push_str(language.tr(&role.to_string()));
+            }
+            if summary.training {
+                description.push_str(", ");
+                description.push_str(language.tr("training"));
+            }
+            details = details.push(text::small(&description));
+            buttons = buttons
+                .push(
+                    button::transparent(
+                        window,
+                        button::button_content(None, language.tr("New window")),
+                    )
+                    .on_press(Message::OpenWindow(entry.path.clone())),
+                )
+                .push(
+                    button::primary(open, button::button_content(None, language.tr("Open")))
+                        .on_press(Message::Open(entry.path.clone())),
+                );
+        }
+        Err(e) => {
+            details = details.push(text::small(e).color(color::WARNING));
+        }
+    }
+    if entry.added {
+        buttons = buttons.push(
+            button::transparent(remove, button::button_content(None, language.tr("Remove")))
+                .on_press(Message::Remove(entry.path.clone())),
+        );
+    }
+    card::simple(Container::new(
+        Row::new()
+            .push(Container::new(details).width(Length::Fill))
+            .push(buttons)
+            .align_items(Align::Center),
+    ))
+}
@@ -0,0 +1,443 @@
+//! Logs of the GUI: human readable on the standard output and structured
+//! as json lines in rotating files of the log directory, so that the logs
+//! of a user not running the GUI from a terminal can be collected. The
+//! latest events are also kept in memory for the logs panel.
+
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use tracing::{field::Field, Level, Subscriber};
+use tracing_subscriber::{
+    filter::EnvFilter,
+    fmt,
+    layer::{self, Layer, SubscriberExt},
+    reload,
+    util::SubscriberInitExt,
+    Registry,
+};
+
+pub const FILE_NAME: &str = "revault_gui.log";
+
+/// Size of a log file before it is rotated.
+const MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
+/// Number of log files kept, the current one included.
+const MAX_FILES: usize = 5;
+/// Number of events kept in memory for the logs panel.
+const MAX_CAPTURED: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 3] = [LogLevel::Info, LogLevel::Debug, LogLevel::Trace];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|l| l.to_string() == name)
+    }
+
+    fn filter(&self) -> EnvFilter {
+        EnvFilter::new(format!("revault_gui={}", self))
+    }
+}
+
+impl std::fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Info => write!(f, "info"),
+            Self::Debug => write!(f, "debug"),
+            Self::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+struct Logs {
+    filter: reload::Handle<EnvFilter, Registry>,
+    level: Mutex<LogLevel>,
+    dir: Option<PathBuf>,
+}
+
+static LOGS: OnceLock<Logs> = OnceLock::new();
+
+/// Sets the global subscriber, the logs are written to the directory if any.
+/// Returns the error preventing the log files to be opened, the logs are then
+/// only written to the standard output.
+pub fn init(level: LogLevel, dir: Option<PathBuf>) -> Result<Option<io::Error>, String> {
+    let (filter, handle) = reload::Layer::new(level.filter());
+    let (file, error) = match dir.as_deref().map(LogFile::open) {
+        Some(Ok(file)) => (Some(file), None),
+        Some(Err(e)) => (None, Some(e)),
+        None => (None, None),
+    };
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(fmt::layer())
+        .with(file.map(|file| fmt::layer().json().with_ansi(false).with_writer(file)))
+        .with(Capture)
+        .try_init()
+        .map_err(|e| e.to_string())?;
+    let _ = LOGS.set(Logs {
+        filter: handle,
+        level: Mutex::new(level),
+        dir: if error.is_none() { dir } else { None },
+    });
+    Ok(error)
+}
+
+pub fn level() -> LogLevel {
+    LOGS.get()
+        .map(|logs| *logs.level.lock().unwrap())
+        .unwrap_or(LogLevel::Info)
+}
+
+/// Changes the level of the logs until the GUI is closed.
+pub fn set_level(level: LogLevel) -> Result<(), String> {
+    let logs = LOGS
+        .get()
+        .ok_or_else(|| "Logs are not initialized".to_string())?;
+    logs.filter
+        .reload(level.filter())
+        .map_err(|e| e.to_string())?;
+    *logs.level.lock().unwrap() = level;
+    tracing::info!("Log level set to {}", level);
+    Ok(())
+}
+
+/// Directory of the log files, None if the logs are not written to files.
+pub fn dir() -> Option<&'static Path> {
+    LOGS.get().and_then(|logs| logs.dir.as_deref())
+}
+
+/// Opens the directory in the file manager of the system.
+pub fn open_dir(dir: &Path) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    let program = "open";
+    #[cfg(target_os = "windows")]
+    let program = "explorer";
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let program = "xdg-open";
+    std::process::Command::new(program)
+        .arg(dir)
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to open {}: {}", dir.display(), e))
+}
+
+/// LogFile writes to the current log file of the directory and renames it
+/// with a number once it is too large, `revault_gui.log.1` being the latest.
+#[derive(Clone)]
+struct LogFile(Arc<Mutex<RotatingFile>>);
+
+struct RotatingFile {
+    dir: PathBuf,
+    file: File,
+    size: u64,
+}
+
+impl LogFile {
+    fn open(dir: &Path) -> Result<Self, io::Error> {
+        std::fs::create_dir_all(dir)?;
+        let file = open_current(dir)?;
+        Ok(Self(Arc::new(Mutex::new(RotatingFile {
+            dir: dir.to_path_buf(),
+            size: file.metadata()?.len(),
+            file,
+        }))))
+    }
+}
+
+fn file_path(dir: &Path, index: usize) -> PathBuf {
+    if index == 0 {
+        dir.join(FILE_NAME)
+    } else {
+        dir.join(format!("{}.{}", FILE_NAME, index))
+    }
+}
+
+fn open_current(dir: &Path) -> Result<File, io::Error> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(file_path(dir, 0))
+}
+
+impl RotatingFile {
+    fn rotate(&mut self) -> Result<(), io::Error> {
+        self.file.flush()?;
+        let _ = std::fs::remove_file(file_path(&self.dir, MAX_FILES - 1));
+        for index in (0..MAX_FILES - 1).rev() {
+            let _ = std::fs::rename(file_path(&self.dir, index), file_path(&self.dir, index + 1));
+        }
+        self.file = open_current(&self.dir)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for LogFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut file = self.0.lock().unwrap();
+        if file.size > 0 && file.size + buf.len() as u64 > MAX_FILE_SIZE {
+            file.rotate()?;
+        }
+        file.file.write_all(buf)?;
+        file.size += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.lock().unwrap().file.flush()
+    }
+}
+
+impl fmt::MakeWriter for LogFile {
+    type Writer = LogFile;
+
+    fn make_writer(&self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+/// Entry is an event of the GUI captured for the logs panel.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub time: i64,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {:>5} {}: {}",
+            chrono::NaiveDateTime::from_timestamp(self.time, 0).format("%Y-%m-%d %H:%M:%S"),
+            self.level,
+            self.target,
+            self.message
+        )
+    }
+}
+
+static CAPTURED: Mutex<VecDeque<Entry>> = Mutex::new(VecDeque::new());
+
+/// Returns the latest events of the GUI, the oldest first.
+pub fn captured() -> Vec<Entry> {
+    CAPTURED.lock().unwrap().iter().cloned().collect()
+}
+
+/// Capture keeps the latest events passing the level filter in memory.
+struct Capture;
+
+impl<S: Subscriber> Layer<S> for Capture {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: layer::Context<'_, S>) {
+        let mut visitor = MessageVisitor(String::new());
+        event.record(&mut visitor);
+        let mut captured = CAPTURED.lock().unwrap();
+        if captured.len() == MAX_CAPTURED {
+            captured.pop_front();
+        }
+        captured.push_back(Entry {
+            time: chrono::Utc::now().timestamp(),
+            level: *event.metadata().level(),
+            target: event.metadata().target().to_string(),
+            message: visitor.0,
+        });
+    }
+}
+
+/// MessageVisitor formats the message of the event followed by its fields.
+struct MessageVisitor(String);
+
+impl tracing::field::Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0.insert_str(0, &format!("{:?}", value));
+        } else {
+            self.0.push_str(&format!(" {}={:?}", field.name(), value));
+        }
+    }
+}
+
+/// Returns the last lines of the file, reading at most its last bytes.
+pub fn tail(path: &Path, max_bytes: u64) -> Result<Vec<String>, io::Error> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    file.seek(SeekFrom::Start(len.saturating_sub(max_bytes)))?;
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+    let content = String::from_utf8_lossy(&buf);
+    let mut lines = content.lines();
+    // The first line is likely cut by the seek.
+    if len > max_bytes {
+        lines.next();
+    }
+    Ok(lines.map(|l| l.to_string()).collect())
+}
+
+/// Returns the level of a line of the revaultd logs, e.g.
+/// "[2021-06-01][revaultd][INFO] Started", if it can be found.
+pub fn daemon_line_level(line: &str) -> Option<Level> {
+    [
+        Level::ERROR,
+        Level::WARN,
+        Level::INFO,
+        Level::DEBUG,
+        Level::TRACE,
+    ]
+    .iter()
+    .copied()
+    .find(|level| line.contains(&format!("[{}]", level)))
+}
+
+/// Replaces the keys, the addresses and the transaction ids of the line by
+/// a placeholder, so that the logs can be shared without the wallet.
+pub fn redact(line: &str) -> String {
+    replace_sensitive(line, |_, _| "[redacted]".to_string())
+}
+
+/// Kind of the sensitive words of a text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sensitive {
+    ExtendedKey,
+    Bech32Address,
+    /// Transaction ids, public keys, signatures and noise keys.
+    Hex,
+    Base58Address,
+    /// PSBTs and the other transactions encoded in base64.
+    Base64,
+}
+
+/// Returns true if the character is part of a base64 word but not of an
+/// alphanumeric one.
+fn is_base64_symbol(c: char) -> bool {
+    matches!(c, '+' | '/' | '=')
+}
+
+/// Replaces each sensitive word of the line by the result of the function.
+pub fn replace_sensitive(line: &str, mut f: impl FnMut(&str, Sensitive) -> String) -> String {
+    let mut replaced = String::with_capacity(line.len());
+    let mut word = String::new();
+    for c in line.chars().chain(std::iter::once(' ')) {
+        if c.is_ascii_alphanumeric() || is_base64_symbol(c) {
+            word.push(c);
+            continue;
+        }
+        match sensitive(&word) {
+            Some(kind) => replaced.push_str(&f(&word, kind)),
+            // The symbols of a word which is not base64 are separators.
+            None => {
+                let mut part = String::new();
+                for c in word.chars().chain(std::iter::once(' ')) {
+                    if c.is_ascii_alphanumeric() {
+                        part.push(c);
+                        continue;
+                    }
+                    match sensitive(&part) {
+                        Some(kind) => replaced.push_str(&f(&part, kind)),
+                        None => replaced.push_str(&part),
+                    }
+                    part.clear();
+                    replaced.push(c);
+                }
+                replaced.pop();
+            }
+        }
+        word.clear();
+        replaced.push(c);
+    }
+    replaced.pop();
+    replaced
+}
+
+fn sensitive(word: &str) -> Option<Sensitive> {
+    if word.contains(is_base64_symbol) {
+        return if is_base64(word) {
+            Some(Sensitive::Base64)
+        } else {
+            None
+        };
+    }
+    let lower = word.to_lowercase();
+    if ["xpub", "xprv", "tpub", "tprv"]
+        .iter()
+        .any(|p| lower.starts_with(p))
+        && word.len() > 100
+    {
+        Some(Sensitive::ExtendedKey)
+    } else if ["bc1", "tb1", "bcrt1"].iter().any(|p| lower.starts_with(p)) && word.len() >= 26 {
+        Some(Sensitive::Bech32Address)
+    } else if word.len() >= 64 && word.chars().all(|c| c.is_ascii_hexdigit()) {
+        Some(Sensitive::Hex)
+    } else if (26..=35).contains(&word.len())
+        && ["1", "3", "2", "m", "n"]
+            .iter()
+            .any(|p| word.starts_with(p))
+        && word.chars().any(|c| c.is_ascii_digit())
+        && word.chars().any(|c| c.is_ascii_alphabetic())
+        && !word.contains(['0', 'O', 'I', 'l'])
+    {
+        Some(Sensitive::Base58Address)
+    } else if is_base64(word) {
+        Some(Sensitive::Base64)
+    } else {
+        None
+    }
+}
+
+/// Returns true if the word is a long run of base64, the padding at the end.
+fn is_base64(word: &str) -> bool {
+    let data = word.trim_end_matches('=');
+    word.len() >= 64
+        && word.len() - data.len() <= 2
+        && data
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redact_sensitive() {
+        let psbt = "cHNidP8BAHUCAAAAASaBcTce3/KF6Tet7qSze3gADAVmy7OtZGQXE8pCFxv2AAAAAAD+////\
+                    AtPf9QUAAAAAGXapFNDFmQPFusKGh2DpD9UhpGZap2UgiKwA4fUFAAAAABepFDVF5uM7gyxHBQ8k\
+                    0+65PJwDlIvHh7MuEwAAAQA=";
+        assert_eq!(
+            redact(&format!("Signed psbt: {}", psbt)),
+            "Signed psbt: [redacted]"
+        );
+
+        let xpub = "xpub6DEzq5DNPx2rPiZJ7wvFhxRKUKDoV1GwjFmFdaxFfbsw9HsHyxc9usoRUMxqJaMrwoXh4\
+                    apahsGEnjAS4cVCBDgqsx5Groww22AdHbgxVDg";
+        assert_eq!(
+            redact(&format!("stakeholder xpub {}.", xpub)),
+            "stakeholder xpub [redacted]."
+        );
+
+        let outpoint = "8c6d08a2a7d3b36a4d7c2b2c4d0b3a82a2f5a8c0d8e3e3b1f7e4b9f0c1a2d3e4:1";
+        assert_eq!(
+            redact(&format!("vault {} unvaulting", outpoint)),
+            "vault [redacted]:1 unvaulting"
+        );
+
+        assert_eq!(
+            redact("deposit to bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq received"),
+            "deposit to [redacted] received"
+        );
+    }
+
+    #[test]
+    fn redact_keeps_plain_text() {
+        let line = "Vault of 0.5 BTC at height 700000, feerate=12 sat/vB, path /tmp/revault";
+        assert_eq!(redact(line), line);
+    }
+}
@@ -1,76 +1,128 @@
 use std::error::Error;
 use std::path::PathBuf;
+use std::str::FromStr;
 
-use iced::{executor, Application, Clipboard, Command, Element, Settings, Subscription};
-use tracing_subscriber::filter::EnvFilter;
+use iced::{executor, Application, Clipboard, Color, Command, Element, Settings, Subscription};
 extern crate serde;
 extern crate serde_json;
 
 mod app;
+mod bitcoind;
 mod conversion;
+mod debounce;
+mod descriptor;
+mod disk;
+mod i18n;
 mod installer;
+mod keys;
+mod launcher;
+mod logs;
+mod net;
+mod noise;
+mod notify;
+mod participant;
+mod price;
 mod revault;
 mod revaultd;
+mod screenshot;
+mod smtp;
 mod ui;
 
 use app::{
-    config::{ConfigError, DEFAULT_FILE_NAME},
+    config::{ConfigError, WindowSize, DEFAULT_FILE_NAME},
     App,
 };
 use installer::Installer;
+use launcher::Launcher;
+use logs::LogLevel;
 use revaultd::config::default_datadir;
 
-enum Args {
-    ConfigPath(PathBuf),
-    DatadirPath(PathBuf),
-    None,
+const USAGE: &str = "Usage: revault-gui [OPTIONS]
+
+Options:
+  --conf <path>        GUI configuration file to run
+  --datadir <path>     revaultd datadir, the installer starts if it has no configuration
+  --network <network>  network of the configuration of the datadir: bitcoin, testnet
+                       or regtest. Defaults to bitcoin
+  --debug              log at the debug level and enable the debug mode of the interface
+  --help               print this message";
+
+/// Arguments of the command line, they override the discovery of the
+/// configuration in the default datadir.
+#[derive(Default)]
+struct Args {
+    conf: Option<PathBuf>,
+    datadir: Option<PathBuf>,
+    network: Option<bitcoin::Network>,
+    debug: bool,
+    #[cfg(feature = "descriptor-vectors")]
+    descriptor_vectors: Option<(usize, u64)>,
 }
 
 fn parse_args(args: Vec<String>) -> Result<Args, Box<dyn Error>> {
-    if args.len() == 1 {
-        return Ok(Args::None);
-    }
-
-    if args.len() == 3 {
-        if args[1] == "--conf" {
-            return Ok(Args::ConfigPath(PathBuf::from(args[2].to_owned())));
-        } else if args[1] == "--datadir" {
-            return Ok(Args::DatadirPath(PathBuf::from(args[2].to_owned())));
+    let mut parsed = Args::default();
+    let mut args = args.into_iter().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| format!("Missing value of '{}'.", name))
+        };
+        match arg.as_str() {
+            "--conf" => parsed.conf = Some(PathBuf::from(value("--conf")?)),
+            "--datadir" => parsed.datadir = Some(PathBuf::from(value("--datadir")?)),
+            "--network" => {
+                let network = value("--network")?;
+                parsed.network = Some(
+                    bitcoin::Network::from_str(&network)
+                        .map_err(|_| format!("Unknown network '{}'.", network))?,
+                );
+            }
+            "--debug" => parsed.debug = true,
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            #[cfg(feature = "descriptor-vectors")]
+            "--descriptor-vectors" => {
+                let runs = value("--descriptor-vectors")?.parse()?;
+                let seed = args.next().map(|s| s.parse()).transpose()?.unwrap_or(0);
+                parsed.descriptor_vectors = Some((runs, seed));
+            }
+            _ => return Err(format!("Unknown argument '{}'.", arg).into()),
         }
     }
-
-    println!("Usage:\n'--conf <configuration file path>'\n'--datadir <datadir path>'");
-    Err(format!("Unknown arguments '{:?}'.", args).into())
+    if parsed.conf.is_some() && (parsed.datadir.is_some() || parsed.network.is_some()) {
+        return Err("'--conf' cannot be used with '--datadir' or '--network'.".into());
+    }
+    Ok(parsed)
 }
 
-fn log_level_from_config(config: &app::Config) -> Result<EnvFilter, Box<dyn Error>> {
+fn log_level_from_config(config: &app::Config) -> Result<LogLevel, Box<dyn Error>> {
     if let Some(level) = &config.log_level {
-        match level.as_ref() {
-            "info" => EnvFilter::try_new("revault_gui=info").map_err(|e| e.into()),
-            "debug" => EnvFilter::try_new("revault_gui=debug").map_err(|e| e.into()),
-            "trace" => EnvFilter::try_new("revault_gui=trace").map_err(|e| e.into()),
-            _ => Err(format!("Unknown loglevel '{:?}'.", level).into()),
-        }
+        LogLevel::from_name(level).ok_or_else(|| format!("Unknown loglevel '{:?}'.", level).into())
     } else if let Some(true) = config.debug {
-        EnvFilter::try_new("revault_gui=debug").map_err(|e| e.into())
+        Ok(LogLevel::Debug)
     } else {
-        EnvFilter::try_new("revault_gui=info").map_err(|e| e.into())
+        Ok(LogLevel::Info)
     }
 }
 
 pub enum GUI {
+    Launcher(Launcher),
     Installer(Installer),
     App(App),
 }
 
 #[derive(Debug, Clone)]
 pub enum Message {
+    Launch(launcher::Message),
     Install(installer::Message),
     Run(app::Message),
 }
 
 pub enum Config {
-    Run(app::Config),
+    Launch(Box<Launcher>),
+    Run(Box<app::Config>),
     Install(PathBuf),
 }
 
@@ -81,19 +133,22 @@ impl Application for GUI {
 
     fn title(&self) -> String {
         match self {
+            Self::Launcher(_) => String::from("Revault GUI"),
             Self::Installer(_) => String::from("Revault Installer"),
+            Self::App(app) if app.is_training() => String::from("Revault GUI - Training"),
             Self::App(_) => String::from("Revault GUI"),
         }
     }
 
     fn new(config: Config) -> (GUI, Command<Self::Message>) {
         match config {
+            Config::Launch(launcher) => (GUI::Launcher(*launcher), Command::none()),
             Config::Install(path) => {
                 let (install, command) = Installer::new(path);
                 (GUI::Installer(install), command.map(Message::Install))
             }
             Config::Run(cfg) => {
-                let (application, command) = App::new(cfg);
+                let (application, command) = App::new(*cfg);
                 (GUI::App(application), command.map(Message::Run))
             }
         }
@@ -110,7 +165,27 @@ impl Application for GUI {
             *self = GUI::App(application);
             return command.map(Message::Run);
         }
+        if let Message::Run(app::Message::OpenLauncher) = message {
+            *self = GUI::Launcher(Launcher::new());
+            return Command::none();
+        }
+        if let (Self::Launcher(l), Message::Launch(launcher::Message::Open(path))) =
+            (&mut *self, &message)
+        {
+            match app::Config::from_file(path) {
+                Ok(cfg) => {
+                    let (application, command) = App::new(cfg);
+                    *self = GUI::App(application);
+                    return command.map(Message::Run);
+                }
+                Err(e) => {
+                    l.failed(path, &e);
+                    return Command::none();
+                }
+            }
+        }
         match (self, message) {
+            (Self::Launcher(l), Message::Launch(msg)) => l.update(msg).map(Message::Launch),
             (Self::Installer(i), Message::Install(msg)) => {
                 i.update(msg, clipboard).map(Message::Install)
             }
@@ -121,13 +196,19 @@ impl Application for GUI {
 
     fn subscription(&self) -> Subscription<Self::Message> {
         match self {
+            Self::Launcher(_) => Subscription::none(),
             Self::Installer(v) => v.subscription().map(Message::Install),
             Self::App(v) => v.subscription().map(Message::Run),
         }
     }
 
+    fn background_color(&self) -> Color {
+        ui::theme::palette().background
+    }
+
     fn view(&mut self) -> Element<Self::Message> {
         match self {
+            Self::Launcher(v) => v.view().map(Message::Launch),
             Self::Installer(v) => v.view().map(Message::Install),
             Self::App(v) => v.view().map(Message::Run),
         }
@@ -137,51 +218,141 @@ impl Application for GUI {
 fn main() -> Result<(), Box<dyn Error>> {
     let args = std::env::args().collect();
 
-    let config = match parse_args(args)? {
-        Args::ConfigPath(path) => Config::Run(app::Config::from_file(&path)?),
-        Args::None => {
-            let path = app::Config::default_path()
-                .map_err(|e| format!("Failed to find revault GUI config: {}", e))?;
-
-            match app::Config::from_file(&path) {
-                Ok(cfg) => Config::Run(cfg),
-                Err(ConfigError::NotFound) => {
-                    let default_datadir_path =
-                        default_datadir().expect("Unexpected filesystem error");
-                    Config::Install(default_datadir_path)
-                }
-                Err(e) => {
-                    return Err(format!("Failed to read configuration file: {}", e).into());
-                }
+    let args = parse_args(args).inspect_err(|_| eprintln!("{}", USAGE))?;
+
+    #[cfg(feature = "descriptor-vectors")]
+    if let Some((runs, seed)) = args.descriptor_vectors {
+        return installer::vectors::run(runs, seed).map_err(|e| e.into());
+    }
+
+    let mut config = if let Some(path) = args.conf {
+        Config::Run(Box::new(app::Config::from_file(&path)?))
+    } else if args.datadir.is_some() || args.network.is_some() {
+        let datadir_path = match args.datadir {
+            Some(path) => path,
+            None => default_datadir().expect("Unexpected filesystem error"),
+        };
+        let path = datadir_path.join(installer::append_network_suffix(
+            DEFAULT_FILE_NAME,
+            &args.network.unwrap_or(bitcoin::Network::Bitcoin),
+        ));
+        match app::Config::from_file(&path) {
+            Ok(cfg) => Config::Run(Box::new(cfg)),
+            Err(ConfigError::NotFound) => Config::Install(datadir_path),
+            Err(e) => {
+                return Err(format!("Failed to read configuration file: {}", e).into());
             }
         }
-        Args::DatadirPath(datadir_path) => {
-            let mut path = datadir_path.clone();
-            path.push(DEFAULT_FILE_NAME);
-            match app::Config::from_file(&path) {
-                Ok(cfg) => Config::Run(cfg),
-                Err(ConfigError::NotFound) => Config::Install(datadir_path),
-                Err(e) => {
-                    return Err(format!("Failed to read configuration file: {}", e).into());
-                }
+    } else {
+        let path = app::Config::default_path()
+            .map_err(|e| format!("Failed to find revault GUI config: {}", e))?;
+        let launcher = Launcher::new();
+
+        match app::Config::from_file(&path) {
+            _ if launcher.has_choice() => Config::Launch(Box::new(launcher)),
+            Ok(cfg) => Config::Run(Box::new(cfg)),
+            Err(ConfigError::NotFound) => {
+                let default_datadir_path = default_datadir().expect("Unexpected filesystem error");
+                Config::Install(default_datadir_path)
+            }
+            Err(e) => {
+                return Err(format!("Failed to read configuration file: {}", e).into());
             }
         }
     };
 
-    let level = if let Config::Run(cfg) = &config {
-        log_level_from_config(&cfg)?
+    // The flag overrides the log level of the configuration.
+    if let (true, Config::Run(cfg)) = (args.debug, &mut config) {
+        cfg.debug = Some(true);
+        cfg.log_level = None;
+    }
+
+    let (level, log_dir) = if let Config::Run(cfg) = &config {
+        (log_level_from_config(&cfg)?, cfg.log_dir())
     } else {
-        EnvFilter::try_new("revault_gui=info").unwrap()
+        (LogLevel::Info, None)
     };
 
-    tracing::subscriber::set_global_default(
-        tracing_subscriber::FmtSubscriber::builder()
-            .with_env_filter(level)
-            .finish(),
-    )?;
+    if let Some(e) = logs::init(level, log_dir)? {
+        tracing::error!("Failed to open the log files: {}", e);
+    }
 
-    if let Err(e) = GUI::run(Settings::with_flags(config)) {
+    let window_size = match &config {
+        Config::Run(cfg) => cfg.window_size,
+        _ => None,
+    };
+
+    if let Config::Run(cfg) = &config {
+        for e in ui::font::init(cfg.font.as_deref(), cfg.font_bold.as_deref(), cfg.text_size) {
+            tracing::error!(
+                "Failed to load the configured font, using the default one: {}",
+                e
+            );
+        }
+    }
+
+    let mut settings = Settings::with_flags(config);
+    settings.default_text_size = ui::font::text_size();
+    if let Some(size) = window_size.map(WindowSize::clamped) {
+        settings.window.size = (size.width, size.height);
+    }
+    if let Err(e) = GUI::run(settings) {
         return Err(format!("Failed to launch UI: {}", e).into());
     };
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, Box<dyn Error>> {
+        let mut all = vec!["revault-gui".to_string()];
+        all.extend(args.iter().map(|a| a.to_string()));
+        parse_args(all)
+    }
+
+    #[test]
+    fn parse_args_in_any_order() {
+        let args = parse(&[
+            "--network",
+            "testnet",
+            "--debug",
+            "--datadir",
+            "/tmp/revault",
+        ])
+        .unwrap();
+        assert_eq!(args.datadir, Some(PathBuf::from("/tmp/revault")));
+        assert_eq!(args.network, Some(bitcoin::Network::Testnet));
+        assert!(args.debug);
+        assert!(args.conf.is_none());
+
+        let args = parse(&["--debug", "--conf", "/tmp/gui.toml"]).unwrap();
+        assert_eq!(args.conf, Some(PathBuf::from("/tmp/gui.toml")));
+        assert!(args.debug);
+
+        let args = parse(&[]).unwrap();
+        assert!(args.conf.is_none() && args.datadir.is_none() && args.network.is_none());
+        assert!(!args.debug);
+    }
+
+    #[test]
+    fn parse_args_missing_values() {
+        assert!(parse(&["--conf"]).is_err());
+        assert!(parse(&["--datadir"]).is_err());
+        assert!(parse(&["--debug", "--network"]).is_err());
+    }
+
+    #[test]
+    fn parse_args_invalid() {
+        assert!(parse(&["--network", "signet"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+        assert!(parse(&["/tmp/gui.toml"]).is_err());
+    }
+
+    #[test]
+    fn parse_args_conf_conflicts() {
+        assert!(parse(&["--conf", "/tmp/gui.toml", "--datadir", "/tmp/revault"]).is_err());
+        assert!(parse(&["--network", "regtest", "--conf", "/tmp/gui.toml"]).is_err());
+    }
+}
@@ -0,0 +1,164 @@
+//! Reachability of the servers of the deployment: a TCP connection is
+//! opened to the server, through the SOCKS5 proxy of the health checks if
+//! any.
+//! The noise handshake is left to revaultd, a reachable server may still
+//! reject the key of the daemon.
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+
+use iced_native::futures::channel::oneshot;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum number of pings run at the same time.
+const MAX_PARALLEL_PINGS: usize = 4;
+
+#[derive(Debug, Clone)]
+pub enum PingError {
+    Address(String),
+    Connection(String),
+    Proxy(String),
+}
+
+impl std::fmt::Display for PingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Address(e) => write!(f, "Invalid server address: {}", e),
+            Self::Connection(e) => write!(f, "Failed to connect to the server: {}", e),
+            Self::Proxy(e) => write!(f, "Proxy error: {}", e),
+        }
+    }
+}
+
+/// ServerHealth is the last known state of a server.
+#[derive(Debug, Clone)]
+pub struct ServerHealth {
+    pub host: String,
+    /// Result of the last ping, None until it returns.
+    pub latency: Option<Result<Duration, PingError>>,
+    /// Unix timestamp of the last successful ping.
+    pub last_seen: Option<i64>,
+}
+
+impl ServerHealth {
+    pub fn new(host: String) -> Self {
+        Self {
+            host,
+            latency: None,
+            last_seen: None,
+        }
+    }
+
+    pub fn record(&mut self, res: Result<Duration, PingError>, now: i64) {
+        if res.is_ok() {
+            self.last_seen = Some(now);
+        }
+        self.latency = Some(res);
+    }
+}
+
+/// Returns the time to open a connection to the host.
+pub fn ping(host: &str, proxy: Option<&SocketAddr>) -> Result<Duration, PingError> {
+    let start = Instant::now();
+    match proxy {
+        Some(proxy) => socks5_connect(proxy, host)?,
+        None => {
+            let addr = host
+                .to_socket_addrs()
+                .map_err(|e| PingError::Address(e.to_string()))?
+                .next()
+                .ok_or_else(|| PingError::Address(format!("{} does not resolve", host)))?;
+            TcpStream::connect_timeout(&addr, TIMEOUT)
+                .map_err(|e| PingError::Connection(e.to_string()))?;
+        }
+    }
+    Ok(start.elapsed())
+}
+
+/// Returns the time to open a connection to each host, in the order of the
+/// hosts. The pings run on their own threads, at most MAX_PARALLEL_PINGS at
+/// a time, so that the executor of the commands does not wait for them.
+pub async fn probe_all(
+    hosts: Vec<String>,
+    proxy: Option<SocketAddr>,
+) -> Vec<Result<Duration, PingError>> {
+    let (sender, receiver) = oneshot::channel();
+    std::thread::spawn(move || {
+        let mut results = Vec::with_capacity(hosts.len());
+        for chunk in hosts.chunks(MAX_PARALLEL_PINGS) {
+            std::thread::scope(|scope| {
+                let pings: Vec<_> = chunk
+                    .iter()
+                    .map(|host| scope.spawn(move || ping(host, proxy.as_ref())))
+                    .collect();
+                for ping in pings {
+                    results.push(ping.join().unwrap_or_else(|_| {
+                        Err(PingError::Connection("the ping panicked".to_string()))
+                    }));
+                }
+            });
+        }
+        let _ = sender.send(results);
+    });
+    receiver.await.unwrap_or_default()
+}
+
+/// Returns the time to open a connection to the host, see probe_all.
+pub async fn probe(host: String, proxy: Option<SocketAddr>) -> Result<Duration, PingError> {
+    probe_all(vec![host], proxy)
+        .await
+        .pop()
+        .unwrap_or_else(|| Err(PingError::Connection("the ping was dropped".to_string())))
+}
+
+/// Opens a connection to the host through the proxy, the host is resolved
+/// by the proxy so that hidden services can be reached.
+fn socks5_connect(proxy: &SocketAddr, host: &str) -> Result<(), PingError> {
+    let (name, port) = host
+        .rsplit_once(':')
+        .and_then(|(name, port)| port.parse::<u16>().ok().map(|port| (name, port)))
+        .ok_or_else(|| PingError::Address(format!("{} is not a host:port address", host)))?;
+    if name.is_empty() || name.len() > 255 {
+        return Err(PingError::Address(format!("{} is not a valid host", name)));
+    }
+
+    let mut stream = TcpStream::connect_timeout(proxy, TIMEOUT)
+        .map_err(|e| PingError::Proxy(format!("Failed to connect to {}: {}", proxy, e)))?;
+    stream
+        .set_read_timeout(Some(TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(TIMEOUT)))
+        .map_err(|e| PingError::Proxy(e.to_string()))?;
+
+    // Version 5, a single authentication method: none.
+    let mut reply = [0; 2];
+    stream
+        .write_all(&[5, 1, 0])
+        .and_then(|_| stream.read_exact(&mut reply))
+        .map_err(|e| PingError::Proxy(e.to_string()))?;
+    if reply != [5, 0] {
+        return Err(PingError::Proxy(
+            "the proxy requires an authentication".to_string(),
+        ));
+    }
+
+    // CONNECT to a domain name.
+    let mut request = vec![5, 1, 0, 3, name.len() as u8];
+    request.extend_from_slice(name.as_bytes());
+    request.extend_from_slice(&port.to_be_bytes());
+    let mut reply = [0; 4];
+    stream
+        .write_all(&request)
+        .and_then(|_| stream.read_exact(&mut reply))
+        .map_err(|e| PingError::Proxy(e.to_string()))?;
+    match reply[1] {
+        0 => Ok(()),
+        3 | 4 => Err(PingError::Connection("host unreachable".to_string())),
+        5 => Err(PingError::Connection("connection refused".to_string())),
+        code => Err(PingError::Proxy(format!(
+            "the proxy failed with code {}",
+            code
+        ))),
+    }
+}
@@ -0,0 +1,282 @@
+//! Noise static key of revaultd, authenticating the daemon to the coordinator
+//! and to the other servers of the deployment. The key is the 32 bytes of the
+//! `noise_secret` file of the revaultd network datadir, its public key is the
+//! X25519 public key the servers are configured with.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use bitcoin::hashes::{hex::FromHex, sha256, Hash};
+use curve25519_dalek::montgomery::MontgomeryPoint;
+use x25519_dalek::{x25519, X25519_BASEPOINT_BYTES};
+
+/// Name of the key file in the revaultd network datadir.
+pub const KEY_FILE_NAME: &str = "noise_secret";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NoiseKeyError {
+    Io(String),
+    /// The file is not 32 bytes long.
+    InvalidLength(usize),
+    /// The file can be read by other users than its owner.
+    Permissions(u32),
+    Random(String),
+}
+
+impl std::fmt::Display for NoiseKeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::InvalidLength(len) => {
+                write!(f, "the key file is {} bytes long instead of 32", len)
+            }
+            Self::Permissions(mode) => write!(
+                f,
+                "the key file permissions are {:o}, it must only be readable by its owner (600)",
+                mode
+            ),
+            Self::Random(e) => write!(f, "failed to generate a key: {}", e),
+        }
+    }
+}
+
+/// Error of a public key of a server typed by the user.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PublicKeyError {
+    Hex,
+    /// The key is not 32 bytes long.
+    InvalidLength(usize),
+    /// The key is not the canonical encoding of a curve point.
+    NonCanonical,
+    /// The point has a small order, the shared secret would be predictable.
+    LowOrder,
+    /// The point is on the quadratic twist of the curve.
+    Twist,
+}
+
+impl std::fmt::Display for PublicKeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Hex => write!(f, "the key must be hex encoded"),
+            Self::InvalidLength(len) => {
+                write!(f, "the key is {} bytes long instead of 32", len)
+            }
+            Self::NonCanonical => write!(f, "the key is not a valid curve point"),
+            Self::LowOrder => write!(f, "the key is a low order curve point"),
+            Self::Twist => write!(f, "the key is not a point of Curve25519"),
+        }
+    }
+}
+
+/// KeyFile is the state of a key file on disk.
+#[derive(Debug, Clone)]
+pub struct KeyFile {
+    pub path: PathBuf,
+    /// Hex of the public key, or the error reading the file.
+    pub public_key: Result<String, NoiseKeyError>,
+    pub permissions: Result<(), NoiseKeyError>,
+}
+
+impl KeyFile {
+    pub fn inspect(path: PathBuf) -> Self {
+        Self {
+            public_key: read(&path).map(|key| public_key_hex(&key)),
+            permissions: check_permissions(&path),
+            path,
+        }
+    }
+
+    pub fn exists(&self) -> bool {
+        self.path.exists()
+    }
+}
+
+pub fn generate() -> Result<[u8; 32], NoiseKeyError> {
+    let mut key = [0; 32];
+    getrandom::getrandom(&mut key).map_err(|e| NoiseKeyError::Random(e.to_string()))?;
+    Ok(key)
+}
+
+pub fn read(path: &Path) -> Result<[u8; 32], NoiseKeyError> {
+    let bytes =
+        std::fs::read(path).map_err(|e| NoiseKeyError::Io(format!("{}: {}", path.display(), e)))?;
+    if bytes.len() != 32 {
+        return Err(NoiseKeyError::InvalidLength(bytes.len()));
+    }
+    let mut key = [0; 32];
+    key.copy_from_slice(&bytes);
+    Ok(key)
+}
+
+/// Writes the key to a file only readable by its owner.
+pub fn write(path: &Path, key: &[u8; 32]) -> Result<(), NoiseKeyError> {
+    let io_error = |e: std::io::Error| NoiseKeyError::Io(format!("{}: {}", path.display(), e));
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path).map_err(io_error)?;
+    file.write_all(key).map_err(io_error)?;
+    // The mode of the options only applies to a new file.
+    restrict_permissions(path)
+}
+
+/// Checks that the file is only readable by its owner.
+pub fn check_permissions(path: &Path) -> Result<(), NoiseKeyError> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(path)
+            .map_err(|e| NoiseKeyError::Io(format!("{}: {}", path.display(), e)))?
+            .permissions()
+            .mode()
+            & 0o777;
+        if mode & 0o077 != 0 {
+            return Err(NoiseKeyError::Permissions(mode));
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+/// Makes the file only readable and writable by its owner.
+pub fn restrict_permissions(path: &Path) -> Result<(), NoiseKeyError> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+            .map_err(|e| NoiseKeyError::Io(format!("{}: {}", path.display(), e)))?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+/// Returns the hex of the public key of the noise static key.
+pub fn public_key_hex(key: &[u8; 32]) -> String {
+    public_key(key)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Parses the hex of the X25519 public key of a server.
+pub fn parse_public_key(hex: &str) -> Result<[u8; 32], PublicKeyError> {
+    let bytes = Vec::from_hex(hex).map_err(|_| PublicKeyError::Hex)?;
+    if bytes.len() != 32 {
+        return Err(PublicKeyError::InvalidLength(bytes.len()));
+    }
+    let mut key = [0; 32];
+    key.copy_from_slice(&bytes);
+    // The top bit is ignored and the value is reduced by X25519, a key
+    // must have a single encoding.
+    if !is_canonical(&key) {
+        return Err(PublicKeyError::NonCanonical);
+    }
+    // The clamped scalars are multiples of the cofactor, the product of a
+    // point of small order with any of them is the point at infinity.
+    if x25519([1; 32], key) == [0; 32] {
+        return Err(PublicKeyError::LowOrder);
+    }
+    // X25519 computes with the points of the twist as well, they have no
+    // Edwards form and are rejected as they are not the key of any server.
+    if MontgomeryPoint(key).to_edwards(0).is_none() {
+        return Err(PublicKeyError::Twist);
+    }
+    Ok(key)
+}
+
+/// Returns the fingerprint of a public key compared out-of-band by the
+/// participants: the first 8 bytes of its sha256, in groups of 4 hex digits.
+pub fn fingerprint(key: &[u8; 32]) -> String {
+    let hash = sha256::Hash::hash(key);
+    hash[..8]
+        .chunks(2)
+        .map(|group| format!("{:02X}{:02X}", group[0], group[1]))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Returns the X25519 public key of the secret key (RFC 7748).
+pub fn public_key(key: &[u8; 32]) -> [u8; 32] {
+    x25519(*key, X25519_BASEPOINT_BYTES)
+}
+
+/// Returns true if the little endian u-coordinate is below the field prime
+/// 2^255 - 19 with the top bit unset.
+fn is_canonical(key: &[u8; 32]) -> bool {
+    if key[31] & 0x80 != 0 {
+        return false;
+    }
+    // The values from the prime to 2^255 - 1 end with 30 bytes of 0xff and 0x7f.
+    !(key[0] >= 0xed && key[1..31].iter().all(|b| *b == 0xff) && key[31] == 0x7f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(hex: &str) -> [u8; 32] {
+        let mut key = [0; 32];
+        key.copy_from_slice(&Vec::from_hex(hex).unwrap());
+        key
+    }
+
+    #[test]
+    fn x25519_vectors() {
+        // RFC 7748, section 6.1.
+        let alice = key("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob = key("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+        let alice_public = key("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a");
+        let bob_public = key("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f");
+
+        assert_eq!(public_key(&alice), alice_public);
+        assert_eq!(public_key(&bob), bob_public);
+        assert_eq!(
+            public_key_hex(&alice),
+            "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+        );
+    }
+
+    #[test]
+    fn parse_valid_public_key() {
+        let hex = "de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f";
+        assert_eq!(parse_public_key(hex), Ok(key(hex)));
+    }
+
+    #[test]
+    fn parse_invalid_public_keys() {
+        assert_eq!(parse_public_key("not hex"), Err(PublicKeyError::Hex));
+        assert_eq!(
+            parse_public_key("de9edb7d"),
+            Err(PublicKeyError::InvalidLength(4))
+        );
+        // The field prime and the basepoint with the top bit set.
+        for hex in &[
+            "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "0900000000000000000000000000000000000000000000000000000000000080",
+        ] {
+            assert_eq!(parse_public_key(hex), Err(PublicKeyError::NonCanonical));
+        }
+        // Points of order 1, 4, 2 and 8.
+        for hex in &[
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "e0eb7a7c3b41b8ae1656e3faf19fc46ada098deb9c32b1fd866205165f49b800",
+        ] {
+            assert_eq!(parse_public_key(hex), Err(PublicKeyError::LowOrder));
+        }
+        // u = 2 and u = 3 are on the twist.
+        for hex in &[
+            "0200000000000000000000000000000000000000000000000000000000000000",
+            "0300000000000000000000000000000000000000000000000000000000000000",
+        ] {
+            assert_eq!(parse_public_key(hex), Err(PublicKeyError::Twist));
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Desktop notifications, sent with the notification tool of the
+//! operating system so that they are displayed while the GUI is hidden.
+
+/// Displays the notification, the error is the one of the tool.
+pub fn desktop(title: &str, body: &str) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    let mut command = {
+        let mut command = std::process::Command::new("osascript");
+        command.arg("-e").arg(format!(
+            "display notification {:?} with title {:?}",
+            body, title
+        ));
+        command
+    };
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut command = std::process::Command::new("powershell");
+        command.arg("-NoProfile").arg("-Command").arg(format!(
+            "[void][System.Reflection.Assembly]::LoadWithPartialName('System.Windows.Forms'); \
+            $n = New-Object System.Windows.Forms.NotifyIcon; \
+            $n.Icon = [System.Drawing.SystemIcons]::Information; $n.Visible = $true; \
+            $n.ShowBalloonTip(10000, '{}', '{}', 'Warning'); Start-Sleep -Seconds 10",
+            title.replace('\'', "''"),
+            body.replace('\'', "''")
+        ));
+        command
+    };
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let mut command = {
+        let mut command = std::process::Command::new("notify-send");
+        command
+            .arg("--app-name=Revault GUI")
+            .arg("--urgency=critical")
+            .arg(title)
+            .arg(body);
+        command
+    };
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("Failed to send the desktop notification: {}", e))?;
+    // The tool is waited on apart from the GUI, the process would stay a
+    // zombie until the GUI exits otherwise.
+    std::thread::spawn(move || {
+        if let Err(e) = child.wait() {
+            tracing::warn!("Failed to wait on the desktop notification: {}", e);
+        }
+    });
+    Ok(())
+}
@@ -0,0 +1,118 @@
+//! Participants of the deployment, identified by the fingerprint of their
+//! key in the descriptors.
+
+use std::str::FromStr;
+
+use bitcoin::{
+    blockdata::script::Instruction, util::bip32::Fingerprint,
+    util::psbt::PartiallySignedTransaction as Psbt, PublicKey,
+};
+use revault_tx::{
+    miniscript::descriptor::DescriptorPublicKey,
+    scripts::{DepositDescriptor, UnvaultDescriptor},
+};
+
+use crate::revault::Role;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Participant {
+    pub fingerprint: Fingerprint,
+    /// None for a cosigning server.
+    pub role: Option<Role>,
+}
+
+/// Returns the stakeholders of the deposit descriptor,
+/// empty if the descriptor is not valid.
+pub fn stakeholders(deposit_descriptor: &str) -> Vec<Participant> {
+    DepositDescriptor::from_str(deposit_descriptor)
+        .map(|desc| {
+            desc.xpubs()
+                .iter()
+                .map(|key| Participant {
+                    fingerprint: fingerprint(key),
+                    role: Some(Role::Stakeholder),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the keys of the unvault descriptor in the order of the script:
+/// the stakeholders are the keys of the deposit descriptor, the managers the
+/// other xpubs and the cosigners the single keys.
+pub fn unvault_participants(
+    deposit_descriptor: &str,
+    unvault_descriptor: &str,
+) -> Vec<Participant> {
+    let stakeholders = stakeholders(deposit_descriptor);
+    UnvaultDescriptor::from_str(unvault_descriptor)
+        .map(|desc| {
+            desc.xpubs()
+                .iter()
+                .map(|key| {
+                    let fingerprint = fingerprint(key);
+                    let role = match key {
+                        DescriptorPublicKey::SinglePub(_) => None,
+                        DescriptorPublicKey::XPub(_)
+                            if stakeholders.iter().any(|s| s.fingerprint == fingerprint) =>
+                        {
+                            Some(Role::Stakeholder)
+                        }
+                        DescriptorPublicKey::XPub(_) => Some(Role::Manager),
+                    };
+                    Participant { fingerprint, role }
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// revault_tx depends on another version of the bitcoin crate.
+fn fingerprint(key: &DescriptorPublicKey) -> Fingerprint {
+    Fingerprint::from(&key.master_fingerprint()[..])
+}
+
+/// Returns true if the participant of the fingerprint did not sign the psbt.
+pub fn awaits_signature(
+    psbt: &Psbt,
+    participants: &[Participant],
+    fingerprint: Fingerprint,
+) -> bool {
+    signatures(psbt, participants)
+        .iter()
+        .any(|(participant, signed)| participant.fingerprint == fingerprint && !signed)
+}
+
+/// Returns for every participant if it signed every input of the psbt.
+/// The keys of the witness script of an input are in the order of the
+/// participants of its descriptor.
+pub fn signatures<'a>(
+    psbt: &Psbt,
+    participants: &'a [Participant],
+) -> Vec<(&'a Participant, bool)> {
+    let mut signed = vec![!psbt.inputs.is_empty(); participants.len()];
+    for input in &psbt.inputs {
+        let keys: Vec<PublicKey> = input
+            .witness_script
+            .as_ref()
+            .map(|script| {
+                script
+                    .instructions()
+                    .filter_map(|instruction| match instruction {
+                        Ok(Instruction::PushBytes(bytes)) if bytes.len() == 33 => {
+                            PublicKey::from_slice(bytes).ok()
+                        }
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        for (i, signed) in signed.iter_mut().enumerate() {
+            *signed &= keys
+                .get(i)
+                .map(|key| input.partial_sigs.contains_key(key))
+                .unwrap_or(false);
+        }
+    }
+    participants.iter().zip(signed).collect()
+}
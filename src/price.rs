@@ -0,0 +1,140 @@
+//! Minimal client of the price feed, fetching the BTC/USD and BTC/EUR rates
+//! from the provider configured by the user. The feed is disabled by default.
+
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Number of seconds a fetched rate is kept before being refreshed.
+pub const CACHE_SECS: i64 = 10 * 60;
+
+#[derive(Debug, Clone)]
+pub enum PriceError {
+    Url(String),
+    Connection(String),
+    Response(String),
+}
+
+impl std::fmt::Display for PriceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Url(e) => write!(f, "Invalid price feed url: {}", e),
+            Self::Connection(e) => write!(f, "Failed to connect to the price feed: {}", e),
+            Self::Response(e) => write!(f, "Unexpected price feed response: {}", e),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum FiatCurrency {
+    #[default]
+    Usd,
+    Eur,
+}
+
+impl std::fmt::Display for FiatCurrency {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Usd => write!(f, "USD"),
+            Self::Eur => write!(f, "EUR"),
+        }
+    }
+}
+
+/// Rates are the prices of one bitcoin.
+#[derive(Debug, Clone)]
+pub struct Rates {
+    pub usd: Option<f64>,
+    pub eur: Option<f64>,
+    /// Unix timestamp of the fetch.
+    pub fetched_at: i64,
+}
+
+impl Rates {
+    pub fn get(&self, currency: FiatCurrency) -> Option<f64> {
+        match currency {
+            FiatCurrency::Usd => self.usd,
+            FiatCurrency::Eur => self.eur,
+        }
+    }
+
+    pub fn is_stale(&self, now: i64) -> bool {
+        now - self.fetched_at >= CACHE_SECS
+    }
+}
+
+/// Fetches the rates from the provider url. The provider answers a json
+/// object with a price by currency code, either as a number
+/// (`{"USD": 30000.5}`) or as a ticker with a `last` field
+/// (`{"USD": {"last": 30000.5}}`).
+/// Only plain http urls are supported, a local proxy can be used for
+/// providers requiring https.
+pub fn fetch_rates(url: &str, now: i64) -> Result<Rates, PriceError> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| PriceError::Url("only http:// urls are supported".to_string()))?;
+    let (host, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+    let addr = if host.contains(':') {
+        host.to_string()
+    } else {
+        format!("{}:80", host)
+    };
+    let addr = addr
+        .to_socket_addrs()
+        .map_err(|e| PriceError::Url(e.to_string()))?
+        .next()
+        .ok_or_else(|| PriceError::Url(format!("no address found for {}", host)))?;
+
+    // http/1.0 so that the body is not chunked.
+    let request = format!(
+        "GET {} HTTP/1.0\r\nHost: {}\r\nAccept: application/json\r\nConnection: close\r\n\r\n",
+        path, host
+    );
+    let mut stream = TcpStream::connect_timeout(&addr, TIMEOUT)
+        .map_err(|e| PriceError::Connection(e.to_string()))?;
+    stream
+        .set_read_timeout(Some(TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(TIMEOUT)))
+        .and_then(|_| stream.write_all(request.as_bytes()))
+        .map_err(|e| PriceError::Connection(e.to_string()))?;
+
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .map_err(|e| PriceError::Connection(e.to_string()))?;
+
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| PriceError::Response("missing http body".to_string()))?;
+    if !head.starts_with("HTTP/1.1 200") && !head.starts_with("HTTP/1.0 200") {
+        return Err(PriceError::Response(
+            head.lines().next().unwrap_or_default().to_string(),
+        ));
+    }
+
+    let json: serde_json::Value =
+        serde_json::from_str(body).map_err(|e| PriceError::Response(e.to_string()))?;
+    let price = |currency: FiatCurrency| {
+        let value = json.get(currency.to_string())?;
+        value
+            .as_f64()
+            .or_else(|| value.get("last").and_then(|last| last.as_f64()))
+    };
+    let rates = Rates {
+        usd: price(FiatCurrency::Usd),
+        eur: price(FiatCurrency::Eur),
+        fetched_at: now,
+    };
+    if rates.usd.is_none() && rates.eur.is_none() {
+        return Err(PriceError::Response("no USD or EUR price".to_string()));
+    }
+    Ok(rates)
+}
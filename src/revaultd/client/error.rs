@@ -22,17 +22,65 @@ use std::{error, fmt};
 
 use serde::{Deserialize, Serialize};
 
-#[allow(dead_code)]
-#[derive(Debug)]
-#[allow(non_camel_case_types)]
+/// Error codes of the responses of revaultd. revaultd 0.1 only answers
+/// with the standard error codes of JSON-RPC 2.0, the errors specific to
+/// the daemon are told apart by their message, see
+/// `RpcErrorCode::from_message`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RpcErrorCode {
-    // Standard errors defined by JSON-RPC 2.0 standard
     /// Invalid request
-    JSONRPC2_INVALID_REQUEST = -32600,
+    InvalidRequest,
     /// Method not found
-    JSONRPC2_METHOD_NOT_FOUND = -32601,
+    MethodNotFound,
     /// Invalid parameters
-    JSONRPC2_INVALID_PARAMS = -32602,
+    InvalidParams,
+    /// Invalid parameters: no vault at the given outpoint
+    VaultNotFound,
+    /// Invalid parameters: the vault status does not allow the command
+    InvalidStatus,
+    /// Internal error
+    Internal,
+    /// Internal error: revaultd failed to communicate with the coordinator
+    Coordinator,
+    Unknown(i32),
+}
+
+impl RpcErrorCode {
+    pub fn of(code: i32, message: &str) -> Self {
+        match code {
+            -32600 => Self::InvalidRequest,
+            -32601 => Self::MethodNotFound,
+            -32602 | -32603 => Self::from_message(code, message),
+            code => Self::Unknown(code),
+        }
+    }
+
+    /// Fallback for the errors that revaultd 0.1 does not tell apart by
+    /// their code: the unknown vaults and the invalid statuses are invalid
+    /// parameters, the failures to reach the coordinator are internal
+    /// errors. The matched words are the ones of the messages of revaultd
+    /// 0.1, any other message keeps the kind of the standard code.
+    fn from_message(code: i32, message: &str) -> Self {
+        let message = message.to_lowercase();
+        if code == -32602 {
+            if message.contains("no vault") || message.contains("unknown vault") {
+                Self::VaultNotFound
+            } else if message.contains("status") {
+                Self::InvalidStatus
+            } else {
+                Self::InvalidParams
+            }
+        } else if message.contains("coordinator") || message.contains("communication") {
+            Self::Coordinator
+        } else {
+            Self::Internal
+        }
+    }
+
+    /// Returns true if revaultd failed to communicate with the coordinator.
+    pub fn is_coordinator(&self) -> bool {
+        *self == Self::Coordinator
+    }
 }
 
 /// A library error
@@ -50,17 +98,28 @@ pub enum Error {
     NonceMismatch,
     /// Response to a request had a jsonrpc field other than "2.0"
     VersionMismatch,
+    /// The server did not answer before the timeout
+    Timeout,
 }
 
 impl From<serde_json::Error> for Error {
     fn from(e: serde_json::Error) -> Error {
-        Error::Json(e)
+        if e.is_io() {
+            Error::from(io::Error::from(e))
+        } else {
+            Error::Json(e)
+        }
     }
 }
 
 impl From<io::Error> for Error {
     fn from(e: io::Error) -> Error {
-        Error::Io(e)
+        match e.kind() {
+            // A read or a write reaching the socket timeout fails
+            // with WouldBlock on unix and TimedOut on windows.
+            io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => Error::Timeout,
+            _ => Error::Io(e),
+        }
     }
 }
 
@@ -79,6 +138,7 @@ impl fmt::Display for Error {
             Error::NoErrorOrResult => write!(f, "Malformed RPC response"),
             Error::NonceMismatch => write!(f, "Nonce of response did not match nonce of request"),
             Error::VersionMismatch => write!(f, "`jsonrpc` field set to non-\"2.0\""),
+            Error::Timeout => write!(f, "Request timed out"),
         }
     }
 }
@@ -102,3 +162,51 @@ pub struct RpcError {
     /// Additional data specific to the error
     pub data: Option<serde_json::Value>,
 }
+
+impl RpcError {
+    pub fn code(&self) -> RpcErrorCode {
+        RpcErrorCode::of(self.code, &self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_params_code() {
+        assert_eq!(
+            RpcErrorCode::of(-32602, "No vault at 'e2a5...:0'"),
+            RpcErrorCode::VaultNotFound
+        );
+        assert_eq!(
+            RpcErrorCode::of(-32602, "Unknown vault e2a5...:0"),
+            RpcErrorCode::VaultNotFound
+        );
+        assert_eq!(
+            RpcErrorCode::of(-32602, "Invalid vault status: 'funded'"),
+            RpcErrorCode::InvalidStatus
+        );
+        assert_eq!(
+            RpcErrorCode::of(-32602, "Invalid outpoint"),
+            RpcErrorCode::InvalidParams
+        );
+        // Only the invalid parameters are told apart as such.
+        assert_eq!(
+            RpcErrorCode::of(-32603, "No vault at 'e2a5...:0'"),
+            RpcErrorCode::Internal
+        );
+    }
+
+    #[test]
+    fn coordinator_code() {
+        assert!(
+            RpcErrorCode::of(-32603, "Communication error with the coordinator").is_coordinator()
+        );
+        assert!(!RpcErrorCode::of(-32603, "Database error").is_coordinator());
+        // The coordinator is only matched in the internal errors.
+        assert!(!RpcErrorCode::of(-32602, "Invalid coordinator signature").is_coordinator());
+        // Codes revaultd 0.1 does not define are not guessed.
+        assert_eq!(RpcErrorCode::of(12000, ""), RpcErrorCode::Unknown(12000));
+    }
+}
@@ -35,13 +35,32 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::{to_writer, Deserializer};
 
-use tracing::debug;
+use tracing::{debug, warn};
+
+/// Maximum of the sum of the delays before the retries of a request.
+pub const MAX_TOTAL_BACKOFF: Duration = Duration::from_secs(15);
+
+/// Retries of a request timing out, the delay before a retry doubles after
+/// each attempt. The client is blocking and the delays sleep the thread of
+/// the call: the request is no longer retried once the sum of the delays
+/// would exceed MAX_TOTAL_BACKOFF, whatever the number of retries.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub retries: u32,
+    pub backoff: Duration,
+}
+
+impl RetryPolicy {
+    pub const NONE: RetryPolicy = RetryPolicy {
+        retries: 0,
+        backoff: Duration::from_secs(0),
+    };
+}
 
 /// A handle to a remote JSONRPC server
 #[derive(Debug, Clone)]
 pub struct Client {
     sockpath: PathBuf,
-    timeout: Option<Duration>,
 }
 
 impl Client {
@@ -49,26 +68,50 @@ impl Client {
     pub fn new<P: AsRef<Path>>(sockpath: P) -> Client {
         Client {
             sockpath: sockpath.as_ref().to_path_buf(),
-            timeout: None,
         }
     }
 
-    /// Set an optional timeout for requests
-    #[allow(dead_code)]
-    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
-        self.timeout = timeout;
+    /// Sends a request to a client, retrying it according to the policy
+    /// if the server does not answer before the timeout.
+    pub fn send_request<S: Serialize + Debug, D: DeserializeOwned + Debug>(
+        &self,
+        method: &str,
+        params: Option<S>,
+        timeout: Option<Duration>,
+        retry: RetryPolicy,
+    ) -> Result<Response<D>, Error> {
+        let mut backoff = retry.backoff;
+        let mut total_backoff = Duration::from_secs(0);
+        let mut attempt = 0;
+        loop {
+            match self.send_request_once(method, params.as_ref(), timeout) {
+                Err(Error::Timeout)
+                    if attempt < retry.retries && total_backoff + backoff <= MAX_TOTAL_BACKOFF =>
+                {
+                    attempt += 1;
+                    warn!(
+                        "Request {} timed out, retrying in {:?} ({}/{})",
+                        method, backoff, attempt, retry.retries
+                    );
+                    std::thread::sleep(backoff);
+                    total_backoff += backoff;
+                    backoff *= 2;
+                }
+                res => return res,
+            }
+        }
     }
 
-    /// Sends a request to a client
-    pub fn send_request<S: Serialize + Debug, D: DeserializeOwned + Debug>(
+    fn send_request_once<S: Serialize + Debug, D: DeserializeOwned + Debug>(
         &self,
         method: &str,
         params: Option<S>,
+        timeout: Option<Duration>,
     ) -> Result<Response<D>, Error> {
         // Setup connection
         let mut stream = UnixStream::connect(&self.sockpath)?;
-        stream.set_read_timeout(self.timeout)?;
-        stream.set_write_timeout(self.timeout)?;
+        stream.set_read_timeout(timeout)?;
+        stream.set_write_timeout(timeout)?;
 
         let request = Request {
             method,
@@ -1,8 +1,10 @@
 use bitcoin::{util::bip32, Network};
+use revault_tx::scripts::{DepositDescriptor, UnvaultDescriptor};
 use serde::{Deserialize, Serialize};
 use std::{
     net::SocketAddr,
     path::{Path, PathBuf},
+    str::FromStr,
 };
 
 // This file is adapted from github.com/re-vault/revaultd:
@@ -82,6 +84,37 @@ pub struct Config {
 
 pub const DEFAULT_FILE_NAME: &str = "revaultd.toml";
 
+/// Returns the content of the configuration file and its entries known by
+/// the GUI, None if the file cannot be parsed.
+fn read_previous(path: &Path) -> Option<(toml::Value, toml::Value)> {
+    let content = std::fs::read(path).ok()?;
+    let previous: toml::Value = toml::from_slice(&content).ok()?;
+    let known = toml::Value::try_from(toml::from_slice::<Config>(&content).ok()?).ok()?;
+    Some((previous, known))
+}
+
+/// Writes the entries of the table to the file table: the entries known by
+/// the GUI and missing from the table are removed, the unknown ones are kept.
+fn merge(file: &mut toml::value::Table, known: &toml::value::Table, table: &toml::value::Table) {
+    for key in known.keys() {
+        if !table.contains_key(key) {
+            file.remove(key);
+        }
+    }
+    let empty = toml::value::Table::new();
+    for (key, value) in table {
+        match (file.get_mut(key), value) {
+            (Some(toml::Value::Table(file)), toml::Value::Table(table)) => {
+                let known = known.get(key).and_then(|k| k.as_table()).unwrap_or(&empty);
+                merge(file, known, table);
+            }
+            _ => {
+                file.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
 impl Config {
     pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
         let config = std::fs::read(path)
@@ -97,8 +130,32 @@ impl Config {
         Ok(config)
     }
 
-    /// default revaultd socket path is .revault/bitcoin/revaultd_rpc
-    pub fn socket_path(&self) -> Result<PathBuf, ConfigError> {
+    /// Writes the configuration to the file, the previous file is kept
+    /// with the `.bak` extension. The entries of the previous file unknown
+    /// to the GUI, e.g. of a newer daemon, are kept as they are.
+    pub fn to_file(&self, path: &Path) -> Result<(), ConfigError> {
+        // Step needed because of ValueAfterTable error in the toml serialize implementation.
+        let mut value = toml::Value::try_from(self)
+            .map_err(|e| ConfigError::Unexpected(format!("Serializing configuration: {}", e)))?;
+        if path.exists() {
+            std::fs::copy(path, path.with_extension("toml.bak")).map_err(|e| {
+                ConfigError::Unexpected(format!("Saving previous configuration file: {}", e))
+            })?;
+            if let Some((mut previous, known)) = read_previous(path) {
+                if let (Some(previous_table), Some(known), Some(table)) =
+                    (previous.as_table_mut(), known.as_table(), value.as_table())
+                {
+                    merge(previous_table, known, table);
+                    value = previous;
+                }
+            }
+        }
+        std::fs::write(path, value.to_string())
+            .map_err(|e| ConfigError::Unexpected(format!("Writing configuration file: {}", e)))
+    }
+
+    /// default revaultd network datadir is .revault/bitcoin
+    pub fn network_datadir(&self) -> Result<PathBuf, ConfigError> {
         let mut path = if let Some(ref datadir) = self.data_dir {
             datadir.clone()
         } else {
@@ -107,10 +164,30 @@ impl Config {
             })?
         };
         path.push(&self.bitcoind_config.network.to_string());
+        Ok(path)
+    }
+
+    /// default revaultd socket path is .revault/bitcoin/revaultd_rpc
+    pub fn socket_path(&self) -> Result<PathBuf, ConfigError> {
+        let mut path = self.network_datadir()?;
         path.push("revaultd_rpc");
         Ok(path)
     }
 
+    /// revaultd writes its logs in the log file of the network datadir once daemonized.
+    pub fn log_file(&self) -> Result<PathBuf, ConfigError> {
+        let mut path = self.network_datadir()?;
+        path.push("log");
+        Ok(path)
+    }
+
+    /// revaultd writes its pid in the pid file of the network datadir once daemonized.
+    pub fn pid_file(&self) -> Result<PathBuf, ConfigError> {
+        let mut path = self.network_datadir()?;
+        path.push("revaultd.pid");
+        Ok(path)
+    }
+
     /// default_config_path returns the default config location of the revault deamon.
     pub fn default_path() -> Result<PathBuf, ConfigError> {
         let mut datadir = default_datadir().map_err(|_| {
@@ -120,6 +197,21 @@ impl Config {
         Ok(datadir)
     }
 
+    /// Number of stakeholders, read from the deposit descriptor.
+    /// Every one of them must sign the revocation transactions to secure a vault.
+    pub fn stakeholders_number(&self) -> Option<usize> {
+        DepositDescriptor::from_str(&self.scripts_config.deposit_descriptor)
+            .ok()
+            .map(|desc| desc.xpubs().len())
+    }
+
+    /// Relative timelock (in blocks) of the unvault transaction output.
+    pub fn unvault_csv(&self) -> Option<u32> {
+        UnvaultDescriptor::from_str(&self.scripts_config.unvault_descriptor)
+            .ok()
+            .map(|desc| desc.csv_value())
+    }
+
     /// returns a revaultd config with empty or dummy values
     pub fn new() -> Config {
         Self {
@@ -203,3 +295,65 @@ impl std::fmt::Display for ConfigError {
 }
 
 impl std::error::Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_keeps_unknown_entries() {
+        let mut file: toml::Value = toml::from_str(
+            r#"
+            daemon = true
+            proxy = "127.0.0.1:9050"
+            future_option = 42
+
+            [bitcoind_config]
+            network = "regtest"
+            cookie_path = "/old/.cookie"
+            addr = "127.0.0.1:18443"
+            rpc_wallet = "revault"
+            "#,
+        )
+        .unwrap();
+        let known: toml::Value = toml::from_str(
+            r#"
+            daemon = true
+            proxy = "127.0.0.1:9050"
+
+            [bitcoind_config]
+            network = "regtest"
+            cookie_path = "/old/.cookie"
+            addr = "127.0.0.1:18443"
+            "#,
+        )
+        .unwrap();
+        // The user removed the proxy and changed the cookie path.
+        let edited: toml::Value = toml::from_str(
+            r#"
+            daemon = true
+
+            [bitcoind_config]
+            network = "regtest"
+            cookie_path = "/new/.cookie"
+            addr = "127.0.0.1:18443"
+            poll_interval_secs = 10
+            "#,
+        )
+        .unwrap();
+
+        merge(
+            file.as_table_mut().unwrap(),
+            known.as_table().unwrap(),
+            edited.as_table().unwrap(),
+        );
+
+        let file = file.as_table().unwrap();
+        assert!(file.get("proxy").is_none());
+        assert_eq!(file["future_option"].as_integer(), Some(42));
+        let bitcoind = file["bitcoind_config"].as_table().unwrap();
+        assert_eq!(bitcoind["cookie_path"].as_str(), Some("/new/.cookie"));
+        assert_eq!(bitcoind["poll_interval_secs"].as_integer(), Some(10));
+        assert_eq!(bitcoind["rpc_wallet"].as_str(), Some("revault"));
+    }
+}
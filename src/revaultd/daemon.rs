@@ -0,0 +1,148 @@
+//! Discovery of the revaultd binary started by the GUI and check of its
+//! version against the range of versions the GUI RPC calls were written for.
+//! The RPC API of a running daemon is detected from its getinfo version.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use super::RevaultDError;
+
+#[cfg(not(windows))]
+const BINARY_NAME: &str = "revaultd";
+#[cfg(windows)]
+const BINARY_NAME: &str = "revaultd.exe";
+
+/// Oldest supported revaultd version.
+pub const MIN_VERSION: Version = Version(0, 1, 0);
+/// First unsupported revaultd version, the RPC may change between minor versions.
+pub const MAX_VERSION: Version = Version(0, 2, 0);
+/// revaultd version whose RPC API the GUI calls are written for, the older
+/// versions of the supported range expose a deprecated API.
+pub const API_VERSION: Version = Version(0, 1, 0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version(pub u64, pub u64, pub u64);
+
+impl Version {
+    /// Parses the first version of the text, `revaultd 0.1.0` or `v0.1.0-rc1`
+    /// give 0.1.0.
+    pub fn parse(text: &str) -> Option<Self> {
+        text.split_whitespace().find_map(|word| {
+            let word = word.strip_prefix('v').unwrap_or(word);
+            let mut numbers = word
+                .split(|c: char| !c.is_ascii_digit() && c != '.')
+                .next()?
+                .split('.')
+                .map(|n| n.parse::<u64>().ok());
+            Some(Self(
+                numbers.next()??,
+                numbers.next()??,
+                numbers.next().flatten().unwrap_or(0),
+            ))
+        })
+    }
+
+    pub fn is_supported(&self) -> bool {
+        *self >= MIN_VERSION && *self < MAX_VERSION
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.0, self.1, self.2)
+    }
+}
+
+/// RPC API of a running revaultd, detected from its getinfo version.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Api {
+    /// The daemon exposes the API the GUI calls are written for.
+    Current(Version),
+    /// The daemon is older than API_VERSION, the next GUI releases may
+    /// drop the support of its API.
+    Deprecated(Version),
+    /// The daemon is newer than API_VERSION, its API was not checked
+    /// against the GUI calls.
+    Untested(Version),
+    /// The version of the daemon could not be parsed.
+    Unknown,
+}
+
+impl Api {
+    pub fn of(version: &str) -> Self {
+        match Version::parse(version) {
+            Some(v) if v < API_VERSION => Self::Deprecated(v),
+            Some(v) if v > API_VERSION => Self::Untested(v),
+            Some(v) => Self::Current(v),
+            None => Self::Unknown,
+        }
+    }
+}
+
+/// Returns the revaultd binary to start: the configured path if any,
+/// else the first one found in the PATH, else the one next to the GUI binary.
+pub fn discover(configured: Option<&Path>) -> Result<PathBuf, RevaultDError> {
+    if let Some(path) = configured {
+        if path.is_file() {
+            return Ok(path.to_path_buf());
+        }
+        return Err(RevaultDError::StartError(format!(
+            "revaultd binary not found at the configured revaultd_path {}",
+            path.display()
+        )));
+    }
+
+    let in_path = std::env::var_os("PATH")
+        .map(|paths| {
+            std::env::split_paths(&paths)
+                .map(|dir| dir.join(BINARY_NAME))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let sibling = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.join(BINARY_NAME)));
+
+    in_path
+        .into_iter()
+        .chain(sibling)
+        .find(|path| path.is_file())
+        .ok_or_else(|| {
+            RevaultDError::StartError(
+                "revaultd binary not found in the PATH nor next to the GUI, \
+                set revaultd_path in the GUI configuration file"
+                    .to_string(),
+            )
+        })
+}
+
+/// Returns the output of `revaultd --version`.
+pub fn version(path: &Path) -> Result<String, RevaultDError> {
+    let output = Command::new(path).arg("--version").output().map_err(|e| {
+        RevaultDError::StartError(format!("Failed to run {}: {}", path.display(), e))
+    })?;
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Checks the version of a revaultd binary or of a running revaultd.
+pub fn check_version(version: &str) -> Result<(), RevaultDError> {
+    match Version::parse(version) {
+        Some(v) if v.is_supported() => Ok(()),
+        _ => Err(RevaultDError::UnsupportedVersion(
+            version.trim().to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_of_the_version() {
+        assert_eq!(Api::of("0.1.0"), Api::Current(Version(0, 1, 0)));
+        assert_eq!(Api::of("v0.0.9"), Api::Deprecated(Version(0, 0, 9)));
+        assert_eq!(Api::of("0.1.2-rc1"), Api::Untested(Version(0, 1, 2)));
+        assert_eq!(Api::of("dev"), Api::Unknown);
+    }
+}
@@ -3,21 +3,29 @@ use std::collections::HashMap;
 use std::fmt::Debug;
 use std::path::Path;
 use std::process::Command;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use std::time::Duration;
 
 use bitcoin::{base64, consensus, util::psbt::PartiallySignedTransaction as Psbt};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use tracing::{debug, error, info, span, Level};
+use tracing::{debug, error, info, span, warn, Level};
 
 mod client;
 pub mod config;
+pub mod daemon;
 pub mod model;
 
 use client::Client;
+pub use client::{error::RpcErrorCode, RetryPolicy};
 use config::Config;
+use daemon::Api;
 use model::{
     DepositAddress, RevocationTransactions, SpendTransaction, SpendTx, SpendTxStatus,
-    UnvaultTransaction, Vault, VaultStatus, VaultTransactions,
+    UnvaultTransaction, Vault, VaultPresignedTransactions, VaultStatus, VaultTransactions,
 };
 
 #[derive(Debug, Clone)]
@@ -25,18 +33,121 @@ pub enum RevaultDError {
     UnexpectedError(String),
     StartError(String),
     RPCError(String),
+    /// revaultd answered with an error, typed by its code so that the
+    /// states can match on it.
+    ServerError(RpcErrorCode, String),
     IOError(std::io::ErrorKind),
     NoAnswerError,
+    /// revaultd did not answer the method before the timeout.
+    Timeout(String),
+    /// The revaultd version is out of the range supported by the GUI.
+    UnsupportedVersion(String),
 }
 
 impl std::fmt::Display for RevaultDError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::StartError(e) => write!(f, "Revaultd error while starting: {}", e),
-            Self::RPCError(e) => write!(f, "Revaultd error rpc call: {}", e),
+            Self::RPCError(e) | Self::ServerError(_, e) => {
+                write!(f, "Revaultd error rpc call: {}", e)
+            }
             Self::UnexpectedError(e) => write!(f, "Revaultd unexpected error: {}", e),
             Self::NoAnswerError => write!(f, "Revaultd returned no answer"),
             Self::IOError(kind) => write!(f, "Revaultd io error: {:?}", kind),
+            Self::Timeout(method) => match retry_of(method) {
+                Retry::Prompt(consequence) => write!(
+                    f,
+                    "Revaultd did not answer {} in time, it was not retried: {}",
+                    method, consequence
+                ),
+                _ => write!(f, "Revaultd did not answer {} in time", method),
+            },
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "Revaultd version '{}' is not supported, the GUI requires a version from {} and before {}",
+                version,
+                daemon::MIN_VERSION,
+                daemon::MAX_VERSION
+            ),
+        }
+    }
+}
+
+/// RPC methods requiring revaultd to reach the coordinator.
+const COORDINATOR_METHODS: [&str; 3] = ["revocationtxs", "unvaulttx", "setspendtx"];
+
+/// Retry of a method timing out: revaultd may have executed a call
+/// changing its state even if it did not answer in time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Retry {
+    /// The call only reads data or executing it twice has the same
+    /// effect as once, it is retried with the backoff of the policy.
+    Automatic,
+    /// The call is not retried, the user retries it knowing the
+    /// consequence of executing it twice.
+    Prompt(&'static str),
+    /// The call is never retried.
+    Never,
+}
+
+/// Returns the retry of the method, methods unknown to the GUI are
+/// never retried.
+pub fn retry_of(method: &str) -> Retry {
+    match method {
+        "getinfo"
+        | "getdepositaddress"
+        | "listvaults"
+        | "listonchaintransactions"
+        | "getrevocationtxs"
+        | "listpresignedtransactions"
+        | "getunvaulttx"
+        | "getspendtx"
+        | "listspendtxs" => Retry::Automatic,
+        // The same signatures are stored and the same transactions broadcast again.
+        "updatespendtx" | "emergency" => Retry::Automatic,
+        "revocationtxs" => Retry::Prompt(
+            "if the signatures were stored the vault is already being secured, \
+             signing again is rejected by revaultd",
+        ),
+        "unvaulttx" => Retry::Prompt(
+            "if the signature was stored the vault is already being delegated, \
+             signing again is rejected by revaultd",
+        ),
+        "setspendtx" => Retry::Prompt(
+            "if the spend was announced the coordinator already has it and the \
+             unvault transactions may be broadcast, check the status of the vaults \
+             before announcing it again",
+        ),
+        "delspendtx" => Retry::Prompt(
+            "if the transaction was deleted, deleting it again fails as it is unknown",
+        ),
+        "revault" => Retry::Prompt(
+            "if the cancel transaction was broadcast the vault is already canceling, \
+             canceling again is rejected by revaultd",
+        ),
+        _ => Retry::Never,
+    }
+}
+
+/// CallPolicy is the timeouts of the calls to revaultd.
+#[derive(Debug, Clone, Copy)]
+pub struct CallPolicy {
+    pub timeout: Duration,
+    /// Timeout of the calls requiring revaultd to reach the coordinator.
+    pub coordinator_timeout: Duration,
+    /// Retries of the calls retried automatically.
+    pub retry: RetryPolicy,
+}
+
+impl Default for CallPolicy {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            coordinator_timeout: Duration::from_secs(120),
+            retry: RetryPolicy {
+                retries: 2,
+                backoff: Duration::from_secs(1),
+            },
         }
     }
 }
@@ -44,11 +155,16 @@ impl std::fmt::Display for RevaultDError {
 #[derive(Debug, Clone)]
 pub struct RevaultD {
     client: Client,
+    policy: CallPolicy,
     pub config: Config,
+    /// false if the last call requiring the coordinator failed to reach it.
+    coordinator_reachable: Arc<AtomicBool>,
+    /// RPC API of the daemon, detected once connected.
+    pub api: Api,
 }
 
 impl RevaultD {
-    pub fn new(config: &Config) -> Result<RevaultD, RevaultDError> {
+    pub fn new(config: &Config, policy: CallPolicy) -> Result<RevaultD, RevaultDError> {
         let span = span!(Level::INFO, "revaultd");
         let _enter = span.enter();
 
@@ -60,14 +176,31 @@ impl RevaultD {
         })?;
 
         let client = Client::new(socket_path);
-        let revaultd = RevaultD {
+        let mut revaultd = RevaultD {
             client,
+            policy,
             config: config.to_owned(),
+            coordinator_reachable: Arc::new(AtomicBool::new(true)),
+            api: Api::Unknown,
         };
 
         debug!("Connecting to revaultd");
 
-        revaultd.get_info()?;
+        revaultd.api = Api::of(&revaultd.get_info()?.version);
+        match revaultd.api {
+            Api::Current(version) => info!("revaultd {} exposes the RPC API of the GUI", version),
+            Api::Deprecated(version) => warn!(
+                "revaultd {} exposes a deprecated RPC API, the GUI is written for revaultd {}",
+                version,
+                daemon::API_VERSION
+            ),
+            Api::Untested(version) => warn!(
+                "revaultd {} is newer than the RPC API of the GUI, written for revaultd {}",
+                version,
+                daemon::API_VERSION
+            ),
+            Api::Unknown => warn!("Failed to detect the RPC API of revaultd from its version"),
+        }
 
         info!("Connected to revaultd");
 
@@ -87,17 +220,71 @@ impl RevaultD {
         let span = span!(Level::INFO, "request");
         let _guard = span.enter();
         info!(method);
-        self.client
-            .send_request(method, input)
-            .and_then(|res| res.into_result())
-            .map_err(|e| {
-                error!("method {} failed: {}", method, e);
-                match e {
-                    client::error::Error::Io(e) => RevaultDError::IOError(e.kind()),
-                    client::error::Error::NoErrorOrResult => RevaultDError::NoAnswerError,
-                    _ => RevaultDError::RPCError(format!("method {} failed: {}", method, e)),
-                }
-            })
+        let timeout = if COORDINATOR_METHODS.contains(&method) {
+            self.policy.coordinator_timeout
+        } else {
+            self.policy.timeout
+        };
+        let retry = match retry_of(method) {
+            Retry::Automatic => self.policy.retry,
+            Retry::Prompt(_) | Retry::Never => RetryPolicy::NONE,
+        };
+        let res = self
+            .client
+            .send_request(method, input, Some(timeout), retry)
+            .and_then(|res| res.into_result());
+
+        if COORDINATOR_METHODS.contains(&method) {
+            let reachable = match &res {
+                Ok(_) => true,
+                Err(client::error::Error::Rpc(e)) => !e.code().is_coordinator(),
+                // revaultd itself did not answer, nothing is known about the coordinator.
+                Err(_) => self.coordinator_reachable(),
+            };
+            self.coordinator_reachable
+                .store(reachable, Ordering::Relaxed);
+        }
+
+        res.map_err(|e| {
+            error!("method {} failed: {}", method, e);
+            match e {
+                client::error::Error::Io(e) => RevaultDError::IOError(e.kind()),
+                client::error::Error::NoErrorOrResult => RevaultDError::NoAnswerError,
+                client::error::Error::Timeout => RevaultDError::Timeout(method.to_string()),
+                client::error::Error::Rpc(ref rpc) => match rpc.code() {
+                    code @ RpcErrorCode::MethodNotFound => RevaultDError::ServerError(
+                        code,
+                        format!("method {} is not supported by this revaultd", method),
+                    ),
+                    code if code.is_coordinator() => RevaultDError::ServerError(
+                        code,
+                        format!(
+                            "method {} failed: the coordinator is unreachable ({})",
+                            method, rpc.message
+                        ),
+                    ),
+                    code => RevaultDError::ServerError(
+                        code,
+                        format!("method {} failed: {}", method, rpc.message),
+                    ),
+                },
+                _ => RevaultDError::RPCError(format!("method {} failed: {}", method, e)),
+            }
+        })
+    }
+
+    /// Returns false if the last call requiring the coordinator could not reach it.
+    pub fn coordinator_reachable(&self) -> bool {
+        self.coordinator_reachable.load(Ordering::Relaxed)
+    }
+
+    /// Sends the method with the params as they are, for the developer console.
+    pub fn raw_call(
+        &self,
+        method: &str,
+        params: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, RevaultDError> {
+        self.call(method, params)
     }
 
     /// get a new deposit address.
@@ -158,6 +345,13 @@ impl RevaultD {
         Ok(())
     }
 
+    pub fn list_presigned_transactions(
+        &self,
+        outpoints: &[String],
+    ) -> Result<ListPresignedTransactionsResponse, RevaultDError> {
+        self.call("listpresignedtransactions", Some(vec![outpoints]))
+    }
+
     pub fn get_unvault_tx(&self, outpoint: &str) -> Result<UnvaultTransaction, RevaultDError> {
         self.call("getunvaulttx", Some(vec![outpoint]))
     }
@@ -213,8 +407,9 @@ impl RevaultD {
         Ok(())
     }
 
-    pub fn emergency(&self) -> Result<(), RevaultDError> {
-        let _res: serde_json::value::Value = self.call("emergency", Option::<Request>::None)?;
+    /// Stops the daemon.
+    pub fn stop(&self) -> Result<(), RevaultDError> {
+        let _res: serde_json::value::Value = self.call("stop", Option::<Request>::None)?;
         Ok(())
     }
 }
@@ -253,6 +448,12 @@ pub struct ListOnchainTransactionsResponse {
     pub onchain_transactions: Vec<VaultTransactions>,
 }
 
+/// listpresignedtransactions response
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListPresignedTransactionsResponse {
+    pub presigned_transactions: Vec<VaultPresignedTransactions>,
+}
+
 /// list_spend_txs
 #[derive(Debug, Clone, Deserialize)]
 pub struct ListSpendTransactionsResponse {
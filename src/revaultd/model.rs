@@ -139,6 +139,12 @@ impl VaultStatus {
         Self::Spending,
     ];
 
+    pub const FUNDED: [VaultStatus; 1] = [Self::Funded];
+
+    /// The vaults with the revocation transactions signed, until the
+    /// unvault transaction is signed.
+    pub const SECURED: [VaultStatus; 3] = [Self::Securing, Self::Secured, Self::Activating];
+
     pub const ACTIVE: [VaultStatus; 1] = [Self::Active];
 
     pub const INACTIVE: [VaultStatus; 4] = [
@@ -205,6 +211,36 @@ impl VaultTransactions {
         }
         &self.deposit
     }
+
+    /// Returns the feerate in sat/vbyte of the transaction, if the outputs
+    /// it spends are all outputs of the transactions of the vault.
+    pub fn feerate(&self, tx: &Transaction) -> Option<u64> {
+        let known: Vec<&Transaction> = [
+            Some(&self.deposit),
+            self.unvault.as_ref(),
+            self.spend.as_ref(),
+            self.cancel.as_ref(),
+            self.emergency.as_ref(),
+            self.unvault_emergency.as_ref(),
+        ]
+        .iter()
+        .flatten()
+        .map(|broadcasted| &broadcasted.tx)
+        .collect();
+        let mut inputs = 0;
+        for input in &tx.input {
+            let previous = known
+                .iter()
+                .find(|known| known.txid() == input.previous_output.txid)?;
+            inputs += previous
+                .output
+                .get(input.previous_output.vout as usize)?
+                .value;
+        }
+        let outputs: u64 = tx.output.iter().map(|output| output.value).sum();
+        let vsize = (tx.get_weight() as u64).div_ceil(4);
+        Some(inputs.checked_sub(outputs)? / vsize)
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -217,6 +253,16 @@ pub struct BroadcastedTransaction {
     pub received_at: i64,
 }
 
+impl BroadcastedTransaction {
+    /// Number of confirmations of the transaction at the given block height,
+    /// 0 if the transaction is not in a block.
+    pub fn confirmations(&self, blockheight: u64) -> u64 {
+        self.blockheight
+            .map(|height| blockheight.saturating_sub(height) + 1)
+            .unwrap_or(0)
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct SignedTransaction {
     #[serde(rename = "hex", with = "bitcoin_transaction")]
@@ -241,6 +287,22 @@ pub struct RevocationTransactions {
     pub emergency_unvault_tx: PartiallySignedTransaction,
 }
 
+/// Presigned transactions of a vault sweeping it to the deep vault,
+/// unset for the manager who does not sign them.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VaultPresignedTransactions {
+    pub vault_outpoint: String,
+    pub emergency: Option<PresignedTransaction>,
+    pub unvault_emergency: Option<PresignedTransaction>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PresignedTransaction {
+    /// The finalized transaction, set once every signature is stored.
+    #[serde(rename = "hex", default, with = "bitcoin_transaction_option")]
+    pub tx: Option<Transaction>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct UnvaultTransaction {
     #[serde(with = "bitcoin_psbt")]
@@ -279,6 +341,20 @@ mod bitcoin_transaction {
     }
 }
 
+mod bitcoin_transaction_option {
+    use bitcoin::Transaction;
+    use serde::{Deserialize, Deserializer};
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Transaction>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Wrapper(#[serde(with = "super::bitcoin_transaction")] Transaction);
+        Option::<Wrapper>::deserialize(deserializer).map(|tx| tx.map(|Wrapper(tx)| tx))
+    }
+}
+
 mod bitcoin_psbt {
     use bitcoin::{base64, consensus::encode, util::psbt::PartiallySignedTransaction};
     use serde::{self, Deserialize, Deserializer};
@@ -0,0 +1,84 @@
+//! Screenshot mode: the transaction ids, addresses and keys displayed are
+//! replaced by placeholders of the same format, so that the screens can be
+//! captured for the documentation or the support without the onchain data
+//! of the wallet. A value always gets the same placeholder while the mode is
+//! enabled, a vault keeps the same txid on every screen. The placeholders
+//! are keyed by a random key drawn when the mode is enabled, so that they
+//! cannot be matched with the values across sessions.
+
+use std::borrow::Cow;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+use crate::logs::{replace_sensitive, Sensitive};
+
+const HEX: &[u8] = b"0123456789abcdef";
+const BECH32: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BASE58: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE64: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Key of the placeholders of the session, drawn when the mode is enabled.
+static KEY: RwLock<Option<RandomState>> = RwLock::new(None);
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn set(enabled: bool) {
+    if enabled && !self::enabled() {
+        if let Ok(mut key) = KEY.write() {
+            *key = Some(RandomState::new());
+        }
+    }
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns the text with its sensitive words replaced if the mode is enabled.
+pub fn mask(text: &str) -> Cow<'_, str> {
+    if !enabled() {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(replace_sensitive(text, placeholder))
+}
+
+/// Returns a placeholder of the same length, prefix and case as the word.
+fn placeholder(word: &str, kind: Sensitive) -> String {
+    let (prefix, charset) = match kind {
+        Sensitive::ExtendedKey => (4, BASE58),
+        // hrp, separator and witness version
+        Sensitive::Bech32Address => (word.to_lowercase().find('1').unwrap_or(0) + 2, BECH32),
+        Sensitive::Hex => (0, HEX),
+        Sensitive::Base58Address => (1, BASE58),
+        Sensitive::Base64 => (0, BASE64),
+    };
+    // The base64 padding is kept.
+    let padding = &word[word.trim_end_matches('=').len()..];
+    let word = &word[..word.len() - padding.len()];
+    let prefix = prefix.min(word.len());
+    let mut hasher = KEY
+        .read()
+        .ok()
+        .and_then(|key| key.as_ref().map(|key| key.build_hasher()))
+        .unwrap_or_else(|| RandomState::new().build_hasher());
+    word.hash(&mut hasher);
+    let mut seed = hasher.finish() | 1;
+    let mut placeholder = word[..prefix].to_string();
+    for _ in prefix..word.len() {
+        // xorshift
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        placeholder.push(charset[(seed % charset.len() as u64) as usize] as char);
+    }
+    let uppercase =
+        (charset == HEX || charset == BECH32) && !word.chars().any(|c| c.is_ascii_lowercase());
+    if uppercase {
+        placeholder.make_ascii_uppercase();
+    }
+    placeholder.push_str(padding);
+    placeholder
+}
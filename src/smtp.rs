@@ -0,0 +1,250 @@
+//! Plain text mails sent through a SMTP relay of this machine, e.g. a
+//! postfix listening on localhost. The relay is trusted: the connection is
+//! neither encrypted nor authenticated, so the relay must be on a loopback
+//! address. The relay handles the delivery.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+pub enum SmtpError {
+    Address(String),
+    /// A field of the mail holds a line break, it would be read as a
+    /// command or a header by the relay.
+    LineBreak(&'static str),
+    Connection(String),
+    /// The relay answered the command with an error reply.
+    Rejected(String),
+}
+
+impl std::fmt::Display for SmtpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Address(e) => write!(f, "Invalid SMTP server address: {}", e),
+            Self::LineBreak(field) => write!(f, "The {} of the mail has a line break", field),
+            Self::Connection(e) => write!(f, "Failed to connect to the SMTP server: {}", e),
+            Self::Rejected(e) => write!(f, "The SMTP server rejected the mail: {}", e),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Mail {
+    pub from: String,
+    pub to: Vec<String>,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Returns an error if a field of the headers or of the commands has a line break.
+fn check_fields(mail: &Mail) -> Result<(), SmtpError> {
+    let has_break = |field: &str| field.contains(['\r', '\n']);
+    if has_break(&mail.from) {
+        return Err(SmtpError::LineBreak("sender"));
+    }
+    if mail.to.iter().any(|to| has_break(to)) {
+        return Err(SmtpError::LineBreak("recipient"));
+    }
+    if has_break(&mail.subject) {
+        return Err(SmtpError::LineBreak("subject"));
+    }
+    Ok(())
+}
+
+/// Sends the mail to the SMTP relay at the host:port address,
+/// which must resolve to a loopback address.
+pub fn send(server: &str, mail: &Mail) -> Result<(), SmtpError> {
+    if mail.to.is_empty() {
+        return Err(SmtpError::Address("no recipient".to_string()));
+    }
+    check_fields(mail)?;
+    let addr = server
+        .to_socket_addrs()
+        .map_err(|e| SmtpError::Address(e.to_string()))?
+        .next()
+        .ok_or_else(|| SmtpError::Address(format!("{} does not resolve", server)))?;
+    if !addr.ip().is_loopback() {
+        return Err(SmtpError::Address(format!(
+            "{} is not a relay of this machine, the connection is not encrypted",
+            server
+        )));
+    }
+    let stream = TcpStream::connect_timeout(&addr, TIMEOUT)
+        .map_err(|e| SmtpError::Connection(e.to_string()))?;
+    stream
+        .set_read_timeout(Some(TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(TIMEOUT)))
+        .map_err(|e| SmtpError::Connection(e.to_string()))?;
+    let mut session = Session {
+        reader: BufReader::new(
+            stream
+                .try_clone()
+                .map_err(|e| SmtpError::Connection(e.to_string()))?,
+        ),
+        writer: stream,
+    };
+
+    session.expect(220)?;
+    session.command("EHLO revault-gui", 250)?;
+    session.command(&format!("MAIL FROM:<{}>", mail.from), 250)?;
+    for to in &mail.to {
+        session.command(&format!("RCPT TO:<{}>", to), 250)?;
+    }
+    session.command("DATA", 354)?;
+    session.write(&message(mail))?;
+    session.command(".", 250)?;
+    // The mail is accepted, an error on quit is not an error of the delivery.
+    let _ = session.command("QUIT", 221);
+    Ok(())
+}
+
+struct Session {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Session {
+    fn write(&mut self, data: &str) -> Result<(), SmtpError> {
+        self.writer
+            .write_all(data.as_bytes())
+            .map_err(|e| SmtpError::Connection(e.to_string()))
+    }
+
+    fn command(&mut self, command: &str, code: u16) -> Result<(), SmtpError> {
+        self.write(&format!("{}\r\n", command))?;
+        self.expect(code)
+    }
+
+    fn expect(&mut self, code: u16) -> Result<(), SmtpError> {
+        read_reply(&mut self.reader, code)
+    }
+}
+
+/// Reads a reply, the lines of a multiline reply are separated
+/// from the code by a dash, the last one by a space.
+fn read_reply<R: BufRead>(reader: &mut R, code: u16) -> Result<(), SmtpError> {
+    loop {
+        let mut line = String::new();
+        reader
+            .read_line(&mut line)
+            .map_err(|e| SmtpError::Connection(e.to_string()))?;
+        if line.is_empty() {
+            return Err(SmtpError::Connection(
+                "the server closed the connection".to_string(),
+            ));
+        }
+        let line = line.trim_end();
+        let reply = line.get(..3).and_then(|c| c.parse::<u16>().ok());
+        if line.as_bytes().get(3) == Some(&b'-') {
+            continue;
+        }
+        return match reply {
+            // 251: the relay forwards the mail of a user not local.
+            Some(c) if c == code || (code == 250 && c == 251) => Ok(()),
+            _ => Err(SmtpError::Rejected(line.to_string())),
+        };
+    }
+}
+
+/// Returns the headers and the body of the mail with CRLF line endings,
+/// a line starting with a dot is escaped with a second one.
+fn message(mail: &Mail) -> String {
+    let mut msg = format!(
+        "From: <{}>\r\nTo: {}\r\nSubject: {}\r\nDate: {}\r\n\
+        MIME-Version: 1.0\r\nContent-Type: text/plain; charset=utf-8\r\n\
+        Content-Transfer-Encoding: 8bit\r\n\r\n",
+        mail.from,
+        mail.to
+            .iter()
+            .map(|to| format!("<{}>", to))
+            .collect::<Vec<String>>()
+            .join(", "),
+        mail.subject,
+        chrono::Utc::now().to_rfc2822(),
+    );
+    for line in mail.body.lines() {
+        if line.starts_with('.') {
+            msg.push('.');
+        }
+        msg.push_str(line);
+        msg.push_str("\r\n");
+    }
+    msg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mail(body: &str) -> Mail {
+        Mail {
+            from: "gui@example.com".to_string(),
+            to: vec![
+                "alice@example.com".to_string(),
+                "bob@example.com".to_string(),
+            ],
+            subject: "Revault wallet report".to_string(),
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    fn message_dot_stuffing() {
+        let msg = message(&mail("line\n.\n.hidden\nend"));
+        let (headers, body) = msg.split_once("\r\n\r\n").unwrap();
+        assert!(headers.contains("To: <alice@example.com>, <bob@example.com>\r\n"));
+        assert!(headers.contains("Subject: Revault wallet report\r\n"));
+        assert_eq!(body, "line\r\n..\r\n..hidden\r\nend\r\n");
+    }
+
+    #[test]
+    fn reply_parsing() {
+        let read = |reply: &str, code| read_reply(&mut reply.as_bytes(), code);
+        assert!(read("220 relay ready\r\n", 220).is_ok());
+        assert!(read("250-relay\r\n250-SIZE 1000\r\n250 HELP\r\n", 250).is_ok());
+        assert!(read("251 forwarding\r\n", 250).is_ok());
+        assert!(matches!(
+            read("250-relay\r\n550 rejected\r\n", 250),
+            Err(SmtpError::Rejected(line)) if line == "550 rejected"
+        ));
+        assert!(matches!(read("", 250), Err(SmtpError::Connection(_))));
+        assert!(matches!(
+            read("garbage\r\n", 250),
+            Err(SmtpError::Rejected(_))
+        ));
+    }
+
+    #[test]
+    fn line_breaks_are_rejected() {
+        assert!(check_fields(&mail("body\nwith lines")).is_ok());
+        let mut m = mail("");
+        m.subject = "report\r\nBcc: eve@example.com".to_string();
+        assert!(matches!(
+            check_fields(&m),
+            Err(SmtpError::LineBreak("subject"))
+        ));
+        let mut m = mail("");
+        m.from = "gui@example.com>\r\nRCPT TO:<eve@example.com".to_string();
+        assert!(matches!(
+            check_fields(&m),
+            Err(SmtpError::LineBreak("sender"))
+        ));
+        let mut m = mail("");
+        m.to.push("eve@example.com\n".to_string());
+        assert!(matches!(
+            send("127.0.0.1:25", &m),
+            Err(SmtpError::LineBreak("recipient"))
+        ));
+    }
+
+    #[test]
+    fn relay_must_be_local() {
+        assert!(matches!(
+            send("192.0.2.1:25", &mail("")),
+            Err(SmtpError::Address(_))
+        ));
+    }
+}
@@ -78,3 +78,28 @@ pub const DARK_GREY: Color = Color::from_rgb(
     0x97 as f32 / 255.0,
     0xa6 as f32 / 255.0,
 );
+
+/// Returns the color of the participant, the hue is derived from the
+/// fingerprint of its key so that it is the same on every screen and
+/// on the computers of the other participants.
+pub fn participant(fingerprint: &bitcoin::util::bip32::Fingerprint) -> Color {
+    let bytes = &fingerprint[..];
+    let hue = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) % 360;
+    from_hsl(hue as f32, 0.65, 0.45)
+}
+
+fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Color {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let h = hue / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    Color::from_rgb(r + m, g + m, b + m)
+}
@@ -1,12 +1,19 @@
+use crate::i18n::Language;
+use crate::participant::Participant;
+use crate::revault::Role;
 use crate::ui::{
     color,
+    component::text,
     icon::{
-        block_icon, deposit_icon, person_check_icon, send_icon, shield_check_icon, shield_icon,
-        shield_notif_icon, turnback_icon,
+        block_icon, deposit_icon, dot_icon, person_check_icon, send_icon, shield_check_icon,
+        shield_icon, shield_notif_icon, turnback_icon,
     },
+    theme,
 };
 
-use iced::{container, Container, Length};
+use iced::{container, Align, Column, Container, Length, Row};
+
+use bitcoin::util::bip32::Fingerprint;
 
 pub fn person_check<'a, T: 'a>() -> Container<'a, T> {
     let icon = person_check_icon().width(Length::Units(20));
@@ -23,7 +30,7 @@ impl container::StyleSheet for PersonBadgeStyle {
     fn style(&self) -> container::Style {
         container::Style {
             border_radius: 40.0,
-            background: color::FOREGROUND.into(),
+            background: theme::palette().foreground.into(),
             text_color: color::CANCEL.into(),
             ..container::Style::default()
         }
@@ -45,7 +52,7 @@ impl container::StyleSheet for ShieldBadgeStyle {
     fn style(&self) -> container::Style {
         container::Style {
             border_radius: 40.0,
-            background: color::FOREGROUND.into(),
+            background: theme::palette().foreground.into(),
             text_color: color::CANCEL.into(),
             ..container::Style::default()
         }
@@ -67,7 +74,7 @@ impl container::StyleSheet for ShieldSuccessBadgeStyle {
     fn style(&self) -> container::Style {
         container::Style {
             border_radius: 40.0,
-            background: color::FOREGROUND.into(),
+            background: theme::palette().foreground.into(),
             text_color: color::SUCCESS.into(),
             ..container::Style::default()
         }
@@ -89,7 +96,7 @@ impl container::StyleSheet for ShieldNotifBadgeStyle {
     fn style(&self) -> container::Style {
         container::Style {
             border_radius: 40.0,
-            background: color::FOREGROUND.into(),
+            background: theme::palette().foreground.into(),
             text_color: color::CANCEL.into(),
             ..container::Style::default()
         }
@@ -251,8 +258,42 @@ impl container::StyleSheet for InactiveBadgeStyle {
     fn style(&self) -> container::Style {
         container::Style {
             border_radius: 40.0,
-            background: color::BACKGROUND.into(),
+            background: theme::palette().background.into(),
+            text_color: theme::palette().text.into(),
             ..container::Style::default()
         }
     }
 }
+
+/// participants displays the participants with their role.
+pub fn participants<'a, T: 'a>(language: Language, participants: &[Participant]) -> Column<'a, T> {
+    let mut col = Column::new().spacing(10);
+    for (role, title) in [
+        (Some(Role::Stakeholder), "Stakeholders"),
+        (Some(Role::Manager), "Managers"),
+    ] {
+        let row = participants
+            .iter()
+            .filter(|p| p.role == role)
+            .fold(Row::new().spacing(20), |row, p| {
+                row.push(participant(&p.fingerprint))
+            });
+        col = col.push(
+            Row::new()
+                .push(Container::new(text::small(language.tr(title))).width(Length::Units(150)))
+                .push(row)
+                .align_items(Align::Center),
+        );
+    }
+    col
+}
+
+/// participant displays the fingerprint of the participant with its color.
+pub fn participant<'a, T: 'a>(fingerprint: &Fingerprint) -> Row<'a, T> {
+    let color = color::participant(fingerprint);
+    Row::new()
+        .push(dot_icon().size(10).color(color))
+        .push(text::bold(text::small(&fingerprint.to_string())).color(color))
+        .spacing(5)
+        .align_items(Align::Center)
+}
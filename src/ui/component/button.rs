@@ -1,4 +1,4 @@
-use crate::ui::{color, component::text, icon::clipboard_icon};
+use crate::ui::{color, component::text, icon::clipboard_icon, theme};
 use iced::{button, Color, Container, Row, Vector};
 
 macro_rules! button {
@@ -44,7 +44,7 @@ button!(
     transparent,
     TransparentStyle,
     Color::TRANSPARENT,
-    Color::BLACK
+    theme::palette().text
 );
 
 pub fn button_content<'a, T: 'a>(icon: Option<iced::Text>, text: &str) -> Container<'a, T> {
@@ -85,7 +85,7 @@ impl button::StyleSheet for ClipboardButtonStyle {
             border_radius: 10.0,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
-            text_color: Color::BLACK,
+            text_color: theme::palette().text,
         }
     }
 }
@@ -102,15 +102,15 @@ impl button::StyleSheet for WhiteCardButtonStyle {
     fn active(&self) -> button::Style {
         button::Style {
             border_radius: 10.0,
-            background: color::FOREGROUND.into(),
+            background: theme::palette().foreground.into(),
             ..button::Style::default()
         }
     }
     fn hovered(&self) -> button::Style {
         button::Style {
             border_radius: 10.0,
-            background: color::FOREGROUND.into(),
-            border_color: color::SECONDARY,
+            background: theme::palette().foreground.into(),
+            border_color: theme::palette().secondary,
             border_width: 1.0,
             ..button::Style::default()
         }
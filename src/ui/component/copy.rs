@@ -0,0 +1,19 @@
+//! Values displayed along a button copying them to the clipboard.
+
+use iced::{button::State, Align, Element, Length, Row};
+
+use super::button;
+
+/// Returns the content followed by a button sending the message, the message
+/// is expected to be the clipboard message of the view with the copied value.
+pub fn copyable<'a, T: 'a + Clone>(
+    state: &'a mut State,
+    content: impl Into<Element<'a, T>>,
+    message: T,
+) -> Row<'a, T> {
+    Row::new()
+        .push(content)
+        .push(button::clipboard(state, message).width(Length::Shrink))
+        .spacing(5)
+        .align_items(Align::Center)
+}
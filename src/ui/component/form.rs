@@ -3,7 +3,7 @@ use iced::{
     Column, Container, Length,
 };
 
-use crate::ui::{color, component::text};
+use crate::ui::{color, component::text, theme};
 
 #[derive(Debug, Clone)]
 pub struct Value<T> {
@@ -94,7 +94,7 @@ struct InvalidFormStyle;
 impl text_input::StyleSheet for InvalidFormStyle {
     fn active(&self) -> text_input::Style {
         text_input::Style {
-            background: iced::Background::Color(color::FOREGROUND),
+            background: iced::Background::Color(theme::palette().foreground),
             border_radius: 5.0,
             border_width: 1.0,
             border_color: color::WARNING,
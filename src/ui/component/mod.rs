@@ -1,10 +1,13 @@
 pub mod badge;
 pub mod button;
+pub mod copy;
 pub mod form;
 pub mod image;
+pub mod qr;
 pub mod text;
 
-use super::{color, font, icon};
+use super::{color, font, icon, theme};
+use crate::i18n::Language;
 
 use iced::{container, scrollable, Column, Container, Length, Row, Scrollable};
 
@@ -50,8 +53,9 @@ impl container::StyleSheet for NavbarStyle {
     fn style(&self) -> container::Style {
         container::Style {
             border_width: 1.0,
-            border_color: color::SECONDARY,
-            background: color::FOREGROUND.into(),
+            border_color: theme::palette().secondary,
+            background: theme::palette().foreground.into(),
+            text_color: theme::palette().text.into(),
             ..container::Style::default()
         }
     }
@@ -67,7 +71,7 @@ pub struct SepStyle;
 impl container::StyleSheet for SepStyle {
     fn style(&self) -> container::Style {
         container::Style {
-            background: color::SECONDARY.into(),
+            background: theme::palette().secondary.into(),
             ..container::Style::default()
         }
     }
@@ -77,7 +81,8 @@ pub struct ContainerBackgroundStyle;
 impl container::StyleSheet for ContainerBackgroundStyle {
     fn style(&self) -> container::Style {
         container::Style {
-            background: color::BACKGROUND.into(),
+            background: theme::palette().background.into(),
+            text_color: theme::palette().text.into(),
             ..container::Style::default()
         }
     }
@@ -87,7 +92,8 @@ pub struct TransparentPickListStyle;
 impl iced::pick_list::StyleSheet for TransparentPickListStyle {
     fn active(&self) -> iced::pick_list::Style {
         iced::pick_list::Style {
-            background: color::FOREGROUND.into(),
+            background: theme::palette().foreground.into(),
+            text_color: theme::palette().text,
             border_width: 1.0,
             border_radius: 10.0,
             ..iced::pick_list::Style::default()
@@ -95,14 +101,16 @@ impl iced::pick_list::StyleSheet for TransparentPickListStyle {
     }
     fn hovered(&self) -> iced::pick_list::Style {
         iced::pick_list::Style {
-            background: color::FOREGROUND.into(),
+            background: theme::palette().foreground.into(),
+            text_color: theme::palette().text,
             border_radius: 10.0,
             ..iced::pick_list::Style::default()
         }
     }
     fn menu(&self) -> iced::pick_list::Menu {
         iced::pick_list::Menu {
-            background: color::FOREGROUND.into(),
+            background: theme::palette().foreground.into(),
+            text_color: theme::palette().text,
             ..iced::pick_list::Menu::default()
         }
     }
@@ -112,17 +120,17 @@ pub struct TooltipStyle;
 impl container::StyleSheet for TooltipStyle {
     fn style(&self) -> container::Style {
         container::Style {
-            text_color: iced::Color::BLACK.into(),
-            background: color::FOREGROUND.into(),
+            text_color: theme::palette().text.into(),
+            background: theme::palette().foreground.into(),
             border_radius: 10.0,
             border_width: 1.0,
-            border_color: color::SECONDARY,
+            border_color: theme::palette().secondary,
         }
     }
 }
 
 pub mod card {
-    use super::color;
+    use super::{color, theme};
     use iced::{container, Container};
 
     pub fn success<'a, T: 'a>(content: Container<'a, T>) -> Container<'a, T> {
@@ -153,10 +161,10 @@ pub mod card {
         fn style(&self) -> container::Style {
             container::Style {
                 border_radius: 10.0,
-                border_color: iced::Color::BLACK,
+                border_color: theme::palette().text,
                 border_width: 2.0,
-                background: color::FOREGROUND.into(),
-                ..container::Style::default()
+                background: theme::palette().foreground.into(),
+                text_color: theme::palette().text.into(),
             }
         }
     }
@@ -174,8 +182,8 @@ pub mod card {
                 border_radius: 10.0,
                 border_color: color::PRIMARY,
                 border_width: 2.0,
-                background: color::FOREGROUND.into(),
-                ..container::Style::default()
+                background: theme::palette().foreground.into(),
+                text_color: theme::palette().text.into(),
             }
         }
     }
@@ -193,8 +201,8 @@ pub mod card {
                 border_radius: 10.0,
                 border_color: color::SUCCESS,
                 border_width: 2.0,
-                background: color::FOREGROUND.into(),
-                ..container::Style::default()
+                background: theme::palette().foreground.into(),
+                text_color: theme::palette().text.into(),
             }
         }
     }
@@ -210,7 +218,7 @@ pub mod card {
                 border_radius: 10.0,
                 border_color: color::INFO_LIGHT,
                 border_width: 2.0,
-                background: color::FOREGROUND.into(),
+                background: theme::palette().foreground.into(),
                 text_color: color::INFO_LIGHT.into(),
             }
         }
@@ -225,7 +233,8 @@ pub mod card {
         fn style(&self) -> container::Style {
             container::Style {
                 border_radius: 10.0,
-                background: color::FOREGROUND.into(),
+                background: theme::palette().foreground.into(),
+                text_color: theme::palette().text.into(),
                 ..container::Style::default()
             }
         }
@@ -240,7 +249,8 @@ pub mod card {
         fn style(&self) -> container::Style {
             container::Style {
                 border_radius: 10.0,
-                background: color::FOREGROUND.into(),
+                background: theme::palette().foreground.into(),
+                text_color: theme::palette().text.into(),
                 ..container::Style::default()
             }
         }
@@ -299,7 +309,7 @@ pub mod card {
         fn style(&self) -> container::Style {
             container::Style {
                 border_radius: 10.0,
-                background: color::SECONDARY.into(),
+                background: theme::palette().secondary.into(),
                 ..container::Style::default()
             }
         }
@@ -322,9 +332,13 @@ impl ProgressBar {
         }
     }
 
-    pub fn draw<'a, T: 'a + Clone>(&self, current_step: usize) -> Container<'a, T> {
+    pub fn draw<'a, T: 'a + Clone>(
+        &self,
+        language: Language,
+        current_step: usize,
+    ) -> Container<'a, T> {
         let mut row = Row::new().spacing(20);
-        for (i, step) in self.steps.iter().enumerate() {
+        for (i, step) in self.steps.iter().map(|step| language.tr(step)).enumerate() {
             match i.cmp(&current_step) {
                 Ordering::Less => row = row.push(self.progress_circle_done(step)),
                 Ordering::Equal => row = row.push(self.progress_circle_doing(step)),
@@ -0,0 +1,34 @@
+use iced::{qr_code, Element, QRCode};
+
+use crate::screenshot;
+
+/// Qr is the scannable code of some data, the code is cached
+/// and only encoded again when the data changes. In screenshot mode
+/// the code is the one of the placeholder of the data.
+#[derive(Debug, Default)]
+pub struct Qr {
+    data: String,
+    state: Option<qr_code::State>,
+}
+
+impl Qr {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, data: &str) {
+        let masked = screenshot::mask(data);
+        let data: &str = &masked;
+        if self.state.is_none() || self.data != data {
+            self.state = qr_code::State::new(data).ok();
+            self.data = data.to_string();
+        }
+    }
+
+    /// Returns None if no data was set or if the data is too long to be encoded.
+    pub fn view<'a, T: 'a>(&'a mut self, cell_size: u16) -> Option<Element<'a, T>> {
+        self.state
+            .as_mut()
+            .map(|state| QRCode::new(state).cell_size(cell_size).into())
+    }
+}
@@ -1,20 +1,25 @@
 use super::{color, font};
+use crate::screenshot;
 use iced::{Container, Text};
 
 pub fn simple(content: &str) -> Text {
-    Text::new(content).font(font::REGULAR).size(20)
+    Text::new(screenshot::mask(content))
+        .font(font::regular())
+        .size(font::text_size())
 }
 
 pub fn small(content: &str) -> Text {
-    Text::new(content).font(font::REGULAR).size(15)
+    Text::new(screenshot::mask(content))
+        .font(font::regular())
+        .size(font::scaled(15))
 }
 
 pub fn paragraph<'a, T: 'a>(s: &str) -> Container<'a, T> {
-    Container::new(Text::new(s).font(font::REGULAR))
+    Container::new(Text::new(screenshot::mask(s)).font(font::regular()))
 }
 
 pub fn bold(t: Text) -> Text {
-    t.font(font::BOLD)
+    t.font(font::bold())
 }
 
 pub fn success(t: Text) -> Text {
@@ -1,3 +1,9 @@
+//! Fonts of the interface. The bundled Open Sans fonts are used unless the
+//! GUI configuration sets other fonts, which are loaded once at startup.
+
+use std::path::Path;
+use std::sync::OnceLock;
+
 use iced::Font;
 
 pub const BOLD: Font = Font::External {
@@ -9,3 +15,104 @@ pub const REGULAR: Font = Font::External {
     name: "Regular",
     bytes: include_bytes!("../../static/fonts/OpenSans-Regular.ttf"),
 };
+
+/// Default size of the regular text.
+pub const DEFAULT_TEXT_SIZE: u16 = 20;
+
+/// Characters a font must render to replace the bundled fonts.
+const REQUIRED_GLYPHS: &str =
+    "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789.,:;()[]/-_%";
+
+#[derive(Debug, Clone, Copy)]
+struct Fonts {
+    regular: Font,
+    bold: Font,
+    text_size: u16,
+}
+
+static FONTS: OnceLock<Fonts> = OnceLock::new();
+
+fn fonts() -> &'static Fonts {
+    FONTS.get_or_init(|| Fonts {
+        regular: REGULAR,
+        bold: BOLD,
+        text_size: DEFAULT_TEXT_SIZE,
+    })
+}
+
+pub fn regular() -> Font {
+    fonts().regular
+}
+
+pub fn bold() -> Font {
+    fonts().bold
+}
+
+/// Returns the size of the regular text.
+pub fn text_size() -> u16 {
+    fonts().text_size
+}
+
+/// Returns the size scaled with the configured text size.
+pub fn scaled(size: u16) -> u16 {
+    (size as u32 * text_size() as u32 / DEFAULT_TEXT_SIZE as u32).max(1) as u16
+}
+
+/// Returns true if the font data has a glyph for every character.
+pub fn has_glyphs(bytes: &[u8], chars: &str) -> bool {
+    match ttf_parser::Face::from_slice(bytes, 0) {
+        Ok(face) => chars.chars().all(|c| face.glyph_index(c).is_some()),
+        Err(_) => false,
+    }
+}
+
+/// Loads a font file, the font is kept for the lifetime of the process.
+fn load(name: &'static str, path: &Path) -> Result<Font, String> {
+    let bytes = std::fs::read(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    if let Err(e) = ttf_parser::Face::from_slice(&bytes, 0) {
+        return Err(format!("{}: {}", path.display(), e));
+    }
+    if !has_glyphs(&bytes, REQUIRED_GLYPHS) {
+        return Err(format!(
+            "{}: the font lacks some of the characters of the interface",
+            path.display()
+        ));
+    }
+    Ok(Font::External {
+        name,
+        bytes: Box::leak(bytes.into_boxed_slice()),
+    })
+}
+
+/// Sets the fonts of the interface, must be called before the first render.
+/// A font which cannot be used is replaced by the bundled one, the errors
+/// are returned to be reported to the user.
+pub fn init(regular: Option<&Path>, bold: Option<&Path>, text_size: Option<u16>) -> Vec<String> {
+    let mut errors = Vec::new();
+    let mut load_or = |name, path: Option<&Path>, default| match path.map(|p| load(name, p)) {
+        Some(Ok(font)) => font,
+        Some(Err(e)) => {
+            errors.push(e);
+            default
+        }
+        None => default,
+    };
+    let regular = load_or("Custom Regular", regular, REGULAR);
+    // Without a bold variant, the custom regular font is used for the bold text.
+    let default_bold = if matches!(regular, Font::External { name, .. } if name == "Custom Regular")
+    {
+        regular
+    } else {
+        BOLD
+    };
+    let bold = load_or("Custom Bold", bold, default_bold);
+    let fonts = Fonts {
+        regular,
+        bold,
+        text_size: text_size.unwrap_or(DEFAULT_TEXT_SIZE),
+    };
+    if FONTS.set(fonts).is_err() {
+        errors.push("the fonts were already set".to_string());
+    }
+    errors
+}
@@ -1,11 +1,102 @@
+use std::sync::OnceLock;
+
 use iced::{Font, HorizontalAlignment, Length, Text};
 
+use super::font;
+
+const ICONS_BYTES: &[u8] = include_bytes!("../../static/icons/bootstrap-icons.ttf");
+
 const ICONS: Font = Font::External {
     name: "Icons",
-    bytes: include_bytes!("../../static/icons/bootstrap-icons.ttf"),
+    bytes: ICONS_BYTES,
 };
 
-fn icon(unicode: char) -> Text {
+/// Codepoint of an icon in the icon font, with the text label displayed
+/// instead if the font has no glyph for it.
+type Icon = (char, &'static str);
+
+const HOME: Icon = ('\u{F3DC}', "Home");
+const SEND: Icon = ('\u{F144}', "Send");
+const DEPOSIT: Icon = ('\u{F123}', "In");
+const WITHDRAWAL: Icon = ('\u{F144}', "Out");
+const TURNBACK: Icon = ('\u{F131}', "Back");
+const HISTORY: Icon = ('\u{F292}', "History");
+const VAULTS: Icon = ('\u{F1C7}', "Vaults");
+const SETTINGS: Icon = ('\u{F3C5}', "Settings");
+const BLOCK: Icon = ('\u{F1C8}', "Block");
+const NETWORK: Icon = ('\u{F3ED}', "Net");
+const DOT: Icon = ('\u{F287}', "*");
+const CLIPBOARD: Icon = ('\u{F28E}', "Copy");
+const SHIELD: Icon = ('\u{F517}', "Shield");
+const SHIELD_NOTIF: Icon = ('\u{F50A}', "Shield!");
+const SHIELD_CHECK: Icon = ('\u{F509}', "Shield+");
+const PERSON_CHECK: Icon = ('\u{F4AF}', "Ok");
+const ARROW_UP: Icon = ('\u{F148}', "^");
+const ARROW_DOWN: Icon = ('\u{F128}', "v");
+const TERMINAL: Icon = ('\u{F5CB}', ">_");
+const TOOLTIP: Icon = ('\u{F410}', "?");
+const PLUS: Icon = ('\u{F4D7}', "+");
+const WARNING: Icon = ('\u{F31B}', "!");
+const TRASH: Icon = ('\u{F5A8}', "Del");
+const STAKEHOLDER: Icon = ('\u{F4AE}', "Stakeholder");
+const MANAGER: Icon = ('\u{F4B4}', "Manager");
+const DONE: Icon = ('\u{F26B}', "[x]");
+const TODO: Icon = ('\u{F28A}', "[ ]");
+
+/// Every icon of the interface.
+const ALL: [Icon; 27] = [
+    HOME,
+    SEND,
+    DEPOSIT,
+    WITHDRAWAL,
+    TURNBACK,
+    HISTORY,
+    VAULTS,
+    SETTINGS,
+    BLOCK,
+    NETWORK,
+    DOT,
+    CLIPBOARD,
+    SHIELD,
+    SHIELD_NOTIF,
+    SHIELD_CHECK,
+    PERSON_CHECK,
+    ARROW_UP,
+    ARROW_DOWN,
+    TERMINAL,
+    TOOLTIP,
+    PLUS,
+    WARNING,
+    TRASH,
+    STAKEHOLDER,
+    MANAGER,
+    DONE,
+    TODO,
+];
+
+/// Returns true if the icon font has no glyph for the codepoint. The font
+/// is checked once for every icon.
+fn is_missing(unicode: char) -> bool {
+    static MISSING: OnceLock<Vec<char>> = OnceLock::new();
+    MISSING
+        .get_or_init(|| {
+            ALL.iter()
+                .map(|(unicode, _)| *unicode)
+                .filter(|unicode| !font::has_glyphs(ICONS_BYTES, &unicode.to_string()))
+                .collect()
+        })
+        .contains(&unicode)
+}
+
+/// Returns the icon, or its text label if the icon font has no glyph for it,
+/// so that a blank square is never rendered in place of an icon.
+fn icon((unicode, label): Icon) -> Text {
+    if is_missing(unicode) {
+        return Text::new(label)
+            .font(font::regular())
+            .horizontal_alignment(HorizontalAlignment::Center)
+            .size(15);
+    }
     Text::new(&unicode.to_string())
         .font(ICONS)
         .width(Length::Units(20))
@@ -14,106 +105,130 @@ fn icon(unicode: char) -> Text {
 }
 
 pub fn home_icon() -> Text {
-    icon('\u{F3DC}')
+    icon(HOME)
 }
 
 pub fn send_icon() -> Text {
-    icon('\u{F144}')
+    icon(SEND)
 }
 
 pub fn deposit_icon() -> Text {
-    icon('\u{F123}')
+    icon(DEPOSIT)
 }
 
 #[allow(dead_code)]
 pub fn withdrawal_icon() -> Text {
-    icon('\u{F144}')
+    icon(WITHDRAWAL)
 }
 
 pub fn turnback_icon() -> Text {
-    icon('\u{F131}')
+    icon(TURNBACK)
 }
 
 #[allow(dead_code)]
 pub fn history_icon() -> Text {
-    icon('\u{F292}')
+    icon(HISTORY)
 }
 
 pub fn vaults_icon() -> Text {
-    icon('\u{F1C7}')
+    icon(VAULTS)
 }
 
 pub fn settings_icon() -> Text {
-    icon('\u{F3C5}')
+    icon(SETTINGS)
 }
 
 pub fn block_icon() -> Text {
-    icon('\u{F1C8}')
+    icon(BLOCK)
 }
 
 pub fn network_icon() -> Text {
-    icon('\u{F3ED}')
+    icon(NETWORK)
 }
 
 pub fn dot_icon() -> Text {
-    icon('\u{F287}')
+    icon(DOT)
 }
 
 pub fn clipboard_icon() -> Text {
-    icon('\u{F28E}')
+    icon(CLIPBOARD)
 }
 
 pub fn shield_icon() -> Text {
-    icon('\u{F517}')
+    icon(SHIELD)
 }
 
 pub fn shield_notif_icon() -> Text {
-    icon('\u{F50A}')
+    icon(SHIELD_NOTIF)
 }
 
 pub fn shield_check_icon() -> Text {
-    icon('\u{F509}')
+    icon(SHIELD_CHECK)
 }
 
 pub fn person_check_icon() -> Text {
-    icon('\u{F4AF}')
+    icon(PERSON_CHECK)
 }
 
-#[allow(dead_code)]
 pub fn arrow_up_icon() -> Text {
-    icon('\u{F148}')
+    icon(ARROW_UP)
+}
+
+pub fn arrow_down_icon() -> Text {
+    icon(ARROW_DOWN)
+}
+
+pub fn terminal_icon() -> Text {
+    icon(TERMINAL)
 }
 
 pub fn tooltip_icon() -> Text {
-    icon('\u{F410}')
+    icon(TOOLTIP)
 }
 
 pub fn plus_icon() -> Text {
-    icon('\u{F4D7}')
+    icon(PLUS)
 }
 
 pub fn warning_icon() -> Text {
-    icon('\u{F31B}')
+    icon(WARNING)
 }
 
 pub fn trash_icon() -> Text {
-    icon('\u{F5A8}')
+    icon(TRASH)
 }
 
 #[allow(dead_code)]
 pub fn stakeholder_icon() -> Text {
-    icon('\u{F4AE}')
+    icon(STAKEHOLDER)
 }
 
 #[allow(dead_code)]
 pub fn manager_icon() -> Text {
-    icon('\u{F4B4}')
+    icon(MANAGER)
 }
 
 pub fn done_icon() -> Text {
-    icon('\u{F26B}')
+    icon(DONE)
 }
 
 pub fn todo_icon() -> Text {
-    icon('\u{F28A}')
+    icon(TODO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn icons_have_a_glyph() {
+        for (unicode, label) in &ALL {
+            assert!(
+                font::has_glyphs(ICONS_BYTES, &unicode.to_string()),
+                "{}",
+                label
+            );
+            assert!(!is_missing(*unicode), "{}", label);
+        }
+    }
 }
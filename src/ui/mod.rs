@@ -3,3 +3,4 @@ pub mod color;
 pub mod component;
 pub mod font;
 pub mod icon;
+pub mod theme;
@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+use std::sync::RwLock;
+
+use iced::Color;
+
+use super::color;
+
+/// Theme used to draw the interface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Theme {
+    #[default]
+    Light,
+    Dark,
+}
+
+impl Theme {
+    pub fn palette(&self) -> Palette {
+        match self {
+            Theme::Light => Palette {
+                background: color::BACKGROUND,
+                foreground: color::FOREGROUND,
+                secondary: color::SECONDARY,
+                text: Color::BLACK,
+                muted: color::SECONDARY,
+            },
+            Theme::Dark => Palette {
+                background: Color::from_rgb8(0x16, 0x18, 0x1C),
+                foreground: Color::from_rgb8(0x23, 0x26, 0x2B),
+                secondary: Color::from_rgb8(0x3A, 0x3E, 0x45),
+                text: Color::from_rgb8(0xE6, 0xE8, 0xEB),
+                muted: Color::from_rgb8(0x6B, 0x72, 0x7D),
+            },
+        }
+    }
+}
+
+/// Colors of the surfaces and of the texts of a theme, the colors of the
+/// states (primary, success, warning...) are the same in both themes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    /// Background of the window and of the main section.
+    pub background: Color,
+    /// Background of the cards, the sidebar and the navbar.
+    pub foreground: Color,
+    /// Borders and separators.
+    pub secondary: Color,
+    pub text: Color,
+    /// Texts of lesser importance.
+    pub muted: Color,
+}
+
+static ACTIVE: RwLock<Theme> = RwLock::new(Theme::Light);
+
+/// Theme used by the styles of the components, the styles are resolved
+/// when the views are drawn.
+pub fn active() -> Theme {
+    ACTIVE.read().map(|t| *t).unwrap_or_default()
+}
+
+pub fn set(theme: Theme) {
+    if let Ok(mut t) = ACTIVE.write() {
+        *t = theme;
+    }
+}
+
+pub fn palette() -> Palette {
+    active().palette()
+}
+
+/// Theme setting of the user, stored in the GUI configuration file.
+/// `System` follows the light/dark preference of the operating system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemePreference {
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl ThemePreference {
+    pub const ALL: [ThemePreference; 3] = [
+        ThemePreference::System,
+        ThemePreference::Light,
+        ThemePreference::Dark,
+    ];
+
+    /// Returns the theme to use, an explicit override of the user always
+    /// takes precedence over the system preference.
+    pub fn resolve(&self) -> Theme {
+        match self {
+            ThemePreference::Light => Theme::Light,
+            ThemePreference::Dark => Theme::Dark,
+            ThemePreference::System => system_theme().unwrap_or_default(),
+        }
+    }
+}
+
+impl std::fmt::Display for ThemePreference {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::System => write!(f, "System"),
+            Self::Light => write!(f, "Light"),
+            Self::Dark => write!(f, "Dark"),
+        }
+    }
+}
+
+/// Detects the light/dark preference of the operating system.
+/// Returns None if the platform does not expose it.
+pub fn system_theme() -> Option<Theme> {
+    if let Ok(gtk_theme) = std::env::var("GTK_THEME") {
+        return Some(theme_from_dark(gtk_theme.to_lowercase().ends_with(":dark")));
+    }
+
+    if cfg!(target_os = "macos") {
+        // The key is only set when the dark appearance is enabled.
+        return command_output("defaults", &["read", "-g", "AppleInterfaceStyle"])
+            .map(|out| theme_from_dark(out.trim() == "Dark"))
+            .or(Some(Theme::Light));
+    }
+
+    if cfg!(target_os = "windows") {
+        return command_output(
+            "reg",
+            &[
+                "query",
+                "HKCU\\Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize",
+                "/v",
+                "AppsUseLightTheme",
+            ],
+        )
+        .map(|out| theme_from_dark(out.contains("0x0")));
+    }
+
+    command_output(
+        "gsettings",
+        &["get", "org.gnome.desktop.interface", "color-scheme"],
+    )
+    .map(|out| theme_from_dark(out.contains("prefer-dark")))
+}
+
+fn theme_from_dark(dark: bool) -> Theme {
+    if dark {
+        Theme::Dark
+    } else {
+        Theme::Light
+    }
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()
+}